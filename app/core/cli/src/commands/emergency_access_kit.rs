@@ -0,0 +1,73 @@
+use anyhow::{bail, Context, Result};
+use bdk::bitcoin::secp256k1::rand::{thread_rng, Rng};
+use eak::{ActiveSpendingKeyset, Backup, Payload};
+use qrcode::render::unicode;
+use rustify::blocking::clients::reqwest::Client;
+use sled::Db;
+use wca::commands::UnsealedKey;
+use wca::pcsc::PCSCTransactor;
+
+use crate::{
+    cache::FromCache,
+    db::transactions::FromDatabase,
+    entities::{Account, HardwareSignerProxy, SignerHistory},
+    nfc::NFCTransactions,
+    signers::Spending,
+};
+
+pub(crate) fn create(client: &Client, db: &Db) -> Result<()> {
+    let account = Account::from_cache(client, db)?;
+    let signers =
+        SignerHistory::from_database(db).context("no paired signers found; please `pair` first")?;
+    let active = signers.active;
+    if let HardwareSignerProxy::Fake(_) = active.hardware {
+        bail!("an emergency access kit requires real hardware to seal the key");
+    }
+
+    let app_key = Spending::public_key(&active.application);
+    let hardware_key = Spending::public_key(&active.hardware);
+    let keyset = account
+        .keysets
+        .iter()
+        .find(|ks| ks.keys.application == app_key && ks.keys.hardware == hardware_key)
+        .context("paired hardware not found in account keyset")?;
+
+    let backup = Backup::new(
+        ActiveSpendingKeyset {
+            local_id: keyset.id.to_string(),
+            network: active.network,
+            app_key,
+            hardware_key,
+            server_key: keyset.keys.server.clone(),
+        },
+        active.application.account_secret_key(),
+    )?;
+
+    let pkek = UnsealedKey::new(thread_rng().gen());
+    let sealed_pkek = PCSCTransactor::new()?.seal_key(UnsealedKey::new(*pkek.expose_secret()))?;
+    let payload = Payload::seal(&backup, pkek.expose_secret(), sealed_pkek)?;
+
+    println!("{}", payload.encode());
+    let image = payload
+        .qr_code()?
+        .render::<unicode::Dense1x2>()
+        .dark_color(unicode::Dense1x2::Light)
+        .light_color(unicode::Dense1x2::Dark)
+        .build();
+    println!("{image}");
+
+    Ok(())
+}
+
+pub(crate) fn restore(payload: &str) -> Result<()> {
+    let payload = Payload::decode(payload)?;
+    let pkek = PCSCTransactor::new()?.unseal_key(payload.sealed_pkek())?;
+    let backup = payload.unseal(pkek.expose_secret())?;
+    let descriptors = backup.descriptors()?;
+
+    println!("network: {}", backup.keyset().network);
+    println!("external: {}", descriptors.external);
+    println!("internal: {}", descriptors.internal);
+
+    Ok(())
+}
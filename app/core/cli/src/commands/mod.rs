@@ -2,6 +2,7 @@ pub(crate) use wipe::wipe;
 
 pub(crate) mod account;
 pub mod check_keyproofs;
+pub(crate) mod emergency_access_kit;
 pub mod end_to_end;
 pub mod firmware;
 pub mod pair;
@@ -2,13 +2,13 @@ use anyhow::Result;
 use bdk::{
     bitcoin::Address,
     blockchain::{Blockchain, ElectrumBlockchain},
-    FeeRate,
 };
 use rustify::blocking::clients::reqwest::Client;
 use sled::Db;
 
 use crate::{
     cache::FromCache,
+    commands::wallet::fee_rate,
     db::transactions::FromDatabase,
     entities::{Account, SignerHistory},
 };
@@ -26,11 +26,12 @@ pub fn drain(
             .active
             .wallet(&account, db, Some(&signers.active.hardware.sign_context()?))?;
 
+    let fee_rate = fee_rate(client, db, wallet.network());
     let mut builder = wallet.build_tx();
     builder
         .drain_wallet()
         .drain_to(recipient.script_pubkey())
-        .fee_rate(FeeRate::default_min_relay_fee())
+        .fee_rate(fee_rate)
         .enable_rbf();
     let (mut psbt, _) = builder.finish()?;
 
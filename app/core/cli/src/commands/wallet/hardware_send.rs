@@ -8,7 +8,7 @@ use sled::Db;
 
 use crate::{
     cache::FromCache,
-    commands::wallet::psbt_from,
+    commands::wallet::{fee_rate, psbt_from},
     db::transactions::FromDatabase,
     entities::{Account, SignerHistory},
 };
@@ -28,7 +28,8 @@ pub fn hardware_send(
             .active
             .wallet(&account, db, Some(&signers.active.hardware.sign_context()?))?;
 
-    let mut psbt = psbt_from(&wallet, recipient, amount)?;
+    let fee_rate = fee_rate(client, db, wallet.network());
+    let mut psbt = psbt_from(&wallet, recipient, amount, fee_rate)?;
 
     let finalised = wallet.sign(&mut psbt, Default::default())?;
     assert!(finalised, "transaction wasn't finalised?!");
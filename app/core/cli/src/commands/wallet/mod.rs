@@ -1,3 +1,14 @@
+use bdk::{bitcoin::Network, FeeRate};
+use rustify::blocking::clients::reqwest::Client;
+use sled::Db;
+
+use crate::{
+    db::transactions::FromDatabase,
+    entities::AuthenticationToken,
+    requests::{helper::EndpointExt, FeeEstimatesRequest},
+    serde_helpers::fromagerie_network,
+};
+
 pub use balance::balance;
 pub use drain::drain;
 pub use hardware_send::hardware_send;
@@ -12,6 +23,7 @@ pub use utxos::utxos;
 mod balance;
 mod drain;
 mod hardware_send;
+pub mod psbt;
 mod receive;
 pub mod recovery;
 mod server_send;
@@ -25,9 +37,36 @@ pub(crate) fn psbt_from<D: bdk::database::BatchDatabase>(
     wallet: &bdk::Wallet<D>,
     recipient: bdk::bitcoin::Address,
     amount: u64,
+    fee_rate: bdk::FeeRate,
 ) -> Result<bdk::bitcoin::psbt::PartiallySignedTransaction, bdk::Error> {
     let mut builder = wallet.build_tx();
-    builder.add_recipient(recipient.script_pubkey(), amount);
+    builder
+        .add_recipient(recipient.script_pubkey(), amount)
+        .fee_rate(fee_rate);
     let (psbt, _) = builder.finish()?;
     Ok(psbt)
 }
+
+/// The server's medium fee estimate for the network, or the minimum relay fee if the server can't
+/// provide one.
+pub(crate) fn fee_rate(client: &Client, db: &Db, network: Network) -> FeeRate {
+    let estimates = AuthenticationToken::from_database(db).and_then(|token| {
+        FeeEstimatesRequest {
+            network: fromagerie_network::name(network).to_string(),
+        }
+        .exec_authenticated(client, &token)
+    });
+
+    match estimates {
+        Ok(estimates) => {
+            if estimates.stale {
+                eprintln!("warning: fee estimates are from {}", estimates.timestamp);
+            }
+            FeeRate::from_sat_per_vb(estimates.medium_sat_per_vb)
+        }
+        Err(err) => {
+            eprintln!("warning: unable to get fee estimates, using the minimum relay fee: {err}");
+            FeeRate::default_min_relay_fee()
+        }
+    }
+}
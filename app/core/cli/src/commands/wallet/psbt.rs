@@ -0,0 +1,297 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context, Result};
+use bdk::{
+    bitcoin::{
+        consensus::encode::{deserialize, serialize},
+        psbt::PartiallySignedTransaction,
+        secp256k1::Secp256k1,
+        Address,
+    },
+    blockchain::{log_progress, Blockchain, ElectrumBlockchain},
+    psbt::PsbtUtils,
+    SignOptions,
+};
+use clap::Args;
+use rustify::blocking::clients::reqwest::Client;
+use sled::Db;
+use wca::pcsc::NullTransactor;
+
+use crate::{
+    cache::FromCache,
+    commands::wallet::{fee_rate, psbt_from},
+    db::transactions::FromDatabase,
+    entities::{Account, AuthenticationToken, SignerHistory},
+    nfc::SafeTransactor,
+    requests::{helper::EndpointExt, SignTransactionRequest},
+    signers::Spending,
+};
+
+// BIP-174: the binary serialisation starts with "psbt" followed by 0xff.
+const PSBT_MAGIC: &[u8] = b"psbt\xff";
+
+#[derive(Clone, Args)]
+pub(crate) struct PsbtOutput {
+    /// Write the PSBT to a file instead of printing it as base64
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+
+    /// Write the binary serialisation instead of base64
+    #[clap(long, requires = "output")]
+    binary: bool,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum PsbtSigner {
+    Hardware,
+    App,
+}
+
+fn decode(bytes: &[u8]) -> Result<PartiallySignedTransaction> {
+    if bytes.starts_with(PSBT_MAGIC) {
+        return Ok(deserialize(bytes)?);
+    }
+
+    let base64 = std::str::from_utf8(bytes).context("PSBT is neither binary nor base64")?;
+    Ok(PartiallySignedTransaction::from_str(base64.trim())?)
+}
+
+fn encode(psbt: &PartiallySignedTransaction, binary: bool) -> Vec<u8> {
+    if binary {
+        serialize(psbt)
+    } else {
+        psbt.to_string().into_bytes()
+    }
+}
+
+fn read(path: &Path) -> Result<PartiallySignedTransaction> {
+    let bytes = fs::read(path).with_context(|| format!("could not read {}", path.display()))?;
+    decode(&bytes).with_context(|| format!("could not parse PSBT from {}", path.display()))
+}
+
+fn write(psbt: &PartiallySignedTransaction, output: &PsbtOutput) -> Result<()> {
+    match &output.output {
+        Some(path) => fs::write(path, encode(psbt, output.binary))
+            .with_context(|| format!("could not write {}", path.display())),
+        None => {
+            println!("{psbt}");
+            Ok(())
+        }
+    }
+}
+
+pub(crate) fn create(
+    client: &Client,
+    db: &Db,
+    blockchain: ElectrumBlockchain,
+    recipient: Address,
+    amount: Option<u64>,
+    output: &PsbtOutput,
+) -> Result<()> {
+    let account = Account::from_cache(client, db)?;
+    let signers =
+        SignerHistory::from_database(db).context("no paired signers found; please `pair` first")?;
+    let wallet = signers.active.wallet(&account, db, None)?;
+
+    wallet.sync(
+        &blockchain,
+        bdk::SyncOptions {
+            progress: Some(Box::new(log_progress())),
+        },
+    )?;
+
+    let fee_rate = fee_rate(client, db, wallet.network());
+    let psbt = match amount {
+        Some(amount) => psbt_from(&wallet, recipient, amount, fee_rate)?,
+        None => {
+            let mut builder = wallet.build_tx();
+            builder
+                .drain_wallet()
+                .drain_to(recipient.script_pubkey())
+                .fee_rate(fee_rate)
+                .enable_rbf();
+            builder.finish()?.0
+        }
+    };
+
+    write(&psbt, output)
+}
+
+pub(crate) fn sign(db: &Db, input: &Path, signer: PsbtSigner, output: &PsbtOutput) -> Result<()> {
+    let signers =
+        SignerHistory::from_database(db).context("no paired signers found; please `pair` first")?;
+    let mut psbt = read(input)?;
+
+    let transaction_signer = match signer {
+        PsbtSigner::Hardware => signers
+            .active
+            .hardware
+            .signer(&signers.active.hardware.sign_context()?),
+        PsbtSigner::App => signers
+            .active
+            .application
+            .signer(&SafeTransactor::new(NullTransactor)),
+    };
+    transaction_signer.sign_transaction(&mut psbt, &SignOptions::default(), &Secp256k1::new())?;
+
+    write(&psbt, output)
+}
+
+pub(crate) fn cosign_server(
+    client: &Client,
+    db: &Db,
+    input: &Path,
+    output: &PsbtOutput,
+) -> Result<()> {
+    let account = Account::from_cache(client, db)?;
+    let mut psbt = read(input)?;
+
+    let response = SignTransactionRequest {
+        account_id: account.id,
+        psbt: psbt.clone(),
+        settings: Default::default(),
+    }
+    .exec_authenticated(client, &AuthenticationToken::from_database(db)?)?;
+
+    // Don't trust the server too much!
+    psbt.combine(response.tx).context("psbt combine error")?;
+
+    write(&psbt, output)
+}
+
+pub(crate) fn combine(inputs: &[PathBuf], output: &PsbtOutput) -> Result<()> {
+    let (first, rest) = inputs.split_first().context("no PSBTs to combine")?;
+    let mut psbt = read(first)?;
+    for path in rest {
+        psbt.combine(read(path)?)
+            .with_context(|| format!("could not combine {}", path.display()))?;
+    }
+
+    write(&psbt, output)
+}
+
+pub(crate) fn inspect(db: &Db, input: &Path) -> Result<()> {
+    let psbt = read(input)?;
+    // Addresses are only displayed when we know which network we're on.
+    let network = SignerHistory::from_database(db)
+        .map(|signers| signers.active.network)
+        .ok();
+
+    let tx = &psbt.unsigned_tx;
+    println!("txid: {}", tx.txid());
+
+    println!("inputs:");
+    for (txin, input) in tx.input.iter().zip(&psbt.inputs) {
+        let value = input
+            .witness_utxo
+            .as_ref()
+            .map(|utxo| utxo.value)
+            .or_else(|| {
+                input
+                    .non_witness_utxo
+                    .as_ref()
+                    .and_then(|prev| prev.output.get(txin.previous_output.vout as usize))
+                    .map(|utxo| utxo.value)
+            });
+        let status = if input.final_script_witness.is_some() || input.final_script_sig.is_some() {
+            "finalised".to_string()
+        } else {
+            format!("{} signature(s)", input.partial_sigs.len())
+        };
+        match value {
+            Some(value) => println!("  {} {value} sats ({status})", txin.previous_output),
+            None => println!("  {} ({status})", txin.previous_output),
+        }
+    }
+
+    println!("outputs:");
+    for txout in &tx.output {
+        match network.and_then(|network| Address::from_script(&txout.script_pubkey, network).ok()) {
+            Some(address) => println!("  {address} {} sats", txout.value),
+            None => println!("  {} {} sats", txout.script_pubkey, txout.value),
+        }
+    }
+
+    if let (Some(fee), Some(fee_rate)) = (psbt.fee_amount(), psbt.fee_rate()) {
+        println!("fee: {fee} sats ({} sat/vB)", fee_rate.as_sat_per_vb());
+    }
+
+    Ok(())
+}
+
+pub(crate) fn broadcast(
+    client: &Client,
+    db: &Db,
+    blockchain: ElectrumBlockchain,
+    input: &Path,
+) -> Result<()> {
+    let account = Account::from_cache(client, db)?;
+    let wallet = SignerHistory::from_database(db)?
+        .active
+        .wallet(&account, db, None)?;
+    let mut psbt = read(input)?;
+
+    if !wallet.finalize_psbt(&mut psbt, SignOptions::default())? {
+        bail!("PSBT is missing signatures and could not be finalised");
+    }
+
+    let transaction = psbt.extract_tx();
+    blockchain.broadcast(&transaction)?;
+    println!("{}", transaction.txid());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use bdk::bitcoin::{OutPoint, PackedLockTime, Sequence, Transaction, TxIn, TxOut, Witness};
+
+    use super::*;
+
+    fn psbt() -> PartiallySignedTransaction {
+        PartiallySignedTransaction::from_unsigned_tx(Transaction {
+            version: 2,
+            lock_time: PackedLockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint::null(),
+                script_sig: Default::default(),
+                sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: Witness::default(),
+            }],
+            output: vec![TxOut {
+                value: 1_000,
+                script_pubkey: Default::default(),
+            }],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn roundtrip_base64() {
+        let encoded = encode(&psbt(), false);
+        assert!(encoded.starts_with(b"cHNidP8"));
+        assert_eq!(decode(&encoded).unwrap(), psbt());
+
+        // Files written by other tools often end with a newline.
+        let mut with_newline = encoded.clone();
+        with_newline.push(b'\n');
+        assert_eq!(decode(&with_newline).unwrap(), psbt());
+    }
+
+    #[test]
+    fn roundtrip_binary() {
+        let encoded = encode(&psbt(), true);
+        assert!(encoded.starts_with(PSBT_MAGIC));
+        assert_eq!(decode(&encoded).unwrap(), psbt());
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!(decode(b"not a psbt").is_err());
+        assert!(decode(&[0xff, 0xfe, 0xfd]).is_err());
+    }
+}
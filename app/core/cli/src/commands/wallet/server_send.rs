@@ -8,7 +8,10 @@ use crate::cache::FromCache;
 use crate::db::transactions::FromDatabase;
 use crate::entities::{Account, AuthenticationToken, SignerHistory};
 use crate::requests::helper::EndpointExt;
-use crate::{commands::wallet::psbt_from, requests::SignTransactionRequest};
+use crate::{
+    commands::wallet::{fee_rate, psbt_from},
+    requests::SignTransactionRequest,
+};
 
 pub fn server_send(
     client: &Client,
@@ -21,7 +24,8 @@ pub fn server_send(
     let signers = SignerHistory::from_database(db)?;
 
     let wallet = signers.active.wallet(&account, db, None)?;
-    let mut psbt = psbt_from(&wallet, recipient, amount)?;
+    let fee_rate = fee_rate(client, db, wallet.network());
+    let mut psbt = psbt_from(&wallet, recipient, amount, fee_rate)?;
     let finalised = wallet.sign(&mut psbt, Default::default())?;
     assert!(!finalised, "transaction was finalised?!");
 
@@ -18,7 +18,8 @@ use bdk::bitcoin::Address;
 use bdk::blockchain::ElectrumBlockchain;
 use bdk::electrum_client::Client as ElectrumClient;
 
-use clap::{Parser, Subcommand};
+use clap::{ArgGroup, Parser, Subcommand};
+use commands::wallet::psbt::{PsbtOutput, PsbtSigner};
 use rustify::blocking::clients::reqwest::Client;
 use tracing_subscriber::{prelude::*, EnvFilter, Registry};
 
@@ -80,6 +81,11 @@ enum Commands {
         #[clap(subcommand)]
         command: FirmwareCommands,
     },
+    /// Emergency Access Kit operations (e.g. create, restore)
+    EmergencyAccessKit {
+        #[clap(subcommand)]
+        command: EmergencyAccessKitCommands,
+    },
 
     /// Wallet snoop command (for debugging wallets)
     Snoop {
@@ -148,6 +154,70 @@ enum WalletCommands {
     },
     /// List the UTXOs in the wallet
     Utxos {},
+    /// Step-by-step PSBT workflow (create, sign, cosign, combine, inspect, broadcast)
+    Psbt {
+        #[clap(subcommand)]
+        command: PsbtCommands,
+    },
+}
+
+#[derive(Clone, Subcommand)]
+enum PsbtCommands {
+    /// Create an unsigned PSBT
+    Create {
+        recipient: Address,
+        /// Amount in sats (omit with --drain)
+        #[clap(required_unless_present = "drain")]
+        amount: Option<u64>,
+        /// Send all funds to the recipient
+        #[clap(long, conflicts_with = "amount")]
+        drain: bool,
+        #[clap(flatten)]
+        output: PsbtOutput,
+    },
+    /// Sign a PSBT with one of the paired signers
+    #[clap(group(ArgGroup::new("signer").required(true).args(["hardware", "app"])))]
+    Sign {
+        /// Base64 or binary PSBT file
+        input: PathBuf,
+        /// Sign with the hardware
+        #[clap(long)]
+        hardware: bool,
+        /// Sign with the app key
+        #[clap(long)]
+        app: bool,
+        #[clap(flatten)]
+        output: PsbtOutput,
+    },
+    /// Have a cosigner sign a PSBT
+    #[clap(group(ArgGroup::new("cosigner").required(true).args(["server"])))]
+    Cosign {
+        /// Base64 or binary PSBT file
+        input: PathBuf,
+        /// Cosign with the server (subject to mobile pay limits)
+        #[clap(long)]
+        server: bool,
+        #[clap(flatten)]
+        output: PsbtOutput,
+    },
+    /// Combine the signatures from several PSBTs of the same transaction
+    Combine {
+        /// Base64 or binary PSBT files
+        #[clap(num_args = 2.., required = true)]
+        inputs: Vec<PathBuf>,
+        #[clap(flatten)]
+        output: PsbtOutput,
+    },
+    /// Display the inputs, outputs, fee and signatures of a PSBT
+    Inspect {
+        /// Base64 or binary PSBT file
+        input: PathBuf,
+    },
+    /// Finalise a fully-signed PSBT and broadcast the transaction
+    Broadcast {
+        /// Base64 or binary PSBT file
+        input: PathBuf,
+    },
 }
 
 #[derive(Clone, Subcommand)]
@@ -173,6 +243,17 @@ enum FirmwareCommands {
     },
 }
 
+#[derive(Clone, Subcommand)]
+enum EmergencyAccessKitCommands {
+    /// Create an Emergency Access Kit payload for the active keyset
+    Create {},
+    /// Unseal an Emergency Access Kit payload and display the wallet descriptors
+    Restore {
+        /// The base58-encoded payload printed on the kit
+        payload: String,
+    },
+}
+
 fn main() -> Result<()> {
     Registry::default()
         .with(EnvFilter::from_default_env())
@@ -235,6 +316,41 @@ fn main() -> Result<()> {
                 }
             },
             WalletCommands::Utxos {} => commands::wallet::utxos(&client, &db, blockchain)?,
+            WalletCommands::Psbt { command } => match command {
+                PsbtCommands::Create {
+                    recipient,
+                    amount,
+                    drain: _,
+                    output,
+                } => commands::wallet::psbt::create(
+                    &client, &db, blockchain, recipient, amount, &output,
+                )?,
+                PsbtCommands::Sign {
+                    input,
+                    hardware,
+                    app: _,
+                    output,
+                } => {
+                    let signer = if hardware {
+                        PsbtSigner::Hardware
+                    } else {
+                        PsbtSigner::App
+                    };
+                    commands::wallet::psbt::sign(&db, &input, signer, &output)?
+                }
+                PsbtCommands::Cosign {
+                    input,
+                    server: _,
+                    output,
+                } => commands::wallet::psbt::cosign_server(&client, &db, &input, &output)?,
+                PsbtCommands::Combine { inputs, output } => {
+                    commands::wallet::psbt::combine(&inputs, &output)?
+                }
+                PsbtCommands::Inspect { input } => commands::wallet::psbt::inspect(&db, &input)?,
+                PsbtCommands::Broadcast { input } => {
+                    commands::wallet::psbt::broadcast(&client, &db, blockchain, &input)?
+                }
+            },
         },
         Commands::EndToEnd {
             ref treasury_root_key,
@@ -253,6 +369,14 @@ fn main() -> Result<()> {
                 firmware_bundle: Some(firmware_bundle),
             } => commands::firmware::upload_bundle(firmware_bundle)?,
         },
+        Commands::EmergencyAccessKit { command } => match command {
+            EmergencyAccessKitCommands::Create {} => {
+                commands::emergency_access_kit::create(&client, &db)?
+            }
+            EmergencyAccessKitCommands::Restore { payload } => {
+                commands::emergency_access_kit::restore(&payload)?
+            }
+        },
 
         Commands::Snoop {
             account_table,
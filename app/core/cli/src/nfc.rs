@@ -16,7 +16,7 @@ use wca::{
     commands::{
         DeviceInfo, FirmwareMetadata, FwupFinish, FwupFinishRspStatus, FwupStart, FwupTransfer,
         GetAuthenticationKey, GetFirmwareMetadata, GetInitialSpendingKey, QueryAuthentication,
-        SignTransaction,
+        SealKey, SealedKey, SignTransaction, UnsealKey, UnsealedKey,
     },
     pcsc::{Performer, Transactor, TransactorError},
 };
@@ -82,6 +82,8 @@ pub trait NFCTransactions {
         network: bdk::bitcoin::Network,
     ) -> Result<DescriptorPublicKey, TransactorError>;
     fn wipe(&self) -> Result<bool, TransactorError>;
+    fn seal_key(&self, key: UnsealedKey) -> Result<SealedKey, TransactorError>;
+    fn unseal_key(&self, sealed_key: SealedKey) -> Result<UnsealedKey, TransactorError>;
 }
 
 impl<T: Transactor + ?Sized> NFCTransactions for T {
@@ -161,6 +163,14 @@ impl<T: Transactor + ?Sized> NFCTransactions for T {
     fn wipe(&self) -> Result<bool, TransactorError> {
         self.perform(WipeState::new())
     }
+
+    fn seal_key(&self, key: UnsealedKey) -> Result<SealedKey, TransactorError> {
+        self.perform(SealKey::new(key))
+    }
+
+    fn unseal_key(&self, sealed_key: SealedKey) -> Result<UnsealedKey, TransactorError> {
+        self.perform(UnsealKey::new(sealed_key))
+    }
 }
 
 pub struct Asset {
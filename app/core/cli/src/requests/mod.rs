@@ -406,3 +406,24 @@ pub struct MobilePaySetupRequest {
 
 #[derive(Deserialize, Debug)]
 pub struct MobilePaySetupResponse {}
+
+#[derive(Debug, Endpoint, Serialize)]
+#[endpoint(
+    path = "api/fee-estimates",
+    method = "GET",
+    response = "FeeEstimatesResponse"
+)]
+pub struct FeeEstimatesRequest {
+    #[endpoint(query)]
+    pub network: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeeEstimatesResponse {
+    pub fast_sat_per_vb: f32,
+    pub medium_sat_per_vb: f32,
+    pub slow_sat_per_vb: f32,
+    #[serde(with = "rfc3339")]
+    pub timestamp: OffsetDateTime,
+    pub stale: bool,
+}
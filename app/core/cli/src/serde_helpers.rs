@@ -49,6 +49,16 @@ pub mod fromagerie_network {
         };
         Ok(value)
     }
+
+    /// The wallet server's name for a network, for use in query strings.
+    pub fn name(network: Network) -> &'static str {
+        match network {
+            Network::Bitcoin => "bitcoin-main",
+            Network::Testnet => "bitcoin-test",
+            Network::Signet => "bitcoin-signet",
+            _ => "bitcoin-regtest",
+        }
+    }
 }
 
 pub mod string {
@@ -21,6 +21,7 @@ use bdk::{
     },
     SignOptions,
 };
+use crypto::secret::Secret;
 use serde::{Deserialize, Serialize};
 use wca::{
     commands::{find_next_bip84_derivation, AUTHENTICATION_DERIVATION_PATH},
@@ -37,16 +38,19 @@ use super::{Authentication, Spending};
 pub(crate) struct SeedSigner {
     #[serde(default, skip)]
     secp: Secp256k1<All>,
-    seed: [u8; 32],
+    seed: Secret<[u8; 32]>,
     network: Network,
     account: ChildNumber,
 }
 
 impl SeedSigner {
     pub(crate) fn new(network: Network, account: u32) -> Self {
+        let mut seed = Secret::new([0; 32]);
+        thread_rng().fill(seed.expose_secret_mut());
+
         Self {
             secp: Secp256k1::new(),
-            seed: thread_rng().gen(),
+            seed,
             network,
             account: ChildNumber::Hardened { index: account },
         }
@@ -59,7 +63,8 @@ impl SeedSigner {
             Network::Testnet | Network::Signet | Network::Regtest => Network::Testnet,
         };
 
-        ExtendedPrivKey::new_master(network, &self.seed).expect("could not create xprv from seed")
+        ExtendedPrivKey::new_master(network, self.seed.expose_secret())
+            .expect("could not create xprv from seed")
     }
 
     fn authentication_private_key(&self) -> SecretKey {
@@ -85,8 +90,12 @@ impl SeedSigner {
         }
     }
 
-    fn account_public_key(&self) -> DescriptorPublicKey {
+    pub(crate) fn account_secret_key(&self) -> DescriptorSecretKey {
         DescriptorSecretKey::XPrv(self.account_private_key())
+    }
+
+    fn account_public_key(&self) -> DescriptorPublicKey {
+        self.account_secret_key()
             .to_public(&self.secp)
             .expect("could not derive dpub from dprv")
     }
@@ -134,8 +143,9 @@ impl Spending for SeedSigner {
         let [_purpose, _coin_type, next_account] = next_path;
         Ok(Self {
             secp: Secp256k1::new(),
+            seed: Secret::new(*self.seed.expose_secret()),
+            network: self.network,
             account: next_account,
-            ..*self
         })
     }
 
@@ -147,9 +157,7 @@ impl Spending for SeedSigner {
 impl PartialEq for SeedSigner {
     fn eq(&self, other: &Self) -> bool {
         // Compare everything except the secp context
-        let x = (self.seed, self.network, self.account);
-        let y = (other.seed, other.network, other.account);
-        x == y
+        self.seed == other.seed && self.network == other.network && self.account == other.account
     }
 }
 
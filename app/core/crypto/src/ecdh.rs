@@ -32,9 +32,9 @@ mod tests {
     fn test_shared_secret() {
         let mut random_bytes = [0u8; 32];
         rand::thread_rng().fill_bytes(&mut random_bytes[..]);
-        let sk1 = crate::keys::SecretKey::new(random_bytes.to_vec()).unwrap();
+        let sk1 = crate::keys::SecretKey::new(random_bytes.to_vec().into()).unwrap();
         rand::thread_rng().fill_bytes(&mut random_bytes[..]);
-        let sk2 = crate::keys::SecretKey::new(random_bytes.to_vec()).unwrap();
+        let sk2 = crate::keys::SecretKey::new(random_bytes.to_vec().into()).unwrap();
 
         let sec1 = Secp256k1SharedSecret::new(&sk1.as_public(), &sk2);
         let sec2 = Secp256k1SharedSecret::new(&sk2.as_public(), &sk1);
@@ -1,9 +1,15 @@
 use bitcoin::hashes::sha256;
 use bitcoin::secp256k1::ecdsa::Signature;
 use bitcoin::secp256k1::{
-    Error as BitcoinError, Message, Secp256k1, SecretKey as BitcoinSecretKey,
+    Error as BitcoinError, Message, Secp256k1, SecretKey as BitcoinSecretKey, ONE_KEY,
 };
+use std::fmt;
+use std::sync::atomic::{compiler_fence, Ordering};
 use std::sync::{Mutex, MutexGuard};
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::secret::SecretBytes;
 
 pub use bitcoin::secp256k1::PublicKey;
 
@@ -13,13 +19,15 @@ pub enum SecretKeyError {
     InvalidSecretBytes(#[from] BitcoinError),
 }
 
+/// A secp256k1 secret key that is overwritten on drop, redacted from `Debug` output and compared
+/// in constant time.
 pub struct SecretKey(Mutex<BitcoinSecretKey>);
 
 impl SecretKey {
     // For usage with `DescriptorSecretKey` exposed via `BDK`, pass the result of `secret_bytes`
     // into this constructor.
-    pub fn new(secret_bytes: Vec<u8>) -> Result<Self, SecretKeyError> {
-        let seckey = BitcoinSecretKey::from_slice(&secret_bytes)?;
+    pub fn new(secret_bytes: SecretBytes) -> Result<Self, SecretKeyError> {
+        let seckey = BitcoinSecretKey::from_slice(secret_bytes.expose_secret())?;
 
         Ok(Self(Mutex::new(seckey)))
     }
@@ -40,3 +48,72 @@ impl SecretKey {
         self.0.lock().unwrap()
     }
 }
+
+impl Zeroize for SecretKey {
+    // All-zero bytes are not a valid secp256k1 secret key, so the key is overwritten with one
+    // instead, the same way later versions of `secp256k1` implement `non_secure_erase`.
+    fn zeroize(&mut self) {
+        let inner = self
+            .0
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        unsafe { std::ptr::write_volatile(inner, ONE_KEY) };
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretKey {}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey([REDACTED])")
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        // Locking the same mutex twice would deadlock.
+        if std::ptr::eq(self, other) {
+            return true;
+        }
+        let this = Zeroizing::new(self.inner().secret_bytes());
+        let that = Zeroizing::new(other.inner().secret_bytes());
+        this.ct_eq(&*that).into()
+    }
+}
+
+impl Eq for SecretKey {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: [u8; 32] = [0xaa; 32];
+
+    #[test]
+    fn test_zeroize() {
+        let mut key = SecretKey::new(SECRET.to_vec().into()).unwrap();
+        key.zeroize();
+        assert_eq!(key.inner().secret_bytes(), ONE_KEY.secret_bytes());
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let key = SecretKey::new(SECRET.to_vec().into()).unwrap();
+        assert_eq!(format!("{key:?}"), "SecretKey([REDACTED])");
+    }
+
+    #[test]
+    fn test_eq() {
+        let key = SecretKey::new(SECRET.to_vec().into()).unwrap();
+        assert_eq!(key, key);
+        assert_eq!(key, SecretKey::new(SECRET.to_vec().into()).unwrap());
+        assert_ne!(key, SecretKey::new(vec![0xbb; 32].into()).unwrap());
+    }
+}
@@ -4,4 +4,6 @@ pub mod hkdf;
 pub mod hmac;
 pub mod invoice;
 pub mod keys;
+pub mod secret;
+pub mod social_recovery;
 pub mod spake2;
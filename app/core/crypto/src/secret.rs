@@ -0,0 +1,108 @@
+use std::fmt;
+
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A wrapper for key material and other secrets.
+///
+/// The wrapped value is zeroized when the `Secret` is dropped, is redacted from `Debug` output,
+/// and is compared in constant time. `Secret` is deliberately not `Clone`: copies of a secret
+/// have to be made explicitly through [`Secret::expose_secret`].
+pub struct Secret<T: Zeroize>(T);
+
+pub type SecretBytes = Secret<Vec<u8>>;
+
+impl<T: Zeroize> Secret<T> {
+    pub fn new(secret: T) -> Self {
+        Self(secret)
+    }
+
+    pub fn expose_secret(&self) -> &T {
+        &self.0
+    }
+
+    pub fn expose_secret_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize> From<T> for Secret<T> {
+    fn from(secret: T) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl<T: Zeroize> Zeroize for Secret<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for Secret<T> {}
+
+impl<T: Zeroize> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret([REDACTED])")
+    }
+}
+
+impl<T: Zeroize + AsRef<[u8]>> PartialEq for Secret<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref().ct_eq(other.0.as_ref()).into()
+    }
+}
+
+impl<T: Zeroize + AsRef<[u8]>> Eq for Secret<T> {}
+
+#[cfg(feature = "serde")]
+impl<T: Zeroize + serde::Serialize> serde::Serialize for Secret<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Zeroize + serde::Deserialize<'de>> serde::Deserialize<'de> for Secret<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `Drop` calls `zeroize`, so this covers what's left behind when a `Secret` is dropped.
+    #[test]
+    fn test_zeroize_clears_array() {
+        let mut secret = Secret::new([0xaau8; 32]);
+        secret.zeroize();
+        assert_eq!(*secret.expose_secret(), [0u8; 32]);
+    }
+
+    #[test]
+    fn test_zeroize_clears_vec() {
+        let mut secret = Secret::new(vec![0xaau8; 32]);
+        secret.zeroize();
+        assert!(secret.expose_secret().is_empty());
+    }
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let secret = Secret::new(*b"hunter2 hunter2 hunter2 hunter2!");
+        assert_eq!(format!("{secret:?}"), "Secret([REDACTED])");
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Secret::new(vec![1, 2, 3]), Secret::new(vec![1, 2, 3]));
+        assert_ne!(Secret::new(vec![1, 2, 3]), Secret::new(vec![1, 2, 4]));
+        assert_ne!(Secret::new(vec![1, 2, 3]), Secret::new(vec![1, 2]));
+    }
+}
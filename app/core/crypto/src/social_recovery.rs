@@ -0,0 +1,649 @@
+//! Social recovery protocol shared by the protected customer (PC) and trusted contact (TC).
+//!
+//! The protocol has three phases:
+//!
+//! 1. Enrollment: the TC sends their identity public key to the PC over a SPAKE2 channel keyed by
+//!    the enrollment code, so that the server cannot substitute its own key.
+//! 2. Sealing: the PC encrypts its private key material under a random PKEK, and seals the PKEK
+//!    to each TC with an ECDH shared secret between the two identity keys.
+//! 3. Recovery: the PC starts a challenge with a new code. The TC unseals the PKEK and re-seals it
+//!    to the PC over a SPAKE2 channel keyed by that code.
+//!
+//! The PC plays the SPAKE2 Alice role and the TC plays Bob. Every message that crosses the wire
+//! has a versioned byte encoding; callers are responsible for any further (e.g. hex) encoding.
+
+use bitcoin::secp256k1::constants::PUBLIC_KEY_SIZE;
+use rand::RngCore;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+use crate::chacha20poly1305::XChaCha20Poly1305;
+use crate::ecdh::Secp256k1SharedSecret;
+use crate::hkdf::Hkdf;
+use crate::hmac::{generate_mac, verify_mac};
+use crate::keys::{PublicKey, SecretKey};
+use crate::secret::{Secret, SecretBytes};
+use crate::spake2::{
+    DefaultBackend, Spake2Confirmed, Spake2Error, Spake2KeysDerived, Spake2MessageSent, Spake2Role,
+    Spake2Start,
+};
+
+const PKMAT_AAD: &str = "Bitkey Social Recovery PKMat Encryption Version 1.0";
+const PKEK_INFO: &str = "Bitkey Social Recovery PKEK Encryption Version 1.0";
+const ENROLLMENT_AAD: &str = "Bitkey Social Recovery Enrollment Version 1.0";
+const RECOVERY_AAD: &str = "Bitkey Social Recovery Challenge Version 1.0";
+const PROTECTED_CUSTOMER_NAME: &str = "ProtectedCustomer";
+const TRUSTED_CONTACT_NAME: &str = "TrustedContact";
+
+const KEY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 24;
+
+#[derive(Debug, Error)]
+pub enum SocialRecoveryError {
+    #[error(transparent)]
+    Spake2(#[from] Spake2Error),
+    #[error("Key confirmation failed")]
+    KeyConfirmationFailed,
+    #[error("Identity key MAC verification failed")]
+    InvalidIdentityMac,
+    #[error("Invalid key")]
+    InvalidKey,
+    #[error("Failed to derive key")]
+    KeyDerivationFailed,
+    #[error("Failed to encrypt")]
+    EncryptionFailed,
+    #[error("Failed to decrypt")]
+    DecryptionFailed,
+    #[error("Unsupported protocol version: {0}")]
+    UnsupportedVersion(u8),
+    #[error("Malformed message")]
+    MalformedMessage,
+}
+
+/// Version of the wire encoding, written as the first byte of every message.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ProtocolVersion {
+    V1 = 1,
+}
+
+impl TryFrom<u8> for ProtocolVersion {
+    type Error = SocialRecoveryError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(ProtocolVersion::V1),
+            v => Err(SocialRecoveryError::UnsupportedVersion(v)),
+        }
+    }
+}
+
+/// The private key encryption key: a random symmetric key that protects the PC's private key
+/// material, and which is sealed to each TC.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Pkek(Secret<[u8; KEY_LENGTH]>);
+
+impl Pkek {
+    pub fn generate() -> Self {
+        let mut key = Secret::new([0u8; KEY_LENGTH]);
+        rand::thread_rng().fill_bytes(key.expose_secret_mut());
+        Self(key)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.expose_secret()
+    }
+}
+
+/// Generate a fresh secp256k1 key, for use as a PC or TC identity key.
+pub fn generate_identity_key() -> SecretKey {
+    loop {
+        let mut bytes = SecretBytes::new(vec![0u8; KEY_LENGTH]);
+        rand::thread_rng().fill_bytes(bytes.expose_secret_mut());
+        if let Ok(key) = SecretKey::new(bytes) {
+            return key;
+        }
+    }
+}
+
+/// XChaCha20-Poly1305 ciphertext, encoded as `version || nonce || ciphertext || tag`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SealedData {
+    nonce: [u8; NONCE_LENGTH],
+    ciphertext: Vec<u8>,
+}
+
+impl SealedData {
+    fn seal(key: &[u8], plaintext: &[u8], aad: &[u8]) -> Result<Self, SocialRecoveryError> {
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let ciphertext = XChaCha20Poly1305::new(key)
+            .encrypt(&nonce, plaintext, aad)
+            .map_err(|_| SocialRecoveryError::EncryptionFailed)?;
+
+        Ok(Self { nonce, ciphertext })
+    }
+
+    fn open(&self, key: &[u8], aad: &[u8]) -> Result<Vec<u8>, SocialRecoveryError> {
+        XChaCha20Poly1305::new(key)
+            .decrypt(&self.nonce, &self.ciphertext, aad)
+            .map_err(|_| SocialRecoveryError::DecryptionFailed)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = vec![ProtocolVersion::V1 as u8];
+        out.extend_from_slice(&self.nonce);
+        out.extend_from_slice(&self.ciphertext);
+        out
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, SocialRecoveryError> {
+        let (version, rest) = bytes
+            .split_first()
+            .ok_or(SocialRecoveryError::MalformedMessage)?;
+        ProtocolVersion::try_from(*version)?;
+        if rest.len() < NONCE_LENGTH {
+            return Err(SocialRecoveryError::MalformedMessage);
+        }
+
+        let (nonce, ciphertext) = rest.split_at(NONCE_LENGTH);
+        Ok(Self {
+            nonce: nonce.try_into().expect("nonce length checked above"),
+            ciphertext: ciphertext.to_vec(),
+        })
+    }
+}
+
+/// Sent by the PC to start enrollment. Stored by the server as `customer_enrollment_pubkey`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EnrollmentRequest {
+    pub pake_message: Vec<u8>,
+}
+
+/// Sent by the TC to complete enrollment. The server stores these as
+/// `trusted_contact_enrollment_pubkey`, `enrollment_key_confirmation`,
+/// `trusted_contact_identity_pubkey` and `trusted_contact_identity_pubkey_mac`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EnrollmentResponse {
+    pub pake_message: Vec<u8>,
+    pub key_confirmation: Vec<u8>,
+    pub identity_key: PublicKey,
+    pub identity_key_mac: Vec<u8>,
+}
+
+/// Sent by the PC to a TC to start a recovery challenge. Stored by the server as
+/// `customer_recovery_pubkey`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RecoveryRequest {
+    pub pake_message: Vec<u8>,
+}
+
+/// Sent by the TC in response to a recovery challenge. The server stores these as
+/// `trusted_contact_recovery_pubkey`, `recovery_key_confirmation` and `recovery_sealed_pkek`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RecoveryResponse {
+    pub pake_message: Vec<u8>,
+    pub key_confirmation: Vec<u8>,
+    pub sealed_pkek: SealedData,
+}
+
+impl EnrollmentRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        encode_fields(&[&self.pake_message])
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, SocialRecoveryError> {
+        let [pake_message] = decode_fields(bytes)?;
+        Ok(Self { pake_message })
+    }
+}
+
+impl EnrollmentResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        encode_fields(&[
+            &self.pake_message,
+            &self.key_confirmation,
+            &self.identity_key.serialize(),
+            &self.identity_key_mac,
+        ])
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, SocialRecoveryError> {
+        let [pake_message, key_confirmation, identity_key, identity_key_mac] =
+            decode_fields(bytes)?;
+        Ok(Self {
+            pake_message,
+            key_confirmation,
+            identity_key: PublicKey::from_slice(&identity_key)
+                .map_err(|_| SocialRecoveryError::InvalidKey)?,
+            identity_key_mac,
+        })
+    }
+}
+
+impl RecoveryRequest {
+    pub fn encode(&self) -> Vec<u8> {
+        encode_fields(&[&self.pake_message])
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, SocialRecoveryError> {
+        let [pake_message] = decode_fields(bytes)?;
+        Ok(Self { pake_message })
+    }
+}
+
+impl RecoveryResponse {
+    pub fn encode(&self) -> Vec<u8> {
+        encode_fields(&[
+            &self.pake_message,
+            &self.key_confirmation,
+            &self.sealed_pkek.encode(),
+        ])
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, SocialRecoveryError> {
+        let [pake_message, key_confirmation, sealed_pkek] = decode_fields(bytes)?;
+        Ok(Self {
+            pake_message,
+            key_confirmation,
+            sealed_pkek: SealedData::decode(&sealed_pkek)?,
+        })
+    }
+}
+
+// Messages are encoded as the version byte followed by each field, prefixed with its length as a
+// big-endian u16.
+fn encode_fields(fields: &[&[u8]]) -> Vec<u8> {
+    let mut out = vec![ProtocolVersion::V1 as u8];
+    for field in fields {
+        let len = u16::try_from(field.len()).expect("field too long to encode");
+        out.extend_from_slice(&len.to_be_bytes());
+        out.extend_from_slice(field);
+    }
+    out
+}
+
+fn decode_fields<const N: usize>(bytes: &[u8]) -> Result<[Vec<u8>; N], SocialRecoveryError> {
+    let (version, mut rest) = bytes
+        .split_first()
+        .ok_or(SocialRecoveryError::MalformedMessage)?;
+    ProtocolVersion::try_from(*version)?;
+
+    let mut fields: [Vec<u8>; N] = std::array::from_fn(|_| Vec::new());
+    for field in fields.iter_mut() {
+        if rest.len() < 2 {
+            return Err(SocialRecoveryError::MalformedMessage);
+        }
+        let (len, tail) = rest.split_at(2);
+        let len = u16::from_be_bytes([len[0], len[1]]) as usize;
+        if tail.len() < len {
+            return Err(SocialRecoveryError::MalformedMessage);
+        }
+        let (value, tail) = tail.split_at(len);
+        *field = value.to_vec();
+        rest = tail;
+    }
+
+    if !rest.is_empty() {
+        return Err(SocialRecoveryError::MalformedMessage);
+    }
+    Ok(fields)
+}
+
+fn pc_start(code: &[u8]) -> Result<(Spake2MessageSent, Vec<u8>), SocialRecoveryError> {
+    Ok(Spake2Start::<DefaultBackend>::new(
+        Spake2Role::Alice,
+        PROTECTED_CUSTOMER_NAME,
+        TRUSTED_CONTACT_NAME,
+    )?
+    .generate_msg(code)?)
+}
+
+// Runs the TC side of a SPAKE2 exchange, which completes in a single message. The TC never
+// receives a confirmation from the PC, so the exchange ends with keys derived.
+fn tc_exchange(
+    code: &[u8],
+    pc_message: &[u8],
+    aad: &str,
+) -> Result<(Vec<u8>, Vec<u8>, Spake2KeysDerived), SocialRecoveryError> {
+    let (sent, pake_message) = Spake2Start::<DefaultBackend>::new(
+        Spake2Role::Bob,
+        TRUSTED_CONTACT_NAME,
+        PROTECTED_CUSTOMER_NAME,
+    )?
+    .generate_msg(code)?;
+    let derived = sent.process_msg(pc_message, Some(aad.as_bytes()))?;
+    let key_confirmation = derived.key_conf_msg()?;
+
+    Ok((pake_message, key_confirmation, derived))
+}
+
+// Completes the PC side of a SPAKE2 exchange and checks the TC's key confirmation.
+fn pc_complete(
+    sent: Spake2MessageSent,
+    tc_message: &[u8],
+    key_confirmation: &[u8],
+    aad: &str,
+) -> Result<Spake2Confirmed, SocialRecoveryError> {
+    sent.process_msg(tc_message, Some(aad.as_bytes()))?
+        .confirm(key_confirmation)
+        .map_err(|_| SocialRecoveryError::KeyConfirmationFailed)
+}
+
+/// The PC's side of an in-flight enrollment. It holds the SPAKE2 state, so it must be kept until
+/// the TC's [`EnrollmentResponse`] arrives.
+pub struct ProtectedCustomerEnrollment {
+    sent: Spake2MessageSent,
+}
+
+impl ProtectedCustomerEnrollment {
+    pub fn start(code: &[u8]) -> Result<(Self, EnrollmentRequest), SocialRecoveryError> {
+        let (sent, pake_message) = pc_start(code)?;
+
+        Ok((Self { sent }, EnrollmentRequest { pake_message }))
+    }
+
+    /// Verify the TC's key confirmation and identity key MAC, returning the authenticated TC
+    /// identity key.
+    pub fn finish(self, response: &EnrollmentResponse) -> Result<PublicKey, SocialRecoveryError> {
+        let keys = pc_complete(
+            self.sent,
+            &response.pake_message,
+            &response.key_confirmation,
+            ENROLLMENT_AAD,
+        )?;
+        verify_mac(
+            keys.keys().bob_encryption_key.expose_secret(),
+            &response.identity_key.serialize(),
+            &response.identity_key_mac,
+        )
+        .map_err(|_| SocialRecoveryError::InvalidIdentityMac)?;
+
+        Ok(response.identity_key)
+    }
+}
+
+/// TC: accept an enrollment with the code shared out of band by the PC.
+pub fn accept_enrollment(
+    code: &[u8],
+    request: &EnrollmentRequest,
+    tc_identity_key: &PublicKey,
+) -> Result<EnrollmentResponse, SocialRecoveryError> {
+    let (pake_message, key_confirmation, keys) =
+        tc_exchange(code, &request.pake_message, ENROLLMENT_AAD)?;
+    let identity_key_mac = generate_mac(
+        keys.keys().bob_encryption_key.expose_secret(),
+        &tc_identity_key.serialize(),
+    )
+    .map_err(|_| SocialRecoveryError::KeyDerivationFailed)?;
+
+    Ok(EnrollmentResponse {
+        pake_message,
+        key_confirmation,
+        identity_key: *tc_identity_key,
+        identity_key_mac,
+    })
+}
+
+/// PC: encrypt private key material under a fresh PKEK.
+pub fn seal_private_key_material(
+    material: &[u8],
+) -> Result<(Pkek, SealedData), SocialRecoveryError> {
+    let pkek = Pkek::generate();
+    let sealed = SealedData::seal(pkek.as_bytes(), material, PKMAT_AAD.as_bytes())?;
+    Ok((pkek, sealed))
+}
+
+/// PC: decrypt private key material with a PKEK recovered from a TC.
+pub fn unseal_private_key_material(
+    pkek: &Pkek,
+    sealed: &SealedData,
+) -> Result<Vec<u8>, SocialRecoveryError> {
+    sealed.open(pkek.as_bytes(), PKMAT_AAD.as_bytes())
+}
+
+// Both parties derive the same key from the ECDH shared secret of their identity keys.
+fn identity_shared_key(
+    our_secret: &SecretKey,
+    their_public: &PublicKey,
+) -> Result<Zeroizing<Vec<u8>>, SocialRecoveryError> {
+    let shared_secret = Secp256k1SharedSecret::new(their_public, our_secret);
+    Hkdf::new(&[], &Zeroizing::new(shared_secret.secret_bytes()))
+        .expand(PKEK_INFO.as_bytes(), KEY_LENGTH as i32)
+        .map(Zeroizing::new)
+        .map_err(|_| SocialRecoveryError::KeyDerivationFailed)
+}
+
+// Ciphertexts of the PKEK are bound to both identity keys.
+fn identity_aad(pc_public: &PublicKey, tc_public: &PublicKey) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(PUBLIC_KEY_SIZE * 2);
+    aad.extend_from_slice(&pc_public.serialize());
+    aad.extend_from_slice(&tc_public.serialize());
+    aad
+}
+
+/// PC: seal the PKEK to an enrolled TC. Stored by the server as `enrollment_sealed_pkek`.
+pub fn seal_pkek(
+    pc_identity_key: &SecretKey,
+    tc_identity_key: &PublicKey,
+    pkek: &Pkek,
+) -> Result<SealedData, SocialRecoveryError> {
+    let key = identity_shared_key(pc_identity_key, tc_identity_key)?;
+    let aad = identity_aad(&pc_identity_key.as_public(), tc_identity_key);
+    SealedData::seal(&key, pkek.as_bytes(), &aad)
+}
+
+/// The PC's side of an in-flight recovery challenge with a single TC.
+pub struct ProtectedCustomerRecovery {
+    sent: Spake2MessageSent,
+}
+
+impl ProtectedCustomerRecovery {
+    pub fn start(code: &[u8]) -> Result<(Self, RecoveryRequest), SocialRecoveryError> {
+        let (sent, pake_message) = pc_start(code)?;
+
+        Ok((Self { sent }, RecoveryRequest { pake_message }))
+    }
+
+    /// Verify the TC's key confirmation and decrypt the PKEK they re-sealed to us.
+    pub fn finish(
+        self,
+        response: &RecoveryResponse,
+        pc_identity_key: &PublicKey,
+        tc_identity_key: &PublicKey,
+    ) -> Result<Pkek, SocialRecoveryError> {
+        let keys = pc_complete(
+            self.sent,
+            &response.pake_message,
+            &response.key_confirmation,
+            RECOVERY_AAD,
+        )?;
+        let pkek = Zeroizing::new(response.sealed_pkek.open(
+            keys.keys().bob_encryption_key.expose_secret(),
+            &identity_aad(pc_identity_key, tc_identity_key),
+        )?);
+        if pkek.len() != KEY_LENGTH {
+            return Err(SocialRecoveryError::InvalidKey);
+        }
+
+        let mut key = Secret::new([0u8; KEY_LENGTH]);
+        key.expose_secret_mut().copy_from_slice(&pkek);
+        Ok(Pkek(key))
+    }
+}
+
+/// TC: respond to a recovery challenge by unsealing the PKEK with our identity key and
+/// re-sealing it over a SPAKE2 channel keyed by the challenge code.
+pub fn respond_to_recovery(
+    code: &[u8],
+    request: &RecoveryRequest,
+    tc_identity_key: &SecretKey,
+    pc_identity_key: &PublicKey,
+    enrollment_sealed_pkek: &SealedData,
+) -> Result<RecoveryResponse, SocialRecoveryError> {
+    let key = identity_shared_key(tc_identity_key, pc_identity_key)?;
+    let aad = identity_aad(pc_identity_key, &tc_identity_key.as_public());
+    let pkek = Zeroizing::new(enrollment_sealed_pkek.open(&key, &aad)?);
+
+    let (pake_message, key_confirmation, keys) =
+        tc_exchange(code, &request.pake_message, RECOVERY_AAD)?;
+    let sealed_pkek =
+        SealedData::seal(keys.keys().bob_encryption_key.expose_secret(), &pkek, &aad)?;
+
+    Ok(RecoveryResponse {
+        pake_message,
+        key_confirmation,
+        sealed_pkek,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENROLLMENT_CODE: &[u8] = b"123456";
+    const RECOVERY_CODE: &[u8] = b"654321";
+
+    fn enroll(
+        pc_code: &[u8],
+        tc_code: &[u8],
+        tc_identity: &PublicKey,
+    ) -> Result<PublicKey, SocialRecoveryError> {
+        let (enrollment, request) = ProtectedCustomerEnrollment::start(pc_code)?;
+        let request = EnrollmentRequest::decode(&request.encode())?;
+        let response = accept_enrollment(tc_code, &request, tc_identity)?;
+        enrollment.finish(&EnrollmentResponse::decode(&response.encode())?)
+    }
+
+    #[test]
+    fn end_to_end() {
+        let pc_identity = generate_identity_key();
+        let tc_identity = generate_identity_key();
+
+        // Enrollment
+        let enrolled_tc =
+            enroll(ENROLLMENT_CODE, ENROLLMENT_CODE, &tc_identity.as_public()).unwrap();
+        assert_eq!(enrolled_tc, tc_identity.as_public());
+
+        // Sealing
+        let material = b"private key material";
+        let (pkek, sealed_material) = seal_private_key_material(material).unwrap();
+        let enrollment_sealed_pkek = seal_pkek(&pc_identity, &enrolled_tc, &pkek).unwrap();
+
+        // Recovery
+        let (recovery, request) = ProtectedCustomerRecovery::start(RECOVERY_CODE).unwrap();
+        let response = respond_to_recovery(
+            RECOVERY_CODE,
+            &RecoveryRequest::decode(&request.encode()).unwrap(),
+            &tc_identity,
+            &pc_identity.as_public(),
+            &SealedData::decode(&enrollment_sealed_pkek.encode()).unwrap(),
+        )
+        .unwrap();
+        let recovered_pkek = recovery
+            .finish(
+                &RecoveryResponse::decode(&response.encode()).unwrap(),
+                &pc_identity.as_public(),
+                &enrolled_tc,
+            )
+            .unwrap();
+
+        assert_eq!(
+            unseal_private_key_material(&recovered_pkek, &sealed_material).unwrap(),
+            material
+        );
+    }
+
+    #[test]
+    fn enrollment_with_wrong_code() {
+        let tc_identity = generate_identity_key();
+
+        assert!(matches!(
+            enroll(ENROLLMENT_CODE, b"000000", &tc_identity.as_public()),
+            Err(SocialRecoveryError::KeyConfirmationFailed)
+        ));
+    }
+
+    #[test]
+    fn enrollment_with_substituted_identity_key() {
+        let tc_identity = generate_identity_key();
+        let (enrollment, request) = ProtectedCustomerEnrollment::start(ENROLLMENT_CODE).unwrap();
+        let mut response =
+            accept_enrollment(ENROLLMENT_CODE, &request, &tc_identity.as_public()).unwrap();
+        response.identity_key = generate_identity_key().as_public();
+
+        assert!(matches!(
+            enrollment.finish(&response),
+            Err(SocialRecoveryError::InvalidIdentityMac)
+        ));
+    }
+
+    #[test]
+    fn recovery_with_wrong_code() {
+        let pc_identity = generate_identity_key();
+        let tc_identity = generate_identity_key();
+        let sealed_pkek =
+            seal_pkek(&pc_identity, &tc_identity.as_public(), &Pkek::generate()).unwrap();
+
+        let (recovery, request) = ProtectedCustomerRecovery::start(RECOVERY_CODE).unwrap();
+        let response = respond_to_recovery(
+            b"000000",
+            &request,
+            &tc_identity,
+            &pc_identity.as_public(),
+            &sealed_pkek,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            recovery.finish(
+                &response,
+                &pc_identity.as_public(),
+                &tc_identity.as_public()
+            ),
+            Err(SocialRecoveryError::KeyConfirmationFailed)
+        ));
+    }
+
+    #[test]
+    fn recovery_by_wrong_trusted_contact() {
+        let pc_identity = generate_identity_key();
+        let tc_identity = generate_identity_key();
+        let sealed_pkek =
+            seal_pkek(&pc_identity, &tc_identity.as_public(), &Pkek::generate()).unwrap();
+
+        let (_, request) = ProtectedCustomerRecovery::start(RECOVERY_CODE).unwrap();
+        assert!(matches!(
+            respond_to_recovery(
+                RECOVERY_CODE,
+                &request,
+                &generate_identity_key(),
+                &pc_identity.as_public(),
+                &sealed_pkek,
+            ),
+            Err(SocialRecoveryError::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn decode_rejects_unknown_version_and_truncation() {
+        let request = EnrollmentRequest {
+            pake_message: vec![1, 2, 3],
+        };
+        let mut encoded = request.encode();
+        assert_eq!(EnrollmentRequest::decode(&encoded).unwrap(), request);
+
+        assert!(matches!(
+            EnrollmentRequest::decode(&encoded[..encoded.len() - 1]),
+            Err(SocialRecoveryError::MalformedMessage)
+        ));
+        encoded[0] = 2;
+        assert!(matches!(
+            EnrollmentRequest::decode(&encoded),
+            Err(SocialRecoveryError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            SealedData::decode(&[1, 0, 0]),
+            Err(SocialRecoveryError::MalformedMessage)
+        ));
+    }
+}
@@ -0,0 +1,168 @@
+extern crate boring_sys;
+use boring_sys::*;
+use zeroize::Zeroizing;
+
+use super::{
+    append_transcript_field, sealed, SharedSecret, Spake2Backend, Spake2Error, Spake2Role,
+};
+
+impl From<spake2_role_t> for Spake2Role {
+    fn from(role: spake2_role_t) -> Self {
+        match role {
+            spake2_role_t::spake2_role_alice => Spake2Role::Alice,
+            spake2_role_t::spake2_role_bob => Spake2Role::Bob,
+            _ => panic!("Invalid role"),
+        }
+    }
+}
+
+impl From<Spake2Role> for spake2_role_t {
+    fn from(val: Spake2Role) -> Self {
+        match val {
+            Spake2Role::Alice => spake2_role_t::spake2_role_alice,
+            Spake2Role::Bob => spake2_role_t::spake2_role_bob,
+        }
+    }
+}
+
+/// SPAKE2 over Ed25519, backed by BoringSSL's SPAKE2_CTX.
+///
+/// BoringSSL hashes the full protocol transcript into the key material itself but does not expose
+/// it, so the transcript used for key confirmation is rebuilt from both identities and messages.
+pub struct BoringSslBackend {
+    ctx: *mut SPAKE2_CTX,
+    role: Spake2Role,
+    my_name: Vec<u8>,
+    their_name: Vec<u8>,
+}
+
+// The context is exclusively owned and only ever accessed through `&mut self` or `self`.
+unsafe impl Send for BoringSslBackend {}
+
+impl sealed::Sealed for BoringSslBackend {}
+
+impl Spake2Backend for BoringSslBackend {
+    /// BoringSSL documentation for SPAKE2_CTX_new is repeated below:
+    ///
+    /// SPAKE2_CTX_new creates a new |SPAKE2_CTX| (which can only be used for a
+    /// single execution of the protocol). SPAKE2 requires the symmetry of the two
+    /// parties to be broken which is indicated via |my_role| – each party must pass
+    /// a different value for this argument.
+    ///
+    /// The |my_name| and |their_name| arguments allow optional, opaque names to be
+    /// bound into the protocol. For example MAC addresses, hostnames, usernames
+    /// etc. These values are not exposed and can avoid context-confusion attacks
+    /// when a password is shared between several devices.
+    fn new(role: Spake2Role, my_name: &[u8], their_name: &[u8]) -> Result<Self, Spake2Error> {
+        unsafe {
+            // CRYPTO_library_init initializes the crypto library.
+            // It must be called if the library is built with BORINGSSL_NO_STATIC_INITIALIZER.
+            // Otherwise, it does nothing and a static initializer is used instead.
+            // It is safe to call this function multiple times and concurrently from multiple threads.
+            // On some ARM configurations, this function may require filesystem access and should be called before entering a sandbox.
+            boring_sys::CRYPTO_library_init();
+        }
+
+        let ctx = unsafe {
+            SPAKE2_CTX_new(
+                role.into(),
+                my_name.as_ptr(),
+                my_name.len(),
+                their_name.as_ptr(),
+                their_name.len(),
+            )
+        };
+
+        if ctx.is_null() {
+            Err(Spake2Error::ContextCreationError)
+        } else {
+            Ok(Self {
+                ctx,
+                role,
+                my_name: my_name.to_vec(),
+                their_name: their_name.to_vec(),
+            })
+        }
+    }
+
+    fn generate_msg(&mut self, password: &[u8]) -> Result<Vec<u8>, Spake2Error> {
+        let mut out = vec![0u8; boring_sys::SPAKE2_MAX_MSG_SIZE as usize];
+        let mut out_len = 0;
+
+        let result = unsafe {
+            SPAKE2_generate_msg(
+                self.ctx,
+                out.as_mut_ptr(),
+                &mut out_len,
+                boring_sys::SPAKE2_MAX_MSG_SIZE as usize,
+                password.as_ptr(),
+                password.len(),
+            )
+        };
+
+        if result == 0 {
+            Err(Spake2Error::GenerateMessageError)
+        } else {
+            out.truncate(out_len);
+            Ok(out)
+        }
+    }
+
+    /// BoringSSL's documentation for SPAKE2_process_msg is repeated below:
+    ///
+    /// SPAKE2_process_msg completes the SPAKE2 exchange given the peer's message in
+    /// |their_msg|, writes at most |max_out_key_len| bytes to |out_key| and sets
+    /// |*out_key_len| to the number of bytes written.
+    ///
+    /// If |max_out_key_key| is smaller than the amount of key material generated
+    /// then the key is silently truncated. If you want to ensure that no truncation
+    /// occurs then |max_out_key| should be at least |SPAKE2_MAX_KEY_SIZE|.
+    ///
+    /// You must call |SPAKE2_generate_msg| on a given |SPAKE2_CTX| before calling
+    /// this function. On successful return, |ctx| is complete and calling
+    /// |SPAKE2_CTX_free| is the only acceptable operation on it.
+    ///
+    /// Returns one on success or zero on error.
+    fn process_msg(self, my_msg: &[u8], their_msg: &[u8]) -> Result<SharedSecret, Spake2Error> {
+        let mut key_material = Zeroizing::new(vec![0u8; boring_sys::SPAKE2_MAX_KEY_SIZE as usize]);
+        let mut key_material_len = 0;
+
+        let result = unsafe {
+            SPAKE2_process_msg(
+                self.ctx,
+                key_material.as_mut_ptr(),
+                &mut key_material_len,
+                boring_sys::SPAKE2_MAX_KEY_SIZE as usize,
+                their_msg.as_ptr(),
+                their_msg.len(),
+            )
+        };
+
+        if result == 0 {
+            return Err(Spake2Error::ProcessMessageError);
+        }
+        key_material.truncate(key_material_len);
+
+        let (alice_name, bob_name, alice_msg, bob_msg) = match self.role {
+            Spake2Role::Alice => (&self.my_name, &self.their_name, my_msg, their_msg),
+            Spake2Role::Bob => (&self.their_name, &self.my_name, their_msg, my_msg),
+        };
+        let mut transcript = Zeroizing::new(Vec::new());
+        for field in [alice_name.as_slice(), bob_name, alice_msg, bob_msg] {
+            append_transcript_field(&mut transcript, field);
+        }
+
+        Ok(SharedSecret {
+            key_material,
+            transcript,
+        })
+    }
+}
+
+impl Drop for BoringSslBackend {
+    fn drop(&mut self) {
+        unsafe {
+            SPAKE2_CTX_free(self.ctx);
+        }
+    }
+}
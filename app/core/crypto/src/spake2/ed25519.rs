@@ -0,0 +1,216 @@
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar;
+use rand::RngCore;
+use sha2::{Digest, Sha512};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::{
+    append_transcript_field, sealed, SharedSecret, Spake2Backend, Spake2Error, Spake2Role,
+};
+
+// BoringSSL's blinding points, found by repeatedly hashing "edwards25519 point generation seed (M)"
+// (or "(N)") with SHA-256 until the digest decodes as a point. They are not in the prime-order
+// subgroup; see `mask`.
+const M: [u8; 32] = [
+    0x5a, 0xda, 0x7e, 0x4b, 0xf6, 0xdd, 0xd9, 0xad, 0xb6, 0x62, 0x6d, 0x32, 0x13, 0x1c, 0x6b, 0x5c,
+    0x51, 0xa1, 0xe3, 0x47, 0xa3, 0x47, 0x8f, 0x53, 0xcf, 0xcf, 0x44, 0x1b, 0x88, 0xee, 0xd1, 0x2e,
+];
+const N: [u8; 32] = [
+    0x10, 0xe3, 0xdf, 0x0a, 0xe3, 0x7d, 0x8e, 0x7a, 0x99, 0xb5, 0xfe, 0x74, 0xb4, 0x46, 0x72, 0x10,
+    0x3d, 0xbd, 0xdc, 0xbd, 0x06, 0xaf, 0x68, 0x0d, 0x71, 0x32, 0x9a, 0x11, 0x69, 0x3b, 0xc7, 0x78,
+];
+
+// The low byte of the group order l.
+const ORDER_LOW_BYTE: u8 = 0xed;
+
+/// Pure-Rust SPAKE2 over Ed25519, producing the same messages and key material as BoringSSL's
+/// `SPAKE2_*` functions, so it interoperates with [`super::BoringSslBackend`].
+pub struct Ed25519Backend {
+    role: Spake2Role,
+    my_name: Vec<u8>,
+    their_name: Vec<u8>,
+    // Our ephemeral scalar x (the private key is 8x) and SHA-512 of the password.
+    secret: Option<(Scalar, Zeroizing<[u8; 64]>)>,
+}
+
+impl sealed::Sealed for Ed25519Backend {}
+
+fn decode_point(bytes: &[u8]) -> Option<EdwardsPoint> {
+    CompressedEdwardsY::from_slice(bytes).ok()?.decompress()
+}
+
+// BoringSSL multiplies the blinding point by the password hash reduced mod l, plus whichever of l,
+// 2l and 4l make the scalar a multiple of eight. Adding k·l to the scalar adds k times the
+// small-order component of the point (l·point), which cancels out against the peer's cofactor-
+// cleared private key. The adjustment is computed without branching on the password.
+fn mask(point: &EdwardsPoint, password_hash: &[u8; 64]) -> EdwardsPoint {
+    let w = Zeroizing::new(Scalar::from_bytes_mod_order_wide(password_hash));
+    let mut low = w.as_bytes()[0];
+    let mut k = 0u8;
+    for bit in [1u8, 2, 4] {
+        let add = low & bit;
+        k |= add;
+        low = low.wrapping_add(ORDER_LOW_BYTE.wrapping_mul(add));
+    }
+
+    // l·P, computed as (l - 1)·P + P since l itself doesn't fit in a `Scalar`.
+    let torsion = point * -Scalar::ONE + point;
+    point * *w + torsion * Scalar::from(k)
+}
+
+impl Ed25519Backend {
+    fn blinding_points(&self) -> (EdwardsPoint, EdwardsPoint) {
+        let m = decode_point(&M).expect("M is a valid point");
+        let n = decode_point(&N).expect("N is a valid point");
+        match self.role {
+            Spake2Role::Alice => (m, n),
+            Spake2Role::Bob => (n, m),
+        }
+    }
+
+    // Split out from `generate_msg` so that tests can supply BoringSSL's random bytes directly.
+    fn message(&mut self, password: &[u8], random: &[u8; 64]) -> Vec<u8> {
+        let (my_blind, _) = self.blinding_points();
+        let x = Scalar::from_bytes_mod_order_wide(random);
+        let mut password_hash = Zeroizing::new([0u8; 64]);
+        password_hash.copy_from_slice(&Sha512::digest(password));
+
+        let msg = (EdwardsPoint::mul_base(&x).mul_by_cofactor() + mask(&my_blind, &password_hash))
+            .compress()
+            .to_bytes()
+            .to_vec();
+        self.secret = Some((x, password_hash));
+        msg
+    }
+}
+
+impl Spake2Backend for Ed25519Backend {
+    fn new(role: Spake2Role, my_name: &[u8], their_name: &[u8]) -> Result<Self, Spake2Error> {
+        Ok(Self {
+            role,
+            my_name: my_name.to_vec(),
+            their_name: their_name.to_vec(),
+            secret: None,
+        })
+    }
+
+    fn generate_msg(&mut self, password: &[u8]) -> Result<Vec<u8>, Spake2Error> {
+        if self.secret.is_some() {
+            return Err(Spake2Error::GenerateMessageError);
+        }
+
+        let mut random = Zeroizing::new([0u8; 64]);
+        rand::thread_rng().fill_bytes(random.as_mut());
+        Ok(self.message(password, &random))
+    }
+
+    fn process_msg(mut self, my_msg: &[u8], their_msg: &[u8]) -> Result<SharedSecret, Spake2Error> {
+        let (x, password_hash) = self.secret.take().ok_or(Spake2Error::ProcessMessageError)?;
+        let (_, their_blind) = self.blinding_points();
+        if their_msg.len() != 32 {
+            return Err(Spake2Error::ProcessMessageError);
+        }
+        let their_point = decode_point(their_msg).ok_or(Spake2Error::ProcessMessageError)?;
+
+        // K = 8x·(Y* - w·N), as BoringSSL's private key is the ephemeral scalar times the cofactor.
+        let k = ((their_point - mask(&their_blind, &password_hash)) * x).mul_by_cofactor();
+        let k = Zeroizing::new(k.compress().to_bytes());
+
+        let (a, b, pa, pb) = match self.role {
+            Spake2Role::Alice => (&self.my_name, &self.their_name, my_msg, their_msg),
+            Spake2Role::Bob => (&self.their_name, &self.my_name, their_msg, my_msg),
+        };
+
+        // Key material = SHA-512 over the same length-prefixed fields as the P-256 backend, with the
+        // full password hash in place of w.
+        let mut hashed = Zeroizing::new(Vec::new());
+        for field in [
+            a.as_slice(),
+            b,
+            pa,
+            pb,
+            k.as_slice(),
+            password_hash.as_slice(),
+        ] {
+            append_transcript_field(&mut hashed, field);
+        }
+        let key_material = Zeroizing::new(Sha512::digest(hashed.as_slice()).to_vec());
+
+        // The confirmation transcript matches the BoringSSL backend's.
+        let mut transcript = Zeroizing::new(Vec::new());
+        for field in [a.as_slice(), b, pa, pb] {
+            append_transcript_field(&mut transcript, field);
+        }
+
+        let mut x = x;
+        x.zeroize();
+        Ok(SharedSecret {
+            key_material,
+            transcript,
+        })
+    }
+}
+
+impl Drop for Ed25519Backend {
+    fn drop(&mut self) {
+        if let Some((x, _)) = self.secret.as_mut() {
+            x.zeroize();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn random(first: u8, step: fn(u8, u8) -> u8) -> [u8; 64] {
+        let mut random = [0u8; 64];
+        for (i, byte) in random.iter_mut().enumerate() {
+            *byte = step(first, i as u8);
+        }
+        random
+    }
+
+    // Generated by BoringSSL's SPAKE2_generate_msg and SPAKE2_process_msg, with RAND_bytes
+    // returning the given bytes.
+    #[test]
+    fn test_matches_boringssl() {
+        let mut alice = Ed25519Backend::new(Spake2Role::Alice, b"alice", b"bob").unwrap();
+        let mut bob = Ed25519Backend::new(Spake2Role::Bob, b"bob", b"alice").unwrap();
+        let alice_msg = alice.message(b"password", &random(1, u8::wrapping_add));
+        let bob_msg = bob.message(b"password", &random(0xff, u8::wrapping_sub));
+        assert_eq!(
+            hex::encode(&alice_msg),
+            "8fdd6f667cd4ef70ed5a312fdcebee7fd00d7d094d7cab14353e6d637dab9841"
+        );
+        assert_eq!(
+            hex::encode(&bob_msg),
+            "17529bb064d5ff7543161f268e9cdfd0b627eaae2d1284ec30b056691b6bd020"
+        );
+
+        let expected_key = "e725a5ccdb1f7089ebe0eb7bdc28dab4e56168e4a0807731dee490c395cafe18\
+                            a3e8e182bf8b903c613bb8c5cd57ae0e98f4b8e556c113bc3e4dd4b7fe071dbf";
+        let alice_secret = alice.process_msg(&alice_msg, &bob_msg).unwrap();
+        let bob_secret = bob.process_msg(&bob_msg, &alice_msg).unwrap();
+        assert_eq!(
+            hex::encode(alice_secret.key_material.as_slice()),
+            expected_key
+        );
+        assert_eq!(
+            hex::encode(bob_secret.key_material.as_slice()),
+            expected_key
+        );
+        assert_eq!(alice_secret.transcript, bob_secret.transcript);
+    }
+
+    #[test]
+    fn test_rejects_truncated_message() {
+        let mut alice = Ed25519Backend::new(Spake2Role::Alice, b"alice", b"bob").unwrap();
+        let alice_msg = alice.generate_msg(b"password").unwrap();
+
+        assert!(matches!(
+            alice.process_msg(&alice_msg, &alice_msg[..31]),
+            Err(Spake2Error::ProcessMessageError)
+        ));
+    }
+}
@@ -0,0 +1,802 @@
+//! SPAKE2 with key confirmation, as a typed state machine:
+//!
+//! [`Spake2Start`] → [`Spake2MessageSent`] → [`Spake2KeysDerived`] → [`Spake2Confirmed`]
+//!
+//! Each transition consumes the previous state, so out-of-order calls do not compile. The session
+//! key is only handed out once the peer's confirmation has been verified.
+//!
+//! What the confirmation MAC covers is versioned by [`Spake2ConfirmationVersion`]. V2, the
+//! default, MACs the protocol transcript (both identities and both messages); V1 MACs a fixed
+//! label and is only for pairing with apps that predate V2. Both parties must use the same
+//! version.
+//!
+//! Three backends are available: [`BoringSslBackend`] (the default, `boringssl` feature), and with
+//! the `pure-rust` feature, [`Ed25519Backend`], which interoperates with BoringSSL and is the
+//! default for builds without boring-sys, and [`P256Backend`], which follows RFC 9382 over P-256
+//! and only interoperates with itself.
+//!
+//! [`Spake2Context`] wraps the same state machine behind `&self` methods with checked
+//! transitions, for use across UniFFI.
+
+#[cfg(feature = "boringssl")]
+mod boringssl;
+#[cfg(feature = "pure-rust")]
+mod ed25519;
+#[cfg(feature = "pure-rust")]
+mod rfc9382;
+
+use std::sync::Mutex;
+
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+use crate::hkdf::Hkdf;
+use crate::hmac::{generate_mac, verify_mac};
+use crate::secret::SecretBytes;
+
+#[cfg(feature = "boringssl")]
+pub use boringssl::BoringSslBackend;
+#[cfg(feature = "pure-rust")]
+pub use ed25519::Ed25519Backend;
+#[cfg(feature = "pure-rust")]
+pub use rfc9382::P256Backend;
+
+#[cfg(feature = "boringssl")]
+pub type DefaultBackend = BoringSslBackend;
+#[cfg(all(feature = "pure-rust", not(feature = "boringssl")))]
+pub type DefaultBackend = Ed25519Backend;
+#[cfg(not(any(feature = "boringssl", feature = "pure-rust")))]
+compile_error!("either the `boringssl` or the `pure-rust` feature must be enabled");
+
+#[derive(Debug, PartialEq)]
+pub struct Spake2Keys {
+    pub alice_encryption_key: SecretBytes,
+    pub bob_encryption_key: SecretBytes,
+    pub alice_conf_key: SecretBytes,
+    pub bob_conf_key: SecretBytes,
+}
+
+impl Spake2Keys {
+    // `Spake2Keys` is deliberately not `Clone`; this is only used to hand a copy across the FFI,
+    // which can't borrow from the context.
+    fn copy(&self) -> Self {
+        let copy = |key: &SecretBytes| SecretBytes::new(key.expose_secret().clone());
+        Self {
+            alice_encryption_key: copy(&self.alice_encryption_key),
+            bob_encryption_key: copy(&self.bob_encryption_key),
+            alice_conf_key: copy(&self.alice_conf_key),
+            bob_conf_key: copy(&self.bob_conf_key),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Spake2Role {
+    Alice,
+    Bob,
+}
+
+/// What the key confirmation messages MAC. Both parties must use the same version.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum Spake2ConfirmationVersion {
+    /// A fixed label, as sent by apps that predate V2. Only use this to pair with those apps; it
+    /// doesn't bind the confirmation to the transcript.
+    V1,
+    /// The protocol transcript: both identities and both messages.
+    #[default]
+    V2,
+}
+
+const CONFIRMATION_KEYS_INFO: &str = "ConfirmationKeys";
+const CONFIRMATION_LABEL: &str = "SocRecKeyConfirmationV1";
+const ENCRYPTION_KEYS_INFO: &str = "EncryptionKeys";
+const ENCRYPTION_KEY_LENGTH: usize = 32;
+const KCA_KCB_LENGTH: usize = 16;
+const SESSION_KEY_INFO: &str = "SessionKey";
+const SESSION_KEY_LENGTH: usize = 32;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The output of a completed SPAKE2 exchange, before key derivation.
+pub struct SharedSecret {
+    /// Ke || Ka
+    key_material: Zeroizing<Vec<u8>>,
+    /// Everything both parties agreed on, MACed by the key confirmation messages.
+    transcript: Zeroizing<Vec<u8>>,
+}
+
+/// A SPAKE2 implementation. Sealed; see [`BoringSslBackend`], [`Ed25519Backend`] and
+/// [`P256Backend`].
+pub trait Spake2Backend: sealed::Sealed + Send + Sized {
+    fn new(role: Spake2Role, my_name: &[u8], their_name: &[u8]) -> Result<Self, Spake2Error>;
+
+    /// Generate our message for `password`. Must be called exactly once.
+    fn generate_msg(&mut self, password: &[u8]) -> Result<Vec<u8>, Spake2Error>;
+
+    /// Complete the exchange with the peer's message.
+    fn process_msg(self, my_msg: &[u8], their_msg: &[u8]) -> Result<SharedSecret, Spake2Error>;
+}
+
+// Transcript fields are prefixed with their length as a little-endian u64, as in RFC 9382.
+fn append_transcript_field(transcript: &mut Vec<u8>, field: &[u8]) {
+    transcript.extend_from_slice(&(field.len() as u64).to_le_bytes());
+    transcript.extend_from_slice(field);
+}
+
+// https://datatracker.ietf.org/doc/rfc9382/
+// Section 4
+fn derive_confirmation_keys(
+    ka: &[u8],
+    aad: Option<&[u8]>,
+) -> Result<(SecretBytes, SecretBytes), Spake2Error> {
+    let mut info = Vec::from(CONFIRMATION_KEYS_INFO.as_bytes());
+    if let Some(additional_data) = aad {
+        info.extend_from_slice(additional_data);
+    }
+
+    // Per RFC9382:
+    // AAD -> KDF(Ka, nil, "ConfirmationKeys" || AAD) = KcA || KcB
+    let hkdf = Hkdf::new(&[], ka);
+    let okm = Zeroizing::new(
+        hkdf.expand(&info, (KCA_KCB_LENGTH * 2) as i32)
+            .map_err(|_| Spake2Error::HkdfError)?,
+    );
+
+    let kca = okm[..KCA_KCB_LENGTH].to_vec().into();
+    let kcb = okm[KCA_KCB_LENGTH..].to_vec().into();
+
+    Ok((kca, kcb))
+}
+
+fn derive_encryption_keys(ke: &[u8]) -> Result<(SecretBytes, SecretBytes), Spake2Error> {
+    let hkdf = Hkdf::new(&[], ke);
+    let info = Vec::from(ENCRYPTION_KEYS_INFO.as_bytes());
+    let ke_okm = Zeroizing::new(
+        hkdf.expand(&info, (ENCRYPTION_KEY_LENGTH * 2) as i32)
+            .map_err(|_| Spake2Error::HkdfError)?,
+    );
+
+    let kea = ke_okm[..ENCRYPTION_KEY_LENGTH].to_vec().into();
+    let keb = ke_okm[ENCRYPTION_KEY_LENGTH..].to_vec().into();
+
+    Ok((kea, keb))
+}
+
+// The exported session key is derived from Ke rather than being Ke itself, so that it's
+// independent of the encryption keys derived from it.
+fn derive_session_key(ke: &[u8]) -> Result<Spake2SessionKey, Spake2Error> {
+    let hkdf = Hkdf::new(&[], ke);
+    let okm = hkdf
+        .expand(SESSION_KEY_INFO.as_bytes(), SESSION_KEY_LENGTH as i32)
+        .map_err(|_| Spake2Error::HkdfError)?;
+    Ok(Spake2SessionKey(okm.into()))
+}
+
+fn derive_keys(key_material: &[u8], aad: Option<&[u8]>) -> Result<Spake2Keys, Spake2Error> {
+    if key_material.is_empty() || key_material.len() % 2 != 0 {
+        return Err(Spake2Error::LengthError);
+    }
+
+    // ke is the first half of input key material, and ka is the second half
+    let (ke, ka) = key_material.split_at(key_material.len() / 2);
+
+    // Derive confirmation keys (KcA, KcB) from Ka
+    let (kca, kcb) = derive_confirmation_keys(ka, aad)?;
+
+    // Derive encryption keys (KEa, KEb) from KE
+    let (kea, keb) = derive_encryption_keys(ke)?;
+
+    Ok(Spake2Keys {
+        alice_encryption_key: kea,
+        bob_encryption_key: keb,
+        alice_conf_key: kca,
+        bob_conf_key: kcb,
+    })
+}
+
+/// The session key produced by a confirmed exchange, derived from Ke.
+#[derive(Debug, PartialEq)]
+pub struct Spake2SessionKey(SecretBytes);
+
+impl Spake2SessionKey {
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.expose_secret()
+    }
+}
+
+pub struct Spake2Start<B: Spake2Backend = DefaultBackend> {
+    backend: B,
+    role: Spake2Role,
+    version: Spake2ConfirmationVersion,
+}
+
+impl<B: Spake2Backend> Spake2Start<B> {
+    /// `my_name` and `their_name` are opaque identities bound into the protocol and the
+    /// confirmation transcript, to avoid context-confusion when a password is shared.
+    pub fn new(role: Spake2Role, my_name: &str, their_name: &str) -> Result<Self, Spake2Error> {
+        Ok(Self {
+            backend: B::new(role, my_name.as_bytes(), their_name.as_bytes())?,
+            role,
+            version: Spake2ConfirmationVersion::default(),
+        })
+    }
+
+    pub fn with_confirmation_version(mut self, version: Spake2ConfirmationVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    pub fn generate_msg(
+        mut self,
+        password: &[u8],
+    ) -> Result<(Spake2MessageSent<B>, Vec<u8>), Spake2Error> {
+        let msg = self.backend.generate_msg(password)?;
+        let next = Spake2MessageSent {
+            backend: self.backend,
+            role: self.role,
+            version: self.version,
+            my_msg: msg.clone(),
+        };
+        Ok((next, msg))
+    }
+}
+
+pub struct Spake2MessageSent<B: Spake2Backend = DefaultBackend> {
+    backend: B,
+    role: Spake2Role,
+    version: Spake2ConfirmationVersion,
+    my_msg: Vec<u8>,
+}
+
+impl<B: Spake2Backend> Spake2MessageSent<B> {
+    /// Process the peer's message and derive keys. The optional `aad` is bound into the
+    /// confirmation keys.
+    pub fn process_msg(
+        self,
+        their_msg: &[u8],
+        aad: Option<&[u8]>,
+    ) -> Result<Spake2KeysDerived, Spake2Error> {
+        let secret = self.backend.process_msg(&self.my_msg, their_msg)?;
+        Spake2KeysDerived::new(self.role, self.version, secret, aad)
+    }
+}
+
+pub struct Spake2KeysDerived {
+    role: Spake2Role,
+    version: Spake2ConfirmationVersion,
+    keys: Spake2Keys,
+    transcript: Zeroizing<Vec<u8>>,
+    session_key: Spake2SessionKey,
+}
+
+impl Spake2KeysDerived {
+    fn new(
+        role: Spake2Role,
+        version: Spake2ConfirmationVersion,
+        secret: SharedSecret,
+        aad: Option<&[u8]>,
+    ) -> Result<Self, Spake2Error> {
+        let keys = derive_keys(&secret.key_material, aad)?;
+        let ke_len = secret.key_material.len() / 2;
+
+        Ok(Self {
+            role,
+            version,
+            keys,
+            session_key: derive_session_key(&secret.key_material[..ke_len])?,
+            transcript: secret.transcript,
+        })
+    }
+
+    /// The derived keys. These are available before confirmation so that a party can send
+    /// encrypted data alongside its confirmation message.
+    pub fn keys(&self) -> &Spake2Keys {
+        &self.keys
+    }
+
+    // The message covered by the confirmation MACs.
+    fn confirmed_message(&self) -> &[u8] {
+        match self.version {
+            Spake2ConfirmationVersion::V1 => CONFIRMATION_LABEL.as_bytes(),
+            Spake2ConfirmationVersion::V2 => &self.transcript,
+        }
+    }
+
+    /// MAC the confirmed message with our confirmation key.
+    pub fn key_conf_msg(&self) -> Result<Vec<u8>, Spake2Error> {
+        let key = match self.role {
+            Spake2Role::Alice => &self.keys.alice_conf_key,
+            Spake2Role::Bob => &self.keys.bob_conf_key,
+        };
+
+        generate_mac(key.expose_secret(), self.confirmed_message())
+            .map_err(|_| Spake2Error::MacError)
+    }
+
+    /// Verify the peer's MAC over the confirmed message with their confirmation key.
+    pub fn confirm(self, received_mac: &[u8]) -> Result<Spake2Confirmed, Spake2Error> {
+        let key = match self.role {
+            Spake2Role::Alice => &self.keys.bob_conf_key, // Alice verifies Bob's MAC
+            Spake2Role::Bob => &self.keys.alice_conf_key, // Bob verifies Alice's MAC
+        };
+
+        verify_mac(key.expose_secret(), self.confirmed_message(), received_mac)
+            .map_err(|_| Spake2Error::MacError)?;
+
+        Ok(Spake2Confirmed {
+            keys: self.keys,
+            session_key: self.session_key,
+        })
+    }
+}
+
+pub struct Spake2Confirmed {
+    keys: Spake2Keys,
+    session_key: Spake2SessionKey,
+}
+
+impl Spake2Confirmed {
+    pub fn keys(&self) -> &Spake2Keys {
+        &self.keys
+    }
+
+    pub fn session_key(&self) -> &Spake2SessionKey {
+        &self.session_key
+    }
+
+    pub fn into_session_key(self) -> Spake2SessionKey {
+        self.session_key
+    }
+}
+
+enum Spake2State {
+    Start(Spake2Start),
+    MessageSent(Spake2MessageSent),
+    KeysDerived(Spake2KeysDerived),
+    Confirmed(Spake2Confirmed),
+    // A transition failed; the exchange cannot be resumed.
+    Failed,
+}
+
+/// Thread-safe wrapper around the SPAKE2 state machine, using the default backend.
+/// Transitions are checked at runtime and fail with [`Spake2Error::InvalidState`] when called out
+/// of order. A failed transition leaves the context unusable.
+pub struct Spake2Context {
+    state: Mutex<Spake2State>,
+}
+
+impl Spake2Context {
+    pub fn new(
+        my_role: Spake2Role,
+        my_name: String,
+        their_name: String,
+    ) -> Result<Self, Spake2Error> {
+        Self::new_with_confirmation_version(
+            my_role,
+            my_name,
+            their_name,
+            Spake2ConfirmationVersion::default(),
+        )
+    }
+
+    pub fn new_with_confirmation_version(
+        my_role: Spake2Role,
+        my_name: String,
+        their_name: String,
+        version: Spake2ConfirmationVersion,
+    ) -> Result<Self, Spake2Error> {
+        let start =
+            Spake2Start::new(my_role, &my_name, &their_name)?.with_confirmation_version(version);
+        Ok(Self {
+            state: Mutex::new(Spake2State::Start(start)),
+        })
+    }
+
+    // Run a consuming transition. On failure the context is left in the failed state.
+    fn transition<T>(
+        &self,
+        f: impl FnOnce(Spake2State) -> (Spake2State, Result<T, Spake2Error>),
+    ) -> Result<T, Spake2Error> {
+        let mut state = self.state.lock().unwrap();
+        let (next, out) = f(std::mem::replace(&mut *state, Spake2State::Failed));
+        *state = next;
+        out
+    }
+
+    /// Generate our SPAKE2 message for `password`. Valid once, from the start state.
+    pub fn generate_msg(&self, password: Vec<u8>) -> Result<Vec<u8>, Spake2Error> {
+        self.transition(|state| match state {
+            Spake2State::Start(start) => match start.generate_msg(&password) {
+                Ok((next, msg)) => (Spake2State::MessageSent(next), Ok(msg)),
+                Err(e) => (Spake2State::Failed, Err(e)),
+            },
+            other => (other, Err(Spake2Error::InvalidState)),
+        })
+    }
+
+    /// Process the peer's message and derive keys. Valid once, after `generate_msg`.
+    pub fn process_msg(
+        &self,
+        their_msg: Vec<u8>,
+        aad: Option<Vec<u8>>,
+    ) -> Result<Spake2Keys, Spake2Error> {
+        self.transition(|state| match state {
+            Spake2State::MessageSent(sent) => match sent.process_msg(&their_msg, aad.as_deref()) {
+                Ok(next) => {
+                    let keys = next.keys().copy();
+                    (Spake2State::KeysDerived(next), Ok(keys))
+                }
+                Err(e) => (Spake2State::Failed, Err(e)),
+            },
+            other => (other, Err(Spake2Error::InvalidState)),
+        })
+    }
+
+    /// MAC the confirmed message with our confirmation key. Valid after `process_msg`.
+    pub fn generate_key_conf_msg(&self) -> Result<Vec<u8>, Spake2Error> {
+        match &*self.state.lock().unwrap() {
+            Spake2State::KeysDerived(derived) => derived.key_conf_msg(),
+            _ => Err(Spake2Error::InvalidState),
+        }
+    }
+
+    /// Verify the peer's confirmation MAC. Valid once, after `process_msg`.
+    pub fn process_key_conf_msg(&self, received_mac: Vec<u8>) -> Result<(), Spake2Error> {
+        self.transition(|state| match state {
+            Spake2State::KeysDerived(derived) => match derived.confirm(&received_mac) {
+                Ok(next) => (Spake2State::Confirmed(next), Ok(())),
+                Err(e) => (Spake2State::Failed, Err(e)),
+            },
+            other => (other, Err(Spake2Error::InvalidState)),
+        })
+    }
+
+    /// The session key. Only available once the peer's confirmation has been verified.
+    pub fn session_key(&self) -> Result<Vec<u8>, Spake2Error> {
+        match &*self.state.lock().unwrap() {
+            Spake2State::Confirmed(confirmed) => Ok(confirmed.session_key().as_bytes().to_vec()),
+            _ => Err(Spake2Error::InvalidState),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum Spake2Error {
+    #[error("Failed to create SPAKE2_CTX")]
+    ContextCreationError,
+    #[error("Invalid argument length")]
+    LengthError,
+    #[error("Failed to generate SPAKE2 message")]
+    GenerateMessageError,
+    #[error("Failed to process SPAKE2 message")]
+    ProcessMessageError,
+    #[error("Invalid name")]
+    InvalidName,
+    #[error("Failed to expand HKDF")]
+    HkdfError,
+    #[error("MAC error")]
+    MacError,
+    #[error("Invalid role")]
+    InvalidRole,
+    #[error("Invalid state for this operation")]
+    InvalidState,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start<B: Spake2Backend>(
+        role: Spake2Role,
+        my_name: &str,
+        their_name: &str,
+        password: &str,
+        version: Spake2ConfirmationVersion,
+    ) -> (Spake2MessageSent<B>, Vec<u8>) {
+        Spake2Start::<B>::new(role, my_name, their_name)
+            .unwrap()
+            .with_confirmation_version(version)
+            .generate_msg(password.as_bytes())
+            .unwrap()
+    }
+
+    // Alice runs backend `A` and Bob runs backend `B`.
+    fn exchange<A: Spake2Backend, B: Spake2Backend>(
+        alice_password: &str,
+        bob_password: &str,
+        version: Spake2ConfirmationVersion,
+    ) -> (Spake2KeysDerived, Spake2KeysDerived) {
+        let (alice, alice_msg) =
+            start::<A>(Spake2Role::Alice, "alice", "bob", alice_password, version);
+        let (bob, bob_msg) = start::<B>(Spake2Role::Bob, "bob", "alice", bob_password, version);
+        (
+            alice.process_msg(&bob_msg, None).unwrap(),
+            bob.process_msg(&alice_msg, None).unwrap(),
+        )
+    }
+
+    fn good<A: Spake2Backend, B: Spake2Backend>(version: Spake2ConfirmationVersion) {
+        let (alice, bob) = exchange::<A, B>("password", "password", version);
+        assert_eq!(alice.keys(), bob.keys());
+
+        let alice_conf = alice.key_conf_msg().unwrap();
+        let bob_conf = bob.key_conf_msg().unwrap();
+        assert_ne!(alice_conf, bob_conf);
+
+        let alice = alice.confirm(&bob_conf).unwrap();
+        let bob = bob.confirm(&alice_conf).unwrap();
+        assert_eq!(alice.session_key().as_bytes(), bob.session_key().as_bytes());
+    }
+
+    fn wrong_password<A: Spake2Backend, B: Spake2Backend>(version: Spake2ConfirmationVersion) {
+        for (alice_password, bob_password) in [("passworf", "password"), ("password", "passworf")] {
+            let (alice, bob) = exchange::<A, B>(alice_password, bob_password, version);
+            assert_ne!(alice.keys(), bob.keys());
+
+            let alice_conf = alice.key_conf_msg().unwrap();
+            let bob_conf = bob.key_conf_msg().unwrap();
+            assert!(matches!(
+                alice.confirm(&bob_conf),
+                Err(Spake2Error::MacError)
+            ));
+            assert!(matches!(
+                bob.confirm(&alice_conf),
+                Err(Spake2Error::MacError)
+            ));
+        }
+    }
+
+    fn fresh_keys_per_session<A: Spake2Backend, B: Spake2Backend>() {
+        let version = Spake2ConfirmationVersion::default();
+        let (alice, bob) = exchange::<A, B>("password", "password", version);
+        let (alice2, bob2) = exchange::<A, B>("password", "password", version);
+
+        assert_eq!(alice.keys(), bob.keys());
+        assert_eq!(alice2.keys(), bob2.keys());
+        assert_ne!(alice.keys(), alice2.keys());
+    }
+
+    fn transcript_binds_identities<A: Spake2Backend, B: Spake2Backend>(
+        version: Spake2ConfirmationVersion,
+    ) {
+        let (alice, alice_msg) = start::<A>(Spake2Role::Alice, "alice", "bob", "password", version);
+        let (bob, bob_msg) = start::<B>(Spake2Role::Bob, "mallory", "alice", "password", version);
+        let alice = alice.process_msg(&bob_msg, None).unwrap();
+        let bob = bob.process_msg(&alice_msg, None).unwrap();
+
+        assert!(alice.confirm(&bob.key_conf_msg().unwrap()).is_err());
+    }
+
+    fn aad_binds_confirmation_keys<A: Spake2Backend, B: Spake2Backend>() {
+        let version = Spake2ConfirmationVersion::default();
+        let (alice, alice_msg) = start::<A>(Spake2Role::Alice, "alice", "bob", "password", version);
+        let (bob, bob_msg) = start::<B>(Spake2Role::Bob, "bob", "alice", "password", version);
+        let alice = alice.process_msg(&bob_msg, Some(b"one")).unwrap();
+        let bob = bob.process_msg(&alice_msg, Some(b"two")).unwrap();
+
+        assert_eq!(
+            alice.keys().alice_encryption_key,
+            bob.keys().alice_encryption_key
+        );
+        assert!(alice.confirm(&bob.key_conf_msg().unwrap()).is_err());
+    }
+
+    fn default_macs_transcript<A: Spake2Backend, B: Spake2Backend>() {
+        let (alice, bob) =
+            exchange::<A, B>("password", "password", Spake2ConfirmationVersion::default());
+
+        let expected = generate_mac(
+            alice.keys().alice_conf_key.expose_secret(),
+            &alice.transcript,
+        )
+        .unwrap();
+        assert_eq!(alice.key_conf_msg().unwrap(), expected);
+        bob.confirm(&expected).unwrap();
+    }
+
+    fn v1_macs_fixed_label<A: Spake2Backend, B: Spake2Backend>() {
+        let (alice, bob) = exchange::<A, B>("password", "password", Spake2ConfirmationVersion::V1);
+
+        let expected = generate_mac(
+            alice.keys().alice_conf_key.expose_secret(),
+            CONFIRMATION_LABEL.as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(alice.key_conf_msg().unwrap(), expected);
+        bob.confirm(&expected).unwrap();
+    }
+
+    fn versions_must_match<A: Spake2Backend, B: Spake2Backend>() {
+        let (alice, alice_msg) = start::<A>(
+            Spake2Role::Alice,
+            "alice",
+            "bob",
+            "password",
+            Spake2ConfirmationVersion::V1,
+        );
+        let (bob, bob_msg) = start::<B>(
+            Spake2Role::Bob,
+            "bob",
+            "alice",
+            "password",
+            Spake2ConfirmationVersion::V2,
+        );
+        let alice = alice.process_msg(&bob_msg, None).unwrap();
+        let bob = bob.process_msg(&alice_msg, None).unwrap();
+
+        assert_eq!(alice.keys(), bob.keys());
+        assert!(matches!(
+            alice.confirm(&bob.key_conf_msg().unwrap()),
+            Err(Spake2Error::MacError)
+        ));
+    }
+
+    macro_rules! backend_tests {
+        ($name:ident, $alice:ty, $bob:ty) => {
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn test_good() {
+                    good::<$alice, $bob>(Spake2ConfirmationVersion::V1);
+                    good::<$alice, $bob>(Spake2ConfirmationVersion::V2);
+                }
+
+                #[test]
+                fn test_wrong_password() {
+                    wrong_password::<$alice, $bob>(Spake2ConfirmationVersion::V1);
+                    wrong_password::<$alice, $bob>(Spake2ConfirmationVersion::V2);
+                }
+
+                #[test]
+                fn test_fresh_keys_per_session() {
+                    fresh_keys_per_session::<$alice, $bob>();
+                }
+
+                #[test]
+                fn test_transcript_binds_identities() {
+                    transcript_binds_identities::<$alice, $bob>(Spake2ConfirmationVersion::V1);
+                    transcript_binds_identities::<$alice, $bob>(Spake2ConfirmationVersion::V2);
+                }
+
+                #[test]
+                fn test_aad_binds_confirmation_keys() {
+                    aad_binds_confirmation_keys::<$alice, $bob>();
+                }
+
+                #[test]
+                fn test_default_macs_transcript() {
+                    default_macs_transcript::<$alice, $bob>();
+                }
+
+                #[test]
+                fn test_v1_macs_fixed_label() {
+                    v1_macs_fixed_label::<$alice, $bob>();
+                }
+
+                #[test]
+                fn test_versions_must_match() {
+                    versions_must_match::<$alice, $bob>();
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "boringssl")]
+    backend_tests!(boringssl, BoringSslBackend, BoringSslBackend);
+    #[cfg(feature = "pure-rust")]
+    backend_tests!(ed25519, Ed25519Backend, Ed25519Backend);
+    #[cfg(feature = "pure-rust")]
+    backend_tests!(p256, P256Backend, P256Backend);
+    // Apps built with and without boring-sys must be able to pair with each other.
+    #[cfg(all(feature = "boringssl", feature = "pure-rust"))]
+    backend_tests!(
+        boringssl_alice_ed25519_bob,
+        BoringSslBackend,
+        Ed25519Backend
+    );
+    #[cfg(all(feature = "boringssl", feature = "pure-rust"))]
+    backend_tests!(
+        ed25519_alice_boringssl_bob,
+        Ed25519Backend,
+        BoringSslBackend
+    );
+
+    #[test]
+    fn test_session_key_is_derived_from_ke() {
+        let key_material = [[1u8; 32], [2u8; 32]].concat();
+        let derived = Spake2KeysDerived::new(
+            Spake2Role::Alice,
+            Spake2ConfirmationVersion::default(),
+            SharedSecret {
+                key_material: key_material.clone().into(),
+                transcript: Vec::new().into(),
+            },
+            None,
+        )
+        .unwrap();
+
+        let expected = Hkdf::new(&[], &key_material[..32])
+            .expand(SESSION_KEY_INFO.as_bytes(), SESSION_KEY_LENGTH as i32)
+            .unwrap();
+        assert_eq!(derived.session_key.as_bytes(), expected);
+        assert_ne!(derived.session_key.as_bytes(), &key_material[..32]);
+        assert_ne!(
+            derived.session_key.as_bytes(),
+            derived.keys().alice_encryption_key.expose_secret()
+        );
+    }
+
+    fn setup_contexts() -> (Spake2Context, Spake2Context) {
+        (
+            Spake2Context::new(Spake2Role::Alice, "alice".to_string(), "bob".to_string()).unwrap(),
+            Spake2Context::new(Spake2Role::Bob, "bob".to_string(), "alice".to_string()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_context_good() {
+        let (alice_ctx, bob_ctx) = setup_contexts();
+        let alice_msg = alice_ctx.generate_msg(b"password".to_vec()).unwrap();
+        let bob_msg = bob_ctx.generate_msg(b"password".to_vec()).unwrap();
+        let alice_keys = alice_ctx.process_msg(bob_msg, None).unwrap();
+        let bob_keys = bob_ctx.process_msg(alice_msg, None).unwrap();
+        assert_eq!(alice_keys, bob_keys);
+
+        assert!(matches!(
+            alice_ctx.session_key(),
+            Err(Spake2Error::InvalidState)
+        ));
+
+        let alice_conf = alice_ctx.generate_key_conf_msg().unwrap();
+        let bob_conf = bob_ctx.generate_key_conf_msg().unwrap();
+        alice_ctx.process_key_conf_msg(bob_conf).unwrap();
+        bob_ctx.process_key_conf_msg(alice_conf).unwrap();
+
+        assert_eq!(
+            alice_ctx.session_key().unwrap(),
+            bob_ctx.session_key().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_context_checked_transitions() {
+        let (alice_ctx, _) = setup_contexts();
+
+        assert!(matches!(
+            alice_ctx.process_msg(vec![0; 32], None),
+            Err(Spake2Error::InvalidState)
+        ));
+        assert!(matches!(
+            alice_ctx.generate_key_conf_msg(),
+            Err(Spake2Error::InvalidState)
+        ));
+
+        alice_ctx.generate_msg(b"password".to_vec()).unwrap();
+        assert!(matches!(
+            alice_ctx.generate_msg(b"password".to_vec()),
+            Err(Spake2Error::InvalidState)
+        ));
+    }
+
+    #[test]
+    fn test_context_unusable_after_failure() {
+        let (alice_ctx, bob_ctx) = setup_contexts();
+        let alice_msg = alice_ctx.generate_msg(b"password".to_vec()).unwrap();
+        let bob_msg = bob_ctx.generate_msg(b"passworf".to_vec()).unwrap();
+        alice_ctx.process_msg(bob_msg, None).unwrap();
+        bob_ctx.process_msg(alice_msg, None).unwrap();
+
+        let bob_conf = bob_ctx.generate_key_conf_msg().unwrap();
+        assert!(matches!(
+            alice_ctx.process_key_conf_msg(bob_conf.clone()),
+            Err(Spake2Error::MacError)
+        ));
+        assert!(matches!(
+            alice_ctx.process_key_conf_msg(bob_conf),
+            Err(Spake2Error::InvalidState)
+        ));
+    }
+}
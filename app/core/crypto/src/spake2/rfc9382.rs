@@ -0,0 +1,238 @@
+use p256::elliptic_curve::group::Group;
+use p256::elliptic_curve::ops::Reduce;
+use p256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use p256::elliptic_curve::{Field, PrimeField};
+use p256::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar, U256};
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::{
+    append_transcript_field, sealed, SharedSecret, Spake2Backend, Spake2Error, Spake2Role,
+};
+
+// https://datatracker.ietf.org/doc/rfc9382/
+// Section 6, the P-256 blinding points.
+const M: [u8; 33] = [
+    0x02, 0x88, 0x6e, 0x2f, 0x97, 0xac, 0xe4, 0x6e, 0x55, 0xba, 0x9d, 0xd7, 0x24, 0x25, 0x79, 0xf2,
+    0x99, 0x3b, 0x64, 0xe1, 0x6e, 0xf3, 0xdc, 0xab, 0x95, 0xaf, 0xd4, 0x97, 0x33, 0x3d, 0x8f, 0xa1,
+    0x2f,
+];
+const N: [u8; 33] = [
+    0x03, 0xd8, 0xbb, 0xd6, 0xc6, 0x39, 0xc6, 0x29, 0x37, 0xb0, 0x4d, 0x99, 0x7f, 0x38, 0xc3, 0x77,
+    0x07, 0x19, 0xc6, 0x29, 0xd7, 0x01, 0x4d, 0x49, 0xa2, 0x4b, 0x4f, 0x98, 0xba, 0xa1, 0x29, 0x2b,
+    0x49,
+];
+
+/// Pure-Rust SPAKE2 as specified by RFC 9382, using the P256-SHA256-HKDF-HMAC ciphersuite.
+///
+/// This does not interoperate with [`super::BoringSslBackend`] or [`super::Ed25519Backend`], which
+/// run over Ed25519; both parties must use the same backend. The password is hashed to a scalar with SHA-256 rather than
+/// a memory-hard function, matching the BoringSSL backend's treatment of the password.
+pub struct P256Backend {
+    role: Spake2Role,
+    my_name: Vec<u8>,
+    their_name: Vec<u8>,
+    secret: Option<(Scalar, Scalar)>,
+}
+
+impl sealed::Sealed for P256Backend {}
+
+fn decode_point(bytes: &[u8]) -> Option<ProjectivePoint> {
+    let encoded = EncodedPoint::from_bytes(bytes).ok()?;
+    let point: Option<AffinePoint> = AffinePoint::from_encoded_point(&encoded).into();
+    point.map(ProjectivePoint::from)
+}
+
+fn encode_point(point: &ProjectivePoint) -> Vec<u8> {
+    point
+        .to_affine()
+        .to_encoded_point(false)
+        .as_bytes()
+        .to_vec()
+}
+
+impl P256Backend {
+    fn blinding_points(&self) -> (ProjectivePoint, ProjectivePoint) {
+        let m = decode_point(&M).expect("M is a valid point");
+        let n = decode_point(&N).expect("N is a valid point");
+        match self.role {
+            Spake2Role::Alice => (m, n),
+            Spake2Role::Bob => (n, m),
+        }
+    }
+
+    // Split out from `generate_msg` so that the RFC test vectors can supply `w` and `x` directly.
+    fn message(&mut self, w: Scalar, x: Scalar) -> Vec<u8> {
+        let (my_blind, _) = self.blinding_points();
+        let msg = encode_point(&(ProjectivePoint::GENERATOR * x + my_blind * w));
+        self.secret = Some((w, x));
+        msg
+    }
+}
+
+impl Spake2Backend for P256Backend {
+    fn new(role: Spake2Role, my_name: &[u8], their_name: &[u8]) -> Result<Self, Spake2Error> {
+        Ok(Self {
+            role,
+            my_name: my_name.to_vec(),
+            their_name: their_name.to_vec(),
+            secret: None,
+        })
+    }
+
+    fn generate_msg(&mut self, password: &[u8]) -> Result<Vec<u8>, Spake2Error> {
+        if self.secret.is_some() {
+            return Err(Spake2Error::GenerateMessageError);
+        }
+
+        let w = <Scalar as Reduce<U256>>::reduce_bytes(&Sha256::digest(password));
+        let x = Scalar::random(&mut rand::thread_rng());
+        Ok(self.message(w, x))
+    }
+
+    fn process_msg(mut self, my_msg: &[u8], their_msg: &[u8]) -> Result<SharedSecret, Spake2Error> {
+        let (w, x) = self.secret.ok_or(Spake2Error::ProcessMessageError)?;
+        let (_, their_blind) = self.blinding_points();
+        let their_point = decode_point(their_msg).ok_or(Spake2Error::ProcessMessageError)?;
+
+        // The cofactor of P-256 is 1.
+        let k = (their_point - their_blind * w) * x;
+        if bool::from(k.is_identity()) {
+            return Err(Spake2Error::ProcessMessageError);
+        }
+
+        let (a, b, pa, pb) = match self.role {
+            Spake2Role::Alice => (&self.my_name, &self.their_name, my_msg, their_msg),
+            Spake2Role::Bob => (&self.their_name, &self.my_name, their_msg, my_msg),
+        };
+        let k = Zeroizing::new(encode_point(&k));
+        let w_bytes: Zeroizing<FieldBytes> = Zeroizing::new(w.to_repr());
+
+        // TT = len(A) || A || len(B) || B || len(pA) || pA || len(pB) || pB || len(K) || K
+        //      || len(w) || w
+        let mut transcript = Zeroizing::new(Vec::new());
+        for field in [a.as_slice(), b, pa, pb, &k, &w_bytes] {
+            append_transcript_field(&mut transcript, field);
+        }
+
+        // Ke || Ka = Hash(TT)
+        let key_material = Zeroizing::new(Sha256::digest(transcript.as_slice()).to_vec());
+
+        self.secret.zeroize();
+        Ok(SharedSecret {
+            key_material,
+            transcript,
+        })
+    }
+}
+
+impl Drop for P256Backend {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spake2::{Spake2ConfirmationVersion, Spake2KeysDerived};
+
+    fn scalar(hex: &str) -> Scalar {
+        let bytes: [u8; 32] = hex::decode(hex).unwrap().try_into().unwrap();
+        Scalar::from_repr(bytes.into()).unwrap()
+    }
+
+    // https://datatracker.ietf.org/doc/rfc9382/
+    // Appendix B, spake2: A='server', B='client'
+    #[test]
+    fn test_rfc_vector() {
+        let w = scalar("2ee57912099d31560b3a44b1184b9b4866e904c49d12ac5042c97dca461b1a5f");
+        let x = scalar("43dd0fd7215bdcb482879fca3220c6a968e66d70b1356cac18bb26c84a78d729");
+        let y = scalar("dcb60106f276b02606d8ef0a328c02e4b629f84f89786af5befb0bc75b6e66be");
+
+        let mut alice = P256Backend::new(Spake2Role::Alice, b"server", b"client").unwrap();
+        let mut bob = P256Backend::new(Spake2Role::Bob, b"client", b"server").unwrap();
+        let pa = alice.message(w, x);
+        let pb = bob.message(w, y);
+        assert_eq!(
+            hex::encode(&pa),
+            concat!(
+                "04a56fa807caaa53a4d28dbb9853b9815c61a411118a6fe516a8798434751470f9",
+                "010153ac33d0d5f2047ffdb1a3e42c9b4e6be662766e1eeb4116988ede5f912c"
+            )
+        );
+        assert_eq!(
+            hex::encode(&pb),
+            concat!(
+                "0406557e482bd03097ad0cbaa5df82115460d951e3451962f1eaf4367a420676d0",
+                "9857ccbc522686c83d1852abfa8ed6e4a1155cf8f1543ceca528afb591a1e0b7"
+            )
+        );
+
+        let alice_secret = alice.process_msg(&pa, &pb).unwrap();
+        let bob_secret = bob.process_msg(&pb, &pa).unwrap();
+        assert_eq!(*alice_secret.transcript, *bob_secret.transcript);
+        assert_eq!(*alice_secret.key_material, *bob_secret.key_material);
+
+        let k = concat!(
+            "0412af7e89717850671913e6b469ace67bd90a4df8ce45c2af19010175e37eed69",
+            "f75897996d539356e2fa6a406d528501f907e04d97515fbe83db277b715d3325"
+        );
+        let expected_transcript = [
+            "0600000000000000736572766572",
+            "0600000000000000636c69656e74",
+            "4100000000000000",
+            &hex::encode(&pa),
+            "4100000000000000",
+            &hex::encode(&pb),
+            "4100000000000000",
+            k,
+            "2000000000000000",
+            "2ee57912099d31560b3a44b1184b9b4866e904c49d12ac5042c97dca461b1a5f",
+        ]
+        .concat();
+        assert_eq!(hex::encode(&*alice_secret.transcript), expected_transcript);
+
+        let (ke, ka) = alice_secret.key_material.split_at(16);
+        assert_eq!(hex::encode(ke), "0e0672dc86f8e45565d338b0540abe69");
+        assert_eq!(hex::encode(ka), "15bdf72e2b35b5c9e5663168e960a91b");
+
+        // The RFC's confirmation MACs are over the transcript.
+        let version = Spake2ConfirmationVersion::V2;
+        let alice = Spake2KeysDerived::new(Spake2Role::Alice, version, alice_secret, None).unwrap();
+        let bob = Spake2KeysDerived::new(Spake2Role::Bob, version, bob_secret, None).unwrap();
+        assert_eq!(
+            hex::encode(alice.keys().alice_conf_key.expose_secret()),
+            "00c12546835755c86d8c0db7851ae86f"
+        );
+        assert_eq!(
+            hex::encode(alice.keys().bob_conf_key.expose_secret()),
+            "a9fa3406c3b781b93d804485430ca27a"
+        );
+
+        let alice_conf = alice.key_conf_msg().unwrap();
+        let bob_conf = bob.key_conf_msg().unwrap();
+        assert_eq!(
+            hex::encode(&alice_conf),
+            "58ad4aa88e0b60d5061eb6b5dd93e80d9c4f00d127c65b3b35b1b5281fee38f0"
+        );
+        assert_eq!(
+            hex::encode(&bob_conf),
+            "d3e2e547f1ae04f2dbdbf0fc4b79f8ecff2dff314b5d32fe9fcef2fb26dc459b"
+        );
+
+        assert!(alice.confirm(&bob_conf).is_ok());
+        assert!(bob.confirm(&alice_conf).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_invalid_point() {
+        let mut alice = P256Backend::new(Spake2Role::Alice, b"alice", b"bob").unwrap();
+        let msg = alice.generate_msg(b"password").unwrap();
+
+        assert!(matches!(
+            alice.process_msg(&msg, &[0x04; 65]),
+            Err(Spake2Error::ProcessMessageError)
+        ));
+    }
+}
@@ -0,0 +1,381 @@
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::util::bip32::{ChildNumber, DerivationPath, ExtendedPrivKey};
+use bitcoin::Network;
+use miniscript::descriptor::{DescriptorSecretKey, DescriptorXKey, KeyMap, Wildcard};
+use miniscript::{Descriptor, DescriptorPublicKey};
+use prost::Message;
+
+use crate::proto;
+use crate::EmergencyAccessKitError;
+
+const RECEIVING_PATH: [ChildNumber; 1] = [ChildNumber::Normal { index: 0 }];
+const CHANGE_PATH: [ChildNumber; 1] = [ChildNumber::Normal { index: 1 }];
+
+/// The spending keyset that is active at the time the kit is generated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActiveSpendingKeyset {
+    pub local_id: String,
+    pub network: Network,
+    pub app_key: DescriptorPublicKey,
+    pub hardware_key: DescriptorPublicKey,
+    pub server_key: DescriptorPublicKey,
+}
+
+/// The plaintext contents of an Emergency Access Kit: the active keyset and the private half of
+/// its app key. This is what gets sealed into a [`crate::Payload`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Backup {
+    keyset: ActiveSpendingKeyset,
+    app_xprv: DescriptorXKey<ExtendedPrivKey>,
+}
+
+impl fmt::Debug for Backup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Backup")
+            .field("keyset", &self.keyset)
+            .field("app_xprv", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// Receiving and change descriptors for a 2-of-3 wallet, with the app key included as an xprv so
+/// that BDK can sign with it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WalletDescriptors {
+    pub external: String,
+    pub internal: String,
+}
+
+impl Backup {
+    pub fn new(
+        keyset: ActiveSpendingKeyset,
+        app_xprv: DescriptorSecretKey,
+    ) -> Result<Self, EmergencyAccessKitError> {
+        let app_xprv = match app_xprv {
+            DescriptorSecretKey::XPrv(xprv) => xprv,
+            DescriptorSecretKey::Single(_) => {
+                return Err(EmergencyAccessKitError::InvalidKey(
+                    "app key must be an xprv".to_string(),
+                ))
+            }
+        };
+
+        let app_key = DescriptorSecretKey::XPrv(app_xprv.clone())
+            .to_public(&Secp256k1::signing_only())
+            .map_err(|e| EmergencyAccessKitError::InvalidKey(e.to_string()))?;
+        if app_key != keyset.app_key {
+            return Err(EmergencyAccessKitError::KeyMismatch);
+        }
+        for key in [&keyset.hardware_key, &keyset.server_key] {
+            if let DescriptorPublicKey::Single(_) = key {
+                return Err(EmergencyAccessKitError::InvalidKey(
+                    "spending key must be an xpub".to_string(),
+                ));
+            }
+        }
+
+        Ok(Self { keyset, app_xprv })
+    }
+
+    pub fn keyset(&self) -> &ActiveSpendingKeyset {
+        &self.keyset
+    }
+
+    pub fn app_xprv(&self) -> DescriptorSecretKey {
+        DescriptorSecretKey::XPrv(self.app_xprv.clone())
+    }
+
+    /// Build the descriptors for a spendable BDK wallet, using the same derivation as the apps:
+    /// `wsh(sortedmulti(2, app, hardware, server))` over the `/0/*` and `/1/*` branches.
+    pub fn descriptors(&self) -> Result<WalletDescriptors, EmergencyAccessKitError> {
+        Ok(WalletDescriptors {
+            external: self.descriptor(&RECEIVING_PATH)?,
+            internal: self.descriptor(&CHANGE_PATH)?,
+        })
+    }
+
+    fn descriptor(&self, path: &[ChildNumber]) -> Result<String, EmergencyAccessKitError> {
+        let app_xprv = DescriptorXKey {
+            origin: self.app_xprv.origin.clone(),
+            derivation_path: self.app_xprv.derivation_path.extend(path),
+            ..self.app_xprv
+        };
+        let app_key = extend_derivation_path(&self.keyset.app_key, path)?;
+
+        let descriptor = Descriptor::<DescriptorPublicKey>::new_wsh_sortedmulti(
+            2,
+            vec![
+                app_key.clone(),
+                extend_derivation_path(&self.keyset.hardware_key, path)?,
+                extend_derivation_path(&self.keyset.server_key, path)?,
+            ],
+        )?;
+
+        let mut key_map = KeyMap::new();
+        key_map.insert(app_key, DescriptorSecretKey::XPrv(app_xprv));
+
+        Ok(descriptor.to_string_with_secret(&key_map))
+    }
+
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        proto::ActiveSpendingKeysetV1 {
+            local_id: Some(self.keyset.local_id.clone()),
+            bitcoin_network_type: network_to_proto(self.keyset.network) as i32,
+            app_key: Some(proto::AppSpendingKey {
+                key: Some(spending_key_to_proto(&self.keyset.app_key)),
+                xprv: Some(self.app_xprv().to_string()),
+            }),
+            hardware_key: Some(spending_key_to_proto(&self.keyset.hardware_key)),
+            f8e_key: Some(spending_key_to_proto(&self.keyset.server_key)),
+        }
+        .encode_to_vec()
+    }
+
+    pub(crate) fn decode(data: &[u8]) -> Result<Self, EmergencyAccessKitError> {
+        let proto = proto::ActiveSpendingKeysetV1::decode(data)?;
+        let app_key = proto
+            .app_key
+            .ok_or(EmergencyAccessKitError::MissingField("app_key"))?;
+
+        let keyset = ActiveSpendingKeyset {
+            local_id: proto
+                .local_id
+                .ok_or(EmergencyAccessKitError::MissingField("local_id"))?,
+            network: network_from_proto(proto.bitcoin_network_type)?,
+            app_key: spending_key_from_proto(
+                app_key
+                    .key
+                    .ok_or(EmergencyAccessKitError::MissingField("app_key.key"))?,
+            )?,
+            hardware_key: spending_key_from_proto(
+                proto
+                    .hardware_key
+                    .ok_or(EmergencyAccessKitError::MissingField("hardware_key"))?,
+            )?,
+            server_key: spending_key_from_proto(
+                proto
+                    .f8e_key
+                    .ok_or(EmergencyAccessKitError::MissingField("f8e_key"))?,
+            )?,
+        };
+        let app_xprv = DescriptorSecretKey::from_str(
+            &app_key
+                .xprv
+                .ok_or(EmergencyAccessKitError::MissingField("app_key.xprv"))?,
+        )
+        .map_err(|e| EmergencyAccessKitError::InvalidKey(e.to_string()))?;
+
+        Self::new(keyset, app_xprv)
+    }
+}
+
+fn extend_derivation_path(
+    key: &DescriptorPublicKey,
+    path: &[ChildNumber],
+) -> Result<DescriptorPublicKey, EmergencyAccessKitError> {
+    match key {
+        DescriptorPublicKey::XPub(xpub) => Ok(DescriptorPublicKey::XPub(DescriptorXKey {
+            origin: xpub.origin.clone(),
+            derivation_path: xpub.derivation_path.extend(path),
+            ..*xpub
+        })),
+        DescriptorPublicKey::Single(_) => Err(EmergencyAccessKitError::InvalidKey(
+            "spending key must be an xpub".to_string(),
+        )),
+    }
+}
+
+fn network_to_proto(network: Network) -> proto::BitcoinNetworkType {
+    match network {
+        Network::Bitcoin => proto::BitcoinNetworkType::Bitcoin,
+        Network::Testnet => proto::BitcoinNetworkType::Testnet,
+        Network::Signet => proto::BitcoinNetworkType::Signet,
+        Network::Regtest => proto::BitcoinNetworkType::Regtest,
+    }
+}
+
+fn network_from_proto(network: i32) -> Result<Network, EmergencyAccessKitError> {
+    match proto::BitcoinNetworkType::from_i32(network) {
+        Some(proto::BitcoinNetworkType::Bitcoin) => Ok(Network::Bitcoin),
+        Some(proto::BitcoinNetworkType::Testnet) => Ok(Network::Testnet),
+        Some(proto::BitcoinNetworkType::Signet) => Ok(Network::Signet),
+        Some(proto::BitcoinNetworkType::Regtest) => Ok(Network::Regtest),
+        Some(proto::BitcoinNetworkType::Unspecified) | None => Err(
+            EmergencyAccessKitError::MissingField("bitcoin_network_type"),
+        ),
+    }
+}
+
+// Paths are written the same way the apps write them: each step prefixed by a slash, with no
+// leading `m`, so that `[fingerprint + origin path] + xpub + derivation path` is a valid dpub.
+fn format_path(path: &DerivationPath) -> String {
+    path.into_iter().fold(String::new(), |mut out, child| {
+        let _ = write!(out, "/{child}");
+        out
+    })
+}
+
+fn spending_key_to_proto(key: &DescriptorPublicKey) -> proto::SpendingPublicKey {
+    let DescriptorPublicKey::XPub(xpub) = key else {
+        unreachable!("spending keys are validated as xpubs")
+    };
+
+    let wildcard = match xpub.wildcard {
+        Wildcard::None => (proto::Wildcard::None, ""),
+        Wildcard::Unhardened => (proto::Wildcard::Unhardened, "/*"),
+        Wildcard::Hardened => (proto::Wildcard::Hardened, "/*'"),
+    };
+
+    proto::SpendingPublicKey {
+        origin: xpub
+            .origin
+            .as_ref()
+            .map(|(fingerprint, path)| proto::Origin {
+                fingerprint: Some(fingerprint.to_string()),
+                derivation_path: Some(format_path(path)),
+            }),
+        xpub: Some(xpub.xkey.to_string()),
+        derivation_path: Some(format!(
+            "{}{}",
+            format_path(&xpub.derivation_path),
+            wildcard.1
+        )),
+        wildcard: wildcard.0 as i32,
+    }
+}
+
+fn spending_key_from_proto(
+    proto: proto::SpendingPublicKey,
+) -> Result<DescriptorPublicKey, EmergencyAccessKitError> {
+    let origin = proto
+        .origin
+        .ok_or(EmergencyAccessKitError::MissingField("origin"))?;
+    let dpub = format!(
+        "[{}{}]{}{}",
+        origin
+            .fingerprint
+            .ok_or(EmergencyAccessKitError::MissingField("origin.fingerprint"))?,
+        origin
+            .derivation_path
+            .ok_or(EmergencyAccessKitError::MissingField(
+                "origin.derivation_path"
+            ))?,
+        proto
+            .xpub
+            .ok_or(EmergencyAccessKitError::MissingField("xpub"))?,
+        proto
+            .derivation_path
+            .ok_or(EmergencyAccessKitError::MissingField("derivation_path"))?,
+    );
+
+    let key = DescriptorPublicKey::from_str(&dpub)
+        .map_err(|e| EmergencyAccessKitError::InvalidKey(e.to_string()))?;
+
+    // Reject keys whose wildcard disagrees with the derivation path string.
+    let expected = match proto::Wildcard::from_i32(proto.wildcard) {
+        Some(proto::Wildcard::None) => Wildcard::None,
+        Some(proto::Wildcard::Unhardened) => Wildcard::Unhardened,
+        Some(proto::Wildcard::Hardened) => Wildcard::Hardened,
+        Some(proto::Wildcard::Unspecified) | None => {
+            return Err(EmergencyAccessKitError::MissingField("wildcard"))
+        }
+    };
+    match &key {
+        DescriptorPublicKey::XPub(xpub) if xpub.wildcard == expected => Ok(key),
+        _ => Err(EmergencyAccessKitError::InvalidKey(dpub)),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use bitcoin::util::bip32::ExtendedPubKey;
+    use rand::RngCore;
+
+    fn account_xkey() -> DescriptorXKey<ExtendedPrivKey> {
+        let secp = Secp256k1::new();
+        let mut seed = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut seed);
+        // Extended keys only serialize as mainnet or testnet
+        let master = ExtendedPrivKey::new_master(Network::Testnet, &seed).unwrap();
+        let path = DerivationPath::from_str("m/84'/1'/0'").unwrap();
+
+        DescriptorXKey {
+            origin: Some((master.fingerprint(&secp), path.clone())),
+            xkey: master.derive_priv(&secp, &path).unwrap(),
+            derivation_path: DerivationPath::master(),
+            wildcard: Wildcard::Unhardened,
+        }
+    }
+
+    fn public(xkey: &DescriptorXKey<ExtendedPrivKey>) -> DescriptorPublicKey {
+        DescriptorPublicKey::XPub(DescriptorXKey {
+            origin: xkey.origin.clone(),
+            xkey: ExtendedPubKey::from_priv(&Secp256k1::new(), &xkey.xkey),
+            derivation_path: xkey.derivation_path.clone(),
+            wildcard: xkey.wildcard,
+        })
+    }
+
+    pub(crate) fn backup() -> Backup {
+        let app = account_xkey();
+        let keyset = ActiveSpendingKeyset {
+            local_id: "keyset-id".to_string(),
+            network: Network::Signet,
+            app_key: public(&app),
+            hardware_key: public(&account_xkey()),
+            server_key: public(&account_xkey()),
+        };
+
+        Backup::new(keyset, DescriptorSecretKey::XPrv(app)).unwrap()
+    }
+
+    #[test]
+    fn encode_roundtrip() {
+        let backup = backup();
+        let decoded = Backup::decode(&backup.encode()).unwrap();
+        assert_eq!(decoded, backup);
+    }
+
+    #[test]
+    fn rejects_mismatched_app_key() {
+        let backup = backup();
+        let other = account_xkey();
+
+        assert!(matches!(
+            Backup::new(backup.keyset, DescriptorSecretKey::XPrv(other)),
+            Err(EmergencyAccessKitError::KeyMismatch)
+        ));
+    }
+
+    #[test]
+    fn debug_redacts_app_xprv() {
+        let backup = backup();
+        let debug = format!("{backup:?}");
+
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains(&backup.app_xprv.xkey.to_string()));
+    }
+
+    #[test]
+    fn descriptors_are_spendable() {
+        let backup = backup();
+        let descriptors = backup.descriptors().unwrap();
+
+        for (descriptor, branch) in [
+            (&descriptors.external, "/0/*"),
+            (&descriptors.internal, "/1/*"),
+        ] {
+            let (public, key_map) =
+                Descriptor::<DescriptorPublicKey>::parse_descriptor(&Secp256k1::new(), descriptor)
+                    .unwrap();
+            assert!(descriptor.starts_with("wsh(sortedmulti(2,"));
+            assert_eq!(key_map.len(), 1);
+            assert_eq!(descriptor.matches(branch).count(), 3);
+            assert_eq!(public.to_string().matches(branch).count(), 3);
+        }
+    }
+}
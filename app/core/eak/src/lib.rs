@@ -0,0 +1,38 @@
+pub mod backup;
+pub mod payload;
+
+mod proto {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/build.wallet.emergencyaccesskit.v1.rs"
+    ));
+}
+
+pub use backup::{ActiveSpendingKeyset, Backup, WalletDescriptors};
+pub use payload::Payload;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EmergencyAccessKitError {
+    #[error("Invalid payload encoding")]
+    InvalidEncoding,
+    #[error("Invalid payload protobuf: {0}")]
+    InvalidProto(#[from] prost::DecodeError),
+    #[error("Missing field: {0}")]
+    MissingField(&'static str),
+    #[error("Unsupported backup version")]
+    UnsupportedVersion,
+    #[error("Invalid key: {0}")]
+    InvalidKey(String),
+    #[error("Invalid encryption key length")]
+    KeyLengthError,
+    #[error("App spending key does not match the keyset")]
+    KeyMismatch,
+    #[error("Failed to encrypt backup")]
+    EncryptError,
+    #[error("Failed to decrypt backup")]
+    DecryptError,
+    #[error(transparent)]
+    DescriptorError(#[from] miniscript::Error),
+    #[error(transparent)]
+    QrCodeError(#[from] qrcode::types::QrError),
+}
@@ -0,0 +1,204 @@
+use bitcoin::util::base58;
+use crypto::chacha20poly1305::XChaCha20Poly1305;
+use prost::Message;
+use qrcode::QrCode;
+use rand::RngCore;
+
+use crate::backup::Backup;
+use crate::proto;
+use crate::EmergencyAccessKitError;
+
+const KEY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 24;
+const TAG_LENGTH: usize = 16;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SealedData {
+    ciphertext: Vec<u8>,
+    nonce: Vec<u8>,
+    tag: Vec<u8>,
+}
+
+/// An Emergency Access Kit payload, as printed on the kit.
+///
+/// The backup is encrypted with XChaCha20-Poly1305 under a random key (the PKEK), and the PKEK
+/// itself is sealed by the hardware (`SealKey`). Recovering the backup therefore requires the
+/// printed payload and the hardware to unseal the PKEK (`UnsealKey`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Payload {
+    sealed_pkek: Vec<u8>,
+    sealed_backup: SealedData,
+}
+
+impl Payload {
+    /// Encrypt `backup` with `pkek`. The `sealed_pkek` is the result of sealing that same key
+    /// with the hardware and is stored alongside the ciphertext.
+    pub fn seal(
+        backup: &Backup,
+        pkek: &[u8],
+        sealed_pkek: Vec<u8>,
+    ) -> Result<Self, EmergencyAccessKitError> {
+        let cipher = cipher(pkek)?;
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let mut ciphertext = cipher
+            .encrypt(&nonce, &backup.encode(), &[])
+            .map_err(|_| EmergencyAccessKitError::EncryptError)?;
+        let tag = ciphertext.split_off(ciphertext.len() - TAG_LENGTH);
+
+        Ok(Self {
+            sealed_pkek,
+            sealed_backup: SealedData {
+                ciphertext,
+                nonce: nonce.to_vec(),
+                tag,
+            },
+        })
+    }
+
+    /// The hardware-sealed PKEK, to be passed to `UnsealKey`.
+    pub fn sealed_pkek(&self) -> Vec<u8> {
+        self.sealed_pkek.clone()
+    }
+
+    /// Decrypt the backup with the PKEK returned by the hardware.
+    pub fn unseal(&self, pkek: &[u8]) -> Result<Backup, EmergencyAccessKitError> {
+        let cipher = cipher(pkek)?;
+        if self.sealed_backup.nonce.len() != NONCE_LENGTH {
+            return Err(EmergencyAccessKitError::DecryptError);
+        }
+
+        let ciphertext = [
+            self.sealed_backup.ciphertext.as_slice(),
+            self.sealed_backup.tag.as_slice(),
+        ]
+        .concat();
+        let plaintext = cipher
+            .decrypt(&self.sealed_backup.nonce, &ciphertext, &[])
+            .map_err(|_| EmergencyAccessKitError::DecryptError)?;
+
+        Backup::decode(&plaintext)
+    }
+
+    /// Base58-encode the payload for printing. The same text is used as the QR code contents.
+    pub fn encode(&self) -> String {
+        let payload = proto::Payload {
+            backup: Some(proto::payload::Backup::BackupV1(proto::BackupV1 {
+                hw_encryption_key_ciphertext: Some(self.sealed_pkek.clone()),
+                sealed_active_spending_keyset: Some(proto::SealedData {
+                    ciphertext: Some(self.sealed_backup.ciphertext.clone()),
+                    nonce: Some(self.sealed_backup.nonce.clone()),
+                    tag: Some(self.sealed_backup.tag.clone()),
+                }),
+            })),
+        };
+
+        base58::encode_slice(&payload.encode_to_vec())
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, EmergencyAccessKitError> {
+        let data =
+            base58::from(encoded.trim()).map_err(|_| EmergencyAccessKitError::InvalidEncoding)?;
+
+        let backup = match proto::Payload::decode(data.as_slice())?.backup {
+            Some(proto::payload::Backup::BackupV1(backup)) => backup,
+            None => return Err(EmergencyAccessKitError::UnsupportedVersion),
+        };
+        let sealed_backup =
+            backup
+                .sealed_active_spending_keyset
+                .ok_or(EmergencyAccessKitError::MissingField(
+                    "sealed_active_spending_keyset",
+                ))?;
+
+        Ok(Self {
+            sealed_pkek: backup.hw_encryption_key_ciphertext.ok_or(
+                EmergencyAccessKitError::MissingField("hw_encryption_key_ciphertext"),
+            )?,
+            sealed_backup: SealedData {
+                ciphertext: sealed_backup
+                    .ciphertext
+                    .ok_or(EmergencyAccessKitError::MissingField("ciphertext"))?,
+                nonce: sealed_backup
+                    .nonce
+                    .ok_or(EmergencyAccessKitError::MissingField("nonce"))?,
+                tag: sealed_backup
+                    .tag
+                    .ok_or(EmergencyAccessKitError::MissingField("tag"))?,
+            },
+        })
+    }
+
+    pub fn qr_code(&self) -> Result<QrCode, EmergencyAccessKitError> {
+        Ok(QrCode::new(self.encode())?)
+    }
+}
+
+fn cipher(pkek: &[u8]) -> Result<XChaCha20Poly1305, EmergencyAccessKitError> {
+    if pkek.len() != KEY_LENGTH {
+        return Err(EmergencyAccessKitError::KeyLengthError);
+    }
+
+    Ok(XChaCha20Poly1305::new(pkek))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::tests::backup;
+
+    fn random_key() -> [u8; KEY_LENGTH] {
+        let mut key = [0u8; KEY_LENGTH];
+        rand::thread_rng().fill_bytes(&mut key);
+        key
+    }
+
+    #[test]
+    fn seal_roundtrip() {
+        let backup = backup();
+        let pkek = random_key();
+        let sealed_pkek = b"sealed by hardware".to_vec();
+
+        let payload = Payload::seal(&backup, &pkek, sealed_pkek.clone()).unwrap();
+        assert_eq!(payload.sealed_pkek(), sealed_pkek);
+        assert_eq!(payload.unseal(&pkek).unwrap(), backup);
+    }
+
+    #[test]
+    fn unseal_with_wrong_key() {
+        let payload = Payload::seal(&backup(), &random_key(), vec![]).unwrap();
+
+        assert!(matches!(
+            payload.unseal(&random_key()),
+            Err(EmergencyAccessKitError::DecryptError)
+        ));
+        assert!(matches!(
+            payload.unseal(&[0u8; 16]),
+            Err(EmergencyAccessKitError::KeyLengthError)
+        ));
+    }
+
+    #[test]
+    fn encode_roundtrip() {
+        let pkek = random_key();
+        let payload = Payload::seal(&backup(), &pkek, vec![1, 2, 3]).unwrap();
+
+        let encoded = payload.encode();
+        let decoded = Payload::decode(&encoded).unwrap();
+        assert_eq!(decoded, payload);
+        assert!(payload.qr_code().is_ok());
+    }
+
+    #[test]
+    fn decode_invalid() {
+        assert!(matches!(
+            Payload::decode("not base58: 0OIl"),
+            Err(EmergencyAccessKitError::InvalidEncoding)
+        ));
+        assert!(matches!(
+            Payload::decode(""),
+            Err(EmergencyAccessKitError::UnsupportedVersion)
+        ));
+    }
+}
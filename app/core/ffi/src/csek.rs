@@ -14,7 +14,9 @@ pub struct UnsealKey(wca::commands::UnsealKey);
 impl SealKey {
     pub fn new(key: UnsealedKey) -> Result<Self, CommandError> {
         let unsealed_key = key.try_into().map_err(CommandError::KeySizeError)?;
-        Ok(Self(wca::commands::SealKey::new(unsealed_key)))
+        Ok(Self(wca::commands::SealKey::new(
+            wca::commands::UnsealedKey::new(unsealed_key),
+        )))
     }
 
     pub fn next(&self, response: Vec<u8>) -> Result<BytesState, CommandError> {
@@ -31,7 +33,7 @@ impl UnsealKey {
         let state = match self.0.next(response)? {
             State::Data { response } => BytesState::Data { response },
             State::Result { value } => BytesState::Result {
-                value: value.into(),
+                value: value.expose_secret().to_vec(),
             },
         };
         Ok(state)
@@ -0,0 +1,87 @@
+use std::str::FromStr;
+
+use eak::{ActiveSpendingKeyset, Backup, EmergencyAccessKitError, Payload, WalletDescriptors};
+use miniscript::descriptor::DescriptorSecretKey;
+use wca::commands::{BtcNetwork, DescriptorPublicKey};
+
+pub struct EmergencyAccessKitBackup {
+    pub local_id: String,
+    pub network: BtcNetwork,
+    pub app_key: DescriptorPublicKey,
+    pub app_xprv: String,
+    pub hardware_key: DescriptorPublicKey,
+    pub server_key: DescriptorPublicKey,
+}
+
+pub struct EmergencyAccessKitPayload(Payload);
+
+impl TryFrom<EmergencyAccessKitBackup> for Backup {
+    type Error = EmergencyAccessKitError;
+
+    fn try_from(value: EmergencyAccessKitBackup) -> Result<Self, Self::Error> {
+        let app_xprv = DescriptorSecretKey::from_str(&value.app_xprv)
+            .map_err(|e| EmergencyAccessKitError::InvalidKey(e.to_string()))?;
+
+        Backup::new(
+            ActiveSpendingKeyset {
+                local_id: value.local_id,
+                network: value.network.into(),
+                app_key: value.app_key,
+                hardware_key: value.hardware_key,
+                server_key: value.server_key,
+            },
+            app_xprv,
+        )
+    }
+}
+
+impl From<Backup> for EmergencyAccessKitBackup {
+    fn from(value: Backup) -> Self {
+        let keyset = value.keyset().clone();
+
+        Self {
+            local_id: keyset.local_id,
+            network: keyset.network.into(),
+            app_key: keyset.app_key,
+            app_xprv: value.app_xprv().to_string(),
+            hardware_key: keyset.hardware_key,
+            server_key: keyset.server_key,
+        }
+    }
+}
+
+impl EmergencyAccessKitPayload {
+    pub fn seal(
+        backup: EmergencyAccessKitBackup,
+        pkek: Vec<u8>,
+        sealed_pkek: Vec<u8>,
+    ) -> Result<Self, EmergencyAccessKitError> {
+        let backup = Backup::try_from(backup)?;
+        Ok(Self(Payload::seal(&backup, &pkek, sealed_pkek)?))
+    }
+
+    pub fn decode(encoded: String) -> Result<Self, EmergencyAccessKitError> {
+        Ok(Self(Payload::decode(&encoded)?))
+    }
+
+    pub fn encode(&self) -> String {
+        self.0.encode()
+    }
+
+    pub fn sealed_pkek(&self) -> Vec<u8> {
+        self.0.sealed_pkek()
+    }
+
+    pub fn unseal(
+        &self,
+        pkek: Vec<u8>,
+    ) -> Result<EmergencyAccessKitBackup, EmergencyAccessKitError> {
+        Ok(self.0.unseal(&pkek)?.into())
+    }
+}
+
+pub fn emergency_access_kit_descriptors(
+    backup: EmergencyAccessKitBackup,
+) -> Result<WalletDescriptors, EmergencyAccessKitError> {
+    Backup::try_from(backup)?.descriptors()
+}
@@ -1,13 +1,21 @@
 mod csek;
+mod emergency_access_kit;
 mod types;
 
 use crate::csek::{SealKey, UnsealKey};
+use crate::emergency_access_kit::{
+    emergency_access_kit_descriptors, EmergencyAccessKitBackup, EmergencyAccessKitPayload,
+};
 use crypto::chacha20poly1305::{ChaCha20Poly1305Error, XChaCha20Poly1305};
 use crypto::ecdh::Secp256k1SharedSecret;
 use crypto::hkdf::{Hkdf, HkdfError};
 use crypto::invoice::{Invoice, InvoiceError, Sha256};
 use crypto::keys::{PublicKey, SecretKey, SecretKeyError};
-use crypto::spake2::{Spake2Context, Spake2Error, Spake2Keys, Spake2Role};
+use crypto::secret::SecretBytes;
+use crypto::spake2::{
+    Spake2ConfirmationVersion, Spake2Context, Spake2Error, Spake2Keys, Spake2Role,
+};
+use eak::{EmergencyAccessKitError, WalletDescriptors};
 use teltra::{TelemetryIdentifiers, Teltra, TeltraError};
 use wca::attestation::{Attestation, AttestationError};
 use wca::command_interface::{Command, State};
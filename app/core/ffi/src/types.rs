@@ -1,6 +1,7 @@
 use std::{fmt::Display, str::FromStr};
 
 use crate::UniffiCustomTypeConverter;
+use crypto::secret::SecretBytes;
 use wca::commands::Signature;
 
 trait Stringable: Display + FromStr {}
@@ -25,3 +26,17 @@ where
         obj.to_string()
     }
 }
+
+// The bytes handed back to the foreign side are out of our hands; everything on this side of the
+// boundary is zeroized on drop.
+impl UniffiCustomTypeConverter for SecretBytes {
+    type Builtin = Vec<u8>;
+
+    fn into_custom(val: Self::Builtin) -> uniffi::Result<Self> {
+        Ok(Self::new(val))
+    }
+
+    fn from_custom(obj: Self) -> Self::Builtin {
+        obj.expose_secret().clone()
+    }
+}
@@ -1,17 +1,18 @@
 use aes_gcm_siv::{
-    aead::{rand_core::RngCore, Aead, OsRng},
+    aead::{generic_array::GenericArray, rand_core::RngCore, Aead, OsRng},
     Aes256GcmSiv, KeyInit, Nonce,
 };
+use crypto::secret::{Secret, SecretBytes};
 
 pub type AeadError = aes_gcm_siv::aead::Error;
-pub type UnsealedKey = [u8; 32];
+pub type UnsealedKey = Secret<[u8; 32]>;
 pub type SealedKey = Vec<u8>;
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq)]
 pub struct UnsealedEnvelope {
     key: UnsealedKey,
     nonce: Nonce,
-    pub(crate) plaintext: Vec<u8>,
+    pub(crate) plaintext: SecretBytes,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -22,7 +23,9 @@ pub struct SealedEnvelope {
 }
 
 fn random_key() -> UnsealedKey {
-    Aes256GcmSiv::generate_key(&mut OsRng).into()
+    let mut key = UnsealedKey::new([0_u8; 32]);
+    OsRng.fill_bytes(key.expose_secret_mut());
+    key
 }
 
 fn random_nonce() -> Nonce {
@@ -38,17 +41,17 @@ impl UnsealedEnvelope {
         Self {
             key: random_key(),
             nonce: random_nonce(),
-            plaintext,
+            plaintext: plaintext.into(),
         }
     }
 
-    pub fn key(&self) -> UnsealedKey {
-        self.key
+    pub fn key(&self) -> &UnsealedKey {
+        &self.key
     }
 
     pub fn seal(&self, sealed_key: SealedKey) -> Result<SealedEnvelope, AeadError> {
-        let cipher = Aes256GcmSiv::new(&self.key.into());
-        let ciphertext = cipher.encrypt(&self.nonce, self.plaintext.as_slice())?;
+        let cipher = Aes256GcmSiv::new(GenericArray::from_slice(self.key.expose_secret()));
+        let ciphertext = cipher.encrypt(&self.nonce, self.plaintext.expose_secret().as_slice())?;
 
         Ok(SealedEnvelope {
             sealant: sealed_key,
@@ -64,38 +67,52 @@ impl SealedEnvelope {
     }
 
     pub fn unseal(&self, key: UnsealedKey) -> Result<UnsealedEnvelope, AeadError> {
-        let cipher = Aes256GcmSiv::new(&key.into());
+        let cipher = Aes256GcmSiv::new(GenericArray::from_slice(key.expose_secret()));
         let plaintext = cipher.decrypt(&self.nonce, self.ciphertext.as_slice())?;
 
         Ok(UnsealedEnvelope {
             key,
             nonce: self.nonce,
-            plaintext,
+            plaintext: plaintext.into(),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{AeadError, SealedKey, UnsealedEnvelope};
+    use super::{AeadError, SealedKey, UnsealedEnvelope, UnsealedKey};
 
     #[test]
     fn seal_roundtrip() -> Result<(), AeadError> {
         let plaintext = b"plaintext".to_vec();
 
         let unsealed_envelope = UnsealedEnvelope::new(plaintext.clone());
-        let key = unsealed_envelope.key();
+        let key = UnsealedKey::new(*unsealed_envelope.key().expose_secret());
         let sealant: SealedKey = Default::default();
 
         let sealed_envelope = unsealed_envelope.seal(sealant.clone())?;
         assert_eq!(sealed_envelope.sealant, sealant);
         assert_eq!(sealed_envelope.nonce, unsealed_envelope.nonce);
-        assert_ne!(sealed_envelope.ciphertext, unsealed_envelope.plaintext);
+        assert_ne!(
+            &sealed_envelope.ciphertext,
+            unsealed_envelope.plaintext.expose_secret()
+        );
 
         let roundtripped_envelope = sealed_envelope.unseal(key)?;
         assert_eq!(roundtripped_envelope, unsealed_envelope);
-        assert_eq!(roundtripped_envelope.plaintext, plaintext);
+        assert_eq!(roundtripped_envelope.plaintext.expose_secret(), &plaintext);
 
         Ok(())
     }
+
+    #[test]
+    fn unsealed_envelope_debug_redacts_key_and_plaintext() {
+        let plaintext = b"plaintext".to_vec();
+        let unsealed_envelope = UnsealedEnvelope::new(plaintext.clone());
+        let debug = format!("{unsealed_envelope:?}");
+
+        assert!(debug.contains("REDACTED"));
+        assert!(!debug.contains(&format!("{:?}", unsealed_envelope.key().expose_secret())));
+        assert!(!debug.contains(&format!("{plaintext:?}")));
+    }
 }
@@ -62,7 +62,7 @@ pub use version::Version;
 pub use wipe_state::WipeState;
 
 pub type SealedKey = Vec<u8>;
-pub type UnsealedKey = [u8; 32];
+pub type UnsealedKey = crypto::secret::Secret<[u8; 32]>;
 pub type Signature = bitcoin::secp256k1::ecdsa::Signature;
 pub use bitcoin::psbt::PartiallySignedTransaction;
 pub use miniscript::DescriptorPublicKey;
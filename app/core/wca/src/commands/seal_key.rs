@@ -7,14 +7,14 @@ use crate::{
     wca,
 };
 
-use crate::command_interface::command;
+use crate::command_interface::{command, CommandFn};
 
 use super::{SealedKey, UnsealedKey};
 
 #[generator(yield(Vec<u8>), resume(Vec<u8>))]
 fn seal_key(key: UnsealedKey) -> Result<SealedKey, CommandError> {
     let apdu: apdu::Command = SealCsekCmd {
-        unsealed_csek: key.to_vec(),
+        unsealed_csek: key.expose_secret().to_vec(),
         csek: None, // TODO(W-5088)
     }
     .try_into()?;
@@ -44,6 +44,27 @@ fn seal_key(key: UnsealedKey) -> Result<SealedKey, CommandError> {
     }
 }
 
-command!(SealKey = seal_key -> SealedKey,
-    unsealed_csek: UnsealedKey
-);
+// Written out rather than generated by `command!`, which clones its arguments: `UnsealedKey` is
+// deliberately not `Clone`, so the generator is handed an explicit copy of the key instead.
+pub struct SealKey {
+    _lock: std::sync::RwLock<Vec<Vec<u8>>>,
+    unsealed_csek: UnsealedKey,
+}
+
+impl SealKey {
+    pub fn new(unsealed_csek: UnsealedKey) -> Self {
+        Self {
+            _lock: Default::default(),
+            unsealed_csek,
+        }
+    }
+
+    fn generator(&self) -> CommandFn<SealedKey, CommandError> {
+        let key = UnsealedKey::new(*self.unsealed_csek.expose_secret());
+        next_gen::generator_fn::CallBoxed::call_boxed(seal_key, (key,))
+    }
+}
+
+impl crate::command_interface::Command<SealedKey, CommandError> for SealKey {
+    command!(next_impl SealedKey);
+}
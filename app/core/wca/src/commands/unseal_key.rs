@@ -34,9 +34,10 @@ fn unseal_key(sealed_key: SealedKey) -> Result<UnsealedKey, CommandError> {
     {
         match UnsealCsekRspStatus::from_i32(rsp_status) {
             Some(UnsealCsekRspStatus::Unspecified) => Err(CommandError::UnspecifiedCommandError),
-            Some(UnsealCsekRspStatus::Success) => {
-                unsealed_csek.try_into().map_err(CommandError::KeySizeError)
-            }
+            Some(UnsealCsekRspStatus::Success) => unsealed_csek
+                .try_into()
+                .map(UnsealedKey::new)
+                .map_err(CommandError::KeySizeError),
             Some(UnsealCsekRspStatus::Error) => Err(CommandError::GeneralCommandError),
             Some(UnsealCsekRspStatus::UnsealError) => {
                 Err(CommandError::SealCsekResponseUnsealError)
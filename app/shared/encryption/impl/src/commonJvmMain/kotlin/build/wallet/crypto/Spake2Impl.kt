@@ -24,15 +24,16 @@ class Spake2Impl(role: Spake2Role, myName: String, theirName: String) : Spake2 {
     )
   }
 
-  override fun generateKeyConfMsg(keys: Spake2Keys): ByteString {
-    return ctx.generateKeyConfMsg(keys.toCore()).toByteString()
+  override fun generateKeyConfMsg(): ByteString {
+    return ctx.generateKeyConfMsg().toByteString()
   }
 
-  override fun processKeyConfMsg(
-    receivedMac: ByteString,
-    keys: Spake2Keys,
-  ) {
-    return ctx.processKeyConfMsg(receivedMac.toByteArray(), keys.toCore())
+  override fun processKeyConfMsg(receivedMac: ByteString) {
+    return ctx.processKeyConfMsg(receivedMac.toByteArray())
+  }
+
+  override fun sessionKey(): ByteString {
+    return ctx.sessionKey().toByteString()
   }
 }
 
@@ -42,12 +43,3 @@ fun Spake2Role.toCore(): build.wallet.core.Spake2Role {
     Spake2Role.Bob -> build.wallet.core.Spake2Role.BOB
   }
 }
-
-fun Spake2Keys.toCore(): build.wallet.core.Spake2Keys {
-  return build.wallet.core.Spake2Keys(
-    aliceEncryptionKey.toByteArray(),
-    bobEncryptionKey.toByteArray(),
-    aliceConfKey.toByteArray(),
-    bobConfKey.toByteArray()
-  )
-}
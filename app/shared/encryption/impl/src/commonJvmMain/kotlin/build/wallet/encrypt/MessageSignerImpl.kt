@@ -13,7 +13,7 @@ class MessageSignerImpl : MessageSigner {
     message: ByteString,
     key: Secp256k1PrivateKey,
   ): String {
-    val coreKey = CoreSecretKey(key.bytes.toUByteList())
+    val coreKey = CoreSecretKey(key.bytes.toByteArray())
     return coreKey.signMessage(message.toUByteList())
   }
 }
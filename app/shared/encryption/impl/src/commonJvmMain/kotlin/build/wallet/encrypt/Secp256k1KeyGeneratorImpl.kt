@@ -1,6 +1,5 @@
 package build.wallet.encrypt
 
-import build.wallet.toUByteList
 import okio.ByteString.Companion.toByteString
 import java.security.SecureRandom
 import build.wallet.core.SecretKey as CoreSecretKey
@@ -18,7 +17,7 @@ class Secp256k1KeyGeneratorImpl : Secp256k1KeyGenerator {
    * spend).
    */
   override fun derivePublicKey(privateKey: Secp256k1PrivateKey): Secp256k1PublicKey {
-    val coreSecretKey = CoreSecretKey(privateKey.bytes.toUByteList())
+    val coreSecretKey = CoreSecretKey(privateKey.bytes.toByteArray())
     return Secp256k1PublicKey(coreSecretKey.asPublic())
   }
 
@@ -26,7 +25,7 @@ class Secp256k1KeyGeneratorImpl : Secp256k1KeyGenerator {
     val randomBytes = ByteArray(32)
     SecureRandom().nextBytes(randomBytes)
     // Check whether the private key is valid by passing it to the Core constructor
-    CoreSecretKey(randomBytes.toUByteList())
+    CoreSecretKey(randomBytes)
     return Secp256k1PrivateKey(randomBytes.toByteString())
   }
 
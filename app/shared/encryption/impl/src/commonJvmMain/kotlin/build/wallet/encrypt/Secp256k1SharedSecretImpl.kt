@@ -1,6 +1,5 @@
 package build.wallet.encrypt
 
-import build.wallet.toUByteList
 import okio.ByteString
 import okio.ByteString.Companion.toByteString
 import build.wallet.core.Secp256k1SharedSecret as CoreSecp256k1SharedSecret
@@ -11,7 +10,7 @@ class Secp256k1SharedSecretImpl : Secp256k1SharedSecret {
     privateKey: Secp256k1PrivateKey,
     publicKey: Secp256k1PublicKey,
   ): ByteString {
-    val coreSecretKey = CoreSecretKey(privateKey.bytes.toUByteList())
+    val coreSecretKey = CoreSecretKey(privateKey.bytes.toByteArray())
     val sharedSecret = CoreSecp256k1SharedSecret(publicKey.value, coreSecretKey)
     return sharedSecret.secretBytes().toByteString()
   }
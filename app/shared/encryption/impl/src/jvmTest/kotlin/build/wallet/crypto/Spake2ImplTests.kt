@@ -67,44 +67,60 @@ class Spake2ImplTests : FunSpec({
     assertKeysMatch(aliceKeys, bobKeys, false)
   }
 
-  test("good round trip with confirmation") {
-    val (aliceKeys, bobKeys) =
-      performKeyExchange(
-        "password".toByteArray(),
-        "password".toByteArray()
-      )
-    assertKeysMatch(aliceKeys, bobKeys, true)
-
+  fun performKeyExchangeWithConfirmation(
+    alicePassword: ByteArray,
+    bobPassword: ByteArray,
+  ) {
     val alice = Spake2Impl(Spake2Role.Alice, "Alice", "Bob")
     val bob = Spake2Impl(Spake2Role.Bob, "Bob", "Alice")
 
-    val aliceKeyConfMsg = alice.generateKeyConfMsg(aliceKeys)
-    val bobKeyConfMsg = bob.generateKeyConfMsg(bobKeys)
+    val aliceMsg = alice.generateMsg(alicePassword.toByteString())
+    val bobMsg = bob.generateMsg(bobPassword.toByteString())
+
+    alice.processMsg(bobMsg, null)
+    bob.processMsg(aliceMsg, null)
 
-    alice.processKeyConfMsg(bobKeyConfMsg, aliceKeys)
-    bob.processKeyConfMsg(aliceKeyConfMsg, bobKeys)
+    val aliceKeyConfMsg = alice.generateKeyConfMsg()
+    val bobKeyConfMsg = bob.generateKeyConfMsg()
+
+    alice.processKeyConfMsg(bobKeyConfMsg)
+    bob.processKeyConfMsg(aliceKeyConfMsg)
+
+    alice.sessionKey() shouldBe bob.sessionKey()
+  }
+
+  test("good round trip with confirmation") {
+    performKeyExchangeWithConfirmation(
+      "password".toByteArray(),
+      "password".toByteArray()
+    )
   }
 
   test("confirmation fails when password is wrong") {
-    val (aliceKeys, bobKeys) =
-      performKeyExchange(
+    shouldThrow<Spake2Exception.MacException> {
+      performKeyExchangeWithConfirmation(
         "password".toByteArray(),
         "passworf".toByteArray()
       )
-    assertKeysMatch(aliceKeys, bobKeys, false)
+    }
+  }
 
+  test("session key is unavailable before confirmation") {
     val alice = Spake2Impl(Spake2Role.Alice, "Alice", "Bob")
     val bob = Spake2Impl(Spake2Role.Bob, "Bob", "Alice")
 
-    val aliceKeyConfMsg = alice.generateKeyConfMsg(aliceKeys)
-    val bobKeyConfMsg = bob.generateKeyConfMsg(bobKeys)
+    val aliceMsg = alice.generateMsg("password".toByteArray().toByteString())
+    val bobMsg = bob.generateMsg("password".toByteArray().toByteString())
+    alice.processMsg(bobMsg, null)
 
-    shouldThrow<Spake2Exception.MacException> {
-      alice.processKeyConfMsg(bobKeyConfMsg, aliceKeys)
+    shouldThrow<Spake2Exception.InvalidState> {
+      alice.sessionKey()
     }
-    shouldThrow<Spake2Exception.MacException> {
-      bob.processKeyConfMsg(aliceKeyConfMsg, bobKeys)
+    shouldThrow<Spake2Exception.InvalidState> {
+      bob.generateKeyConfMsg()
     }
+    bob.processMsg(aliceMsg, null)
+    bob.generateKeyConfMsg()
   }
 
   test("same password results in different keys across sessions") {
@@ -50,25 +50,30 @@ interface Spake2 {
   ): Spake2Keys
 
   /**
-   * Generates a key confirmation message using the derived keys.
+   * Generates a key confirmation message: a MAC over the protocol transcript (both identities and
+   * both messages) with this party's confirmation key. Must be called after `processMsg`.
    *
-   * @param keys The Spake2Keys object containing the derived keys.
    * @return A ByteString containing the key confirmation message to be sent to the other party.
-   * @throws Error If there's an issue generating the key confirmation message.
+   * @throws Error If called out of order or the MAC cannot be generated.
    */
   @Throws(Error::class)
-  fun generateKeyConfMsg(keys: Spake2Keys): ByteString
+  fun generateKeyConfMsg(): ByteString
 
   /**
-   * Processes the key confirmation message received from the other party.
+   * Verifies the key confirmation message received from the other party. Must be called after
+   * `processMsg`, and can only be called once.
    *
    * @param receivedMac The key confirmation message received from the other party, as a ByteString.
-   * @param keys The Spake2Keys object containing the derived keys.
-   * @throws Error If there's an issue verifying the key confirmation message.
+   * @throws Error If called out of order or the key confirmation message does not verify.
    */
   @Throws(Error::class)
-  fun processKeyConfMsg(
-    receivedMac: ByteString,
-    keys: Spake2Keys,
-  )
+  fun processKeyConfMsg(receivedMac: ByteString)
+
+  /**
+   * Returns the session key. Only available once `processKeyConfMsg` has succeeded.
+   *
+   * @throws Error If the other party's key confirmation has not been verified.
+   */
+  @Throws(Error::class)
+  fun sessionKey(): ByteString
 }
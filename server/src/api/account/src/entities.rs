@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::fmt;
 use std::{env, env::VarError};
 
 use bdk_utils::bdk::bitcoin::secp256k1::PublicKey;
@@ -14,7 +15,8 @@ use strum_macros::{Display as StrumDisplay, EnumString};
 use time::{serde::rfc3339, OffsetDateTime};
 use types::account::identifiers::TouchpointId;
 use types::account::identifiers::{AccountId, AuthKeysId, KeysetId};
-use types::notification::{NotificationChannel, NotificationsPreferences};
+use types::account::risk::RiskAssessment;
+use types::notification::{Locale, NotificationChannel, NotificationsPreferences};
 use utoipa::ToSchema;
 
 use crate::error::AccountError;
@@ -88,7 +90,7 @@ impl TouchpointPlatform {
     }
 }
 
-#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq)]
 #[serde(tag = "type")]
 pub enum Touchpoint {
     Email {
@@ -108,9 +110,76 @@ pub enum Touchpoint {
         platform: TouchpointPlatform,
         arn: String,
         device_token: String,
+        // Push touchpoints were always active before they could be deactivated, so ones persisted
+        // without the field are active
+        #[serde(default = "default_true")]
+        active: bool,
+    },
+    Webhook {
+        id: TouchpointId,
+        url: String,
+        // Shared with the account when the touchpoint is added and used to sign deliveries
+        secret: String,
+        #[serde(default)]
+        active: bool,
     },
 }
 
+fn default_true() -> bool {
+    true
+}
+
+// Written out by hand so that webhook secrets never end up in logs
+impl fmt::Debug for Touchpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Touchpoint::Email {
+                id,
+                email_address,
+                active,
+            } => f
+                .debug_struct("Email")
+                .field("id", id)
+                .field("email_address", email_address)
+                .field("active", active)
+                .finish(),
+            Touchpoint::Phone {
+                id,
+                phone_number,
+                country_code,
+                active,
+            } => f
+                .debug_struct("Phone")
+                .field("id", id)
+                .field("phone_number", phone_number)
+                .field("country_code", country_code)
+                .field("active", active)
+                .finish(),
+            Touchpoint::Push {
+                platform,
+                arn,
+                device_token,
+                active,
+            } => f
+                .debug_struct("Push")
+                .field("platform", platform)
+                .field("arn", arn)
+                .field("device_token", device_token)
+                .field("active", active)
+                .finish(),
+            Touchpoint::Webhook {
+                id, url, active, ..
+            } => f
+                .debug_struct("Webhook")
+                .field("id", id)
+                .field("url", url)
+                .field("secret", &"<redacted>")
+                .field("active", active)
+                .finish(),
+        }
+    }
+}
+
 impl Touchpoint {
     pub fn new_phone(
         id: TouchpointId,
@@ -134,10 +203,21 @@ impl Touchpoint {
         }
     }
 
+    pub fn new_webhook(id: TouchpointId, url: String, secret: String, active: bool) -> Self {
+        Touchpoint::Webhook {
+            id,
+            url,
+            secret,
+            active,
+        }
+    }
+
     pub fn is_active(&self) -> bool {
         match self {
-            Touchpoint::Email { active, .. } | Touchpoint::Phone { active, .. } => *active,
-            Touchpoint::Push { .. } => true,
+            Touchpoint::Email { active, .. }
+            | Touchpoint::Phone { active, .. }
+            | Touchpoint::Push { active, .. }
+            | Touchpoint::Webhook { active, .. } => *active,
         }
     }
 }
@@ -148,6 +228,7 @@ impl From<&Touchpoint> for NotificationChannel {
             Touchpoint::Email { .. } => NotificationChannel::Email,
             Touchpoint::Push { .. } => NotificationChannel::Push,
             Touchpoint::Phone { .. } => NotificationChannel::Sms,
+            Touchpoint::Webhook { .. } => NotificationChannel::Webhook,
         }
     }
 }
@@ -257,6 +338,12 @@ pub struct CommonAccountFields {
     pub recovery_auth_pubkey: Option<PublicKey>,
     #[serde(default)]
     pub notifications_preferences: NotificationsPreferences,
+    // The language notifications are sent in
+    #[serde(default)]
+    pub locale: Locale,
+    // The most recent onboarding risk assessment, for support
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub risk_assessment: Option<RiskAssessment>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -277,10 +364,25 @@ pub struct FullAccount {
     pub comms_verification_claims: Vec<CommsVerificationClaim>,
     #[serde(default)]
     pub auth_keys: HashMap<AuthKeysId, FullAccountAuthKeys>,
+    // Customer-chosen Delay & Notify period; the account type's default applies when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery_delay_period_num_sec: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_recovery_delay_period: Option<PendingRecoveryDelayPeriod>,
     #[serde(flatten)]
     pub common_fields: CommonAccountFields,
 }
 
+// A shortened Delay & Notify period waits out the current one before it takes effect
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PendingRecoveryDelayPeriod {
+    pub delay_period_num_sec: i64,
+    #[serde(with = "rfc3339")]
+    pub requested_at: OffsetDateTime,
+    #[serde(with = "rfc3339")]
+    pub effective_at: OffsetDateTime,
+}
+
 impl FullAccount {
     #[must_use]
     pub fn new(
@@ -304,6 +406,8 @@ impl FullAccount {
             application_auth_pubkey,
             hardware_auth_pubkey,
             comms_verification_claims: vec![],
+            recovery_delay_period_num_sec: None,
+            pending_recovery_delay_period: None,
             common_fields: CommonAccountFields {
                 active_auth_keys_id,
                 touchpoints: vec![],
@@ -313,6 +417,8 @@ impl FullAccount {
                 onboarding_complete: false,
                 recovery_auth_pubkey,
                 notifications_preferences: Default::default(),
+                locale: Default::default(),
+                risk_assessment: None,
             },
         }
     }
@@ -369,6 +475,8 @@ impl LiteAccount {
                 onboarding_complete: true,
                 recovery_auth_pubkey,
                 notifications_preferences: Default::default(),
+                locale: Default::default(),
+                risk_assessment: None,
             },
         }
     }
@@ -393,6 +501,8 @@ impl LiteAccount {
             hardware_auth_pubkey: auth_keys.hardware_pubkey,
             comms_verification_claims: vec![],
             auth_keys: HashMap::from([(auth_keys_id.clone(), auth_keys)]),
+            recovery_delay_period_num_sec: None,
+            pending_recovery_delay_period: None,
             common_fields: CommonAccountFields {
                 active_auth_keys_id: auth_keys_id,
                 onboarding_complete: false,
@@ -461,10 +571,18 @@ impl Account {
             .find(|t| matches!(t, Touchpoint::Phone { phone_number: p, .. } if *p == phone_number))
     }
 
+    pub fn get_touchpoint_by_webhook_url(&self, url: String) -> Option<&Touchpoint> {
+        self.get_common_fields()
+            .touchpoints
+            .iter()
+            .find(|t| matches!(t, Touchpoint::Webhook { url: u, .. } if *u == url))
+    }
+
     pub fn get_touchpoint_by_id(&self, touchpoint_id: TouchpointId) -> Option<&Touchpoint> {
         self.get_common_fields().touchpoints.iter().find(|t| {
             matches!(t, Touchpoint::Phone { id, .. } if *id == touchpoint_id)
                 || matches!(t, Touchpoint::Email { id, .. } if *id == touchpoint_id)
+                || matches!(t, Touchpoint::Webhook { id, .. } if *id == touchpoint_id)
         })
     }
 
@@ -472,7 +590,7 @@ impl Account {
         self.get_common_fields()
             .touchpoints
             .iter()
-            .find(|t| matches!(t, Touchpoint::Push { .. }))
+            .find(|t| matches!(t, Touchpoint::Push { .. }) && t.is_active())
     }
 
     pub fn get_push_touchpoint_by_platform_and_device_token(
@@ -639,11 +757,11 @@ impl From<Account> for PubkeysToAccount {
 #[cfg(test)]
 mod tests {
 
-    use crate::entities::{CommonAccountFields, FullAccount, TouchpointPlatform};
+    use crate::entities::{CommonAccountFields, FullAccount, Touchpoint, TouchpointPlatform};
     use crate::spend_limit::SpendingLimit;
     use bdk_utils::bdk::bitcoin::secp256k1::PublicKey;
     use time::OffsetDateTime;
-    use types::account::identifiers::{AccountId, AuthKeysId, KeysetId};
+    use types::account::identifiers::{AccountId, AuthKeysId, KeysetId, TouchpointId};
 
     #[test]
     fn test_is_spending_limit_active() {
@@ -659,6 +777,8 @@ mod tests {
             application_auth_pubkey: None,
             hardware_auth_pubkey: PublicKey::from_slice(&pubkey).unwrap(),
             comms_verification_claims: vec![],
+            recovery_delay_period_num_sec: None,
+            pending_recovery_delay_period: None,
             common_fields: CommonAccountFields {
                 active_auth_keys_id: AuthKeysId::gen().unwrap(),
                 touchpoints: vec![],
@@ -668,6 +788,8 @@ mod tests {
                 onboarding_complete: false,
                 recovery_auth_pubkey: None,
                 notifications_preferences: Default::default(),
+                locale: Default::default(),
+                risk_assessment: None,
             },
         };
         let set_and_enabled_spending_limit_account = FullAccount {
@@ -714,4 +836,17 @@ mod tests {
             TouchpointPlatform::FcmTeam
         ));
     }
+
+    #[test]
+    fn test_webhook_touchpoint_debug_redacts_secret() {
+        let touchpoint = Touchpoint::new_webhook(
+            TouchpointId::gen().unwrap(),
+            "https://example.com/hooks/bitkey".to_string(),
+            "webhook-secret".to_string(),
+            true,
+        );
+        let debug = format!("{touchpoint:?}");
+        assert!(debug.contains("https://example.com/hooks/bitkey"));
+        assert!(!debug.contains("webhook-secret"));
+    }
 }
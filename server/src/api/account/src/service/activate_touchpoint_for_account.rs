@@ -36,6 +36,14 @@ impl Service {
                     country_code.to_owned(),
                     true,
                 )),
+                Touchpoint::Webhook {
+                    id, url, secret, ..
+                } => Ok(Touchpoint::new_webhook(
+                    id.to_owned(),
+                    url.to_owned(),
+                    secret.to_owned(),
+                    true,
+                )),
                 _ => Err(AccountError::Unexpected),
             }
         } else {
@@ -53,6 +61,9 @@ impl Service {
             Touchpoint::Phone { .. } => {
                 touchpoints.retain(|t| !matches!(t, Touchpoint::Phone { .. }));
             }
+            Touchpoint::Webhook { .. } => {
+                touchpoints.retain(|t| !matches!(t, Touchpoint::Webhook { .. }));
+            }
             _ => {}
         }
 
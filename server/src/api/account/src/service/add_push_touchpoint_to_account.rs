@@ -17,19 +17,23 @@ impl Service {
         let mut account = self.repo.fetch(&input.account_id).await?;
 
         let device_token = input.device_token.clone();
-        if let Some(touchpoint) = account.get_common_fields().touchpoints.iter().find(|t| {
+        let existing_touchpoint = account.get_common_fields().touchpoints.iter().find(|t| {
             if let Touchpoint::Push {
                 platform: _,
                 arn: _,
                 device_token,
+                active: _,
             } = t
             {
                 *device_token == input.device_token
             } else {
                 false
             }
-        }) {
-            return Ok(touchpoint.to_owned());
+        });
+        if let Some(touchpoint) = existing_touchpoint {
+            if touchpoint.is_active() {
+                return Ok(touchpoint.to_owned());
+            }
         }
 
         let device_arn = generate_device_arn(
@@ -51,17 +55,20 @@ impl Service {
         let new_touchpoint = Touchpoint::Push {
             platform: input.platform,
             arn: device_arn.to_string(),
-            device_token,
+            device_token: device_token.clone(),
+            active: true,
         };
+
+        // A deactivated touchpoint for the same device token is replaced by the new one, which has
+        // a fresh endpoint
+        let mut touchpoints = account.get_common_fields().touchpoints.clone();
+        touchpoints.retain(
+            |t| !matches!(t, Touchpoint::Push { device_token: token, .. } if *token == device_token),
+        );
+        touchpoints.push(new_touchpoint.clone());
         match &mut account {
-            Account::Full(full_account) => full_account
-                .common_fields
-                .touchpoints
-                .push(new_touchpoint.clone()),
-            Account::Lite(lite_account) => lite_account
-                .common_fields
-                .touchpoints
-                .push(new_touchpoint.clone()),
+            Account::Full(full_account) => full_account.common_fields.touchpoints = touchpoints,
+            Account::Lite(lite_account) => lite_account.common_fields.touchpoints = touchpoints,
         };
 
         self.repo.persist(&account).await?;
@@ -12,7 +12,8 @@ impl Service {
     ) -> Result<FullAccount, AccountError> {
         let account_id = input.clone().account_id;
         let is_test_account = input.is_test_account;
-        let full_account = FullAccount::new(
+        let risk_assessment = input.risk_assessment.clone();
+        let mut full_account = FullAccount::new(
             account_id,
             input.clone().keyset_id,
             input.clone().auth_key_id,
@@ -23,6 +24,7 @@ impl Service {
                 ..AccountProperties::default()
             },
         );
+        full_account.common_fields.risk_assessment = risk_assessment;
         self.repo.persist(&full_account.clone().into()).await?;
         Ok(full_account)
     }
@@ -12,7 +12,7 @@ impl Service {
     ) -> Result<LiteAccount, AccountError> {
         let account_id = input.clone().account_id.to_owned();
         let is_test_account = input.is_test_account;
-        let lite_account = LiteAccount::new(
+        let mut lite_account = LiteAccount::new(
             account_id,
             input.clone().auth_key_id,
             input.clone().auth,
@@ -21,6 +21,7 @@ impl Service {
                 ..AccountProperties::default()
             },
         );
+        lite_account.common_fields.risk_assessment = input.risk_assessment;
         self.repo.persist(&lite_account.clone().into()).await?;
         Ok(lite_account)
     }
@@ -0,0 +1,39 @@
+use crate::{
+    entities::{Account, Touchpoint},
+    error::AccountError,
+};
+
+use super::{DeactivatePushTouchpointInput, Service};
+
+impl Service {
+    /// Deactivates the push touchpoint for a device token that's no longer valid, e.g. because the
+    /// app was uninstalled. The app reactivates it the next time it registers for push
+    /// notifications.
+    pub async fn deactivate_push_touchpoint(
+        &self,
+        input: DeactivatePushTouchpointInput<'_>,
+    ) -> Result<(), AccountError> {
+        let mut account = self.repo.fetch(input.account_id).await?;
+        let mut touchpoints: Vec<Touchpoint> = account.get_common_fields().touchpoints.clone();
+
+        for t in touchpoints.iter_mut() {
+            if let Touchpoint::Push {
+                device_token,
+                active,
+                ..
+            } = t
+            {
+                if device_token == input.device_token {
+                    *active = false;
+                }
+            }
+        }
+
+        match &mut account {
+            Account::Full(full_account) => full_account.common_fields.touchpoints = touchpoints,
+            Account::Lite(lite_account) => lite_account.common_fields.touchpoints = touchpoints,
+        };
+        self.repo.persist(&account).await?;
+        Ok(())
+    }
+}
@@ -7,8 +7,8 @@ use crate::{
 };
 
 use super::{
-    FetchOrCreateEmailTouchpointInput, FetchOrCreatePhoneTouchpointInput, FetchTouchpointByIdInput,
-    Service,
+    FetchOrCreateEmailTouchpointInput, FetchOrCreatePhoneTouchpointInput,
+    FetchOrCreateWebhookTouchpointInput, FetchTouchpointByIdInput, Service,
 };
 
 impl Service {
@@ -97,4 +97,36 @@ impl Service {
 
         Ok(new_touchpoint)
     }
+
+    /// Returns the webhook touchpoint for the given URL, creating an inactive one with the given
+    /// secret if none exists. An existing touchpoint keeps the secret it was created with.
+    pub async fn fetch_or_create_webhook_touchpoint(
+        &self,
+        input: FetchOrCreateWebhookTouchpointInput,
+    ) -> Result<Touchpoint, AccountError> {
+        let account = self.repo.fetch(&input.account_id).await?;
+
+        if let Some(existing_touchpoint) = account.get_touchpoint_by_webhook_url(input.url.clone())
+        {
+            return Ok(existing_touchpoint.to_owned());
+        }
+
+        // Purge inactive webhook touchpoints
+        let common = account.get_common_fields().to_owned();
+        let mut touchpoints = common.touchpoints;
+        touchpoints.retain(|t| !matches!(t, Touchpoint::Webhook { active: false, .. }));
+
+        let new_touchpoint =
+            Touchpoint::new_webhook(TouchpointId::gen()?, input.url, input.secret, false);
+
+        // Add new touchpoint
+        touchpoints.push(new_touchpoint.to_owned());
+        let updated_account = account.update(CommonAccountFields {
+            touchpoints,
+            ..common
+        })?;
+        self.repo.persist(&updated_account).await?;
+
+        Ok(new_touchpoint)
+    }
 }
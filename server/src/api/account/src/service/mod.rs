@@ -1,10 +1,13 @@
+use std::fmt;
+
 use bdk_utils::bdk::bitcoin::secp256k1::PublicKey;
 use isocountry::CountryCode;
 use types::account::identifiers::{AccountId, AuthKeysId, KeysetId, TouchpointId};
+use types::account::risk::RiskAssessment;
 
 use crate::entities::{
     CommsVerificationClaim, CommsVerificationScope, FullAccountAuthKeys, LiteAccount,
-    LiteAccountAuthKeys, SpendingKeyset, TouchpointPlatform,
+    LiteAccountAuthKeys, PendingRecoveryDelayPeriod, SpendingKeyset, TouchpointPlatform,
 };
 use crate::spend_limit::SpendingLimit;
 use crate::{
@@ -20,6 +23,7 @@ mod create_account_and_keysets;
 mod create_and_rotate_auth_keys;
 mod create_inactive_spending_keyset;
 mod create_lite_account;
+mod deactivate_push_touchpoint;
 mod delete_account;
 mod fetch_account;
 mod fetch_and_update_spend_limit;
@@ -28,6 +32,8 @@ mod fetch_touchpoint;
 mod migrations;
 mod put_comms_verification_claim;
 mod rotate_to_spending_keyset;
+mod update_recovery_delay_period;
+mod update_risk_assessment;
 mod upgrade_lite_account_to_full_account;
 
 #[derive(Clone)]
@@ -67,6 +73,7 @@ pub struct CreateAccountAndKeysetsInput {
     // TODO [BKR-518]: Clean up keysets
     pub keyset: Keyset,
     pub is_test_account: bool,
+    pub risk_assessment: Option<RiskAssessment>,
 }
 
 #[derive(Debug, Clone)]
@@ -84,6 +91,7 @@ pub struct CreateLiteAccountInput<'a> {
     pub auth_key_id: AuthKeysId,
     pub auth: LiteAccountAuthKeys,
     pub is_test_account: bool,
+    pub risk_assessment: Option<RiskAssessment>,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +120,23 @@ pub struct FetchOrCreateEmailTouchpointInput {
     pub email_address: String,
 }
 
+#[derive(Clone)]
+pub struct FetchOrCreateWebhookTouchpointInput {
+    pub account_id: AccountId,
+    pub url: String,
+    pub secret: String,
+}
+
+impl fmt::Debug for FetchOrCreateWebhookTouchpointInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FetchOrCreateWebhookTouchpointInput")
+            .field("account_id", &self.account_id)
+            .field("url", &self.url)
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ActivateTouchpointForAccountInput {
     pub account_id: AccountId,
@@ -148,6 +173,19 @@ pub struct RotateToSpendingKeysetInput<'a> {
     pub keyset_id: &'a KeysetId,
 }
 
+#[derive(Debug, Clone)]
+pub struct UpdateRecoveryDelayPeriodInput<'a> {
+    pub account_id: &'a AccountId,
+    pub recovery_delay_period_num_sec: Option<i64>,
+    pub pending_recovery_delay_period: Option<PendingRecoveryDelayPeriod>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateRiskAssessmentInput<'a> {
+    pub account_id: &'a AccountId,
+    pub risk_assessment: RiskAssessment,
+}
+
 #[derive(Debug, Clone)]
 pub struct CompleteOnboardingInput<'a> {
     pub account_id: &'a AccountId,
@@ -158,6 +196,12 @@ pub struct ClearPushTouchpointsInput<'a> {
     pub account_id: &'a AccountId,
 }
 
+#[derive(Debug, Clone)]
+pub struct DeactivatePushTouchpointInput<'a> {
+    pub account_id: &'a AccountId,
+    pub device_token: &'a str,
+}
+
 #[derive(Clone)]
 pub struct UpgradeLiteAccountToFullAccountInput<'a> {
     pub lite_account: &'a LiteAccount,
@@ -165,6 +209,7 @@ pub struct UpgradeLiteAccountToFullAccountInput<'a> {
     pub spending_keyset: SpendingKeyset,
     pub auth_key_id: AuthKeysId,
     pub auth_keys: FullAccountAuthKeys,
+    pub risk_assessment: RiskAssessment,
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,25 @@
+use super::{FetchAccountInput, Service, UpdateRecoveryDelayPeriodInput};
+use crate::entities::FullAccount;
+use crate::error::AccountError;
+
+impl Service {
+    pub async fn update_recovery_delay_period(
+        &self,
+        input: UpdateRecoveryDelayPeriodInput<'_>,
+    ) -> Result<FullAccount, AccountError> {
+        let full_account = self
+            .fetch_full_account(FetchAccountInput {
+                account_id: input.account_id,
+            })
+            .await?;
+
+        let updated_account = FullAccount {
+            recovery_delay_period_num_sec: input.recovery_delay_period_num_sec,
+            pending_recovery_delay_period: input.pending_recovery_delay_period,
+            ..full_account
+        };
+
+        self.repo.persist(&updated_account.clone().into()).await?;
+        Ok(updated_account)
+    }
+}
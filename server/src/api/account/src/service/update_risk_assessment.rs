@@ -0,0 +1,19 @@
+use crate::entities::CommonAccountFields;
+use crate::error::AccountError;
+
+use super::{Service, UpdateRiskAssessmentInput};
+
+impl Service {
+    pub async fn update_risk_assessment(
+        &self,
+        input: UpdateRiskAssessmentInput<'_>,
+    ) -> Result<(), AccountError> {
+        let account = self.repo.fetch(input.account_id).await?;
+        let updated_account = account.update(CommonAccountFields {
+            risk_assessment: Some(input.risk_assessment),
+            ..account.get_common_fields().clone()
+        })?;
+        self.repo.persist(&updated_account).await?;
+        Ok(())
+    }
+}
@@ -7,12 +7,13 @@ impl Service {
         &self,
         input: UpgradeLiteAccountToFullAccountInput<'_>,
     ) -> Result<FullAccount, AccountError> {
-        let full_account = input.lite_account.upgrade_to_full_account(
+        let mut full_account = input.lite_account.upgrade_to_full_account(
             input.keyset_id,
             input.spending_keyset,
             input.auth_key_id,
             input.auth_keys,
         );
+        full_account.common_fields.risk_assessment = Some(input.risk_assessment);
         self.repo.persist(&full_account.clone().into()).await?;
         Ok(full_account)
     }
@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+
+use crate::destination::Destination;
+use crate::errors::AnalyticsError;
+use crate::routes::definitions::EventBundle;
+
+/// Appends events to a file as newline-delimited JSON, for local development and tests.
+#[derive(Clone, Debug)]
+pub struct FileTracker {
+    pub path: PathBuf,
+}
+
+impl Destination for FileTracker {
+    async fn track(&self, events: EventBundle) -> Result<(), AnalyticsError> {
+        let mut lines = String::new();
+        for event in events.events {
+            let str_event = serde_json::to_string(&event).map_err(|_| {
+                AnalyticsError::InvalidEvent(String::from("File track failed to unpack the event"))
+            })?;
+            lines.push_str(&str_event);
+            lines.push('\n');
+        }
+
+        // Write the whole bundle at once so concurrent writers don't interleave events
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| self.write_error(e))?;
+        file.write_all(lines.as_bytes())
+            .await
+            .map_err(|e| self.write_error(e))
+    }
+}
+
+impl FileTracker {
+    fn write_error(&self, e: std::io::Error) -> AnalyticsError {
+        AnalyticsError::DestinationError(format!(
+            "Error writing events to {}: {e}",
+            self.path.display()
+        ))
+    }
+}
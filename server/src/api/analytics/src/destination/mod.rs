@@ -3,6 +3,7 @@ use crate::routes::definitions::EventBundle;
 
 use serde::Deserialize;
 
+pub mod file_tracker;
 pub mod segment_tracker;
 pub mod stdout_tracker;
 pub mod tracker;
@@ -11,6 +12,7 @@ pub mod tracker;
 pub enum AnalyticsDestinationType {
     Segment,
     Stdout,
+    File,
 }
 
 #[trait_variant::make(Destination: Send)]
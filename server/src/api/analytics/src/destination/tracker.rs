@@ -1,5 +1,8 @@
 use tracing::instrument;
 
+use std::path::PathBuf;
+
+use crate::destination::file_tracker::FileTracker;
 use crate::destination::stdout_tracker::StdoutTracker;
 use crate::destination::{AnalyticsDestinationType, Destination};
 use crate::errors::AnalyticsError;
@@ -12,6 +15,7 @@ pub struct Tracker {
     destination: AnalyticsDestinationType,
     segment_tracker: Option<SegmentTracker>,
     stdout_tracker: Option<StdoutTracker>,
+    file_tracker: Option<FileTracker>,
 }
 
 impl Tracker {
@@ -19,17 +23,28 @@ impl Tracker {
         destination_type: AnalyticsDestinationType,
         api_key: String,
         api_url: String,
+        file_path: String,
     ) -> Tracker {
         match destination_type {
             AnalyticsDestinationType::Segment => Tracker {
                 destination: destination_type,
                 segment_tracker: Some(SegmentTracker { api_key, api_url }),
                 stdout_tracker: None,
+                file_tracker: None,
             },
             AnalyticsDestinationType::Stdout => Tracker {
                 destination: destination_type,
                 segment_tracker: None,
                 stdout_tracker: Some(StdoutTracker {}),
+                file_tracker: None,
+            },
+            AnalyticsDestinationType::File => Tracker {
+                destination: destination_type,
+                segment_tracker: None,
+                stdout_tracker: None,
+                file_tracker: Some(FileTracker {
+                    path: PathBuf::from(file_path),
+                }),
             },
         }
     }
@@ -53,6 +68,12 @@ impl Tracker {
                 })?;
                 tracker.track(events).await
             }
+            AnalyticsDestinationType::File => {
+                let tracker = self.file_tracker.as_ref().ok_or_else(|| {
+                    AnalyticsError::DestinationError(String::from("File tracker is not available"))
+                })?;
+                tracker.track(events).await
+            }
         }
     }
 }
@@ -1,3 +1,6 @@
 pub mod destination;
 pub mod errors;
+pub mod pipeline;
 pub mod routes;
+pub mod scrub;
+pub mod validation;
@@ -0,0 +1,119 @@
+use queue::sqs::SqsQueue;
+use serde::Deserialize;
+use tracing::{event, instrument, Level};
+
+use crate::destination::tracker::Tracker;
+use crate::errors::AnalyticsError;
+use crate::routes::definitions::EventBundle;
+use crate::scrub::scrub_event;
+use crate::validation::validate_event;
+
+pub const ANALYTICS_QUEUE_ENV_VAR: &str = "ANALYTICS_QUEUE_URL";
+
+/// How the events endpoint delivers events to the destination.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryMode {
+    /// Track events while handling the request.
+    #[default]
+    Inline,
+    /// Enqueue events on SQS for the analytics worker to batch and deliver.
+    Queued,
+}
+
+#[derive(Clone)]
+pub enum EventPipeline {
+    Inline(Tracker),
+    Queued { sqs: SqsQueue, queue_url: String },
+}
+
+impl EventPipeline {
+    /// Validates and scrubs the events, then tracks or enqueues the ones that conform to the
+    /// schema.
+    #[instrument(skip(self, bundle))]
+    pub async fn submit(&self, bundle: EventBundle) -> Result<(), AnalyticsError> {
+        let bundle = prepare_events(bundle);
+        if bundle.events.is_empty() {
+            return Ok(());
+        }
+
+        match self {
+            Self::Inline(tracker) => tracker.track(bundle).await,
+            Self::Queued { sqs, queue_url } => {
+                let message = serde_json::to_string(&bundle).map_err(|e| {
+                    AnalyticsError::InvalidEvent(format!("Error serializing events: {e}"))
+                })?;
+                sqs.enqueue(queue_url, &message).await.map_err(|e| {
+                    AnalyticsError::DestinationError(format!("Error enqueueing events: {e}"))
+                })
+            }
+        }
+    }
+}
+
+/// Drops events that don't conform to the schema, and scrubs PII from the rest. Invalid events are
+/// dropped individually so that one bad event doesn't lose the rest of the bundle.
+pub fn prepare_events(bundle: EventBundle) -> EventBundle {
+    let events = bundle
+        .events
+        .into_iter()
+        .filter_map(|analytics_event| match validate_event(&analytics_event) {
+            Ok(()) => Some(scrub_event(analytics_event)),
+            Err(e) => {
+                event!(Level::WARN, "Dropping invalid analytics event: {e:?}");
+                None
+            }
+        })
+        .collect();
+    EventBundle { events }
+}
+
+/// Decodes a message enqueued by [`EventPipeline::submit`].
+pub fn decode_queued_events(message: &str) -> Result<EventBundle, AnalyticsError> {
+    serde_json::from_str(message)
+        .map_err(|e| AnalyticsError::InvalidEvent(format!("Error deserializing events: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use queue::sqs::SqsQueue;
+
+    use crate::pipeline::{decode_queued_events, EventPipeline};
+    use crate::routes::definitions::{Action, Event, EventBundle, HardwareInfo};
+
+    #[tokio::test]
+    async fn test_queued_pipeline_enqueues_valid_scrubbed_events() {
+        let sqs = SqsQueue::Test(Default::default());
+        let pipeline = EventPipeline::Queued {
+            sqs: sqs.clone(),
+            queue_url: String::new(),
+        };
+        let valid_event = Event {
+            event_time: String::from("2024-03-14T12:00:00Z"),
+            action: Action::AppOpenInitialize as i32,
+            app_device_id: String::from("test-app-device-id"),
+            hw_info: Some(HardwareInfo {
+                serial_number: String::from("test-serial-number"),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let invalid_event = Event {
+            event_time: String::from("test-time"),
+            ..valid_event.clone()
+        };
+
+        pipeline
+            .submit(EventBundle {
+                events: vec![valid_event, invalid_event],
+            })
+            .await
+            .unwrap();
+
+        let messages = sqs.fetch_messages("").await.unwrap();
+        assert_eq!(messages.len(), 1);
+        let bundle = decode_queued_events(messages[0].body().unwrap()).unwrap();
+        assert_eq!(bundle.events.len(), 1);
+        assert_eq!(bundle.events[0].hw_info.as_ref().unwrap().serial_number, "");
+    }
+}
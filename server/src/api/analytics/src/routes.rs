@@ -5,12 +5,14 @@ use axum::extract::State;
 use axum::routing::post;
 use axum::Router;
 use prost::Message;
+use queue::sqs::SqsQueue;
 use serde::Deserialize;
 use tracing::{event, Level};
 
 use crate::destination::tracker::Tracker;
 use crate::destination::AnalyticsDestinationType;
 use crate::errors::{AnalyticsError, ApiError};
+use crate::pipeline::{DeliveryMode, EventPipeline, ANALYTICS_QUEUE_ENV_VAR};
 
 use self::definitions::EventBundle;
 
@@ -20,7 +22,7 @@ pub mod definitions {
 }
 
 #[derive(Clone, axum_macros::FromRef)]
-pub struct RouteState(pub Tracker);
+pub struct RouteState(pub EventPipeline);
 
 impl From<RouteState> for Router {
     fn from(state: RouteState) -> Self {
@@ -34,31 +36,52 @@ impl From<RouteState> for Router {
 pub struct Config {
     pub analytics_destination: AnalyticsDestinationType,
     pub analytics_api_url: String,
+    /// Where the File destination writes events.
+    #[serde(default = "default_analytics_file_path")]
+    pub analytics_file_path: String,
+    #[serde(default)]
+    pub analytics_delivery: DeliveryMode,
+}
+
+fn default_analytics_file_path() -> String {
+    String::from("analytics-events.ndjson")
 }
 
 impl Config {
-    pub fn to_state(self) -> RouteState {
+    pub fn to_tracker(self) -> Tracker {
         let api_key = match self.analytics_destination {
             AnalyticsDestinationType::Segment => {
                 env::var("SEGMENT_API_KEY").expect("SEGMENT_API_KEY environment variable not set")
             }
-            AnalyticsDestinationType::Stdout => String::from(""),
+            AnalyticsDestinationType::Stdout | AnalyticsDestinationType::File => String::from(""),
         };
 
-        RouteState(Tracker::new(
+        Tracker::new(
             self.analytics_destination,
             api_key,
             self.analytics_api_url,
-        ))
+            self.analytics_file_path,
+        )
+    }
+
+    pub fn to_state(self, sqs: SqsQueue) -> RouteState {
+        let pipeline = match self.analytics_delivery {
+            DeliveryMode::Inline => EventPipeline::Inline(self.to_tracker()),
+            DeliveryMode::Queued => EventPipeline::Queued {
+                sqs,
+                queue_url: env::var(ANALYTICS_QUEUE_ENV_VAR).unwrap_or_default(),
+            },
+        };
+        RouteState(pipeline)
     }
 }
 
 pub async fn record_event(
-    State(tracker): State<Tracker>,
+    State(pipeline): State<EventPipeline>,
     events_blob: Bytes,
 ) -> Result<(), ApiError> {
     let events = decode_event_bundle(events_blob)?;
-    tracker.track(events).await.map_err(ApiError::from)
+    pipeline.submit(events).await.map_err(ApiError::from)
 }
 
 fn decode_event_bundle(blob: Bytes) -> Result<EventBundle, AnalyticsError> {
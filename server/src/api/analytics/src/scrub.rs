@@ -0,0 +1,70 @@
+use crate::routes::definitions::{Event, HardwareInfo, PlatformInfo};
+
+/// Removes personally identifying fields from an event before it leaves the server. Events keep
+/// the pseudonymous identifiers (account, app device, installation and session IDs) that analytics
+/// relies on, but not the hardware serial number or the phone's own device identifier.
+pub fn scrub_event(mut event: Event) -> Event {
+    // Every field is listed, so adding one to the definitions doesn't compile until it's decided
+    // here whether it identifies a person.
+    if let Some(HardwareInfo {
+        // Firmware and model are shared by every device of a kind. The manufacture info is a few
+        // characters of the serial number identifying the production run, not the device.
+        firmware_version: _,
+        hw_model: _,
+        hw_manufacture_info: _,
+        hw_paired: _,
+        serial_number,
+    }) = event.hw_info.as_mut()
+    {
+        serial_number.clear();
+    }
+    if let Some(PlatformInfo {
+        // These describe the app and the kind of phone it runs on, which many customers share.
+        client_type: _,
+        application_version: _,
+        os_type: _,
+        os_version: _,
+        device_make: _,
+        device_model: _,
+        app_id: _,
+        device_id,
+    }) = event.platform_info.as_mut()
+    {
+        device_id.clear();
+    }
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::definitions::{Event, HardwareInfo, PlatformInfo};
+    use crate::scrub::scrub_event;
+
+    #[test]
+    fn test_scrub_event() {
+        let event = Event {
+            app_device_id: String::from("test-app-device-id"),
+            hw_info: Some(HardwareInfo {
+                hw_model: String::from("test-hw-model"),
+                serial_number: String::from("test-serial-number"),
+                ..Default::default()
+            }),
+            platform_info: Some(PlatformInfo {
+                device_id: String::from("test-device-id"),
+                os_version: String::from("test-os-version"),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let scrubbed = scrub_event(event);
+
+        let hw_info = scrubbed.hw_info.unwrap();
+        assert_eq!(hw_info.serial_number, "");
+        assert_eq!(hw_info.hw_model, "test-hw-model");
+        let platform_info = scrubbed.platform_info.unwrap();
+        assert_eq!(platform_info.device_id, "");
+        assert_eq!(platform_info.os_version, "test-os-version");
+        assert_eq!(scrubbed.app_device_id, "test-app-device-id");
+    }
+}
@@ -0,0 +1,87 @@
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::errors::AnalyticsError;
+use crate::routes::definitions::{Action, Client, Event, OsType};
+
+/// The protobuf package events are validated against.
+pub const EVENT_SCHEMA: &str = "build.wallet.analytics.v1";
+
+/// Checks that an event conforms to the [`EVENT_SCHEMA`] beyond what protobuf decoding enforces:
+/// proto3 decodes unknown enum values and missing fields without complaint.
+pub fn validate_event(event: &Event) -> Result<(), AnalyticsError> {
+    let invalid = |reason: &str| {
+        Err(AnalyticsError::InvalidEvent(format!(
+            "{EVENT_SCHEMA}: {reason}"
+        )))
+    };
+
+    if OffsetDateTime::parse(&event.event_time, &Rfc3339).is_err() {
+        return invalid("event_time must be an RFC 3339 timestamp");
+    }
+    match Action::try_from(event.action) {
+        Ok(Action::Unspecified) | Err(_) => return invalid("action must be a known action"),
+        Ok(_) => {}
+    }
+    if event.app_device_id.is_empty() {
+        return invalid("app_device_id is required");
+    }
+    if let Some(platform_info) = &event.platform_info {
+        if Client::try_from(platform_info.client_type).is_err() {
+            return invalid("platform_info.client_type must be a known client");
+        }
+        if OsType::try_from(platform_info.os_type).is_err() {
+            return invalid("platform_info.os_type must be a known OS type");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::definitions::{Action, Event, PlatformInfo};
+    use crate::validation::validate_event;
+
+    fn valid_event() -> Event {
+        Event {
+            event_time: String::from("2024-03-14T12:00:00Z"),
+            action: Action::AppOpenInitialize as i32,
+            app_device_id: String::from("test-app-device-id"),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_event() {
+        assert!(validate_event(&valid_event()).is_ok());
+
+        let invalid_events = vec![
+            Event {
+                event_time: String::from("test-time"),
+                ..valid_event()
+            },
+            Event {
+                action: Action::Unspecified as i32,
+                ..valid_event()
+            },
+            Event {
+                action: i32::MAX,
+                ..valid_event()
+            },
+            Event {
+                app_device_id: String::new(),
+                ..valid_event()
+            },
+            Event {
+                platform_info: Some(PlatformInfo {
+                    os_type: i32::MAX,
+                    ..Default::default()
+                }),
+                ..valid_event()
+            },
+        ];
+        for event in invalid_events {
+            assert!(validate_event(&event).is_err(), "{event:?}");
+        }
+    }
+}
@@ -110,7 +110,7 @@ impl ElectrumServerConfig {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ElectrumRpcUris {
     pub mainnet: String,
     pub testnet: String,
@@ -4,6 +4,7 @@ use bdk_utils::bdk::bitcoin::{
 };
 use serde::{Deserialize, Serialize};
 use time::{serde::rfc3339, OffsetDateTime};
+use types::account::identifiers::AccountId;
 
 #[derive(Deserialize, Serialize, PartialEq, Debug, Clone)]
 pub struct Block {
@@ -15,6 +16,21 @@ pub struct Block {
     pub network: Network, // GSI Partition Key
     #[serde(with = "rfc3339")]
     pub created_at: OffsetDateTime,
+    /// Whether the block's transactions have been recorded in account transaction histories.
+    /// Blocks that failed to index are retried until this is set.
+    #[serde(default)]
+    pub transactions_indexed: bool,
+    /// The account transaction records written for the block, so they can be removed if the
+    /// block is reorganized out of the chain.
+    #[serde(default)]
+    pub indexed_transactions: Vec<IndexedTransaction>,
+}
+
+/// An account transaction record written when a block was indexed.
+#[derive(Deserialize, Serialize, PartialEq, Debug, Clone)]
+pub struct IndexedTransaction {
+    pub account_id: AccountId,
+    pub txid: String,
 }
 
 impl Block {
@@ -27,6 +43,8 @@ impl Block {
             time: bdk_block.header.time,
             created_at: OffsetDateTime::now_utc(),
             network,
+            transactions_indexed: false,
+            indexed_transactions: Vec::new(),
         })
     }
 }
@@ -2,8 +2,11 @@ use database::ddb::DatabaseError;
 use thiserror::Error;
 
 pub mod entities;
+pub mod metrics;
 pub mod repository;
+pub mod routes;
 pub mod service;
+pub mod transaction_history;
 
 #[derive(Error, Debug)]
 pub enum ChainIndexerError {
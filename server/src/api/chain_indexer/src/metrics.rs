@@ -0,0 +1,24 @@
+use metrics::factory::{Counter, MetricsFactory, ObservableGauge};
+use once_cell::sync::Lazy;
+
+pub const NETWORK_KEY: &str = "network";
+
+pub static FACTORY: Lazy<MetricsFactory> = Lazy::new(|| MetricsFactory::new("chain_indexer"));
+
+// Counters
+pub static BLOCK_INDEXING_FAILURE: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("block_indexing.failure", None));
+pub static ORPHANED_BLOCKS: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("orphaned_blocks", None));
+
+// Gauges
+pub static WALLET_HEALTH_INACTIVE_KEYSET_ACCOUNTS: Lazy<ObservableGauge<u64>> =
+    Lazy::new(|| FACTORY.u64_observable_gauge("wallet_health.inactive_keyset_accounts", None));
+pub static WALLET_HEALTH_INACTIVE_KEYSET_BALANCE: Lazy<ObservableGauge<u64>> =
+    Lazy::new(|| FACTORY.u64_observable_gauge("wallet_health.inactive_keyset_balance", None));
+pub static WALLET_HEALTH_DUST_UTXOS: Lazy<ObservableGauge<u64>> =
+    Lazy::new(|| FACTORY.u64_observable_gauge("wallet_health.dust_utxos", None));
+pub static WALLET_HEALTH_PENDING_OUTFLOWS: Lazy<ObservableGauge<u64>> =
+    Lazy::new(|| FACTORY.u64_observable_gauge("wallet_health.pending_outflows", None));
+pub static WALLET_HEALTH_PENDING_OUTFLOW_BALANCE: Lazy<ObservableGauge<u64>> =
+    Lazy::new(|| FACTORY.u64_observable_gauge("wallet_health.pending_outflow_balance", None));
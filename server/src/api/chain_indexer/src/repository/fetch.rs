@@ -1,11 +1,16 @@
 use bdk_utils::bdk::bitcoin::{BlockHash, Network};
-use database::ddb::{try_from_item, try_to_attribute_val, DDBService, DatabaseError};
+use database::{
+    aws_sdk_dynamodb::types::AttributeValue,
+    ddb::{try_from_item, try_from_items, try_to_attribute_val, DDBService, DatabaseError},
+};
 use tracing::{event, instrument, Level};
 
 use super::Repository;
 use crate::{
     entities::Block,
-    repository::{NETWORK_HEIGHT_INDEX, NETWORK_HEIGHT_PARTITION_KEY, PARTITION_KEY},
+    repository::{
+        NETWORK_HEIGHT_INDEX, NETWORK_HEIGHT_PARTITION_KEY, NETWORK_HEIGHT_SORT_KEY, PARTITION_KEY,
+    },
 };
 
 impl Repository {
@@ -63,4 +68,93 @@ impl Repository {
             .map(|block| try_from_item(block, database_object))
             .transpose()
     }
+
+    /// Fetches every block at or above the given height, lowest first.
+    #[instrument(skip(self))]
+    pub(crate) async fn fetch_from_height(
+        &self,
+        network: Network,
+        height: u64,
+    ) -> Result<Vec<Block>, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+        let network_attr = try_to_attribute_val(network, database_object)?;
+
+        let mut exclusive_start_key = None;
+        let mut result = Vec::new();
+
+        loop {
+            let item_output = self
+                .connection
+                .client
+                .query()
+                .table_name(table_name.clone())
+                .index_name(NETWORK_HEIGHT_INDEX)
+                .key_condition_expression(format!(
+                    "{NETWORK_HEIGHT_PARTITION_KEY} = :network AND {NETWORK_HEIGHT_SORT_KEY} >= :height"
+                ))
+                .expression_attribute_values(":network", network_attr.clone())
+                .expression_attribute_values(":height", AttributeValue::N(height.to_string()))
+                .scan_index_forward(true)
+                .set_exclusive_start_key(exclusive_start_key.clone())
+                .send()
+                .await
+                .map_err(|err| {
+                    let service_err = err.into_service_error();
+                    event!(
+                        Level::ERROR,
+                        "Could not fetch blocks from height {height}: {service_err:?}",
+                    );
+                    DatabaseError::FetchError(database_object)
+                })?;
+
+            let mut blocks: Vec<Block> =
+                try_from_items(item_output.items().to_owned(), database_object)?;
+            result.append(&mut blocks);
+
+            if let Some(last_evaluated_key) = item_output.last_evaluated_key() {
+                exclusive_start_key = Some(last_evaluated_key.to_owned());
+            } else {
+                break;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Fetches up to `limit` of the highest blocks, highest first.
+    #[instrument(skip(self))]
+    pub(crate) async fn fetch_latest(
+        &self,
+        network: Network,
+        limit: i32,
+    ) -> Result<Vec<Block>, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let items = self
+            .connection
+            .client
+            .query()
+            .table_name(table_name)
+            .index_name(NETWORK_HEIGHT_INDEX)
+            .key_condition_expression(format!("{NETWORK_HEIGHT_PARTITION_KEY} = :val"))
+            .expression_attribute_values(":val", try_to_attribute_val(network, database_object)?)
+            .limit(limit)
+            .scan_index_forward(false)
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not fetch latest blocks: {service_err:?}",
+                );
+                DatabaseError::FetchError(database_object)
+            })?
+            .items()
+            .to_owned();
+
+        try_from_items(items, database_object)
+    }
 }
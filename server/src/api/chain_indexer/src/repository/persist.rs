@@ -1,11 +1,12 @@
+use bdk_utils::bdk::bitcoin::BlockHash;
 use database::{
     aws_sdk_dynamodb::error::ProvideErrorMetadata,
-    ddb::{try_to_item, DDBService, DatabaseError},
+    ddb::{try_to_attribute_val, try_to_item, DDBService, DatabaseError},
 };
 use tracing::{event, instrument, Level};
 
-use super::Repository;
-use crate::entities::Block;
+use super::{Repository, PARTITION_KEY};
+use crate::entities::{Block, IndexedTransaction};
 
 impl Repository {
     #[instrument(skip(self))]
@@ -34,4 +35,78 @@ impl Repository {
 
         Ok(())
     }
+
+    /// Marks a block's transactions as indexed, recording the account transactions written for it.
+    #[instrument(skip(self, indexed_transactions))]
+    pub async fn mark_transactions_indexed(
+        &self,
+        block_hash: BlockHash,
+        indexed_transactions: &[IndexedTransaction],
+    ) -> Result<(), DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        self.connection
+            .client
+            .update_item()
+            .table_name(table_name)
+            .key(
+                PARTITION_KEY,
+                try_to_attribute_val(block_hash, database_object)?,
+            )
+            .update_expression(
+                "SET transactions_indexed = :transactions_indexed, indexed_transactions = :indexed_transactions",
+            )
+            .condition_expression("attribute_exists(block_hash)")
+            .expression_attribute_values(
+                ":transactions_indexed",
+                try_to_attribute_val(true, database_object)?,
+            )
+            .expression_attribute_values(
+                ":indexed_transactions",
+                try_to_attribute_val(indexed_transactions, database_object)?,
+            )
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not mark block {block_hash} as indexed: {service_err:?} with message: {:?}",
+                    service_err.message()
+                );
+                DatabaseError::PersistenceError(database_object)
+            })?;
+
+        Ok(())
+    }
+
+    /// Deletes a block that is no longer part of the chain.
+    #[instrument(skip(self))]
+    pub async fn delete(&self, block_hash: BlockHash) -> Result<(), DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        self.connection
+            .client
+            .delete_item()
+            .table_name(table_name)
+            .key(
+                PARTITION_KEY,
+                try_to_attribute_val(block_hash, database_object)?,
+            )
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not delete block {block_hash}: {service_err:?} with message: {:?}",
+                    service_err.message()
+                );
+                DatabaseError::PersistenceError(database_object)
+            })?;
+
+        Ok(())
+    }
 }
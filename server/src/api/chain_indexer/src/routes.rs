@@ -0,0 +1,261 @@
+use std::str::FromStr;
+
+use axum::extract::{Path, Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use errors::ApiError;
+use exchange_rate::money::{FixedPointRate, Rounding};
+use http_server::swagger::{SwaggerEndpoint, Url};
+use serde::{Deserialize, Serialize};
+use time::{serde::rfc3339, OffsetDateTime};
+use tracing::instrument;
+use types::account::identifiers::AccountId;
+use types::currencies::CurrencyCode;
+use types::exchange_rate::ExchangeRate;
+use types::serde::deserialize_iso_4217;
+use types::transaction_history::{AccountTransaction, TransactionHistoryCursor};
+use utoipa::{OpenApi, ToSchema};
+
+use crate::transaction_history::list_transactions::ListTransactionsInput;
+use crate::transaction_history::Service as TransactionHistoryService;
+
+#[derive(Clone, axum_macros::FromRef)]
+pub struct RouteState(pub TransactionHistoryService);
+
+impl From<RouteState> for Router {
+    fn from(value: RouteState) -> Self {
+        Router::new()
+            .route(
+                "/api/accounts/:account_id/transactions",
+                get(list_transactions),
+            )
+            .route("/api/accounts/:account_id/balance", get(get_balance))
+            .with_state(value)
+    }
+}
+
+impl From<RouteState> for SwaggerEndpoint {
+    fn from(_: RouteState) -> Self {
+        (
+            Url::new(
+                "Transaction History",
+                "/docs/transaction-history/openapi.json",
+            ),
+            ApiDoc::openapi(),
+        )
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        list_transactions,
+        get_balance,
+    ),
+    components(
+        schemas(CurrencyCode, FiatValue, TransactionResponse, ListTransactionsResponse, BalanceResponse)
+    ),
+    tags(
+        (name = "Transaction History", description = "Confirmed Transactions & Balances")
+    )
+)]
+struct ApiDoc;
+
+fn default_currency_code() -> CurrencyCode {
+    CurrencyCode::USD
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListTransactionsQuery {
+    limit: Option<i32>,
+    cursor: Option<String>,
+    #[serde(
+        default = "default_currency_code",
+        deserialize_with = "deserialize_iso_4217"
+    )]
+    currency_code: CurrencyCode,
+}
+
+/// The value of an amount in a fiat currency, at a given exchange rate.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct FiatValue {
+    pub currency_code: CurrencyCode,
+    /// The value in the currency's minor units (e.g. cents), rounded half up.
+    pub amount: i64,
+    pub rate: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct TransactionResponse {
+    pub txid: String,
+    pub block_height: u64,
+    #[serde(with = "rfc3339")]
+    pub confirmed_at: OffsetDateTime,
+    pub received_sats: u64,
+    pub sent_sats: u64,
+    /// Received less sent; negative for outgoing transactions.
+    pub net_sats: i64,
+    pub fee_sats: Option<u64>,
+    /// Whether the value of every output spent from the account is known.
+    pub complete: bool,
+    /// The net amount valued at the exchange rate when the transaction was confirmed, if the rate
+    /// history has one.
+    pub fiat_value: Option<FiatValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct ListTransactionsResponse {
+    pub transactions: Vec<TransactionResponse>,
+    /// Pass as `cursor` to fetch the next page. Absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
+#[instrument(err, skip(transaction_history_service))]
+#[utoipa::path(
+    get,
+    path = "/api/accounts/{account_id}/transactions",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+        ("limit" = Option<i32>, Query, description = "Maximum number of transactions to return, up to 100"),
+        ("cursor" = Option<String>, Query, description = "The next_cursor from the previous page"),
+        ("currency_code" = Option<String>, Query, description = "ISO-4217 code of the currency to value transactions in. Defaults to USD"),
+    ),
+    responses(
+        (status = 200, description = "A page of the account's confirmed transactions, newest first", body=ListTransactionsResponse),
+        (status = 400, description = "Invalid cursor or currency code"),
+    ),
+)]
+async fn list_transactions(
+    Path(account_id): Path<AccountId>,
+    State(transaction_history_service): State<TransactionHistoryService>,
+    Query(query): Query<ListTransactionsQuery>,
+) -> Result<Json<ListTransactionsResponse>, ApiError> {
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(TransactionHistoryCursor::from_str)
+        .transpose()
+        .map_err(ApiError::GenericBadRequest)?;
+
+    let page = transaction_history_service
+        .list_transactions(ListTransactionsInput {
+            account_id: &account_id,
+            limit: query.limit,
+            cursor: cursor.as_ref(),
+            currency_code: &query.currency_code,
+        })
+        .await?;
+
+    let transactions = page
+        .transactions
+        .into_iter()
+        .map(|t| transaction_response(t.transaction, t.exchange_rate))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Json(ListTransactionsResponse {
+        transactions,
+        next_cursor: page.next_cursor.map(|c| c.to_string()),
+    }))
+}
+
+fn transaction_response(
+    transaction: AccountTransaction,
+    exchange_rate: Option<ExchangeRate>,
+) -> Result<TransactionResponse, ApiError> {
+    let received_sats = transaction.received_sats();
+    let sent_sats = transaction.sent_sats();
+    let net_sats = received_sats as i64 - sent_sats as i64;
+    let fiat_value = exchange_rate
+        .map(|rate| fiat_value(net_sats, rate))
+        .transpose()?;
+
+    Ok(TransactionResponse {
+        complete: transaction.is_complete(),
+        txid: transaction.txid,
+        block_height: transaction.block_height,
+        confirmed_at: transaction.confirmed_at,
+        received_sats,
+        sent_sats,
+        net_sats,
+        fee_sats: transaction.fee_sats,
+        fiat_value,
+    })
+}
+
+fn fiat_value(sats: i64, exchange_rate: ExchangeRate) -> Result<FiatValue, ApiError> {
+    let fixed_point_rate =
+        FixedPointRate::from_major_units(exchange_rate.rate, exchange_rate.to_currency.clone())?;
+    let minor_units =
+        fixed_point_rate.minor_units_for_sats(sats.unsigned_abs(), Rounding::HalfUp)?;
+    let amount = i64::try_from(minor_units).map_err(|_| {
+        ApiError::GenericInternalApplicationError("Fiat value out of range".to_string())
+    })?;
+
+    Ok(FiatValue {
+        currency_code: exchange_rate.to_currency,
+        amount: if sats < 0 { -amount } else { amount },
+        rate: exchange_rate.rate,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BalanceQuery {
+    #[serde(
+        default = "default_currency_code",
+        deserialize_with = "deserialize_iso_4217"
+    )]
+    currency_code: CurrencyCode,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct BalanceResponse {
+    pub confirmed_sats: u64,
+    pub total_received_sats: u64,
+    pub total_sent_sats: u64,
+    pub transaction_count: usize,
+    /// Whether the value of every output spent from the account is known. If not, the confirmed
+    /// balance may be overstated.
+    pub complete: bool,
+    /// The confirmed balance at the latest stored exchange rate, if there is a recent one.
+    pub fiat_value: Option<FiatValue>,
+}
+
+#[instrument(err, skip(transaction_history_service))]
+#[utoipa::path(
+    get,
+    path = "/api/accounts/{account_id}/balance",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+        ("currency_code" = Option<String>, Query, description = "ISO-4217 code of the currency to value the balance in. Defaults to USD"),
+    ),
+    responses(
+        (status = 200, description = "The account's confirmed balance across all of its keysets", body=BalanceResponse),
+        (status = 400, description = "Invalid currency code"),
+    ),
+)]
+async fn get_balance(
+    Path(account_id): Path<AccountId>,
+    State(transaction_history_service): State<TransactionHistoryService>,
+    Query(query): Query<BalanceQuery>,
+) -> Result<Json<BalanceResponse>, ApiError> {
+    let balance = transaction_history_service
+        .get_balance(&account_id, &query.currency_code)
+        .await?;
+
+    let confirmed_sats = i64::try_from(balance.confirmed_sats).map_err(|_| {
+        ApiError::GenericInternalApplicationError("Balance out of range".to_string())
+    })?;
+    let fiat_value = balance
+        .exchange_rate
+        .map(|rate| fiat_value(confirmed_sats, rate))
+        .transpose()?;
+
+    Ok(Json(BalanceResponse {
+        confirmed_sats: balance.confirmed_sats,
+        total_received_sats: balance.total_received_sats,
+        total_sent_sats: balance.total_sent_sats,
+        transaction_count: balance.transaction_count,
+        complete: balance.complete,
+        fiat_value,
+    }))
+}
@@ -1,8 +1,11 @@
 use super::Service;
-use crate::ChainIndexerError;
+use crate::{entities::Block, ChainIndexerError};
 use bdk_utils::bdk::bitcoin::{consensus::encode::deserialize, Block as BdkBlock, BlockHash};
 use tracing::{event, Level};
 
+/// How many of the most recent blocks are checked for transactions that failed to index.
+const INDEXING_RETRY_DEPTH: i32 = 144;
+
 impl Service {
     pub async fn get_new_blocks(&self) -> Result<Vec<BdkBlock>, ChainIndexerError> {
         event!(
@@ -18,10 +21,9 @@ impl Service {
         if let Some(init_block) = self.repo.fetch_init_block(self.settings.network).await? {
             let mut current_hash = tip_hash;
             loop {
-                // We've already seen the block, so we've found a common parent with the tip.
+                // We've already seen the block, so we've found a common parent with the tip. Any
+                // blocks we've stored above it were reorganized out, see `get_orphaned_blocks`.
                 if self.repo.fetch(current_hash).await?.is_some() {
-                    // TODO: to detect re-orgs, we should query the DB for any blocks with a
-                    // prev_hash that is equal to the current_hash.
                     break;
                 }
 
@@ -56,10 +58,58 @@ impl Service {
         Ok(new_blocks)
     }
 
-    pub(crate) async fn get_block(
+    /// Returns the stored blocks that the given new blocks, as returned by `get_new_blocks`,
+    /// replace. These are on a branch that is no longer part of the chain.
+    pub async fn get_orphaned_blocks(
         &self,
-        block_hash: &BlockHash,
-    ) -> Result<BdkBlock, ChainIndexerError> {
+        new_blocks: &[BdkBlock],
+    ) -> Result<Vec<Block>, ChainIndexerError> {
+        let Some(first_block) = new_blocks.first() else {
+            return Ok(Vec::new());
+        };
+
+        let new_hashes = new_blocks
+            .iter()
+            .map(|block| block.block_hash())
+            .collect::<Vec<_>>();
+        Ok(self
+            .repo
+            .fetch_from_height(self.settings.network, first_block.bip34_block_height()?)
+            .await?
+            .into_iter()
+            .filter(|block| !new_hashes.contains(&block.block_hash))
+            .collect())
+    }
+
+    /// Returns recent stored blocks whose transactions haven't been indexed, lowest first.
+    pub async fn get_unindexed_blocks(&self) -> Result<Vec<Block>, ChainIndexerError> {
+        let mut blocks = self
+            .repo
+            .fetch_latest(self.settings.network, INDEXING_RETRY_DEPTH)
+            .await?
+            .into_iter()
+            .filter(|block| !block.transactions_indexed)
+            .collect::<Vec<_>>();
+        blocks.sort_by_key(|block| block.height);
+
+        Ok(blocks)
+    }
+
+    /// Returns the block at the given height in the current chain.
+    pub async fn get_block_at_height(&self, height: u64) -> Result<BdkBlock, ChainIndexerError> {
+        let block_hash: BlockHash = self
+            .http_client
+            .get(&format!("{}/block-height/{height}", self.settings.base_url))
+            .send()
+            .await?
+            .text()
+            .await?
+            .parse()?;
+
+        self.get_block(&block_hash).await
+    }
+
+    pub async fn get_block(&self, block_hash: &BlockHash) -> Result<BdkBlock, ChainIndexerError> {
         Ok(deserialize(
             &self
                 .http_client
@@ -1,6 +1,9 @@
 use super::Service;
-use crate::{entities::Block, ChainIndexerError};
-use bdk_utils::bdk::bitcoin::Block as BdkBlock;
+use crate::{
+    entities::{Block, IndexedTransaction},
+    ChainIndexerError,
+};
+use bdk_utils::bdk::bitcoin::{Block as BdkBlock, BlockHash};
 
 impl Service {
     pub async fn add_block(&self, block: &BdkBlock) -> Result<(), ChainIndexerError> {
@@ -10,4 +13,25 @@ impl Service {
 
         Ok(())
     }
+
+    /// Records that a block's transactions were indexed, along with the records written for it.
+    pub async fn mark_transactions_indexed(
+        &self,
+        block_hash: BlockHash,
+        indexed_transactions: &[IndexedTransaction],
+    ) -> Result<(), ChainIndexerError> {
+        self.repo
+            .mark_transactions_indexed(block_hash, indexed_transactions)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Forgets a block that has been reorganized out of the chain, so that it's fetched again if
+    /// it ever becomes part of the chain.
+    pub async fn remove_block(&self, block_hash: BlockHash) -> Result<(), ChainIndexerError> {
+        self.repo.delete(block_hash).await?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,29 @@
+use bdk_utils::bdk::bitcoin::blockdata::block::Bip34Error;
+use errors::ApiError;
+use exchange_rate::rate_history::error::RateHistoryError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum TransactionHistoryError {
+    #[error(transparent)]
+    Database(#[from] database::ddb::DatabaseError),
+    #[error(transparent)]
+    RateHistory(#[from] RateHistoryError),
+    #[error("BIP34 error: {0}")]
+    Bip34(#[from] Bip34Error),
+    #[error("Invalid block time {0}")]
+    InvalidBlockTime(u32),
+}
+
+impl From<TransactionHistoryError> for ApiError {
+    fn from(value: TransactionHistoryError) -> Self {
+        let err_msg = value.to_string();
+        match value {
+            TransactionHistoryError::Database(e) => e.into(),
+            TransactionHistoryError::RateHistory(e) => e.into(),
+            TransactionHistoryError::Bip34(_) | TransactionHistoryError::InvalidBlockTime(_) => {
+                ApiError::GenericInternalApplicationError(err_msg)
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use time::OffsetDateTime;
+use tracing::instrument;
+use types::account::identifiers::AccountId;
+use types::currencies::CurrencyCode;
+use types::exchange_rate::ExchangeRate;
+
+use super::{error::TransactionHistoryError, Service};
+
+pub struct Balance {
+    /// Total received less total sent.
+    pub confirmed_sats: u64,
+    pub total_received_sats: u64,
+    pub total_sent_sats: u64,
+    pub transaction_count: usize,
+    /// Whether the value of every output the account has spent is known. If not, the confirmed
+    /// balance overstates the account's funds.
+    pub complete: bool,
+    /// The most recent stored exchange rate, if the rate history has a current one.
+    pub exchange_rate: Option<ExchangeRate>,
+}
+
+impl Service {
+    /// Sums an account's confirmed transactions across all of its keysets.
+    #[instrument(skip(self))]
+    pub async fn get_balance(
+        &self,
+        account_id: &AccountId,
+        currency_code: &CurrencyCode,
+    ) -> Result<Balance, TransactionHistoryError> {
+        let transactions = self.repository.fetch_all_for_account(account_id).await?;
+
+        let total_received_sats = transactions.iter().map(|t| t.received_sats()).sum::<u64>();
+        let total_sent_sats = transactions.iter().map(|t| t.sent_sats()).sum::<u64>();
+        let exchange_rate = self
+            .rate_history_service
+            .get_stored_rate(currency_code, OffsetDateTime::now_utc())
+            .await?;
+
+        Ok(Balance {
+            confirmed_sats: total_received_sats.saturating_sub(total_sent_sats),
+            total_received_sats,
+            total_sent_sats,
+            transaction_count: transactions.len(),
+            complete: transactions.iter().all(|t| t.is_complete()),
+            exchange_rate,
+        })
+    }
+}
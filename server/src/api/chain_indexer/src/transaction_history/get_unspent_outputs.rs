@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use tracing::instrument;
+use types::account::identifiers::AccountId;
+
+use super::{error::TransactionHistoryError, Service};
+
+/// An output paid to one of the account's addresses that no recorded transaction has spent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnspentOutput {
+    pub txid: String,
+    pub vout: u32,
+    pub address: String,
+    pub amount_sats: u64,
+}
+
+impl Service {
+    /// Returns an account's confirmed unspent outputs across all of its keysets. Outputs spent by
+    /// transactions that haven't confirmed yet are still included.
+    #[instrument(skip(self))]
+    pub async fn get_unspent_outputs(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Vec<UnspentOutput>, TransactionHistoryError> {
+        let transactions = self.repository.fetch_all_for_account(account_id).await?;
+
+        let spent = transactions
+            .iter()
+            .flat_map(|t| t.spent_inputs.iter())
+            .map(|i| (i.previous_txid.as_str(), i.previous_vout))
+            .collect::<HashSet<_>>();
+
+        Ok(transactions
+            .iter()
+            .flat_map(|t| {
+                t.received_outputs
+                    .iter()
+                    .filter(|o| !spent.contains(&(t.txid.as_str(), o.vout)))
+                    .map(|o| UnspentOutput {
+                        txid: t.txid.clone(),
+                        vout: o.vout,
+                        address: o.address.clone(),
+                        amount_sats: o.amount_sats,
+                    })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use bdk_utils::bdk::bitcoin::address::NetworkUnchecked;
+use bdk_utils::bdk::bitcoin::{Address, Block, Network, Script, TxIn};
+use itertools::Itertools;
+use time::OffsetDateTime;
+use tracing::instrument;
+use types::account::identifiers::AccountId;
+use types::transaction_history::{AccountTransaction, ReceivedOutput, SpentInput};
+
+use super::{error::TransactionHistoryError, Service};
+use crate::entities::IndexedTransaction;
+
+pub struct IndexBlockInput<'a> {
+    pub block: &'a Block,
+    pub network: Network,
+    /// The owners of the addresses returned by [`candidate_addresses`] for the block.
+    pub owners: &'a HashMap<Address<NetworkUnchecked>, AccountId>,
+}
+
+/// Returns every address in a block that could belong to an account: the addresses paid by each
+/// output, and the addresses spent from by each segwit v0 script-hash input.
+pub fn candidate_addresses(block: &Block, network: Network) -> Vec<Address<NetworkUnchecked>> {
+    block
+        .txdata
+        .iter()
+        .flat_map(|tx| {
+            let outputs = tx
+                .output
+                .iter()
+                .filter_map(|output| output_address(&output.script_pubkey, network));
+            let inputs = tx
+                .input
+                .iter()
+                .filter_map(|input| input_address(input, network));
+            outputs.chain(inputs).collect::<Vec<_>>()
+        })
+        .unique()
+        .collect()
+}
+
+impl Service {
+    /// Records every transaction in the block that pays to or spends from an account, and returns
+    /// the records written. Indexing the same block again rewrites the same records.
+    ///
+    /// Spent amounts are taken from the account's record of the transaction that created the
+    /// output, so blocks should be indexed in order.
+    #[instrument(skip(self, input))]
+    pub async fn index_block(
+        &self,
+        input: IndexBlockInput<'_>,
+    ) -> Result<Vec<IndexedTransaction>, TransactionHistoryError> {
+        let IndexBlockInput {
+            block,
+            network,
+            owners,
+        } = input;
+
+        let block_height = block.bip34_block_height()?;
+        let block_hash = block.block_hash().to_string();
+        let confirmed_at = OffsetDateTime::from_unix_timestamp(block.header.time.into())
+            .map_err(|_| TransactionHistoryError::InvalidBlockTime(block.header.time))?;
+        let created_at = OffsetDateTime::now_utc();
+
+        // Outputs paid to accounts earlier in this block, keyed by outpoint.
+        let mut block_outputs: HashMap<(String, u32), u64> = HashMap::new();
+        // Records of earlier transactions fetched to price spent outputs.
+        let mut fetched: HashMap<(AccountId, String), Option<AccountTransaction>> = HashMap::new();
+        let mut records: Vec<AccountTransaction> = Vec::new();
+
+        for tx in &block.txdata {
+            let txid = tx.txid().to_string();
+            let new_record = |account_id: &AccountId| AccountTransaction {
+                account_id: account_id.clone(),
+                txid: txid.clone(),
+                block_height,
+                block_hash: block_hash.clone(),
+                confirmed_at,
+                received_outputs: Vec::new(),
+                spent_inputs: Vec::new(),
+                fee_sats: None,
+                created_at,
+            };
+            let mut tx_records: HashMap<AccountId, AccountTransaction> = HashMap::new();
+
+            for (vout, output) in tx.output.iter().enumerate() {
+                let Some(address) = output_address(&output.script_pubkey, network) else {
+                    continue;
+                };
+                let Some(account_id) = owners.get(&address) else {
+                    continue;
+                };
+
+                let vout = vout as u32;
+                block_outputs.insert((txid.clone(), vout), output.value);
+                tx_records
+                    .entry(account_id.clone())
+                    .or_insert_with(|| new_record(account_id))
+                    .received_outputs
+                    .push(ReceivedOutput {
+                        vout,
+                        address: address.assume_checked().to_string(),
+                        amount_sats: output.value,
+                    });
+            }
+
+            let mut spending_accounts = Vec::new();
+            for tx_in in &tx.input {
+                if tx_in.previous_output.is_null() {
+                    continue;
+                }
+                let owner = input_address(tx_in, network).and_then(|address| owners.get(&address));
+                spending_accounts.push(owner.cloned());
+                let Some(account_id) = owner else {
+                    continue;
+                };
+
+                let previous_txid = tx_in.previous_output.txid.to_string();
+                let previous_vout = tx_in.previous_output.vout;
+                let amount_sats = match block_outputs.get(&(previous_txid.clone(), previous_vout)) {
+                    Some(amount_sats) => Some(*amount_sats),
+                    None => {
+                        let key = (account_id.clone(), previous_txid.clone());
+                        if !fetched.contains_key(&key) {
+                            let previous =
+                                self.repository.fetch(account_id, &previous_txid).await?;
+                            fetched.insert(key.clone(), previous);
+                        }
+                        fetched[&key].as_ref().and_then(|previous| {
+                            previous
+                                .received_outputs
+                                .iter()
+                                .find(|o| o.vout == previous_vout)
+                                .map(|o| o.amount_sats)
+                        })
+                    }
+                };
+
+                tx_records
+                    .entry(account_id.clone())
+                    .or_insert_with(|| new_record(account_id))
+                    .spent_inputs
+                    .push(SpentInput {
+                        previous_txid,
+                        previous_vout,
+                        amount_sats,
+                    });
+            }
+
+            // The fee is only attributable to an account that funded every input.
+            let funding_account = spending_accounts
+                .first()
+                .cloned()
+                .flatten()
+                .filter(|first| spending_accounts.iter().all(|a| a.as_ref() == Some(first)));
+            if let Some(account_id) = funding_account {
+                if let Some(record) = tx_records.get_mut(&account_id) {
+                    let outputs_sats: u64 = tx.output.iter().map(|o| o.value).sum();
+                    record.fee_sats = record
+                        .is_complete()
+                        .then(|| record.sent_sats().checked_sub(outputs_sats))
+                        .flatten();
+                }
+            }
+
+            records.extend(tx_records.into_values());
+        }
+
+        for record in &records {
+            self.repository.persist(record).await?;
+        }
+
+        Ok(records
+            .into_iter()
+            .map(|record| IndexedTransaction {
+                account_id: record.account_id,
+                txid: record.txid,
+            })
+            .collect())
+    }
+}
+
+fn output_address(script: &Script, network: Network) -> Option<Address<NetworkUnchecked>> {
+    if script.is_op_return() {
+        return None;
+    }
+    Address::from_script(script, network)
+        .ok()
+        .and_then(|address| unchecked(&address))
+}
+
+/// Derives the address a segwit v0 script-hash input spends from, using the witness script,
+/// which is always the last witness element. Account wallets only use P2WSH descriptors, so other
+/// input types never match an account's address.
+fn input_address(tx_in: &TxIn, network: Network) -> Option<Address<NetworkUnchecked>> {
+    if !tx_in.script_sig.is_empty() {
+        return None;
+    }
+    let witness_script = tx_in.witness.last()?;
+    unchecked(&Address::p2wsh(Script::from_bytes(witness_script), network))
+}
+
+// [W-5648]: Use `as_unchecked` once it's available in BDK.
+fn unchecked(address: &Address) -> Option<Address<NetworkUnchecked>> {
+    Address::from_str(&address.to_string()).ok()
+}
@@ -0,0 +1,62 @@
+use tracing::instrument;
+use types::account::identifiers::AccountId;
+use types::currencies::CurrencyCode;
+use types::exchange_rate::ExchangeRate;
+use types::transaction_history::{AccountTransaction, TransactionHistoryCursor};
+
+use super::{error::TransactionHistoryError, Service, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+pub struct ListTransactionsInput<'a> {
+    pub account_id: &'a AccountId,
+    pub limit: Option<i32>,
+    pub cursor: Option<&'a TransactionHistoryCursor>,
+    pub currency_code: &'a CurrencyCode,
+}
+
+/// A confirmed transaction along with the exchange rate when it was confirmed, if the rate
+/// history has one.
+pub struct ValuedTransaction {
+    pub transaction: AccountTransaction,
+    pub exchange_rate: Option<ExchangeRate>,
+}
+
+pub struct TransactionPage {
+    pub transactions: Vec<ValuedTransaction>,
+    pub next_cursor: Option<TransactionHistoryCursor>,
+}
+
+impl Service {
+    /// Returns a page of an account's confirmed transactions across all of its keysets, newest
+    /// first.
+    #[instrument(skip(self, input))]
+    pub async fn list_transactions(
+        &self,
+        input: ListTransactionsInput<'_>,
+    ) -> Result<TransactionPage, TransactionHistoryError> {
+        let limit = input
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let (transactions, next_cursor) = self
+            .repository
+            .fetch_page(input.account_id, limit, input.cursor)
+            .await?;
+
+        let mut valued_transactions = Vec::with_capacity(transactions.len());
+        for transaction in transactions {
+            let exchange_rate = self
+                .rate_history_service
+                .get_stored_rate(input.currency_code, transaction.confirmed_at)
+                .await?;
+            valued_transactions.push(ValuedTransaction {
+                transaction,
+                exchange_rate,
+            });
+        }
+
+        Ok(TransactionPage {
+            transactions: valued_transactions,
+            next_cursor,
+        })
+    }
+}
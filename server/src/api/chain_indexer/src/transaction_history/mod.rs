@@ -0,0 +1,33 @@
+use exchange_rate::rate_history::Service as RateHistoryService;
+use repository::transaction_history::Repository;
+
+pub mod error;
+pub mod get_balance;
+pub mod get_unspent_outputs;
+pub mod index_block;
+pub mod list_transactions;
+pub mod remove_block;
+
+/// Number of transactions returned per page when the client doesn't ask for a page size.
+pub const DEFAULT_PAGE_SIZE: i32 = 25;
+
+/// Upper bound on the page size a client can ask for.
+pub const MAX_PAGE_SIZE: i32 = 100;
+
+/// A service for recording the confirmed transactions of every account as blocks are indexed,
+/// and serving each account's history and balance from that record.
+#[derive(Clone)]
+pub struct Service {
+    pub repository: Repository,
+    pub rate_history_service: RateHistoryService,
+}
+
+impl Service {
+    #[must_use]
+    pub fn new(repository: Repository, rate_history_service: RateHistoryService) -> Self {
+        Self {
+            repository,
+            rate_history_service,
+        }
+    }
+}
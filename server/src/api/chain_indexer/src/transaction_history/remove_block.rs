@@ -0,0 +1,30 @@
+use bdk_utils::bdk::bitcoin::BlockHash;
+use tracing::instrument;
+
+use super::{error::TransactionHistoryError, Service};
+use crate::entities::IndexedTransaction;
+
+impl Service {
+    /// Removes the account transaction records written for a block that has been reorganized out
+    /// of the chain. Records since rewritten for a block in the new chain are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_hash` - The hash of the orphaned block
+    /// * `indexed_transactions` - The records written when the block was indexed
+    #[instrument(skip(self, indexed_transactions))]
+    pub async fn remove_block(
+        &self,
+        block_hash: BlockHash,
+        indexed_transactions: &[IndexedTransaction],
+    ) -> Result<(), TransactionHistoryError> {
+        let block_hash = block_hash.to_string();
+        for indexed in indexed_transactions {
+            self.repository
+                .delete_if_in_block(&indexed.account_id, &indexed.txid, &block_hash)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -114,6 +114,18 @@ impl Connection {
             DatabaseObject::Migration => ("MIGRATION_TABLE", "Migration"),
             DatabaseObject::SocialRecovery => ("SOCIAL_RECOVERY_TABLE", "SocialRecovery"),
             DatabaseObject::Consent => ("CONSENT_TABLE", "Consent"),
+            DatabaseObject::RecoveryHistory => ("RECOVERY_HISTORY_TABLE", "RecoveryHistory"),
+            DatabaseObject::ExchangeRateHistory => {
+                ("EXCHANGE_RATE_HISTORY_TABLE", "ExchangeRateHistory")
+            }
+            DatabaseObject::AccountTransaction => {
+                ("ACCOUNT_TRANSACTION_TABLE", "AccountTransaction")
+            }
+            DatabaseObject::OnboardingSignal => ("ONBOARDING_SIGNAL_TABLE", "OnboardingSignal"),
+            DatabaseObject::RecoveryCancellationAttempts => (
+                "RECOVERY_CANCELLATION_ATTEMPTS_TABLE",
+                "RecoveryCancellationAttempts",
+            ),
         };
 
         match self {
@@ -203,6 +215,11 @@ pub enum DatabaseObject {
     Migration,
     SocialRecovery,
     Consent,
+    RecoveryHistory,
+    ExchangeRateHistory,
+    AccountTransaction,
+    OnboardingSignal,
+    RecoveryCancellationAttempts,
 }
 
 impl fmt::Display for DatabaseObject {
@@ -219,6 +236,13 @@ impl fmt::Display for DatabaseObject {
             DatabaseObject::Migration => write!(f, "Migration"),
             DatabaseObject::SocialRecovery => write!(f, "SocialRecovery"),
             DatabaseObject::Consent => write!(f, "Consent"),
+            DatabaseObject::RecoveryHistory => write!(f, "RecoveryHistory"),
+            DatabaseObject::ExchangeRateHistory => write!(f, "ExchangeRateHistory"),
+            DatabaseObject::AccountTransaction => write!(f, "AccountTransaction"),
+            DatabaseObject::OnboardingSignal => write!(f, "OnboardingSignal"),
+            DatabaseObject::RecoveryCancellationAttempts => {
+                write!(f, "RecoveryCancellationAttempts")
+            }
         }
     }
 }
@@ -93,6 +93,8 @@ pub enum ErrorCode {
     InvitationExpired,
     // Money Movement,
     NoSpendingLimitExists,
+    // Rate limiting
+    TooManyRequests,
 }
 
 // An ErrorCode always maps to a single ErrorCategory
@@ -121,6 +123,7 @@ impl From<ErrorCode> for ErrorCategory {
             | ErrorCode::InvalidPhoneNumber
             | ErrorCode::InvalidEmailAddress
             | ErrorCode::InvitationExpired
+            | ErrorCode::TooManyRequests
             | ErrorCode::AccountNotFound => ErrorCategory::InvalidRequestError,
         }
     }
@@ -150,6 +153,7 @@ impl From<ErrorCode> for StatusCode {
             | ErrorCode::InvitationExpired => StatusCode::CONFLICT,
             ErrorCode::NoSpendingLimitExists => StatusCode::METHOD_NOT_ALLOWED,
             ErrorCode::Unauthorized => StatusCode::UNAUTHORIZED,
+            ErrorCode::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 }
@@ -0,0 +1,451 @@
+use std::cmp::Ordering;
+
+use async_trait::async_trait;
+use metrics::{KeyValue, ResultCounter};
+use moka::future::Cache;
+use time::OffsetDateTime;
+use tracing::{event, Level};
+use types::currencies::CurrencyCode;
+use types::currencies::CurrencyCode::BTC;
+use types::exchange_rate::bitstamp::BitstampRateProvider;
+use types::exchange_rate::cash::CashAppRateProvider;
+use types::exchange_rate::coingecko::RateProvider as CoingeckoRateProvider;
+use types::exchange_rate::coinmarketcap::RateProvider as CoinmarketcapRateProvider;
+
+use crate::currency_conversion::SpotExchangeRateProvider;
+use crate::error::{ExchangeRateError, ProviderResponseError};
+use crate::metrics as exchange_rate_metrics;
+use crate::{ExchangeRateProvider, ExchangeRateProviderType};
+
+/// Quotes further than this fraction away from the median of all quotes are discarded, unless the
+/// service is configured otherwise.
+pub const DEFAULT_MAX_DEVIATION: f64 = 0.02;
+
+/// How much we trust an aggregated rate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RateConfidence {
+    /// At least three providers agreed on the rate.
+    High,
+    /// Two providers agreed on the rate.
+    Medium,
+    /// Only a single provider's quote was usable.
+    Low,
+    /// No provider's quote was usable, so this is the last good rate we aggregated.
+    Stale,
+}
+
+impl std::fmt::Display for RateConfidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateConfidence::High => write!(f, "high"),
+            RateConfidence::Medium => write!(f, "medium"),
+            RateConfidence::Low => write!(f, "low"),
+            RateConfidence::Stale => write!(f, "stale"),
+        }
+    }
+}
+
+/// The result of aggregating spot rates across providers.
+#[derive(Clone, Debug)]
+pub struct AggregatedRate {
+    /// The median of the quotes that were not rejected as outliers.
+    pub rate: f64,
+    pub confidence: RateConfidence,
+    /// Providers whose quotes contributed to the rate.
+    pub sources: Vec<ExchangeRateProviderType>,
+    /// Providers whose quotes were discarded as outliers.
+    pub outliers: Vec<ExchangeRateProviderType>,
+    /// When the quotes were retrieved. For a stale rate, this is when it was last fresh.
+    pub time_retrieved: OffsetDateTime,
+}
+
+/// Why no aggregated rate could be produced from a set of quotes.
+#[derive(Debug)]
+enum Rejection {
+    /// None of the providers returned a usable quote.
+    NoQuotes {
+        failed: Vec<ExchangeRateProviderType>,
+    },
+    /// Every usable quote deviated too far from the median, so the providers disagree.
+    NoAgreement {
+        quotes: Vec<(ExchangeRateProviderType, f64)>,
+    },
+}
+
+impl std::fmt::Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::NoQuotes { failed } => {
+                write!(f, "no provider returned a usable quote (")?;
+                write_list(f, failed.iter().map(|p| p.to_string()))?;
+                write!(f, ")")
+            }
+            Rejection::NoAgreement { quotes } => {
+                write!(f, "quotes deviate too far from each other (")?;
+                write_list(f, quotes.iter().map(|(p, rate)| format!("{p}: {rate}")))?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+fn write_list(
+    f: &mut std::fmt::Formatter<'_>,
+    items: impl Iterator<Item = String>,
+) -> std::fmt::Result {
+    write!(f, "{}", items.collect::<Vec<_>>().join(", "))
+}
+
+/// A single provider's answer to the aggregate provider's spot rate request.
+pub struct ProviderQuote {
+    pub provider: ExchangeRateProviderType,
+    pub rate: Option<f64>,
+}
+
+pub struct AggregateQuotes {
+    pub currency_code: CurrencyCode,
+    pub quotes: Vec<ProviderQuote>,
+    pub time_retrieved: OffsetDateTime,
+}
+
+/// A spot rate provider that queries every upstream provider concurrently and returns the median
+/// of their quotes, after discarding outliers. If no provider returns a usable quote, it falls
+/// back to the last good rate it aggregated, for as long as that rate remains in `last_good`.
+#[derive(Clone)]
+pub struct AggregateRateProvider {
+    pub bitstamp: BitstampRateProvider,
+    pub cash_app: CashAppRateProvider,
+    pub coingecko: CoingeckoRateProvider,
+    pub coinmarketcap: CoinmarketcapRateProvider,
+    pub max_deviation: f64,
+    /// The last good aggregated rate, keyed by ISO-4217 currency code. The cache's time-to-live
+    /// bounds how old a fallback rate can be.
+    pub(crate) last_good: Cache<u16, AggregatedRate>,
+}
+
+impl AggregateRateProvider {
+    pub fn new(max_deviation: f64, last_good: Cache<u16, AggregatedRate>) -> Self {
+        Self {
+            bitstamp: BitstampRateProvider::new(),
+            cash_app: CashAppRateProvider::new(),
+            coingecko: CoingeckoRateProvider::new(),
+            coinmarketcap: CoinmarketcapRateProvider::new(),
+            max_deviation,
+            last_good,
+        }
+    }
+
+    /// Returns the aggregated rate for the given currency, along with how much it can be trusted.
+    pub async fn aggregated_rate(
+        &self,
+        currency: &CurrencyCode,
+    ) -> Result<AggregatedRate, ExchangeRateError> {
+        let quotes = self.request(currency).await?;
+        self.resolve(quotes).await
+    }
+
+    async fn resolve(&self, quotes: AggregateQuotes) -> Result<AggregatedRate, ExchangeRateError> {
+        let AggregateQuotes {
+            currency_code,
+            quotes,
+            time_retrieved,
+        } = quotes;
+        let cache_key = currency_code.clone() as u16;
+
+        let aggregated_rate = match aggregate(quotes, self.max_deviation, time_retrieved) {
+            Ok(aggregated_rate) => {
+                for outlier in &aggregated_rate.outliers {
+                    exchange_rate_metrics::PROVIDER_OUTLIER.add(1, &[provider_attribute(outlier)]);
+                }
+                self.last_good
+                    .insert(cache_key, aggregated_rate.clone())
+                    .await;
+                aggregated_rate
+            }
+            Err(rejection) => {
+                let Some(last_good) = self.last_good.get(&cache_key).await else {
+                    event!(
+                        Level::ERROR,
+                        "Unable to aggregate a rate for {} with no rate to fall back to: {}",
+                        currency_code,
+                        rejection
+                    );
+                    return Err(
+                        ProviderResponseError::MissingData(Self::rate_provider_type()).into(),
+                    );
+                };
+
+                event!(
+                    Level::WARN,
+                    "Unable to aggregate a rate for {}, falling back to rate from {}: {}",
+                    currency_code,
+                    last_good.time_retrieved,
+                    rejection
+                );
+                exchange_rate_metrics::AGGREGATE_FALLBACK.add(1, &[]);
+                exchange_rate_metrics::AGGREGATE_FALLBACK_AGE.record(
+                    (time_retrieved - last_good.time_retrieved).whole_seconds() as u64,
+                    &[],
+                );
+                AggregatedRate {
+                    confidence: RateConfidence::Stale,
+                    ..last_good
+                }
+            }
+        };
+
+        exchange_rate_metrics::AGGREGATE_RATE.add(
+            1,
+            &[KeyValue::new(
+                exchange_rate_metrics::CONFIDENCE_KEY,
+                aggregated_rate.confidence.to_string(),
+            )],
+        );
+        Ok(aggregated_rate)
+    }
+}
+
+impl ExchangeRateProvider for AggregateRateProvider {
+    fn root_url(&self) -> &str {
+        "aggregate://"
+    }
+
+    fn rate_provider_type() -> ExchangeRateProviderType {
+        ExchangeRateProviderType::Aggregate
+    }
+}
+
+#[async_trait]
+impl SpotExchangeRateProvider for AggregateRateProvider {
+    type ResponseType = AggregateQuotes;
+
+    /// Queries every provider concurrently. Individual provider failures are recorded rather
+    /// than returned, so this never fails.
+    async fn request(
+        &self,
+        currency: &CurrencyCode,
+    ) -> Result<Self::ResponseType, ExchangeRateError> {
+        let time_retrieved = OffsetDateTime::now_utc();
+        let (bitstamp, cash_app, coingecko, coinmarketcap) = futures::join!(
+            quote(&self.bitstamp, currency),
+            quote(&self.cash_app, currency),
+            quote(&self.coingecko, currency),
+            quote(&self.coinmarketcap, currency),
+        );
+
+        Ok(AggregateQuotes {
+            currency_code: currency.clone(),
+            quotes: vec![bitstamp, cash_app, coingecko, coinmarketcap],
+            time_retrieved,
+        })
+    }
+
+    /// Only returns the rate, for callers that treat every provider alike. Use
+    /// [`AggregateRateProvider::aggregated_rate`] or
+    /// [`crate::service::Service::get_latest_aggregated_rate`] to also learn how much it can be
+    /// trusted.
+    async fn parse_response(&self, response: Self::ResponseType) -> Result<f64, ExchangeRateError> {
+        self.resolve(response).await.map(|r| r.rate)
+    }
+}
+
+async fn quote<T>(provider: &T, currency: &CurrencyCode) -> ProviderQuote
+where
+    T: SpotExchangeRateProvider,
+{
+    // Providers that can't quote the currency at all aren't failures, so they're left out of the
+    // request metrics.
+    if !T::supported_currency_codes().contains(currency) {
+        return ProviderQuote {
+            provider: T::rate_provider_type(),
+            rate: None,
+        };
+    }
+
+    let attributes = [provider_attribute(&T::rate_provider_type())];
+    let start_time = OffsetDateTime::now_utc();
+
+    let rate = provider
+        .rate(&BTC, currency)
+        .await
+        .count_result(
+            &exchange_rate_metrics::PROVIDER_REQUEST,
+            &exchange_rate_metrics::PROVIDER_REQUEST_FAILURE,
+            &attributes,
+        )
+        .map_err(|e| {
+            event!(
+                Level::WARN,
+                "Failed to get spot rate for {} from {}: {}",
+                currency,
+                T::rate_provider_type(),
+                e
+            )
+        })
+        .ok();
+
+    exchange_rate_metrics::PROVIDER_RESPONSE_TIME.record(
+        (OffsetDateTime::now_utc() - start_time).whole_milliseconds() as u64,
+        &attributes,
+    );
+
+    ProviderQuote {
+        provider: T::rate_provider_type(),
+        rate,
+    }
+}
+
+fn provider_attribute(provider: &ExchangeRateProviderType) -> KeyValue {
+    KeyValue::new(exchange_rate_metrics::PROVIDER_KEY, provider.to_string())
+}
+
+/// Discards quotes further than `max_deviation` from the median of all usable quotes and returns
+/// the median of the rest. Returns the reason if no rate could be produced.
+fn aggregate(
+    quotes: Vec<ProviderQuote>,
+    max_deviation: f64,
+    time_retrieved: OffsetDateTime,
+) -> Result<AggregatedRate, Rejection> {
+    let (usable, failed): (Vec<_>, Vec<_>) = quotes
+        .into_iter()
+        .partition(|q| q.rate.is_some_and(|rate| rate.is_finite() && rate > 0.0));
+    let usable = usable
+        .into_iter()
+        .filter_map(|q| q.rate.map(|rate| (q.provider, rate)))
+        .collect::<Vec<_>>();
+
+    let Some(overall_median) = median(usable.iter().map(|(_, rate)| *rate).collect()) else {
+        return Err(Rejection::NoQuotes {
+            failed: failed.into_iter().map(|q| q.provider).collect(),
+        });
+    };
+    let (sources, outliers): (Vec<_>, Vec<_>) = usable
+        .iter()
+        .cloned()
+        .partition(|(_, rate)| (rate - overall_median).abs() / overall_median <= max_deviation);
+
+    let Some(rate) = median(sources.iter().map(|(_, rate)| *rate).collect()) else {
+        return Err(Rejection::NoAgreement { quotes: usable });
+    };
+    let confidence = match sources.len() {
+        1 => RateConfidence::Low,
+        2 => RateConfidence::Medium,
+        _ => RateConfidence::High,
+    };
+
+    Ok(AggregatedRate {
+        rate,
+        confidence,
+        sources: sources.into_iter().map(|(provider, _)| provider).collect(),
+        outliers: outliers.into_iter().map(|(provider, _)| provider).collect(),
+        time_retrieved,
+    })
+}
+
+fn median(mut rates: Vec<f64>) -> Option<f64> {
+    if rates.is_empty() {
+        return None;
+    }
+
+    rates.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let mid = rates.len() / 2;
+    if rates.len() % 2 == 0 {
+        Some((rates[mid - 1] + rates[mid]) / 2.0)
+    } else {
+        Some(rates[mid])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::{aggregate, ProviderQuote, RateConfidence, Rejection};
+    use crate::ExchangeRateProviderType::{Bitstamp, CashApp, Coingecko, Coinmarketcap};
+
+    #[test]
+    fn test_aggregate_discards_outliers() {
+        let quotes = vec![
+            ProviderQuote {
+                provider: Bitstamp,
+                rate: Some(50_000.0),
+            },
+            ProviderQuote {
+                provider: CashApp,
+                rate: Some(50_100.0),
+            },
+            ProviderQuote {
+                provider: Coingecko,
+                rate: Some(49_900.0),
+            },
+            ProviderQuote {
+                provider: Coinmarketcap,
+                rate: Some(65_000.0),
+            },
+        ];
+
+        let aggregated_rate = aggregate(quotes, 0.02, OffsetDateTime::now_utc()).unwrap();
+        assert_eq!(aggregated_rate.rate, 50_000.0);
+        assert_eq!(aggregated_rate.confidence, RateConfidence::High);
+        assert_eq!(aggregated_rate.sources.len(), 3);
+        assert_eq!(aggregated_rate.outliers.len(), 1);
+    }
+
+    #[test]
+    fn test_aggregate_ignores_failed_providers() {
+        let quotes = vec![
+            ProviderQuote {
+                provider: Bitstamp,
+                rate: None,
+            },
+            ProviderQuote {
+                provider: CashApp,
+                rate: Some(50_000.0),
+            },
+            ProviderQuote {
+                provider: Coingecko,
+                rate: Some(50_200.0),
+            },
+            ProviderQuote {
+                provider: Coinmarketcap,
+                rate: Some(f64::NAN),
+            },
+        ];
+
+        let aggregated_rate = aggregate(quotes, 0.02, OffsetDateTime::now_utc()).unwrap();
+        assert_eq!(aggregated_rate.rate, 50_100.0);
+        assert_eq!(aggregated_rate.confidence, RateConfidence::Medium);
+    }
+
+    #[test]
+    fn test_aggregate_without_quotes() {
+        let quotes = vec![ProviderQuote {
+            provider: Bitstamp,
+            rate: None,
+        }];
+
+        assert!(matches!(
+            aggregate(quotes, 0.02, OffsetDateTime::now_utc()),
+            Err(Rejection::NoQuotes { failed }) if failed.len() == 1
+        ));
+    }
+
+    #[test]
+    fn test_aggregate_without_agreement() {
+        let quotes = vec![
+            ProviderQuote {
+                provider: Bitstamp,
+                rate: Some(50_000.0),
+            },
+            ProviderQuote {
+                provider: CashApp,
+                rate: Some(60_000.0),
+            },
+        ];
+
+        assert!(matches!(
+            aggregate(quotes, 0.02, OffsetDateTime::now_utc()),
+            Err(Rejection::NoAgreement { quotes }) if quotes.len() == 2
+        ));
+    }
+}
@@ -1,37 +1,94 @@
 use async_trait::async_trait;
 
+use crate::aggregate::AggregatedRate;
 use crate::error::ExchangeRateError::{
     ProviderUnreachable, UnsupportedDestinationCurrency, UnsupportedSourceCurrency,
 };
 use crate::error::{ExchangeRateError, ProviderResponseError};
+use crate::money::{FixedPointRate, Rounding};
 use crate::service::Service;
 use crate::{ExchangeRateProvider, ExchangeRateProviderType};
 use account::spend_limit::Money;
-use bdk_utils::constants::ONE_BTC_IN_SATOSHIS;
 use types::currencies::CurrencyCode::BTC;
-use types::currencies::{Currency, CurrencyCode};
+use types::currencies::{Currency, CurrencyCode, RateSource};
 use types::exchange_rate::bitstamp::{BitstampRate, BitstampRateProvider};
 use types::exchange_rate::cash::{CashAppQuote, CashAppRateProvider};
+use types::exchange_rate::coingecko::{
+    RateProvider as CoingeckoRateProvider, SpotResponse as CoingeckoSpotResponse,
+};
+use types::exchange_rate::coinmarketcap::{
+    LatestResponse as CoinmarketcapLatestResponse, RateProvider as CoinmarketcapRateProvider,
+};
 use types::exchange_rate::local_rate_provider::{LocalRateProvider, LocalRateType};
 
+/// Converts a fiat amount, denominated in that currency's minor units, to satoshis. Any
+/// fractional satoshi is rounded down.
 pub async fn sats_for<T>(
     exchange_rate_service: &Service,
     rate_provider: T,
     money: &Money,
 ) -> Result<u64, ExchangeRateError>
+where
+    T: SpotExchangeRateProvider + 'static,
+{
+    fixed_point_rate(exchange_rate_service, rate_provider, &money.currency_code)
+        .await?
+        .sats_for_minor_units(money.amount, Rounding::Down)
+}
+
+/// Converts a fiat amount to satoshis at the latest aggregated rate, like [`sats_for`], and also
+/// returns that rate so callers can decide whether it's trustworthy enough to act on.
+pub async fn sats_for_aggregated_rate(
+    exchange_rate_service: &Service,
+    money: &Money,
+) -> Result<(u64, AggregatedRate), ExchangeRateError> {
+    let aggregated_rate = exchange_rate_service
+        .get_latest_aggregated_rate(
+            exchange_rate_service.aggregate_rate_provider(),
+            money.currency_code.clone(),
+        )
+        .await?;
+    let sats = FixedPointRate::from_major_units(aggregated_rate.rate, money.currency_code.clone())?
+        .sats_for_minor_units(money.amount, Rounding::Down)?;
+
+    Ok((sats, aggregated_rate))
+}
+
+/// Converts an amount in satoshis to the given fiat currency, denominated in that currency's
+/// minor units. Any fractional minor unit is rounded down.
+pub async fn fiat_for<T>(
+    exchange_rate_service: &Service,
+    rate_provider: T,
+    sats: u64,
+    currency_code: CurrencyCode,
+) -> Result<Money, ExchangeRateError>
+where
+    T: SpotExchangeRateProvider + 'static,
+{
+    let amount = fixed_point_rate(exchange_rate_service, rate_provider, &currency_code)
+        .await?
+        .minor_units_for_sats(sats, Rounding::Down)?;
+
+    Ok(Money {
+        amount,
+        currency_code,
+    })
+}
+
+async fn fixed_point_rate<T>(
+    exchange_rate_service: &Service,
+    rate_provider: T,
+    currency_code: &CurrencyCode,
+) -> Result<FixedPointRate, ExchangeRateError>
 where
     T: SpotExchangeRateProvider + 'static,
 {
     let rate = exchange_rate_service
-        .get_latest_rate(rate_provider, money.currency_code.clone())
+        .get_latest_rate(rate_provider, currency_code.clone())
         .await?
         .rate;
 
-    let x = ONE_BTC_IN_SATOSHIS as f64  // 1 BTC in SATOSHIs
-        / rate                               // 1 USD in SATOSHIs
-        * 0.01                               // 1 US cent in SATOSHIs //TODO: use whatever shift we need coming from "from" currency
-        * (money.amount as f64); // amount in SATOSHIs
-    Ok(x as u64)
+    FixedPointRate::from_major_units(rate, currency_code.clone())
 }
 
 /// Trait for exchange rate providers that support spot rates.
@@ -39,8 +96,8 @@ where
 pub trait SpotExchangeRateProvider: ExchangeRateProvider {
     type ResponseType: Send;
 
-    /// Get the exchange rate for the given currency pair. Returns an error if the source currency
-    /// is not supported by the provider.
+    /// Get the exchange rate for the given currency pair. Returns an error if either currency is
+    /// not supported by the provider.
     #[tracing::instrument(skip(self))]
     async fn rate(&self, from: &CurrencyCode, to: &CurrencyCode) -> Result<f64, ExchangeRateError> {
         match from {
@@ -48,8 +105,8 @@ pub trait SpotExchangeRateProvider: ExchangeRateProvider {
                 let destination_currency: Currency = to.clone().into();
 
                 match destination_currency {
-                    Currency::Fiat(fiat_currency) => {
-                        if !Currency::supported_fiat_currencies().contains(&fiat_currency) {
+                    Currency::Fiat(_) => {
+                        if !Self::supported_currency_codes().contains(to) {
                             return Err(UnsupportedDestinationCurrency(to.clone()));
                         }
 
@@ -81,6 +138,10 @@ impl ExchangeRateProvider for BitstampRateProvider {
     fn rate_provider_type() -> ExchangeRateProviderType {
         ExchangeRateProviderType::Bitstamp
     }
+
+    fn supported_currency_codes() -> Vec<CurrencyCode> {
+        Currency::supported_currency_codes_for(&RateSource::Bitstamp)
+    }
 }
 
 #[async_trait]
@@ -117,6 +178,10 @@ impl ExchangeRateProvider for CashAppRateProvider {
     fn rate_provider_type() -> ExchangeRateProviderType {
         ExchangeRateProviderType::CashApp
     }
+
+    fn supported_currency_codes() -> Vec<CurrencyCode> {
+        Currency::supported_currency_codes_for(&RateSource::CashApp)
+    }
 }
 
 #[async_trait]
@@ -146,7 +211,69 @@ impl SpotExchangeRateProvider for CashAppRateProvider {
                     Self::rate_provider_type(),
                 ))?;
 
-        Ok(latest_quote.base_value_cents as f64 / 100.0)
+        let minor_units_per_major_unit =
+            10f64.powi(latest_quote.currency_code.fractional_digits() as i32);
+        Ok(latest_quote.base_value_cents as f64 / minor_units_per_major_unit)
+    }
+}
+
+// The `ExchangeRateProvider` impls for Coingecko and Coinmarketcap live alongside their
+// historical rate support.
+#[async_trait]
+impl SpotExchangeRateProvider for CoingeckoRateProvider {
+    type ResponseType = (CurrencyCode, CoingeckoSpotResponse);
+
+    async fn request(
+        &self,
+        currency: &CurrencyCode,
+    ) -> Result<Self::ResponseType, ExchangeRateError> {
+        self.spot_rate_request(currency)
+            .send()
+            .await
+            .map_err(|_| ProviderUnreachable)?
+            .json::<CoingeckoSpotResponse>()
+            .await
+            .map(|response| (currency.clone(), response))
+            .map_err(|_| ProviderResponseError::Deserialization(Self::rate_provider_type()).into())
+    }
+
+    async fn parse_response(&self, response: Self::ResponseType) -> Result<f64, ExchangeRateError> {
+        let (currency, response) = response;
+        response
+            .bitcoin
+            .get(&currency.to_string().to_lowercase())
+            .copied()
+            .ok_or_else(|| ProviderResponseError::MissingData(Self::rate_provider_type()).into())
+    }
+}
+
+#[async_trait]
+impl SpotExchangeRateProvider for CoinmarketcapRateProvider {
+    type ResponseType = (CurrencyCode, CoinmarketcapLatestResponse);
+
+    async fn request(
+        &self,
+        currency: &CurrencyCode,
+    ) -> Result<Self::ResponseType, ExchangeRateError> {
+        self.latest_rate_request(currency)
+            .send()
+            .await
+            .map_err(|_| ProviderUnreachable)?
+            .json::<CoinmarketcapLatestResponse>()
+            .await
+            .map(|response| (currency.clone(), response))
+            .map_err(|_| ProviderResponseError::Deserialization(Self::rate_provider_type()).into())
+    }
+
+    async fn parse_response(&self, response: Self::ResponseType) -> Result<f64, ExchangeRateError> {
+        let (currency, response) = response;
+        response
+            .data
+            .btc
+            .first()
+            .and_then(|quotes| quotes.quote.get(&currency.to_string()))
+            .map(|value| value.price)
+            .ok_or_else(|| ProviderResponseError::MissingData(Self::rate_provider_type()).into())
     }
 }
 
@@ -185,10 +312,10 @@ impl SpotExchangeRateProvider for LocalRateProvider {
 #[cfg(test)]
 mod sats_for_tests {
     use account::spend_limit::Money;
-    use types::currencies::CurrencyCode::{AUD, USD};
+    use types::currencies::CurrencyCode::{USD, XXX};
     use types::exchange_rate::local_rate_provider::LocalRateProvider;
 
-    use crate::currency_conversion::sats_for;
+    use crate::currency_conversion::{fiat_for, sats_for};
     use crate::service::Service;
 
     #[tokio::test]
@@ -211,14 +338,32 @@ mod sats_for_tests {
     }
 
     #[tokio::test]
-    async fn test_aud_should_fail_test() {
+    async fn test_fiat_for_conversion() {
+        let rate_provider = LocalRateProvider::new();
+        let test_amounts = vec![(44, 0), (45, 1), (4410, 100), (100_000_000, 2_267_800)];
+        for (sat_amount, cent_amount) in test_amounts {
+            let result = fiat_for(&Service::new(), rate_provider.clone(), sat_amount, USD)
+                .await
+                .unwrap();
+            assert_eq!(
+                result,
+                Money {
+                    amount: cent_amount,
+                    currency_code: USD,
+                }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_currency_should_fail_test() {
         let rate_provider = LocalRateProvider::new();
         let rate_result = sats_for(
             &Service::new(),
             rate_provider,
             &Money {
                 amount: 1,
-                currency_code: AUD,
+                currency_code: XXX,
             },
         )
         .await;
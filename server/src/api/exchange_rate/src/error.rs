@@ -17,6 +17,10 @@ pub enum ExchangeRateError {
     ProviderResponseInvalid(#[from] ProviderResponseError),
     #[error("Could not retrieve rates due to rate limits")]
     ProviderRateLimited,
+    #[error("Exchange rate must be a finite, positive number")]
+    InvalidRate,
+    #[error("Currency conversion overflowed")]
+    ConversionOverflow,
 }
 
 impl From<ExchangeRateError> for ApiError {
@@ -29,7 +33,9 @@ impl From<ExchangeRateError> for ApiError {
             | ExchangeRateError::ProviderRateLimited => ApiError::GenericBadRequest(err_msg),
             ExchangeRateError::CacheRead
             | ExchangeRateError::ProviderUnreachable
-            | ExchangeRateError::ProviderResponseInvalid(_) => {
+            | ExchangeRateError::ProviderResponseInvalid(_)
+            | ExchangeRateError::InvalidRate
+            | ExchangeRateError::ConversionOverflow => {
                 ApiError::GenericInternalApplicationError(err_msg)
             }
         }
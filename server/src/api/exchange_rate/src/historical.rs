@@ -7,7 +7,7 @@ use reqwest::StatusCode;
 use std::collections::HashMap;
 use time::OffsetDateTime;
 use tracing::{error, event, Level};
-use types::currencies::CurrencyCode;
+use types::currencies::{Currency, CurrencyCode, RateSource};
 use types::exchange_rate::coingecko::{
     RateProvider as CoingeckoRateProvider, Response as CoingeckoResponse,
 };
@@ -53,6 +53,10 @@ impl ExchangeRateProvider for CoinmarketcapRateProvider {
     fn rate_provider_type() -> ExchangeRateProviderType {
         ExchangeRateProviderType::Coinmarketcap
     }
+
+    fn supported_currency_codes() -> Vec<CurrencyCode> {
+        Currency::supported_currency_codes_for(&RateSource::Coinmarketcap)
+    }
 }
 
 #[async_trait]
@@ -121,6 +125,10 @@ impl ExchangeRateProvider for CoingeckoRateProvider {
     fn rate_provider_type() -> ExchangeRateProviderType {
         ExchangeRateProviderType::Coingecko
     }
+
+    fn supported_currency_codes() -> Vec<CurrencyCode> {
+        Currency::supported_currency_codes_for(&RateSource::Coingecko)
+    }
 }
 
 #[async_trait]
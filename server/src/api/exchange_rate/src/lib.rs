@@ -1,8 +1,13 @@
+use types::currencies::{Currency, CurrencyCode};
+
+pub mod aggregate;
 pub mod currency_conversion;
 pub(crate) mod error;
 pub mod flags;
 pub mod historical;
 pub(crate) mod metrics;
+pub mod money;
+pub mod rate_history;
 pub mod routes;
 pub mod service;
 #[cfg(test)]
@@ -11,6 +16,7 @@ mod tests;
 /// Type used to express the exchange rate provider.
 #[derive(Clone, Debug)]
 pub enum ExchangeRateProviderType {
+    Aggregate,
     Bitstamp,
     CashApp,
     Coingecko,
@@ -21,6 +27,7 @@ pub enum ExchangeRateProviderType {
 impl std::fmt::Display for ExchangeRateProviderType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            ExchangeRateProviderType::Aggregate => write!(f, "Aggregate"),
             ExchangeRateProviderType::Bitstamp => write!(f, "Bitstamp"),
             ExchangeRateProviderType::CashApp => write!(f, "Cash App"),
             ExchangeRateProviderType::Coingecko => write!(f, "Coingecko"),
@@ -34,4 +41,9 @@ impl std::fmt::Display for ExchangeRateProviderType {
 pub trait ExchangeRateProvider: Send + Sync {
     fn root_url(&self) -> &str;
     fn rate_provider_type() -> ExchangeRateProviderType;
+
+    /// The fiat currencies this provider can quote. Defaults to every supported currency.
+    fn supported_currency_codes() -> Vec<CurrencyCode> {
+        Currency::supported_currency_codes()
+    }
 }
@@ -5,6 +5,8 @@ use metrics::{
 use once_cell::sync::Lazy;
 
 pub(crate) const FACTORY_NAME: &str = "exchange_rate";
+pub(crate) const PROVIDER_KEY: &str = "provider";
+pub(crate) const CONFIDENCE_KEY: &str = "confidence";
 
 //TODO[W-5630]: Replace with std once stabilized
 static FACTORY: Lazy<MetricsFactory> = Lazy::new(|| MetricsFactory::new(FACTORY_NAME));
@@ -17,6 +19,21 @@ pub(crate) static GET_EXCHANGE_RATE: Lazy<Counter<u64>> =
 pub(crate) static GET_EXCHANGE_RATE_CACHE_HITS: Lazy<Counter<u64>> =
     Lazy::new(|| FACTORY.u64_counter("get_exchange_rate.hits", None));
 
+// Counts the number of spot rate requests made to each provider by the aggregate provider.
+pub(crate) static PROVIDER_REQUEST: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("aggregate.provider_request", None));
+pub(crate) static PROVIDER_REQUEST_FAILURE: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("aggregate.provider_request.failure", None));
+// Counts the number of provider quotes discarded for deviating too far from the median.
+pub(crate) static PROVIDER_OUTLIER: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("aggregate.provider_outlier", None));
+// Counts the number of times the aggregate provider served the last good rate instead.
+pub(crate) static AGGREGATE_FALLBACK: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("aggregate.fallback", None));
+// Counts the number of rates served by the aggregate provider, by how much they can be trusted.
+pub(crate) static AGGREGATE_RATE: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("aggregate.rate", None));
+
 // Gauges
 
 // We measure the USD price of bitcoin.
@@ -32,3 +49,11 @@ pub(crate) static UNCACHED_RESPONSE_TIME: Lazy<Histogram<u64>> =
 // Measures the spread of how long it takes to respond to a cached response.
 pub(crate) static CACHED_RESPONSE_TIME: Lazy<Histogram<u64>> =
     Lazy::new(|| FACTORY.u64_histogram("cached_response_time", Some(Unit::new("ms"))));
+
+// Measures the spread of how long each provider takes to respond to the aggregate provider.
+pub(crate) static PROVIDER_RESPONSE_TIME: Lazy<Histogram<u64>> =
+    Lazy::new(|| FACTORY.u64_histogram("aggregate.provider_response_time", Some(Unit::new("ms"))));
+
+// Measures how old the last good rate is when the aggregate provider falls back to it.
+pub(crate) static AGGREGATE_FALLBACK_AGE: Lazy<Histogram<u64>> =
+    Lazy::new(|| FACTORY.u64_histogram("aggregate.fallback_age", Some(Unit::new("s"))));
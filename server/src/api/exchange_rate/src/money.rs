@@ -0,0 +1,198 @@
+use bdk_utils::constants::ONE_BTC_IN_SATOSHIS;
+use types::currencies::CurrencyCode;
+
+use crate::error::ExchangeRateError;
+
+/// Number of decimal places kept below the currency's minor unit when a provider's rate is
+/// converted into fixed-point.
+const RATE_PRECISION: u32 = 6;
+
+/// How to resolve a remainder when a conversion does not divide evenly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round to the nearest unit, with ties rounded away from zero.
+    HalfUp,
+}
+
+/// The price of one bitcoin in a fiat currency, expressed in that currency's minor units and
+/// scaled by `10^RATE_PRECISION` so that all conversions are done in integer arithmetic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedPointRate {
+    currency_code: CurrencyCode,
+    scaled_minor_units_per_btc: u128,
+}
+
+impl FixedPointRate {
+    /// Builds a fixed-point rate from a provider's price of one bitcoin in major units
+    /// (e.g. dollars). This is the only place a float is involved.
+    pub fn from_major_units(
+        rate: f64,
+        currency_code: CurrencyCode,
+    ) -> Result<Self, ExchangeRateError> {
+        if !rate.is_finite() || rate <= 0.0 {
+            return Err(ExchangeRateError::InvalidRate);
+        }
+
+        let scale = 10f64.powi((currency_code.fractional_digits() as u32 + RATE_PRECISION) as i32);
+        let scaled = (rate * scale).round();
+        if scaled < 1.0 || scaled >= u128::MAX as f64 {
+            return Err(ExchangeRateError::InvalidRate);
+        }
+
+        Ok(Self {
+            currency_code,
+            scaled_minor_units_per_btc: scaled as u128,
+        })
+    }
+
+    pub fn currency_code(&self) -> &CurrencyCode {
+        &self.currency_code
+    }
+
+    /// Converts an amount in the currency's minor units (e.g. cents) to satoshis.
+    pub fn sats_for_minor_units(
+        &self,
+        minor_units: u64,
+        rounding: Rounding,
+    ) -> Result<u64, ExchangeRateError> {
+        let numerator = (minor_units as u128)
+            .checked_mul(ONE_BTC_IN_SATOSHIS as u128)
+            .and_then(|n| n.checked_mul(10u128.pow(RATE_PRECISION)))
+            .ok_or(ExchangeRateError::ConversionOverflow)?;
+
+        let sats = divide(numerator, self.scaled_minor_units_per_btc, rounding);
+        u64::try_from(sats).map_err(|_| ExchangeRateError::ConversionOverflow)
+    }
+
+    /// Converts an amount in satoshis to the currency's minor units (e.g. cents).
+    pub fn minor_units_for_sats(
+        &self,
+        sats: u64,
+        rounding: Rounding,
+    ) -> Result<u64, ExchangeRateError> {
+        let numerator = (sats as u128)
+            .checked_mul(self.scaled_minor_units_per_btc)
+            .ok_or(ExchangeRateError::ConversionOverflow)?;
+        let denominator = ONE_BTC_IN_SATOSHIS as u128 * 10u128.pow(RATE_PRECISION);
+
+        let minor_units = divide(numerator, denominator, rounding);
+        u64::try_from(minor_units).map_err(|_| ExchangeRateError::ConversionOverflow)
+    }
+}
+
+fn divide(numerator: u128, denominator: u128, rounding: Rounding) -> u128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder == 0 {
+        return quotient;
+    }
+
+    match rounding {
+        Rounding::Down => quotient,
+        Rounding::Up => quotient + 1,
+        Rounding::HalfUp if remainder >= denominator - remainder => quotient + 1,
+        Rounding::HalfUp => quotient,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use types::currencies::CurrencyCode::{self, AUD, BTC, EUR, GBP, USD};
+
+    use super::{FixedPointRate, Rounding};
+    use crate::error::ExchangeRateError;
+
+    #[test]
+    fn rejects_non_positive_and_non_finite_rates() {
+        for rate in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                FixedPointRate::from_major_units(rate, USD),
+                Err(ExchangeRateError::InvalidRate)
+            ));
+        }
+    }
+
+    #[test]
+    fn converts_using_minor_units() {
+        let rate = FixedPointRate::from_major_units(50_000.0, USD).unwrap();
+        // One dollar is 1/50000th of a bitcoin.
+        assert_eq!(
+            rate.sats_for_minor_units(100, Rounding::Down).unwrap(),
+            2_000
+        );
+        assert_eq!(
+            rate.minor_units_for_sats(2_000, Rounding::Down).unwrap(),
+            100
+        );
+
+        // Bitcoin itself has eight fractional digits, so its minor unit is the satoshi.
+        let identity = FixedPointRate::from_major_units(1.0, BTC).unwrap();
+        assert_eq!(
+            identity
+                .sats_for_minor_units(12_345, Rounding::Down)
+                .unwrap(),
+            12_345
+        );
+    }
+
+    #[test]
+    fn applies_rounding_mode() {
+        // 1 cent at 22678 USD/BTC is 44.095... sats.
+        let rate = FixedPointRate::from_major_units(22_678.0, USD).unwrap();
+        assert_eq!(rate.sats_for_minor_units(1, Rounding::Down).unwrap(), 44);
+        assert_eq!(rate.sats_for_minor_units(1, Rounding::Up).unwrap(), 45);
+        assert_eq!(rate.sats_for_minor_units(1, Rounding::HalfUp).unwrap(), 44);
+        assert_eq!(
+            rate.sats_for_minor_units(100, Rounding::HalfUp).unwrap(),
+            4_410
+        );
+    }
+
+    #[test]
+    fn reports_overflow() {
+        let rate = FixedPointRate::from_major_units(0.000001, USD).unwrap();
+        assert!(matches!(
+            rate.sats_for_minor_units(u64::MAX, Rounding::Down),
+            Err(ExchangeRateError::ConversionOverflow)
+        ));
+    }
+
+    fn currency_code() -> impl Strategy<Value = CurrencyCode> {
+        prop_oneof![Just(AUD), Just(EUR), Just(GBP), Just(USD)]
+    }
+
+    proptest! {
+        // As long as a satoshi is worth no more than one minor unit, rounding the sats up and
+        // the fiat back down recovers the original fiat amount exactly.
+        #[test]
+        fn fiat_round_trips_through_sats(
+            rate in 1.0f64..1_000_000.0,
+            minor_units in 0u64..1_000_000_000_000,
+            currency_code in currency_code(),
+        ) {
+            let rate = FixedPointRate::from_major_units(rate, currency_code).unwrap();
+            let sats = rate.sats_for_minor_units(minor_units, Rounding::Up).unwrap();
+            prop_assert_eq!(rate.minor_units_for_sats(sats, Rounding::Down).unwrap(), minor_units);
+        }
+
+        // Going from sats to fiat loses at most the value of one minor unit in sats.
+        #[test]
+        fn sats_round_trip_through_fiat_within_one_minor_unit(
+            rate in 1.0f64..10_000_000.0,
+            sats in 0u64..2_100_000_000_000_000,
+            currency_code in currency_code(),
+        ) {
+            let rate = FixedPointRate::from_major_units(rate, currency_code).unwrap();
+            let minor_units = rate.minor_units_for_sats(sats, Rounding::Down).unwrap();
+            let round_tripped = rate.sats_for_minor_units(minor_units, Rounding::Down).unwrap();
+            let one_minor_unit = rate.sats_for_minor_units(1, Rounding::Up).unwrap();
+            prop_assert!(round_tripped <= sats);
+            prop_assert!(sats - round_tripped <= one_minor_unit);
+        }
+    }
+}
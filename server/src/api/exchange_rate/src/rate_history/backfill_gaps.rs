@@ -0,0 +1,142 @@
+use time::OffsetDateTime;
+use tracing::{event, instrument, Level};
+use types::currencies::CurrencyCode;
+use types::exchange_rate::history::{ExchangeRateSnapshot, ExchangeRateSnapshotSource};
+
+use super::{error::RateHistoryError, Service, MAX_BACKFILLS_PER_RUN, SNAPSHOT_INTERVAL};
+use crate::error::ExchangeRateError;
+use crate::historical::HistoricalExchangeRateProvider;
+
+impl Service {
+    /// Fills gaps in a currency's rate history between `from` and `to` from a historical rate
+    /// provider, returning how many snapshots were added. At most [`MAX_BACKFILLS_PER_RUN`]
+    /// requests are made, so long gaps are filled over several runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate_provider` - The historical rate provider to backfill from
+    /// * `currency_code` - The currency whose history is backfilled
+    /// * `from` - The start of the window to backfill
+    /// * `to` - The end of the window to backfill
+    #[instrument(skip(self, rate_provider))]
+    pub async fn backfill_gaps<T>(
+        &self,
+        rate_provider: T,
+        currency_code: CurrencyCode,
+        from: OffsetDateTime,
+        to: OffsetDateTime,
+    ) -> Result<usize, RateHistoryError>
+    where
+        T: HistoricalExchangeRateProvider,
+    {
+        let existing = self
+            .repository
+            .fetch_range(&currency_code, from, to)
+            .await?
+            .into_iter()
+            .map(|snapshot| snapshot.timestamp)
+            .collect::<Vec<_>>();
+
+        let mut backfilled = 0;
+        for timestamp in missing_timestamps(&existing, from, to) {
+            let rate = match rate_provider
+                .rate(&vec![currency_code.clone()], timestamp)
+                .await
+            {
+                Ok(mut rates) => rates.remove(&currency_code.to_string()),
+                Err(ExchangeRateError::ProviderRateLimited) => break,
+                Err(e) => {
+                    event!(
+                        Level::WARN,
+                        "Failed to backfill {currency_code} rate at {timestamp}: {e}"
+                    );
+                    None
+                }
+            };
+
+            if let Some(rate) = rate {
+                self.repository
+                    .persist(&ExchangeRateSnapshot {
+                        currency_code: currency_code.clone(),
+                        timestamp,
+                        rate,
+                        source: ExchangeRateSnapshotSource::Backfill,
+                    })
+                    .await?;
+                backfilled += 1;
+            }
+        }
+
+        Ok(backfilled)
+    }
+}
+
+// Returns the timestamps, one [`SNAPSHOT_INTERVAL`] apart, needed to close every gap longer than
+// that interval between `from`, the existing snapshots and `to`.
+fn missing_timestamps(
+    existing: &[OffsetDateTime],
+    from: OffsetDateTime,
+    to: OffsetDateTime,
+) -> Vec<OffsetDateTime> {
+    let mut missing = Vec::new();
+    let mut cursor = from - SNAPSHOT_INTERVAL;
+
+    for next in existing.iter().copied().chain([to + SNAPSHOT_INTERVAL]) {
+        let mut timestamp = cursor + SNAPSHOT_INTERVAL;
+        while timestamp <= next - SNAPSHOT_INTERVAL {
+            if missing.len() == MAX_BACKFILLS_PER_RUN {
+                return missing;
+            }
+            missing.push(timestamp);
+            timestamp += SNAPSHOT_INTERVAL;
+        }
+        cursor = next;
+    }
+
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+    use time::Duration;
+
+    use super::{missing_timestamps, MAX_BACKFILLS_PER_RUN, SNAPSHOT_INTERVAL};
+
+    #[test]
+    fn test_no_gaps() {
+        let from = datetime!(2024-01-01 00:00 UTC);
+        let existing = (0..=12)
+            .map(|i| from + SNAPSHOT_INTERVAL * i)
+            .collect::<Vec<_>>();
+
+        assert!(missing_timestamps(&existing, from, from + Duration::hours(1)).is_empty());
+    }
+
+    #[test]
+    fn test_fills_gaps() {
+        let from = datetime!(2024-01-01 00:00 UTC);
+        let existing = vec![from, from + Duration::minutes(20)];
+
+        assert_eq!(
+            missing_timestamps(&existing, from, from + Duration::minutes(30)),
+            vec![
+                from + Duration::minutes(5),
+                from + Duration::minutes(10),
+                from + Duration::minutes(15),
+                from + Duration::minutes(25),
+                from + Duration::minutes(30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bounded_per_run() {
+        let from = datetime!(2024-01-01 00:00 UTC);
+
+        assert_eq!(
+            missing_timestamps(&[], from, from + Duration::days(1)).len(),
+            MAX_BACKFILLS_PER_RUN
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use errors::ApiError;
+use thiserror::Error;
+
+use crate::error::ExchangeRateError;
+
+#[derive(Debug, Error)]
+pub enum RateHistoryError {
+    #[error(transparent)]
+    Database(#[from] database::ddb::DatabaseError),
+    #[error(transparent)]
+    ExchangeRate(#[from] ExchangeRateError),
+}
+
+impl From<RateHistoryError> for ApiError {
+    fn from(value: RateHistoryError) -> Self {
+        match value {
+            RateHistoryError::Database(e) => e.into(),
+            RateHistoryError::ExchangeRate(e) => e.into(),
+        }
+    }
+}
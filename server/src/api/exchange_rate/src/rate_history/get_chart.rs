@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use time::serde::rfc3339;
+use time::{Duration, OffsetDateTime};
+use tracing::instrument;
+use types::currencies::{Currency, CurrencyCode};
+use types::exchange_rate::history::ExchangeRateSnapshot;
+use utoipa::ToSchema;
+
+use super::{error::RateHistoryError, Service, MAX_CHART_TTL, SNAPSHOT_INTERVAL};
+use crate::error::ExchangeRateError;
+
+/// The span of time a chart covers.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChartRange {
+    Day,
+    Week,
+    Month,
+    Year,
+    All,
+}
+
+impl ChartRange {
+    // How far back the chart covers, or `None` for the entire history.
+    fn span(&self) -> Option<Duration> {
+        match self {
+            ChartRange::Day => Some(Duration::days(1)),
+            ChartRange::Week => Some(Duration::weeks(1)),
+            ChartRange::Month => Some(Duration::days(30)),
+            ChartRange::Year => Some(Duration::days(365)),
+            ChartRange::All => None,
+        }
+    }
+
+    fn bucket_width(&self) -> Duration {
+        match self {
+            ChartRange::Day => Duration::minutes(15),
+            ChartRange::Week => Duration::hours(1),
+            ChartRange::Month => Duration::hours(6),
+            ChartRange::Year => Duration::days(1),
+            ChartRange::All => Duration::weeks(1),
+        }
+    }
+
+    /// How long a chart is served from memory before it is rebuilt. Charts with narrow buckets
+    /// are rebuilt about as often as snapshots are recorded, so their latest bucket stays current.
+    pub fn cache_ttl(&self) -> Duration {
+        match self {
+            ChartRange::Day => SNAPSHOT_INTERVAL,
+            ChartRange::Week => Duration::minutes(15),
+            ChartRange::Month | ChartRange::Year | ChartRange::All => MAX_CHART_TTL,
+        }
+    }
+}
+
+/// A chart along with when it was built, so it can be rebuilt once it's older than its range's
+/// [`ChartRange::cache_ttl`].
+#[derive(Clone, Debug)]
+pub(crate) struct CachedChart {
+    built_at: OffsetDateTime,
+    buckets: Vec<OhlcBucket>,
+}
+
+/// The opening, highest, lowest and closing rates within one interval of a chart.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct OhlcBucket {
+    #[serde(with = "rfc3339")]
+    pub start: OffsetDateTime,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl Service {
+    /// Returns the rate history for a currency over the given range, summarized into buckets.
+    /// Buckets with no snapshots are omitted. Charts are cached for their range's
+    /// [`ChartRange::cache_ttl`].
+    ///
+    /// # Arguments
+    ///
+    /// * `currency_code` - The currency to chart
+    /// * `range` - The span of time to chart
+    /// * `now` - The end of the chart
+    #[instrument(skip(self))]
+    pub async fn get_chart(
+        &self,
+        currency_code: CurrencyCode,
+        range: ChartRange,
+        now: OffsetDateTime,
+    ) -> Result<Vec<OhlcBucket>, RateHistoryError> {
+        if !Currency::supported_currency_codes().contains(&currency_code) {
+            return Err(ExchangeRateError::UnsupportedDestinationCurrency(currency_code).into());
+        }
+
+        let cache_key = (currency_code.clone() as u16, range);
+        if let Some(chart) = self.charts.get(&cache_key).await {
+            if now - chart.built_at < range.cache_ttl() {
+                return Ok(chart.buckets);
+            }
+        }
+
+        let buckets = self.build_chart(&currency_code, range, now).await?;
+        self.charts
+            .insert(
+                cache_key,
+                CachedChart {
+                    built_at: now,
+                    buckets: buckets.clone(),
+                },
+            )
+            .await;
+        Ok(buckets)
+    }
+
+    async fn build_chart(
+        &self,
+        currency_code: &CurrencyCode,
+        range: ChartRange,
+        now: OffsetDateTime,
+    ) -> Result<Vec<OhlcBucket>, RateHistoryError> {
+        let from = range
+            .span()
+            .map_or(OffsetDateTime::UNIX_EPOCH, |span| now - span);
+        let snapshots = self
+            .repository
+            .fetch_range(currency_code, from, now)
+            .await?;
+
+        Ok(bucket(&snapshots, range.bucket_width()))
+    }
+}
+
+// Groups snapshots, which must be sorted oldest first, into buckets of the given width aligned to
+// the unix epoch.
+fn bucket(snapshots: &[ExchangeRateSnapshot], width: Duration) -> Vec<OhlcBucket> {
+    let width_seconds = width.whole_seconds();
+    let mut buckets: Vec<OhlcBucket> = Vec::new();
+
+    for snapshot in snapshots {
+        let timestamp = snapshot.timestamp.unix_timestamp();
+        let start = OffsetDateTime::UNIX_EPOCH
+            + Duration::seconds(timestamp - timestamp.rem_euclid(width_seconds));
+
+        match buckets.last_mut() {
+            Some(current) if current.start == start => {
+                current.high = current.high.max(snapshot.rate);
+                current.low = current.low.min(snapshot.rate);
+                current.close = snapshot.rate;
+            }
+            _ => buckets.push(OhlcBucket {
+                start,
+                open: snapshot.rate,
+                high: snapshot.rate,
+                low: snapshot.rate,
+                close: snapshot.rate,
+            }),
+        }
+    }
+
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+    use time::Duration;
+    use types::currencies::CurrencyCode::USD;
+    use types::exchange_rate::history::{ExchangeRateSnapshot, ExchangeRateSnapshotSource};
+
+    use super::{bucket, OhlcBucket};
+
+    #[test]
+    fn test_bucket() {
+        let start = datetime!(2024-01-01 00:00 UTC);
+        let snapshots = [(0, 100.0), (5, 120.0), (10, 90.0), (15, 110.0), (20, 105.0)]
+            .into_iter()
+            .map(|(minutes, rate)| ExchangeRateSnapshot {
+                currency_code: USD,
+                timestamp: start + Duration::minutes(minutes),
+                rate,
+                source: ExchangeRateSnapshotSource::Spot,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            bucket(&snapshots, Duration::minutes(15)),
+            vec![
+                OhlcBucket {
+                    start,
+                    open: 100.0,
+                    high: 120.0,
+                    low: 90.0,
+                    close: 90.0,
+                },
+                OhlcBucket {
+                    start: start + Duration::minutes(15),
+                    open: 110.0,
+                    high: 110.0,
+                    low: 105.0,
+                    close: 105.0,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use time::OffsetDateTime;
+use tracing::{event, instrument, Level};
+use types::currencies::CurrencyCode;
+use types::currencies::CurrencyCode::BTC;
+use types::exchange_rate::history::{ExchangeRateSnapshot, ExchangeRateSnapshotSource};
+use types::exchange_rate::ExchangeRate;
+
+use super::{error::RateHistoryError, Service, HISTORICAL_RATE_TOLERANCE};
+use crate::historical::HistoricalExchangeRateProvider;
+use crate::service::Service as ExchangeRateService;
+
+impl Service {
+    /// Returns the historical exchange rates for the given timestamps, serving them from the rate
+    /// history where possible and falling back to the historical rate provider otherwise. Rates
+    /// fetched from the provider are added to the history. Timestamps no rate could be found for
+    /// are omitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_rate_service` - The service used to query the historical rate provider
+    /// * `rate_provider` - The historical rate provider to fall back to
+    /// * `currency_code` - The currency to return rates in
+    /// * `timestamps` - The times to return rates for
+    #[instrument(skip(self, exchange_rate_service, rate_provider))]
+    pub async fn get_historical_rates<T>(
+        &self,
+        exchange_rate_service: &ExchangeRateService,
+        rate_provider: T,
+        currency_code: CurrencyCode,
+        timestamps: Vec<OffsetDateTime>,
+    ) -> Result<Vec<ExchangeRate>, RateHistoryError>
+    where
+        T: HistoricalExchangeRateProvider + 'static + Clone,
+    {
+        let (Some(earliest), Some(latest)) = (
+            timestamps.iter().min().copied(),
+            timestamps.iter().max().copied(),
+        ) else {
+            return Ok(Vec::new());
+        };
+
+        // Fetch every snapshot that could serve any of the timestamps in a single query, rather
+        // than one query per timestamp.
+        let snapshots = self
+            .repository
+            .fetch_range(&currency_code, earliest - HISTORICAL_RATE_TOLERANCE, latest)
+            .await?;
+
+        let mut rates = Vec::with_capacity(timestamps.len());
+        let mut missing = Vec::new();
+        for timestamp in timestamps.iter().copied() {
+            let rate = stored_rate_at(&snapshots, &currency_code, timestamp);
+            if rate.is_none() {
+                missing.push(timestamp);
+            }
+            rates.push(rate);
+        }
+
+        if !missing.is_empty() {
+            let fetched = exchange_rate_service
+                .get_historical_rates(rate_provider, currency_code.clone(), missing)
+                .await?;
+
+            for rate in fetched {
+                if let Err(e) = self
+                    .repository
+                    .persist(&ExchangeRateSnapshot {
+                        currency_code: currency_code.clone(),
+                        timestamp: rate.time_retrieved,
+                        rate: rate.rate,
+                        source: ExchangeRateSnapshotSource::Backfill,
+                    })
+                    .await
+                {
+                    event!(
+                        Level::WARN,
+                        "Failed to record historical {currency_code} rate at {}: {e}",
+                        rate.time_retrieved
+                    );
+                }
+
+                // Fill every slot for this timestamp, in case it was requested more than once.
+                for (slot, timestamp) in rates.iter_mut().zip(timestamps.iter()) {
+                    if slot.is_none() && *timestamp == rate.time_retrieved {
+                        *slot = Some(rate.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(rates.into_iter().flatten().collect())
+    }
+}
+
+// Returns the rate at `at` from the latest snapshot at or before it, if that snapshot is within
+// the tolerance. The snapshots must be sorted oldest first.
+fn stored_rate_at(
+    snapshots: &[ExchangeRateSnapshot],
+    currency_code: &CurrencyCode,
+    at: OffsetDateTime,
+) -> Option<ExchangeRate> {
+    let index = snapshots.partition_point(|snapshot| snapshot.timestamp <= at);
+    snapshots[..index]
+        .last()
+        .filter(|snapshot| at - snapshot.timestamp <= HISTORICAL_RATE_TOLERANCE)
+        .map(|snapshot| ExchangeRate {
+            from_currency: BTC,
+            to_currency: currency_code.clone(),
+            time_retrieved: at,
+            rate: snapshot.rate,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+    use time::Duration;
+    use types::currencies::CurrencyCode::USD;
+    use types::exchange_rate::history::{ExchangeRateSnapshot, ExchangeRateSnapshotSource};
+
+    use super::stored_rate_at;
+
+    #[test]
+    fn test_stored_rate_at() {
+        let start = datetime!(2024-01-01 00:00 UTC);
+        let snapshots = [(0, 100.0), (5, 110.0), (30, 120.0)]
+            .into_iter()
+            .map(|(minutes, rate)| ExchangeRateSnapshot {
+                currency_code: USD,
+                timestamp: start + Duration::minutes(minutes),
+                rate,
+                source: ExchangeRateSnapshotSource::Spot,
+            })
+            .collect::<Vec<_>>();
+
+        let rate_at =
+            |minutes| stored_rate_at(&snapshots, &USD, start + Duration::minutes(minutes));
+
+        assert_eq!(rate_at(-1), None);
+        assert_eq!(rate_at(0).map(|r| r.rate), Some(100.0));
+        assert_eq!(rate_at(7).map(|r| r.rate), Some(110.0));
+        assert_eq!(rate_at(10).map(|r| r.rate), Some(110.0));
+        assert_eq!(rate_at(11), None);
+        assert_eq!(rate_at(31).map(|r| r.rate), Some(120.0));
+    }
+}
@@ -0,0 +1,35 @@
+use time::OffsetDateTime;
+use tracing::instrument;
+use types::currencies::CurrencyCode;
+use types::currencies::CurrencyCode::BTC;
+use types::exchange_rate::ExchangeRate;
+
+use super::{error::RateHistoryError, Service, HISTORICAL_RATE_TOLERANCE};
+
+impl Service {
+    /// Returns the rate for a currency at the given time from the rate history alone, without
+    /// consulting any provider. Returns `None` if there is no snapshot close enough to `at`.
+    ///
+    /// # Arguments
+    ///
+    /// * `currency_code` - The currency to return the rate in
+    /// * `at` - The time to return the rate for
+    #[instrument(skip(self))]
+    pub async fn get_stored_rate(
+        &self,
+        currency_code: &CurrencyCode,
+        at: OffsetDateTime,
+    ) -> Result<Option<ExchangeRate>, RateHistoryError> {
+        Ok(self
+            .repository
+            .fetch_latest_at_or_before(currency_code, at)
+            .await?
+            .filter(|snapshot| at - snapshot.timestamp <= HISTORICAL_RATE_TOLERANCE)
+            .map(|snapshot| ExchangeRate {
+                from_currency: BTC,
+                to_currency: currency_code.clone(),
+                time_retrieved: at,
+                rate: snapshot.rate,
+            }))
+    }
+}
@@ -0,0 +1,50 @@
+use moka::future::{Cache, CacheBuilder};
+use repository::exchange_rate::Repository;
+use time::Duration;
+
+use self::get_chart::{CachedChart, ChartRange};
+
+pub mod backfill_gaps;
+pub mod error;
+pub mod get_chart;
+pub mod get_historical_rates;
+pub mod get_stored_rate;
+pub mod snapshot_latest_rates;
+
+/// How often the snapshot worker records spot rates. Gaps longer than this are backfilled.
+pub const SNAPSHOT_INTERVAL: Duration = Duration::minutes(5);
+
+/// How far before a requested timestamp a stored snapshot may be and still be served for it.
+pub const HISTORICAL_RATE_TOLERANCE: Duration = Duration::minutes(5);
+
+/// Upper bound on historical provider requests per backfill, to stay clear of rate limits.
+pub const MAX_BACKFILLS_PER_RUN: usize = 24;
+
+/// The longest any chart is served from memory before it is rebuilt from the rate history. Charts
+/// with narrower buckets are rebuilt sooner, see [`ChartRange::cache_ttl`].
+pub const MAX_CHART_TTL: Duration = Duration::hours(1);
+
+/// A service for recording exchange rates over time and serving historical rates and charts from
+/// that record.
+#[derive(Clone)]
+pub struct Service {
+    pub repository: Repository,
+    /// Charts, keyed by ISO-4217 currency code and range. Every chart reads its whole range of
+    /// the history, so they are too expensive to rebuild on every request.
+    charts: Cache<(u16, ChartRange), CachedChart>,
+}
+
+impl Service {
+    #[must_use]
+    pub fn new(repository: Repository) -> Self {
+        // We set the max capacity to 5000 since the current max ISO-4217 currency codes is 1000,
+        // and each has a chart per range.
+        let charts = CacheBuilder::new(5000)
+            .time_to_live(core::time::Duration::from_secs(
+                MAX_CHART_TTL.whole_seconds() as u64,
+            ))
+            .build();
+
+        Self { repository, charts }
+    }
+}
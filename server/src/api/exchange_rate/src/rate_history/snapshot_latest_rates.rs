@@ -0,0 +1,42 @@
+use tracing::instrument;
+use types::exchange_rate::history::{ExchangeRateSnapshot, ExchangeRateSnapshotSource};
+
+use super::{error::RateHistoryError, Service};
+use crate::currency_conversion::SpotExchangeRateProvider;
+use crate::service::Service as ExchangeRateService;
+
+impl Service {
+    /// Records the latest spot rate for every supported currency, returning how many were
+    /// recorded. Currencies whose rate could not be retrieved are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `exchange_rate_service` - The service used to fetch (and cache) spot rates
+    /// * `rate_provider` - The spot rate provider to query
+    #[instrument(skip(self, exchange_rate_service, rate_provider))]
+    pub async fn snapshot_latest_rates<T>(
+        &self,
+        exchange_rate_service: &ExchangeRateService,
+        rate_provider: T,
+    ) -> Result<usize, RateHistoryError>
+    where
+        T: SpotExchangeRateProvider + 'static + Clone,
+    {
+        let rates = exchange_rate_service
+            .get_latest_rates(rate_provider)
+            .await?;
+
+        for rate in &rates {
+            self.repository
+                .persist(&ExchangeRateSnapshot {
+                    currency_code: rate.to_currency.clone(),
+                    timestamp: rate.time_retrieved,
+                    rate: rate.rate,
+                    source: ExchangeRateSnapshotSource::Spot,
+                })
+                .await?;
+        }
+
+        Ok(rates.len())
+    }
+}
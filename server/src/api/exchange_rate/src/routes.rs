@@ -1,5 +1,7 @@
-use crate::flags::FLAG_USE_CASH_EXCHANGE_RATE_PROVIDER;
-use axum::extract::State;
+use crate::flags::{
+    FLAG_USE_AGGREGATE_EXCHANGE_RATE_PROVIDER, FLAG_USE_CASH_EXCHANGE_RATE_PROVIDER,
+};
+use axum::extract::{Query, State};
 use axum::routing::{get, post};
 use axum::{Json, Router};
 use errors::ApiError;
@@ -9,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use tracing::instrument;
 use types::currencies::{
-    Currency, CurrencyCode, CurrencyData, FiatCurrency, FiatDisplayConfiguration,
+    Currency, CurrencyCode, CurrencyData, FiatCurrency, FiatDisplayConfiguration, SymbolPlacement,
 };
 use types::exchange_rate::bitstamp::BitstampRateProvider;
 use types::exchange_rate::cash::CashAppRateProvider;
@@ -18,10 +20,18 @@ use types::exchange_rate::ExchangeRate;
 use types::serde::{deserialize_iso_4217, deserialize_ts_vec};
 use utoipa::{OpenApi, ToSchema};
 
+use crate::aggregate::AggregateRateProvider;
+use crate::rate_history::get_chart::{ChartRange, OhlcBucket};
+use crate::rate_history::Service as RateHistoryService;
 use crate::service::Service as ExchangeRateService;
+use crate::ExchangeRateProvider;
 
 #[derive(Clone, axum_macros::FromRef)]
-pub struct RouteState(pub ExchangeRateService, pub FeatureFlagsService);
+pub struct RouteState(
+    pub ExchangeRateService,
+    pub FeatureFlagsService,
+    pub RateHistoryService,
+);
 
 impl RouteState {
     pub fn unauthed_router(&self) -> Router {
@@ -40,6 +50,7 @@ impl RouteState {
                 "/api/exchange-rates/historical",
                 post(get_historical_price_data),
             )
+            .route("/api/exchange-rates/chart", get(get_price_chart))
             .with_state(self.to_owned())
     }
 }
@@ -58,9 +69,10 @@ impl From<RouteState> for SwaggerEndpoint {
         get_supported_currencies,
         get_supported_price_data,
         get_historical_price_data,
+        get_price_chart,
     ),
     components(
-        schemas(SupportedFiatCurrenciesResponse, FiatCurrency, SupportedPriceDataResponse, ExchangeRate, CurrencyData, FiatDisplayConfiguration, HistoricalPriceQuery, HistoricalPriceResponse)
+        schemas(SupportedFiatCurrenciesResponse, FiatCurrency, SupportedPriceDataResponse, ExchangeRate, CurrencyData, FiatDisplayConfiguration, SymbolPlacement, HistoricalPriceQuery, HistoricalPriceResponse, ChartRange, OhlcBucket, PriceChartQuery, PriceChartResponse)
     ),
     tags(
         (name = "Exchange Rates", description = "Exchange Rate Price Data"),
@@ -75,12 +87,34 @@ struct ApiDoc;
         (status = 200, description = "Retrieved a list of supported fiat currencies", body=SupportedFiatCurrenciesResponse)
     ),
 )]
-pub async fn get_supported_currencies() -> Result<Json<SupportedFiatCurrenciesResponse>, ApiError> {
+pub async fn get_supported_currencies(
+    State(feature_flags_service): State<FeatureFlagsService>,
+) -> Result<Json<SupportedFiatCurrenciesResponse>, ApiError> {
     Ok(Json(SupportedFiatCurrenciesResponse {
-        supported_currencies: Currency::supported_fiat_currencies(),
+        supported_currencies: Currency::fiat_currencies(spot_currency_codes(
+            &feature_flags_service,
+        )),
     }))
 }
 
+// The currencies the active spot rate provider can quote, so we never advertise a currency we
+// can't price.
+fn spot_currency_codes(feature_flags_service: &FeatureFlagsService) -> Vec<CurrencyCode> {
+    if FLAG_USE_AGGREGATE_EXCHANGE_RATE_PROVIDER
+        .resolver(feature_flags_service)
+        .resolve()
+    {
+        AggregateRateProvider::supported_currency_codes()
+    } else if FLAG_USE_CASH_EXCHANGE_RATE_PROVIDER
+        .resolver(feature_flags_service)
+        .resolve()
+    {
+        CashAppRateProvider::supported_currency_codes()
+    } else {
+        BitstampRateProvider::supported_currency_codes()
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, ToSchema)]
 pub struct SupportedFiatCurrenciesResponse {
     pub supported_currencies: Vec<FiatCurrency>,
@@ -98,11 +132,18 @@ pub async fn get_supported_price_data(
     State(exchange_rate_service): State<ExchangeRateService>,
     State(feature_flags_service): State<FeatureFlagsService>,
 ) -> Result<Json<SupportedPriceDataResponse>, ApiError> {
+    let use_aggregate_rate = FLAG_USE_AGGREGATE_EXCHANGE_RATE_PROVIDER
+        .resolver(&feature_flags_service)
+        .resolve();
     let use_cash_app_rate = FLAG_USE_CASH_EXCHANGE_RATE_PROVIDER
         .resolver(&feature_flags_service)
         .resolve();
 
-    let exchange_rates = if use_cash_app_rate {
+    let exchange_rates = if use_aggregate_rate {
+        exchange_rate_service
+            .get_latest_rates(exchange_rate_service.aggregate_rate_provider())
+            .await?
+    } else if use_cash_app_rate {
         exchange_rate_service
             .get_latest_rates(CashAppRateProvider::new())
             .await?
@@ -120,7 +161,7 @@ pub struct SupportedPriceDataResponse {
     exchange_rates: Vec<ExchangeRate>,
 }
 
-#[instrument(err, skip(exchange_rate_service))]
+#[instrument(err, skip(exchange_rate_service, rate_history_service))]
 #[utoipa::path(
     post,
     path = "/api/exchange-rates/historical",
@@ -130,10 +171,12 @@ pub struct SupportedPriceDataResponse {
 )]
 pub async fn get_historical_price_data(
     State(exchange_rate_service): State<ExchangeRateService>,
+    State(rate_history_service): State<RateHistoryService>,
     Json(request): Json<HistoricalPriceQuery>,
 ) -> Result<Json<HistoricalPriceResponse>, ApiError> {
-    let exchange_rates = exchange_rate_service
+    let exchange_rates = rate_history_service
         .get_historical_rates(
+            &exchange_rate_service,
             CoingeckoRateProvider::new(),
             request.currency_code,
             request.timestamps,
@@ -155,3 +198,48 @@ pub struct HistoricalPriceQuery {
 pub struct HistoricalPriceResponse {
     pub exchange_rates: Vec<ExchangeRate>,
 }
+
+#[instrument(err, skip(rate_history_service))]
+#[utoipa::path(
+    get,
+    path = "/api/exchange-rates/chart",
+    params(
+        ("currency_code" = String, Query, description = "ISO-4217 code of a supported currency"),
+        ("range" = ChartRange, Query, description = "The span of time to chart"),
+    ),
+    responses(
+        (status = 200, description = "Retrieve the price history of bitcoin in a specific currency over a range of time.", body=PriceChartResponse)
+    ),
+)]
+pub async fn get_price_chart(
+    State(rate_history_service): State<RateHistoryService>,
+    Query(query): Query<PriceChartQuery>,
+) -> Result<Json<PriceChartResponse>, ApiError> {
+    let buckets = rate_history_service
+        .get_chart(
+            query.currency_code.clone(),
+            query.range,
+            OffsetDateTime::now_utc(),
+        )
+        .await?;
+
+    Ok(Json(PriceChartResponse {
+        currency_code: query.currency_code,
+        range: query.range,
+        buckets,
+    }))
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PriceChartQuery {
+    #[serde(deserialize_with = "deserialize_iso_4217")]
+    currency_code: CurrencyCode,
+    range: ChartRange,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, ToSchema)]
+pub struct PriceChartResponse {
+    pub currency_code: CurrencyCode,
+    pub range: ChartRange,
+    pub buckets: Vec<OhlcBucket>,
+}
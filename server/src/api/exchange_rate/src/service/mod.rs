@@ -1,8 +1,12 @@
+use crate::aggregate::{
+    AggregateRateProvider, AggregatedRate, RateConfidence, DEFAULT_MAX_DEVIATION,
+};
 use crate::currency_conversion::SpotExchangeRateProvider;
 use crate::error::ExchangeRateError::CacheRead;
 use crate::error::{ExchangeRateError, ProviderResponseError};
 use futures::future::join_all;
 use moka::future::{Cache, CacheBuilder};
+use serde::Deserialize;
 use time::OffsetDateTime;
 use tracing::log::error;
 
@@ -13,15 +17,31 @@ use types::exchange_rate::ExchangeRate;
 
 use crate::metrics as exchange_rate_metrics;
 
+#[derive(Deserialize, Clone)]
+pub struct Config {
+    /// How far, as a fraction of the median, a provider's quote may be from the median of all
+    /// quotes before [`AggregateRateProvider`] discards it.
+    pub exchange_rate_max_deviation: f64,
+}
+
 /// A service for fetching the latest exchange rates.
 #[derive(Clone)]
 pub struct Service {
     /// A cache of exchange rates, keyed by their ISO-4217 currency code.
     cache: Cache<u16, ExchangeRate>,
+    /// A cache of aggregated rates, keyed by their ISO-4217 currency code.
+    aggregated_rates: Cache<u16, AggregatedRate>,
+    /// The last good rate aggregated by [`AggregateRateProvider`], keyed by ISO-4217 currency code.
+    last_good_aggregated_rates: Cache<u16, AggregatedRate>,
+    max_deviation: f64,
 }
 
 pub const TIME_WINDOW_DURATION: core::time::Duration = core::time::Duration::from_secs(5 * 60);
 
+/// How long [`AggregateRateProvider`] may keep serving its last good rate when every upstream
+/// provider is failing.
+pub const MAX_FALLBACK_RATE_AGE: core::time::Duration = core::time::Duration::from_secs(30 * 60);
+
 impl Default for Service {
     fn default() -> Self {
         Self::new()
@@ -34,12 +54,74 @@ impl Service {
     /// The initializer chooses a default time window of between [`TIME_WINDOW_DURATION`] ago and
     /// now, so the next call of get_latest_rates will always trigger a refresh.
     pub fn new() -> Self {
+        Self::new_with_config(Config {
+            exchange_rate_max_deviation: DEFAULT_MAX_DEVIATION,
+        })
+    }
+
+    pub fn new_with_config(config: Config) -> Self {
         // We set the max capacity to 1000 since the current max ISO-4217 currency codes is 1000.
         let cache = CacheBuilder::new(1000)
             .time_to_live(TIME_WINDOW_DURATION)
             .build();
+        let aggregated_rates = CacheBuilder::new(1000)
+            .time_to_live(TIME_WINDOW_DURATION)
+            .build();
+        let last_good_aggregated_rates = CacheBuilder::new(1000)
+            .time_to_live(MAX_FALLBACK_RATE_AGE)
+            .build();
+
+        Self {
+            cache,
+            aggregated_rates,
+            last_good_aggregated_rates,
+            max_deviation: config.exchange_rate_max_deviation,
+        }
+    }
+
+    /// Returns a provider that aggregates spot rates across all upstream providers, sharing this
+    /// service's record of the last good aggregated rates.
+    pub fn aggregate_rate_provider(&self) -> AggregateRateProvider {
+        AggregateRateProvider::new(self.max_deviation, self.last_good_aggregated_rates.clone())
+    }
+
+    /// Returns the latest aggregated rate for a given currency, along with how much it can be
+    /// trusted.
+    ///
+    /// Like [`Service::get_latest_rate`], rates are reused for [`TIME_WINDOW_DURATION`], except
+    /// for stale ones, so that a fresh rate is tried for again on the next call.
+    pub async fn get_latest_aggregated_rate(
+        &self,
+        rate_provider: AggregateRateProvider,
+        currency_code: CurrencyCode,
+    ) -> Result<AggregatedRate, ExchangeRateError> {
+        if let Currency::Bitcoin(_) = Currency::from(currency_code.clone()) {
+            return Ok(AggregatedRate {
+                rate: 1.0,
+                confidence: RateConfidence::High,
+                sources: vec![],
+                outliers: vec![],
+                time_retrieved: OffsetDateTime::now_utc(),
+            });
+        }
+
+        let cache_key = currency_code.clone() as u16;
+        if let Some(aggregated_rate) = self.aggregated_rates.get(&cache_key).await {
+            exchange_rate_metrics::GET_EXCHANGE_RATE_CACHE_HITS.add(1, &[]);
+            exchange_rate_metrics::GET_EXCHANGE_RATE.add(1, &[]);
+            return Ok(aggregated_rate);
+        }
+
+        let aggregated_rate = rate_provider.aggregated_rate(&currency_code).await?;
+        Self::measure_rate(aggregated_rate.rate, &currency_code);
+        if aggregated_rate.confidence != RateConfidence::Stale {
+            self.aggregated_rates
+                .insert(cache_key, aggregated_rate.clone())
+                .await;
+        }
+        exchange_rate_metrics::GET_EXCHANGE_RATE.add(1, &[]);
 
-        Self { cache }
+        Ok(aggregated_rate)
     }
 
     /// Returns the latest exchange rate for a given currency.
@@ -80,7 +162,7 @@ impl Service {
         Ok(new_rate.into_value())
     }
 
-    /// Returns the latest exchange rates for all supported fiat currencies.
+    /// Returns the latest exchange rates for all fiat currencies the provider supports.
     ///
     /// If what we have in-memory was less than [`TIME_WINDOW_DURATION`] ago, we return that.
     /// Otherwise, we fetch the latest rates from the provider and return those.
@@ -92,7 +174,7 @@ impl Service {
         T: SpotExchangeRateProvider + 'static + Clone,
     {
         // If one of the requests fails, we log and return `None` for that currency.
-        let request_futures = Currency::supported_currency_codes()
+        let request_futures = T::supported_currency_codes()
             .into_iter()
             .map(|currency| {
                 let self_copy = self.clone();
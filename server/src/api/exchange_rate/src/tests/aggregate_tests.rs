@@ -0,0 +1,166 @@
+use crate::aggregate::{AggregateRateProvider, RateConfidence, DEFAULT_MAX_DEVIATION};
+use crate::service::Service;
+use moka::future::CacheBuilder;
+use types::currencies::CurrencyCode::USD;
+use types::exchange_rate::bitstamp::BitstampRateProvider;
+use types::exchange_rate::cash::CashAppRateProvider;
+use types::exchange_rate::coingecko::RateProvider as CoingeckoRateProvider;
+use types::exchange_rate::coinmarketcap::RateProvider as CoinmarketcapRateProvider;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn aggregate_rate_provider(mock_server: &MockServer) -> AggregateRateProvider {
+    AggregateRateProvider {
+        bitstamp: BitstampRateProvider {
+            root_url: format!("{}/bitstamp", mock_server.uri()),
+        },
+        cash_app: CashAppRateProvider {
+            root_url: format!("{}/cash", mock_server.uri()),
+        },
+        coingecko: CoingeckoRateProvider {
+            root_url: mock_server.uri(),
+            api_key: "abc123".to_string(),
+        },
+        coinmarketcap: CoinmarketcapRateProvider {
+            root_url: format!("{}/cmc/", mock_server.uri()),
+            api_key: "abc123".to_string(),
+        },
+        max_deviation: DEFAULT_MAX_DEVIATION,
+        last_good: CacheBuilder::new(10).build(),
+    }
+}
+
+async fn mount_providers(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/bitstamp/btcusd"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(r#"{"ask": "50000.0"}"#, "application/json"),
+        )
+        .mount(mock_server)
+        .await;
+
+    let cash_app_body = r#"{
+            "exchange_data": {
+                "base_currency_code": "BTC",
+                "rates": [
+                    {
+                        "change_cents": 0,
+                        "base_value_cents": 5010000,
+                        "currency_code": "USD"
+                    }
+                ]
+            }
+        }"#;
+    Mock::given(method("POST"))
+        .and(path("/cash"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(cash_app_body, "application/json"))
+        .mount(mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/simple/price"))
+        .and(query_param("ids", "bitcoin"))
+        .and(query_param("vs_currencies", "usd"))
+        .and(query_param("x_cg_pro_api_key", "abc123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(r#"{"bitcoin": {"usd": 49900.0}}"#, "application/json"),
+        )
+        .mount(mock_server)
+        .await;
+
+    // Coinmarketcap is wildly off, and should be discarded as an outlier.
+    let coinmarketcap_body = r#"{
+            "data": {
+                "BTC": [
+                    {
+                        "quote": {
+                            "USD": {
+                                "price": 65000.0
+                            }
+                        }
+                    }
+                ]
+            }
+        }"#;
+    Mock::given(method("GET"))
+        .and(path("/cmc/cryptocurrency/quotes/latest"))
+        .and(query_param("symbol", "BTC"))
+        .and(query_param("convert", "USD"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(coinmarketcap_body, "application/json"),
+        )
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_aggregate_rate_success() {
+    let mock_server = MockServer::start().await;
+    mount_providers(&mock_server).await;
+    let provider = aggregate_rate_provider(&mock_server);
+
+    let aggregated_rate = provider.aggregated_rate(&USD).await.unwrap();
+
+    assert_eq!(aggregated_rate.rate, 50_000.0);
+    assert_eq!(aggregated_rate.confidence, RateConfidence::High);
+    assert_eq!(aggregated_rate.sources.len(), 3);
+    assert_eq!(aggregated_rate.outliers.len(), 1);
+}
+
+#[tokio::test]
+async fn test_aggregate_rate_falls_back_to_last_good_rate() {
+    let mock_server = MockServer::start().await;
+    mount_providers(&mock_server).await;
+    let provider = aggregate_rate_provider(&mock_server);
+    let fresh_rate = provider.aggregated_rate(&USD).await.unwrap();
+
+    // Every provider goes down.
+    mock_server.reset().await;
+
+    let stale_rate = provider.aggregated_rate(&USD).await.unwrap();
+    assert_eq!(stale_rate.rate, fresh_rate.rate);
+    assert_eq!(stale_rate.confidence, RateConfidence::Stale);
+    assert_eq!(stale_rate.time_retrieved, fresh_rate.time_retrieved);
+}
+
+#[tokio::test]
+async fn test_aggregate_rate_fails_without_last_good_rate() {
+    let mock_server = MockServer::start().await;
+    let provider = aggregate_rate_provider(&mock_server);
+
+    assert!(provider.aggregated_rate(&USD).await.is_err());
+}
+
+#[tokio::test]
+async fn test_latest_aggregated_rate_is_cached_unless_stale() {
+    let mock_server = MockServer::start().await;
+    let service = Service::new();
+    let provider = aggregate_rate_provider(&mock_server);
+    mount_providers(&mock_server).await;
+    provider.aggregated_rate(&USD).await.unwrap();
+
+    // Every provider goes down, so the provider can only return its last good rate.
+    mock_server.reset().await;
+    let stale_rate = service
+        .get_latest_aggregated_rate(provider.clone(), USD)
+        .await
+        .unwrap();
+    assert_eq!(stale_rate.confidence, RateConfidence::Stale);
+
+    // The stale rate isn't reused once the providers recover.
+    mount_providers(&mock_server).await;
+    let fresh_rate = service
+        .get_latest_aggregated_rate(provider.clone(), USD)
+        .await
+        .unwrap();
+    assert_eq!(fresh_rate.confidence, RateConfidence::High);
+
+    // The fresh one is, even if they go down again.
+    mock_server.reset().await;
+    let cached_rate = service
+        .get_latest_aggregated_rate(provider, USD)
+        .await
+        .unwrap();
+    assert_eq!(cached_rate.confidence, RateConfidence::High);
+}
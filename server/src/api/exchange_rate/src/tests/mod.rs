@@ -1 +1,2 @@
+mod aggregate_tests;
 mod historical_tests;
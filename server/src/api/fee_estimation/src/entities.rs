@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use time::{serde::rfc3339, OffsetDateTime};
+use utoipa::ToSchema;
+
+use crate::sources::FeeEstimateSource;
+
+/// Fee rates, in sat/vB, for confirming a transaction within each target number of blocks.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct FeeEstimates {
+    /// Fee rate to confirm within [`FAST_TARGET_BLOCKS`](crate::sources::FAST_TARGET_BLOCKS).
+    pub fast_sat_per_vb: f64,
+    /// Fee rate to confirm within [`MEDIUM_TARGET_BLOCKS`](crate::sources::MEDIUM_TARGET_BLOCKS).
+    pub medium_sat_per_vb: f64,
+    /// Fee rate to confirm within [`SLOW_TARGET_BLOCKS`](crate::sources::SLOW_TARGET_BLOCKS).
+    pub slow_sat_per_vb: f64,
+    /// When the estimates were retrieved from their sources.
+    #[serde(with = "rfc3339")]
+    pub timestamp: OffsetDateTime,
+    /// The sources that contributed to the estimates.
+    pub sources: Vec<FeeEstimateSource>,
+    /// Whether every source was unavailable, so these are the last good estimates.
+    pub stale: bool,
+}
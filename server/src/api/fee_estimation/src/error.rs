@@ -0,0 +1,32 @@
+use bdk_utils::error::BdkUtilError;
+use errors::ApiError;
+use thiserror::Error;
+
+use crate::sources::FeeEstimateSource;
+
+#[derive(Debug, Error)]
+pub enum FeeEstimationError {
+    #[error("Could not request fee estimates from {0}: {1}")]
+    Request(FeeEstimateSource, String),
+    #[error("{0} has no fee estimate for a {1} block target")]
+    MissingTarget(FeeEstimateSource, usize),
+    #[error("{0} does not support network {1}")]
+    UnsupportedNetwork(FeeEstimateSource, String),
+    #[error(transparent)]
+    BdkUtil(#[from] BdkUtilError),
+    #[error("No fee estimate source is available")]
+    Unavailable,
+}
+
+impl From<FeeEstimationError> for ApiError {
+    fn from(value: FeeEstimationError) -> Self {
+        let err_msg = value.to_string();
+        match value {
+            FeeEstimationError::Unavailable => ApiError::GenericServiceUnavailable(err_msg),
+            FeeEstimationError::UnsupportedNetwork(_, _) => ApiError::GenericBadRequest(err_msg),
+            FeeEstimationError::Request(_, _)
+            | FeeEstimationError::MissingTarget(_, _)
+            | FeeEstimationError::BdkUtil(_) => ApiError::GenericInternalApplicationError(err_msg),
+        }
+    }
+}
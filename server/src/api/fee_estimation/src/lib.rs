@@ -0,0 +1,5 @@
+pub mod entities;
+pub mod error;
+pub mod routes;
+pub mod service;
+pub mod sources;
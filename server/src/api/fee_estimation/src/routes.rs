@@ -0,0 +1,79 @@
+use account::entities::Network;
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use bdk_utils::generate_electrum_rpc_uris;
+use errors::ApiError;
+use feature_flags::service::Service as FeatureFlagsService;
+use http_server::swagger::{SwaggerEndpoint, Url};
+use serde::Deserialize;
+use tracing::instrument;
+use utoipa::OpenApi;
+
+use crate::entities::FeeEstimates;
+use crate::service::Service as FeeEstimationService;
+use crate::sources::FeeEstimateSource;
+
+#[derive(Clone, axum_macros::FromRef)]
+pub struct RouteState(pub FeeEstimationService, pub FeatureFlagsService);
+
+impl RouteState {
+    pub fn basic_validation_router(&self) -> Router {
+        Router::new()
+            .route("/api/fee-estimates", get(get_fee_estimates))
+            .with_state(self.to_owned())
+    }
+}
+
+impl From<RouteState> for SwaggerEndpoint {
+    fn from(_: RouteState) -> Self {
+        (
+            Url::new("Fee Estimation", "/docs/fee-estimation/openapi.json"),
+            ApiDoc::openapi(),
+        )
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_fee_estimates,
+    ),
+    components(
+        schemas(FeeEstimates, FeeEstimateSource)
+    ),
+    tags(
+        (name = "Fee Estimation", description = "Transaction Fee Rate Estimates")
+    )
+)]
+struct ApiDoc;
+
+#[derive(Debug, Deserialize)]
+pub struct FeeEstimatesQuery {
+    network: Network,
+}
+
+#[instrument(err, skip(fee_estimation_service, feature_flags_service))]
+#[utoipa::path(
+    get,
+    path = "/api/fee-estimates",
+    params(
+        ("network" = String, Query, description = "The network to estimate fees for: bitcoin-main, bitcoin-test, bitcoin-signet or bitcoin-regtest"),
+    ),
+    responses(
+        (status = 200, description = "Fee rates in sat/vB for fast, medium and slow confirmation", body=FeeEstimates),
+        (status = 503, description = "No fee estimate source is available"),
+    ),
+)]
+pub async fn get_fee_estimates(
+    State(fee_estimation_service): State<FeeEstimationService>,
+    State(feature_flags_service): State<FeatureFlagsService>,
+    Query(query): Query<FeeEstimatesQuery>,
+) -> Result<Json<FeeEstimates>, ApiError> {
+    let rpc_uris = generate_electrum_rpc_uris(&feature_flags_service)?;
+    let fee_estimates = fee_estimation_service
+        .get_fee_estimates(query.network.into(), &rpc_uris)
+        .await?;
+
+    Ok(Json(fee_estimates))
+}
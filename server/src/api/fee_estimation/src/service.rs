@@ -0,0 +1,96 @@
+use bdk_utils::bdk::bitcoin::Network;
+use bdk_utils::ElectrumRpcUris;
+use moka::future::{Cache, CacheBuilder};
+use time::OffsetDateTime;
+use tracing::{event, instrument, Level};
+
+use crate::entities::FeeEstimates;
+use crate::error::FeeEstimationError;
+use crate::sources::esplora::EsploraClient;
+use crate::sources::{combine, electrum, TargetFeeRates};
+
+/// How long fee estimates are served from the cache before the sources are asked again.
+pub const CACHE_DURATION: core::time::Duration = core::time::Duration::from_secs(60);
+
+/// How long the last good estimates may be served when every source is unavailable.
+pub const MAX_FALLBACK_ESTIMATE_AGE: core::time::Duration =
+    core::time::Duration::from_secs(30 * 60);
+
+/// A service for estimating fee rates by combining mempool.space's Esplora API with the
+/// configured Electrum server.
+#[derive(Clone)]
+pub struct Service {
+    esplora: EsploraClient,
+    cache: Cache<Network, FeeEstimates>,
+    last_good: Cache<Network, FeeEstimates>,
+}
+
+impl Default for Service {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Service {
+    pub fn new() -> Self {
+        Self {
+            esplora: EsploraClient::new(),
+            cache: CacheBuilder::new(8).time_to_live(CACHE_DURATION).build(),
+            last_good: CacheBuilder::new(8)
+                .time_to_live(MAX_FALLBACK_ESTIMATE_AGE)
+                .build(),
+        }
+    }
+
+    /// Returns fee estimates for the network. If one source is unavailable, the estimates come
+    /// from the other alone; if both are, the last good estimates are returned marked as stale.
+    #[instrument(skip(self, rpc_uris))]
+    pub async fn get_fee_estimates(
+        &self,
+        network: Network,
+        rpc_uris: &ElectrumRpcUris,
+    ) -> Result<FeeEstimates, FeeEstimationError> {
+        if let Some(fee_estimates) = self.cache.get(&network).await {
+            return Ok(fee_estimates);
+        }
+
+        let timestamp = OffsetDateTime::now_utc();
+        let (esplora, electrum) = futures::join!(
+            self.esplora.fee_estimates(network),
+            electrum::fee_estimates(network, rpc_uris),
+        );
+        let estimates = [esplora, electrum]
+            .into_iter()
+            .filter_map(|result| {
+                result
+                    .map_err(|e| event!(Level::WARN, "Failed to get fee estimates: {e}"))
+                    .ok()
+            })
+            .collect::<Vec<TargetFeeRates>>();
+
+        match combine(estimates, timestamp) {
+            Some(fee_estimates) => {
+                self.cache.insert(network, fee_estimates.clone()).await;
+                self.last_good.insert(network, fee_estimates.clone()).await;
+                Ok(fee_estimates)
+            }
+            None => {
+                let last_good = self
+                    .last_good
+                    .get(&network)
+                    .await
+                    .ok_or(FeeEstimationError::Unavailable)?;
+
+                event!(
+                    Level::WARN,
+                    "No fee estimate source available for {network}, falling back to estimates from {}",
+                    last_good.timestamp
+                );
+                Ok(FeeEstimates {
+                    stale: true,
+                    ..last_good
+                })
+            }
+        }
+    }
+}
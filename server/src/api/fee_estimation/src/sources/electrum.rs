@@ -0,0 +1,44 @@
+use bdk_utils::bdk::bitcoin::Network;
+use bdk_utils::bdk::electrum_client::ElectrumApi;
+use bdk_utils::{get_electrum_client, ElectrumRpcUris};
+
+use super::{
+    FeeEstimateSource, TargetFeeRates, FAST_TARGET_BLOCKS, MEDIUM_TARGET_BLOCKS, SLOW_TARGET_BLOCKS,
+};
+use crate::error::FeeEstimationError;
+
+const SOURCE: FeeEstimateSource = FeeEstimateSource::Electrum;
+
+/// Electrum servers return fee rates in BTC/kvB.
+const SAT_PER_VB_PER_BTC_PER_KVB: f64 = 100_000.0;
+
+/// Requests fee estimates from the Electrum server configured for the network. The Electrum
+/// client is blocking, so the request runs on the blocking thread pool.
+pub async fn fee_estimates(
+    network: Network,
+    rpc_uris: &ElectrumRpcUris,
+) -> Result<TargetFeeRates, FeeEstimationError> {
+    let rpc_uris = rpc_uris.clone();
+    let targets = [FAST_TARGET_BLOCKS, MEDIUM_TARGET_BLOCKS, SLOW_TARGET_BLOCKS];
+
+    let rates = tokio::task::spawn_blocking(move || {
+        get_electrum_client(network, &rpc_uris)?
+            .batch_estimate_fee(targets)
+            .map_err(|e| FeeEstimationError::Request(SOURCE, e.to_string()))
+    })
+    .await
+    .map_err(|e| FeeEstimationError::Request(SOURCE, e.to_string()))??;
+
+    // A negative rate means the server doesn't have enough data for the target.
+    let rate = |index: usize| match rates.get(index) {
+        Some(rate) if *rate > 0.0 => Ok(rate * SAT_PER_VB_PER_BTC_PER_KVB),
+        _ => Err(FeeEstimationError::MissingTarget(SOURCE, targets[index])),
+    };
+
+    Ok(TargetFeeRates {
+        source: SOURCE,
+        fast: rate(0)?,
+        medium: rate(1)?,
+        slow: rate(2)?,
+    })
+}
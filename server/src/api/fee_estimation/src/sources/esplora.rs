@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bdk_utils::bdk::bitcoin::Network;
+use reqwest::Client;
+
+use super::{
+    FeeEstimateSource, TargetFeeRates, FAST_TARGET_BLOCKS, MEDIUM_TARGET_BLOCKS, SLOW_TARGET_BLOCKS,
+};
+use crate::error::FeeEstimationError;
+
+const SOURCE: FeeEstimateSource = FeeEstimateSource::Esplora;
+
+/// How long to wait for Esplora before giving up on it, so a slow upstream can't hold up fee
+/// estimation while the other sources have already answered.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Requests fee estimates from mempool.space's Esplora-compatible API.
+#[derive(Clone)]
+pub struct EsploraClient {
+    client: Client,
+}
+
+impl Default for EsploraClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EsploraClient {
+    pub fn new() -> Self {
+        Self {
+            client: Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .expect("Failed to build Esplora client"),
+        }
+    }
+
+    fn root_url(network: Network) -> Result<&'static str, FeeEstimationError> {
+        match network {
+            Network::Bitcoin => Ok("https://mempool.space/api"),
+            Network::Testnet => Ok("https://mempool.space/testnet/api"),
+            Network::Signet => Ok("https://bitkey.mempool.space/signet/api"),
+            _ => Err(FeeEstimationError::UnsupportedNetwork(
+                SOURCE,
+                network.to_string(),
+            )),
+        }
+    }
+
+    pub async fn fee_estimates(
+        &self,
+        network: Network,
+    ) -> Result<TargetFeeRates, FeeEstimationError> {
+        let url = format!("{}/fee-estimates", Self::root_url(network)?);
+        // Esplora keys fee rates in sat/vB by confirmation target in blocks.
+        let estimates = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| FeeEstimationError::Request(SOURCE, e.to_string()))?
+            .json::<HashMap<String, f64>>()
+            .await
+            .map_err(|e| FeeEstimationError::Request(SOURCE, e.to_string()))?;
+
+        let rate = |target: usize| {
+            estimates
+                .get(&target.to_string())
+                .copied()
+                .ok_or(FeeEstimationError::MissingTarget(SOURCE, target))
+        };
+
+        Ok(TargetFeeRates {
+            source: SOURCE,
+            fast: rate(FAST_TARGET_BLOCKS)?,
+            medium: rate(MEDIUM_TARGET_BLOCKS)?,
+            slow: rate(SLOW_TARGET_BLOCKS)?,
+        })
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
+
+use crate::entities::FeeEstimates;
+
+pub mod electrum;
+pub mod esplora;
+
+pub const FAST_TARGET_BLOCKS: usize = 1;
+pub const MEDIUM_TARGET_BLOCKS: usize = 3;
+pub const SLOW_TARGET_BLOCKS: usize = 6;
+
+/// The lowest fee rate, in sat/vB, that nodes relay by default.
+pub const MIN_RELAY_FEE_RATE: f64 = 1.0;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum FeeEstimateSource {
+    Esplora,
+    Electrum,
+}
+
+impl fmt::Display for FeeEstimateSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeeEstimateSource::Esplora => write!(f, "Esplora"),
+            FeeEstimateSource::Electrum => write!(f, "Electrum"),
+        }
+    }
+}
+
+/// A single source's fee rates, in sat/vB, for each confirmation target.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TargetFeeRates {
+    pub source: FeeEstimateSource,
+    pub fast: f64,
+    pub medium: f64,
+    pub slow: f64,
+}
+
+/// Combines the estimates of every source that answered by taking the highest rate for each
+/// target, since underpaying costs the customer more than overpaying slightly. Rates are floored
+/// at the minimum relay fee and made non-increasing from the fast to the slow target. Returns
+/// `None` if no source answered.
+pub(crate) fn combine(
+    estimates: Vec<TargetFeeRates>,
+    timestamp: OffsetDateTime,
+) -> Option<FeeEstimates> {
+    let usable = estimates
+        .into_iter()
+        .filter(|e| [e.fast, e.medium, e.slow].iter().all(|r| r.is_finite()))
+        .collect::<Vec<_>>();
+    if usable.is_empty() {
+        return None;
+    }
+
+    let highest = |rate: fn(&TargetFeeRates) -> f64| {
+        usable.iter().map(rate).fold(MIN_RELAY_FEE_RATE, f64::max)
+    };
+    let fast_sat_per_vb = highest(|e| e.fast);
+    let medium_sat_per_vb = highest(|e| e.medium).min(fast_sat_per_vb);
+    let slow_sat_per_vb = highest(|e| e.slow).min(medium_sat_per_vb);
+
+    Some(FeeEstimates {
+        fast_sat_per_vb,
+        medium_sat_per_vb,
+        slow_sat_per_vb,
+        timestamp,
+        sources: usable.iter().map(|e| e.source).collect(),
+        stale: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::{combine, FeeEstimateSource, TargetFeeRates};
+
+    #[test]
+    fn test_combine_takes_highest_rate_per_target() {
+        let estimates = vec![
+            TargetFeeRates {
+                source: FeeEstimateSource::Esplora,
+                fast: 40.0,
+                medium: 20.0,
+                slow: 12.0,
+            },
+            TargetFeeRates {
+                source: FeeEstimateSource::Electrum,
+                fast: 35.0,
+                medium: 25.0,
+                slow: 8.0,
+            },
+        ];
+
+        let fee_estimates = combine(estimates, OffsetDateTime::now_utc()).unwrap();
+        assert_eq!(fee_estimates.fast_sat_per_vb, 40.0);
+        assert_eq!(fee_estimates.medium_sat_per_vb, 25.0);
+        assert_eq!(fee_estimates.slow_sat_per_vb, 12.0);
+        assert_eq!(
+            fee_estimates.sources,
+            vec![FeeEstimateSource::Esplora, FeeEstimateSource::Electrum]
+        );
+        assert!(!fee_estimates.stale);
+    }
+
+    #[test]
+    fn test_combine_floors_and_orders_rates() {
+        let estimates = vec![TargetFeeRates {
+            source: FeeEstimateSource::Electrum,
+            fast: 5.0,
+            medium: 7.0,
+            slow: 0.2,
+        }];
+
+        let fee_estimates = combine(estimates, OffsetDateTime::now_utc()).unwrap();
+        assert_eq!(fee_estimates.fast_sat_per_vb, 5.0);
+        assert_eq!(fee_estimates.medium_sat_per_vb, 5.0);
+        assert_eq!(fee_estimates.slow_sat_per_vb, 1.0);
+    }
+
+    #[test]
+    fn test_combine_without_estimates() {
+        assert!(combine(vec![], OffsetDateTime::now_utc()).is_none());
+        assert!(combine(
+            vec![TargetFeeRates {
+                source: FeeEstimateSource::Esplora,
+                fast: f64::NAN,
+                medium: 2.0,
+                slow: 1.0,
+            }],
+            OffsetDateTime::now_utc()
+        )
+        .is_none());
+    }
+}
@@ -22,6 +22,27 @@ pub struct SpendingEntry {
     pub timestamp: OffsetDateTime,
     /// The total amount of money leaving the customers wallet, this should be the sum of outputs minus change
     pub outflow_amount: u64,
+    /// Set if the spend speeds up an earlier spend, in which case `outflow_amount` is only the fee
+    /// it adds on top of the earlier spend.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_bump: Option<FeeBump>,
+}
+
+/// How a fee bump speeds up an earlier transaction.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FeeBumpMethod {
+    /// Replace-by-fee: the bump spends the same inputs and pays the same recipients with a higher fee.
+    Rbf,
+    /// Child-pays-for-parent: the bump spends an output of the earlier transaction back to the wallet.
+    Cpfp,
+}
+
+/// Links the [`SpendingEntry`] of a fee bump to the entry of the transaction it speeds up.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FeeBump {
+    pub original_txid: Txid,
+    pub method: FeeBumpMethod,
 }
 
 /// A record of the total amount spent (or at least cosigned by f8e) by an account on a given day.
@@ -61,17 +82,31 @@ impl DailySpendingRecord {
     }
 
     pub fn update_with_psbt(&mut self, wallet: &dyn AttributableWallet, psbt: &Psbt) {
-        let tx = &psbt.unsigned_tx;
+        self.add_spending_entry(SpendingEntry {
+            txid: psbt.unsigned_tx.txid(),
+            timestamp: OffsetDateTime::now_utc(),
+            outflow_amount: get_total_outflow_for_psbt(wallet, psbt),
+            fee_bump: None,
+        });
+    }
+
+    /// Records a fee bump, counting only the fee it adds against the limit.
+    pub fn update_with_fee_bump(&mut self, psbt: &Psbt, fee_bump: FeeBump, fee_delta_sats: u64) {
+        self.add_spending_entry(SpendingEntry {
+            txid: psbt.unsigned_tx.txid(),
+            timestamp: OffsetDateTime::now_utc(),
+            outflow_amount: fee_delta_sats,
+            fee_bump: Some(fee_bump),
+        });
+    }
+
+    fn add_spending_entry(&mut self, new_entry: SpendingEntry) {
         if !self
             .spending_entries
             .iter()
-            .any(|entry| entry.txid == tx.txid())
+            .any(|entry| entry.txid == new_entry.txid)
         {
-            self.spending_entries.push(SpendingEntry {
-                txid: tx.txid(),
-                timestamp: OffsetDateTime::now_utc(),
-                outflow_amount: get_total_outflow_for_psbt(wallet, psbt),
-            });
+            self.spending_entries.push(new_entry);
             if self.spending_entries.len() > 2000 {
                 // NB: DDB max item size is 400kb
                 // With the current schema, each [SpendingEntry] is 121 bytes. That means we can have up to
@@ -94,7 +129,7 @@ impl DailySpendingRecord {
                 "{}",
                 format!(
                     "Transaction ID {} already in DailySpendingRecord, not putting it in again",
-                    tx.txid()
+                    new_entry.txid
                 )
             );
         }
@@ -128,7 +163,7 @@ mod tests {
     use types::account::identifiers::AccountId;
 
     use crate::daily_spend_record::entities::{
-        AttributableWallet, DailySpendingRecord, RETENTION_DAYS,
+        AttributableWallet, DailySpendingRecord, FeeBump, FeeBumpMethod, RETENTION_DAYS,
     };
     use crate::util::MobilepayDatetimeError;
 
@@ -218,4 +253,40 @@ mod tests {
 
         assert_eq!(spending_record.spending_entries.len(), 1)
     }
+
+    #[test]
+    fn update_spending_record_with_fee_bump() {
+        let original = Psbt::from_unsigned_tx(Transaction {
+            version: 0,
+            lock_time: LockTime::ZERO,
+            input: Vec::new(),
+            output: Vec::new(),
+        })
+        .unwrap();
+        let replacement = Psbt::from_unsigned_tx(Transaction {
+            version: 0,
+            lock_time: LockTime::from_consensus(1),
+            input: Vec::new(),
+            output: Vec::new(),
+        })
+        .unwrap();
+        let dummy_wallet = DummyWallet::new(vec![]);
+
+        let account_id = AccountId::gen().unwrap();
+        let mut spending_record =
+            DailySpendingRecord::try_new(&account_id, OffsetDateTime::now_utc().date()).unwrap();
+
+        spending_record.update_with_psbt(&dummy_wallet, &original);
+        let fee_bump = FeeBump {
+            original_txid: original.unsigned_tx.txid(),
+            method: FeeBumpMethod::Rbf,
+        };
+        spending_record.update_with_fee_bump(&replacement, fee_bump.clone(), 500);
+
+        let entries = spending_record.get_spending_entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].txid, replacement.unsigned_tx.txid());
+        assert_eq!(entries[1].outflow_amount, 500);
+        assert_eq!(entries[1].fee_bump, Some(fee_bump));
+    }
 }
@@ -2,11 +2,14 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use account::spend_limit::SpendingLimit;
+use fee_estimation::entities::FeeEstimates;
 
 #[derive(Debug, Default)]
 pub struct Features {
     pub settings: Settings,
     pub daily_limit_sats: u64,
+    /// Current fee estimates for the wallet's network, if any source was available.
+    pub fee_estimates: Option<FeeEstimates>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
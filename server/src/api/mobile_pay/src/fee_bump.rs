@@ -0,0 +1,249 @@
+use std::collections::HashSet;
+
+use bdk_utils::bdk::bitcoin::psbt::Psbt;
+use bdk_utils::bdk::bitcoin::OutPoint;
+use bdk_utils::bdk::psbt::PsbtUtils;
+use bdk_utils::AttributableWallet;
+
+use crate::daily_spend_record::entities::FeeBumpMethod;
+use crate::util::get_external_outputs_for_psbt;
+
+/// A transaction that only adds fees on top of one the server has already cosigned.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ValidFeeBump {
+    pub method: FeeBumpMethod,
+    /// The fee the bump pays on top of the original transaction.
+    pub fee_delta_sats: u64,
+}
+
+/// Checks that `bump` speeds up `original` without moving any funds other than the added fee.
+///
+/// A bump that shares inputs with the original is treated as a replacement (RBF), and one that
+/// spends an output of the original as a child (CPFP).
+pub(crate) fn validate_fee_bump(
+    wallet: &dyn AttributableWallet,
+    original: &Psbt,
+    bump: &Psbt,
+) -> Result<ValidFeeBump, String> {
+    let original_txid = original.unsigned_tx.txid();
+    let original_inputs = spent_outpoints(original);
+
+    if bump
+        .unsigned_tx
+        .input
+        .iter()
+        .any(|input| original_inputs.contains(&input.previous_output))
+    {
+        validate_replacement(wallet, original, bump)
+    } else if bump
+        .unsigned_tx
+        .input
+        .iter()
+        .any(|input| input.previous_output.txid == original_txid)
+    {
+        validate_child(wallet, bump)
+    } else {
+        Err("Transaction neither replaces nor spends from the original transaction".to_string())
+    }
+}
+
+fn validate_replacement(
+    wallet: &dyn AttributableWallet,
+    original: &Psbt,
+    replacement: &Psbt,
+) -> Result<ValidFeeBump, String> {
+    if replacement.unsigned_tx.input.len() != original.unsigned_tx.input.len()
+        || spent_outpoints(replacement) != spent_outpoints(original)
+    {
+        return Err(
+            "Replacement must spend exactly the same inputs as the original transaction"
+                .to_string(),
+        );
+    }
+
+    let recipients = |psbt: &Psbt| {
+        let mut outputs = get_external_outputs_for_psbt(wallet, psbt)
+            .into_iter()
+            .map(|output| (output.script_pubkey.clone(), output.value))
+            .collect::<Vec<_>>();
+        outputs.sort();
+        outputs
+    };
+    if recipients(replacement) != recipients(original) {
+        return Err(
+            "Replacement must pay the same recipients the same amounts as the original transaction"
+                .to_string(),
+        );
+    }
+
+    // The inputs are the same, so any value taken out of the outputs goes to the fee.
+    let output_total = |psbt: &Psbt| -> u64 {
+        psbt.unsigned_tx
+            .output
+            .iter()
+            .map(|output| output.value)
+            .sum()
+    };
+    let fee_delta_sats = output_total(original)
+        .checked_sub(output_total(replacement))
+        .filter(|delta| *delta > 0)
+        .ok_or_else(|| {
+            "Replacement must pay a higher fee than the original transaction".to_string()
+        })?;
+
+    Ok(ValidFeeBump {
+        method: FeeBumpMethod::Rbf,
+        fee_delta_sats,
+    })
+}
+
+fn validate_child(wallet: &dyn AttributableWallet, child: &Psbt) -> Result<ValidFeeBump, String> {
+    if !wallet
+        .all_inputs_are_from_self(child)
+        .map_err(|err| format!("Invalid child transaction for given wallet: {err}"))?
+    {
+        return Err("Child transaction must only spend from the wallet".to_string());
+    }
+    if !wallet.is_addressed_to_self(child).unwrap_or(false) {
+        return Err("Child transaction must only pay back to the wallet".to_string());
+    }
+
+    let fee_delta_sats = child
+        .fee_amount()
+        .ok_or_else(|| "Unable to determine child transaction fee".to_string())?;
+
+    Ok(ValidFeeBump {
+        method: FeeBumpMethod::Cpfp,
+        fee_delta_sats,
+    })
+}
+
+fn spent_outpoints(psbt: &Psbt) -> HashSet<OutPoint> {
+    psbt.unsigned_tx
+        .input
+        .iter()
+        .map(|input| input.previous_output)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use bdk_utils::bdk::bitcoin::absolute::LockTime;
+    use bdk_utils::bdk::bitcoin::psbt::{Input, Output, Psbt};
+    use bdk_utils::bdk::bitcoin::{OutPoint, Sequence, Transaction, TxIn, TxOut, Witness};
+    use bdk_utils::bdk::database::AnyDatabase;
+    use bdk_utils::bdk::wallet::{get_funded_wallet, AddressIndex};
+    use bdk_utils::bdk::{FeeRate, Wallet};
+
+    use crate::daily_spend_record::entities::FeeBumpMethod;
+    use crate::fee_bump::{validate_fee_bump, ValidFeeBump};
+
+    fn wallet_and_original() -> (Wallet<AnyDatabase>, Psbt, usize) {
+        let wallet = get_funded_wallet("wpkh([c258d2e4/84h/1h/0h]tpubDDYkZojQFQjht8Tm4jsS3iuEmKjTiEGjG6KnuFNKKJb5A6ZUCUZKdvLdSDWofKi4ToRCwb9poe1XdqfUnP4jaJjCB2Zwv11ZLgSbnZSNecE/0/*)").0;
+        let recipient_wallet = get_funded_wallet("wpkh([c258d2e4/84h/1h/0h]tpubDDYkZojQFQjht8Tm4jsS3iuEmKjTiEGjG6KnuFNKKJb5A6ZUCUZKdvLdSDWofKi4ToRCwb9poe1XdqfUnP4jaJjCB2Zwv11ZLgSbnZSNecE/1/*)").0;
+        let recipient = recipient_wallet.get_address(AddressIndex::New).unwrap();
+        let mut builder = wallet.build_tx();
+        builder
+            .add_recipient(recipient.script_pubkey(), 1_000)
+            .fee_rate(FeeRate::from_sat_per_vb(5.0));
+        let (psbt, _) = builder.finish().unwrap();
+        let change_index = psbt
+            .outputs
+            .iter()
+            .position(|output| !output.bip32_derivation.is_empty())
+            .unwrap();
+        (wallet, psbt, change_index)
+    }
+
+    #[test]
+    fn replacement_with_lower_change_is_valid() {
+        let (wallet, original, change_index) = wallet_and_original();
+        let mut replacement = original.clone();
+        replacement.unsigned_tx.output[change_index].value -= 500;
+
+        assert_eq!(
+            validate_fee_bump(&wallet, &original, &replacement),
+            Ok(ValidFeeBump {
+                method: FeeBumpMethod::Rbf,
+                fee_delta_sats: 500,
+            })
+        );
+    }
+
+    #[test]
+    fn replacement_changing_recipients_is_invalid() {
+        let (wallet, original, change_index) = wallet_and_original();
+        let mut replacement = original.clone();
+        let recipient_index = 1 - change_index;
+        replacement.unsigned_tx.output[recipient_index].value += 500;
+        replacement.unsigned_tx.output[change_index].value -= 1_000;
+
+        assert!(validate_fee_bump(&wallet, &original, &replacement).is_err());
+    }
+
+    #[test]
+    fn replacement_without_higher_fee_is_invalid() {
+        let (wallet, original, _) = wallet_and_original();
+        let mut replacement = original.clone();
+        replacement.unsigned_tx.lock_time = LockTime::from_consensus(1);
+
+        assert!(validate_fee_bump(&wallet, &original, &replacement).is_err());
+    }
+
+    #[test]
+    fn child_paying_back_to_wallet_is_valid() {
+        let (wallet, original, change_index) = wallet_and_original();
+        let change = original.unsigned_tx.output[change_index].clone();
+        let derivation = original.outputs[change_index].bip32_derivation.clone();
+        let mut child = Psbt::from_unsigned_tx(Transaction {
+            version: 2,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint::new(original.unsigned_tx.txid(), change_index as u32),
+                script_sig: Default::default(),
+                sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: Witness::new(),
+            }],
+            output: vec![TxOut {
+                value: change.value - 300,
+                script_pubkey: change.script_pubkey.clone(),
+            }],
+        })
+        .unwrap();
+        child.inputs = vec![Input {
+            witness_utxo: Some(change),
+            bip32_derivation: derivation.clone(),
+            ..Default::default()
+        }];
+        child.outputs = vec![Output {
+            bip32_derivation: derivation,
+            ..Default::default()
+        }];
+
+        assert_eq!(
+            validate_fee_bump(&wallet, &original, &child),
+            Ok(ValidFeeBump {
+                method: FeeBumpMethod::Cpfp,
+                fee_delta_sats: 300,
+            })
+        );
+
+        // Paying anywhere other than the wallet moves more than the fee.
+        child.outputs[0].bip32_derivation.clear();
+        assert!(validate_fee_bump(&wallet, &original, &child).is_err());
+    }
+
+    #[test]
+    fn unrelated_transaction_is_invalid() {
+        let (wallet, original, _) = wallet_and_original();
+        let unrelated = Psbt::from_unsigned_tx(Transaction {
+            version: 2,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn::default()],
+            output: original.unsigned_tx.output.clone(),
+        })
+        .unwrap();
+
+        assert!(validate_fee_bump(&wallet, &original, &unrelated).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 pub mod daily_spend_record;
 pub mod entities;
+pub(crate) mod fee_bump;
 pub(crate) mod metrics;
 pub mod routes;
 pub mod signed_psbt_cache;
@@ -16,6 +16,16 @@ pub(crate) static MOBILE_PAY_COSIGN_OVERFLOW: Lazy<Counter<u64>> =
 pub(crate) static MOBILE_PAY_INPUTS_DO_NOT_BELONG_TO_SELF: Lazy<Counter<u64>> =
     Lazy::new(|| FACTORY.u64_counter("inputs_do_not_belong_to_self", None));
 
+// Counts the number of attempts to sign a Mobile Pay PSBT paying a fee rate far above the current
+// fee estimates.
+pub(crate) static MOBILE_PAY_EXCESSIVE_FEE_RATE: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("excessive_fee_rate", None));
+
+// Counts the number of times a spending limit couldn't be converted to sats because the aggregated
+// exchange rate wasn't trustworthy enough.
+pub(crate) static MOBILE_PAY_UNTRUSTED_EXCHANGE_RATE: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("untrusted_exchange_rate", None));
+
 // Histograms
 
 // Measures the spread of how long it takes to cosign a Mobile Pay transaction.
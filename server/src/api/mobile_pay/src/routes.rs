@@ -17,18 +17,24 @@ use account::service::{FetchAccountInput, Service as AccountService};
 use account::spend_limit::{Money, SpendingLimit};
 use authn_authz::key_claims::KeyClaims;
 use authn_authz::userpool::UserPoolService;
-use bdk_utils::bdk::SignOptions;
-use bdk_utils::generate_electrum_rpc_uris;
+use bdk_utils::bdk::bitcoin::Txid;
+use bdk_utils::bdk::database::AnyDatabase;
+use bdk_utils::bdk::{SignOptions, Wallet};
 use bdk_utils::{
     bdk::bitcoin::psbt::PartiallySignedTransaction as Psbt, is_psbt_addressed_to_wallet,
 };
+use bdk_utils::{generate_electrum_rpc_uris, ElectrumRpcUris};
 use bdk_utils::{AttributableWallet, DescriptorKeyset, TransactionBroadcasterTrait};
 use errors::ErrorCode::NoSpendingLimitExists;
 use errors::{ApiError, RouteError};
-use exchange_rate::currency_conversion::sats_for;
-use exchange_rate::flags::FLAG_USE_CASH_EXCHANGE_RATE_PROVIDER;
+use exchange_rate::aggregate::RateConfidence;
+use exchange_rate::currency_conversion::{sats_for, sats_for_aggregated_rate};
+use exchange_rate::flags::{
+    FLAG_USE_AGGREGATE_EXCHANGE_RATE_PROVIDER, FLAG_USE_CASH_EXCHANGE_RATE_PROVIDER,
+};
 use exchange_rate::service::Service as ExchangeRateService;
 use feature_flags::service::Service as FeatureFlagsService;
+use fee_estimation::service::Service as FeeEstimationService;
 use http_server::swagger::{SwaggerEndpoint, Url};
 use metrics::KeyValue;
 use types::account::identifiers::{AccountId, KeysetId};
@@ -39,12 +45,13 @@ use types::exchange_rate::cash::CashAppRateProvider;
 use types::exchange_rate::local_rate_provider::LocalRateProvider;
 use wsm_rust_client::{SigningService, WsmClient};
 
-use crate::daily_spend_record::entities::{DailySpendingRecord, SpendingEntry};
+use crate::daily_spend_record::entities::{DailySpendingRecord, FeeBump, SpendingEntry};
 use crate::daily_spend_record::service::Service as DailySpendRecordService;
 use crate::entities::{Features, Settings};
+use crate::fee_bump::validate_fee_bump;
 use crate::metrics as mobile_pay_metrics;
 use crate::signed_psbt_cache::service::Service as SignedPsbtCacheService;
-use crate::spend_rules::SpendRuleSet;
+use crate::spend_rules::{check_fee_bump_rate, SpendRuleSet};
 use crate::util::total_sats_spent_today;
 
 #[derive(Clone, Deserialize)]
@@ -63,6 +70,7 @@ pub struct RouteState(
     pub ExchangeRateService,
     pub SignedPsbtCacheService,
     pub FeatureFlagsService,
+    pub FeeEstimationService,
 );
 
 impl From<RouteState> for Router {
@@ -76,6 +84,10 @@ impl From<RouteState> for Router {
                 "/api/accounts/:account_id/keysets/:keyset_id/sign-transaction",
                 post(sign_transaction_with_keyset),
             )
+            .route(
+                "/api/accounts/:account_id/keysets/:keyset_id/bump-fee",
+                post(bump_fee_with_keyset),
+            )
             .route(
                 "/api/accounts/:account_id/mobile-pay",
                 put(setup_mobile_pay_for_account),
@@ -110,11 +122,12 @@ impl From<RouteState> for SwaggerEndpoint {
     paths(
         sign_transaction_with_active_keyset,
         sign_transaction_with_keyset,
+        bump_fee_with_keyset,
         setup_mobile_pay_for_account,
         get_mobile_pay_for_account,
     ),
     components(
-        schemas(CurrencyCode, SpendingLimit, Settings, Money, MobilePaySetupRequest, MobilePaySetupResponse, SignTransactionData, SignTransactionResponse)
+        schemas(CurrencyCode, SpendingLimit, Settings, Money, MobilePaySetupRequest, MobilePaySetupResponse, SignTransactionData, BumpFeeData, SignTransactionResponse)
     ),
     tags(
         (name = "Mobile Pay", description = "Spend Limits & Transaction Signing")
@@ -134,6 +147,15 @@ pub struct SignTransactionResponse {
     pub tx: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BumpFeeData {
+    /// Either a replacement for the original transaction, or a child spending one of its outputs.
+    pub psbt: String,
+    /// The ID of a transaction that was cosigned with Mobile Pay today or yesterday.
+    pub original_txid: String,
+}
+
 #[instrument(
     skip(
         account_service,
@@ -142,7 +164,8 @@ pub struct SignTransactionResponse {
         daily_spend_record_service,
         signed_psbt_cache_service,
         exchange_rate_service,
-        feature_flags_service
+        feature_flags_service,
+        fee_estimation_service
     ),
     fields(keyset_id, active_keyset_id)
 )]
@@ -157,6 +180,7 @@ async fn sign_transaction_maybe_broadcast_impl(
     exchange_rate_service: ExchangeRateService,
     signed_psbt_cache_service: SignedPsbtCacheService,
     feature_flags_service: FeatureFlagsService,
+    fee_estimation_service: FeeEstimationService,
 ) -> Result<SignTransactionResponse, ApiError> {
     let (account_id, keyset_id) = account_keyset;
 
@@ -248,9 +272,18 @@ async fn sign_transaction_maybe_broadcast_impl(
         // bundle up yesterday and today's spending records for spend rule checking
         let spending_entries = mobile_pay_spending_record.spending_entries();
 
+        // Fee estimates are only used to sanity check the fee rate, so signing goes ahead without
+        // them if no source is available.
+        let fee_estimates = fee_estimation_service
+            .get_fee_estimates(wallet.network(), &rpc_uris)
+            .await
+            .map_err(|e| event!(Level::WARN, "Unable to get fee estimates: {e}"))
+            .ok();
+
         let features = Features {
             settings: Settings { limit },
             daily_limit_sats,
+            fee_estimates,
         };
 
         SpendRuleSet::default()
@@ -273,6 +306,40 @@ async fn sign_transaction_maybe_broadcast_impl(
         None
     };
 
+    cosign_and_maybe_broadcast(
+        &keyset_id,
+        &requested_descriptor,
+        wallet,
+        &request.psbt,
+        updated_spending_record,
+        !bypass_mobile_spend_limit,
+        signing_start_time,
+        &rpc_uris,
+        &wsm_client,
+        &daily_spend_record_service,
+        &signed_psbt_cache_service,
+        transaction_broadcaster.as_ref(),
+    )
+    .await
+}
+
+/// Signs the PSBT with the server key, saves the updated daily spending record and caches the
+/// signed PSBT, and broadcasts the transaction if it's fully signed.
+#[allow(clippy::too_many_arguments)]
+async fn cosign_and_maybe_broadcast(
+    keyset_id: &KeysetId,
+    requested_descriptor: &DescriptorKeyset,
+    wallet: Wallet<AnyDatabase>,
+    psbt: &str,
+    updated_spending_record: Option<DailySpendingRecord>,
+    is_mobile_pay: bool,
+    signing_start_time: OffsetDateTime,
+    rpc_uris: &ElectrumRpcUris,
+    wsm_client: &WsmClient,
+    daily_spend_record_service: &DailySpendRecordService,
+    signed_psbt_cache_service: &SignedPsbtCacheService,
+    transaction_broadcaster: &dyn TransactionBroadcasterTrait,
+) -> Result<SignTransactionResponse, ApiError> {
     // currently, wsm constructs a BDK wallet to do its signing, so we need to construct external and internal descriptors for it
     let receiving = requested_descriptor
         .receiving()
@@ -284,7 +351,7 @@ async fn sign_transaction_maybe_broadcast_impl(
             &keyset_id.to_string(),
             &receiving.to_string(),
             &change.to_string(),
-            &request.psbt,
+            psbt,
         )
         .await
         .map_err(|err| {
@@ -303,7 +370,7 @@ async fn sign_transaction_maybe_broadcast_impl(
         (OffsetDateTime::now_utc() - signing_start_time).whole_milliseconds() as u64,
         &[KeyValue::new(
             mobile_pay_metrics::IS_MOBILE_PAY,
-            !bypass_mobile_spend_limit,
+            is_mobile_pay,
         )],
     );
 
@@ -328,12 +395,12 @@ async fn sign_transaction_maybe_broadcast_impl(
 
     if psbt_fully_signed {
         let broadcast_start_time = OffsetDateTime::now_utc();
-        transaction_broadcaster.broadcast(wallet, &mut signed_psbt, &rpc_uris)?;
+        transaction_broadcaster.broadcast(wallet, &mut signed_psbt, rpc_uris)?;
         mobile_pay_metrics::MOBILE_PAY_F8E_TIME_TO_BROADCAST.record(
             (OffsetDateTime::now_utc() - broadcast_start_time).whole_milliseconds() as u64,
             &[KeyValue::new(
                 mobile_pay_metrics::IS_MOBILE_PAY,
-                !bypass_mobile_spend_limit,
+                is_mobile_pay,
             )],
         );
     }
@@ -352,7 +419,8 @@ async fn sign_transaction_maybe_broadcast_impl(
         exchange_rate_service,
         signed_psbt_cache_service,
         request,
-        feature_flags_service
+        feature_flags_service,
+        fee_estimation_service
     )
 )]
 #[utoipa::path(
@@ -379,6 +447,7 @@ async fn sign_transaction_with_keyset(
     State(exchange_rate_service): State<ExchangeRateService>,
     State(signed_psbt_cache_service): State<SignedPsbtCacheService>,
     State(feature_flags_service): State<FeatureFlagsService>,
+    State(fee_estimation_service): State<FeeEstimationService>,
     Json(request): Json<SignTransactionData>,
 ) -> Result<Json<SignTransactionResponse>, ApiError> {
     let response = sign_transaction_maybe_broadcast_impl(
@@ -392,6 +461,7 @@ async fn sign_transaction_with_keyset(
         exchange_rate_service,
         signed_psbt_cache_service,
         feature_flags_service,
+        fee_estimation_service,
     )
     .await?;
     Ok(Json(response))
@@ -408,7 +478,8 @@ async fn sign_transaction_with_keyset(
         exchange_rate_service,
         signed_psbt_cache_service,
         request,
-        feature_flags_service
+        feature_flags_service,
+        fee_estimation_service
     )
 )]
 #[utoipa::path(
@@ -435,6 +506,7 @@ async fn sign_transaction_with_active_keyset(
     State(exchange_rate_service): State<ExchangeRateService>,
     State(signed_psbt_cache_service): State<SignedPsbtCacheService>,
     State(feature_flags_service): State<FeatureFlagsService>,
+    State(fee_estimation_service): State<FeeEstimationService>,
     Json(request): Json<SignTransactionData>,
 ) -> Result<Json<SignTransactionResponse>, ApiError> {
     let response = sign_transaction_maybe_broadcast_impl(
@@ -448,6 +520,234 @@ async fn sign_transaction_with_active_keyset(
         exchange_rate_service,
         signed_psbt_cache_service,
         feature_flags_service,
+        fee_estimation_service,
+    )
+    .await?;
+    Ok(Json(response))
+}
+
+#[instrument(
+    skip(
+        account_service,
+        wsm_client,
+        config,
+        daily_spend_record_service,
+        signed_psbt_cache_service,
+        exchange_rate_service,
+        feature_flags_service,
+        fee_estimation_service
+    ),
+    fields(active_keyset_id)
+)]
+#[allow(clippy::too_many_arguments)]
+async fn bump_fee_impl(
+    account_id: AccountId,
+    keyset_id: KeysetId,
+    account_service: AccountService,
+    wsm_client: WsmClient,
+    config: Config,
+    request: BumpFeeData,
+    transaction_broadcaster: Arc<dyn TransactionBroadcasterTrait>,
+    daily_spend_record_service: DailySpendRecordService,
+    exchange_rate_service: ExchangeRateService,
+    signed_psbt_cache_service: SignedPsbtCacheService,
+    feature_flags_service: FeatureFlagsService,
+    fee_estimation_service: FeeEstimationService,
+) -> Result<SignTransactionResponse, ApiError> {
+    let signing_start_time = OffsetDateTime::now_utc();
+
+    let psbt = Psbt::from_str(&request.psbt)
+        .map_err(|err| RouteError::InvalidPsbt(err.to_string(), request.psbt.clone()))?;
+    let original_txid = Txid::from_str(&request.original_txid).map_err(|err| {
+        ApiError::GenericBadRequest(format!("Invalid original transaction ID: {err}"))
+    })?;
+
+    // as with signing, return an already-signed bump from the cache without counting it again
+    if let Some(signed_psbt) = signed_psbt_cache_service
+        .get(psbt.unsigned_tx.txid())
+        .await?
+    {
+        return Ok(SignTransactionResponse {
+            tx: signed_psbt.psbt.to_string(),
+        });
+    }
+
+    let full_account = account_service
+        .fetch_full_account(FetchAccountInput {
+            account_id: &account_id,
+        })
+        .await?;
+    tracing::Span::current().record(
+        "active_keyset_id",
+        &full_account.active_keyset_id.to_string(),
+    );
+
+    if !full_account.is_spending_limit_active() {
+        let msg = "Attempted to bump fee with Mobile Pay when user has Mobile Pay turned off.";
+        error!("{msg}");
+        return Err(ApiError::GenericForbidden(msg.to_string()));
+    }
+    let limit = full_account
+        .spending_limit
+        .clone()
+        .ok_or(RouteError::MissingMobilePaySettings)?;
+
+    let requested_descriptor: DescriptorKeyset = full_account
+        .spending_keysets
+        .get(&keyset_id)
+        .ok_or_else(|| RouteError::NoSpendKeysetError(keyset_id.to_string()))?
+        .to_owned()
+        .into();
+
+    let rpc_uris = generate_electrum_rpc_uris(&feature_flags_service)?;
+    let wallet = requested_descriptor.generate_wallet(false, &rpc_uris)?;
+
+    // Only transactions counted against the limit can be bumped, so the original is always in the
+    // same records the fee is counted against.
+    let mobile_pay_spending_record =
+        get_mobile_pay_spending_record(&account_id, &daily_spend_record_service).await?;
+    let spending_entries = mobile_pay_spending_record.spending_entries();
+    if !spending_entries
+        .iter()
+        .any(|entry| entry.txid == original_txid)
+    {
+        return Err(ApiError::GenericBadRequest(
+            "Original transaction was not cosigned with Mobile Pay today or yesterday".to_string(),
+        ));
+    }
+    let original = signed_psbt_cache_service
+        .get(original_txid)
+        .await?
+        .ok_or_else(|| {
+            ApiError::GenericBadRequest("Original transaction is no longer available".to_string())
+        })?
+        .psbt;
+
+    let fee_bump = validate_fee_bump(&wallet, &original, &psbt).map_err(|reason| {
+        let error_message = format!("Transaction is not a valid fee bump: {reason}");
+        event!(Level::INFO, error_message);
+        ApiError::GenericBadRequest(error_message)
+    })?;
+
+    let daily_limit_sats = sats_for_limit(
+        &limit,
+        &config,
+        &exchange_rate_service,
+        &feature_flags_service,
+    )
+    .await?;
+    let total_spent = total_sats_spent_today(&spending_entries, &limit, OffsetDateTime::now_utc())
+        .map_err(ApiError::GenericBadRequest)?;
+    if total_spent + fee_bump.fee_delta_sats > daily_limit_sats {
+        mobile_pay_metrics::MOBILE_PAY_COSIGN_OVERFLOW.add(1, &[]);
+        let error_message = format!(
+            "Fee bump of {} with existing spend of {total_spent} for the day exceeds limit.",
+            fee_bump.fee_delta_sats
+        );
+        event!(Level::INFO, error_message);
+        return Err(ApiError::GenericBadRequest(error_message));
+    }
+
+    // As when signing, the fee rate is only sanity checked if there are fee estimates.
+    let fee_estimates = fee_estimation_service
+        .get_fee_estimates(wallet.network(), &rpc_uris)
+        .await
+        .map_err(|e| event!(Level::WARN, "Unable to get fee estimates: {e}"))
+        .ok();
+    let features = Features {
+        settings: Settings { limit },
+        daily_limit_sats,
+        fee_estimates,
+    };
+    check_fee_bump_rate(&original, &psbt, fee_bump.method, &features).map_err(|reason| {
+        let error_message = format!("Fee bump failed to pass spend rules: {reason}");
+        event!(Level::INFO, error_message);
+        ApiError::GenericBadRequest(error_message)
+    })?;
+
+    let mut today_spending_record = mobile_pay_spending_record.today;
+    today_spending_record.update_with_fee_bump(
+        &psbt,
+        FeeBump {
+            original_txid,
+            method: fee_bump.method,
+        },
+        fee_bump.fee_delta_sats,
+    );
+
+    cosign_and_maybe_broadcast(
+        &keyset_id,
+        &requested_descriptor,
+        wallet,
+        &request.psbt,
+        Some(today_spending_record),
+        true,
+        signing_start_time,
+        &rpc_uris,
+        &wsm_client,
+        &daily_spend_record_service,
+        &signed_psbt_cache_service,
+        transaction_broadcaster.as_ref(),
+    )
+    .await
+}
+
+#[instrument(
+    err,
+    level = "INFO",
+    skip(
+        account_service,
+        wsm_client,
+        config,
+        daily_spend_record_service,
+        exchange_rate_service,
+        signed_psbt_cache_service,
+        request,
+        feature_flags_service,
+        fee_estimation_service
+    )
+)]
+#[utoipa::path(
+    post,
+    path = "/api/accounts/{account_id}/keysets/{keyset_id}/bump-fee",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+        ("keyset_id" = KeySetId, Path, description = "KeysetId"),
+    ),
+    request_body = BumpFeeData,
+    responses(
+        (status = 200, description = "Fee bump was validated and signed with the server key in the specified keyset. Only the added fee counts against the Mobile Pay limit", body=SignTransactionResponse),
+        (status = 400, description = "Transaction isn't a fee bump of a Mobile Pay transaction, or the added fee exceeds the limit"),
+        (status = 403, description = "Mobile Pay is turned off"),
+        (status = 404, description = "Account could not be found")
+    ),
+)]
+async fn bump_fee_with_keyset(
+    Path((account_id, keyset_id)): Path<(AccountId, KeysetId)>,
+    State(account_service): State<AccountService>,
+    State(wsm_client): State<WsmClient>,
+    State(config): State<Config>,
+    State(transaction_broadcaster): State<Arc<dyn TransactionBroadcasterTrait>>,
+    State(daily_spend_record_service): State<DailySpendRecordService>,
+    State(exchange_rate_service): State<ExchangeRateService>,
+    State(signed_psbt_cache_service): State<SignedPsbtCacheService>,
+    State(feature_flags_service): State<FeatureFlagsService>,
+    State(fee_estimation_service): State<FeeEstimationService>,
+    Json(request): Json<BumpFeeData>,
+) -> Result<Json<SignTransactionResponse>, ApiError> {
+    let response = bump_fee_impl(
+        account_id,
+        keyset_id,
+        account_service,
+        wsm_client,
+        config,
+        request,
+        transaction_broadcaster,
+        daily_spend_record_service,
+        exchange_rate_service,
+        signed_psbt_cache_service,
+        feature_flags_service,
+        fee_estimation_service,
     )
     .await?;
     Ok(Json(response))
@@ -680,13 +980,21 @@ impl MobilePaySpendingRecord {
 
 enum RateProvider {
     Local(LocalRateProvider),
+    // Constructed from the exchange rate service, which holds its fallback rates.
+    Aggregate,
     CashApp(CashAppRateProvider),
     Bitstamp(BitstampRateProvider),
 }
 
-fn select_exchange_rate_provider(config: &Config, use_cash_app_rate: bool) -> RateProvider {
+fn select_exchange_rate_provider(
+    config: &Config,
+    use_aggregate_rate: bool,
+    use_cash_app_rate: bool,
+) -> RateProvider {
     if config.use_local_currency_exchange {
         RateProvider::Local(LocalRateProvider::new())
+    } else if use_aggregate_rate {
+        RateProvider::Aggregate
     } else if use_cash_app_rate {
         RateProvider::CashApp(CashAppRateProvider::new())
     } else {
@@ -700,14 +1008,43 @@ async fn sats_for_limit(
     exchange_rate_service: &ExchangeRateService,
     feature_flags_service: &FeatureFlagsService,
 ) -> Result<u64, ApiError> {
+    let use_aggregate_rate = FLAG_USE_AGGREGATE_EXCHANGE_RATE_PROVIDER
+        .resolver(feature_flags_service)
+        .resolve();
     let use_cash_app_rate = FLAG_USE_CASH_EXCHANGE_RATE_PROVIDER
         .resolver(feature_flags_service)
         .resolve();
 
-    match select_exchange_rate_provider(config, use_cash_app_rate) {
+    let conversion_error = |_| {
+        ApiError::GenericInternalApplicationError("Could not convert limit to sats".to_string())
+    };
+    match select_exchange_rate_provider(config, use_aggregate_rate, use_cash_app_rate) {
         RateProvider::Local(provider) => {
             sats_for(exchange_rate_service, provider, &limit.amount).await
         }
+        RateProvider::Aggregate => {
+            let (sats, aggregated_rate) =
+                sats_for_aggregated_rate(exchange_rate_service, &limit.amount)
+                    .await
+                    .map_err(conversion_error)?;
+
+            // A rate only one provider vouches for, or one that's no longer current, could let
+            // a spend through well above the limit, so Mobile Pay isn't available until the
+            // providers agree again.
+            if matches!(
+                aggregated_rate.confidence,
+                RateConfidence::Low | RateConfidence::Stale
+            ) {
+                mobile_pay_metrics::MOBILE_PAY_UNTRUSTED_EXCHANGE_RATE.add(1, &[]);
+                let error_message = format!(
+                    "Exchange rate for {} has {} confidence",
+                    limit.amount.currency_code, aggregated_rate.confidence
+                );
+                event!(Level::WARN, error_message);
+                return Err(ApiError::GenericServiceUnavailable(error_message));
+            }
+            Ok(sats)
+        }
         RateProvider::CashApp(provider) => {
             sats_for(exchange_rate_service, provider, &limit.amount).await
         }
@@ -715,9 +1052,7 @@ async fn sats_for_limit(
             sats_for(exchange_rate_service, provider, &limit.amount).await
         }
     }
-    .map_err(|_| {
-        ApiError::GenericInternalApplicationError("Could not convert limit to sats".to_string())
-    })
+    .map_err(conversion_error)
 }
 
 async fn get_mobile_pay_spending_record(
@@ -758,6 +1093,7 @@ mod tests {
                 use_local_currency_exchange: true,
             },
             true,
+            true,
         ) {
             RateProvider::Local(_provider) => {}
             _ => assert!(false, "Unexpected exchange rate provider returned"),
@@ -768,6 +1104,7 @@ mod tests {
                 use_local_currency_exchange: false,
             },
             false,
+            false,
         ) {
             RateProvider::Bitstamp(_provider) => {}
             _ => assert!(false, "Unexpected exchange rate provider returned"),
@@ -777,10 +1114,22 @@ mod tests {
             &Config {
                 use_local_currency_exchange: false,
             },
+            false,
             true,
         ) {
             RateProvider::CashApp(_provider) => {}
             _ => assert!(false, "Unexpected exchange rate provider returned"),
         }
+        // Return the aggregate provider, even if Cash App is also enabled
+        match select_exchange_rate_provider(
+            &Config {
+                use_local_currency_exchange: false,
+            },
+            true,
+            true,
+        ) {
+            RateProvider::Aggregate => {}
+            _ => assert!(false, "Unexpected exchange rate provider returned"),
+        }
     }
 }
@@ -134,6 +134,7 @@ mod tests {
                 )
                 .unwrap(),
                 outflow_amount: tx.sent,
+                fee_bump: None,
             })
             .collect()
     }
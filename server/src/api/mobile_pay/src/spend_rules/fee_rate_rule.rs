@@ -0,0 +1,224 @@
+use time::OffsetDateTime;
+
+use bdk_utils::bdk::bitcoin::psbt::PartiallySignedTransaction;
+use bdk_utils::bdk::database::AnyDatabase;
+use bdk_utils::bdk::psbt::PsbtUtils;
+use bdk_utils::bdk::Wallet;
+
+use crate::daily_spend_record::entities::{FeeBumpMethod, SpendingEntry};
+use crate::entities::Features;
+use crate::metrics;
+
+use super::Rule;
+
+/// How many times the fast fee estimate a transaction may pay before we refuse to cosign it.
+const MAX_FEE_RATE_MULTIPLE: f64 = 5.0;
+
+/// The lowest fee rate cap, in sat/vB. When the mempool is nearly empty, a multiple of the fast
+/// estimate is too tight to leave room for a customer bumping their fee.
+const MIN_MAX_FEE_RATE_SAT_PER_VB: f64 = 50.0;
+
+/// The PSBT isn't finalized, so its size excludes witnesses. This allows for the witness of a
+/// 2-of-3 P2WSH multisig input.
+const WITNESS_VBYTES_PER_INPUT: u64 = 64;
+
+pub(crate) struct FeeRateRule;
+
+impl Rule for FeeRateRule {
+    /// Ensure the PSBT doesn't pay a fee rate far above the current fast fee estimate, so a
+    /// compromised app can't drain funds to miners. Skipped if there are no fee estimates.
+    fn check_transaction(
+        &self,
+        _: &Wallet<AnyDatabase>,
+        psbt: &PartiallySignedTransaction,
+        features: &Features,
+        _: &Vec<&SpendingEntry>,
+        _: OffsetDateTime,
+    ) -> Result<(), String> {
+        check_fee_rate(&[psbt], features)
+    }
+}
+
+/// Ensure a fee bump doesn't pay a fee rate far above the current fast fee estimate, like
+/// [`FeeRateRule`]. A child only speeds up the original by raising the rate of the two together,
+/// so that's the rate checked for it.
+pub(crate) fn check_fee_bump_rate(
+    original: &PartiallySignedTransaction,
+    bump: &PartiallySignedTransaction,
+    method: FeeBumpMethod,
+    features: &Features,
+) -> Result<(), String> {
+    match method {
+        FeeBumpMethod::Rbf => check_fee_rate(&[bump], features),
+        FeeBumpMethod::Cpfp => check_fee_rate(&[original, bump], features),
+    }
+}
+
+// Checks the fee rate the PSBTs pay together. Skipped if there are no fee estimates.
+fn check_fee_rate(
+    psbts: &[&PartiallySignedTransaction],
+    features: &Features,
+) -> Result<(), String> {
+    let Some(fee_estimates) = &features.fee_estimates else {
+        return Ok(());
+    };
+
+    let mut fee_sats = 0;
+    let mut vsize = 0;
+    for psbt in psbts {
+        fee_sats += psbt
+            .fee_amount()
+            .ok_or_else(|| "Unable to determine PSBT fee".to_string())?;
+        vsize += psbt.unsigned_tx.vsize() as u64
+            + psbt.unsigned_tx.input.len() as u64 * WITNESS_VBYTES_PER_INPUT;
+    }
+    let fee_rate = fee_sats as f64 / vsize as f64;
+
+    let max_fee_rate =
+        (fee_estimates.fast_sat_per_vb * MAX_FEE_RATE_MULTIPLE).max(MIN_MAX_FEE_RATE_SAT_PER_VB);
+    if fee_rate <= max_fee_rate {
+        Ok(())
+    } else {
+        metrics::MOBILE_PAY_EXCESSIVE_FEE_RATE.add(1, &[]);
+        Err(format!(
+            "Transaction fee rate of {fee_rate:.1} sat/vB exceeds the maximum of {max_fee_rate:.1} sat/vB."
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use bdk_utils::bdk::bitcoin::absolute::LockTime;
+    use bdk_utils::bdk::bitcoin::psbt::{Input, Psbt};
+    use bdk_utils::bdk::bitcoin::{OutPoint, Sequence, Transaction, TxIn, TxOut, Witness};
+    use bdk_utils::bdk::wallet::{get_funded_wallet, AddressIndex};
+    use bdk_utils::bdk::FeeRate;
+    use fee_estimation::entities::FeeEstimates;
+
+    use crate::daily_spend_record::entities::FeeBumpMethod;
+    use crate::entities::Features;
+    use crate::spend_rules::fee_rate_rule::{check_fee_bump_rate, FeeRateRule};
+    use crate::spend_rules::Rule;
+
+    fn check_fee_rate(sat_per_vb: f32, fee_estimates: Option<FeeEstimates>) -> Result<(), String> {
+        let wallet = get_funded_wallet("wpkh([c258d2e4/84h/1h/0h]tpubDDYkZojQFQjht8Tm4jsS3iuEmKjTiEGjG6KnuFNKKJb5A6ZUCUZKdvLdSDWofKi4ToRCwb9poe1XdqfUnP4jaJjCB2Zwv11ZLgSbnZSNecE/0/*)").0;
+        let address = wallet.get_address(AddressIndex::New).unwrap();
+        let mut builder = wallet.build_tx();
+        builder
+            .add_recipient(address.script_pubkey(), 1_000)
+            .fee_rate(FeeRate::from_sat_per_vb(sat_per_vb));
+        let (psbt, _) = builder.finish().unwrap();
+
+        let features = Features {
+            fee_estimates,
+            ..Default::default()
+        };
+        FeeRateRule {}.check_transaction(
+            &wallet,
+            &psbt,
+            &features,
+            &Vec::new(),
+            OffsetDateTime::now_utc(),
+        )
+    }
+
+    fn fee_estimates(fast_sat_per_vb: f64) -> Option<FeeEstimates> {
+        Some(FeeEstimates {
+            fast_sat_per_vb,
+            medium_sat_per_vb: fast_sat_per_vb / 2.0,
+            slow_sat_per_vb: 1.0,
+            timestamp: OffsetDateTime::now_utc(),
+            sources: vec![],
+            stale: false,
+        })
+    }
+
+    #[test]
+    fn fee_rate_within_estimate() {
+        assert!(check_fee_rate(12.0, fee_estimates(10.0)).is_ok());
+    }
+
+    #[test]
+    fn fee_rate_far_above_estimate() {
+        assert!(check_fee_rate(500.0, fee_estimates(10.0)).is_err());
+    }
+
+    #[test]
+    fn fee_rate_within_floor_for_low_estimate() {
+        assert!(check_fee_rate(40.0, fee_estimates(1.0)).is_ok());
+        assert!(check_fee_rate(80.0, fee_estimates(1.0)).is_err());
+    }
+
+    #[test]
+    fn fee_rate_without_estimates() {
+        assert!(check_fee_rate(500.0, None).is_ok());
+    }
+
+    #[test]
+    fn fee_bump_far_above_estimate() {
+        let wallet = get_funded_wallet("wpkh([c258d2e4/84h/1h/0h]tpubDDYkZojQFQjht8Tm4jsS3iuEmKjTiEGjG6KnuFNKKJb5A6ZUCUZKdvLdSDWofKi4ToRCwb9poe1XdqfUnP4jaJjCB2Zwv11ZLgSbnZSNecE/0/*)").0;
+        let address = wallet.get_address(AddressIndex::New).unwrap();
+        let mut builder = wallet.build_tx();
+        builder
+            .add_recipient(address.script_pubkey(), 1_000)
+            .fee_rate(FeeRate::from_sat_per_vb(5.0));
+        let (original, _) = builder.finish().unwrap();
+        let features = Features {
+            fee_estimates: fee_estimates(10.0),
+            ..Default::default()
+        };
+
+        // A replacement is checked at its own rate.
+        let change_index = original
+            .unsigned_tx
+            .output
+            .iter()
+            .position(|output| output.value > 1_000)
+            .unwrap();
+        let mut replacement = original.clone();
+        replacement.unsigned_tx.output[change_index].value -= 500;
+        assert!(
+            check_fee_bump_rate(&original, &replacement, FeeBumpMethod::Rbf, &features).is_ok()
+        );
+        replacement.unsigned_tx.output[change_index].value -= 20_000;
+        assert!(
+            check_fee_bump_rate(&original, &replacement, FeeBumpMethod::Rbf, &features).is_err()
+        );
+
+        // A child is checked at the rate of the original and the child together.
+        let change = original.unsigned_tx.output[change_index].clone();
+        let child = |fee_sats: u64| {
+            let mut child = Psbt::from_unsigned_tx(Transaction {
+                version: 2,
+                lock_time: LockTime::ZERO,
+                input: vec![TxIn {
+                    previous_output: OutPoint::new(
+                        original.unsigned_tx.txid(),
+                        change_index as u32,
+                    ),
+                    script_sig: Default::default(),
+                    sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                    witness: Witness::new(),
+                }],
+                output: vec![TxOut {
+                    value: change.value - fee_sats,
+                    script_pubkey: change.script_pubkey.clone(),
+                }],
+            })
+            .unwrap();
+            child.inputs = vec![Input {
+                witness_utxo: Some(change.clone()),
+                ..Default::default()
+            }];
+            child
+        };
+        assert!(
+            check_fee_bump_rate(&original, &child(2_000), FeeBumpMethod::Cpfp, &features).is_ok()
+        );
+        assert!(
+            check_fee_bump_rate(&original, &child(20_000), FeeBumpMethod::Cpfp, &features).is_err()
+        );
+    }
+}
@@ -9,9 +9,13 @@ use crate::daily_spend_record::entities::SpendingEntry;
 use crate::entities::Features;
 
 use self::daily_spend_limit_rule::DailySpendingLimitRule;
+use self::fee_rate_rule::FeeRateRule;
 use self::valid_psbt_for_wallet_rule::ValidPsbtForWalletRule;
 
+pub(crate) use self::fee_rate_rule::check_fee_bump_rate;
+
 mod daily_spend_limit_rule;
+mod fee_rate_rule;
 mod valid_psbt_for_wallet_rule;
 
 trait Rule {
@@ -35,6 +39,7 @@ impl Default for SpendRuleSet {
             rules: vec![
                 Box::new(DailySpendingLimitRule {}),
                 Box::new(ValidPsbtForWalletRule {}),
+                Box::new(FeeRateRule {}),
             ],
         }
     }
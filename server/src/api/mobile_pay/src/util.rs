@@ -1,5 +1,6 @@
 use account::spend_limit::SpendingLimit;
 use bdk_utils::bdk::bitcoin::psbt::Psbt;
+use bdk_utils::bdk::bitcoin::TxOut;
 use bdk_utils::{AttributableWallet, PsbtWithDerivation};
 use thiserror::Error;
 use time::{Duration, OffsetDateTime, Time, UtcOffset};
@@ -28,6 +29,17 @@ impl From<MobilepayDatetimeError> for ApiError {
 }
 
 pub(crate) fn get_total_outflow_for_psbt(wallet: &dyn AttributableWallet, psbt: &Psbt) -> u64 {
+    get_external_outputs_for_psbt(wallet, psbt)
+        .iter()
+        .map(|output| output.value)
+        .sum()
+}
+
+/// Returns the outputs of the PSBT that don't pay back to the wallet.
+pub(crate) fn get_external_outputs_for_psbt<'a>(
+    wallet: &dyn AttributableWallet,
+    psbt: &'a Psbt,
+) -> Vec<&'a TxOut> {
     psbt.unsigned_tx
         .output
         .iter()
@@ -40,8 +52,8 @@ pub(crate) fn get_total_outflow_for_psbt(wallet: &dyn AttributableWallet, psbt:
                 .get_output_spk_and_derivation(*idx)
                 .is_some_and(|spk| wallet.is_my_psbt_address(&spk).is_ok_and(|x| x))
         })
-        .map(|(_idx, output)| output.value)
-        .sum()
+        .map(|(_idx, output)| output)
+        .collect()
 }
 
 pub(crate) fn total_sats_spent_today(
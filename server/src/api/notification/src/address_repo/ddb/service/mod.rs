@@ -8,7 +8,7 @@ use bdk_utils::bdk::bitcoin::Address;
 use database::ddb::{Connection, DDBService};
 use std::collections::HashMap;
 use time::OffsetDateTime;
-use types::account::identifiers::AccountId;
+use types::account::identifiers::{AccountId, KeysetId};
 
 #[derive(Debug, Clone)]
 pub struct Service {
@@ -79,4 +79,16 @@ impl AddressWatchlistTrait for Service {
             .map(|item| (item.address, item.account_id))
             .collect());
     }
+
+    async fn get_keyset_ids(
+        &self,
+        addresses: &[Address<NetworkUnchecked>],
+    ) -> Result<HashMap<Address<NetworkUnchecked>, KeysetId>, Error> {
+        let watched_addresses = self.repo.fetch_batch(addresses).await?;
+
+        Ok(watched_addresses
+            .into_iter()
+            .map(|item| (item.address, item.spending_keyset_id))
+            .collect())
+    }
 }
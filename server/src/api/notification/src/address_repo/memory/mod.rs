@@ -8,11 +8,11 @@ use bdk_utils::bdk::bitcoin::address::NetworkUnchecked;
 use bdk_utils::bdk::bitcoin::Address;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use types::account::identifiers::AccountId;
+use types::account::identifiers::{AccountId, KeysetId};
 
 #[derive(Debug, Default, Clone)]
 pub struct Service {
-    repository: Arc<Mutex<HashMap<Address<NetworkUnchecked>, AccountId>>>,
+    repository: Arc<Mutex<HashMap<Address<NetworkUnchecked>, (AccountId, KeysetId)>>>,
 }
 
 #[async_trait]
@@ -27,8 +27,12 @@ impl AddressWatchlistTrait for Service {
             .lock()
             .map_err(|err| InternalError(err.to_string()))?;
 
-        for AddressAndKeysetId { address, .. } in addresses {
-            if let Some(id) = repo.get(&address.clone()) {
+        for AddressAndKeysetId {
+            address,
+            spending_keyset_id,
+        } in addresses
+        {
+            if let Some((id, _)) = repo.get(&address.clone()) {
                 if id != account_id {
                     return Err(Error::AccountMismatchError(
                         address.clone().assume_checked().to_string(),
@@ -37,7 +41,10 @@ impl AddressWatchlistTrait for Service {
                 }
             }
 
-            repo.insert(address.clone(), account_id.clone());
+            repo.insert(
+                address.clone(),
+                (account_id.clone(), spending_keyset_id.clone()),
+            );
         }
         Ok(())
     }
@@ -53,7 +60,22 @@ impl AddressWatchlistTrait for Service {
 
         return Ok(addrs
             .iter()
-            .filter_map(|k| repo.get(k).map(|v| (k.clone(), v.clone())))
+            .filter_map(|k| repo.get(k).map(|(v, _)| (k.clone(), v.clone())))
             .collect());
     }
+
+    async fn get_keyset_ids(
+        &self,
+        addrs: &[Address<NetworkUnchecked>],
+    ) -> Result<HashMap<Address<NetworkUnchecked>, KeysetId>, Error> {
+        let repo = self
+            .repository
+            .lock()
+            .map_err(|err| InternalError(err.to_string()))?;
+
+        Ok(addrs
+            .iter()
+            .filter_map(|k| repo.get(k).map(|(_, v)| (k.clone(), v.clone())))
+            .collect())
+    }
 }
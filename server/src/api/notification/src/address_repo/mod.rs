@@ -34,6 +34,13 @@ pub trait AddressWatchlistTrait: DynClone + fmt::Debug + Send + Sync {
         &self,
         addresses: &[Address<NetworkUnchecked>],
     ) -> Result<HashMap<Address<NetworkUnchecked>, AccountId>, Error>;
+
+    /// Query for a set of addresses, returning a map of Address->KeysetId for the addresses
+    /// that are known by the AddressWatchlist
+    async fn get_keyset_ids(
+        &self,
+        addresses: &[Address<NetworkUnchecked>],
+    ) -> Result<HashMap<Address<NetworkUnchecked>, KeysetId>, Error>;
 }
 
 dyn_clone::clone_trait_object!(AddressWatchlistTrait);
@@ -122,6 +122,32 @@ async fn test_batch_insert_succeeds(
     );
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_get_keyset_ids_returns_inserted_keysets(
+    #[values(testnet_address(), mainnet_address())] addr1: AddressAndKeysetId,
+    #[values(testnet_address(), mainnet_address())] addr2: AddressAndKeysetId,
+    #[values(memory_repo(), ddb_repo().await)] mut repo: impl AddressWatchlistTrait,
+) {
+    let acct_id = AccountId::gen().unwrap();
+
+    repo.insert(&[addr1.clone(), addr2.clone()], &acct_id)
+        .await
+        .unwrap();
+    let keyset_ids = repo
+        .get_keyset_ids(&[addr1.address.clone(), addr2.address.clone()])
+        .await
+        .unwrap();
+    assert_eq!(
+        &addr1.spending_keyset_id,
+        keyset_ids.get(&addr1.address).unwrap()
+    );
+    assert_eq!(
+        &addr2.spending_keyset_id,
+        keyset_ids.get(&addr2.address).unwrap()
+    );
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_get_without_insert_returns_none(
@@ -21,6 +21,16 @@ pub enum NotificationClientsError {
     TwilioLookupError,
     #[error("Unsupported SMS country code")]
     TwilioUnsupportedSmsCountryCodeError,
+    #[error("Twilio status callback URL is invalid")]
+    TwilioInvalidStatusCallbackError,
+    #[error("Webhook endpoint did not answer the challenge")]
+    WebhookChallengeFailedError,
+    #[error("Webhook endpoint rejected the delivery with status {0}")]
+    WebhookDeliveryError(reqwest::StatusCode),
+    #[error("Webhook endpoint doesn't resolve to a public address")]
+    WebhookDisallowedDestinationError,
+    #[error(transparent)]
+    ReqwestMiddlewareError(#[from] reqwest_middleware::Error),
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
     #[error(transparent)]
@@ -31,6 +41,22 @@ pub enum NotificationClientsError {
     Base64DecodeError(#[from] base64::DecodeError),
 }
 
+impl NotificationClientsError {
+    /// Whether a webhook delivery that failed with this error is worth attempting again later:
+    /// connection failures, timeouts, and 5xx, 408 or 429 responses.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            NotificationClientsError::WebhookDeliveryError(status) => {
+                status.is_server_error()
+                    || *status == reqwest::StatusCode::REQUEST_TIMEOUT
+                    || *status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            NotificationClientsError::ReqwestError(e) => e.is_connect() || e.is_timeout(),
+            _ => false,
+        }
+    }
+}
+
 impl From<NotificationClientsError> for ApiError {
     fn from(val: NotificationClientsError) -> Self {
         let err_msg = val.to_string();
@@ -43,7 +69,10 @@ impl From<NotificationClientsError> for ApiError {
             | NotificationClientsError::TwilioCreateMessageError
             | NotificationClientsError::TwilioLookupError
             | NotificationClientsError::TwilioUnsupportedSmsCountryCodeError
+            | NotificationClientsError::TwilioInvalidStatusCallbackError
+            | NotificationClientsError::WebhookDeliveryError(_)
             | NotificationClientsError::ReqwestError(_)
+            | NotificationClientsError::ReqwestMiddlewareError(_)
             | NotificationClientsError::Sha1InvalidKeyLength(_) => {
                 ApiError::GenericInternalApplicationError(err_msg)
             }
@@ -52,6 +81,10 @@ impl From<NotificationClientsError> for ApiError {
                 detail: Some(err_msg),
                 field: None,
             },
+            NotificationClientsError::WebhookChallengeFailedError
+            | NotificationClientsError::WebhookDisallowedDestinationError => {
+                ApiError::GenericBadRequest(err_msg)
+            }
             NotificationClientsError::MacError(_)
             | NotificationClientsError::Base64DecodeError(_) => {
                 ApiError::GenericUnauthorized(err_msg)
@@ -1,3 +1,4 @@
 pub mod error;
 pub mod iterable;
 pub mod twilio;
+pub mod webhook;
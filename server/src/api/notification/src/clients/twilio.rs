@@ -19,6 +19,7 @@ use tracing::instrument;
 use tracing::Level;
 
 use crate::clients::error::NotificationClientsError;
+use crate::entities::NotificationCompositeKey;
 use crate::metrics;
 
 const TWILIO_API_URL: &str = "https://api.twilio.com/2010-04-01/";
@@ -158,6 +159,7 @@ impl TwilioClient {
         country_code: CountryCode,
         to: String,
         body: String,
+        notification: &NotificationCompositeKey,
     ) -> Result<(), NotificationClientsError> {
         if !self.is_supported_sms_country_code(country_code) {
             event!(
@@ -192,7 +194,17 @@ impl TwilioClient {
                 }
 
                 params.insert("MessagingServiceSid", messaging_service_sid.to_owned());
-                params.insert("StatusCallback", status_callback.clone());
+                // Status callbacks identify the notification so failed deliveries can fail over
+                let (account_id, notification_id) = notification;
+                let status_callback = reqwest::Url::parse_with_params(
+                    status_callback,
+                    &[
+                        ("account_id", account_id.to_string()),
+                        ("notification_id", notification_id.to_string()),
+                    ],
+                )
+                .map_err(|_| NotificationClientsError::TwilioCreateMessageError)?;
+                params.insert("StatusCallback", status_callback.to_string());
 
                 let response = client
                     .post(
@@ -280,6 +292,7 @@ impl TwilioClient {
     pub fn validate_callback_signature(
         &self,
         request: &HashMap<String, String>,
+        query: Option<&str>,
         signature: String,
     ) -> Result<(), NotificationClientsError> {
         match self {
@@ -290,12 +303,16 @@ impl TwilioClient {
             } => {
                 // https://github.com/twilio/twilio-python/blob/main/twilio/request_validator.py
 
+                // Twilio signs the exact URL it called, which is the status callback with the
+                // query it was sent with
+                let mut url = reqwest::Url::parse(status_callback)
+                    .map_err(|_| NotificationClientsError::TwilioInvalidStatusCallbackError)?;
+                url.set_query(query);
+                let url = url.to_string();
                 let to_sign = request
                     .iter()
                     .sorted_by(|a, b| Ord::cmp(a.0, b.0))
-                    .fold(status_callback.clone(), |acc, (key, value)| {
-                        format!("{}{}{}", acc, key, value)
-                    });
+                    .fold(url, |acc, (key, value)| format!("{}{}{}", acc, key, value));
 
                 let mut mac = Hmac::<Sha1>::new_from_slice(auth_token.as_bytes())?;
                 mac.update(to_sign.as_bytes());
@@ -342,7 +359,7 @@ mod tests {
 
     #[test]
     fn test_validate_callback_signature() {
-        let client = TwilioClient::Real {
+        let mut client = TwilioClient::Real {
             endpoint: reqwest::Url::try_from("http://example.com").unwrap(),
             client: reqwest::Client::new(),
             account_sid: "".to_string(),
@@ -351,7 +368,7 @@ mod tests {
             key_secret: "".to_string(),
             default_messaging_service_sid: "".to_string(),
             messaging_service_sid_overrides: HashMap::new(),
-            status_callback: "https://mycompany.com/myapp.php?foo=1&bar=2".to_string(),
+            status_callback: "https://mycompany.com/myapp.php".to_string(),
         };
 
         // https://www.twilio.com/docs/usage/security#explore-the-algorithm-yourself
@@ -363,8 +380,26 @@ mod tests {
             ("CallSid".to_string(), "CA1234567890ABCDE".to_string()),
         ]);
 
-        let result = client
-            .validate_callback_signature(&request, "GvWf1cFY/Q7PnoempGyD5oXAezc=".to_string());
+        let result = client.validate_callback_signature(
+            &request,
+            Some("foo=1&bar=2"),
+            "GvWf1cFY/Q7PnoempGyD5oXAezc=".to_string(),
+        );
+        assert!(result.is_ok(), "{:?}", result);
+
+        // The query Twilio calls back with already includes any the status callback was sent with
+        let TwilioClient::Real {
+            status_callback, ..
+        } = &mut client
+        else {
+            unreachable!()
+        };
+        *status_callback = "https://mycompany.com/myapp.php?foo=1".to_string();
+        let result = client.validate_callback_signature(
+            &request,
+            Some("foo=1&bar=2"),
+            "GvWf1cFY/Q7PnoempGyD5oXAezc=".to_string(),
+        );
         assert!(result.is_ok(), "{:?}", result);
     }
 
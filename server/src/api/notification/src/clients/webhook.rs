@@ -0,0 +1,284 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+
+use ::metrics::ResultCounter;
+use hmac::{Hmac, Mac};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::redirect::Policy;
+use reqwest::{Client, Url};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use strum_macros::EnumString;
+use time::OffsetDateTime;
+use tracing::{event, instrument, Level};
+use url::Host;
+
+use crate::clients::error::NotificationClientsError;
+use crate::metrics;
+
+pub const TIMESTAMP_HEADER: &str = "Bitkey-Webhook-Timestamp";
+pub const SIGNATURE_HEADER: &str = "Bitkey-Webhook-Signature";
+const SIGNATURE_VERSION: &str = "v1";
+const SECRET_LENGTH_BYTES: usize = 32;
+const CHALLENGE_LENGTH_BYTES: usize = 16;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Deserialize, EnumString, Clone)]
+#[serde(rename_all = "lowercase", tag = "mode")]
+pub enum WebhookMode {
+    Test,
+    Environment,
+}
+
+impl WebhookMode {
+    pub fn to_client(&self) -> WebhookClient {
+        WebhookClient::new(self.to_owned())
+    }
+}
+
+#[derive(Clone)]
+pub enum WebhookClient {
+    Real,
+    Test,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Challenge {
+    challenge: String,
+}
+
+impl WebhookClient {
+    pub fn new(mode: WebhookMode) -> Self {
+        match mode {
+            WebhookMode::Environment => Self::Real,
+            WebhookMode::Test => Self::Test,
+        }
+    }
+
+    /// Sends a random challenge to the endpoint, which proves it holds the secret and is willing
+    /// to receive deliveries by echoing the challenge back.
+    #[instrument(skip(self, secret))]
+    pub async fn verify_endpoint(
+        &self,
+        url: &str,
+        secret: &str,
+    ) -> Result<(), NotificationClientsError> {
+        match self {
+            Self::Real => {
+                let challenge = hex::encode(rand::random::<[u8; CHALLENGE_LENGTH_BYTES]>());
+                let body = serde_json::to_string(&Challenge {
+                    challenge: challenge.clone(),
+                })
+                .map_err(|_| NotificationClientsError::WebhookChallengeFailedError)?;
+
+                let response = signed_post(url, secret, body).await?;
+                if !response.status().is_success() {
+                    event!(
+                        Level::INFO,
+                        "Webhook challenge failed with status {}",
+                        response.status()
+                    );
+                    return Err(NotificationClientsError::WebhookChallengeFailedError);
+                }
+
+                match response.json::<Challenge>().await {
+                    Ok(echoed) if echoed.challenge == challenge => Ok(()),
+                    _ => Err(NotificationClientsError::WebhookChallengeFailedError),
+                }
+            }
+            Self::Test => Ok(()),
+        }
+    }
+
+    /// Makes a single delivery attempt. Failures are retried later by the caller rather than
+    /// here, so that a slow endpoint doesn't hold up other deliveries.
+    #[instrument(skip(self, secret, body))]
+    pub async fn deliver(
+        &self,
+        url: &str,
+        secret: &str,
+        body: String,
+    ) -> Result<(), NotificationClientsError> {
+        match self {
+            Self::Real => match signed_post(url, secret, body).await {
+                Ok(response) if response.status().is_success() => Ok(()),
+                Ok(response) => Err(NotificationClientsError::WebhookDeliveryError(
+                    response.status(),
+                )),
+                Err(e) => Err(e),
+            }
+            .count_result(
+                &metrics::WEBHOOK_DELIVERY_ATTEMPT,
+                &metrics::WEBHOOK_DELIVERY_FAILURE,
+                &[],
+            ),
+            Self::Test => Ok(()),
+        }
+    }
+}
+
+async fn signed_post(
+    url: &str,
+    secret: &str,
+    body: String,
+) -> Result<reqwest::Response, NotificationClientsError> {
+    let client = pinned_client(url).await?;
+    let timestamp = OffsetDateTime::now_utc().unix_timestamp();
+    let signature = sign(secret, timestamp, &body)?;
+    Ok(client
+        .post(url)
+        .header(CONTENT_TYPE, "application/json")
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(SIGNATURE_HEADER, signature)
+        .body(body)
+        .send()
+        .await?)
+}
+
+/// Signs `{timestamp}.{body}` with HMAC-SHA256. Covering the timestamp lets receivers reject
+/// replayed deliveries.
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> Result<String, NotificationClientsError> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())?;
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+    Ok(format!(
+        "{SIGNATURE_VERSION}={}",
+        hex::encode(mac.finalize().into_bytes())
+    ))
+}
+
+pub fn generate_secret() -> String {
+    hex::encode(rand::random::<[u8; SECRET_LENGTH_BYTES]>())
+}
+
+pub fn is_valid_url(url: &str) -> bool {
+    Url::parse(url).is_ok_and(|url| {
+        url.scheme() == "https"
+            && match url.host() {
+                Some(Host::Domain(_)) => true,
+                Some(Host::Ipv4(ip)) => is_public_ip(IpAddr::V4(ip)),
+                Some(Host::Ipv6(ip)) => is_public_ip(IpAddr::V6(ip)),
+                None => false,
+            }
+    })
+}
+
+// Endpoints are chosen by customers, so the host is resolved up front, every address is checked
+// to be public, and the request is pinned to those addresses so that the name can't resolve
+// elsewhere by the time it's connected to. Redirects aren't followed for the same reason.
+async fn pinned_client(url: &str) -> Result<Client, NotificationClientsError> {
+    let url =
+        Url::parse(url).map_err(|_| NotificationClientsError::WebhookDisallowedDestinationError)?;
+    let builder = Client::builder()
+        .redirect(Policy::none())
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT);
+
+    let builder = match url.host() {
+        Some(Host::Domain(domain)) => {
+            let port = url.port_or_known_default().unwrap_or_default();
+            let addrs = tokio::net::lookup_host((domain, port))
+                .await
+                .map_err(|_| NotificationClientsError::WebhookDisallowedDestinationError)?
+                .collect::<Vec<SocketAddr>>();
+            if addrs.is_empty() || !addrs.iter().all(|addr| is_public_ip(addr.ip())) {
+                return Err(NotificationClientsError::WebhookDisallowedDestinationError);
+            }
+            builder.resolve_to_addrs(domain, &addrs)
+        }
+        Some(Host::Ipv4(ip)) if is_public_ip(IpAddr::V4(ip)) => builder,
+        Some(Host::Ipv6(ip)) if is_public_ip(IpAddr::V6(ip)) => builder,
+        _ => return Err(NotificationClientsError::WebhookDisallowedDestinationError),
+    };
+    Ok(builder.build()?)
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4(ip),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        // Includes the instance metadata endpoint at 169.254.169.254
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8, 100.64.0.0/10 (carrier-grade NAT) and 240.0.0.0/4 (reserved)
+        || a == 0
+        || (a == 100 && (b & 0xc0) == 64)
+        || a >= 240)
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // Unique local addresses, which include the instance metadata endpoint at fd00:ec2::254
+        || (first & 0xfe00) == 0xfc00
+        // Link-local unicast
+        || (first & 0xffc0) == 0xfe80)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use super::{is_public_ip, is_valid_url, sign};
+
+    #[test]
+    fn test_sign() {
+        let signature = sign("secret", 1700000000, "{}").unwrap();
+        assert!(signature.starts_with("v1="));
+        assert_eq!(signature.len(), "v1=".len() + 64);
+        assert_eq!(signature, sign("secret", 1700000000, "{}").unwrap());
+        assert_ne!(signature, sign("secret", 1700000001, "{}").unwrap());
+        assert_ne!(signature, sign("other", 1700000000, "{}").unwrap());
+    }
+
+    #[test]
+    fn test_is_valid_url() {
+        assert!(is_valid_url("https://example.com/hooks/bitkey"));
+        assert!(!is_valid_url("http://example.com/hooks/bitkey"));
+        assert!(!is_valid_url("example.com"));
+        assert!(!is_valid_url(""));
+        assert!(!is_valid_url("https://127.0.0.1/hooks/bitkey"));
+        assert!(!is_valid_url("https://169.254.169.254/latest/meta-data"));
+        assert!(!is_valid_url("https://[::1]/hooks/bitkey"));
+        assert!(is_valid_url("https://93.184.216.34/hooks/bitkey"));
+    }
+
+    #[test]
+    fn test_is_public_ip() {
+        for ip in [
+            "0.0.0.0",
+            "10.0.0.1",
+            "100.64.0.1",
+            "127.0.0.1",
+            "169.254.169.254",
+            "172.16.0.1",
+            "192.168.1.1",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "::ffff:127.0.0.1",
+            "fd00:ec2::254",
+            "fe80::1",
+        ] {
+            assert!(!is_public_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+        }
+        for ip in ["1.1.1.1", "93.184.216.34", "2606:4700:4700::1111"] {
+            assert!(is_public_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+        }
+    }
+}
@@ -15,4 +15,9 @@ pub enum EmailPayload {
         campaign_type: IterableCampaignType,
         data_fields: HashMap<String, String>,
     },
+    // Rendered from our own templates, for notifications without an Iterable campaign
+    Rendered {
+        subject: String,
+        html: String,
+    },
 }
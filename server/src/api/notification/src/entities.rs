@@ -6,6 +6,7 @@ use time::serde::rfc3339;
 use time::OffsetDateTime;
 use time::{format_description::FormatItem, Duration};
 use types::account::identifiers::{AccountId, TouchpointId};
+use types::notification::{Locale, NotificationChannel};
 
 use crate::{
     identifiers::NotificationId, DeliveryStatus, NotificationError, NotificationMessage,
@@ -23,12 +24,14 @@ pub const EXECUTION_TIME_FORMAT: &[FormatItem<'_>] =
 pub enum NotificationType {
     Scheduled,
     Customer,
+    DeadLetter,
 }
 
 #[derive(Debug)]
 pub enum Notification {
     Scheduled(ScheduledNotification),
     Customer(CustomerNotification),
+    WebhookDeadLetter(WebhookDeadLetter),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -72,11 +75,13 @@ impl ScheduledNotification {
     }
 }
 
-impl TryFrom<ScheduledNotification> for NotificationMessage {
+// Scheduled notifications don't carry the account, so the caller resolves its locale
+impl TryFrom<(ScheduledNotification, Locale)> for NotificationMessage {
     type Error = NotificationError;
 
-    fn try_from(n: ScheduledNotification) -> Result<Self, Self::Error> {
-        NotificationMessage::try_from((n.composite_key(), n.payload_type, n.payload))
+    fn try_from(v: (ScheduledNotification, Locale)) -> Result<Self, Self::Error> {
+        let (n, locale) = v;
+        NotificationMessage::try_from((n.composite_key(), locale, n.payload_type, n.payload))
     }
 }
 
@@ -93,9 +98,24 @@ pub enum NotificationTouchpoint {
         platform: TouchpointPlatform,
         device_token: String,
     },
+    Webhook {
+        touchpoint_id: TouchpointId,
+    },
     Fake,
 }
 
+impl NotificationTouchpoint {
+    pub fn channel(&self) -> Option<NotificationChannel> {
+        match self {
+            NotificationTouchpoint::Email { .. } => Some(NotificationChannel::Email),
+            NotificationTouchpoint::Phone { .. } => Some(NotificationChannel::Sms),
+            NotificationTouchpoint::Push { .. } => Some(NotificationChannel::Push),
+            NotificationTouchpoint::Webhook { .. } => Some(NotificationChannel::Webhook),
+            NotificationTouchpoint::Fake => None,
+        }
+    }
+}
+
 impl From<Touchpoint> for NotificationTouchpoint {
     fn from(t: Touchpoint) -> Self {
         match t {
@@ -104,11 +124,12 @@ impl From<Touchpoint> for NotificationTouchpoint {
             Touchpoint::Push {
                 platform,
                 device_token,
-                arn: _,
+                ..
             } => NotificationTouchpoint::Push {
                 platform,
                 device_token,
             },
+            Touchpoint::Webhook { id, .. } => NotificationTouchpoint::Webhook { touchpoint_id: id },
         }
     }
 }
@@ -126,6 +147,14 @@ pub struct CustomerNotification {
     pub created_at: OffsetDateTime,
     #[serde(with = "rfc3339")]
     pub updated_at: OffsetDateTime,
+    // Only kept for notifications that fail over to other channels, so the message can be rebuilt
+    #[serde(default)]
+    pub payload: Option<NotificationPayload>,
+    #[serde(default)]
+    pub attempts: Vec<DeliveryAttempt>,
+    // The other touchpoints the same notification was sent to, which it never fails over to
+    #[serde(default)]
+    pub sibling_touchpoints: Vec<NotificationTouchpoint>,
 }
 
 impl CustomerNotification {
@@ -134,12 +163,45 @@ impl CustomerNotification {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DeliveryAttempt {
+    pub touchpoint: NotificationTouchpoint,
+    pub status: DeliveryStatus,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(with = "rfc3339")]
+    pub attempted_at: OffsetDateTime,
+}
+
 impl From<CustomerNotification> for Notification {
     fn from(n: CustomerNotification) -> Self {
         Notification::Customer(n)
     }
 }
 
+/// A webhook delivery that still failed after all of its retries. The body is kept exactly as it
+/// was signed so it can be inspected or redelivered.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WebhookDeadLetter {
+    #[serde(rename = "partition_key")]
+    pub account_id: AccountId, // Partition Key
+    #[serde(rename = "sort_key")]
+    pub unique_id: NotificationId, // Sort Key
+    pub notification_id: NotificationId, // The customer notification that failed to deliver
+    pub touchpoint_id: TouchpointId,
+    pub url: String,
+    pub body: String,
+    pub error: String,
+    #[serde(with = "rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl From<WebhookDeadLetter> for Notification {
+    fn from(n: WebhookDeadLetter) -> Self {
+        Notification::WebhookDeadLetter(n)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
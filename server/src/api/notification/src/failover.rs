@@ -0,0 +1,77 @@
+use types::notification::{NotificationCategory, NotificationChannel};
+
+// AccountSecurity notifications have to reach the customer, so a failed delivery is sent again on
+// the next channel in this order. Webhooks are for monitoring and never take part in failover.
+const ACCOUNT_SECURITY_FAILOVER_ORDER: &[NotificationChannel] = &[
+    NotificationChannel::Push,
+    NotificationChannel::Sms,
+    NotificationChannel::Email,
+];
+
+fn failover_order(category: NotificationCategory) -> &'static [NotificationChannel] {
+    match category {
+        NotificationCategory::AccountSecurity => ACCOUNT_SECURITY_FAILOVER_ORDER,
+        NotificationCategory::MoneyMovement | NotificationCategory::ProductMarketing => &[],
+    }
+}
+
+pub fn fails_over(category: NotificationCategory) -> bool {
+    !failover_order(category).is_empty()
+}
+
+/// Returns the channels, in order of preference, that a notification in `category` falls back to
+/// when its delivery on `failed_channel` doesn't succeed.
+pub fn next_channels(
+    category: NotificationCategory,
+    failed_channel: NotificationChannel,
+) -> Vec<NotificationChannel> {
+    let order = failover_order(category);
+    order
+        .iter()
+        .position(|channel| *channel == failed_channel)
+        .map(|i| order[i + 1..].to_vec())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use types::notification::{NotificationCategory, NotificationChannel};
+
+    use super::{fails_over, next_channels};
+
+    #[test]
+    fn test_next_channels() {
+        assert!(fails_over(NotificationCategory::AccountSecurity));
+        assert_eq!(
+            next_channels(
+                NotificationCategory::AccountSecurity,
+                NotificationChannel::Push
+            ),
+            vec![NotificationChannel::Sms, NotificationChannel::Email]
+        );
+        assert_eq!(
+            next_channels(
+                NotificationCategory::AccountSecurity,
+                NotificationChannel::Sms
+            ),
+            vec![NotificationChannel::Email]
+        );
+        assert!(next_channels(
+            NotificationCategory::AccountSecurity,
+            NotificationChannel::Email
+        )
+        .is_empty());
+        assert!(next_channels(
+            NotificationCategory::AccountSecurity,
+            NotificationChannel::Webhook
+        )
+        .is_empty());
+
+        assert!(!fails_over(NotificationCategory::MoneyMovement));
+        assert!(next_channels(
+            NotificationCategory::MoneyMovement,
+            NotificationChannel::Push
+        )
+        .is_empty());
+    }
+}
@@ -2,6 +2,7 @@ use std::{fmt, str::FromStr};
 
 use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::OffsetDateTime;
 use ulid::Ulid;
 
 use crate::{entities::NotificationType, NotificationError};
@@ -21,6 +22,20 @@ impl NotificationId {
     pub fn gen_customer() -> Self {
         Self::gen(NotificationType::Customer)
     }
+
+    pub fn gen_dead_letter() -> Self {
+        Self::gen(NotificationType::DeadLetter)
+    }
+
+    /// The id of an account's digest sent at `send_at`. It's derived rather than generated so
+    /// that every notification held back until then is counted into the same digest.
+    pub fn digest(send_at: OffsetDateTime) -> Self {
+        let timestamp_ms = (send_at.unix_timestamp_nanos() / 1_000_000) as u64;
+        Self(
+            NotificationType::Scheduled,
+            Ulid::from_parts(timestamp_ms, 0),
+        )
+    }
 }
 
 impl fmt::Display for NotificationId {
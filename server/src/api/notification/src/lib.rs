@@ -6,18 +6,29 @@ use derive_builder::Builder;
 use errors::ApiError;
 use payloads::{
     comms_verification::CommsVerificationPayload,
+    money_movement_digest::MoneyMovementDigestPayload,
     recovery_canceled_delay_period::RecoveryCanceledDelayPeriodPayload,
+    recovery_delay_period_change_pending::RecoveryDelayPeriodChangePendingPayload,
     recovery_relationship_deleted::RecoveryRelationshipDeletedPayload,
     recovery_relationship_invitation_accepted::RecoveryRelationshipInvitationAcceptedPayload,
+    recovery_relationship_invitation_expiring::RecoveryRelationshipInvitationExpiringPayload,
+    recovery_relationship_stale::{
+        RecoveryRelationshipStalePayload, RecoveryRelationshipStaleTrustedContactPayload,
+    },
     social_challenge_response_received::SocialChallengeResponseReceivedPayload,
+    social_recovery::{
+        SocialRecoveryCanceledPayload, SocialRecoveryCompletedPayload,
+        SocialRecoveryInitiatedPayload,
+    },
 };
 use queue::sqs::QueueError;
 use serde::{Deserialize, Serialize};
 use sms::SmsPayload;
 use strum::ParseError;
 use strum_macros::{Display as StrumDisplay, EnumString};
+use templates::TemplateError;
 use thiserror::Error;
-use types::notification::NotificationCategory;
+use types::notification::{Locale, NotificationCategory};
 use ulid::DecodeError;
 
 use self::{
@@ -29,6 +40,7 @@ use self::{
         test_notification::TestNotificationPayload,
     },
     push::SNSPushPayload,
+    webhook::WebhookPayload,
 };
 use entities::NotificationCompositeKey;
 use types::{account::identifiers::AccountId, notification::NotificationChannel};
@@ -37,6 +49,7 @@ pub mod address_repo;
 pub mod clients;
 pub mod email;
 pub mod entities;
+pub mod failover;
 pub mod identifiers;
 pub mod metrics;
 pub mod payloads;
@@ -46,10 +59,13 @@ pub mod routes;
 pub mod schedule;
 pub mod service;
 pub mod sms;
+pub mod templates;
+pub mod webhook;
 
 pub const PUSH_QUEUE_ENV_VAR: &str = "PUSH_QUEUE_URL";
 pub const EMAIL_QUEUE_ENV_VAR: &str = "EMAIL_QUEUE_URL";
 pub const SMS_QUEUE_ENV_VAR: &str = "SMS_QUEUE_URL";
+pub const WEBHOOK_QUEUE_ENV_VAR: &str = "WEBHOOK_QUEUE_URL";
 
 #[derive(Error, Debug)]
 pub enum NotificationError {
@@ -73,6 +89,8 @@ pub enum NotificationError {
     SerdeJson(#[from] serde_json::Error),
     #[error(transparent)]
     Queue(#[from] QueueError),
+    #[error(transparent)]
+    Template(#[from] TemplateError),
 }
 
 impl From<NotificationError> for ApiError {
@@ -86,7 +104,8 @@ impl From<NotificationError> for ApiError {
             | NotificationError::ParseIdentifier
             | NotificationError::ParseEnum(_)
             | NotificationError::SerdeJson(_)
-            | NotificationError::ParseUlid(_) => ApiError::GenericInternalApplicationError(message),
+            | NotificationError::ParseUlid(_)
+            | NotificationError::Template(_) => ApiError::GenericInternalApplicationError(message),
             NotificationError::AccountError(e) => e.into(),
             NotificationError::Queue(e) => e.into(),
         }
@@ -106,6 +125,14 @@ pub enum NotificationPayloadType {
     RecoveryRelationshipInvitationAccepted,
     RecoveryRelationshipDeleted,
     SocialChallengeResponseReceived,
+    RecoveryDelayPeriodChangePending,
+    RecoveryRelationshipInvitationExpiring,
+    RecoveryRelationshipStale,
+    RecoveryRelationshipStaleTrustedContact,
+    MoneyMovementDigest,
+    SocialRecoveryInitiated,
+    SocialRecoveryCompleted,
+    SocialRecoveryCanceled,
 }
 
 impl From<NotificationPayloadType> for NotificationCategory {
@@ -114,19 +141,57 @@ impl From<NotificationPayloadType> for NotificationCategory {
             NotificationPayloadType::CommsVerification
             | NotificationPayloadType::RecoveryCanceledDelayPeriod
             | NotificationPayloadType::RecoveryCompletedDelayPeriod
+            | NotificationPayloadType::RecoveryDelayPeriodChangePending
             | NotificationPayloadType::RecoveryPendingDelayPeriod
             | NotificationPayloadType::RecoveryRelationshipDeleted
             | NotificationPayloadType::RecoveryRelationshipInvitationAccepted
+            | NotificationPayloadType::RecoveryRelationshipInvitationExpiring
+            | NotificationPayloadType::RecoveryRelationshipStale
+            | NotificationPayloadType::RecoveryRelationshipStaleTrustedContact
             | NotificationPayloadType::SocialChallengeResponseReceived
+            | NotificationPayloadType::SocialRecoveryCanceled
+            | NotificationPayloadType::SocialRecoveryCompleted
+            | NotificationPayloadType::SocialRecoveryInitiated
             | NotificationPayloadType::TestPushNotification => {
                 NotificationCategory::AccountSecurity
             }
-            NotificationPayloadType::PaymentNotification => NotificationCategory::MoneyMovement,
+            NotificationPayloadType::MoneyMovementDigest
+            | NotificationPayloadType::PaymentNotification => NotificationCategory::MoneyMovement,
         }
     }
 }
 
 impl NotificationPayloadType {
+    // Whether the notification is held back during quiet hours and collapsed into a digest.
+    // AccountSecurity notifications always go out immediately.
+    pub(crate) fn is_digestible(&self) -> bool {
+        matches!(self, NotificationPayloadType::PaymentNotification)
+    }
+
+    // Whether the notification is delivered to webhook touchpoints. Only payment and recovery
+    // events are; verification codes in particular must never leave through a webhook.
+    pub(crate) fn is_webhook_event(&self) -> bool {
+        match self {
+            NotificationPayloadType::CommsVerification
+            | NotificationPayloadType::TestPushNotification => false,
+            NotificationPayloadType::MoneyMovementDigest
+            | NotificationPayloadType::PaymentNotification
+            | NotificationPayloadType::RecoveryCanceledDelayPeriod
+            | NotificationPayloadType::RecoveryCompletedDelayPeriod
+            | NotificationPayloadType::RecoveryDelayPeriodChangePending
+            | NotificationPayloadType::RecoveryPendingDelayPeriod
+            | NotificationPayloadType::RecoveryRelationshipDeleted
+            | NotificationPayloadType::RecoveryRelationshipInvitationAccepted
+            | NotificationPayloadType::RecoveryRelationshipInvitationExpiring
+            | NotificationPayloadType::RecoveryRelationshipStale
+            | NotificationPayloadType::RecoveryRelationshipStaleTrustedContact
+            | NotificationPayloadType::SocialChallengeResponseReceived
+            | NotificationPayloadType::SocialRecoveryCanceled
+            | NotificationPayloadType::SocialRecoveryCompleted
+            | NotificationPayloadType::SocialRecoveryInitiated => true,
+        }
+    }
+
     fn filter_payload(
         &self,
         payload: &NotificationPayload,
@@ -185,6 +250,63 @@ impl NotificationPayloadType {
                 );
                 payload.social_challenge_response_received_payload.is_some()
             }
+            NotificationPayloadType::RecoveryDelayPeriodChangePending => {
+                builder.recovery_delay_period_change_pending_payload(
+                    payload.recovery_delay_period_change_pending_payload.clone(),
+                );
+                payload
+                    .recovery_delay_period_change_pending_payload
+                    .is_some()
+            }
+            NotificationPayloadType::RecoveryRelationshipInvitationExpiring => {
+                builder.recovery_relationship_invitation_expiring_payload(
+                    payload
+                        .recovery_relationship_invitation_expiring_payload
+                        .clone(),
+                );
+                payload
+                    .recovery_relationship_invitation_expiring_payload
+                    .is_some()
+            }
+            NotificationPayloadType::RecoveryRelationshipStale => {
+                builder.recovery_relationship_stale_payload(
+                    payload.recovery_relationship_stale_payload.clone(),
+                );
+                payload.recovery_relationship_stale_payload.is_some()
+            }
+            NotificationPayloadType::RecoveryRelationshipStaleTrustedContact => {
+                builder.recovery_relationship_stale_trusted_contact_payload(
+                    payload
+                        .recovery_relationship_stale_trusted_contact_payload
+                        .clone(),
+                );
+                payload
+                    .recovery_relationship_stale_trusted_contact_payload
+                    .is_some()
+            }
+            NotificationPayloadType::MoneyMovementDigest => {
+                builder
+                    .money_movement_digest_payload(payload.money_movement_digest_payload.clone());
+                payload.money_movement_digest_payload.is_some()
+            }
+            NotificationPayloadType::SocialRecoveryInitiated => {
+                builder.social_recovery_initiated_payload(
+                    payload.social_recovery_initiated_payload.clone(),
+                );
+                payload.social_recovery_initiated_payload.is_some()
+            }
+            NotificationPayloadType::SocialRecoveryCompleted => {
+                builder.social_recovery_completed_payload(
+                    payload.social_recovery_completed_payload.clone(),
+                );
+                payload.social_recovery_completed_payload.is_some()
+            }
+            NotificationPayloadType::SocialRecoveryCanceled => {
+                builder.social_recovery_canceled_payload(
+                    payload.social_recovery_canceled_payload.clone(),
+                );
+                payload.social_recovery_canceled_payload.is_some()
+            }
         };
         if valid_payload {
             builder
@@ -213,6 +335,8 @@ pub struct NotificationMessage {
     pub email_payload: Option<EmailPayload>,
     pub push_payload: Option<SNSPushPayload>,
     pub sms_payload: Option<SmsPayload>,
+    #[serde(default)]
+    pub webhook_payload: Option<WebhookPayload>,
 }
 
 impl fmt::Display for NotificationMessage {
@@ -228,6 +352,7 @@ impl fmt::Display for NotificationMessage {
 impl
     TryFrom<(
         NotificationCompositeKey,
+        Locale,
         NotificationPayloadType,
         NotificationPayload,
     )> for NotificationMessage
@@ -237,21 +362,30 @@ impl
     fn try_from(
         value: (
             NotificationCompositeKey,
+            Locale,
             NotificationPayloadType,
             NotificationPayload,
         ),
     ) -> Result<Self, Self::Error> {
-        let (composite_key, payload_type, payload) = value;
+        let (composite_key, locale, payload_type, payload) = value;
 
-        match payload_type {
+        let webhook_payload = if payload_type.is_webhook_event() {
+            Some(WebhookPayload::new(&composite_key, payload_type, &payload)?)
+        } else {
+            None
+        };
+
+        let message = match payload_type {
             NotificationPayloadType::CommsVerification => NotificationMessage::try_from((
                 composite_key,
+                locale,
                 payload
                     .comms_verification_payload
                     .ok_or(NotificationError::PayloadNotFound(payload_type))?,
             )),
             NotificationPayloadType::PaymentNotification => NotificationMessage::try_from((
                 composite_key,
+                locale,
                 payload
                     .payment_payload
                     .ok_or(NotificationError::PayloadNotFound(payload_type))?,
@@ -259,6 +393,7 @@ impl
             NotificationPayloadType::RecoveryCanceledDelayPeriod => {
                 NotificationMessage::try_from((
                     composite_key,
+                    locale,
                     payload
                         .recovery_canceled_delay_period_payload
                         .ok_or(NotificationError::PayloadNotFound(payload_type))?,
@@ -267,6 +402,7 @@ impl
             NotificationPayloadType::RecoveryCompletedDelayPeriod => {
                 NotificationMessage::try_from((
                     composite_key,
+                    locale,
                     payload
                         .recovery_completed_delay_period_payload
                         .ok_or(NotificationError::PayloadNotFound(payload_type))?,
@@ -274,12 +410,14 @@ impl
             }
             NotificationPayloadType::RecoveryPendingDelayPeriod => NotificationMessage::try_from((
                 composite_key,
+                locale,
                 payload
                     .recovery_pending_delay_period_payload
                     .ok_or(NotificationError::PayloadNotFound(payload_type))?,
             )),
             NotificationPayloadType::TestPushNotification => NotificationMessage::try_from((
                 composite_key,
+                locale,
                 payload
                     .test_notification_payload
                     .ok_or(NotificationError::PayloadNotFound(payload_type))?,
@@ -287,6 +425,7 @@ impl
             NotificationPayloadType::RecoveryRelationshipInvitationAccepted => {
                 NotificationMessage::try_from((
                     composite_key,
+                    locale,
                     payload
                         .recovery_relationship_invitation_accepted_payload
                         .ok_or(NotificationError::PayloadNotFound(payload_type))?,
@@ -295,6 +434,7 @@ impl
             NotificationPayloadType::RecoveryRelationshipDeleted => {
                 NotificationMessage::try_from((
                     composite_key,
+                    locale,
                     payload
                         .recovery_relationship_deleted_payload
                         .ok_or(NotificationError::PayloadNotFound(payload_type))?,
@@ -303,12 +443,80 @@ impl
             NotificationPayloadType::SocialChallengeResponseReceived => {
                 NotificationMessage::try_from((
                     composite_key,
+                    locale,
                     payload
                         .social_challenge_response_received_payload
                         .ok_or(NotificationError::PayloadNotFound(payload_type))?,
                 ))
             }
-        }
+            NotificationPayloadType::RecoveryDelayPeriodChangePending => {
+                NotificationMessage::try_from((
+                    composite_key,
+                    locale,
+                    payload
+                        .recovery_delay_period_change_pending_payload
+                        .ok_or(NotificationError::PayloadNotFound(payload_type))?,
+                ))
+            }
+            NotificationPayloadType::RecoveryRelationshipInvitationExpiring => {
+                NotificationMessage::try_from((
+                    composite_key,
+                    locale,
+                    payload
+                        .recovery_relationship_invitation_expiring_payload
+                        .ok_or(NotificationError::PayloadNotFound(payload_type))?,
+                ))
+            }
+            NotificationPayloadType::RecoveryRelationshipStale => NotificationMessage::try_from((
+                composite_key,
+                locale,
+                payload
+                    .recovery_relationship_stale_payload
+                    .ok_or(NotificationError::PayloadNotFound(payload_type))?,
+            )),
+            NotificationPayloadType::RecoveryRelationshipStaleTrustedContact => {
+                NotificationMessage::try_from((
+                    composite_key,
+                    locale,
+                    payload
+                        .recovery_relationship_stale_trusted_contact_payload
+                        .ok_or(NotificationError::PayloadNotFound(payload_type))?,
+                ))
+            }
+            NotificationPayloadType::MoneyMovementDigest => NotificationMessage::try_from((
+                composite_key,
+                locale,
+                payload
+                    .money_movement_digest_payload
+                    .ok_or(NotificationError::PayloadNotFound(payload_type))?,
+            )),
+            NotificationPayloadType::SocialRecoveryInitiated => NotificationMessage::try_from((
+                composite_key,
+                locale,
+                payload
+                    .social_recovery_initiated_payload
+                    .ok_or(NotificationError::PayloadNotFound(payload_type))?,
+            )),
+            NotificationPayloadType::SocialRecoveryCompleted => NotificationMessage::try_from((
+                composite_key,
+                locale,
+                payload
+                    .social_recovery_completed_payload
+                    .ok_or(NotificationError::PayloadNotFound(payload_type))?,
+            )),
+            NotificationPayloadType::SocialRecoveryCanceled => NotificationMessage::try_from((
+                composite_key,
+                locale,
+                payload
+                    .social_recovery_canceled_payload
+                    .ok_or(NotificationError::PayloadNotFound(payload_type))?,
+            )),
+        }?;
+
+        Ok(NotificationMessage {
+            webhook_payload,
+            ..message
+        })
     }
 }
 
@@ -324,6 +532,9 @@ impl NotificationMessage {
         if self.sms_payload.is_some() {
             channels.insert(NotificationChannel::Sms);
         }
+        if self.webhook_payload.is_some() {
+            channels.insert(NotificationChannel::Webhook);
+        }
         channels
     }
 }
@@ -350,4 +561,23 @@ pub struct NotificationPayload {
     pub recovery_relationship_deleted_payload: Option<RecoveryRelationshipDeletedPayload>,
     #[serde(default)]
     pub social_challenge_response_received_payload: Option<SocialChallengeResponseReceivedPayload>,
+    #[serde(default)]
+    pub recovery_delay_period_change_pending_payload:
+        Option<RecoveryDelayPeriodChangePendingPayload>,
+    #[serde(default)]
+    pub recovery_relationship_invitation_expiring_payload:
+        Option<RecoveryRelationshipInvitationExpiringPayload>,
+    #[serde(default)]
+    pub recovery_relationship_stale_payload: Option<RecoveryRelationshipStalePayload>,
+    #[serde(default)]
+    pub recovery_relationship_stale_trusted_contact_payload:
+        Option<RecoveryRelationshipStaleTrustedContactPayload>,
+    #[serde(default)]
+    pub money_movement_digest_payload: Option<MoneyMovementDigestPayload>,
+    #[serde(default)]
+    pub social_recovery_initiated_payload: Option<SocialRecoveryInitiatedPayload>,
+    #[serde(default)]
+    pub social_recovery_completed_payload: Option<SocialRecoveryCompletedPayload>,
+    #[serde(default)]
+    pub social_recovery_canceled_payload: Option<SocialRecoveryCanceledPayload>,
 }
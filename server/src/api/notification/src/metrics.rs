@@ -4,6 +4,7 @@ use once_cell::sync::Lazy;
 pub const FACTORY_NAME: &str = "notifications";
 pub const CUSTOMER_NOTIFICATION_TYPE: &str = "customer_notification_type";
 pub(crate) const COUNTRY_CODE_KEY: &str = "country_code";
+pub(crate) const CHANNEL_KEY: &str = "channel";
 
 pub static FACTORY: Lazy<MetricsFactory> = Lazy::new(|| MetricsFactory::new(FACTORY_NAME));
 
@@ -28,3 +29,9 @@ pub(crate) static TWILIO_MESSAGE_STATUS_DELIVERED: Lazy<Counter<u64>> =
     Lazy::new(|| FACTORY.u64_counter("twilio.message_status.delivered", None));
 pub(crate) static TWILIO_MESSAGE_STATUS_UNDELIVERED: Lazy<Counter<u64>> =
     Lazy::new(|| FACTORY.u64_counter("twilio.message_status.undelivered", None));
+pub(crate) static NOTIFICATION_FAILOVER: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("notification.failover", None));
+pub(crate) static WEBHOOK_DELIVERY_ATTEMPT: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("webhook.delivery.attempt", None));
+pub(crate) static WEBHOOK_DELIVERY_FAILURE: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("webhook.delivery.failure", None));
@@ -2,11 +2,12 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 use strum_macros::Display as StrumDisplay;
-use types::account::identifiers::AccountId;
+use types::{account::identifiers::AccountId, notification::Locale};
 
+use super::LOCALE_FIELD;
 use crate::{
     clients::iterable::IterableCampaignType, email::EmailPayload,
-    entities::NotificationCompositeKey, sms::SmsPayload, NotificationError, NotificationMessage,
+    entities::NotificationCompositeKey, templates, NotificationError, NotificationMessage,
 };
 
 const VERIFICATION_CODE_FIELD: &str = "verificationCode";
@@ -27,32 +28,36 @@ pub struct CommsVerificationPayload {
     pub template_type: TemplateType,
 }
 
-impl TryFrom<(NotificationCompositeKey, CommsVerificationPayload)> for NotificationMessage {
+impl TryFrom<(NotificationCompositeKey, Locale, CommsVerificationPayload)> for NotificationMessage {
     type Error = NotificationError;
 
     fn try_from(
-        v: (NotificationCompositeKey, CommsVerificationPayload),
+        v: (NotificationCompositeKey, Locale, CommsVerificationPayload),
     ) -> Result<Self, Self::Error> {
-        let (composite_key, payload) = v;
+        let (composite_key, locale, payload) = v;
         let (account_id, _) = composite_key.clone();
+        let rendered = templates::render(
+            "comms_verification",
+            locale,
+            &HashMap::from([("code", payload.code.to_owned().into())]),
+        )?;
         Ok(NotificationMessage {
             composite_key,
             account_id,
             email_payload: Some(EmailPayload::Iterable {
                 campaign_type: IterableCampaignType::CommsVerification,
                 data_fields: HashMap::from([
-                    (VERIFICATION_CODE_FIELD.to_string(), payload.code.to_owned()),
+                    (VERIFICATION_CODE_FIELD.to_string(), payload.code),
                     (
                         TEMPLATE_TYPE_FIELD.to_string(),
                         payload.template_type.to_string(),
                     ),
+                    (LOCALE_FIELD.to_string(), locale.to_string()),
                 ]),
             }),
             push_payload: None,
-            sms_payload: Some(SmsPayload {
-                message: format!("Your Bitkey verification code is: {}", payload.code),
-                unsupported_country_codes: None,
-            }),
+            sms_payload: rendered.sms_payload(),
+            webhook_payload: None,
         })
     }
 }
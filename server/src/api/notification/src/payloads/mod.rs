@@ -1,9 +1,17 @@
 pub mod comms_verification;
+pub mod money_movement_digest;
 pub mod payment;
 pub mod recovery_canceled_delay_period;
 pub mod recovery_completed_delay_period;
+pub mod recovery_delay_period_change_pending;
 pub mod recovery_pending_delay_period;
 pub mod recovery_relationship_deleted;
 pub mod recovery_relationship_invitation_accepted;
+pub mod recovery_relationship_invitation_expiring;
+pub mod recovery_relationship_stale;
 pub mod social_challenge_response_received;
+pub mod social_recovery;
 pub mod test_notification;
+
+// Iterable campaigns pick the language of the email from this data field
+pub(crate) const LOCALE_FIELD: &str = "locale";
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use types::{account::identifiers::AccountId, notification::Locale};
+
+use crate::{
+    entities::NotificationCompositeKey, push::AndroidChannelId, templates, NotificationError,
+    NotificationMessage,
+};
+
+/// Stands in for the MoneyMovement notifications an account received during quiet hours.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct MoneyMovementDigestPayload {
+    pub account_id: AccountId,
+    pub notification_count: u32,
+}
+
+impl TryFrom<(NotificationCompositeKey, Locale, MoneyMovementDigestPayload)>
+    for NotificationMessage
+{
+    type Error = NotificationError;
+
+    fn try_from(
+        v: (NotificationCompositeKey, Locale, MoneyMovementDigestPayload),
+    ) -> Result<Self, Self::Error> {
+        let (composite_key, locale, payload) = v;
+        // A digest of one reads better as the notification it replaced
+        let rendered = if payload.notification_count > 1 {
+            templates::render(
+                "money_movement_digest",
+                locale,
+                &HashMap::from([(
+                    "notification_count",
+                    payload.notification_count.to_string().into(),
+                )]),
+            )?
+        } else {
+            templates::render("payment_notification", locale, &HashMap::new())?
+        };
+        Ok(NotificationMessage {
+            composite_key,
+            account_id: payload.account_id,
+            email_payload: None,
+            push_payload: rendered.push_payload(AndroidChannelId::Transactions),
+            sms_payload: None,
+            webhook_payload: None,
+        })
+    }
+}
@@ -1,9 +1,11 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
-use types::account::identifiers::AccountId;
+use types::{account::identifiers::AccountId, notification::Locale};
 
 use crate::{
-    entities::NotificationCompositeKey, push::AndroidChannelId, push::SNSPushPayload,
-    NotificationError, NotificationMessage,
+    entities::NotificationCompositeKey, push::AndroidChannelId, templates, NotificationError,
+    NotificationMessage,
 };
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -11,21 +13,21 @@ pub struct PaymentPayload {
     pub account_id: AccountId,
 }
 
-impl TryFrom<(NotificationCompositeKey, PaymentPayload)> for NotificationMessage {
+impl TryFrom<(NotificationCompositeKey, Locale, PaymentPayload)> for NotificationMessage {
     type Error = NotificationError;
 
-    fn try_from(v: (NotificationCompositeKey, PaymentPayload)) -> Result<Self, Self::Error> {
-        let (composite_key, payload) = v;
+    fn try_from(
+        v: (NotificationCompositeKey, Locale, PaymentPayload),
+    ) -> Result<Self, Self::Error> {
+        let (composite_key, locale, payload) = v;
+        let rendered = templates::render("payment_notification", locale, &HashMap::new())?;
         Ok(NotificationMessage {
             composite_key,
             account_id: payload.account_id,
             email_payload: None,
-            push_payload: Some(SNSPushPayload {
-                message: "You've received bitcoin.".to_owned(),
-                android_channel_id: AndroidChannelId::Transactions,
-                ..Default::default()
-            }),
+            push_payload: rendered.push_payload(AndroidChannelId::Transactions),
             sms_payload: None,
+            webhook_payload: None,
         })
     }
 }
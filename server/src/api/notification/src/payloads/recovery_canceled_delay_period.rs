@@ -3,11 +3,13 @@ use std::collections::HashMap;
 use account::entities::Factor;
 use serde::{Deserialize, Serialize};
 use time::{serde::rfc3339, OffsetDateTime};
+use types::notification::Locale;
 
+use super::LOCALE_FIELD;
 use crate::{
     clients::iterable::IterableCampaignType, email::EmailPayload,
-    entities::NotificationCompositeKey, push::AndroidChannelId, push::SNSPushPayload,
-    sms::SmsPayload, NotificationError, NotificationMessage,
+    entities::NotificationCompositeKey, push::AndroidChannelId, templates, NotificationError,
+    NotificationMessage,
 };
 
 const LOST_FACTOR_FIELD: &str = "lostFactor";
@@ -19,41 +21,47 @@ pub struct RecoveryCanceledDelayPeriodPayload {
     pub lost_factor: Factor,
 }
 
-impl TryFrom<(NotificationCompositeKey, RecoveryCanceledDelayPeriodPayload)>
-    for NotificationMessage
+impl
+    TryFrom<(
+        NotificationCompositeKey,
+        Locale,
+        RecoveryCanceledDelayPeriodPayload,
+    )> for NotificationMessage
 {
     type Error = NotificationError;
 
     fn try_from(
-        v: (NotificationCompositeKey, RecoveryCanceledDelayPeriodPayload),
+        v: (
+            NotificationCompositeKey,
+            Locale,
+            RecoveryCanceledDelayPeriodPayload,
+        ),
     ) -> Result<Self, Self::Error> {
-        let (composite_key, payload) = v;
+        let (composite_key, locale, payload) = v;
         let (account_id, _) = composite_key.clone();
 
-        let message = match payload.lost_factor {
-            Factor::App => "Your mobile recovery request has been canceled. If you didn't cancel this request, please return to your Bitkey app to take further action.".to_owned(),
-            Factor::Hw => "Your Bitkey hardware device recovery has been canceled. If you didn't cancel this, please open to the Bitkey app to take further action.".to_owned(),
+        let template = match payload.lost_factor {
+            Factor::App => "recovery_canceled_delay_period.app",
+            Factor::Hw => "recovery_canceled_delay_period.hw",
         };
+        let rendered = templates::render(template, locale, &HashMap::new())?;
 
         Ok(NotificationMessage {
             composite_key,
             account_id,
             email_payload: Some(EmailPayload::Iterable {
                 campaign_type: IterableCampaignType::RecoveryCanceledDelayPeriod,
-                data_fields: HashMap::from([(
-                    LOST_FACTOR_FIELD.to_string(),
-                    payload.lost_factor.to_string(),
-                )]),
-            }),
-            push_payload: Some(SNSPushPayload {
-                message: message.clone(),
-                android_channel_id: AndroidChannelId::RecoveryAccountSecurity,
-                ..Default::default()
-            }),
-            sms_payload: Some(SmsPayload {
-                message,
-                unsupported_country_codes: None,
+                data_fields: HashMap::from([
+                    (
+                        LOST_FACTOR_FIELD.to_string(),
+                        payload.lost_factor.to_string(),
+                    ),
+                    (LOCALE_FIELD.to_string(), locale.to_string()),
+                ]),
             }),
+            push_payload: rendered.push_payload(AndroidChannelId::RecoveryAccountSecurity),
+            sms_payload: rendered.sms_payload(),
+            webhook_payload: None,
         })
     }
 }
@@ -3,11 +3,13 @@ use std::collections::HashMap;
 use account::entities::Factor;
 use serde::{Deserialize, Serialize};
 use time::{serde::rfc3339, OffsetDateTime};
+use types::notification::Locale;
 
+use super::LOCALE_FIELD;
 use crate::{
     clients::iterable::IterableCampaignType, email::EmailPayload,
-    entities::NotificationCompositeKey, push::AndroidChannelId, push::SNSPushPayload,
-    sms::SmsPayload, NotificationError, NotificationMessage,
+    entities::NotificationCompositeKey, push::AndroidChannelId, templates, NotificationError,
+    NotificationMessage,
 };
 
 const LOST_FACTOR_FIELD: &str = "lostFactor";
@@ -22,6 +24,7 @@ pub struct RecoveryCompletedDelayPeriodPayload {
 impl
     TryFrom<(
         NotificationCompositeKey,
+        Locale,
         RecoveryCompletedDelayPeriodPayload,
     )> for NotificationMessage
 {
@@ -30,36 +33,35 @@ impl
     fn try_from(
         v: (
             NotificationCompositeKey,
+            Locale,
             RecoveryCompletedDelayPeriodPayload,
         ),
     ) -> Result<Self, Self::Error> {
-        let (composite_key, payload) = v;
+        let (composite_key, locale, payload) = v;
         let (account_id, _) = composite_key.clone();
 
-        let message = match payload.lost_factor {
-            Factor::App => "Your Bitkey wallet is ready to be recovered on your new phone. Please open your Bitkey app to complete your wallet recovery.".to_owned(),
-            Factor::Hw => "Your replacement Bitkey hardware device is ready. To complete your wallet recovery, please open your Bitkey app.".to_owned(),
+        let template = match payload.lost_factor {
+            Factor::App => "recovery_completed_delay_period.app",
+            Factor::Hw => "recovery_completed_delay_period.hw",
         };
+        let rendered = templates::render(template, locale, &HashMap::new())?;
 
         Ok(NotificationMessage {
             composite_key,
             account_id,
             email_payload: Some(EmailPayload::Iterable {
                 campaign_type: IterableCampaignType::RecoveryCompletedDelayPeriod,
-                data_fields: HashMap::from([(
-                    LOST_FACTOR_FIELD.to_string(),
-                    payload.lost_factor.to_string(),
-                )]),
-            }),
-            push_payload: Some(SNSPushPayload {
-                message: message.clone(),
-                android_channel_id: AndroidChannelId::RecoveryAccountSecurity,
-                ..Default::default()
-            }),
-            sms_payload: Some(SmsPayload {
-                message,
-                unsupported_country_codes: None,
+                data_fields: HashMap::from([
+                    (
+                        LOST_FACTOR_FIELD.to_string(),
+                        payload.lost_factor.to_string(),
+                    ),
+                    (LOCALE_FIELD.to_string(), locale.to_string()),
+                ]),
             }),
+            push_payload: rendered.push_payload(AndroidChannelId::RecoveryAccountSecurity),
+            sms_payload: rendered.sms_payload(),
+            webhook_payload: None,
         })
     }
 }
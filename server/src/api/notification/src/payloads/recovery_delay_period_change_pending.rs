@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::{serde::rfc3339, Duration, OffsetDateTime};
+use types::notification::Locale;
+
+use crate::{
+    entities::NotificationCompositeKey,
+    push::AndroidChannelId,
+    templates::{self, TemplateValue},
+    NotificationError, NotificationMessage,
+};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RecoveryDelayPeriodChangePendingPayload {
+    pub delay_period_num_sec: i64,
+    #[serde(with = "rfc3339")]
+    pub effective_at: OffsetDateTime,
+}
+
+impl
+    TryFrom<(
+        NotificationCompositeKey,
+        Locale,
+        RecoveryDelayPeriodChangePendingPayload,
+    )> for NotificationMessage
+{
+    type Error = NotificationError;
+
+    fn try_from(
+        v: (
+            NotificationCompositeKey,
+            Locale,
+            RecoveryDelayPeriodChangePendingPayload,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (composite_key, locale, payload) = v;
+        let (account_id, _) = composite_key.clone();
+        let rendered = templates::render(
+            "recovery_delay_period_change_pending",
+            locale,
+            &HashMap::from([
+                (
+                    "delay_period",
+                    TemplateValue::Duration(Duration::seconds(payload.delay_period_num_sec)),
+                ),
+                (
+                    "time_remaining",
+                    TemplateValue::Duration(payload.effective_at - OffsetDateTime::now_utc()),
+                ),
+                ("effective_date", TemplateValue::Date(payload.effective_at)),
+            ]),
+        )?;
+
+        // There's no Iterable campaign for this notification yet, so the email is rendered here
+        Ok(NotificationMessage {
+            composite_key,
+            account_id,
+            email_payload: rendered.email_payload(),
+            push_payload: rendered.push_payload(AndroidChannelId::RecoveryAccountSecurity),
+            sms_payload: rendered.sms_payload(),
+            webhook_payload: None,
+        })
+    }
+}
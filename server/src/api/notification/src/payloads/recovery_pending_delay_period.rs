@@ -3,16 +3,22 @@ use std::collections::HashMap;
 use account::entities::Factor;
 use serde::{Deserialize, Serialize};
 use time::{serde::rfc3339, OffsetDateTime};
+use types::notification::Locale;
 
+use super::LOCALE_FIELD;
 use crate::{
-    clients::iterable::IterableCampaignType, email::EmailPayload,
-    entities::NotificationCompositeKey, push::AndroidChannelId, push::SNSPushPayload,
-    sms::SmsPayload, NotificationError, NotificationMessage,
+    clients::iterable::IterableCampaignType,
+    email::EmailPayload,
+    entities::NotificationCompositeKey,
+    push::AndroidChannelId,
+    templates::{self, TemplateValue},
+    NotificationError, NotificationMessage,
 };
 
 const LOST_FACTOR_FIELD: &str = "lostFactor";
 const DURATION_FIELD: &str = "duration";
 const END_DATE_FIELD: &str = "endDate";
+const CANCELLATION_TOKEN_FIELD: &str = "cancellationToken";
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct RecoveryPendingDelayPeriodPayload {
@@ -21,79 +27,87 @@ pub struct RecoveryPendingDelayPeriodPayload {
     #[serde(with = "rfc3339")]
     pub delay_end_time: OffsetDateTime,
     pub lost_factor: Factor,
+    // Lets the customer cancel the recovery from the email or SMS without authenticating in the
+    // app. It's never serialized, so it's only sent with the first notification and isn't
+    // persisted with the scheduled reminders.
+    #[serde(default, skip_serializing)]
+    pub cancellation_token: Option<String>,
 }
 
-impl TryFrom<(NotificationCompositeKey, RecoveryPendingDelayPeriodPayload)>
-    for NotificationMessage
+impl
+    TryFrom<(
+        NotificationCompositeKey,
+        Locale,
+        RecoveryPendingDelayPeriodPayload,
+    )> for NotificationMessage
 {
     type Error = NotificationError;
 
     fn try_from(
-        v: (NotificationCompositeKey, RecoveryPendingDelayPeriodPayload),
+        v: (
+            NotificationCompositeKey,
+            Locale,
+            RecoveryPendingDelayPeriodPayload,
+        ),
     ) -> Result<Self, Self::Error> {
-        let (composite_key, payload) = v;
+        let (composite_key, locale, payload) = v;
         let (account_id, _) = composite_key.clone();
-        let duration = payload.delay_end_time - OffsetDateTime::now_utc();
-
-        let formatted_duration = if duration.whole_hours() > 18 {
-            // Call anything above 18 hours 1 day
-            let whole_days = duration.whole_days().max(1);
-            format!(
-                "{} day{}",
-                whole_days,
-                if whole_days != 1 { "s" } else { "" }
-            )
-        } else if duration.whole_minutes() > 45 {
-            // Call anything above 45 minutes 1 hour
-            let whole_hours = duration.whole_hours().max(1);
-            format!(
-                "{} hour{}",
-                whole_hours,
-                if whole_hours != 1 { "s" } else { "" }
-            )
-        } else {
-            let whole_minutes = duration.whole_minutes();
-            format!(
-                "{} minute{}",
-                whole_minutes,
-                if whole_minutes != 1 { "s" } else { "" }
-            )
-        };
+        let delay_remaining =
+            TemplateValue::Duration(payload.delay_end_time - OffsetDateTime::now_utc());
 
-        let message = match payload.lost_factor {
-            Factor::App => format!("Your Bitkey wallet will be ready on your new phone in {}. If you didn't request this, please cancel immediately in your Bitkey app.", formatted_duration),
-            Factor::Hw => format!("Your new Bitkey hardware device will be ready to use in {}. If you didn't request a new device, please cancel this immediately in your Bitkey app.", formatted_duration),
+        let template = match payload.lost_factor {
+            Factor::App => "recovery_pending_delay_period.app",
+            Factor::Hw => "recovery_pending_delay_period.hw",
         };
-
-        let calendar_end_date = payload.delay_end_time.to_calendar_date();
-        let formatted_end_date = format!(
-            "{} {} {}",
-            calendar_end_date.2, calendar_end_date.1, calendar_end_date.0
-        );
+        let rendered = templates::render(
+            template,
+            locale,
+            &HashMap::from([("delay_remaining", delay_remaining.clone())]),
+        )?;
+
+        let mut sms_payload = rendered.sms_payload();
+        if let (Some(sms_payload), Some(cancellation_token)) =
+            (sms_payload.as_mut(), &payload.cancellation_token)
+        {
+            let cancellation = templates::render(
+                "recovery_pending_delay_period.cancellation",
+                locale,
+                &HashMap::from([(
+                    "cancellation_token",
+                    TemplateValue::Text(cancellation_token.to_owned()),
+                )]),
+            )?;
+            if let Some(message) = cancellation.sms {
+                sms_payload.message = format!("{} {message}", sms_payload.message);
+            }
+        }
+
+        let mut data_fields = HashMap::from([
+            (
+                LOST_FACTOR_FIELD.to_string(),
+                payload.lost_factor.to_string(),
+            ),
+            (DURATION_FIELD.to_string(), delay_remaining.format(locale)),
+            (
+                END_DATE_FIELD.to_string(),
+                TemplateValue::Date(payload.delay_end_time).format(locale),
+            ),
+            (LOCALE_FIELD.to_string(), locale.to_string()),
+        ]);
+        if let Some(cancellation_token) = payload.cancellation_token {
+            data_fields.insert(CANCELLATION_TOKEN_FIELD.to_string(), cancellation_token);
+        }
 
         Ok(NotificationMessage {
             composite_key,
             account_id,
             email_payload: Some(EmailPayload::Iterable {
                 campaign_type: IterableCampaignType::RecoveryPendingDelayPeriod,
-                data_fields: HashMap::from([
-                    (
-                        LOST_FACTOR_FIELD.to_string(),
-                        payload.lost_factor.to_string(),
-                    ),
-                    (DURATION_FIELD.to_string(), formatted_duration),
-                    (END_DATE_FIELD.to_string(), formatted_end_date),
-                ]),
-            }),
-            push_payload: Some(SNSPushPayload {
-                message: message.clone(),
-                android_channel_id: AndroidChannelId::RecoveryAccountSecurity,
-                ..Default::default()
-            }),
-            sms_payload: Some(SmsPayload {
-                message,
-                unsupported_country_codes: None,
+                data_fields,
             }),
+            push_payload: rendered.push_payload(AndroidChannelId::RecoveryAccountSecurity),
+            sms_payload,
+            webhook_payload: None,
         })
     }
 }
@@ -102,8 +116,9 @@ impl TryFrom<(NotificationCompositeKey, RecoveryPendingDelayPeriodPayload)>
 mod tests {
     use account::entities::Factor;
     use time::{Duration, OffsetDateTime};
-    use types::account::identifiers::AccountId;
+    use types::{account::identifiers::AccountId, notification::Locale};
 
+    use crate::email::EmailPayload;
     use crate::identifiers::NotificationId;
     use crate::payloads::recovery_pending_delay_period::RecoveryPendingDelayPeriodPayload;
     use crate::NotificationMessage;
@@ -121,10 +136,13 @@ mod tests {
             initiation_time: start_time,
             delay_end_time: end_time,
             lost_factor: Factor::Hw,
+            cancellation_token: None,
         };
 
         let notification_message: NotificationMessage =
-            (composite_key.clone(), payload).try_into().unwrap();
+            (composite_key.clone(), Locale::EnUs, payload)
+                .try_into()
+                .unwrap();
         let push_payload = notification_message.push_payload.unwrap();
         assert!(push_payload.message.contains("1 day"));
 
@@ -135,11 +153,73 @@ mod tests {
             initiation_time: start_time,
             delay_end_time: end_time,
             lost_factor: Factor::Hw,
+            cancellation_token: None,
         };
 
         let notification_message: NotificationMessage =
-            (composite_key, payload).try_into().unwrap();
+            (composite_key, Locale::EnUs, payload).try_into().unwrap();
         let push_payload = notification_message.push_payload.unwrap();
         assert!(push_payload.message.contains("12 hours"));
     }
+
+    #[test]
+    fn test_notification_message_cancellation_token() {
+        let start_time = OffsetDateTime::now_utc();
+        let composite_key = (
+            AccountId::gen().expect("Valid AccountId"),
+            NotificationId::gen_scheduled(),
+        );
+        let payload = RecoveryPendingDelayPeriodPayload {
+            initiation_time: start_time,
+            delay_end_time: start_time + Duration::days(1),
+            lost_factor: Factor::App,
+            cancellation_token: Some("token".to_string()),
+        };
+
+        // The token is never persisted with the payload
+        let serialized = serde_json::to_value(&payload).unwrap();
+        assert!(serialized.get("cancellation_token").is_none());
+
+        let notification_message: NotificationMessage =
+            (composite_key, Locale::EnUs, payload).try_into().unwrap();
+        let Some(EmailPayload::Iterable { data_fields, .. }) = notification_message.email_payload
+        else {
+            panic!("Expected Iterable email payload");
+        };
+        assert_eq!(
+            data_fields.get("cancellationToken"),
+            Some(&"token".to_string())
+        );
+        assert!(notification_message
+            .sms_payload
+            .unwrap()
+            .message
+            .ends_with("this code: token"));
+    }
+
+    #[test]
+    fn test_notification_message_localized() {
+        let start_time = OffsetDateTime::now_utc();
+        let composite_key = (
+            AccountId::gen().expect("Valid AccountId"),
+            NotificationId::gen_scheduled(),
+        );
+        let payload = RecoveryPendingDelayPeriodPayload {
+            initiation_time: start_time,
+            delay_end_time: start_time + Duration::days(2) + Duration::minutes(1),
+            lost_factor: Factor::App,
+            cancellation_token: None,
+        };
+
+        let notification_message: NotificationMessage =
+            (composite_key, Locale::EsUs, payload).try_into().unwrap();
+        let push_payload = notification_message.push_payload.unwrap();
+        assert!(push_payload.message.contains("2 días"));
+        let Some(EmailPayload::Iterable { data_fields, .. }) = notification_message.email_payload
+        else {
+            panic!("Expected Iterable email payload");
+        };
+        assert_eq!(data_fields.get("locale"), Some(&"es-US".to_string()));
+        assert_eq!(data_fields.get("duration"), Some(&"2 días".to_string()));
+    }
 }
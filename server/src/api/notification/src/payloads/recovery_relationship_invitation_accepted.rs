@@ -1,14 +1,13 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
+use types::notification::Locale;
 
+use super::LOCALE_FIELD;
 use crate::{
-    clients::iterable::IterableCampaignType,
-    email::EmailPayload,
-    entities::NotificationCompositeKey,
-    push::{AndroidChannelId, SNSPushPayload},
-    sms::SmsPayload,
-    NotificationError, NotificationMessage,
+    clients::iterable::IterableCampaignType, email::EmailPayload,
+    entities::NotificationCompositeKey, push::AndroidChannelId, templates, NotificationError,
+    NotificationMessage,
 };
 
 const TRUSTED_CONTACT_ALIAS_FIELD: &str = "trustedContactAlias";
@@ -21,6 +20,7 @@ pub struct RecoveryRelationshipInvitationAcceptedPayload {
 impl
     TryFrom<(
         NotificationCompositeKey,
+        Locale,
         RecoveryRelationshipInvitationAcceptedPayload,
     )> for NotificationMessage
 {
@@ -29,34 +29,36 @@ impl
     fn try_from(
         v: (
             NotificationCompositeKey,
+            Locale,
             RecoveryRelationshipInvitationAcceptedPayload,
         ),
     ) -> Result<Self, Self::Error> {
-        let (composite_key, payload) = v;
+        let (composite_key, locale, payload) = v;
         let (account_id, _) = composite_key.clone();
-        let message = format!(
-            "{} is now set up as a trusted contact for your Bitkey. They can assist if you ever need help recovering a part of your Bitkey wallet.",
-            payload.trusted_contact_alias
-        );
+        let rendered = templates::render(
+            "recovery_relationship_invitation_accepted",
+            locale,
+            &HashMap::from([(
+                "trusted_contact_alias",
+                payload.trusted_contact_alias.to_owned().into(),
+            )]),
+        )?;
         Ok(NotificationMessage {
             composite_key,
             account_id,
             email_payload: Some(EmailPayload::Iterable {
                 campaign_type: IterableCampaignType::RecoveryRelationshipInvitationAccepted,
-                data_fields: HashMap::from([(
-                    TRUSTED_CONTACT_ALIAS_FIELD.to_string(),
-                    payload.trusted_contact_alias,
-                )]),
-            }),
-            push_payload: Some(SNSPushPayload {
-                message: message.clone(),
-                android_channel_id: AndroidChannelId::RecoveryAccountSecurity,
-                ..Default::default()
-            }),
-            sms_payload: Some(SmsPayload {
-                message,
-                unsupported_country_codes: None,
+                data_fields: HashMap::from([
+                    (
+                        TRUSTED_CONTACT_ALIAS_FIELD.to_string(),
+                        payload.trusted_contact_alias,
+                    ),
+                    (LOCALE_FIELD.to_string(), locale.to_string()),
+                ]),
             }),
+            push_payload: rendered.push_payload(AndroidChannelId::RecoveryAccountSecurity),
+            sms_payload: rendered.sms_payload(),
+            webhook_payload: None,
         })
     }
 }
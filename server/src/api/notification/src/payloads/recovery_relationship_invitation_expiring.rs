@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::{serde::rfc3339, OffsetDateTime};
+use types::notification::Locale;
+
+use crate::{
+    entities::NotificationCompositeKey,
+    push::AndroidChannelId,
+    templates::{self, TemplateValue},
+    NotificationError, NotificationMessage,
+};
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RecoveryRelationshipInvitationExpiringPayload {
+    pub trusted_contact_alias: String,
+    #[serde(with = "rfc3339")]
+    pub expires_at: OffsetDateTime,
+}
+
+impl
+    TryFrom<(
+        NotificationCompositeKey,
+        Locale,
+        RecoveryRelationshipInvitationExpiringPayload,
+    )> for NotificationMessage
+{
+    type Error = NotificationError;
+
+    fn try_from(
+        v: (
+            NotificationCompositeKey,
+            Locale,
+            RecoveryRelationshipInvitationExpiringPayload,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (composite_key, locale, payload) = v;
+        let (account_id, _) = composite_key.clone();
+        let rendered = templates::render(
+            "recovery_relationship_invitation_expiring",
+            locale,
+            &HashMap::from([
+                (
+                    "trusted_contact_alias",
+                    payload.trusted_contact_alias.into(),
+                ),
+                (
+                    "time_remaining",
+                    TemplateValue::Duration(payload.expires_at - OffsetDateTime::now_utc()),
+                ),
+            ]),
+        )?;
+
+        // There's no Iterable campaign for this notification yet, so the email is rendered here
+        Ok(NotificationMessage {
+            composite_key,
+            account_id,
+            email_payload: rendered.email_payload(),
+            push_payload: rendered.push_payload(AndroidChannelId::RecoveryAccountSecurity),
+            sms_payload: rendered.sms_payload(),
+            webhook_payload: None,
+        })
+    }
+}
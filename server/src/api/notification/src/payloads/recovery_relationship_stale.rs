@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use types::notification::Locale;
+
+use crate::{
+    entities::NotificationCompositeKey,
+    push::AndroidChannelId,
+    templates::{self, TemplateError, TemplateVariables},
+    NotificationError, NotificationMessage,
+};
+
+/// Sent to the customer when a Trusted Contact they never confirmed is dropped.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct RecoveryRelationshipStalePayload {
+    pub trusted_contact_alias: String,
+}
+
+/// Sent to the Trusted Contact when the customer never confirmed them.
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct RecoveryRelationshipStaleTrustedContactPayload {
+    pub customer_alias: String,
+}
+
+impl
+    TryFrom<(
+        NotificationCompositeKey,
+        Locale,
+        RecoveryRelationshipStalePayload,
+    )> for NotificationMessage
+{
+    type Error = NotificationError;
+
+    fn try_from(
+        v: (
+            NotificationCompositeKey,
+            Locale,
+            RecoveryRelationshipStalePayload,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (composite_key, locale, payload) = v;
+        Ok(render_message(
+            composite_key,
+            locale,
+            "recovery_relationship_stale",
+            HashMap::from([(
+                "trusted_contact_alias",
+                payload.trusted_contact_alias.into(),
+            )]),
+        )?)
+    }
+}
+
+impl
+    TryFrom<(
+        NotificationCompositeKey,
+        Locale,
+        RecoveryRelationshipStaleTrustedContactPayload,
+    )> for NotificationMessage
+{
+    type Error = NotificationError;
+
+    fn try_from(
+        v: (
+            NotificationCompositeKey,
+            Locale,
+            RecoveryRelationshipStaleTrustedContactPayload,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (composite_key, locale, payload) = v;
+        Ok(render_message(
+            composite_key,
+            locale,
+            "recovery_relationship_stale_trusted_contact",
+            HashMap::from([("customer_alias", payload.customer_alias.into())]),
+        )?)
+    }
+}
+
+// There's no Iterable campaign for these notifications yet, so the email is rendered here
+fn render_message(
+    composite_key: NotificationCompositeKey,
+    locale: Locale,
+    template: &str,
+    variables: TemplateVariables,
+) -> Result<NotificationMessage, TemplateError> {
+    let (account_id, _) = composite_key.clone();
+    let rendered = templates::render(template, locale, &variables)?;
+    Ok(NotificationMessage {
+        composite_key,
+        account_id,
+        email_payload: rendered.email_payload(),
+        push_payload: rendered.push_payload(AndroidChannelId::RecoveryAccountSecurity),
+        sms_payload: rendered.sms_payload(),
+        webhook_payload: None,
+    })
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use time::{serde::rfc3339, OffsetDateTime};
+use types::notification::Locale;
+
+use crate::{
+    entities::NotificationCompositeKey,
+    push::AndroidChannelId,
+    templates::{self, TemplateError, TemplateVariables},
+    NotificationError, NotificationMessage,
+};
+
+/// Sent to the customer when a social recovery is started for their account.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SocialRecoveryInitiatedPayload {
+    #[serde(with = "rfc3339")]
+    pub initiation_time: OffsetDateTime,
+}
+
+/// Sent to the customer once enough Trusted Contacts have responded to complete their social
+/// recovery.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SocialRecoveryCompletedPayload {
+    #[serde(with = "rfc3339")]
+    pub initiation_time: OffsetDateTime,
+}
+
+/// Sent to the customer when their social recovery is canceled.
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct SocialRecoveryCanceledPayload {
+    #[serde(with = "rfc3339")]
+    pub initiation_time: OffsetDateTime,
+}
+
+impl
+    TryFrom<(
+        NotificationCompositeKey,
+        Locale,
+        SocialRecoveryInitiatedPayload,
+    )> for NotificationMessage
+{
+    type Error = NotificationError;
+
+    fn try_from(
+        v: (
+            NotificationCompositeKey,
+            Locale,
+            SocialRecoveryInitiatedPayload,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (composite_key, locale, _) = v;
+        Ok(render_message(
+            composite_key,
+            locale,
+            "social_recovery_initiated",
+            HashMap::new(),
+        )?)
+    }
+}
+
+impl
+    TryFrom<(
+        NotificationCompositeKey,
+        Locale,
+        SocialRecoveryCompletedPayload,
+    )> for NotificationMessage
+{
+    type Error = NotificationError;
+
+    fn try_from(
+        v: (
+            NotificationCompositeKey,
+            Locale,
+            SocialRecoveryCompletedPayload,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (composite_key, locale, _) = v;
+        Ok(render_message(
+            composite_key,
+            locale,
+            "social_recovery_completed",
+            HashMap::new(),
+        )?)
+    }
+}
+
+impl
+    TryFrom<(
+        NotificationCompositeKey,
+        Locale,
+        SocialRecoveryCanceledPayload,
+    )> for NotificationMessage
+{
+    type Error = NotificationError;
+
+    fn try_from(
+        v: (
+            NotificationCompositeKey,
+            Locale,
+            SocialRecoveryCanceledPayload,
+        ),
+    ) -> Result<Self, Self::Error> {
+        let (composite_key, locale, _) = v;
+        Ok(render_message(
+            composite_key,
+            locale,
+            "social_recovery_canceled",
+            HashMap::new(),
+        )?)
+    }
+}
+
+// There's no Iterable campaign for these notifications yet, so the email is rendered here
+fn render_message(
+    composite_key: NotificationCompositeKey,
+    locale: Locale,
+    template: &str,
+    variables: TemplateVariables,
+) -> Result<NotificationMessage, TemplateError> {
+    let (account_id, _) = composite_key.clone();
+    let rendered = templates::render(template, locale, &variables)?;
+    Ok(NotificationMessage {
+        composite_key,
+        account_id,
+        email_payload: rendered.email_payload(),
+        push_payload: rendered.push_payload(AndroidChannelId::RecoveryAccountSecurity),
+        sms_payload: rendered.sms_payload(),
+        webhook_payload: None,
+    })
+}
@@ -1,30 +1,32 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
+use types::notification::Locale;
 
 use crate::{
-    entities::NotificationCompositeKey, push::SNSPushPayload, NotificationError,
+    entities::NotificationCompositeKey, push::AndroidChannelId, templates, NotificationError,
     NotificationMessage,
 };
 
 #[derive(Deserialize, Serialize, Clone, Debug, Default)]
 pub struct TestNotificationPayload {}
 
-impl TryFrom<(NotificationCompositeKey, TestNotificationPayload)> for NotificationMessage {
+impl TryFrom<(NotificationCompositeKey, Locale, TestNotificationPayload)> for NotificationMessage {
     type Error = NotificationError;
 
     fn try_from(
-        v: (NotificationCompositeKey, TestNotificationPayload),
+        v: (NotificationCompositeKey, Locale, TestNotificationPayload),
     ) -> Result<Self, Self::Error> {
-        let (composite_key, _payload) = v;
+        let (composite_key, locale, _payload) = v;
         let (account_id, _) = composite_key.clone();
+        let rendered = templates::render("test_push_notification", locale, &HashMap::new())?;
         Ok(NotificationMessage {
             composite_key,
             account_id,
             email_payload: None,
-            push_payload: Some(SNSPushPayload {
-                message: "Test Notification Received".to_owned(),
-                ..Default::default()
-            }),
+            push_payload: rendered.push_payload(AndroidChannelId::General),
             sms_payload: None,
+            webhook_payload: None,
         })
     }
 }
@@ -0,0 +1,101 @@
+use database::{
+    aws_sdk_dynamodb::{error::ProvideErrorMetadata, types::AttributeValue},
+    ddb::{try_to_attribute_val, try_to_item, DDBService, DatabaseError},
+};
+use time::format_description::well_known::Rfc3339;
+use tracing::{event, instrument, Level};
+
+use crate::{
+    entities::ScheduledNotification,
+    repository::{PARTITION_KEY, SORT_KEY},
+    DeliveryStatus,
+};
+
+use super::Repository;
+
+impl Repository {
+    /// Counts a notification into a pending digest, creating the digest if it's the first. Both
+    /// steps are conditional writes, so concurrent notifications are all counted.
+    ///
+    /// Returns `false` if the digest was already picked up for delivery, in which case the
+    /// notification wasn't counted.
+    ///
+    /// ### Arguments
+    ///
+    /// * `digest` - The digest to create, with a count of one and a key derived from when it's sent
+    ///
+    #[instrument(skip(self))]
+    pub async fn add_to_digest(
+        &self,
+        digest: &ScheduledNotification,
+    ) -> Result<bool, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let created = self
+            .connection
+            .client
+            .put_item()
+            .table_name(table_name.clone())
+            .set_item(Some(try_to_item(digest.clone(), database_object)?))
+            .condition_expression("attribute_not_exists(partition_key)")
+            .send()
+            .await;
+        match created {
+            Ok(_) => return Ok(true),
+            Err(err) => {
+                let service_err = err.into_service_error();
+                if !service_err.is_conditional_check_failed_exception() {
+                    event!(
+                        Level::ERROR,
+                        "Could not persist digest {:?}: {service_err:?} with message: {:?}",
+                        digest,
+                        service_err.message()
+                    );
+                    return Err(DatabaseError::PersistenceError(database_object));
+                }
+            }
+        }
+
+        let updated_at = digest.updated_at.format(&Rfc3339).map_err(|err| {
+            event!(Level::ERROR, "Could not format updated_at: {:?}", err);
+            DatabaseError::DatetimeFormatError(database_object)
+        })?;
+        let incremented = self
+            .connection
+            .client
+            .update_item()
+            .table_name(table_name)
+            .key(
+                PARTITION_KEY,
+                try_to_attribute_val(&digest.account_id, database_object)?,
+            )
+            .key(
+                SORT_KEY,
+                try_to_attribute_val(digest.unique_id, database_object)?,
+            )
+            .condition_expression("delivery_status = :new")
+            .update_expression("SET payload.money_movement_digest_payload.notification_count = payload.money_movement_digest_payload.notification_count + :one, updated_at = :updated_at")
+            .expression_attribute_values(":new", AttributeValue::S(DeliveryStatus::New.to_string()))
+            .expression_attribute_values(":one", AttributeValue::N("1".to_string()))
+            .expression_attribute_values(":updated_at", AttributeValue::S(updated_at))
+            .send()
+            .await;
+        match incremented {
+            Ok(_) => Ok(true),
+            Err(err) => {
+                let service_err = err.into_service_error();
+                if service_err.is_conditional_check_failed_exception() {
+                    return Ok(false);
+                }
+                event!(
+                    Level::ERROR,
+                    "Could not add to digest {:?}: {service_err:?} with message: {:?}",
+                    digest,
+                    service_err.message()
+                );
+                Err(DatabaseError::UpdateError(database_object))
+            }
+        }
+    }
+}
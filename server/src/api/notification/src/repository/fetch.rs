@@ -1,12 +1,12 @@
 use database::{
     aws_sdk_dynamodb::{error::ProvideErrorMetadata, types::AttributeValue},
-    ddb::{try_from_item, DDBService, DatabaseError},
+    ddb::{try_from_item, try_to_attribute_val, DDBService, DatabaseError},
 };
 use tracing::{event, instrument, Level};
 
 use crate::{entities::CustomerNotification, DeliveryStatus, NotificationCompositeKey};
 
-use super::Repository;
+use super::{Repository, PARTITION_KEY, SORT_KEY};
 
 impl Repository {
     /// Returns a pending customer notification corresponding to the account id and unique_id
@@ -71,4 +71,49 @@ impl Repository {
             Ok(Some(notification))
         }
     }
+
+    /// Returns the customer notification corresponding to the account id and unique_id, whatever
+    /// its delivery status
+    ///
+    /// ### Arguments
+    ///
+    /// * `composite_key` - The composite key containing both (AccountId, NotificationId) that uniquely identifies the notification
+    #[instrument(skip(self))]
+    pub async fn fetch_customer(
+        &self,
+        composite_key: NotificationCompositeKey,
+    ) -> Result<Option<CustomerNotification>, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let (partition_key, sort_key) = composite_key;
+
+        let item_output = self
+            .connection
+            .client
+            .get_item()
+            .table_name(table_name)
+            .key(
+                PARTITION_KEY,
+                try_to_attribute_val(partition_key, database_object)?,
+            )
+            .key(SORT_KEY, try_to_attribute_val(sort_key, database_object)?)
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not fetch customer notification: {service_err:?} with message: {:?}",
+                    service_err.message()
+                );
+                DatabaseError::FetchError(database_object)
+            })?;
+
+        item_output
+            .item
+            .map(|item| try_from_item(item, database_object))
+            .transpose()
+    }
 }
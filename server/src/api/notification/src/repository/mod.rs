@@ -11,6 +11,7 @@ use database::{
 };
 use tracing::{event, Level};
 
+mod add_to_digest;
 mod fetch;
 mod fetch_for_account_id;
 mod fetch_in_execution_window;
@@ -18,7 +18,7 @@ impl Repository {
     ///
     /// ### Arguments
     ///
-    /// * `notification` - A wrapper type around either the Customer or Scheduled Notification we're persisting
+    /// * `notification` - A wrapper type around a Customer or Scheduled Notification or a webhook dead letter we're persisting
     ///
     #[instrument(skip(self))]
     pub async fn persist_notification(&self, n: &Notification) -> Result<(), DatabaseError> {
@@ -27,6 +27,7 @@ impl Repository {
         let item = match n {
             Notification::Customer(n) => try_to_item(n.clone(), database_object)?,
             Notification::Scheduled(n) => try_to_item(n.clone(), database_object)?,
+            Notification::WebhookDeadLetter(n) => try_to_item(n.clone(), database_object)?,
         };
         self.connection
             .client
@@ -66,6 +67,7 @@ impl Repository {
             .map(|n| match n {
                 Notification::Customer(n) => try_to_item(n, database_object),
                 Notification::Scheduled(n) => try_to_item(n, database_object),
+                Notification::WebhookDeadLetter(n) => try_to_item(n, database_object),
             })
             .collect::<Result<Vec<HashMap<String, AttributeValue>>, DatabaseError>>()?;
 
@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 use ::metrics::KeyValue;
 use account::service::{FetchAccountInput, Service as AccountService};
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, RawQuery, State},
     routing::get,
     routing::post,
     routing::put,
@@ -17,16 +17,17 @@ use serde::{Deserialize, Serialize};
 use tracing::{event, instrument, Level};
 use types::{
     account::identifiers::AccountId,
-    notification::{NotificationChannel, NotificationsPreferences},
+    notification::{Locale, NotificationChannel, NotificationsPreferences, QuietHours},
 };
 use utoipa::{OpenApi, ToSchema};
 
 use crate::{
     address_repo::{AddressAndKeysetId, AddressWatchlistTrait},
+    identifiers::NotificationId,
     payloads::test_notification::TestNotificationPayload,
     service::{
-        FetchNotificationsPreferencesInput, SendNotificationInput,
-        UpdateNotificationsPreferencesInput,
+        FetchLocaleInput, FetchNotificationsPreferencesInput, RecordDeliveryAttemptInput,
+        SendNotificationInput, UpdateLocaleInput, UpdateNotificationsPreferencesInput,
     },
     NotificationPayloadType,
 };
@@ -70,6 +71,14 @@ impl RouteState {
                 "/api/accounts/:account_id/notifications-preferences",
                 get(get_notifications_preferences),
             )
+            .route(
+                "/api/accounts/:account_id/notifications-locale",
+                put(set_notifications_locale),
+            )
+            .route(
+                "/api/accounts/:account_id/notifications-locale",
+                get(get_notifications_locale),
+            )
             .route_layer(FACTORY.route_layer(FACTORY_NAME.to_owned()))
             .with_state(self.to_owned())
     }
@@ -91,11 +100,14 @@ impl From<RouteState> for SwaggerEndpoint {
         add_address,
         set_notifications_preferences,
         get_notifications_preferences,
+        set_notifications_locale,
+        get_notifications_locale,
     ),
     components(
         schemas(SendTestPushData, SendTestPushResponse),
         schemas(RegisterWatchAddressRequest, RegisterWatchAddressResponse),
-        schemas(SetNotificationsPreferencesRequest, NotificationsPreferences, NotificationChannel),
+        schemas(SetNotificationsPreferencesRequest, NotificationsPreferences, NotificationChannel, QuietHours),
+        schemas(NotificationsLocaleRequest, NotificationsLocaleResponse, Locale),
     ),
     tags(
         (name = "Notification", description = "Touchpoints with Users")
@@ -246,10 +258,21 @@ mod x_twilio_signature {
     }
 }
 
-#[instrument(err, skip(twilio_client))]
+// Messages sent before status callbacks carried the notification don't have these
+#[derive(Debug, Deserialize)]
+pub struct TwilioStatusCallbackParams {
+    pub account_id: Option<AccountId>,
+    pub notification_id: Option<NotificationId>,
+}
+
+#[instrument(err, skip(notification_service, twilio_client))]
 #[utoipa::path(
     post,
     path = "/api/twilio/status-callback",
+    params(
+        ("account_id" = Option<AccountId>, Query, description = "AccountId the message was sent to"),
+        ("notification_id" = Option<String>, Query, description = "The customer notification the message delivers"),
+    ),
     request_body = HashMap<String, String>,
     responses(
         (status = 204, description = "Callback successful"),
@@ -257,15 +280,18 @@ mod x_twilio_signature {
     ),
 )]
 pub async fn twilio_status_callback(
+    State(notification_service): State<NotificationService>,
     State(twilio_client): State<TwilioClient>,
     TypedHeader(signature): TypedHeader<x_twilio_signature::Header>,
+    RawQuery(query): RawQuery,
+    Query(params): Query<TwilioStatusCallbackParams>,
     Form(request): Form<HashMap<String, String>>,
 ) -> Result<impl axum::response::IntoResponse, ApiError> {
     let signature = signature
         .0
         .to_str()
         .map_err(|_| ApiError::GenericBadRequest("Invalid signature header".to_string()))?;
-    twilio_client.validate_callback_signature(&request, signature.to_string())?;
+    twilio_client.validate_callback_signature(&request, query.as_deref(), signature.to_string())?;
 
     let Some(status) = request.get("MessageStatus") else {
         return Err(ApiError::GenericBadRequest(
@@ -292,6 +318,22 @@ pub async fn twilio_status_callback(
         _ => {}
     }
 
+    if let ("failed" | "undelivered", Some(account_id), Some(notification_id)) =
+        (status.as_str(), params.account_id, params.notification_id)
+    {
+        let error = match request.get("ErrorCode") {
+            Some(error_code) => format!("Twilio reported the message {status} ({error_code})"),
+            None => format!("Twilio reported the message {status}"),
+        };
+        notification_service
+            .record_delivery_attempt(RecordDeliveryAttemptInput {
+                composite_key: (account_id, notification_id),
+                channel: NotificationChannel::Sms,
+                error: Some(error),
+            })
+            .await?;
+    }
+
     Ok(axum::http::StatusCode::NO_CONTENT)
 }
 
@@ -310,6 +352,8 @@ pub struct SetNotificationsPreferencesRequest {
     pub account_security: HashSet<NotificationChannel>,
     pub money_movement: HashSet<NotificationChannel>,
     pub product_marketing: HashSet<NotificationChannel>,
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHours>,
 }
 
 // TODO: Remove along with SetNotificationsPreferencesRequest in part 2 of W-5882
@@ -319,6 +363,7 @@ impl From<SetNotificationsPreferencesRequest> for NotificationsPreferences {
             account_security: request.account_security,
             money_movement: request.money_movement,
             product_marketing: request.product_marketing,
+            quiet_hours: request.quiet_hours,
         }
     }
 }
@@ -340,6 +385,16 @@ pub async fn set_notifications_preferences(
     State(notification_service): State<NotificationService>,
     Json(request): Json<SetNotificationsPreferencesRequest>,
 ) -> Result<Json<NotificationsPreferences>, ApiError> {
+    if request
+        .quiet_hours
+        .as_ref()
+        .is_some_and(|quiet_hours| !quiet_hours.is_valid())
+    {
+        return Err(ApiError::GenericBadRequest(
+            "Invalid quiet hours".to_string(),
+        ));
+    }
+
     let new_notifications_preferences = NotificationsPreferences::from(request);
     notification_service
         .update_notifications_preferences(UpdateNotificationsPreferencesInput {
@@ -374,3 +429,66 @@ pub async fn get_notifications_preferences(
 
     Ok(Json(notifications_preferences))
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationsLocaleRequest {
+    pub locale: Locale,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NotificationsLocaleResponse {
+    pub locale: Locale,
+}
+
+#[instrument(err, skip(notification_service))]
+#[utoipa::path(
+    put,
+    path = "/api/accounts/{account_id}/notifications-locale",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    request_body = NotificationsLocaleRequest,
+    responses(
+        (status = 200, description = "Notifications locale set", body=NotificationsLocaleResponse),
+    ),
+)]
+pub async fn set_notifications_locale(
+    Path(account_id): Path<AccountId>,
+    State(notification_service): State<NotificationService>,
+    Json(request): Json<NotificationsLocaleRequest>,
+) -> Result<Json<NotificationsLocaleResponse>, ApiError> {
+    notification_service
+        .update_locale(UpdateLocaleInput {
+            account_id: &account_id,
+            locale: request.locale,
+        })
+        .await?;
+
+    Ok(Json(NotificationsLocaleResponse {
+        locale: request.locale,
+    }))
+}
+
+#[instrument(err, skip(notification_service))]
+#[utoipa::path(
+    get,
+    path = "/api/accounts/{account_id}/notifications-locale",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    responses(
+        (status = 200, description = "Notifications locale", body=NotificationsLocaleResponse),
+    ),
+)]
+pub async fn get_notifications_locale(
+    Path(account_id): Path<AccountId>,
+    State(notification_service): State<NotificationService>,
+) -> Result<Json<NotificationsLocaleResponse>, ApiError> {
+    let locale = notification_service
+        .fetch_locale(FetchLocaleInput {
+            account_id: &account_id,
+        })
+        .await?;
+
+    Ok(Json(NotificationsLocaleResponse { locale }))
+}
@@ -10,10 +10,21 @@ pub type ScheduledNotificationEvent = (
     Option<NotificationSchedule>,
 );
 
+// The standard 7-day delay gets a reminder every 2 days; other lengths scale from that
+const REMINDERS_PER_DELAY_PERIOD: f64 = 3.5;
+const MIN_REMINDER_INTERVAL: Duration = Duration::days(1);
+const MAX_REMINDER_INTERVAL: Duration = Duration::days(7);
+
 #[derive(Debug)]
 pub enum ScheduleNotificationType {
     TestPushNotification,
     RecoveryPendingDelayNotify(OffsetDateTime),
+    RecoveryDelayPeriodChangePending(OffsetDateTime),
+}
+
+fn reminder_interval(now: OffsetDateTime, delay_end_time: OffsetDateTime) -> Duration {
+    ((delay_end_time - now) / REMINDERS_PER_DELAY_PERIOD)
+        .clamp(MIN_REMINDER_INTERVAL, MAX_REMINDER_INTERVAL)
 }
 
 impl ScheduleNotificationType {
@@ -35,14 +46,14 @@ impl ScheduleNotificationType {
                 vec![
                     (
                         // Starts now
-                        // Sends every 2 days
+                        // Sends every 2 days, scaled to the delay length
                         // Ends at delay end
                         // =
                         // DAYS 0, 2, 4, 6 (standard 7-day window)
                         NotificationPayloadType::RecoveryPendingDelayPeriod,
                         now,
                         Some(NotificationSchedule {
-                            interval: Duration::days(2),
+                            interval: reminder_interval(now, *delay_end_time),
                             end_date_time: Some(*delay_end_time),
                             jitter: Some(Duration::ZERO),
                         }),
@@ -77,6 +88,49 @@ impl ScheduleNotificationType {
                     ),
                 ]
             }
+            ScheduleNotificationType::RecoveryDelayPeriodChangePending(effective_at) => {
+                vec![(
+                    // Starts now
+                    // Sends on the same cadence as a pending recovery
+                    // Ends when the new delay takes effect
+                    NotificationPayloadType::RecoveryDelayPeriodChangePending,
+                    now,
+                    Some(NotificationSchedule {
+                        interval: reminder_interval(now, *effective_at),
+                        end_date_time: Some(*effective_at),
+                        jitter: Some(Duration::ZERO),
+                    }),
+                )]
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration, OffsetDateTime};
+
+    use super::reminder_interval;
+
+    #[test]
+    fn test_reminder_interval_scales_with_delay() {
+        let now = OffsetDateTime::now_utc();
+        assert_eq!(
+            reminder_interval(now, now + Duration::days(7)),
+            Duration::days(2)
+        );
+        assert_eq!(
+            reminder_interval(now, now + Duration::days(14)),
+            Duration::days(4)
+        );
+        // Short delays still get at most a daily reminder, long ones at least a weekly one
+        assert_eq!(
+            reminder_interval(now, now + Duration::seconds(20)),
+            Duration::days(1)
+        );
+        assert_eq!(
+            reminder_interval(now, now + Duration::days(60)),
+            Duration::days(7)
+        );
+    }
+}
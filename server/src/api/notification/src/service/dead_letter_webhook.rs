@@ -0,0 +1,32 @@
+use errors::ApiError;
+use time::OffsetDateTime;
+use tracing::instrument;
+
+use crate::{
+    entities::{Notification, WebhookDeadLetter},
+    identifiers::NotificationId,
+};
+
+use super::{DeadLetterWebhookInput, Service};
+
+impl Service {
+    /// Records a webhook delivery that failed after all of its retries so it can be replayed.
+    #[instrument(skip(self, input), fields(composite_key = ?input.composite_key))]
+    pub async fn dead_letter_webhook(&self, input: DeadLetterWebhookInput) -> Result<(), ApiError> {
+        let (account_id, notification_id) = input.composite_key.clone();
+        self.notification_repo
+            .persist_notification(&Notification::from(WebhookDeadLetter {
+                account_id,
+                unique_id: NotificationId::gen_dead_letter(),
+                notification_id,
+                touchpoint_id: input.touchpoint_id,
+                url: input.url,
+                body: input.body,
+                error: input.error,
+                created_at: OffsetDateTime::now_utc(),
+            }))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use time::OffsetDateTime;
+use tracing::instrument;
+use types::account::identifiers::AccountId;
+
+use crate::{
+    entities::{ScheduledNotification, EXECUTION_DATE_FORMAT, EXECUTION_TIME_FORMAT},
+    identifiers::NotificationId,
+    payloads::money_movement_digest::MoneyMovementDigestPayload,
+    DeliveryStatus, NotificationError, NotificationPayload, NotificationPayloadType,
+};
+
+use super::Service;
+
+impl Service {
+    /// This function holds back a MoneyMovement notification until the account's quiet hours end.
+    /// Every notification held back during the same quiet hours is counted into a single digest,
+    /// which the scheduled notification worker sends once they're over.
+    ///
+    /// Returns `false` if the digest is already being sent, in which case the caller should send
+    /// the notification itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account the notification was for
+    /// * `send_at` - When the account's current quiet hours end
+    #[instrument(skip(self))]
+    pub(crate) async fn add_to_digest(
+        &self,
+        account_id: &AccountId,
+        send_at: OffsetDateTime,
+    ) -> Result<bool, NotificationError> {
+        let now = OffsetDateTime::now_utc();
+        let digest = ScheduledNotification {
+            account_id: account_id.to_owned(),
+            unique_id: NotificationId::digest(send_at),
+            sharded_execution_date: format!("{}:{}", send_at.format(&EXECUTION_DATE_FORMAT)?, 0),
+            execution_time: send_at.format(&EXECUTION_TIME_FORMAT)?,
+            execution_date_time: send_at,
+            payload_type: NotificationPayloadType::MoneyMovementDigest,
+            payload: NotificationPayload {
+                money_movement_digest_payload: Some(MoneyMovementDigestPayload {
+                    account_id: account_id.to_owned(),
+                    notification_count: 1,
+                }),
+                ..Default::default()
+            },
+            delivery_status: DeliveryStatus::New,
+            created_at: now,
+            updated_at: now,
+            schedule: None,
+        };
+
+        Ok(self.notification_repo.add_to_digest(&digest).await?)
+    }
+}
@@ -0,0 +1,40 @@
+use account::entities::CommonAccountFields;
+use errors::ApiError;
+use tracing::instrument;
+use types::notification::Locale;
+
+use super::{FetchLocaleInput, Service, UpdateLocaleInput};
+
+impl Service {
+    #[instrument(skip(self))]
+    pub async fn fetch_locale(&self, input: FetchLocaleInput<'_>) -> Result<Locale, ApiError> {
+        self.account_repo
+            .fetch(input.account_id)
+            .await
+            .map_err(ApiError::from)
+            .map(|account| account.get_common_fields().locale)
+    }
+
+    #[instrument(skip(self))]
+    pub async fn update_locale(&self, input: UpdateLocaleInput<'_>) -> Result<(), ApiError> {
+        let account = self
+            .account_repo
+            .fetch(input.account_id)
+            .await
+            .map_err(ApiError::from)?;
+
+        let updated_account = account
+            .update(CommonAccountFields {
+                locale: input.locale,
+                ..account.get_common_fields().clone()
+            })
+            .map_err(ApiError::from)?;
+
+        self.account_repo
+            .persist(&updated_account)
+            .await
+            .map_err(ApiError::from)?;
+
+        Ok(())
+    }
+}
@@ -42,6 +42,7 @@ impl<'a> Migration for InitialNotificationsPreferences<'a> {
                 NotificationChannel::Push,
                 NotificationChannel::Email,
             ]),
+            quiet_hours: None,
         };
 
         let initial_notifications_preferences_without_email = &initial_notifications_preferences
@@ -4,7 +4,10 @@ use account::{repository::Repository as AccountRepository, service::Service as A
 use queue::sqs::SqsQueue;
 use repository::consent::Repository as ConsentRepository;
 use serde::Deserialize;
-use types::{account::identifiers::AccountId, notification::NotificationsPreferences};
+use types::{
+    account::identifiers::{AccountId, TouchpointId},
+    notification::{Locale, NotificationChannel, NotificationsPreferences},
+};
 
 use time::{Duration, OffsetDateTime};
 
@@ -16,15 +19,20 @@ use crate::{
     },
     repository::Repository,
     schedule::ScheduleNotificationType,
-    DeliveryStatus, NotificationPayload, NotificationPayloadType, EMAIL_QUEUE_ENV_VAR,
-    PUSH_QUEUE_ENV_VAR, SMS_QUEUE_ENV_VAR,
+    DeliveryStatus, NotificationMessage, NotificationPayload, NotificationPayloadType,
+    EMAIL_QUEUE_ENV_VAR, PUSH_QUEUE_ENV_VAR, SMS_QUEUE_ENV_VAR, WEBHOOK_QUEUE_ENV_VAR,
 };
 
+mod dead_letter_webhook;
+mod digest_notification;
 mod fetch_for_account;
 mod fetch_scheduled_for_window;
+mod locale;
 pub mod migrations;
 pub mod notifications_preferences;
 mod persist_notifications;
+mod record_delivery_attempt;
+mod retry_webhook_delivery;
 mod schedule_notifications;
 mod send_notification;
 mod update_delivery_status;
@@ -43,6 +51,7 @@ pub struct Service {
     push_queue_url: String,
     sms_queue_url: String,
     email_queue_url: String,
+    webhook_queue_url: String,
     iterable_client: IterableClient,
     consent_repo: ConsentRepository,
 }
@@ -64,10 +73,20 @@ impl Service {
             push_queue_url: env::var(PUSH_QUEUE_ENV_VAR).unwrap_or_default(),
             sms_queue_url: env::var(SMS_QUEUE_ENV_VAR).unwrap_or_default(),
             email_queue_url: env::var(EMAIL_QUEUE_ENV_VAR).unwrap_or_default(),
+            webhook_queue_url: env::var(WEBHOOK_QUEUE_ENV_VAR).unwrap_or_default(),
             iterable_client,
             consent_repo,
         }
     }
+
+    fn queue_url(&self, channel: NotificationChannel) -> &str {
+        match channel {
+            NotificationChannel::Push => self.push_queue_url.as_str(),
+            NotificationChannel::Email => self.email_queue_url.as_str(),
+            NotificationChannel::Sms => self.sms_queue_url.as_str(),
+            NotificationChannel::Webhook => self.webhook_queue_url.as_str(),
+        }
+    }
 }
 
 // General Inputs
@@ -123,6 +142,29 @@ pub struct FetchScheduledForWindowInput {
     pub duration: Duration,
 }
 
+#[derive(Debug)]
+pub struct DeadLetterWebhookInput {
+    pub composite_key: NotificationCompositeKey,
+    pub touchpoint_id: TouchpointId,
+    pub url: String,
+    pub body: String,
+    pub error: String,
+}
+
+#[derive(Debug)]
+pub struct RecordDeliveryAttemptInput {
+    pub composite_key: NotificationCompositeKey,
+    pub channel: NotificationChannel,
+    // None if the delivery succeeded
+    pub error: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct RetryWebhookDeliveryInput<'a> {
+    pub composite_key: NotificationCompositeKey,
+    pub message: &'a NotificationMessage,
+}
+
 #[derive(Debug)]
 pub struct UpdateDeliveryStatusInput {
     pub composite_key: NotificationCompositeKey,
@@ -148,3 +190,14 @@ pub struct UpdateNotificationsPreferencesInput<'a> {
     pub account_id: &'a AccountId,
     pub notifications_preferences: &'a NotificationsPreferences,
 }
+
+#[derive(Debug)]
+pub struct FetchLocaleInput<'a> {
+    pub account_id: &'a AccountId,
+}
+
+#[derive(Debug)]
+pub struct UpdateLocaleInput<'a> {
+    pub account_id: &'a AccountId,
+    pub locale: Locale,
+}
@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use ::metrics::KeyValue;
+use account::service::FetchAccountInput;
+use time::OffsetDateTime;
+use tracing::{event, instrument, Level};
+use types::notification::{NotificationCategory, NotificationChannel};
+
+use crate::{
+    entities::{DeliveryAttempt, Notification, NotificationTouchpoint},
+    failover, metrics, DeliveryStatus, NotificationError, NotificationMessage,
+};
+
+use super::{RecordDeliveryAttemptInput, Service};
+
+impl Service {
+    /// Records the outcome of delivering a customer notification to its current touchpoint. If
+    /// the delivery failed and the notification's category has a failover policy, the same
+    /// message is enqueued again for the next channel the account has an active touchpoint on
+    /// that the notification hasn't already been sent to.
+    #[instrument(skip(self))]
+    pub async fn record_delivery_attempt(
+        &self,
+        input: RecordDeliveryAttemptInput,
+    ) -> Result<(), NotificationError> {
+        let Some(mut notification) = self
+            .notification_repo
+            .fetch_customer(input.composite_key.clone())
+            .await?
+        else {
+            event!(
+                Level::WARN,
+                "Customer notification not found for delivery attempt: {:?}",
+                input.composite_key
+            );
+            return Ok(());
+        };
+
+        // Status callbacks can arrive after the notification has already failed over elsewhere
+        if notification.touchpoint.channel() != Some(input.channel) {
+            return Ok(());
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let status = if input.error.is_some() {
+            DeliveryStatus::Error
+        } else {
+            DeliveryStatus::Completed
+        };
+        notification.attempts.push(DeliveryAttempt {
+            touchpoint: notification.touchpoint.clone(),
+            status,
+            error: input.error,
+            attempted_at: now,
+        });
+        notification.delivery_status = status;
+        notification.updated_at = now;
+
+        let next = match (&notification.payload, status) {
+            (Some(payload), DeliveryStatus::Error) => {
+                let account = self
+                    .account_service
+                    .fetch_account(FetchAccountInput {
+                        account_id: &notification.account_id,
+                    })
+                    .await?;
+                let message = NotificationMessage::try_from((
+                    notification.composite_key(),
+                    account.get_common_fields().locale,
+                    notification.payload_type,
+                    payload.clone(),
+                ))?;
+
+                // Touchpoints are never retried for the same notification, and those its siblings
+                // were sent to are left to their own deliveries. Preferences aren't consulted:
+                // reaching the customer twice beats not reaching them at all.
+                let excluded = notification
+                    .attempts
+                    .iter()
+                    .map(|a| &a.touchpoint)
+                    .chain(notification.sibling_touchpoints.iter())
+                    .collect::<HashSet<_>>();
+                let touchpoint = failover::next_channels(
+                    NotificationCategory::from(notification.payload_type),
+                    input.channel,
+                )
+                .into_iter()
+                .filter(|channel| message.channels().contains(channel))
+                .find_map(|channel| {
+                    account
+                        .get_common_fields()
+                        .touchpoints
+                        .iter()
+                        .filter(|t| t.is_active() && NotificationChannel::from(*t) == channel)
+                        .map(|t| NotificationTouchpoint::from(t.to_owned()))
+                        .find(|t| !excluded.contains(t))
+                        .map(|t| (channel, t))
+                });
+                touchpoint.map(|(channel, t)| (channel, t, message))
+            }
+            _ => None,
+        };
+
+        let Some((channel, touchpoint, message)) = next else {
+            self.notification_repo
+                .persist_notification(&Notification::from(notification))
+                .await?;
+            return Ok(());
+        };
+
+        event!(
+            Level::INFO,
+            "Failing over notification {:?} from {} to {channel}",
+            input.composite_key,
+            input.channel,
+        );
+        notification.touchpoint = touchpoint;
+        notification.delivery_status = DeliveryStatus::Enqueued;
+        self.notification_repo
+            .persist_notification(&Notification::from(notification))
+            .await?;
+        self.sqs
+            .enqueue(self.queue_url(channel), &serde_json::to_string(&message)?)
+            .await?;
+        metrics::NOTIFICATION_FAILOVER.add(
+            1,
+            &[KeyValue::new(metrics::CHANNEL_KEY, channel.to_string())],
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use tracing::{event, instrument, Level};
+use types::notification::NotificationChannel;
+
+use crate::{DeliveryStatus, NotificationError};
+
+use super::{RetryWebhookDeliveryInput, Service};
+
+const MAX_WEBHOOK_DELIVERY_ATTEMPTS: usize = 5;
+const WEBHOOK_RETRY_BASE_DELAY_SECONDS: i32 = 30;
+// The longest delay SQS allows on a message
+const WEBHOOK_RETRY_MAX_DELAY_SECONDS: i32 = 900;
+
+impl Service {
+    /// Puts a webhook notification whose last delivery failed transiently back on the queue,
+    /// delayed with exponential backoff. Retrying from the queue rather than in place means a slow
+    /// endpoint doesn't hold up the rest of the batch. Returns false once the notification has
+    /// used up its attempts, in which case it should be dead-lettered instead.
+    #[instrument(skip(self, input), fields(composite_key = ?input.composite_key))]
+    pub async fn retry_webhook_delivery(
+        &self,
+        input: RetryWebhookDeliveryInput<'_>,
+    ) -> Result<bool, NotificationError> {
+        let Some(notification) = self
+            .notification_repo
+            .fetch_customer(input.composite_key.clone())
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        let attempts = notification
+            .attempts
+            .iter()
+            .filter(|a| a.touchpoint == notification.touchpoint)
+            .count();
+        if attempts >= MAX_WEBHOOK_DELIVERY_ATTEMPTS {
+            return Ok(false);
+        }
+
+        let delay_seconds = WEBHOOK_RETRY_BASE_DELAY_SECONDS
+            .saturating_mul(1 << attempts.saturating_sub(1))
+            .min(WEBHOOK_RETRY_MAX_DELAY_SECONDS);
+        event!(
+            Level::INFO,
+            "Retrying webhook delivery {:?} in {delay_seconds}s after {attempts} attempts",
+            input.composite_key,
+        );
+        self.notification_repo
+            .update_delivery_status(input.composite_key, DeliveryStatus::Enqueued)
+            .await?;
+        self.sqs
+            .enqueue_with_delay(
+                self.queue_url(NotificationChannel::Webhook),
+                &serde_json::to_string(input.message)?,
+                delay_seconds,
+            )
+            .await?;
+
+        Ok(true)
+    }
+}
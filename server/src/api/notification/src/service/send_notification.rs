@@ -1,10 +1,12 @@
 use account::service::FetchAccountInput;
+use strum::IntoEnumIterator;
 use time::OffsetDateTime;
 use tracing::instrument;
 use types::notification::{NotificationCategory, NotificationChannel};
 
 use crate::{
     entities::{CustomerNotification, Notification, NotificationTouchpoint},
+    failover,
     identifiers::NotificationId,
     DeliveryStatus, NotificationError, NotificationMessage,
 };
@@ -26,7 +28,36 @@ impl Service {
             })
             .await?;
 
-        let (customer_notifications, serialized_messages) = account
+        // MoneyMovement notifications that arrive during quiet hours go out as a digest once they
+        // end. Notifications targeting specific touchpoints are never held back.
+        let notifications_preferences = &account.get_common_fields().notifications_preferences;
+        if input.only_touchpoints.is_none()
+            && input.payload_type.is_digestible()
+            && NotificationChannel::iter().any(|channel| {
+                notifications_preferences
+                    .is_enabled(NotificationCategory::from(input.payload_type), channel)
+            })
+        {
+            if let Some(quiet_until) = notifications_preferences
+                .quiet_hours
+                .as_ref()
+                .and_then(|quiet_hours| quiet_hours.quiet_until(OffsetDateTime::now_utc()))
+            {
+                if self.add_to_digest(input.account_id, quiet_until).await? {
+                    return Ok(());
+                }
+            }
+        }
+
+        let locale = account.get_common_fields().locale;
+
+        // Notifications targeting specific touchpoints (e.g. verification codes) must not be
+        // redirected elsewhere, so only keep what's needed to fail over for the others
+        let failover_payload = (input.only_touchpoints.is_none()
+            && failover::fails_over(NotificationCategory::from(input.payload_type)))
+        .then(|| payload.clone());
+
+        let (mut customer_notifications, serialized_messages) = account
             .get_common_fields()
             .to_owned()
             .touchpoints
@@ -59,10 +90,14 @@ impl Service {
                     delivery_status: DeliveryStatus::Enqueued,
                     created_at: OffsetDateTime::now_utc(),
                     updated_at: OffsetDateTime::now_utc(),
+                    payload: failover_payload.clone(),
+                    attempts: vec![],
+                    sibling_touchpoints: vec![],
                 };
 
                 let notification_message = NotificationMessage::try_from((
                     customer_notification.composite_key(),
+                    locale,
                     input.payload_type,
                     payload.clone(),
                 ))?;
@@ -82,6 +117,22 @@ impl Service {
                 Ok((c, s))
             })?;
 
+        // A failed delivery doesn't fail over to touchpoints the notification is already on its
+        // way to, so the customer isn't sent it twice there
+        if failover_payload.is_some() {
+            let touchpoints = customer_notifications
+                .iter()
+                .map(|n| n.touchpoint.clone())
+                .collect::<Vec<_>>();
+            for n in customer_notifications.iter_mut() {
+                n.sibling_touchpoints = touchpoints
+                    .iter()
+                    .filter(|t| **t != n.touchpoint)
+                    .cloned()
+                    .collect();
+            }
+        }
+
         if !customer_notifications.is_empty() {
             self.notification_repo
                 .persist_notifications(
@@ -93,12 +144,7 @@ impl Service {
                 .await?;
 
             for (channel, message) in serialized_messages {
-                let queue_url = match channel {
-                    NotificationChannel::Push => self.push_queue_url.as_str(),
-                    NotificationChannel::Email => self.email_queue_url.as_str(),
-                    NotificationChannel::Sms => self.sms_queue_url.as_str(),
-                };
-                self.sqs.enqueue(queue_url, &message).await?;
+                self.sqs.enqueue(self.queue_url(channel), &message).await?;
             }
         }
 
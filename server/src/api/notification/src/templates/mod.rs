@@ -0,0 +1,478 @@
+use std::collections::{HashMap, HashSet};
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+use thiserror::Error;
+use types::notification::Locale;
+
+use crate::{
+    email::EmailPayload,
+    push::{AndroidChannelId, SNSPushPayload},
+    sms::SmsPayload,
+};
+
+pub use values::TemplateValue;
+
+mod values;
+
+// Notifications fall back to this locale when there's no translation for the account's locale
+pub const DEFAULT_LOCALE: Locale = Locale::EnUs;
+
+// Every locale needs an asset here, even if it doesn't translate every template
+const ASSETS: &[(Locale, &str)] = &[
+    (Locale::EnUs, include_str!("../../templates/en-US.json")),
+    (Locale::EsUs, include_str!("../../templates/es-US.json")),
+];
+
+// The templates that can be rendered, along with the variables each of them is given. Templates
+// for payload types whose copy depends on the payload are suffixed with a variant.
+const TEMPLATE_VARIABLES: &[(&str, &[&str])] = &[
+    ("test_push_notification", &[]),
+    ("comms_verification", &["code"]),
+    ("payment_notification", &[]),
+    ("money_movement_digest", &["notification_count"]),
+    ("recovery_pending_delay_period.app", &["delay_remaining"]),
+    ("recovery_pending_delay_period.hw", &["delay_remaining"]),
+    (
+        "recovery_pending_delay_period.cancellation",
+        &["cancellation_token"],
+    ),
+    ("recovery_completed_delay_period.app", &[]),
+    ("recovery_completed_delay_period.hw", &[]),
+    ("recovery_canceled_delay_period.app", &[]),
+    ("recovery_canceled_delay_period.hw", &[]),
+    (
+        "recovery_relationship_invitation_accepted",
+        &["trusted_contact_alias"],
+    ),
+    ("recovery_relationship_deleted", &["trusted_contact_alias"]),
+    (
+        "social_challenge_response_received",
+        &["trusted_contact_alias"],
+    ),
+    (
+        "recovery_delay_period_change_pending",
+        &["delay_period", "time_remaining", "effective_date"],
+    ),
+    (
+        "recovery_relationship_invitation_expiring",
+        &["trusted_contact_alias", "time_remaining"],
+    ),
+    ("recovery_relationship_stale", &["trusted_contact_alias"]),
+    (
+        "recovery_relationship_stale_trusted_contact",
+        &["customer_alias"],
+    ),
+    ("social_recovery_initiated", &[]),
+    ("social_recovery_completed", &[]),
+    ("social_recovery_canceled", &[]),
+];
+
+static REGISTRY: Lazy<Result<Registry, TemplateError>> = Lazy::new(Registry::load);
+
+#[derive(Clone, Debug, Error)]
+pub enum TemplateError {
+    #[error("Could not parse notification templates for {0}: {1}")]
+    Parse(Locale, String),
+    #[error("No notification templates for {0}")]
+    MissingLocale(Locale),
+    #[error("Unknown notification template {1} for {0}")]
+    UnknownTemplate(Locale, String),
+    #[error("Notification template {0} is missing from the default locale")]
+    MissingTemplate(String),
+    #[error("Notification template {1} for {0} has an unterminated variable")]
+    UnterminatedVariable(Locale, String),
+    #[error("Notification template {1} for {0} uses undeclared variable {2}")]
+    UndeclaredVariable(Locale, String, String),
+    #[error(
+        "Notification template {1} for {0} doesn't have the same channels as the default locale"
+    )]
+    ChannelMismatch(Locale, String),
+    #[error("No value was given for variable {1} of notification template {0}")]
+    MissingVariable(String, String),
+}
+
+pub type TemplateVariables = HashMap<&'static str, TemplateValue>;
+
+/// The text of a notification for each of the channels its template defines.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderedTemplate {
+    pub push: Option<RenderedPush>,
+    pub sms: Option<String>,
+    pub email: Option<RenderedEmail>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderedPush {
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub html: String,
+}
+
+impl RenderedTemplate {
+    pub fn push_payload(&self, android_channel_id: AndroidChannelId) -> Option<SNSPushPayload> {
+        self.push.as_ref().map(|push| SNSPushPayload {
+            title: push.title.to_owned(),
+            message: push.body.to_owned(),
+            android_channel_id,
+            ..Default::default()
+        })
+    }
+
+    pub fn sms_payload(&self) -> Option<SmsPayload> {
+        self.sms.as_ref().map(|message| SmsPayload {
+            message: message.to_owned(),
+            unsupported_country_codes: None,
+        })
+    }
+
+    pub fn email_payload(&self) -> Option<EmailPayload> {
+        self.email.as_ref().map(|email| EmailPayload::Rendered {
+            subject: email.subject.to_owned(),
+            html: email.html.to_owned(),
+        })
+    }
+}
+
+/// Checks that the embedded templates parse and are consistent with each other. This runs at
+/// startup so that a bad template fails the deploy instead of the notifications that use it.
+pub fn validate() -> Result<(), TemplateError> {
+    REGISTRY.as_ref().map(|_| ()).map_err(Clone::clone)
+}
+
+/// Renders the template with the given name in the given locale, falling back to the default
+/// locale if it hasn't been translated.
+///
+/// # Arguments
+///
+/// * `name` - The name of the template, usually the payload type
+/// * `locale` - The locale of the account receiving the notification
+/// * `variables` - The values of every variable the template declares
+pub fn render(
+    name: &str,
+    locale: Locale,
+    variables: &TemplateVariables,
+) -> Result<RenderedTemplate, TemplateError> {
+    let registry = REGISTRY.as_ref().map_err(Clone::clone)?;
+    let (locale, templates) = registry
+        .get(locale, name)
+        .or_else(|| registry.get(DEFAULT_LOCALE, name))
+        .ok_or_else(|| TemplateError::MissingTemplate(name.to_owned()))?;
+
+    let text = |template: &Template| template.render(name, locale, variables, false);
+    let html = |template: &Template| template.render(name, locale, variables, true);
+
+    Ok(RenderedTemplate {
+        push: templates
+            .push
+            .as_ref()
+            .map(|(title, body)| {
+                Ok::<_, TemplateError>(RenderedPush {
+                    title: text(title)?,
+                    body: text(body)?,
+                })
+            })
+            .transpose()?,
+        sms: templates.sms.as_ref().map(text).transpose()?,
+        email: templates
+            .email
+            .as_ref()
+            .map(|(subject, body)| {
+                Ok::<_, TemplateError>(RenderedEmail {
+                    subject: text(subject)?,
+                    html: html(body)?,
+                })
+            })
+            .transpose()?,
+    })
+}
+
+#[derive(Deserialize)]
+struct ChannelTemplatesAsset {
+    #[serde(default)]
+    push: Option<PushTemplateAsset>,
+    #[serde(default)]
+    sms: Option<String>,
+    #[serde(default)]
+    email: Option<EmailTemplateAsset>,
+}
+
+#[derive(Deserialize)]
+struct PushTemplateAsset {
+    #[serde(default)]
+    title: String,
+    body: String,
+}
+
+#[derive(Deserialize)]
+struct EmailTemplateAsset {
+    subject: String,
+    html: String,
+}
+
+#[derive(Debug)]
+enum Segment {
+    Literal(String),
+    Variable(String),
+}
+
+#[derive(Debug)]
+struct Template(Vec<Segment>);
+
+impl Template {
+    fn parse(source: &str, locale: Locale, name: &str) -> Result<Self, TemplateError> {
+        let mut segments = Vec::new();
+        let mut rest = source;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Literal(rest[..start].to_owned()));
+            }
+            let after_start = &rest[start + 2..];
+            let end = after_start
+                .find("}}")
+                .ok_or_else(|| TemplateError::UnterminatedVariable(locale, name.to_owned()))?;
+            segments.push(Segment::Variable(after_start[..end].trim().to_owned()));
+            rest = &after_start[end + 2..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment::Literal(rest.to_owned()));
+        }
+        Ok(Self(segments))
+    }
+
+    fn variables(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().filter_map(|segment| match segment {
+            Segment::Variable(variable) => Some(variable.as_str()),
+            Segment::Literal(_) => None,
+        })
+    }
+
+    fn render(
+        &self,
+        name: &str,
+        locale: Locale,
+        variables: &TemplateVariables,
+        escape_html: bool,
+    ) -> Result<String, TemplateError> {
+        self.0
+            .iter()
+            .try_fold(String::new(), |mut rendered, segment| {
+                match segment {
+                    Segment::Literal(literal) => rendered.push_str(literal),
+                    Segment::Variable(variable) => {
+                        let value = variables
+                            .get(variable.as_str())
+                            .ok_or_else(|| {
+                                TemplateError::MissingVariable(name.to_owned(), variable.to_owned())
+                            })?
+                            .format(locale);
+                        if escape_html {
+                            rendered.push_str(&html_escape(&value));
+                        } else {
+                            rendered.push_str(&value);
+                        }
+                    }
+                }
+                Ok(rendered)
+            })
+    }
+}
+
+#[derive(Debug)]
+struct ChannelTemplates {
+    push: Option<(Template, Template)>,
+    sms: Option<Template>,
+    email: Option<(Template, Template)>,
+}
+
+impl ChannelTemplates {
+    fn parse(
+        asset: ChannelTemplatesAsset,
+        locale: Locale,
+        name: &str,
+    ) -> Result<Self, TemplateError> {
+        let parse = |source: &str| Template::parse(source, locale, name);
+        Ok(Self {
+            push: asset
+                .push
+                .map(|push| Ok::<_, TemplateError>((parse(&push.title)?, parse(&push.body)?)))
+                .transpose()?,
+            sms: asset.sms.as_deref().map(parse).transpose()?,
+            email: asset
+                .email
+                .map(|email| Ok::<_, TemplateError>((parse(&email.subject)?, parse(&email.html)?)))
+                .transpose()?,
+        })
+    }
+
+    fn templates(&self) -> impl Iterator<Item = &Template> {
+        let push = self.push.iter().flat_map(|(title, body)| [title, body]);
+        let email = self
+            .email
+            .iter()
+            .flat_map(|(subject, html)| [subject, html]);
+        push.chain(self.sms.iter()).chain(email)
+    }
+
+    fn channels(&self) -> (bool, bool, bool) {
+        (
+            self.push.is_some(),
+            self.sms.is_some(),
+            self.email.is_some(),
+        )
+    }
+}
+
+struct Registry(HashMap<Locale, HashMap<String, ChannelTemplates>>);
+
+impl Registry {
+    fn load() -> Result<Self, TemplateError> {
+        let declared = TEMPLATE_VARIABLES
+            .iter()
+            .map(|(name, variables)| (*name, variables.iter().copied().collect::<HashSet<_>>()))
+            .collect::<HashMap<_, _>>();
+
+        let mut locales = HashMap::new();
+        for locale in Locale::iter() {
+            let (_, source) = ASSETS
+                .iter()
+                .find(|(asset_locale, _)| *asset_locale == locale)
+                .ok_or(TemplateError::MissingLocale(locale))?;
+            let assets: HashMap<String, ChannelTemplatesAsset> = serde_json::from_str(source)
+                .map_err(|e| TemplateError::Parse(locale, e.to_string()))?;
+
+            let mut templates = HashMap::new();
+            for (name, asset) in assets {
+                let declared_variables = declared
+                    .get(name.as_str())
+                    .ok_or_else(|| TemplateError::UnknownTemplate(locale, name.to_owned()))?;
+                let channel_templates = ChannelTemplates::parse(asset, locale, &name)?;
+                if let Some(variable) = channel_templates
+                    .templates()
+                    .flat_map(Template::variables)
+                    .find(|variable| !declared_variables.contains(variable))
+                {
+                    return Err(TemplateError::UndeclaredVariable(
+                        locale,
+                        name.to_owned(),
+                        variable.to_owned(),
+                    ));
+                }
+                templates.insert(name, channel_templates);
+            }
+            locales.insert(locale, templates);
+        }
+
+        let registry = Self(locales);
+        for (name, _) in TEMPLATE_VARIABLES {
+            let (_, default) = registry
+                .get(DEFAULT_LOCALE, name)
+                .ok_or_else(|| TemplateError::MissingTemplate(name.to_string()))?;
+            // Switching languages shouldn't change which channels a customer hears from us on
+            for locale in Locale::iter() {
+                if let Some((_, translated)) = registry.get(locale, name) {
+                    if translated.channels() != default.channels() {
+                        return Err(TemplateError::ChannelMismatch(locale, name.to_string()));
+                    }
+                }
+            }
+        }
+        Ok(registry)
+    }
+
+    fn get(&self, locale: Locale, name: &str) -> Option<(Locale, &ChannelTemplates)> {
+        self.0
+            .get(&locale)
+            .and_then(|templates| templates.get(name))
+            .map(|templates| (locale, templates))
+    }
+}
+
+fn html_escape(value: &str) -> String {
+    value
+        .chars()
+        .fold(String::with_capacity(value.len()), |mut escaped, c| {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#39;"),
+                _ => escaped.push(c),
+            }
+            escaped
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use types::notification::Locale;
+
+    use super::{render, validate, Template, TemplateError, TemplateValue};
+
+    #[test]
+    fn test_embedded_templates_are_valid() {
+        validate().unwrap();
+    }
+
+    #[test]
+    fn test_render_localized() {
+        let variables = HashMap::from([(
+            "trusted_contact_alias",
+            TemplateValue::Text("Alice".to_owned()),
+        )]);
+
+        let english = render("recovery_relationship_deleted", Locale::EnUs, &variables).unwrap();
+        let spanish = render("recovery_relationship_deleted", Locale::EsUs, &variables).unwrap();
+
+        let english_sms = english.sms.unwrap();
+        let spanish_sms = spanish.sms.unwrap();
+        assert!(english_sms.starts_with("Alice has been removed"));
+        assert!(spanish_sms.starts_with("Alice ya no es"));
+        assert_eq!(english.push.unwrap().body, english_sms);
+    }
+
+    #[test]
+    fn test_render_escapes_html_in_email() {
+        let variables = HashMap::from([(
+            "trusted_contact_alias",
+            TemplateValue::Text("<b>Alice</b>".to_owned()),
+        )]);
+
+        let rendered = render("recovery_relationship_stale", Locale::EnUs, &variables).unwrap();
+
+        assert!(rendered.sms.unwrap().starts_with("<b>Alice</b>"));
+        assert!(rendered
+            .email
+            .unwrap()
+            .html
+            .contains("&lt;b&gt;Alice&lt;/b&gt;"));
+    }
+
+    #[test]
+    fn test_render_missing_variable() {
+        let result = render(
+            "recovery_relationship_deleted",
+            Locale::EnUs,
+            &HashMap::new(),
+        );
+        assert!(matches!(result, Err(TemplateError::MissingVariable(..))));
+    }
+
+    #[test]
+    fn test_parse_unterminated_variable() {
+        let result = Template::parse("Hello {{name", Locale::EnUs, "test");
+        assert!(matches!(
+            result,
+            Err(TemplateError::UnterminatedVariable(..))
+        ));
+    }
+}
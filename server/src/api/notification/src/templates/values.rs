@@ -0,0 +1,130 @@
+use time::{Duration, Month, OffsetDateTime};
+use types::notification::Locale;
+
+const SATS_PER_BTC: u64 = 100_000_000;
+
+/// A typed value substituted into a template. Values are formatted for the locale the template
+/// is being rendered in.
+#[derive(Clone, Debug)]
+pub enum TemplateValue {
+    Text(String),
+    // Rounded to the coarsest unit that reads naturally, e.g. "2 days"
+    Duration(Duration),
+    // Rendered as a calendar date, e.g. "5 March 2024"
+    Date(OffsetDateTime),
+    Amount { sats: u64 },
+}
+
+impl TemplateValue {
+    pub fn format(&self, locale: Locale) -> String {
+        match self {
+            Self::Text(text) => text.to_owned(),
+            Self::Duration(duration) => format_duration(*duration, locale),
+            Self::Date(date) => format_date(*date, locale),
+            Self::Amount { sats } => format_amount(*sats, locale),
+        }
+    }
+}
+
+impl From<String> for TemplateValue {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Duration> for TemplateValue {
+    fn from(duration: Duration) -> Self {
+        Self::Duration(duration)
+    }
+}
+
+fn format_duration(duration: Duration, locale: Locale) -> String {
+    let (count, (singular, plural)) = if duration.whole_hours() > 18 {
+        // Call anything above 18 hours 1 day
+        (duration.whole_days().max(1), duration_units(locale).0)
+    } else if duration.whole_minutes() > 45 {
+        // Call anything above 45 minutes 1 hour
+        (duration.whole_hours().max(1), duration_units(locale).1)
+    } else {
+        (duration.whole_minutes(), duration_units(locale).2)
+    };
+    format!("{} {}", count, if count != 1 { plural } else { singular })
+}
+
+type Unit = (&'static str, &'static str);
+
+fn duration_units(locale: Locale) -> (Unit, Unit, Unit) {
+    match locale {
+        Locale::EnUs => (("day", "days"), ("hour", "hours"), ("minute", "minutes")),
+        Locale::EsUs => (("día", "días"), ("hora", "horas"), ("minuto", "minutos")),
+    }
+}
+
+fn format_date(date: OffsetDateTime, locale: Locale) -> String {
+    let (year, month, day) = date.to_calendar_date();
+    match locale {
+        Locale::EnUs => format!("{} {} {}", day, month, year),
+        Locale::EsUs => format!("{} de {} de {}", day, spanish_month(month), year),
+    }
+}
+
+fn spanish_month(month: Month) -> &'static str {
+    match month {
+        Month::January => "enero",
+        Month::February => "febrero",
+        Month::March => "marzo",
+        Month::April => "abril",
+        Month::May => "mayo",
+        Month::June => "junio",
+        Month::July => "julio",
+        Month::August => "agosto",
+        Month::September => "septiembre",
+        Month::October => "octubre",
+        Month::November => "noviembre",
+        Month::December => "diciembre",
+    }
+}
+
+fn format_amount(sats: u64, locale: Locale) -> String {
+    let decimal_separator = match locale {
+        Locale::EnUs => ".",
+        Locale::EsUs => ",",
+    };
+    let whole = sats / SATS_PER_BTC;
+    let fraction = format!("{:08}", sats % SATS_PER_BTC);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        format!("{} BTC", whole)
+    } else {
+        format!("{}{}{} BTC", whole, decimal_separator, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use time::{macros::datetime, Duration};
+    use types::notification::Locale;
+
+    use super::TemplateValue;
+
+    #[rstest]
+    #[case::days(TemplateValue::Duration(Duration::days(2) + Duration::minutes(1)), Locale::EnUs, "2 days")]
+    #[case::round_up_to_day(TemplateValue::Duration(Duration::hours(20)), Locale::EnUs, "1 day")]
+    #[case::hours(TemplateValue::Duration(Duration::hours(12) + Duration::minutes(1)), Locale::EnUs, "12 hours")]
+    #[case::minutes(
+        TemplateValue::Duration(Duration::minutes(1)),
+        Locale::EnUs,
+        "1 minute"
+    )]
+    #[case::dias(TemplateValue::Duration(Duration::days(2)), Locale::EsUs, "2 días")]
+    #[case::hora(TemplateValue::Duration(Duration::hours(1)), Locale::EsUs, "1 hora")]
+    #[case::date(TemplateValue::Date(datetime!(2024-03-05 12:00 UTC)), Locale::EnUs, "5 March 2024")]
+    #[case::fecha(TemplateValue::Date(datetime!(2024-03-05 12:00 UTC)), Locale::EsUs, "5 de marzo de 2024")]
+    #[case::whole_amount(TemplateValue::Amount { sats: 200_000_000 }, Locale::EnUs, "2 BTC")]
+    #[case::amount(TemplateValue::Amount { sats: 150_000 }, Locale::EnUs, "0.0015 BTC")]
+    #[case::monto(TemplateValue::Amount { sats: 150_000 }, Locale::EsUs, "0,0015 BTC")]
+    fn test_format(#[case] value: TemplateValue, #[case] locale: Locale, #[case] expected: &str) {
+        assert_eq!(value.format(locale), expected);
+    }
+}
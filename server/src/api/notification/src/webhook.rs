@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::{serde::rfc3339, OffsetDateTime};
+use types::account::identifiers::AccountId;
+
+use crate::{
+    entities::NotificationCompositeKey, identifiers::NotificationId, NotificationPayload,
+    NotificationPayloadType,
+};
+
+// The fields of each event's payload that are delivered to the customer's endpoint. Anything not
+// listed, like a recovery's cancellation token, never leaves through a webhook.
+fn allowed_fields(event: NotificationPayloadType) -> &'static [&'static str] {
+    match event {
+        NotificationPayloadType::CommsVerification
+        | NotificationPayloadType::PaymentNotification
+        | NotificationPayloadType::TestPushNotification => &[],
+        NotificationPayloadType::MoneyMovementDigest => &["notification_count"],
+        NotificationPayloadType::RecoveryCanceledDelayPeriod
+        | NotificationPayloadType::RecoveryCompletedDelayPeriod => {
+            &["initiation_time", "lost_factor"]
+        }
+        NotificationPayloadType::RecoveryDelayPeriodChangePending => {
+            &["delay_period_num_sec", "effective_at"]
+        }
+        NotificationPayloadType::RecoveryPendingDelayPeriod => {
+            &["initiation_time", "delay_end_time", "lost_factor"]
+        }
+        NotificationPayloadType::RecoveryRelationshipDeleted
+        | NotificationPayloadType::RecoveryRelationshipInvitationAccepted
+        | NotificationPayloadType::RecoveryRelationshipStale
+        | NotificationPayloadType::SocialChallengeResponseReceived => &["trusted_contact_alias"],
+        NotificationPayloadType::RecoveryRelationshipInvitationExpiring => {
+            &["trusted_contact_alias", "expires_at"]
+        }
+        NotificationPayloadType::RecoveryRelationshipStaleTrustedContact => &["customer_alias"],
+        NotificationPayloadType::SocialRecoveryCanceled
+        | NotificationPayloadType::SocialRecoveryCompleted
+        | NotificationPayloadType::SocialRecoveryInitiated => &["initiation_time"],
+    }
+}
+
+/// The JSON body delivered to webhook touchpoints. Receivers can use `id` to drop duplicate
+/// deliveries.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WebhookPayload {
+    pub id: NotificationId,
+    pub account_id: AccountId,
+    pub event: NotificationPayloadType,
+    #[serde(with = "rfc3339")]
+    pub created_at: OffsetDateTime,
+    pub data: Value,
+}
+
+impl WebhookPayload {
+    pub(crate) fn new(
+        composite_key: &NotificationCompositeKey,
+        event: NotificationPayloadType,
+        payload: &NotificationPayload,
+    ) -> Result<Self, serde_json::Error> {
+        let (account_id, id) = composite_key.to_owned();
+
+        // The payload has already been filtered down to the event's own field, so only keep
+        // the fields that are set, and only the parts of those that may be shared
+        let allowed_fields = allowed_fields(event);
+        let mut data = serde_json::to_value(payload)?;
+        if let Value::Object(fields) = &mut data {
+            fields.retain(|_, v| !v.is_null());
+            for v in fields.values_mut() {
+                if let Value::Object(event_fields) = v {
+                    event_fields.retain(|k, _| allowed_fields.contains(&k.as_str()));
+                }
+            }
+        }
+
+        Ok(Self {
+            id,
+            account_id,
+            event,
+            created_at: OffsetDateTime::now_utc(),
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use account::entities::Factor;
+    use time::{Duration, OffsetDateTime};
+    use types::account::identifiers::AccountId;
+
+    use super::WebhookPayload;
+    use crate::{
+        identifiers::NotificationId,
+        payloads::{
+            payment::PaymentPayload,
+            recovery_pending_delay_period::RecoveryPendingDelayPeriodPayload,
+        },
+        NotificationPayloadBuilder, NotificationPayloadType,
+    };
+
+    #[test]
+    fn test_webhook_data_is_allowlisted() {
+        let account_id = AccountId::gen().unwrap();
+        let composite_key = (account_id.clone(), NotificationId::gen_customer());
+        let now = OffsetDateTime::now_utc();
+
+        let payload = NotificationPayloadBuilder::default()
+            .recovery_pending_delay_period_payload(Some(RecoveryPendingDelayPeriodPayload {
+                initiation_time: now,
+                delay_end_time: now + Duration::days(7),
+                lost_factor: Factor::Hw,
+                cancellation_token: Some("7K3QH2MX".to_owned()),
+            }))
+            .build()
+            .unwrap();
+        let webhook_payload = WebhookPayload::new(
+            &composite_key,
+            NotificationPayloadType::RecoveryPendingDelayPeriod,
+            &payload,
+        )
+        .unwrap();
+        let data = &webhook_payload.data["recovery_pending_delay_period_payload"];
+        assert!(data.get("cancellation_token").is_none());
+        assert!(data.get("delay_end_time").is_some());
+        let body = serde_json::to_string(&webhook_payload).unwrap();
+        assert!(!body.contains("cancellation_token"));
+        assert!(!body.contains("7K3QH2MX"));
+
+        let payload = NotificationPayloadBuilder::default()
+            .payment_payload(Some(PaymentPayload {
+                account_id: account_id.clone(),
+            }))
+            .build()
+            .unwrap();
+        let webhook_payload = WebhookPayload::new(
+            &composite_key,
+            NotificationPayloadType::PaymentNotification,
+            &payload,
+        )
+        .unwrap();
+        assert_eq!(
+            webhook_payload.data["payment_payload"],
+            serde_json::json!({})
+        );
+    }
+}
@@ -1,15 +1,27 @@
+use account::service::{FetchAccountInput, Service as AccountService};
 use async_trait::async_trait;
 use error::NotificationValidationError;
 use notification::{
     entities::NotificationCompositeKey,
     payloads::{
-        comms_verification::CommsVerificationPayload, payment::PaymentPayload,
+        comms_verification::CommsVerificationPayload,
+        money_movement_digest::MoneyMovementDigestPayload,
+        payment::PaymentPayload,
         recovery_canceled_delay_period::RecoveryCanceledDelayPeriodPayload,
         recovery_completed_delay_period::RecoveryCompletedDelayPeriodPayload,
+        recovery_delay_period_change_pending::RecoveryDelayPeriodChangePendingPayload,
         recovery_pending_delay_period::RecoveryPendingDelayPeriodPayload,
         recovery_relationship_deleted::RecoveryRelationshipDeletedPayload,
         recovery_relationship_invitation_accepted::RecoveryRelationshipInvitationAcceptedPayload,
+        recovery_relationship_invitation_expiring::RecoveryRelationshipInvitationExpiringPayload,
+        recovery_relationship_stale::{
+            RecoveryRelationshipStalePayload, RecoveryRelationshipStaleTrustedContactPayload,
+        },
         social_challenge_response_received::SocialChallengeResponseReceivedPayload,
+        social_recovery::{
+            SocialRecoveryCanceledPayload, SocialRecoveryCompletedPayload,
+            SocialRecoveryInitiatedPayload,
+        },
         test_notification::TestNotificationPayload,
     },
     NotificationPayload, NotificationPayloadType,
@@ -22,11 +34,15 @@ mod error;
 #[derive(Clone)]
 pub struct NotificationValidationState {
     recovery_service: RecoveryRepository,
+    account_service: AccountService,
 }
 
 impl NotificationValidationState {
-    pub fn new(recovery_service: RecoveryRepository) -> Self {
-        Self { recovery_service }
+    pub fn new(recovery_service: RecoveryRepository, account_service: AccountService) -> Self {
+        Self {
+            recovery_service,
+            account_service,
+        }
     }
 }
 
@@ -81,6 +97,38 @@ pub fn to_validator(
                 .social_challenge_response_received_payload
                 .as_ref()
                 .ok_or(NotificationValidationError::ToValidatorError)?,
+            NotificationPayloadType::RecoveryDelayPeriodChangePending => payload
+                .recovery_delay_period_change_pending_payload
+                .as_ref()
+                .ok_or(NotificationValidationError::ToValidatorError)?,
+            NotificationPayloadType::RecoveryRelationshipInvitationExpiring => payload
+                .recovery_relationship_invitation_expiring_payload
+                .as_ref()
+                .ok_or(NotificationValidationError::ToValidatorError)?,
+            NotificationPayloadType::RecoveryRelationshipStale => payload
+                .recovery_relationship_stale_payload
+                .as_ref()
+                .ok_or(NotificationValidationError::ToValidatorError)?,
+            NotificationPayloadType::RecoveryRelationshipStaleTrustedContact => payload
+                .recovery_relationship_stale_trusted_contact_payload
+                .as_ref()
+                .ok_or(NotificationValidationError::ToValidatorError)?,
+            NotificationPayloadType::MoneyMovementDigest => payload
+                .money_movement_digest_payload
+                .as_ref()
+                .ok_or(NotificationValidationError::ToValidatorError)?,
+            NotificationPayloadType::SocialRecoveryInitiated => payload
+                .social_recovery_initiated_payload
+                .as_ref()
+                .ok_or(NotificationValidationError::ToValidatorError)?,
+            NotificationPayloadType::SocialRecoveryCompleted => payload
+                .social_recovery_completed_payload
+                .as_ref()
+                .ok_or(NotificationValidationError::ToValidatorError)?,
+            NotificationPayloadType::SocialRecoveryCanceled => payload
+                .social_recovery_canceled_payload
+                .as_ref()
+                .ok_or(NotificationValidationError::ToValidatorError)?,
         };
     Ok(validator)
 }
@@ -214,3 +262,132 @@ impl ValidateNotificationDelivery for SocialChallengeResponseReceivedPayload {
         true
     }
 }
+
+#[async_trait]
+impl ValidateNotificationDelivery for RecoveryDelayPeriodChangePendingPayload {
+    async fn validate_delivery(
+        &self,
+        state: &NotificationValidationState,
+        composite_key: &NotificationCompositeKey,
+    ) -> bool {
+        let (account_id, _) = composite_key;
+        let account_result = state
+            .account_service
+            .fetch_full_account(FetchAccountInput { account_id })
+            .await;
+
+        // Stop reminding once the change has taken effect or has been replaced
+        if let Ok(account) = account_result {
+            if let Some(pending) = account.pending_recovery_delay_period.as_ref() {
+                return OffsetDateTime::now_utc() < pending.effective_at
+                    && pending.effective_at == self.effective_at;
+            }
+        }
+        false
+    }
+}
+
+#[async_trait]
+impl ValidateNotificationDelivery for RecoveryRelationshipInvitationExpiringPayload {
+    async fn validate_delivery(
+        &self,
+        _state: &NotificationValidationState,
+        _composite_key: &NotificationCompositeKey,
+    ) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl ValidateNotificationDelivery for RecoveryRelationshipStalePayload {
+    async fn validate_delivery(
+        &self,
+        _state: &NotificationValidationState,
+        _composite_key: &NotificationCompositeKey,
+    ) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl ValidateNotificationDelivery for RecoveryRelationshipStaleTrustedContactPayload {
+    async fn validate_delivery(
+        &self,
+        _state: &NotificationValidationState,
+        _composite_key: &NotificationCompositeKey,
+    ) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl ValidateNotificationDelivery for MoneyMovementDigestPayload {
+    async fn validate_delivery(
+        &self,
+        _state: &NotificationValidationState,
+        _composite_key: &NotificationCompositeKey,
+    ) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl ValidateNotificationDelivery for SocialRecoveryInitiatedPayload {
+    async fn validate_delivery(
+        &self,
+        state: &NotificationValidationState,
+        composite_key: &NotificationCompositeKey,
+    ) -> bool {
+        let (account_id, _) = composite_key;
+        let recovery_result = state
+            .recovery_service
+            .fetch(account_id, self.initiation_time)
+            .await;
+
+        if let Ok(recovery) = recovery_result {
+            return recovery.recovery_status == RecoveryStatus::Pending;
+        }
+        false
+    }
+}
+
+#[async_trait]
+impl ValidateNotificationDelivery for SocialRecoveryCompletedPayload {
+    async fn validate_delivery(
+        &self,
+        state: &NotificationValidationState,
+        composite_key: &NotificationCompositeKey,
+    ) -> bool {
+        let (account_id, _) = composite_key;
+        let recovery_result = state
+            .recovery_service
+            .fetch(account_id, self.initiation_time)
+            .await;
+
+        if let Ok(recovery) = recovery_result {
+            return recovery.recovery_status == RecoveryStatus::Pending;
+        }
+        false
+    }
+}
+
+#[async_trait]
+impl ValidateNotificationDelivery for SocialRecoveryCanceledPayload {
+    async fn validate_delivery(
+        &self,
+        state: &NotificationValidationState,
+        composite_key: &NotificationCompositeKey,
+    ) -> bool {
+        let (account_id, _) = composite_key;
+        let recovery_result = state
+            .recovery_service
+            .fetch(account_id, self.initiation_time)
+            .await;
+
+        if let Ok(recovery) = recovery_result {
+            return recovery.recovery_status == RecoveryStatus::Canceled
+                || recovery.recovery_status == RecoveryStatus::CanceledInContest;
+        }
+        false
+    }
+}
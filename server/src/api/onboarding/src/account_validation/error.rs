@@ -21,6 +21,8 @@ pub enum AccountValidationError {
     DuplicateAccountForKeys(Account),
     #[error("Invalid network for Test Account")]
     InvalidNetworkForTestAccount,
+    #[error("Account creation denied by risk assessment")]
+    Denied,
     #[error(transparent)]
     DatabaseError(#[from] DatabaseError),
     #[error(transparent)]
@@ -55,6 +57,7 @@ impl From<AccountValidationError> for ApiError {
             | AccountValidationError::DuplicateAccountForKeys(_) => {
                 ApiError::GenericBadRequest(err_msg)
             }
+            AccountValidationError::Denied => ApiError::GenericForbidden(err_msg),
         }
     }
 }
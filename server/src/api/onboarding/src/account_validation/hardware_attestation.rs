@@ -0,0 +1,41 @@
+use account::service::Service as AccountService;
+use async_trait::async_trait;
+use recovery::repository::Repository as RecoveryService;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
+
+use crate::routes::Config;
+
+use super::{error::AccountValidationError, AccountValidationRequest, Rule, RuleOutcome};
+
+// Every full account carries this factor, so scoring it would only shift the thresholds
+const UNATTESTED_HARDWARE_SCORE: u32 = 0;
+
+/// Records on the assessment that the paired hardware wasn't attested. Only the app verifies the
+/// hardware's device identity certificate chain today, so the server has no attested serial number
+/// to count accounts per device by. Once the server verifies the chain itself, this rule should
+/// score failed attestation, and the velocity rule should count the attested serial.
+pub(crate) struct HardwareAttestationRule;
+
+#[async_trait]
+impl Rule for HardwareAttestationRule {
+    async fn validate(
+        &self,
+        request: &AccountValidationRequest,
+        _: &Config,
+        _: &AccountService,
+        _: &RecoveryService,
+        _: &OnboardingSignalRepository,
+    ) -> Result<RuleOutcome, AccountValidationError> {
+        // This check only applies to requests that pair hardware
+        match request {
+            AccountValidationRequest::CreateFullAccount { .. }
+            | AccountValidationRequest::UpgradeAccount { .. } => Ok(RuleOutcome::risk(
+                "HARDWARE_ATTESTATION",
+                UNATTESTED_HARDWARE_SCORE,
+                "Hardware attestation isn't verified by the server",
+            )),
+            AccountValidationRequest::CreateLiteAccount { .. }
+            | AccountValidationRequest::ActivateTouchpoint { .. } => Ok(RuleOutcome::default()),
+        }
+    }
+}
@@ -5,13 +5,21 @@ use account::entities::{
 use account::service::Service as AccountService;
 use async_trait::async_trait;
 
+use ::metrics::KeyValue;
 use recovery::repository::Repository as RecoveryService;
-use tracing::instrument;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
+use time::OffsetDateTime;
+use tracing::{event, instrument, Level};
+use types::account::identifiers::AccountId;
+use types::account::risk::{OnboardingSignalKind, RiskAssessment, RiskDecision, RiskFactor};
 
+use crate::metrics;
 use crate::routes::Config;
 
+use self::hardware_attestation::HardwareAttestationRule;
 use self::no_recovery_with_hardware_auth_pubkey::NoRecoveryWithHardwareAuthPubkeyRule;
 use self::no_recovery_with_recovery_auth_pubkey::NoRecoveryWithRecoveryAuthPubkeyRule;
+use self::onboarding_velocity::OnboardingVelocityRule;
 use self::test_account_with_mainnet_keysets::TestAccountsWithMainnetKeysetsRule;
 use self::unique_app_auth_pubkey_for_account::UniqueAppAuthPubkeyForAccountRule;
 use self::unique_hardware_auth_pubkey_for_account::UniqueHardwareAuthPubkeyForAccountRule;
@@ -24,14 +32,20 @@ use self::{
 use errors::ApiError;
 
 pub mod error;
+pub(crate) mod hardware_attestation;
 pub(crate) mod no_recovery_with_app_auth_pubkey;
 pub(crate) mod no_recovery_with_hardware_auth_pubkey;
 pub(crate) mod no_recovery_with_recovery_auth_pubkey;
+pub(crate) mod onboarding_velocity;
 pub(crate) mod test_account_with_mainnet_keysets;
 pub(crate) mod unique_app_auth_pubkey_for_account;
 pub(crate) mod unique_hardware_auth_pubkey_for_account;
 pub(crate) mod unique_recovery_auth_pubkey_for_account;
 
+/// Scores at or above these thresholds challenge or deny the account.
+const CHALLENGE_SCORE: u32 = 50;
+const DENY_SCORE: u32 = 100;
+
 #[derive(Debug)]
 pub(crate) enum AccountValidationRequest {
     CreateFullAccount {
@@ -44,15 +58,66 @@ pub(crate) enum AccountValidationRequest {
         auth: LiteAccountAuthKeysPayload,
     },
     UpgradeAccount {
+        account_id: AccountId,
         auth: UpgradeLiteAccountAuthKeysPayload,
         is_test_account: bool,
         spending_network: Network,
     },
+    ActivateTouchpoint {
+        account_id: AccountId,
+        touchpoint: String,
+    },
+}
+
+impl AccountValidationRequest {
+    /// The account the request is for, if it already exists.
+    fn account_id(&self) -> Option<&AccountId> {
+        match self {
+            AccountValidationRequest::CreateFullAccount { .. }
+            | AccountValidationRequest::CreateLiteAccount { .. } => None,
+            AccountValidationRequest::UpgradeAccount { account_id, .. }
+            | AccountValidationRequest::ActivateTouchpoint { account_id, .. } => Some(account_id),
+        }
+    }
+
+    /// The identifier to record an onboarding signal for, if the request has one of `kind`.
+    pub(crate) fn signal_value(&self, kind: OnboardingSignalKind) -> Option<&str> {
+        match (kind, self) {
+            (
+                OnboardingSignalKind::Touchpoint,
+                AccountValidationRequest::ActivateTouchpoint { touchpoint, .. },
+            ) => Some(touchpoint.as_str()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(PartialEq, Debug)]
-pub(crate) struct AccountValidationResponse {
-    pub existing_account: Account,
+pub(crate) enum AccountValidationResponse {
+    /// The request repeats the one that created this account.
+    ExistingAccount(Account),
+    Assessed(RiskAssessment),
+}
+
+/// A rule's contribution to the risk of a request. Rules that only pass or fail return the
+/// default, which carries no risk.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct RuleOutcome {
+    pub factors: Vec<RiskFactor>,
+}
+
+impl RuleOutcome {
+    /// `key` identifies what the risk is about, so that re-assessing it replaces the factor
+    /// instead of adding to it.
+    pub fn risk(key: impl Into<String>, score: u32, reason: impl Into<String>) -> Self {
+        Self {
+            factors: vec![RiskFactor {
+                key: key.into(),
+                score,
+                reason: reason.into(),
+            }],
+        }
+    }
 }
 
 #[async_trait]
@@ -63,7 +128,8 @@ trait Rule {
         config: &Config,
         account_service: &AccountService,
         recovery_service: &RecoveryService,
-    ) -> Result<(), AccountValidationError>;
+        signal_repository: &OnboardingSignalRepository,
+    ) -> Result<RuleOutcome, AccountValidationError>;
 }
 
 pub(crate) struct AccountValidation {
@@ -81,36 +147,108 @@ impl Default for AccountValidation {
                 Box::new(UniqueHardwareAuthPubkeyForAccountRule {}),
                 Box::new(UniqueRecoveryAuthPubkeyForAccountRule {}),
                 Box::new(TestAccountsWithMainnetKeysetsRule {}),
+                Box::new(HardwareAttestationRule {}),
+                Box::new(OnboardingVelocityRule {
+                    kind: OnboardingSignalKind::Touchpoint,
+                }),
             ],
         }
     }
 }
 
 impl AccountValidation {
-    #[instrument(skip(self, config, account_service, recovery_service))]
+    /// Runs every rule against the request. Hard failures are returned as errors; otherwise the
+    /// rules' scores are summed into a decision. Denied requests aren't rejected here, since
+    /// callers handle them differently depending on whether the account exists yet.
+    #[instrument(skip(self, config, account_service, recovery_service, signal_repository))]
     pub async fn validate(
         &self,
-        request: AccountValidationRequest,
+        request: &AccountValidationRequest,
         config: &Config,
         account_service: &AccountService,
         recovery_service: &RecoveryService,
-    ) -> Result<Option<AccountValidationResponse>, ApiError> {
+        signal_repository: &OnboardingSignalRepository,
+    ) -> Result<AccountValidationResponse, ApiError> {
+        let mut factors = Vec::new();
         for rule in self.rules.iter() {
             match rule
-                .validate(&request, config, account_service, recovery_service)
+                .validate(
+                    request,
+                    config,
+                    account_service,
+                    recovery_service,
+                    signal_repository,
+                )
                 .await
             {
                 Err(AccountValidationError::DuplicateAccountForKeys(existing_account)) => {
-                    return Ok(Some(AccountValidationResponse { existing_account }));
+                    return Ok(AccountValidationResponse::ExistingAccount(existing_account));
                 }
-                Err(e) => Err::<(), ApiError>(e.into()),
-                Ok(_) => Ok(()),
-            }?;
+                Err(e) => return Err(e.into()),
+                Ok(outcome) => factors.extend(outcome.factors),
+            }
+        }
+
+        let assessment = assessment_for_factors(factors, OffsetDateTime::now_utc());
+        if assessment.decision != RiskDecision::Allow {
+            event!(
+                Level::WARN,
+                "Account validation decided {} with score {}: {:?}",
+                assessment.decision,
+                assessment.score,
+                assessment.factors,
+            );
         }
-        Ok(None)
+        metrics::RISK_DECISION.add(
+            1,
+            &[KeyValue::new(
+                metrics::DECISION_KEY,
+                assessment.decision.to_string(),
+            )],
+        );
+        Ok(AccountValidationResponse::Assessed(assessment))
     }
 }
 
+fn decision_for_score(score: u32) -> RiskDecision {
+    if score >= DENY_SCORE {
+        RiskDecision::Deny
+    } else if score >= CHALLENGE_SCORE {
+        RiskDecision::Challenge
+    } else {
+        RiskDecision::Allow
+    }
+}
+
+fn assessment_for_factors(factors: Vec<RiskFactor>, assessed_at: OffsetDateTime) -> RiskAssessment {
+    let score = factors.iter().map(|factor| factor.score).sum();
+    RiskAssessment {
+        decision: decision_for_score(score),
+        score,
+        factors,
+        assessed_at,
+    }
+}
+
+/// Folds an assessment of a later request, such as activating a touchpoint, into the account's
+/// onboarding assessment. Factors the later request re-assessed replace the existing ones, so
+/// repeating a request doesn't count the same risk twice.
+pub(crate) fn merge_assessments(
+    existing: Option<RiskAssessment>,
+    latest: RiskAssessment,
+) -> RiskAssessment {
+    let Some(existing) = existing else {
+        return latest;
+    };
+    let factors = existing
+        .factors
+        .into_iter()
+        .filter(|factor| latest.factors.iter().all(|f| f.key != factor.key))
+        .chain(latest.factors)
+        .collect();
+    assessment_for_factors(factors, latest.assessed_at)
+}
+
 fn is_repeat_account_creation(
     request: &AccountValidationRequest,
     existing_account: &Account,
@@ -148,3 +286,63 @@ fn is_repeat_account_creation(
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+    use types::account::risk::{RiskDecision, RiskFactor};
+
+    use super::{assessment_for_factors, decision_for_score, merge_assessments};
+
+    fn factor(key: &str, score: u32) -> RiskFactor {
+        RiskFactor {
+            key: key.to_string(),
+            score,
+            reason: format!("{key} reason"),
+        }
+    }
+
+    #[test]
+    fn test_decision_for_score() {
+        assert_eq!(decision_for_score(0), RiskDecision::Allow);
+        assert_eq!(decision_for_score(49), RiskDecision::Allow);
+        assert_eq!(decision_for_score(50), RiskDecision::Challenge);
+        assert_eq!(decision_for_score(100), RiskDecision::Deny);
+    }
+
+    #[test]
+    fn test_merge_assessments_accumulates_distinct_factors() {
+        let existing = assessment_for_factors(vec![factor("first", 25)], OffsetDateTime::now_utc());
+        let merged = merge_assessments(
+            Some(existing),
+            assessment_for_factors(vec![factor("second", 25)], OffsetDateTime::now_utc()),
+        );
+        assert_eq!(merged.score, 50);
+        assert_eq!(merged.decision, RiskDecision::Challenge);
+        assert_eq!(
+            merged.factors,
+            vec![factor("first", 25), factor("second", 25)]
+        );
+
+        assert_eq!(merge_assessments(None, merged.clone()), merged);
+    }
+
+    #[test]
+    fn test_merge_assessments_replaces_reassessed_factors() {
+        let existing = assessment_for_factors(
+            vec![factor("mainnet", 10), factor("touchpoint", 25)],
+            OffsetDateTime::now_utc(),
+        );
+        // Activating the same touchpoint again re-counts it rather than adding to it
+        let merged = merge_assessments(
+            Some(existing),
+            assessment_for_factors(vec![factor("touchpoint", 25)], OffsetDateTime::now_utc()),
+        );
+        assert_eq!(merged.score, 35);
+        assert_eq!(merged.decision, RiskDecision::Allow);
+        assert_eq!(
+            merged.factors,
+            vec![factor("mainnet", 10), factor("touchpoint", 25)]
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use account::service::Service as AccountService;
 use async_trait::async_trait;
 use recovery::repository::Repository as RecoveryService;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
 
 use crate::routes::Config;
 
-use super::{error::AccountValidationError, AccountValidationRequest, Rule};
+use super::{error::AccountValidationError, AccountValidationRequest, Rule, RuleOutcome};
 
 pub(crate) struct NoRecoveryWithAppAuthPubkeyRule;
 
@@ -16,13 +17,15 @@ impl Rule for NoRecoveryWithAppAuthPubkeyRule {
         _: &Config,
         _: &AccountService,
         recovery_service: &RecoveryService,
-    ) -> Result<(), AccountValidationError> {
+        _: &OnboardingSignalRepository,
+    ) -> Result<RuleOutcome, AccountValidationError> {
         // This check only applies to creating or upgrading to full accounts
         let app_auth_pubkey = match request {
             AccountValidationRequest::CreateFullAccount { auth, .. } => auth.app,
             AccountValidationRequest::UpgradeAccount { auth, .. } => auth.app,
-            AccountValidationRequest::CreateLiteAccount { .. } => {
-                return Ok(());
+            AccountValidationRequest::CreateLiteAccount { .. }
+            | AccountValidationRequest::ActivateTouchpoint { .. } => {
+                return Ok(RuleOutcome::default());
             }
         };
 
@@ -38,6 +41,6 @@ impl Rule for NoRecoveryWithAppAuthPubkeyRule {
             return Err(AccountValidationError::AppAuthPubkeyReuseRecovery)?;
         }
 
-        Ok(())
+        Ok(RuleOutcome::default())
     }
 }
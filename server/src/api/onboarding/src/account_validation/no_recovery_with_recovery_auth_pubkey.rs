@@ -1,10 +1,11 @@
 use account::service::Service as AccountService;
 use async_trait::async_trait;
 use recovery::repository::Repository as RecoveryService;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
 
 use crate::routes::Config;
 
-use super::{error::AccountValidationError, AccountValidationRequest, Rule};
+use super::{error::AccountValidationError, AccountValidationRequest, Rule, RuleOutcome};
 
 pub(crate) struct NoRecoveryWithRecoveryAuthPubkeyRule;
 
@@ -16,19 +17,21 @@ impl Rule for NoRecoveryWithRecoveryAuthPubkeyRule {
         _: &Config,
         _: &AccountService,
         recovery_service: &RecoveryService,
-    ) -> Result<(), AccountValidationError> {
+        _: &OnboardingSignalRepository,
+    ) -> Result<RuleOutcome, AccountValidationError> {
         // This check only applies to creating full or lite accounts
         let recovery_auth_pubkey = match request {
             AccountValidationRequest::CreateFullAccount { auth, .. } => {
                 if let Some(recovery_auth_pubkey) = auth.recovery {
                     recovery_auth_pubkey
                 } else {
-                    return Ok(());
+                    return Ok(RuleOutcome::default());
                 }
             }
             AccountValidationRequest::CreateLiteAccount { auth, .. } => auth.recovery,
-            AccountValidationRequest::UpgradeAccount { .. } => {
-                return Ok(());
+            AccountValidationRequest::UpgradeAccount { .. }
+            | AccountValidationRequest::ActivateTouchpoint { .. } => {
+                return Ok(RuleOutcome::default());
             }
         };
 
@@ -44,6 +47,6 @@ impl Rule for NoRecoveryWithRecoveryAuthPubkeyRule {
             return Err(AccountValidationError::RecoveryAuthPubkeyReuseRecovery)?;
         }
 
-        Ok(())
+        Ok(RuleOutcome::default())
     }
 }
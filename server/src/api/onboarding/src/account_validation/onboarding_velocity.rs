@@ -0,0 +1,59 @@
+use account::service::Service as AccountService;
+use async_trait::async_trait;
+use recovery::repository::Repository as RecoveryService;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
+use time::{Duration, OffsetDateTime};
+use types::account::risk::{OnboardingSignal, OnboardingSignalKind};
+
+use crate::routes::Config;
+
+use super::{error::AccountValidationError, AccountValidationRequest, Rule, RuleOutcome};
+
+const VELOCITY_WINDOW: Duration = Duration::days(1);
+const SCORE_PER_RECENT_ACCOUNT: u32 = 25;
+
+/// Adds risk for each other account onboarded recently with the same verified touchpoint. Signals
+/// are recorded by the routes once the request succeeds.
+pub(crate) struct OnboardingVelocityRule {
+    pub kind: OnboardingSignalKind,
+}
+
+#[async_trait]
+impl Rule for OnboardingVelocityRule {
+    async fn validate(
+        &self,
+        request: &AccountValidationRequest,
+        _: &Config,
+        _: &AccountService,
+        _: &RecoveryService,
+        signal_repository: &OnboardingSignalRepository,
+    ) -> Result<RuleOutcome, AccountValidationError> {
+        let Some(value) = request.signal_value(self.kind) else {
+            return Ok(RuleOutcome::default());
+        };
+
+        let key = OnboardingSignal::key(self.kind, value);
+        let window_start = OffsetDateTime::now_utc() - VELOCITY_WINDOW;
+        let recent_accounts = signal_repository
+            .fetch_for_key(&key)
+            .await?
+            .into_iter()
+            .filter(|signal| {
+                signal.created_at >= window_start
+                    && Some(&signal.account_id) != request.account_id()
+            })
+            .count() as u32;
+
+        if recent_accounts == 0 {
+            return Ok(RuleOutcome::default());
+        }
+        let subject = match self.kind {
+            OnboardingSignalKind::Touchpoint => "Touchpoint",
+        };
+        Ok(RuleOutcome::risk(
+            key,
+            recent_accounts * SCORE_PER_RECENT_ACCOUNT,
+            format!("{subject} used by {recent_accounts} other account(s) in the past day"),
+        ))
+    }
+}
@@ -1,10 +1,13 @@
 use account::{entities::Network, service::Service as AccountService};
 use async_trait::async_trait;
 use recovery::repository::Repository as RecoveryService;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
 
 use crate::routes::Config;
 
-use super::{error::AccountValidationError, AccountValidationRequest, Rule};
+use super::{error::AccountValidationError, AccountValidationRequest, Rule, RuleOutcome};
+
+const TEST_ACCOUNT_WITH_MAINNET_KEYSETS_SCORE: u32 = 50;
 
 pub(crate) struct TestAccountsWithMainnetKeysetsRule;
 
@@ -16,7 +19,8 @@ impl Rule for TestAccountsWithMainnetKeysetsRule {
         config: &Config,
         _: &AccountService,
         _: &RecoveryService,
-    ) -> Result<(), AccountValidationError> {
+        _: &OnboardingSignalRepository,
+    ) -> Result<RuleOutcome, AccountValidationError> {
         // This check only applies to creating or upgrading to full accounts
         let (is_test_account, spending_network) = match request {
             AccountValidationRequest::CreateFullAccount {
@@ -29,18 +33,24 @@ impl Rule for TestAccountsWithMainnetKeysetsRule {
                 spending_network,
                 ..
             } => (is_test_account, spending_network),
-            AccountValidationRequest::CreateLiteAccount { .. } => {
-                return Ok(());
+            AccountValidationRequest::CreateLiteAccount { .. }
+            | AccountValidationRequest::ActivateTouchpoint { .. } => {
+                return Ok(RuleOutcome::default());
             }
         };
 
-        if !config.allow_test_accounts_with_mainnet_keysets
-            && *is_test_account
-            && *spending_network == Network::BitcoinMain
-        {
+        if !*is_test_account || *spending_network != Network::BitcoinMain {
+            return Ok(RuleOutcome::default());
+        }
+        if !config.allow_test_accounts_with_mainnet_keysets {
             return Err(AccountValidationError::InvalidNetworkForTestAccount);
         }
 
-        Ok(())
+        // Where this is allowed it's only expected for internal testing, so have it reviewed
+        Ok(RuleOutcome::risk(
+            "TEST_ACCOUNT_WITH_MAINNET_KEYSETS",
+            TEST_ACCOUNT_WITH_MAINNET_KEYSETS_SCORE,
+            "Test account with mainnet keysets",
+        ))
     }
 }
@@ -1,11 +1,13 @@
 use account::service::{FetchAccountByAuthKeyInput, Service as AccountService};
 use async_trait::async_trait;
 use recovery::repository::Repository as RecoveryService;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
 
 use crate::routes::Config;
 
 use super::{
     error::AccountValidationError, is_repeat_account_creation, AccountValidationRequest, Rule,
+    RuleOutcome,
 };
 
 pub(crate) struct UniqueAppAuthPubkeyForAccountRule;
@@ -18,13 +20,15 @@ impl Rule for UniqueAppAuthPubkeyForAccountRule {
         _: &Config,
         account_service: &AccountService,
         _: &RecoveryService,
-    ) -> Result<(), AccountValidationError> {
+        _: &OnboardingSignalRepository,
+    ) -> Result<RuleOutcome, AccountValidationError> {
         // This check only applies to creating or upgrading to full accounts
         let app_auth_pubkey = match request {
             AccountValidationRequest::CreateFullAccount { auth, .. } => auth.app,
             AccountValidationRequest::UpgradeAccount { auth, .. } => auth.app,
-            AccountValidationRequest::CreateLiteAccount { .. } => {
-                return Ok(());
+            AccountValidationRequest::CreateLiteAccount { .. }
+            | AccountValidationRequest::ActivateTouchpoint { .. } => {
+                return Ok(RuleOutcome::default());
             }
         };
 
@@ -45,6 +49,6 @@ impl Rule for UniqueAppAuthPubkeyForAccountRule {
             ));
         }
 
-        Ok(())
+        Ok(RuleOutcome::default())
     }
 }
@@ -1,11 +1,13 @@
 use account::service::{FetchAccountByAuthKeyInput, Service as AccountService};
 use async_trait::async_trait;
 use recovery::repository::Repository as RecoveryService;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
 
 use crate::routes::Config;
 
 use super::{
     error::AccountValidationError, is_repeat_account_creation, AccountValidationRequest, Rule,
+    RuleOutcome,
 };
 
 pub(crate) struct UniqueHardwareAuthPubkeyForAccountRule;
@@ -18,13 +20,15 @@ impl Rule for UniqueHardwareAuthPubkeyForAccountRule {
         _: &Config,
         account_service: &AccountService,
         _: &RecoveryService,
-    ) -> Result<(), AccountValidationError> {
+        _: &OnboardingSignalRepository,
+    ) -> Result<RuleOutcome, AccountValidationError> {
         // This check only applies to creating or upgrading to full accounts
         let hw_auth_pubkey = match request {
             AccountValidationRequest::CreateFullAccount { auth, .. } => auth.hardware,
             AccountValidationRequest::UpgradeAccount { auth, .. } => auth.hardware,
-            AccountValidationRequest::CreateLiteAccount { .. } => {
-                return Ok(());
+            AccountValidationRequest::CreateLiteAccount { .. }
+            | AccountValidationRequest::ActivateTouchpoint { .. } => {
+                return Ok(RuleOutcome::default());
             }
         };
 
@@ -45,6 +49,6 @@ impl Rule for UniqueHardwareAuthPubkeyForAccountRule {
             ));
         }
 
-        Ok(())
+        Ok(RuleOutcome::default())
     }
 }
@@ -1,11 +1,13 @@
 use account::service::{FetchAccountByAuthKeyInput, Service as AccountService};
 use async_trait::async_trait;
 use recovery::repository::Repository as RecoveryService;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
 
 use crate::routes::Config;
 
 use super::{
     error::AccountValidationError, is_repeat_account_creation, AccountValidationRequest, Rule,
+    RuleOutcome,
 };
 
 pub(crate) struct UniqueRecoveryAuthPubkeyForAccountRule;
@@ -18,19 +20,21 @@ impl Rule for UniqueRecoveryAuthPubkeyForAccountRule {
         _: &Config,
         account_service: &AccountService,
         _: &RecoveryService,
-    ) -> Result<(), AccountValidationError> {
+        _: &OnboardingSignalRepository,
+    ) -> Result<RuleOutcome, AccountValidationError> {
         // This check only applies to creating full or lite accounts
         let recovery_auth_pubkey = match request {
             AccountValidationRequest::CreateFullAccount { auth, .. } => {
                 if let Some(recovery_auth_pubkey) = auth.recovery {
                     recovery_auth_pubkey
                 } else {
-                    return Ok(());
+                    return Ok(RuleOutcome::default());
                 }
             }
             AccountValidationRequest::CreateLiteAccount { auth, .. } => auth.recovery,
-            AccountValidationRequest::UpgradeAccount { .. } => {
-                return Ok(());
+            AccountValidationRequest::UpgradeAccount { .. }
+            | AccountValidationRequest::ActivateTouchpoint { .. } => {
+                return Ok(RuleOutcome::default());
             }
         };
 
@@ -51,6 +55,6 @@ impl Rule for UniqueRecoveryAuthPubkeyForAccountRule {
             ));
         }
 
-        Ok(())
+        Ok(RuleOutcome::default())
     }
 }
@@ -0,0 +1,6 @@
+use feature_flags::flag::Flag;
+
+// Webhook touchpoints are only offered to the developer and enterprise accounts targeted by this
+// flag
+pub(crate) const FLAG_WEBHOOK_TOUCHPOINTS_ENABLE: Flag<bool> =
+    Flag::new("f8e-webhook-touchpoints-enable");
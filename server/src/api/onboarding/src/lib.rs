@@ -14,6 +14,7 @@ use types::{
 };
 
 pub mod account_validation;
+pub(crate) mod flags;
 pub(crate) mod metrics;
 pub mod routes;
 
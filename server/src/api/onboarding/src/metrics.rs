@@ -1,4 +1,10 @@
-use metrics::factory::MetricsFactory;
+use metrics::factory::{Counter, MetricsFactory};
 use once_cell::sync::Lazy;
 
+pub(crate) const DECISION_KEY: &str = "decision";
+
 pub(crate) static FACTORY: Lazy<MetricsFactory> = Lazy::new(|| MetricsFactory::new("onboarding"));
+
+// Counters
+pub(crate) static RISK_DECISION: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("account_validation.risk_decision", None));
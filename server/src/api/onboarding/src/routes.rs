@@ -29,8 +29,9 @@ use account::service::{
     ActivateTouchpointForAccountInput, AddPushTouchpointToAccountInput, CompleteOnboardingInput,
     CreateAccountAndKeysetsInput, CreateInactiveSpendingKeysetInput, CreateLiteAccountInput,
     DeleteAccountInput, FetchAccountInput, FetchOrCreateEmailTouchpointInput,
-    FetchOrCreatePhoneTouchpointInput, FetchTouchpointByIdInput, RotateToSpendingKeysetInput,
-    Service as AccountService, UpgradeLiteAccountToFullAccountInput,
+    FetchOrCreatePhoneTouchpointInput, FetchOrCreateWebhookTouchpointInput,
+    FetchTouchpointByIdInput, RotateToSpendingKeysetInput, Service as AccountService,
+    UpdateRiskAssessmentInput, UpgradeLiteAccountToFullAccountInput,
 };
 use authn_authz::key_claims::KeyClaims;
 use authn_authz::userpool::{CreateRecoveryUserInput, CreateWalletUserInput, UserPoolService};
@@ -54,12 +55,19 @@ use notification::clients::iterable::{
     IterableClient, IterableMode, IterableUserId, ACCOUNT_ID_KEY, TOUCHPOINT_ID_KEY, USER_SCOPE_KEY,
 };
 use notification::clients::twilio::{TwilioClient, TwilioMode};
+use notification::clients::webhook::{self, WebhookClient, WebhookMode};
 use notification::service::Service as NotificationService;
 use recovery::repository::Repository as RecoveryService;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
 use types::account::identifiers::{AccountId, AuthKeysId, KeysetId, TouchpointId};
+use types::account::risk::{OnboardingSignal, OnboardingSignalKind, RiskDecision};
 use wsm_rust_client::{SigningService, WsmClient};
 
-use crate::account_validation::{AccountValidation, AccountValidationRequest};
+use crate::account_validation::error::AccountValidationError;
+use crate::account_validation::{
+    merge_assessments, AccountValidation, AccountValidationRequest, AccountValidationResponse,
+};
+use crate::flags::FLAG_WEBHOOK_TOUCHPOINTS_ENABLE;
 use crate::{create_account_iterable_users, enable_account_security_notifications, metrics};
 use once_cell::sync::Lazy;
 
@@ -77,6 +85,7 @@ pub struct Config {
     pub(crate) allow_test_accounts_with_mainnet_keysets: bool,
     pub iterable: IterableMode,
     pub twilio: TwilioMode,
+    pub webhook: WebhookMode,
 }
 
 #[derive(Clone, axum_macros::FromRef)]
@@ -92,6 +101,8 @@ pub struct RouteState(
     pub IterableClient,
     pub TwilioClient,
     pub FeatureFlagsService,
+    pub WebhookClient,
+    pub OnboardingSignalRepository,
 );
 
 impl RouteState {
@@ -275,11 +286,15 @@ async fn add_device_token_to_account(
 pub enum AccountAddTouchpointRequest {
     Phone { phone_number: String },
     Email { email_address: String },
+    Webhook { url: String },
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct AccountAddTouchpointResponse {
     pub touchpoint_id: TouchpointId,
+    // Only returned for webhooks; deliveries to the webhook are signed with it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_secret: Option<String>,
 }
 
 #[utoipa::path(
@@ -291,6 +306,7 @@ pub struct AccountAddTouchpointResponse {
     request_body = AccountAddTouchpointRequest,
     responses(
         (status = 200, description = "Touchpoint was added pending verification", body=AccountAddTouchpointResponse),
+        (status = 403, description = "Webhook touchpoints aren't enabled for the account"),
     ),
 )]
 async fn add_touchpoint_to_account(
@@ -298,6 +314,7 @@ async fn add_touchpoint_to_account(
     State(comms_verification_service): State<CommsVerificationService>,
     State(iterable_client): State<IterableClient>,
     State(twilio_client): State<TwilioClient>,
+    State(feature_flags_service): State<FeatureFlagsService>,
     Path(account_id): Path<AccountId>,
     Json(request): Json<AccountAddTouchpointRequest>,
 ) -> Result<Json<AccountAddTouchpointResponse>, ApiError> {
@@ -377,7 +394,10 @@ async fn add_touchpoint_to_account(
                 })
                 .await?;
 
-            Ok(Json(AccountAddTouchpointResponse { touchpoint_id }))
+            Ok(Json(AccountAddTouchpointResponse {
+                touchpoint_id,
+                webhook_secret: None,
+            }))
         }
         AccountAddTouchpointRequest::Email { email_address } => {
             if !EMAIL_REGEX.is_match(&email_address) {
@@ -443,7 +463,62 @@ async fn add_touchpoint_to_account(
                 })
                 .await?;
 
-            Ok(Json(AccountAddTouchpointResponse { touchpoint_id }))
+            Ok(Json(AccountAddTouchpointResponse {
+                touchpoint_id,
+                webhook_secret: None,
+            }))
+        }
+        AccountAddTouchpointRequest::Webhook { url } => {
+            if !FLAG_WEBHOOK_TOUCHPOINTS_ENABLE
+                .resolver_for_account(&feature_flags_service, &account_id.to_string())
+                .resolve()
+            {
+                return Err(ApiError::GenericForbidden(
+                    "Webhook touchpoints are not enabled for this account".to_string(),
+                ));
+            }
+            if !webhook::is_valid_url(&url) {
+                return Err(ApiError::GenericBadRequest(
+                    "Webhook URL must be an HTTPS URL on a public host".to_string(),
+                ));
+            }
+
+            let touchpoint = account_service
+                .fetch_or_create_webhook_touchpoint(FetchOrCreateWebhookTouchpointInput {
+                    account_id: account_id.clone(),
+                    url,
+                    secret: webhook::generate_secret(),
+                })
+                .await?;
+
+            let Touchpoint::Webhook {
+                id: touchpoint_id,
+                secret,
+                active,
+                ..
+            } = touchpoint
+            else {
+                let msg = "Unexpected error adding touchpoint";
+                error!("{msg}");
+                return Err(ApiError::GenericInternalApplicationError(msg.to_string()));
+            };
+
+            if active {
+                let msg = "Touchpoint already active";
+                error!("{msg}");
+                return Err(ApiError::Specific {
+                    code: ErrorCode::TouchpointAlreadyActive,
+                    detail: Some(msg.to_string()),
+                    field: None,
+                });
+            }
+
+            // Webhooks aren't sent a verification code; the endpoint answers a challenge signed
+            // with this secret when the touchpoint is activated instead
+            Ok(Json(AccountAddTouchpointResponse {
+                touchpoint_id,
+                webhook_secret: Some(secret),
+            }))
         }
     }
 }
@@ -525,11 +600,16 @@ pub struct AccountActivateTouchpointResponse {}
         (status = 200, description = "Touchpoint was activated", body=AccountActivateTouchpointResponse),
     ),
 )]
+#[allow(clippy::too_many_arguments)]
 async fn activate_touchpoint_for_account(
     State(account_service): State<AccountService>,
+    State(recovery_service): State<RecoveryService>,
+    State(signal_repository): State<OnboardingSignalRepository>,
+    State(config): State<Config>,
     State(comms_verification_service): State<CommsVerificationService>,
     State(iterable_client): State<IterableClient>,
     State(notification_service): State<NotificationService>,
+    State(webhook_client): State<WebhookClient>,
     key_proof: KeyClaims,
     Path((account_id, touchpoint_id)): Path<(AccountId, TouchpointId)>,
     Json(_request): Json<AccountActivateTouchpointRequest>,
@@ -555,7 +635,9 @@ async fn activate_touchpoint_for_account(
         .await?;
 
     if match touchpoint {
-        Touchpoint::Email { active, .. } | Touchpoint::Phone { active, .. } => active,
+        Touchpoint::Email { active, .. }
+        | Touchpoint::Phone { active, .. }
+        | Touchpoint::Webhook { active, .. } => active,
         _ => false,
     } {
         let msg = "Touchpoint already active";
@@ -567,13 +649,17 @@ async fn activate_touchpoint_for_account(
         });
     }
 
-    let scope = CommsVerificationScope::AddTouchpointId(touchpoint_id.clone());
-    comms_verification_service
-        .consume_verification_for_scope(ConsumeVerificationForScopeInput {
-            account_id: account_id.clone(),
-            scope: scope.clone(),
-        })
-        .await?;
+    if let Touchpoint::Webhook { url, secret, .. } = &touchpoint {
+        webhook_client.verify_endpoint(url, secret).await?;
+    } else {
+        let scope = CommsVerificationScope::AddTouchpointId(touchpoint_id.clone());
+        comms_verification_service
+            .consume_verification_for_scope(ConsumeVerificationForScopeInput {
+                account_id: account_id.clone(),
+                scope: scope.clone(),
+            })
+            .await?;
+    }
 
     account_service
         .activate_touchpoint_for_account(ActivateTouchpointForAccountInput {
@@ -582,6 +668,47 @@ async fn activate_touchpoint_for_account(
         })
         .await?;
 
+    // Reassess the account now that it shares a verified touchpoint with any other accounts
+    let touchpoint_value = match &touchpoint {
+        Touchpoint::Email { email_address, .. } => Some(email_address.to_owned()),
+        Touchpoint::Phone { phone_number, .. } => Some(phone_number.to_owned()),
+        _ => None,
+    };
+    if let Some(touchpoint_value) = touchpoint_value {
+        let validation_request = AccountValidationRequest::ActivateTouchpoint {
+            account_id: account_id.clone(),
+            touchpoint: touchpoint_value.clone(),
+        };
+        if let AccountValidationResponse::Assessed(assessment) = AccountValidation::default()
+            .validate(
+                &validation_request,
+                &config,
+                &account_service,
+                &recovery_service,
+                &signal_repository,
+            )
+            .await?
+        {
+            let assessment = merge_assessments(
+                account.get_common_fields().risk_assessment.clone(),
+                assessment,
+            );
+            account_service
+                .update_risk_assessment(UpdateRiskAssessmentInput {
+                    account_id: &account_id,
+                    risk_assessment: assessment,
+                })
+                .await?;
+            signal_repository
+                .persist(&OnboardingSignal::new(
+                    OnboardingSignalKind::Touchpoint,
+                    &touchpoint_value,
+                    account_id.clone(),
+                ))
+                .await?;
+        }
+    }
+
     if let Touchpoint::Email {
         id, email_address, ..
     } = &touchpoint
@@ -812,6 +939,7 @@ impl TryFrom<&Account> for CreateAccountResponse {
         user_pool_service,
         config,
         iterable_client,
+        signal_repository,
     )
 )]
 #[utoipa::path(
@@ -833,18 +961,27 @@ pub async fn create_account(
     State(user_pool_service): State<UserPoolService>,
     State(config): State<Config>,
     State(iterable_client): State<IterableClient>,
+    State(signal_repository): State<OnboardingSignalRepository>,
     Json(request): Json<CreateAccountRequest>,
 ) -> Result<Json<CreateAccountResponse>, ApiError> {
-    if let Some(v) = AccountValidation::default()
+    let validation_request = AccountValidationRequest::from(&request);
+    let assessment = match AccountValidation::default()
         .validate(
-            AccountValidationRequest::from(&request),
+            &validation_request,
             &config,
             &account_service,
             &recovery_service,
+            &signal_repository,
         )
         .await?
     {
-        return Ok(Json(CreateAccountResponse::try_from(&v.existing_account)?));
+        AccountValidationResponse::ExistingAccount(existing_account) => {
+            return Ok(Json(CreateAccountResponse::try_from(&existing_account)?));
+        }
+        AccountValidationResponse::Assessed(assessment) => assessment,
+    };
+    if assessment.decision == RiskDecision::Deny {
+        return Err(AccountValidationError::Denied.into());
     }
 
     let account_id = AccountId::new(id_generator.gen_account_id()).map_err(|e| {
@@ -869,7 +1006,7 @@ pub async fn create_account(
             ApiError::GenericInternalApplicationError(msg.to_string())
         })?;
 
-    match request {
+    let account = match request {
         CreateAccountRequest::Full {
             spending,
             is_test_account,
@@ -922,6 +1059,7 @@ pub async fn create_account(
                     },
                 },
                 is_test_account,
+                risk_assessment: Some(assessment),
             };
             let account = account_service.create_account_and_keysets(input).await?;
 
@@ -937,13 +1075,13 @@ pub async fn create_account(
                     |_| (),
                 );
 
-            Ok(Json(CreateAccountResponse {
+            CreateAccountResponse {
                 account_id: account.id,
                 keyset: Some(CreateKeysetResponse {
                     keyset_id: account.active_keyset_id,
                     spending: spending_server_dpub,
                 }),
-            }))
+            }
         }
         CreateAccountRequest::Lite {
             auth,
@@ -958,14 +1096,17 @@ pub async fn create_account(
                     recovery_pubkey: auth.recovery,
                 },
                 is_test_account,
+                risk_assessment: Some(assessment),
             };
             let account = account_service.create_lite_account(input).await?;
-            Ok(Json(CreateAccountResponse {
+            CreateAccountResponse {
                 account_id: account.id,
                 keyset: None,
-            }))
+            }
         }
-    }
+    };
+
+    Ok(Json(account))
 }
 
 #[derive(Deserialize, Serialize, PartialEq, Debug, ToSchema)]
@@ -977,6 +1118,7 @@ pub struct UpgradeAccountRequest {
 impl From<(&LiteAccount, &UpgradeAccountRequest)> for AccountValidationRequest {
     fn from(value: (&LiteAccount, &UpgradeAccountRequest)) -> Self {
         AccountValidationRequest::UpgradeAccount {
+            account_id: value.0.id.clone(),
             auth: value.1.auth.to_owned(),
             is_test_account: value.0.common_fields.properties.is_test_account,
             spending_network: value.1.spending.network.into(),
@@ -1026,6 +1168,7 @@ pub async fn upgrade_account(
     State(id_generator): State<IdentifierGenerator>,
     State(user_pool_service): State<UserPoolService>,
     State(config): State<Config>,
+    State(signal_repository): State<OnboardingSignalRepository>,
     Path(account_id): Path<AccountId>,
     Json(request): Json<UpgradeAccountRequest>,
 ) -> Result<Json<CreateAccountResponse>, ApiError> {
@@ -1060,14 +1203,27 @@ pub async fn upgrade_account(
         }
     };
 
-    AccountValidation::default()
+    let validation_request = AccountValidationRequest::from((lite_account, &request));
+    let assessment = match AccountValidation::default()
         .validate(
-            AccountValidationRequest::from((lite_account, &request)),
+            &validation_request,
             &config,
             &account_service,
             &recovery_service,
+            &signal_repository,
         )
-        .await?;
+        .await?
+    {
+        // Upgrades are never repeats of another account's creation, so keys that belong to
+        // another account are rejected rather than answered with that account
+        AccountValidationResponse::ExistingAccount(existing_account) => {
+            return Err(AccountValidationError::DuplicateAccountForKeys(existing_account).into());
+        }
+        AccountValidationResponse::Assessed(assessment) => assessment,
+    };
+    if assessment.decision == RiskDecision::Deny {
+        return Err(AccountValidationError::Denied.into());
+    }
 
     // Create a wallet Cognito user
     user_pool_service
@@ -1134,6 +1290,10 @@ pub async fn upgrade_account(
                     .recovery_pubkey,
             ),
         },
+        risk_assessment: merge_assessments(
+            lite_account.common_fields.risk_assessment.clone(),
+            assessment,
+        ),
     };
     let full_account = account_service
         .upgrade_lite_account_to_full_account(input)
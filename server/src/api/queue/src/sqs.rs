@@ -57,6 +57,18 @@ impl SqsQueue {
 
     #[instrument(skip(self))]
     pub async fn enqueue(&self, queue_url: &str, message: &str) -> Result<(), QueueError> {
+        self.enqueue_with_delay(queue_url, message, 0).await
+    }
+
+    /// Enqueues a message that only becomes visible to consumers after `delay_seconds`, which SQS
+    /// caps at 15 minutes. Test queues make it visible straight away.
+    #[instrument(skip(self))]
+    pub async fn enqueue_with_delay(
+        &self,
+        queue_url: &str,
+        message: &str,
+        delay_seconds: i32,
+    ) -> Result<(), QueueError> {
         match self {
             Self::Real(client) => {
                 if queue_url.is_empty() {
@@ -66,6 +78,7 @@ impl SqsQueue {
                     .send_message()
                     .queue_url(queue_url)
                     .message_body(message)
+                    .delay_seconds(delay_seconds)
                     .send()
                     .await
                     .map_err(|e| {
@@ -4,22 +4,25 @@ use serde::{Deserialize, Serialize};
 use time::{serde::rfc3339, Duration, OffsetDateTime};
 use utoipa::ToSchema;
 
-use account::entities::{Factor, FullAccount};
+use account::entities::{Factor, FullAccount, PendingRecoveryDelayPeriod};
 use authn_authz::key_claims::KeyClaims;
 use bdk_utils::bdk::bitcoin::secp256k1::PublicKey;
 use types::account::identifiers::{AccountId, AuthKeysId};
+use types::recovery::social::challenge::SocialChallengeId;
 
 use crate::error::RecoveryError;
 
 #[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, ToSchema)]
 pub enum RecoveryType {
     DelayAndNotify,
+    SocialRecovery,
 }
 
 impl fmt::Display for RecoveryType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             RecoveryType::DelayAndNotify => write!(f, "DelayAndNotify"),
+            RecoveryType::SocialRecovery => write!(f, "SocialRecovery"),
         }
     }
 }
@@ -30,6 +33,7 @@ impl FromStr for RecoveryType {
     fn from_str(input: &str) -> Result<RecoveryType, Self::Err> {
         match input {
             "DelayAndNotify" => Ok(RecoveryType::DelayAndNotify),
+            "SocialRecovery" => Ok(RecoveryType::SocialRecovery),
             _ => Err(()),
         }
     }
@@ -38,6 +42,8 @@ impl FromStr for RecoveryType {
 #[derive(Deserialize, Serialize, Clone, Debug, ToSchema)]
 pub struct RecoveryRequirements {
     pub delay_notify_requirements: Option<DelayNotifyRequirements>,
+    #[serde(default)]
+    pub social_recovery_requirements: Option<SocialRecoveryRequirements>,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, ToSchema)]
@@ -45,11 +51,25 @@ pub struct DelayNotifyRequirements {
     pub lost_factor: Factor,
     #[serde(with = "rfc3339")]
     pub delay_end_time: OffsetDateTime,
+    // Hash of the token that cancels the recovery without authenticating; the token itself is
+    //   only ever sent to the customer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancellation_token_hash: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, ToSchema)]
+pub struct SocialRecoveryRequirements {
+    pub social_challenge_id: SocialChallengeId,
+    // Number of trusted contacts that must respond to the social challenge
+    //   before the recovery can be completed
+    pub required_responses: usize,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, ToSchema)]
 pub struct RecoveryAction {
     pub delay_notify_action: Option<DelayNotifyRecoveryAction>,
+    #[serde(default)]
+    pub social_recovery_action: Option<SocialRecoveryAction>,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, ToSchema)]
@@ -57,6 +77,11 @@ pub struct DelayNotifyRecoveryAction {
     pub destination: RecoveryDestination,
 }
 
+#[derive(Deserialize, Serialize, Clone, Debug, ToSchema)]
+pub struct SocialRecoveryAction {
+    pub destination: RecoveryDestination,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
 #[serde(tag = "type")]
 pub struct RecoveryDestination {
@@ -103,12 +128,30 @@ pub struct WalletRecovery {
 
 impl WalletRecovery {
     pub fn get_lost_factor(&self) -> Option<Factor> {
-        let requirements = self.requirements.delay_notify_requirements.as_ref();
-        if let Some(requirements) = requirements {
-            return Some(requirements.lost_factor);
+        match self.recovery_type {
+            RecoveryType::DelayAndNotify => self
+                .requirements
+                .delay_notify_requirements
+                .as_ref()
+                .map(|requirements| requirements.lost_factor),
+            // Social recovery restores a lost App with the help of Trusted Contacts
+            RecoveryType::SocialRecovery => Some(Factor::App),
         }
+    }
 
-        None
+    pub fn get_destination(&self) -> Option<&RecoveryDestination> {
+        match self.recovery_type {
+            RecoveryType::DelayAndNotify => self
+                .recovery_action
+                .delay_notify_action
+                .as_ref()
+                .map(|action| &action.destination),
+            RecoveryType::SocialRecovery => self
+                .recovery_action
+                .social_recovery_action
+                .as_ref()
+                .map(|action| &action.destination),
+        }
     }
 }
 
@@ -166,14 +209,74 @@ impl ToActor for KeyClaims {
 }
 
 pub trait RecoveryValuesPerAccountType {
-    fn recovery_delay_period(&self) -> Duration;
+    fn default_recovery_delay_period(&self) -> Duration;
+    fn recovery_delay_period_bounds(&self) -> (Duration, Duration);
+    fn recovery_delay_period(&self, now: OffsetDateTime) -> Duration;
 }
 
 impl RecoveryValuesPerAccountType for FullAccount {
-    fn recovery_delay_period(&self) -> Duration {
+    fn default_recovery_delay_period(&self) -> Duration {
         match self.common_fields.properties.is_test_account {
             true => Duration::seconds(20),
             false => Duration::days(7),
         }
     }
+
+    fn recovery_delay_period_bounds(&self) -> (Duration, Duration) {
+        match self.common_fields.properties.is_test_account {
+            true => (Duration::seconds(20), Duration::hours(1)),
+            false => (Duration::days(7), Duration::days(30)),
+        }
+    }
+
+    fn recovery_delay_period(&self, now: OffsetDateTime) -> Duration {
+        chosen_recovery_delay_period_num_sec(self, now)
+            .map_or_else(|| self.default_recovery_delay_period(), Duration::seconds)
+    }
+}
+
+// The customer's chosen delay at `now`, taking a pending shortening into account once it's due
+fn chosen_recovery_delay_period_num_sec(account: &FullAccount, now: OffsetDateTime) -> Option<i64> {
+    match account.pending_recovery_delay_period.as_ref() {
+        Some(pending) if pending.effective_at <= now => Some(pending.delay_period_num_sec),
+        _ => account.recovery_delay_period_num_sec,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoveryDelayPeriodUpdate {
+    pub recovery_delay_period_num_sec: Option<i64>,
+    pub pending_recovery_delay_period: Option<PendingRecoveryDelayPeriod>,
+}
+
+/// Works out how to move an account to a new Delay & Notify period. Lengthening applies right
+/// away, while shortening has to wait out the current period so that someone holding the
+/// account's keys can't shorten the delay right before starting a recovery.
+pub fn plan_recovery_delay_period_update(
+    account: &FullAccount,
+    delay_period: Duration,
+    now: OffsetDateTime,
+) -> Result<RecoveryDelayPeriodUpdate, RecoveryError> {
+    let (min, max) = account.recovery_delay_period_bounds();
+    if delay_period < min || delay_period > max {
+        return Err(RecoveryError::RecoveryDelayPeriodOutOfBounds);
+    }
+
+    let chosen = chosen_recovery_delay_period_num_sec(account, now);
+    let current = account.recovery_delay_period(now);
+    if delay_period >= current {
+        return Ok(RecoveryDelayPeriodUpdate {
+            recovery_delay_period_num_sec: Some(delay_period.whole_seconds()),
+            pending_recovery_delay_period: None,
+        });
+    }
+
+    Ok(RecoveryDelayPeriodUpdate {
+        recovery_delay_period_num_sec: chosen,
+        pending_recovery_delay_period: Some(PendingRecoveryDelayPeriod {
+            delay_period_num_sec: delay_period.whole_seconds(),
+            requested_at: now,
+            effective_at: now + current,
+        }),
+    })
 }
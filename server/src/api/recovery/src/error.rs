@@ -7,6 +7,10 @@ use errors::{ApiError, ErrorCode};
 use thiserror::Error;
 use tracing::{event, Level};
 
+use crate::service::cancellation_token::error::ServiceError as CancellationTokenServiceError;
+use crate::service::history::error::ServiceError as RecoveryHistoryServiceError;
+use crate::service::social::challenge::error::ServiceError as SocialChallengeServiceError;
+
 #[derive(Debug, Error)]
 pub enum RecoveryError {
     #[error("Invalid Transition")]
@@ -77,6 +81,8 @@ pub enum RecoveryError {
     MalformedRecoveryRequirements,
     #[error("Cannot update parameters for non-test account")]
     InvalidUpdateForNonTestAccount,
+    #[error("Recovery delay period is outside the allowed bounds")]
+    RecoveryDelayPeriodOutOfBounds,
     #[error(transparent)]
     ApiError(#[from] ApiError),
     #[error("Destination hardware auth pubkey in use by an account")]
@@ -93,6 +99,16 @@ pub enum RecoveryError {
     RecoveryAuthPubkeyReuseRecovery,
     #[error("Invalid recovery relationship type")]
     InvalidRecoveryRelationshipType,
+    #[error(transparent)]
+    SocialChallenge(#[from] SocialChallengeServiceError),
+    #[error("Not enough Trusted Contacts have responded to the social challenge")]
+    SocialChallengeThresholdNotMet,
+    #[error(transparent)]
+    RecoveryHistory(#[from] RecoveryHistoryServiceError),
+    #[error(transparent)]
+    CancellationToken(#[from] CancellationTokenServiceError),
+    #[error("Recovery cancellation token is not for the pending recovery")]
+    CancellationTokenMismatch,
 }
 
 impl From<RecoveryError> for ApiError {
@@ -128,7 +144,9 @@ impl From<RecoveryError> for ApiError {
             | RecoveryError::TouchpointTypeMismatch
             | RecoveryError::InvalidRecoverySource
             | RecoveryError::InvalidRecoveryDestination
-            | RecoveryError::InvalidUpdateForNonTestAccount => ApiError::GenericBadRequest(err_msg),
+            | RecoveryError::InvalidUpdateForNonTestAccount
+            | RecoveryError::RecoveryDelayPeriodOutOfBounds
+            | RecoveryError::SocialChallengeThresholdNotMet => ApiError::GenericBadRequest(err_msg),
             RecoveryError::AccountService(err) => match err {
                 AccountError::DDBError(err) => err.into(),
                 _ => ApiError::GenericInternalApplicationError(err_msg),
@@ -167,6 +185,10 @@ impl From<RecoveryError> for ApiError {
                 field: None,
             },
             RecoveryError::ApiError(e) => e,
+            RecoveryError::SocialChallenge(e) => e.into(),
+            RecoveryError::RecoveryHistory(e) => e.into(),
+            RecoveryError::CancellationToken(e) => e.into(),
+            RecoveryError::CancellationTokenMismatch => ApiError::GenericForbidden(err_msg),
             RecoveryError::HwAuthPubkeyReuseAccount | RecoveryError::HwAuthPubkeyReuseRecovery => {
                 ApiError::Specific {
                     code: ErrorCode::HwAuthPubkeyInUse,
@@ -23,8 +23,16 @@ pub(crate) static DELAY_NOTIFY_COMPLETED: Lazy<Counter<u64>> =
     Lazy::new(|| FACTORY.u64_counter("delay_notify.completed", None));
 pub(crate) static DELAY_NOTIFY_CODE_SUBMITTED: Lazy<Counter<u64>> =
     Lazy::new(|| FACTORY.u64_counter("delay_notify.code_submitted", None));
+pub(crate) static SOCIAL_RECOVERY_CREATED: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("social_recovery.created", None));
+pub(crate) static SOCIAL_RECOVERY_CANCELED: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("social_recovery.canceled", None));
+pub(crate) static SOCIAL_RECOVERY_COMPLETED: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("social_recovery.completed", None));
 pub(crate) static AUTH_KEYS_ROTATED: Lazy<Counter<u64>> =
     Lazy::new(|| FACTORY.u64_counter("auth_keys_rotated", None));
+pub(crate) static RECOVERY_HISTORY_WRITE_FAILED: Lazy<Counter<u64>> =
+    Lazy::new(|| FACTORY.u64_counter("history.write_failed", None));
 
 // Gauges
 pub static DELAY_NOTIFY_PENDING: Lazy<ObservableGauge<u64>> =
@@ -35,3 +43,5 @@ pub(crate) static DELAY_NOTIFY_TIME_TO_CANCEL: Lazy<Histogram<u64>> =
     Lazy::new(|| FACTORY.u64_histogram("delay_notify.time_to_cancel", Some(Unit::new("min"))));
 pub(crate) static DELAY_NOTIFY_TIME_TO_COMPLETE: Lazy<Histogram<u64>> =
     Lazy::new(|| FACTORY.u64_histogram("delay_notify.time_to_complete", Some(Unit::new("min"))));
+pub(crate) static SOCIAL_RECOVERY_TIME_TO_COMPLETE: Lazy<Histogram<u64>> =
+    Lazy::new(|| FACTORY.u64_histogram("social_recovery.time_to_complete", Some(Unit::new("min"))));
@@ -1,12 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
 use ::metrics::KeyValue;
-use account::entities::Account;
+use account::entities::{Account, FullAccount};
 use axum::Extension;
 
 use axum::routing::{delete, put};
 use axum::{
     extract::{Path, State},
+    http::HeaderMap,
     routing::{get, post},
     Json, Router,
 };
@@ -15,9 +16,11 @@ use serde_json::Value;
 use time::serde::rfc3339;
 use time::{Duration, OffsetDateTime};
 use tracing::{event, instrument, Level};
+use types::recovery::history::{RecoveryHistoryAction, RecoveryHistoryEntry};
 use types::recovery::social::challenge::{
     SocialChallenge, SocialChallengeId, SocialChallengeResponse, TrustedContactChallengeRequest,
 };
+use types::recovery::social::policy::RecoveryPolicy;
 use types::recovery::social::relationship::{RecoveryRelationship, RecoveryRelationshipId};
 use utoipa::{OpenApi, ToSchema};
 
@@ -26,7 +29,7 @@ use account::{
     error::AccountError,
     service::{
         ClearPushTouchpointsInput, CreateAndRotateAuthKeysInput, FetchAccountInput,
-        Service as AccountService,
+        Service as AccountService, UpdateRecoveryDelayPeriodInput,
     },
 };
 use authn_authz::key_claims::KeyClaims;
@@ -39,27 +42,42 @@ use comms_verification::{
 use errors::{ApiError, ErrorCode};
 use feature_flags::service::Service as FeatureFlagsService;
 use http_server::swagger::{SwaggerEndpoint, Url};
-use notification::{entities::NotificationTouchpoint, service::Service as NotificationService};
+use notification::{
+    entities::NotificationTouchpoint,
+    payloads::recovery_delay_period_change_pending::RecoveryDelayPeriodChangePendingPayload,
+    schedule::ScheduleNotificationType,
+    service::{ScheduleNotificationsInput, Service as NotificationService},
+    NotificationPayloadBuilder,
+};
 use types::account::identifiers::{AccountId, TouchpointId};
 use wsm_rust_client::WsmClient;
 
 use crate::flags::FLAG_SOCIAL_RECOVERY_ENABLE;
+use crate::service::cancellation_token::{
+    verify_cancellation_token::VerifyCancellationTokenInput, Service as CancellationTokenService,
+};
 use crate::service::social::challenge::create_social_challenge::CreateSocialChallengeInput;
 use crate::service::social::challenge::fetch_social_challenge::{
     FetchSocialChallengeAsCustomerInput, FetchSocialChallengeAsTrustedContactInput,
 };
-use crate::service::social::challenge::respond_to_social_challenge::RespondToSocialChallengeInput;
+use crate::service::social::challenge::respond_to_social_challenge::{
+    RespondToSocialChallengeInput, RespondToSocialChallengeOutput,
+};
 use crate::service::social::relationship::accept_recovery_relationship_invitation::AcceptRecoveryRelationshipInvitationInput;
 use crate::service::social::relationship::create_recovery_relationship_invitation::CreateRecoveryRelationshipInvitationInput;
 use crate::service::social::relationship::delete_recovery_relationship::DeleteRecoveryRelationshipInput;
 use crate::service::social::relationship::endorse_recovery_relationships::EndorseRecoveryRelationshipsInput;
+use crate::service::social::relationship::get_recovery_policy::GetRecoveryPolicyInput;
 use crate::service::social::relationship::get_recovery_relationship_invitation_for_code::GetRecoveryRelationshipInvitationForCodeInput;
 use crate::service::social::relationship::get_recovery_relationships::GetRecoveryRelationshipsInput;
 use crate::service::social::relationship::reissue_recovery_relationship_invitation::ReissueRecoveryRelationshipInvitationInput;
+use crate::service::social::relationship::update_recovery_policy::UpdateRecoveryPolicyInput;
 use crate::{
     entities::{
-        DelayNotifyRecoveryAction, DelayNotifyRequirements, RecoveryAction, RecoveryDestination,
-        RecoveryRequirements, RecoveryType, ToActor, ToActorStrategy, WalletRecovery,
+        plan_recovery_delay_period_update, DelayNotifyRecoveryAction, DelayNotifyRequirements,
+        RecoveryAction, RecoveryDestination, RecoveryRequirements, RecoveryType,
+        RecoveryValuesPerAccountType, SocialRecoveryAction, SocialRecoveryRequirements, ToActor,
+        ToActorStrategy, WalletRecovery,
     },
     error::RecoveryError,
     metrics,
@@ -68,9 +86,15 @@ use crate::{
     service::social::relationship::Service as RecoveryRelationshipService,
     state_machine::{
         cancel_recovery::CanceledRecoveryState, pending_recovery::PendingRecoveryResponse,
-        run_recovery_fsm, PendingDelayNotifyRecovery, RecoveryEvent, RecoveryResponse,
+        run_recovery_fsm, PendingDelayNotifyRecovery, PendingSocialRecovery, RecoveryEvent,
+        RecoveryResponse, SocialRecoveryResponse,
     },
 };
+use crate::{
+    service::history::get_recovery_history::GetRecoveryHistoryInput,
+    service::history::record_recovery_history_entry::RecordRecoveryHistoryEntryInput,
+    service::history::Service as RecoveryHistoryService,
+};
 use types::recovery::social::relationship::RecoveryRelationshipEndorsement;
 
 #[derive(Clone, axum_macros::FromRef)]
@@ -84,6 +108,8 @@ pub struct RouteState(
     pub RecoveryRelationshipService,
     pub SocialChallengeService,
     pub FeatureFlagsService,
+    pub RecoveryHistoryService,
+    pub CancellationTokenService,
 );
 
 impl RouteState {
@@ -93,6 +119,14 @@ impl RouteState {
                 "/api/accounts/:account_id/delay-notify/complete",
                 post(complete_delay_notify_transaction),
             )
+            .route(
+                "/api/accounts/:account_id/social-recovery/complete",
+                post(complete_social_recovery),
+            )
+            .route(
+                "/api/accounts/:account_id/delay-notify/cancel-with-token",
+                post(cancel_delay_notify_with_token),
+            )
             .route_layer(metrics::FACTORY.route_layer("recovery".to_owned()))
             .with_state(self.to_owned())
     }
@@ -135,6 +169,38 @@ impl RouteState {
                 "/api/accounts/:account_id/recovery/relationships",
                 put(endorse_recovery_relationships),
             )
+            .route(
+                "/api/accounts/:account_id/social-recovery",
+                post(create_social_recovery),
+            )
+            .route(
+                "/api/accounts/:account_id/social-recovery",
+                get(get_social_recovery_status),
+            )
+            .route(
+                "/api/accounts/:account_id/social-recovery",
+                delete(cancel_social_recovery),
+            )
+            .route(
+                "/api/accounts/:account_id/recovery/policy",
+                get(get_recovery_policy),
+            )
+            .route(
+                "/api/accounts/:account_id/recovery/delay-period",
+                get(get_recovery_delay_period),
+            )
+            .route(
+                "/api/accounts/:account_id/recovery/delay-period",
+                put(update_recovery_delay_period),
+            )
+            .route(
+                "/api/accounts/:account_id/recovery/policy",
+                put(update_recovery_policy),
+            )
+            .route(
+                "/api/accounts/:account_id/recovery/history",
+                get(get_recovery_history),
+            )
             .route_layer(metrics::FACTORY.route_layer("recovery".to_owned()))
             .with_state(self.to_owned())
     }
@@ -197,19 +263,29 @@ impl From<RouteState> for SwaggerEndpoint {
 #[openapi(
     paths(
         cancel_delay_notify,
+        cancel_delay_notify_with_token,
+        cancel_social_recovery,
         complete_delay_notify_transaction,
+        complete_social_recovery,
         create_delay_notify,
         create_recovery_relationship,
+        create_social_recovery,
         delete_recovery_relationship,
         endorse_recovery_relationships,
         fetch_social_challenge,
+        get_recovery_delay_period,
+        get_recovery_history,
+        get_recovery_policy,
         get_recovery_relationship_invitation_for_code,
         get_recovery_relationships,
         get_recovery_status,
+        get_social_recovery_status,
         respond_to_social_challenge,
         rotate_authentication_keys,
         send_verification_code,
         start_social_challenge,
+        update_recovery_delay_period,
+        update_recovery_policy,
         update_recovery_relationship,
         verify_code,
         verify_social_challenge_code,
@@ -218,11 +294,13 @@ impl From<RouteState> for SwaggerEndpoint {
         schemas(
             AuthenticationKey,
             CanceledRecoveryState,
+            CancelDelayNotifyWithTokenRequest,
             CompleteDelayNotifyRequest,
             CompleteDelayNotifyResponse,
             CreateAccountDelayNotifyRequest,
             CreateRecoveryRelationshipRequest,
             CreateRecoveryRelationshipResponse,
+            CreateSocialRecoveryRequest,
             Customer,
             CustomerSocialChallenge,
             CustomerSocialChallengeResponse,
@@ -234,13 +312,19 @@ impl From<RouteState> for SwaggerEndpoint {
             Factor,
             FetchSocialChallengeResponse,
             FullAccountAuthKeysPayload,
+            GetRecoveryHistoryResponse,
             GetRecoveryRelationshipInvitationForCodeResponse,
             GetRecoveryRelationshipsResponse,
             InboundInvitation,
             OutboundInvitation,
             PendingDelayNotifyRecovery,
             PendingRecoveryResponse,
+            PendingSocialRecovery,
             RecoveryAction,
+            RecoveryDelayPeriodResponse,
+            RecoveryHistoryAction,
+            RecoveryHistoryEntryResponse,
+            RecoveryPolicyResponse,
             RecoveryRequirements,
             RecoveryResponse,
             RecoveryRelationshipEndorsement,
@@ -251,12 +335,17 @@ impl From<RouteState> for SwaggerEndpoint {
             RotateAuthenticationKeysResponse,
             SendAccountVerificationCodeRequest,
             SendAccountVerificationCodeResponse,
+            SocialRecoveryAction,
+            SocialRecoveryRequirements,
+            SocialRecoveryResponse,
             StartChallengeTrustedContactRequest,
             StartSocialChallengeRequest,
             StartSocialChallengeResponse,
             TrustedContact,
             TrustedContactSocialChallenge,
             UnendorsedTrustedContact,
+            UpdateRecoveryDelayPeriodRequest,
+            UpdateRecoveryPolicyRequest,
             UpdateRecoveryRelationshipRequest,
             UpdateRecoveryRelationshipResponse,
             VerifyAccountVerificationCodeRequest,
@@ -286,7 +375,10 @@ pub struct CreateAccountDelayNotifyRequest {
         account_service,
         notification_service,
         recovery_service,
-        comms_verification_service
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service
     )
 )]
 #[utoipa::path(
@@ -301,12 +393,16 @@ pub struct CreateAccountDelayNotifyRequest {
         (status = 404, description = "Account not found")
     ),
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn create_delay_notify(
     Path(account_id): Path<AccountId>,
     State(account_service): State<AccountService>,
     State(notification_service): State<NotificationService>,
     State(recovery_service): State<RecoveryRepository>,
     State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
     key_proof: KeyClaims,
     Json(request): Json<CreateAccountDelayNotifyRequest>,
 ) -> Result<Json<Value>, ApiError> {
@@ -338,6 +434,9 @@ pub async fn create_delay_notify(
         &recovery_service,
         &notification_service,
         &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
     )
     .await
     .map(|r| Json(r.response()))?;
@@ -352,6 +451,9 @@ pub async fn create_delay_notify(
             notification_service,
             recovery_service,
             comms_verification_service,
+            social_challenge_service,
+            recovery_history_service,
+            cancellation_token_service,
             UpdateDelayForTestRecoveryRequest {
                 delay_period_num_sec: request.delay_period_num_sec,
             },
@@ -368,12 +470,16 @@ pub struct UpdateDelayForTestRecoveryRequest {
     pub delay_period_num_sec: Option<i64>, // TODO: [W-774] Update visibility of struct after migration
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn update_recovery_delay_for_test_account(
     account_id: AccountId,
     account_service: AccountService,
     notification_service: NotificationService,
     recovery_service: RecoveryRepository,
     comms_verification_service: CommsVerificationService,
+    social_challenge_service: SocialChallengeService,
+    recovery_history_service: RecoveryHistoryService,
+    cancellation_token_service: CancellationTokenService,
     request: UpdateDelayForTestRecoveryRequest,
 ) -> Result<Json<Value>, ApiError> {
     let events = vec![
@@ -389,6 +495,9 @@ async fn update_recovery_delay_for_test_account(
         &recovery_service,
         &notification_service,
         &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
     )
     .await
     .map(|r| Json(r.response()))
@@ -400,7 +509,10 @@ async fn update_recovery_delay_for_test_account(
         account_service,
         notification_service,
         recovery_service,
-        comms_verification_service
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service
     )
 )]
 #[utoipa::path(
@@ -416,12 +528,16 @@ async fn update_recovery_delay_for_test_account(
         (status = 404, description = "Account not found")
     ),
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn update_delay_for_test_account(
     Path(account_id): Path<AccountId>,
     State(account_service): State<AccountService>,
     State(notification_service): State<NotificationService>,
     State(recovery_service): State<RecoveryRepository>,
     State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
     key_proof: KeyClaims,
     Json(request): Json<UpdateDelayForTestRecoveryRequest>,
 ) -> Result<Json<Value>, ApiError> {
@@ -431,6 +547,9 @@ pub async fn update_delay_for_test_account(
         notification_service,
         recovery_service,
         comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service,
         request,
     )
     .await
@@ -442,7 +561,10 @@ pub async fn update_delay_for_test_account(
         account_service,
         notification_service,
         recovery_service,
-        comms_verification_service
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service
     )
 )]
 #[utoipa::path(
@@ -456,12 +578,16 @@ pub async fn update_delay_for_test_account(
         (status = 404, description = "Account not found")
     ),
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn cancel_delay_notify(
     Path(account_id): Path<AccountId>,
     State(account_service): State<AccountService>,
     State(notification_service): State<NotificationService>,
     State(recovery_service): State<RecoveryRepository>,
     State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
     key_proof: KeyClaims,
 ) -> Result<(), ApiError> {
     let events = vec![
@@ -475,6 +601,110 @@ pub async fn cancel_delay_notify(
         &recovery_service,
         &notification_service,
         &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CancelDelayNotifyWithTokenRequest {
+    pub token: String,
+}
+
+#[instrument(
+    err,
+    skip(
+        account_service,
+        notification_service,
+        recovery_service,
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service,
+        headers,
+        request
+    )
+)]
+#[utoipa::path(
+    post,
+    path = "/api/accounts/{account_id}/delay-notify/cancel-with-token",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    request_body = CancelDelayNotifyWithTokenRequest,
+    responses(
+        (status = 200, description = "D&N Recovery was canceled"),
+        (status = 400, description = "Token was malformed"),
+        (status = 403, description = "Token was invalid, expired, or not for the pending recovery"),
+        (status = 429, description = "Too many failed cancellation attempts from the caller")
+    ),
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn cancel_delay_notify_with_token(
+    Path(account_id): Path<AccountId>,
+    State(account_service): State<AccountService>,
+    State(notification_service): State<NotificationService>,
+    State(recovery_service): State<RecoveryRepository>,
+    State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
+    headers: HeaderMap,
+    Json(request): Json<CancelDelayNotifyWithTokenRequest>,
+) -> Result<(), ApiError> {
+    // The load balancer appends the address it saw to X-Forwarded-For, so only the last entry
+    // can't be spoofed by the caller
+    let source = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .last()
+        .map(str::trim)
+        .unwrap_or("unknown");
+    let recovery = recovery_service
+        .fetch_pending(&account_id, RecoveryType::DelayAndNotify)
+        .await
+        .map_err(RecoveryError::from)?;
+    cancellation_token_service
+        .verify_cancellation_token(VerifyCancellationTokenInput {
+            recovery: recovery.as_ref(),
+            token: &request.token,
+            source,
+            now: recovery_service.cur_time(),
+        })
+        .await
+        .map_err(|e| {
+            event!(
+                Level::WARN,
+                "Rejected recovery cancellation token for account {account_id}: {e}"
+            );
+            RecoveryError::from(e)
+        })?;
+
+    let events = vec![
+        RecoveryEvent::CheckAccountRecoveryState,
+        RecoveryEvent::CancelRecoveryWithToken {
+            recovery_created_at: recovery
+                .map(|r| r.created_at)
+                .ok_or(RecoveryError::NoExistingRecovery)?,
+        },
+    ];
+    run_recovery_fsm(
+        account_id,
+        events,
+        &account_service,
+        &recovery_service,
+        &notification_service,
+        &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
     )
     .await?;
 
@@ -487,7 +717,10 @@ pub async fn cancel_delay_notify(
         account_service,
         notification_service,
         recovery_service,
-        comms_verification_service
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service
     )
 )]
 #[utoipa::path(
@@ -501,12 +734,16 @@ pub async fn cancel_delay_notify(
         (status = 404, description = "Account or D&N recovery not found")
     ),
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn get_recovery_status(
     Path(account_id): Path<AccountId>,
     State(account_service): State<AccountService>,
     State(notification_service): State<NotificationService>,
     State(recovery_service): State<RecoveryRepository>,
     State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
 ) -> Result<Json<Value>, ApiError> {
     let events = vec![RecoveryEvent::CheckAccountRecoveryState];
     run_recovery_fsm(
@@ -516,6 +753,9 @@ pub async fn get_recovery_status(
         &recovery_service,
         &notification_service,
         &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
     )
     .await
     .map(|r| Json(r.response()))
@@ -539,6 +779,9 @@ pub struct CompleteDelayNotifyResponse {}
         notification_service,
         user_pool_service,
         comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service,
     )
 )]
 #[utoipa::path(
@@ -554,12 +797,16 @@ pub struct CompleteDelayNotifyResponse {}
         (status = 404, description = "Account not found, D&N recovery not found or D&N recovery still pending.")
     ),
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn complete_delay_notify_transaction(
     Path(account_id): Path<AccountId>,
     State(notification_service): State<NotificationService>,
     State(account_service): State<AccountService>,
     State(recovery_service): State<RecoveryRepository>,
     State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
     State(user_pool_service): State<UserPoolService>,
     Json(request): Json<CompleteDelayNotifyRequest>,
 ) -> Result<Json<Value>, ApiError> {
@@ -579,6 +826,9 @@ pub async fn complete_delay_notify_transaction(
         &recovery_service,
         &notification_service,
         &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
     )
     .await
     .map(|r| Json(r.response()))
@@ -594,7 +844,10 @@ pub struct SendAccountVerificationCodeRequest {
 #[serde(rename_all = "snake_case")]
 pub struct SendAccountVerificationCodeResponse {}
 
-#[instrument(err, skip(account_service, comms_verification_service))]
+#[instrument(
+    err,
+    skip(account_service, comms_verification_service, recovery_history_service)
+)]
 #[utoipa::path(
     post,
     path = "/api/accounts/{account_id}/delay-notify/send-verification-code",
@@ -611,6 +864,7 @@ pub async fn send_verification_code(
     Path(account_id): Path<AccountId>,
     State(account_service): State<AccountService>,
     State(comms_verification_service): State<CommsVerificationService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
     key_proof: KeyClaims,
     Json(request): Json<SendAccountVerificationCodeRequest>,
 ) -> Result<Json<SendAccountVerificationCodeResponse>, ApiError> {
@@ -649,6 +903,16 @@ pub async fn send_verification_code(
         })
         .await?;
 
+    recovery_history_service
+        .record_recovery_history_entry(RecordRecoveryHistoryEntryInput {
+            account_id: &account_id,
+            action: RecoveryHistoryAction::CommsVerificationSent,
+            actor_account_id: &account_id,
+            app_signed: key_proof.app_signed,
+            hw_signed: key_proof.hw_signed,
+        })
+        .await;
+
     Ok(Json(SendAccountVerificationCodeResponse {}))
 }
 
@@ -662,7 +926,7 @@ pub struct VerifyAccountVerificationCodeRequest {
 #[serde(rename_all = "snake_case")]
 pub struct VerifyAccountVerificationCodeResponse {}
 
-#[instrument(err, skip(comms_verification_service))]
+#[instrument(err, skip(comms_verification_service, recovery_history_service))]
 #[utoipa::path(
     post,
     path = "/api/accounts/{account_id}/delay-notify/verify-code",
@@ -678,6 +942,7 @@ pub struct VerifyAccountVerificationCodeResponse {}
 pub async fn verify_code(
     Path(account_id): Path<AccountId>,
     State(comms_verification_service): State<CommsVerificationService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
     key_proof: KeyClaims,
     Json(request): Json<VerifyAccountVerificationCodeRequest>,
 ) -> Result<Json<VerifyAccountVerificationCodeResponse>, ApiError> {
@@ -686,7 +951,7 @@ pub async fn verify_code(
 
     comms_verification_service
         .verify_for_scope(VerifyForScopeInput {
-            account_id,
+            account_id: account_id.clone(),
             scope,
             code: request.verification_code,
             duration: Duration::minutes(10),
@@ -702,6 +967,16 @@ pub async fn verify_code(
 
     metrics::DELAY_NOTIFY_CODE_SUBMITTED.add(1, &[KeyValue::new(metrics::CODE_MATCHED_KEY, true)]);
 
+    recovery_history_service
+        .record_recovery_history_entry(RecordRecoveryHistoryEntryInput {
+            account_id: &account_id,
+            action: RecoveryHistoryAction::CommsVerificationVerified,
+            actor_account_id: &account_id,
+            app_signed: key_proof.app_signed,
+            hw_signed: key_proof.hw_signed,
+        })
+        .await;
+
     Ok(Json(VerifyAccountVerificationCodeResponse {}))
 }
 
@@ -729,6 +1004,9 @@ pub struct RotateAuthenticationKeysResponse {}
         recovery_service,
         comms_verification_service,
         user_pool_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service,
     )
 )]
 #[utoipa::path(
@@ -744,12 +1022,16 @@ pub struct RotateAuthenticationKeysResponse {}
         (status = 404, description = "Account not found.")
     ),
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn rotate_authentication_keys(
     Path(account_id): Path<AccountId>,
     State(notification_service): State<NotificationService>,
     State(account_service): State<AccountService>,
     State(recovery_service): State<RecoveryRepository>,
     State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
     State(user_pool_service): State<UserPoolService>,
     key_proof: KeyClaims,
     Json(request): Json<RotateAuthenticationKeysRequest>,
@@ -818,24 +1100,34 @@ pub async fn rotate_authentication_keys(
         ));
     }
 
-    // Cancel D+N if exists
-    let events = vec![
+    // Cancel D+N or social recovery if exists
+    for check_state in [
         RecoveryEvent::CheckAccountRecoveryState,
-        RecoveryEvent::CancelRecovery { key_proof },
-    ];
-    if let Err(e) = run_recovery_fsm(
-        account_id.clone(),
-        events,
-        &account_service,
-        &recovery_service,
-        &notification_service,
-        &comms_verification_service,
-    )
-    .await
-    {
-        if !matches!(e.clone(), ApiError::Specific{code, ..} if code == ErrorCode::NoRecoveryExists)
+        RecoveryEvent::CheckSocialRecoveryState,
+    ] {
+        let events = vec![
+            check_state,
+            RecoveryEvent::CancelRecovery {
+                key_proof: key_proof.clone(),
+            },
+        ];
+        if let Err(e) = run_recovery_fsm(
+            account_id.clone(),
+            events,
+            &account_service,
+            &recovery_service,
+            &notification_service,
+            &comms_verification_service,
+            &social_challenge_service,
+            &recovery_history_service,
+            &cancellation_token_service,
+        )
+        .await
         {
-            return Err(e);
+            if !matches!(e.clone(), ApiError::Specific{code, ..} if code == ErrorCode::NoRecoveryExists)
+            {
+                return Err(e);
+            }
         }
     }
 
@@ -872,6 +1164,16 @@ pub async fn rotate_authentication_keys(
         })
         .await?;
 
+    recovery_history_service
+        .record_recovery_history_entry(RecordRecoveryHistoryEntryInput {
+            account_id: &account_id,
+            action: RecoveryHistoryAction::AuthKeysRotated,
+            actor_account_id: &account_id,
+            app_signed: key_proof.app_signed,
+            hw_signed: key_proof.hw_signed,
+        })
+        .await;
+
     metrics::AUTH_KEYS_ROTATED.add(1, &[]);
 
     Ok(Json(RotateAuthenticationKeysResponse {}))
@@ -1770,7 +2072,19 @@ pub struct RespondToSocialChallengeResponse {}
 /// and to provide the shared secret that the Customer will use to recover
 /// their account.
 ///
-#[instrument(err, skip(social_challenge_service, feature_flags_service))]
+#[instrument(
+    err,
+    skip(
+        account_service,
+        notification_service,
+        recovery_service,
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service,
+        feature_flags_service
+    )
+)]
 #[utoipa::path(
     put,
     path = "/api/accounts/{account_id}/recovery/social-challenges/{social_challenge_id}",
@@ -1783,10 +2097,18 @@ pub struct RespondToSocialChallengeResponse {}
         (status = 200, description = "Responded to social challenge", body=RespondToSocialChallengeResponse),
     ),
 )]
+#[allow(clippy::too_many_arguments)]
 pub async fn respond_to_social_challenge(
     Path((account_id, social_challenge_id)): Path<(AccountId, SocialChallengeId)>,
+    State(account_service): State<AccountService>,
+    State(notification_service): State<NotificationService>,
+    State(recovery_service): State<RecoveryRepository>,
+    State(comms_verification_service): State<CommsVerificationService>,
     State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
     State(feature_flags_service): State<FeatureFlagsService>,
+    key_proof: KeyClaims,
     Json(request): Json<RespondToSocialChallengeRequest>,
 ) -> Result<Json<RespondToSocialChallengeResponse>, ApiError> {
     if !FLAG_SOCIAL_RECOVERY_ENABLE
@@ -1798,7 +2120,10 @@ pub async fn respond_to_social_challenge(
         ));
     }
 
-    social_challenge_service
+    let RespondToSocialChallengeOutput {
+        challenge,
+        first_response,
+    } = social_challenge_service
         .respond_to_social_challenge(RespondToSocialChallengeInput {
             trusted_contact_account_id: &account_id,
             social_challenge_id: &social_challenge_id,
@@ -1809,6 +2134,42 @@ pub async fn respond_to_social_challenge(
         })
         .await?;
 
+    recovery_history_service
+        .record_recovery_history_entry(RecordRecoveryHistoryEntryInput {
+            account_id: &challenge.customer_account_id,
+            action: RecoveryHistoryAction::SocialChallengeResponded,
+            actor_account_id: &account_id,
+            app_signed: key_proof.app_signed,
+            hw_signed: key_proof.hw_signed,
+        })
+        .await;
+
+    // Advance the customer's social recovery, if any. The response is already recorded, so a
+    //   failure here is logged rather than failing the Trusted Contact's request.
+    let events = vec![
+        RecoveryEvent::CheckSocialRecoveryState,
+        RecoveryEvent::SocialChallengeResponded { first_response },
+    ];
+    if let Err(err) = run_recovery_fsm(
+        challenge.customer_account_id.clone(),
+        events,
+        &account_service,
+        &recovery_service,
+        &notification_service,
+        &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
+    )
+    .await
+    {
+        event!(
+            Level::ERROR,
+            "Could not advance social recovery for account {} after social challenge response: {err:?}",
+            challenge.customer_account_id
+        );
+    }
+
     Ok(Json(RespondToSocialChallengeResponse {}))
 }
 
@@ -1871,3 +2232,658 @@ pub async fn fetch_social_challenge(
         social_challenge: result.into(),
     }))
 }
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CreateSocialRecoveryRequest {
+    pub social_challenge_id: SocialChallengeId,
+    pub auth: FullAccountAuthKeysPayload,
+}
+
+///
+/// Used by the Customer to start a Social recovery of a lost App, backed by
+/// a previously started Social challenge.
+///
+/// The request must be signed by the Hardware.
+///
+#[instrument(
+    err,
+    skip(
+        account_service,
+        notification_service,
+        recovery_service,
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service,
+        feature_flags_service
+    )
+)]
+#[utoipa::path(
+    post,
+    path = "/api/accounts/{account_id}/social-recovery",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    request_body = CreateSocialRecoveryRequest,
+    responses(
+        (status = 200, description = "Social recovery was created", body=SocialRecoveryResponse),
+        (status = 404, description = "Account or social challenge not found")
+    ),
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn create_social_recovery(
+    Path(account_id): Path<AccountId>,
+    State(account_service): State<AccountService>,
+    State(notification_service): State<NotificationService>,
+    State(recovery_service): State<RecoveryRepository>,
+    State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
+    State(feature_flags_service): State<FeatureFlagsService>,
+    key_proof: KeyClaims,
+    Json(request): Json<CreateSocialRecoveryRequest>,
+) -> Result<Json<Value>, ApiError> {
+    if !FLAG_SOCIAL_RECOVERY_ENABLE
+        .resolver(&feature_flags_service)
+        .resolve()
+    {
+        return Err(ApiError::GenericForbidden(
+            "Feature not enabled".to_string(),
+        ));
+    }
+
+    let full_account = account_service
+        .fetch_full_account(FetchAccountInput {
+            account_id: &account_id,
+        })
+        .await?;
+
+    let destination = RecoveryDestination {
+        source_auth_keys_id: full_account.common_fields.active_auth_keys_id.to_owned(),
+        app_auth_pubkey: request.auth.app,
+        hardware_auth_pubkey: request.auth.hardware,
+        recovery_auth_pubkey: request.auth.recovery,
+    };
+    let events = vec![
+        RecoveryEvent::CheckSocialRecoveryState,
+        RecoveryEvent::CreateSocialRecovery {
+            account: full_account,
+            destination,
+            social_challenge_id: request.social_challenge_id,
+            key_proof,
+        },
+    ];
+    run_recovery_fsm(
+        account_id,
+        events,
+        &account_service,
+        &recovery_service,
+        &notification_service,
+        &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
+    )
+    .await
+    .map(|r| Json(r.response()))
+}
+
+#[instrument(
+    err,
+    skip(
+        account_service,
+        notification_service,
+        recovery_service,
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service,
+        feature_flags_service
+    )
+)]
+#[utoipa::path(
+    get,
+    path = "/api/accounts/{account_id}/social-recovery",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    responses(
+        (status = 200, description = "Social recovery fetched status", body=SocialRecoveryResponse),
+        (status = 404, description = "Account not found")
+    ),
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn get_social_recovery_status(
+    Path(account_id): Path<AccountId>,
+    State(account_service): State<AccountService>,
+    State(notification_service): State<NotificationService>,
+    State(recovery_service): State<RecoveryRepository>,
+    State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
+    State(feature_flags_service): State<FeatureFlagsService>,
+) -> Result<Json<Value>, ApiError> {
+    if !FLAG_SOCIAL_RECOVERY_ENABLE
+        .resolver(&feature_flags_service)
+        .resolve()
+    {
+        return Err(ApiError::GenericForbidden(
+            "Feature not enabled".to_string(),
+        ));
+    }
+
+    let events = vec![RecoveryEvent::CheckSocialRecoveryState];
+    run_recovery_fsm(
+        account_id,
+        events,
+        &account_service,
+        &recovery_service,
+        &notification_service,
+        &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
+    )
+    .await
+    .map(|r| Json(r.response()))
+}
+
+#[instrument(
+    err,
+    skip(
+        account_service,
+        notification_service,
+        recovery_service,
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service,
+        feature_flags_service
+    )
+)]
+#[utoipa::path(
+    delete,
+    path = "/api/accounts/{account_id}/social-recovery",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    responses(
+        (status = 200, description = "Social recovery was canceled"),
+        (status = 404, description = "Account not found")
+    ),
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn cancel_social_recovery(
+    Path(account_id): Path<AccountId>,
+    State(account_service): State<AccountService>,
+    State(notification_service): State<NotificationService>,
+    State(recovery_service): State<RecoveryRepository>,
+    State(comms_verification_service): State<CommsVerificationService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
+    State(feature_flags_service): State<FeatureFlagsService>,
+    key_proof: KeyClaims,
+) -> Result<(), ApiError> {
+    if !FLAG_SOCIAL_RECOVERY_ENABLE
+        .resolver(&feature_flags_service)
+        .resolve()
+    {
+        return Err(ApiError::GenericForbidden(
+            "Feature not enabled".to_string(),
+        ));
+    }
+
+    let events = vec![
+        RecoveryEvent::CheckSocialRecoveryState,
+        RecoveryEvent::CancelRecovery { key_proof },
+    ];
+    run_recovery_fsm(
+        account_id,
+        events,
+        &account_service,
+        &recovery_service,
+        &notification_service,
+        &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[instrument(
+    err,
+    skip(
+        account_service,
+        recovery_service,
+        notification_service,
+        user_pool_service,
+        comms_verification_service,
+        social_challenge_service,
+        recovery_history_service,
+        cancellation_token_service,
+        feature_flags_service,
+    )
+)]
+#[utoipa::path(
+    post,
+    path = "/api/accounts/{account_id}/social-recovery/complete",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    request_body = CompleteDelayNotifyRequest,
+    responses(
+        (status = 200, description = "Social recovery was completed", body=CompleteDelayNotifyResponse),
+        (status = 400, description = "Not enough Trusted Contacts have responded or signatures are invalid."),
+        (status = 404, description = "Account or social recovery not found.")
+    ),
+)]
+#[allow(clippy::too_many_arguments)]
+pub async fn complete_social_recovery(
+    Path(account_id): Path<AccountId>,
+    State(notification_service): State<NotificationService>,
+    State(account_service): State<AccountService>,
+    State(recovery_service): State<RecoveryRepository>,
+    State(comms_verification_service): State<CommsVerificationService>,
+    State(user_pool_service): State<UserPoolService>,
+    State(social_challenge_service): State<SocialChallengeService>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+    State(cancellation_token_service): State<CancellationTokenService>,
+    State(feature_flags_service): State<FeatureFlagsService>,
+    Json(request): Json<CompleteDelayNotifyRequest>,
+) -> Result<Json<Value>, ApiError> {
+    if !FLAG_SOCIAL_RECOVERY_ENABLE
+        .resolver(&feature_flags_service)
+        .resolve()
+    {
+        return Err(ApiError::GenericForbidden(
+            "Feature not enabled".to_string(),
+        ));
+    }
+
+    let events = vec![
+        RecoveryEvent::CheckSocialRecoveryState,
+        RecoveryEvent::CheckSocialChallengeThreshold,
+        RecoveryEvent::CheckEligibleForCompletion {
+            challenge: request.challenge,
+            app_signature: request.app_signature,
+            hardware_signature: request.hardware_signature,
+        },
+        RecoveryEvent::RotateKeyset { user_pool_service },
+    ];
+    run_recovery_fsm(
+        account_id,
+        events,
+        &account_service,
+        &recovery_service,
+        &notification_service,
+        &comms_verification_service,
+        &social_challenge_service,
+        &recovery_history_service,
+        &cancellation_token_service,
+    )
+    .await
+    .map(|r| Json(r.response()))
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateRecoveryPolicyRequest {
+    pub threshold: u32,
+    #[serde(default)]
+    pub weights: HashMap<RecoveryRelationshipId, u32>,
+    #[serde(default)]
+    pub min_relationship_age_secs: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RecoveryPolicyResponse {
+    pub threshold: u32,
+    pub weights: HashMap<RecoveryRelationshipId, u32>,
+    pub min_relationship_age_secs: i64,
+}
+
+impl From<RecoveryPolicy> for RecoveryPolicyResponse {
+    fn from(value: RecoveryPolicy) -> Self {
+        Self {
+            threshold: value.threshold,
+            weights: value.weights,
+            min_relationship_age_secs: value.min_relationship_age_secs,
+        }
+    }
+}
+
+///
+/// This route is used by Full Accounts to fetch the policy that decides how many
+/// Trusted Contacts must respond to a social challenge
+///
+#[instrument(err, skip(recovery_relationship_service, feature_flags_service))]
+#[utoipa::path(
+    get,
+    path = "/api/accounts/{account_id}/recovery/policy",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    responses(
+        (status = 200, description = "Recovery policy fetched", body=RecoveryPolicyResponse),
+    ),
+)]
+pub async fn get_recovery_policy(
+    Path(account_id): Path<AccountId>,
+    State(recovery_relationship_service): State<RecoveryRelationshipService>,
+    State(feature_flags_service): State<FeatureFlagsService>,
+) -> Result<Json<RecoveryPolicyResponse>, ApiError> {
+    if !FLAG_SOCIAL_RECOVERY_ENABLE
+        .resolver(&feature_flags_service)
+        .resolve()
+    {
+        return Err(ApiError::GenericForbidden(
+            "Feature not enabled".to_string(),
+        ));
+    }
+
+    let policy = recovery_relationship_service
+        .get_recovery_policy(GetRecoveryPolicyInput {
+            customer_account_id: &account_id,
+        })
+        .await?;
+    Ok(Json(policy.into()))
+}
+
+///
+/// This route is used by Full Accounts to set their k-of-n recovery policy.
+///
+/// Customers will need to provide:
+/// - Account access token
+/// - Hardware keyproof
+///
+#[instrument(
+    err,
+    skip(
+        account_service,
+        recovery_service,
+        recovery_relationship_service,
+        feature_flags_service
+    )
+)]
+#[utoipa::path(
+    put,
+    path = "/api/accounts/{account_id}/recovery/policy",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    request_body = UpdateRecoveryPolicyRequest,
+    responses(
+        (status = 200, description = "Recovery policy updated", body=RecoveryPolicyResponse),
+    ),
+)]
+pub async fn update_recovery_policy(
+    Path(account_id): Path<AccountId>,
+    State(account_service): State<AccountService>,
+    State(recovery_service): State<RecoveryRepository>,
+    State(recovery_relationship_service): State<RecoveryRelationshipService>,
+    State(feature_flags_service): State<FeatureFlagsService>,
+    key_proof: KeyClaims,
+    Json(request): Json<UpdateRecoveryPolicyRequest>,
+) -> Result<Json<RecoveryPolicyResponse>, ApiError> {
+    if !key_proof.hw_signed {
+        event!(
+            Level::WARN,
+            "valid signature over access token required by hw auth key"
+        );
+        return Err(ApiError::GenericBadRequest(
+            "valid signature over access token required by hw auth key".to_string(),
+        ));
+    }
+
+    if !FLAG_SOCIAL_RECOVERY_ENABLE
+        .resolver(&feature_flags_service)
+        .resolve()
+    {
+        return Err(ApiError::GenericForbidden(
+            "Feature not enabled".to_string(),
+        ));
+    }
+
+    let Account::Full(_) = account_service
+        .fetch_account(FetchAccountInput {
+            account_id: &account_id,
+        })
+        .await?
+    else {
+        return Err(ApiError::GenericForbidden(
+            "Incorrect calling account type".to_string(),
+        ));
+    };
+
+    // The policy a pending social recovery was started under can't be changed out from under it
+    if recovery_service
+        .has_pending_recovery(&account_id, RecoveryType::SocialRecovery)
+        .await?
+    {
+        return Err(ApiError::GenericConflict(
+            "Recovery policy cannot change during a social recovery".to_string(),
+        ));
+    }
+
+    let policy = recovery_relationship_service
+        .update_recovery_policy(UpdateRecoveryPolicyInput {
+            customer_account_id: &account_id,
+            threshold: request.threshold,
+            weights: request.weights,
+            min_relationship_age_secs: request.min_relationship_age_secs,
+        })
+        .await?;
+    Ok(Json(policy.into()))
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UpdateRecoveryDelayPeriodRequest {
+    pub delay_period_num_sec: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RecoveryDelayPeriodResponse {
+    pub delay_period_num_sec: i64,
+    pub min_delay_period_num_sec: i64,
+    pub max_delay_period_num_sec: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_delay_period_num_sec: Option<i64>,
+    #[serde(default, with = "rfc3339::option")]
+    pub pending_effective_at: Option<OffsetDateTime>,
+}
+
+impl RecoveryDelayPeriodResponse {
+    fn new(account: &FullAccount, now: OffsetDateTime) -> Self {
+        let (min, max) = account.recovery_delay_period_bounds();
+        // A shortening that's already taken effect is reflected in the current delay
+        let pending = account
+            .pending_recovery_delay_period
+            .as_ref()
+            .filter(|p| now < p.effective_at);
+        Self {
+            delay_period_num_sec: account.recovery_delay_period(now).whole_seconds(),
+            min_delay_period_num_sec: min.whole_seconds(),
+            max_delay_period_num_sec: max.whole_seconds(),
+            pending_delay_period_num_sec: pending.map(|p| p.delay_period_num_sec),
+            pending_effective_at: pending.map(|p| p.effective_at),
+        }
+    }
+}
+
+#[instrument(err, skip(account_service, recovery_service))]
+#[utoipa::path(
+    get,
+    path = "/api/accounts/{account_id}/recovery/delay-period",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    responses(
+        (status = 200, description = "Recovery delay period fetched", body=RecoveryDelayPeriodResponse),
+        (status = 404, description = "Account not found")
+    ),
+)]
+pub async fn get_recovery_delay_period(
+    Path(account_id): Path<AccountId>,
+    State(account_service): State<AccountService>,
+    State(recovery_service): State<RecoveryRepository>,
+) -> Result<Json<RecoveryDelayPeriodResponse>, ApiError> {
+    let account = account_service
+        .fetch_full_account(FetchAccountInput {
+            account_id: &account_id,
+        })
+        .await?;
+    Ok(Json(RecoveryDelayPeriodResponse::new(
+        &account,
+        recovery_service.cur_time(),
+    )))
+}
+
+///
+/// This route is used by Full Accounts to choose their Delay & Notify period.
+/// Longer delays take effect immediately; shorter ones only take effect once the
+/// current delay has passed, and the customer is notified in the meantime.
+///
+/// Customers will need to provide:
+/// - Account access token
+/// - Both App and Hardware keyproofs
+///
+#[instrument(err, skip(account_service, recovery_service, notification_service))]
+#[utoipa::path(
+    put,
+    path = "/api/accounts/{account_id}/recovery/delay-period",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    request_body = UpdateRecoveryDelayPeriodRequest,
+    responses(
+        (status = 200, description = "Recovery delay period updated", body=RecoveryDelayPeriodResponse),
+        (status = 400, description = "Delay period is out of bounds"),
+        (status = 404, description = "Account not found")
+    ),
+)]
+pub async fn update_recovery_delay_period(
+    Path(account_id): Path<AccountId>,
+    State(account_service): State<AccountService>,
+    State(recovery_service): State<RecoveryRepository>,
+    State(notification_service): State<NotificationService>,
+    key_proof: KeyClaims,
+    Json(request): Json<UpdateRecoveryDelayPeriodRequest>,
+) -> Result<Json<RecoveryDelayPeriodResponse>, ApiError> {
+    if !(key_proof.hw_signed && key_proof.app_signed) {
+        event!(
+            Level::WARN,
+            "valid signature over access token required by both app and hw auth keys"
+        );
+        return Err(ApiError::GenericBadRequest(
+            "valid signature over access token required by both app and hw auth keys".to_string(),
+        ));
+    }
+
+    let account = account_service
+        .fetch_full_account(FetchAccountInput {
+            account_id: &account_id,
+        })
+        .await?;
+
+    let now = recovery_service.cur_time();
+    let update = plan_recovery_delay_period_update(
+        &account,
+        Duration::seconds(request.delay_period_num_sec),
+        now,
+    )?;
+    let updated_account = account_service
+        .update_recovery_delay_period(UpdateRecoveryDelayPeriodInput {
+            account_id: &account_id,
+            recovery_delay_period_num_sec: update.recovery_delay_period_num_sec,
+            pending_recovery_delay_period: update.pending_recovery_delay_period.clone(),
+        })
+        .await?;
+
+    if let Some(pending) = update.pending_recovery_delay_period {
+        let payload = NotificationPayloadBuilder::default()
+            .recovery_delay_period_change_pending_payload(Some(
+                RecoveryDelayPeriodChangePendingPayload {
+                    delay_period_num_sec: pending.delay_period_num_sec,
+                    effective_at: pending.effective_at,
+                },
+            ))
+            .build()
+            .map_err(|_| RecoveryError::GenerateNotificationPayloadError)?;
+        notification_service
+            .schedule_notifications(ScheduleNotificationsInput {
+                account_id: account_id.clone(),
+                notification_type: ScheduleNotificationType::RecoveryDelayPeriodChangePending(
+                    pending.effective_at,
+                ),
+                payload,
+            })
+            .await?;
+    }
+
+    Ok(Json(RecoveryDelayPeriodResponse::new(
+        &updated_account,
+        now,
+    )))
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, PartialEq)]
+pub struct RecoveryHistoryEntryResponse {
+    pub action: RecoveryHistoryAction,
+    pub actor_account_id: AccountId,
+    pub app_signed: bool,
+    pub hw_signed: bool,
+    #[serde(with = "rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl From<RecoveryHistoryEntry> for RecoveryHistoryEntryResponse {
+    fn from(value: RecoveryHistoryEntry) -> Self {
+        Self {
+            action: value.action,
+            actor_account_id: value.actor_account_id,
+            app_signed: value.app_signed,
+            hw_signed: value.hw_signed,
+            created_at: value.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GetRecoveryHistoryResponse {
+    pub entries: Vec<RecoveryHistoryEntryResponse>,
+}
+
+///
+/// This route is used by Full Accounts and support tooling to review what has happened
+/// to an account's recoveries, oldest first
+///
+#[instrument(err, skip(recovery_history_service))]
+#[utoipa::path(
+    get,
+    path = "/api/accounts/{account_id}/recovery/history",
+    params(
+        ("account_id" = AccountId, Path, description = "AccountId"),
+    ),
+    responses(
+        (status = 200, description = "Recovery history fetched", body=GetRecoveryHistoryResponse),
+    ),
+)]
+pub async fn get_recovery_history(
+    Path(account_id): Path<AccountId>,
+    State(recovery_history_service): State<RecoveryHistoryService>,
+) -> Result<Json<GetRecoveryHistoryResponse>, ApiError> {
+    let entries = recovery_history_service
+        .get_recovery_history(GetRecoveryHistoryInput {
+            account_id: &account_id,
+        })
+        .await?;
+    Ok(Json(GetRecoveryHistoryResponse {
+        entries: entries.into_iter().map(Into::into).collect(),
+    }))
+}
@@ -0,0 +1,41 @@
+use database::ddb::DatabaseError;
+use errors::{ApiError, ErrorCode};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ServiceError {
+    #[error("Malformed recovery cancellation token")]
+    MalformedToken,
+    #[error("Invalid recovery cancellation token")]
+    InvalidToken,
+    #[error("Recovery cancellation token has expired")]
+    TokenExpired,
+    #[error("Too many recovery cancellation attempts")]
+    TooManyAttempts,
+    #[error("Failed to hash recovery cancellation token")]
+    Signing(#[from] hmac::digest::InvalidLength),
+    #[error("RECOVERY_CANCELLATION_TOKEN_KEY environment variable not set")]
+    MissingSigningKey,
+    #[error(transparent)]
+    Database(#[from] DatabaseError),
+}
+
+impl From<ServiceError> for ApiError {
+    fn from(value: ServiceError) -> Self {
+        let msg = value.to_string();
+        match value {
+            ServiceError::MalformedToken => ApiError::GenericBadRequest(msg),
+            ServiceError::InvalidToken | ServiceError::TokenExpired => {
+                ApiError::GenericForbidden(msg)
+            }
+            ServiceError::TooManyAttempts => ApiError::Specific {
+                code: ErrorCode::TooManyRequests,
+                detail: Some(msg),
+                field: None,
+            },
+            ServiceError::Signing(_)
+            | ServiceError::MissingSigningKey
+            | ServiceError::Database(_) => ApiError::GenericInternalApplicationError(msg),
+        }
+    }
+}
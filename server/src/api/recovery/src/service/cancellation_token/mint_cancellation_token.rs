@@ -0,0 +1,35 @@
+use hmac::Mac;
+
+use super::{error::ServiceError, Service, TEST_TOKEN};
+use crate::service::social::relationship::gen_code;
+
+pub struct MintCancellationTokenInput {
+    pub is_test_account: bool,
+}
+
+pub struct MintedCancellationToken {
+    pub token: String,
+    pub token_hash: String,
+}
+
+impl Service {
+    /// This function mints a short, random token that cancels a Delay & Notify recovery without
+    /// the customer having to authenticate. Only the token's hash is meant to be stored, on the
+    /// recovery itself, so the token stops working as soon as that recovery is no longer pending.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_test_account` - Test accounts get a fixed token, so that tests can use it
+    pub fn mint_cancellation_token(
+        &self,
+        input: MintCancellationTokenInput,
+    ) -> Result<MintedCancellationToken, ServiceError> {
+        let token = match input.is_test_account {
+            true => TEST_TOKEN.to_owned(),
+            false => gen_code(),
+        };
+        let token_hash = hex::encode(self.hash_token(&token)?.finalize().into_bytes());
+
+        Ok(MintedCancellationToken { token, token_hash })
+    }
+}
@@ -0,0 +1,168 @@
+use std::{env, sync::Arc};
+
+use hmac::{Hmac, Mac};
+use repository::recovery::cancellation_attempts::Repository as CancellationAttemptsRepository;
+use serde::Deserialize;
+use sha2::Sha256;
+use time::{Duration, OffsetDateTime};
+
+use self::error::ServiceError;
+
+pub mod error;
+pub mod mint_cancellation_token;
+pub mod verify_cancellation_token;
+
+pub const TEST_TOKEN: &str = "00000000";
+const TEST_SIGNING_KEY: &str = "recovery-cancellation-token-test-key";
+// Tokens are 8 Crockford base32 characters, short enough to type from an SMS
+const TOKEN_LENGTH: usize = 8;
+// Only failed attempts are counted, per source in fixed windows. Sources behind a shared NAT
+// share a limit, so it's looser than it would be per account.
+const MAX_FAILURES_PER_WINDOW: u64 = 10;
+const ATTEMPT_WINDOW: Duration = Duration::minutes(15);
+
+#[derive(Deserialize)]
+pub struct Config {
+    pub recovery_cancellation_token: CancellationTokenMode,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "lowercase", tag = "mode")]
+pub enum CancellationTokenMode {
+    Test,
+    Environment,
+}
+
+#[derive(Clone)]
+pub struct Service {
+    // Tokens are stored as an HMAC under this key, so a leaked hash can't be brute-forced offline
+    signing_key: Arc<Vec<u8>>,
+    // Failed attempts are kept in DynamoDB so that the limit holds across server instances
+    attempts_repository: CancellationAttemptsRepository,
+}
+
+impl Service {
+    pub fn new(
+        config: Config,
+        attempts_repository: CancellationAttemptsRepository,
+    ) -> Result<Self, ServiceError> {
+        let signing_key = match config.recovery_cancellation_token {
+            CancellationTokenMode::Test => TEST_SIGNING_KEY.to_owned(),
+            CancellationTokenMode::Environment => env::var("RECOVERY_CANCELLATION_TOKEN_KEY")
+                .map_err(|_| ServiceError::MissingSigningKey)?,
+        };
+        Ok(Self {
+            signing_key: Arc::new(signing_key.into_bytes()),
+            attempts_repository,
+        })
+    }
+
+    fn hash_token(&self, token: &str) -> Result<Hmac<Sha256>, ServiceError> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.signing_key)?;
+        mac.update(token.as_bytes());
+        Ok(mac)
+    }
+
+    // Counts a failed attempt from the source and returns whether it's still within the limit
+    async fn record_failure(
+        &self,
+        source: &str,
+        now: OffsetDateTime,
+    ) -> Result<bool, ServiceError> {
+        let window = ATTEMPT_WINDOW.whole_seconds();
+        let window_start = now - Duration::seconds(now.unix_timestamp().rem_euclid(window));
+        let failures = self
+            .attempts_repository
+            .increment_failures(source, window_start, window_start + ATTEMPT_WINDOW)
+            .await?;
+        Ok(failures <= MAX_FAILURES_PER_WINDOW)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use database::{ddb, ddb::DDBService, DBMode};
+    use repository::recovery::cancellation_attempts::Repository as CancellationAttemptsRepository;
+    use time::{Duration, OffsetDateTime};
+
+    use super::{
+        error::ServiceError, mint_cancellation_token::MintCancellationTokenInput,
+        CancellationTokenMode, Config, Service, TEST_TOKEN,
+    };
+
+    // Checking a token never touches the attempts table, so the connection is never used
+    async fn service() -> Service {
+        let connection = ddb::Config {
+            dynamodb: DBMode::Test,
+        }
+        .to_connection()
+        .await;
+        Service::new(
+            Config {
+                recovery_cancellation_token: CancellationTokenMode::Test,
+            },
+            CancellationAttemptsRepository::new(connection),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let service = service().await;
+        let now = OffsetDateTime::now_utc();
+        let expires_at = now + Duration::days(1);
+        let minted = service
+            .mint_cancellation_token(MintCancellationTokenInput {
+                is_test_account: false,
+            })
+            .unwrap();
+        assert_eq!(minted.token.len(), 8);
+        assert_ne!(minted.token_hash, minted.token);
+
+        service
+            .check_token(&minted.token, Some(&minted.token_hash), expires_at, now)
+            .unwrap();
+        // Tokens are read out of an SMS, so lowercase and look-alike characters are accepted
+        service
+            .check_token(
+                &minted.token.to_lowercase(),
+                Some(&minted.token_hash),
+                expires_at,
+                now,
+            )
+            .unwrap();
+
+        let result = service.check_token(
+            &minted.token,
+            Some(&minted.token_hash),
+            expires_at,
+            expires_at,
+        );
+        assert!(matches!(result, Err(ServiceError::TokenExpired)));
+
+        // Recoveries without a token can't be cancelled with one
+        let result = service.check_token(&minted.token, None, expires_at, now);
+        assert!(matches!(result, Err(ServiceError::InvalidToken)));
+    }
+
+    #[tokio::test]
+    async fn test_wrong_token() {
+        let service = service().await;
+        let now = OffsetDateTime::now_utc();
+        let expires_at = now + Duration::days(1);
+        let minted = service
+            .mint_cancellation_token(MintCancellationTokenInput {
+                is_test_account: true,
+            })
+            .unwrap();
+        assert_eq!(minted.token, TEST_TOKEN);
+
+        let result = service.check_token("ZZZZZZZZ", Some(&minted.token_hash), expires_at, now);
+        assert!(matches!(result, Err(ServiceError::InvalidToken)));
+
+        for token in ["garbage", "0000000U", ""] {
+            let result = service.check_token(token, Some(&minted.token_hash), expires_at, now);
+            assert!(matches!(result, Err(ServiceError::MalformedToken)));
+        }
+    }
+}
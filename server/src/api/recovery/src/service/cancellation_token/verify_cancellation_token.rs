@@ -0,0 +1,84 @@
+use hmac::Mac;
+use time::OffsetDateTime;
+
+use super::{error::ServiceError, Service, TOKEN_LENGTH};
+use crate::{entities::WalletRecovery, service::social::relationship::disambiguate_code_input};
+
+pub struct VerifyCancellationTokenInput<'a> {
+    pub recovery: Option<&'a WalletRecovery>,
+    pub token: &'a str,
+    pub source: &'a str,
+    pub now: OffsetDateTime,
+}
+
+impl Service {
+    /// This function checks a recovery cancellation token against the hash stored on the pending
+    /// Delay & Notify recovery, and that the recovery's delay hasn't ended. Only failed attempts
+    /// count towards the rate limit for their source, so a valid token is always accepted however
+    /// many bad ones were presented before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `recovery` - The account's pending Delay & Notify recovery, if any
+    /// * `token` - The token, as sent to the customer by `mint_cancellation_token`
+    /// * `source` - Where the attempt came from, typically the client's IP address
+    /// * `now` - The time to check the token's expiry against
+    pub async fn verify_cancellation_token(
+        &self,
+        input: VerifyCancellationTokenInput<'_>,
+    ) -> Result<(), ServiceError> {
+        let requirements = input
+            .recovery
+            .and_then(|r| r.requirements.delay_notify_requirements.as_ref());
+        let result = match requirements {
+            Some(requirements) => self.check_token(
+                input.token,
+                requirements.cancellation_token_hash.as_deref(),
+                requirements.delay_end_time,
+                input.now,
+            ),
+            None => Err(ServiceError::InvalidToken),
+        };
+
+        match result {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                if self.record_failure(input.source, input.now).await? {
+                    Err(e)
+                } else {
+                    Err(ServiceError::TooManyAttempts)
+                }
+            }
+        }
+    }
+
+    pub(super) fn check_token(
+        &self,
+        token: &str,
+        token_hash: Option<&str>,
+        expires_at: OffsetDateTime,
+        now: OffsetDateTime,
+    ) -> Result<(), ServiceError> {
+        let token = disambiguate_code_input(token);
+        if token.len() != TOKEN_LENGTH
+            || !token
+                .chars()
+                .all(|c| c.is_ascii_digit() || (c.is_ascii_uppercase() && c != 'U'))
+        {
+            return Err(ServiceError::MalformedToken);
+        }
+
+        let token_hash = token_hash
+            .and_then(|h| hex::decode(h).ok())
+            .ok_or(ServiceError::InvalidToken)?;
+        self.hash_token(&token)?
+            .verify_slice(&token_hash)
+            .map_err(|_| ServiceError::InvalidToken)?;
+
+        if now >= expires_at {
+            return Err(ServiceError::TokenExpired);
+        }
+
+        Ok(())
+    }
+}
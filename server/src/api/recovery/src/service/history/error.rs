@@ -0,0 +1,20 @@
+use errors::ApiError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ServiceError {
+    #[error("Failed to generate recovery history entry id")]
+    GenerateId(#[from] external_identifier::Error),
+    #[error(transparent)]
+    Database(#[from] database::ddb::DatabaseError),
+}
+
+impl From<ServiceError> for ApiError {
+    fn from(value: ServiceError) -> Self {
+        let msg = value.to_string();
+        match value {
+            ServiceError::GenerateId(_) => ApiError::GenericInternalApplicationError(msg),
+            ServiceError::Database(e) => e.into(),
+        }
+    }
+}
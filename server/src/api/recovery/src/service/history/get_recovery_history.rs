@@ -0,0 +1,24 @@
+use types::{account::identifiers::AccountId, recovery::history::RecoveryHistoryEntry};
+
+use super::{error::ServiceError, Service};
+
+pub struct GetRecoveryHistoryInput<'a> {
+    pub account_id: &'a AccountId,
+}
+
+impl Service {
+    /// This function fetches an account's recovery history, oldest entry first.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account whose recovery history is being fetched
+    pub async fn get_recovery_history(
+        &self,
+        input: GetRecoveryHistoryInput<'_>,
+    ) -> Result<Vec<RecoveryHistoryEntry>, ServiceError> {
+        Ok(self
+            .repository
+            .fetch_for_account_id(input.account_id)
+            .await?)
+    }
+}
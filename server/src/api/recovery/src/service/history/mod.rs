@@ -0,0 +1,17 @@
+use repository::recovery::history::Repository;
+
+pub mod error;
+pub mod get_recovery_history;
+pub mod record_recovery_history_entry;
+
+#[derive(Clone)]
+pub struct Service {
+    pub repository: Repository,
+}
+
+impl Service {
+    #[must_use]
+    pub fn new(repository: Repository) -> Self {
+        Self { repository }
+    }
+}
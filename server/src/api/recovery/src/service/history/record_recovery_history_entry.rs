@@ -0,0 +1,56 @@
+use tracing::{event, Level};
+use types::{
+    account::identifiers::AccountId,
+    recovery::history::{RecoveryHistoryAction, RecoveryHistoryEntry},
+};
+
+use crate::metrics;
+
+use super::{error::ServiceError, Service};
+
+pub struct RecordRecoveryHistoryEntryInput<'a> {
+    pub account_id: &'a AccountId,
+    pub action: RecoveryHistoryAction,
+    pub actor_account_id: &'a AccountId,
+    pub app_signed: bool,
+    pub hw_signed: bool,
+}
+
+impl Service {
+    /// This function appends an entry to an account's recovery history. History is recorded
+    /// after the change it describes has been made, so a failure to record it is logged and
+    /// counted rather than failing a request whose change has already gone through.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - The account whose recovery the entry is about
+    /// * `action` - What happened to the recovery
+    /// * `actor_account_id` - The account that took the action, which is a Trusted Contact's for social challenge responses
+    /// * `app_signed` - Whether the action was signed by the actor's app auth key
+    /// * `hw_signed` - Whether the action was signed by the actor's hardware auth key
+    pub async fn record_recovery_history_entry(&self, input: RecordRecoveryHistoryEntryInput<'_>) {
+        let action = input.action;
+        if let Err(e) = self.persist_entry(input).await {
+            metrics::RECOVERY_HISTORY_WRITE_FAILED.add(1, &[]);
+            event!(
+                Level::ERROR,
+                "Failed to record {action:?} in recovery history: {e}"
+            );
+        }
+    }
+
+    async fn persist_entry(
+        &self,
+        input: RecordRecoveryHistoryEntryInput<'_>,
+    ) -> Result<RecoveryHistoryEntry, ServiceError> {
+        let entry = RecoveryHistoryEntry::new(
+            input.account_id,
+            input.action,
+            input.actor_account_id,
+            input.app_signed,
+            input.hw_signed,
+        )?;
+        self.repository.persist(&entry).await?;
+        Ok(entry)
+    }
+}
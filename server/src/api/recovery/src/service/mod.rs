@@ -1 +1,3 @@
+pub mod cancellation_token;
+pub mod history;
 pub mod social;
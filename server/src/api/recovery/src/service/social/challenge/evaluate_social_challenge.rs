@@ -0,0 +1,77 @@
+use time::OffsetDateTime;
+use types::{account::identifiers::AccountId, recovery::social::challenge::SocialChallenge};
+
+use crate::service::social::relationship::get_recovery_policy::GetRecoveryPolicyInput;
+
+use super::{error::ServiceError, Service};
+
+pub struct EvaluateSocialChallengeInput<'a> {
+    pub customer_account_id: &'a AccountId,
+    pub challenge: &'a SocialChallenge,
+    // Relationship ages are measured against this, so that it follows the recovery clock
+    pub now: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SocialChallengeEvaluation {
+    pub satisfied_weight: u32,
+    pub required_weight: u32,
+    // Whether the most recent response is the one that pushed the challenge over the threshold
+    pub satisfied_by_latest_response: bool,
+}
+
+impl SocialChallengeEvaluation {
+    pub fn is_satisfied(&self) -> bool {
+        self.satisfied_weight >= self.required_weight
+    }
+}
+
+impl Service {
+    /// This function checks a social challenge's responses against the customer's recovery
+    /// policy. Only responses from endorsed Trusted Contacts whose relationship is older than
+    /// the policy's minimum age count, each with the weight the policy assigns it.
+    ///
+    /// # Arguments
+    ///
+    /// * `customer_account_id` - The customer that owns the challenge
+    /// * `challenge` - The challenge to evaluate
+    /// * `now` - The time to measure relationship ages at
+    pub async fn evaluate_social_challenge(
+        &self,
+        input: EvaluateSocialChallengeInput<'_>,
+    ) -> Result<SocialChallengeEvaluation, ServiceError> {
+        if input.challenge.customer_account_id != *input.customer_account_id {
+            return Err(ServiceError::AccountNotCustomer);
+        }
+
+        let policy = self
+            .recovery_relationship_service
+            .get_recovery_policy(GetRecoveryPolicyInput {
+                customer_account_id: input.customer_account_id,
+            })
+            .await?;
+        let endorsed_trusted_contacts = self
+            .repository
+            .fetch_recovery_relationships_for_account(input.customer_account_id)
+            .await?
+            .endorsed_trusted_contacts;
+
+        let now = input.now;
+        let responses = &input.challenge.responses;
+        let satisfied_weight = policy.satisfied_weight(responses, &endorsed_trusted_contacts, now);
+        // Responses are appended as they arrive, so dropping the last one gives the weight
+        //   before the most recent Trusted Contact responded
+        let previous_weight = policy.satisfied_weight(
+            &responses[..responses.len().saturating_sub(1)],
+            &endorsed_trusted_contacts,
+            now,
+        );
+
+        Ok(SocialChallengeEvaluation {
+            satisfied_weight,
+            required_weight: policy.threshold,
+            satisfied_by_latest_response: policy.is_satisfied_by(satisfied_weight)
+                && !policy.is_satisfied_by(previous_weight),
+        })
+    }
+}
@@ -46,7 +46,9 @@ impl Service {
             .fetch_recovery_relationship(input.recovery_relationship_id)
             .await?
         {
-            RecoveryRelationship::Invitation(_) => {
+            RecoveryRelationship::Invitation(_)
+            | RecoveryRelationship::ArchivedInvitation(_)
+            | RecoveryRelationship::Stale(_) => {
                 return Err(ServiceError::RecoveryRelationshipStatusMismatch)
             }
             RecoveryRelationship::Unendorsed(r) => (r.common_fields, r.connection_fields),
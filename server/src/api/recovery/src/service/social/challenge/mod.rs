@@ -6,6 +6,7 @@ use super::relationship::Service as RecoveryRelationshipService;
 
 pub mod create_social_challenge;
 pub mod error;
+pub mod evaluate_social_challenge;
 pub mod fetch_social_challenge;
 pub mod respond_to_social_challenge;
 
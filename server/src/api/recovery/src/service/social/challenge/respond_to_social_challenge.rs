@@ -22,11 +22,18 @@ pub struct RespondToSocialChallengeInput<'a> {
     pub recovery_sealed_pkek: &'a str,
 }
 
+pub struct RespondToSocialChallengeOutput {
+    pub challenge: SocialChallenge,
+    // Whether this is the Trusted Contact's first response, rather than one replacing an
+    //   earlier response that was already counted
+    pub first_response: bool,
+}
+
 impl Service {
     pub async fn respond_to_social_challenge(
         &self,
         input: RespondToSocialChallengeInput<'_>,
-    ) -> Result<SocialChallenge, ServiceError> {
+    ) -> Result<RespondToSocialChallengeOutput, ServiceError> {
         let prev_challenge = self
             .repository
             .fetch_social_challenge(input.social_challenge_id)
@@ -47,6 +54,10 @@ impl Service {
             _ => return Err(ServiceError::AccountNotTrustedContact),
         };
 
+        let first_response = !prev_challenge
+            .responses
+            .iter()
+            .any(|r| r.recovery_relationship_id == common_fields.id);
         let challenge = self
             .repository
             .persist_social_challenge(&prev_challenge.with_response(SocialChallengeResponse {
@@ -73,6 +84,9 @@ impl Service {
             })
             .await?;
 
-        Ok(challenge)
+        Ok(RespondToSocialChallengeOutput {
+            challenge,
+            first_response,
+        })
     }
 }
@@ -65,6 +65,9 @@ impl Service {
                 }
                 return Err(ServiceError::RelationshipAlreadyEstablished);
             }
+            RecoveryRelationship::ArchivedInvitation(_) | RecoveryRelationship::Stale(_) => {
+                return Err(ServiceError::InvitationExpired);
+            }
         };
 
         if OffsetDateTime::now_utc() > invitation.expires_at {
@@ -107,6 +107,9 @@ impl Service {
                 &connection.common_fields,
                 &connection.connection_fields,
             ),
+            RecoveryRelationship::ArchivedInvitation(_) | RecoveryRelationship::Stale(_) => {
+                Err(ServiceError::RelationshipInactive)
+            }
         }?;
 
         self.repository
@@ -21,6 +21,8 @@ pub enum ServiceError {
     InvitationNonEndorsable,
     #[error("Recovery relationship already established")]
     RelationshipAlreadyEstablished,
+    #[error("Recovery relationship is no longer active")]
+    RelationshipInactive,
     #[error("Recovery relationship invitation expired")]
     InvitationExpired,
     #[error("Recovery relationship invitation code mismatch")]
@@ -37,6 +39,12 @@ pub enum ServiceError {
     InvalidKeyProof,
     #[error("Invalid operation for access token")]
     InvalidOperationForAccessToken,
+    #[error("Invalid recovery policy")]
+    InvalidRecoveryPolicy,
+    #[error("Recovery policy can only weigh endorsed trusted contacts")]
+    RecoveryPolicyRelationshipNotEndorsed,
+    #[error("Recovery policy threshold cannot be met by endorsed trusted contacts")]
+    RecoveryPolicyThresholdUnattainable,
     #[error(transparent)]
     Notification(#[from] notification::NotificationError),
     #[error(transparent)]
@@ -54,8 +62,12 @@ impl From<ServiceError> for ApiError {
             | ServiceError::NotificationPayloadBuilder(_) => {
                 ApiError::GenericInternalApplicationError(msg)
             }
-            ServiceError::InvitationNonEndorsable => ApiError::GenericBadRequest(msg),
+            ServiceError::InvitationNonEndorsable
+            | ServiceError::InvalidRecoveryPolicy
+            | ServiceError::RecoveryPolicyRelationshipNotEndorsed
+            | ServiceError::RecoveryPolicyThresholdUnattainable => ApiError::GenericBadRequest(msg),
             ServiceError::Database(e) => e.into(),
+            ServiceError::RelationshipInactive => ApiError::GenericNotFound(msg),
             ServiceError::RelationshipAlreadyEstablished
             | ServiceError::AccountAlreadyTrustedContact => ApiError::GenericConflict(msg),
             ServiceError::UnauthorizedRelationshipDeletion
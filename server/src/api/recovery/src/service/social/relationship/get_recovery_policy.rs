@@ -0,0 +1,26 @@
+use types::{account::identifiers::AccountId, recovery::social::policy::RecoveryPolicy};
+
+use super::{error::ServiceError, Service};
+
+pub struct GetRecoveryPolicyInput<'a> {
+    pub customer_account_id: &'a AccountId,
+}
+
+impl Service {
+    /// This function fetches the recovery policy for a customer. Customers that haven't set a
+    /// policy get the default one, where any single endorsed Trusted Contact can help them recover.
+    ///
+    /// # Arguments
+    ///
+    /// * `customer_account_id` - The customer account whose policy is being fetched
+    pub async fn get_recovery_policy(
+        &self,
+        input: GetRecoveryPolicyInput<'_>,
+    ) -> Result<RecoveryPolicy, ServiceError> {
+        Ok(self
+            .repository
+            .fetch_optional_recovery_policy(input.customer_account_id)
+            .await?
+            .unwrap_or_else(|| RecoveryPolicy::default_for_account(input.customer_account_id)))
+    }
+}
@@ -11,9 +11,12 @@ pub mod create_recovery_relationship_invitation;
 pub mod delete_recovery_relationship;
 pub mod endorse_recovery_relationships;
 pub mod error;
+pub mod get_recovery_policy;
 pub mod get_recovery_relationship_invitation_for_code;
 pub mod get_recovery_relationships;
 pub mod reissue_recovery_relationship_invitation;
+pub mod sweep_recovery_relationships;
+pub mod update_recovery_policy;
 
 const TEST_EXPIRATION_SECS: i64 = 3000;
 
@@ -35,7 +38,7 @@ impl Service {
     }
 }
 
-fn disambiguate_code_input(code: &str) -> String {
+pub(crate) fn disambiguate_code_input(code: &str) -> String {
     // https://www.crockford.com/base32.html
     // Decode Os as 0s and Is and Ls as 1s for human readability errors
     code.to_uppercase()
@@ -43,7 +46,7 @@ fn disambiguate_code_input(code: &str) -> String {
         .replace(['I', 'L'], "1")
 }
 
-fn gen_code() -> String {
+pub(crate) fn gen_code() -> String {
     let mut code_bytes: [u8; 5] = [0; 5];
     rand::thread_rng().fill(&mut code_bytes);
     base32::encode(Alphabet::Crockford, &code_bytes)
@@ -0,0 +1,293 @@
+use notification::payloads::recovery_relationship_invitation_expiring::RecoveryRelationshipInvitationExpiringPayload;
+use notification::payloads::recovery_relationship_stale::{
+    RecoveryRelationshipStalePayload, RecoveryRelationshipStaleTrustedContactPayload,
+};
+use notification::service::SendNotificationInput;
+use notification::{NotificationPayloadBuilder, NotificationPayloadType};
+use time::{Duration, OffsetDateTime};
+use tracing::{event, instrument, Level};
+use types::recovery::social::relationship::{
+    RecoveryRelationship, RecoveryRelationshipArchivedInvitation, RecoveryRelationshipInvitation,
+    RecoveryRelationshipStale, RecoveryRelationshipUnendorsed,
+};
+
+use super::{error::ServiceError, Service};
+
+// How long before an invitation expires that the customer gets reminded about it
+const INVITATION_REMINDER_WINDOW: Duration = Duration::days(1);
+// Expired invitations can still be reissued for a while before they're archived
+const INVITATION_ARCHIVE_GRACE_PERIOD: Duration = Duration::days(7);
+// How long an accepted invitation can go without the customer endorsing it
+const UNENDORSED_STALE_AFTER: Duration = Duration::days(14);
+
+pub struct SweepRecoveryRelationshipsInput {
+    pub now: OffsetDateTime,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SweepRecoveryRelationshipsOutput {
+    pub reminders_sent: usize,
+    pub invitations_archived: usize,
+    pub relationships_marked_stale: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum InvitationSweepAction {
+    Remind,
+    Archive,
+}
+
+fn invitation_sweep_action(
+    invitation: &RecoveryRelationshipInvitation,
+    now: OffsetDateTime,
+) -> Option<InvitationSweepAction> {
+    if now > invitation.expires_at + INVITATION_ARCHIVE_GRACE_PERIOD {
+        Some(InvitationSweepAction::Archive)
+    } else if invitation.reminder_sent_at.is_none()
+        // Invitations that were never going to last longer than the window, like those for
+        // test accounts, aren't worth a reminder
+        && invitation.expires_at - invitation.common_fields.created_at > INVITATION_REMINDER_WINDOW
+        && now <= invitation.expires_at
+        && invitation.expires_at - now <= INVITATION_REMINDER_WINDOW
+    {
+        Some(InvitationSweepAction::Remind)
+    } else {
+        None
+    }
+}
+
+fn is_stale(connection: &RecoveryRelationshipUnendorsed, now: OffsetDateTime) -> bool {
+    now - connection.common_fields.updated_at > UNENDORSED_STALE_AFTER
+}
+
+impl Service {
+    /// This function moves recovery relationships that haven't been endorsed along their lifecycle.
+    /// Customers are reminded once about invitations that are about to expire, invitations that
+    /// expired long enough ago are archived, and Trusted Contacts that were never endorsed are
+    /// marked as stale. Failures for individual relationships are logged and skipped so that they
+    /// get retried on the next sweep.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The time to evaluate expiry and staleness against
+    #[instrument(skip(self, input))]
+    pub async fn sweep_recovery_relationships(
+        &self,
+        input: SweepRecoveryRelationshipsInput,
+    ) -> Result<SweepRecoveryRelationshipsOutput, ServiceError> {
+        let relationships = self
+            .repository
+            .fetch_recovery_relationships_to_sweep()
+            .await?;
+
+        let mut output = SweepRecoveryRelationshipsOutput::default();
+
+        for relationship in relationships.invitations {
+            let RecoveryRelationship::Invitation(invitation) = relationship else {
+                continue;
+            };
+            let result = match invitation_sweep_action(&invitation, input.now) {
+                Some(InvitationSweepAction::Remind) => self
+                    .remind_invitation(&invitation, &input.now)
+                    .await
+                    .map(|_| output.reminders_sent += 1),
+                Some(InvitationSweepAction::Archive) => self
+                    .archive_invitation(&invitation)
+                    .await
+                    .map(|_| output.invitations_archived += 1),
+                None => Ok(()),
+            };
+            if let Err(e) = result {
+                event!(
+                    Level::WARN,
+                    "Failed to sweep recovery relationship invitation {}: {e}",
+                    invitation.common_fields.id
+                );
+            }
+        }
+
+        for relationship in relationships.unendorsed_trusted_contacts {
+            let RecoveryRelationship::Unendorsed(connection) = relationship else {
+                continue;
+            };
+            if !is_stale(&connection, input.now) {
+                continue;
+            }
+            match self.mark_stale(&connection).await {
+                Ok(_) => output.relationships_marked_stale += 1,
+                Err(e) => event!(
+                    Level::WARN,
+                    "Failed to mark recovery relationship {} as stale: {e}",
+                    connection.common_fields.id
+                ),
+            }
+        }
+
+        Ok(output)
+    }
+
+    async fn remind_invitation(
+        &self,
+        invitation: &RecoveryRelationshipInvitation,
+        now: &OffsetDateTime,
+    ) -> Result<(), ServiceError> {
+        // Notify first, so that a failed notification leaves the invitation unchanged and the
+        // reminder is retried on the next sweep. A failure to persist afterwards can remind the
+        // customer twice, which is better than not at all.
+        self.notification_service
+            .send_notification(SendNotificationInput {
+                account_id: &invitation.common_fields.customer_account_id,
+                payload_type: NotificationPayloadType::RecoveryRelationshipInvitationExpiring,
+                payload: &NotificationPayloadBuilder::default()
+                    .recovery_relationship_invitation_expiring_payload(Some(
+                        RecoveryRelationshipInvitationExpiringPayload {
+                            trusted_contact_alias: invitation
+                                .common_fields
+                                .trusted_contact_alias
+                                .clone(),
+                            expires_at: invitation.expires_at,
+                        },
+                    ))
+                    .build()?,
+                only_touchpoints: None,
+            })
+            .await?;
+
+        self.repository
+            .persist_recovery_relationship(&RecoveryRelationship::Invitation(
+                invitation.with_reminder_sent_at(now),
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn archive_invitation(
+        &self,
+        invitation: &RecoveryRelationshipInvitation,
+    ) -> Result<(), ServiceError> {
+        self.repository
+            .persist_recovery_relationship(&RecoveryRelationship::ArchivedInvitation(
+                RecoveryRelationshipArchivedInvitation {
+                    common_fields: invitation.common_fields.to_owned(),
+                    expires_at: invitation.expires_at,
+                },
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn mark_stale(
+        &self,
+        connection: &RecoveryRelationshipUnendorsed,
+    ) -> Result<(), ServiceError> {
+        // As with reminders, notify before persisting so that failures are retried
+        self.notification_service
+            .send_notification(SendNotificationInput {
+                account_id: &connection.common_fields.customer_account_id,
+                payload_type: NotificationPayloadType::RecoveryRelationshipStale,
+                payload: &NotificationPayloadBuilder::default()
+                    .recovery_relationship_stale_payload(Some(RecoveryRelationshipStalePayload {
+                        trusted_contact_alias: connection
+                            .common_fields
+                            .trusted_contact_alias
+                            .clone(),
+                    }))
+                    .build()?,
+                only_touchpoints: None,
+            })
+            .await?;
+
+        self.notification_service
+            .send_notification(SendNotificationInput {
+                account_id: &connection.connection_fields.trusted_contact_account_id,
+                payload_type: NotificationPayloadType::RecoveryRelationshipStaleTrustedContact,
+                payload: &NotificationPayloadBuilder::default()
+                    .recovery_relationship_stale_trusted_contact_payload(Some(
+                        RecoveryRelationshipStaleTrustedContactPayload {
+                            customer_alias: connection.connection_fields.customer_alias.clone(),
+                        },
+                    ))
+                    .build()?,
+                only_touchpoints: None,
+            })
+            .await?;
+
+        self.repository
+            .persist_recovery_relationship(&RecoveryRelationship::Stale(
+                RecoveryRelationshipStale {
+                    common_fields: connection.common_fields.to_owned(),
+                    connection_fields: connection.connection_fields.to_owned(),
+                },
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Duration, OffsetDateTime};
+    use types::account::identifiers::AccountId;
+    use types::recovery::social::relationship::{
+        RecoveryRelationshipCommonFields, RecoveryRelationshipId, RecoveryRelationshipInvitation,
+    };
+
+    use super::{invitation_sweep_action, InvitationSweepAction};
+
+    fn invitation(
+        expires_at: OffsetDateTime,
+        reminder_sent_at: Option<OffsetDateTime>,
+    ) -> RecoveryRelationshipInvitation {
+        RecoveryRelationshipInvitation {
+            common_fields: RecoveryRelationshipCommonFields {
+                id: RecoveryRelationshipId::gen().unwrap(),
+                customer_account_id: AccountId::gen().unwrap(),
+                trusted_contact_alias: "Trusted Contact".to_owned(),
+                created_at: expires_at - Duration::days(3),
+                updated_at: expires_at - Duration::days(3),
+            },
+            code: String::new(),
+            expires_at,
+            customer_enrollment_pubkey: String::new(),
+            reminder_sent_at,
+        }
+    }
+
+    #[test]
+    fn test_invitation_sweep_action() {
+        let now = OffsetDateTime::now_utc();
+
+        let tests = vec![
+            (invitation(now + Duration::days(2), None), None),
+            (
+                invitation(now + Duration::hours(12), None),
+                Some(InvitationSweepAction::Remind),
+            ),
+            (invitation(now + Duration::hours(12), Some(now)), None),
+            // Short-lived invitations aren't worth a reminder
+            (
+                RecoveryRelationshipInvitation {
+                    common_fields: RecoveryRelationshipCommonFields {
+                        created_at: now,
+                        ..invitation(now + Duration::hours(12), None).common_fields
+                    },
+                    ..invitation(now + Duration::hours(12), None)
+                },
+                None,
+            ),
+            // Recently expired invitations are left alone so they can still be reissued
+            (invitation(now - Duration::days(1), None), None),
+            (
+                invitation(now - Duration::days(8), Some(now)),
+                Some(InvitationSweepAction::Archive),
+            ),
+        ];
+
+        for (invitation, expected) in tests {
+            assert_eq!(invitation_sweep_action(&invitation, now), expected);
+        }
+    }
+}
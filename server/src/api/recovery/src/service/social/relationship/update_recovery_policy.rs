@@ -0,0 +1,74 @@
+use std::collections::{HashMap, HashSet};
+
+use types::{
+    account::identifiers::AccountId,
+    recovery::social::{policy::RecoveryPolicy, relationship::RecoveryRelationshipId},
+};
+
+use super::{error::ServiceError, get_recovery_policy::GetRecoveryPolicyInput, Service};
+
+pub struct UpdateRecoveryPolicyInput<'a> {
+    pub customer_account_id: &'a AccountId,
+    pub threshold: u32,
+    pub weights: HashMap<RecoveryRelationshipId, u32>,
+    pub min_relationship_age_secs: i64,
+}
+
+impl Service {
+    /// This function replaces the customer's recovery policy. The policy has to be satisfiable by
+    /// the customer's current endorsed Trusted Contacts, and can only weigh relationships that are
+    /// endorsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `customer_account_id` - The customer account whose policy is being updated
+    /// * `threshold` - The total weight of responses required to satisfy a social challenge
+    /// * `weights` - Per-relationship weights, which must be positive; relationships that aren't
+    ///   listed count as 1
+    /// * `min_relationship_age_secs` - How old a relationship must be before its responses count
+    pub async fn update_recovery_policy(
+        &self,
+        input: UpdateRecoveryPolicyInput<'_>,
+    ) -> Result<RecoveryPolicy, ServiceError> {
+        // A zero weight would leave an endorsed Trusted Contact unable to contribute to a
+        //   challenge, so they have to be removed instead
+        if input.threshold == 0
+            || input.min_relationship_age_secs < 0
+            || input.weights.values().any(|weight| *weight == 0)
+        {
+            return Err(ServiceError::InvalidRecoveryPolicy);
+        }
+
+        let endorsed_trusted_contacts = self
+            .repository
+            .fetch_recovery_relationships_for_account(input.customer_account_id)
+            .await?
+            .endorsed_trusted_contacts;
+
+        let endorsed_ids = endorsed_trusted_contacts
+            .iter()
+            .map(|r| &r.common_fields().id)
+            .collect::<HashSet<_>>();
+        if input.weights.keys().any(|id| !endorsed_ids.contains(id)) {
+            return Err(ServiceError::RecoveryPolicyRelationshipNotEndorsed);
+        }
+
+        let prev_policy = self
+            .get_recovery_policy(GetRecoveryPolicyInput {
+                customer_account_id: input.customer_account_id,
+            })
+            .await?;
+        let policy = RecoveryPolicy {
+            threshold: input.threshold,
+            weights: input.weights,
+            min_relationship_age_secs: input.min_relationship_age_secs,
+            ..prev_policy
+        };
+
+        if policy.attainable_weight(&endorsed_trusted_contacts) < policy.threshold {
+            return Err(ServiceError::RecoveryPolicyThresholdUnattainable);
+        }
+
+        Ok(self.repository.persist_recovery_policy(&policy).await?)
+    }
+}
@@ -4,16 +4,20 @@ use account::entities::FullAccountAuthKeysPayload;
 use account::service::ClearPushTouchpointsInput;
 use account::service::CreateAndRotateAuthKeysInput;
 use async_trait::async_trait;
+use types::recovery::history::RecoveryHistoryAction;
 
 use super::{
-    rotated_keyset::RotatedKeysetState, RecoveryEvent, RecoveryServices, RecoveryStateResponse,
-    Transition, TransitionTo, TransitioningRecoveryState,
+    current_social_recovery::pending_social_recovery, rotated_keyset::RotatedKeysetState,
+    RecoveryEvent, RecoveryServices, RecoveryStateResponse, Transition, TransitionTo,
+    TransitioningRecoveryState,
 };
 use crate::entities::RecoveryStatus;
+use crate::entities::RecoveryType;
 use crate::entities::WalletRecovery;
 use crate::error::RecoveryError;
 use crate::metrics;
-use crate::state_machine::{PendingDelayNotifyRecovery, RecoveryResponse};
+use crate::service::history::record_recovery_history_entry::RecordRecoveryHistoryEntryInput;
+use crate::state_machine::{PendingDelayNotifyRecovery, RecoveryResponse, SocialRecoveryResponse};
 
 pub(crate) struct CompletableRecoveryState {
     pub(crate) account: FullAccount,
@@ -24,6 +28,23 @@ pub(crate) struct CompletableRecoveryState {
 #[async_trait]
 impl RecoveryStateResponse for CompletableRecoveryState {
     fn response(self: Box<Self>) -> serde_json::Value {
+        if let RecoveryType::SocialRecovery = self.recovery.recovery_type {
+            // Only reachable once the threshold is met, so report that many responses
+            let required_responses = self
+                .recovery
+                .requirements
+                .social_recovery_requirements
+                .as_ref()
+                .map_or(0, |requirements| requirements.required_responses);
+            return serde_json::json!(SocialRecoveryResponse {
+                pending_social_recovery: pending_social_recovery(
+                    &self.recovery,
+                    required_responses
+                ),
+                active_contest: self.active_contest,
+            });
+        }
+
         let requirements = self
             .recovery
             .requirements
@@ -57,21 +78,19 @@ impl TransitioningRecoveryState for CompletableRecoveryState {
             let recovery = &self.recovery;
             let account = self.account.clone();
 
-            let action = &recovery
-                .recovery_action
-                .delay_notify_action
-                .as_ref()
+            let destination = recovery
+                .get_destination()
                 .ok_or(RecoveryError::NoPendingRecoveryDestination)?;
-            if account.common_fields.active_auth_keys_id != action.destination.source_auth_keys_id {
+            if account.common_fields.active_auth_keys_id != destination.source_auth_keys_id {
                 return Err(RecoveryError::InvalidRecoveryDestination);
             }
 
             user_pool_service
                 .rotate_account_auth_keys(
                     &account.id,
-                    action.destination.app_auth_pubkey,
-                    action.destination.hardware_auth_pubkey,
-                    action.destination.recovery_auth_pubkey,
+                    destination.app_auth_pubkey,
+                    destination.hardware_auth_pubkey,
+                    destination.recovery_auth_pubkey,
                 )
                 .await?;
 
@@ -79,9 +98,9 @@ impl TransitioningRecoveryState for CompletableRecoveryState {
                 .account
                 .create_and_rotate_auth_keys(CreateAndRotateAuthKeysInput {
                     account_id: &account.id,
-                    app_auth_pubkey: action.destination.app_auth_pubkey,
-                    hardware_auth_pubkey: action.destination.hardware_auth_pubkey,
-                    recovery_auth_pubkey: action.destination.recovery_auth_pubkey,
+                    app_auth_pubkey: destination.app_auth_pubkey,
+                    hardware_auth_pubkey: destination.hardware_auth_pubkey,
+                    recovery_auth_pubkey: destination.recovery_auth_pubkey,
                 })
                 .await?;
 
@@ -100,6 +119,22 @@ impl TransitioningRecoveryState for CompletableRecoveryState {
                 )
                 .await?;
 
+            // Completion requires signatures from both the destination app and hardware keys
+            let action = match recovery.recovery_type {
+                RecoveryType::DelayAndNotify => RecoveryHistoryAction::DelayNotifyCompleted,
+                RecoveryType::SocialRecovery => RecoveryHistoryAction::SocialRecoveryCompleted,
+            };
+            services
+                .recovery_history
+                .record_recovery_history_entry(RecordRecoveryHistoryEntryInput {
+                    account_id: &account.id,
+                    action,
+                    actor_account_id: &account.id,
+                    app_signed: true,
+                    hw_signed: true,
+                })
+                .await;
+
             let mut attributes = vec![KeyValue::new(
                 metrics::CREATED_DURING_CONTEST_KEY,
                 self.active_contest,
@@ -110,8 +145,18 @@ impl TransitioningRecoveryState for CompletableRecoveryState {
                     lost_factor.to_string(),
                 ));
             }
-            metrics::DELAY_NOTIFY_COMPLETED.add(1, &attributes);
-            metrics::DELAY_NOTIFY_TIME_TO_COMPLETE.record(
+            let (completed, time_to_complete) = match recovery.recovery_type {
+                RecoveryType::DelayAndNotify => (
+                    &metrics::DELAY_NOTIFY_COMPLETED,
+                    &metrics::DELAY_NOTIFY_TIME_TO_COMPLETE,
+                ),
+                RecoveryType::SocialRecovery => (
+                    &metrics::SOCIAL_RECOVERY_COMPLETED,
+                    &metrics::SOCIAL_RECOVERY_TIME_TO_COMPLETE,
+                ),
+            };
+            completed.add(1, &attributes);
+            time_to_complete.record(
                 (services.recovery.cur_time() - recovery.created_at).whole_minutes() as u64,
                 &attributes,
             );
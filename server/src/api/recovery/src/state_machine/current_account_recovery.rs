@@ -22,7 +22,7 @@ use notification::{
     NotificationPayloadBuilder, NotificationPayloadType,
 };
 use time::{format_description::well_known::Rfc3339, Duration};
-use types::account::identifiers::AccountId;
+use types::{account::identifiers::AccountId, recovery::history::RecoveryHistoryAction};
 
 use crate::{
     entities::{
@@ -32,6 +32,8 @@ use crate::{
     },
     helpers::validate_signatures,
     metrics,
+    service::cancellation_token::mint_cancellation_token::MintCancellationTokenInput,
+    service::history::record_recovery_history_entry::RecordRecoveryHistoryEntryInput,
     state_machine::{PendingDelayNotifyRecovery, RecoveryResponse},
 };
 
@@ -120,6 +122,15 @@ impl TransitioningRecoveryState for CurrentAccountRecoveryState {
                 }
             }
 
+            // Only one kind of recovery can be pending at a time
+            if services
+                .recovery
+                .has_pending_recovery(&account.id, RecoveryType::SocialRecovery)
+                .await?
+            {
+                return Err(RecoveryError::StartRecoveryForAccount);
+            }
+
             // Source needs to match account's active auth keys
             if account.common_fields.active_auth_keys_id != destination.source_auth_keys_id {
                 return Err(RecoveryError::InvalidRecoverySource);
@@ -153,19 +164,28 @@ impl TransitioningRecoveryState for CurrentAccountRecoveryState {
                     .await?;
             }
 
+            let cancellation_token = services.cancellation_token.mint_cancellation_token(
+                MintCancellationTokenInput {
+                    is_test_account: account.common_fields.properties.is_test_account,
+                },
+            )?;
+
             let recovery_type = RecoveryType::DelayAndNotify;
-            let delay_period = account.recovery_delay_period();
+            let delay_period = account.recovery_delay_period(now);
             let requirements = RecoveryRequirements {
                 delay_notify_requirements: Some(DelayNotifyRequirements {
                     lost_factor,
                     delay_end_time: now + delay_period,
+                    cancellation_token_hash: Some(cancellation_token.token_hash),
                 }),
+                social_recovery_requirements: None,
             };
 
             let recovery_action = RecoveryAction {
                 delay_notify_action: Some(DelayNotifyRecoveryAction {
                     destination: destination.clone(),
                 }),
+                social_recovery_action: None,
             };
 
             let new_recovery = WalletRecovery {
@@ -184,6 +204,17 @@ impl TransitioningRecoveryState for CurrentAccountRecoveryState {
             let recovery_service = services.recovery;
             recovery_service.create(&new_recovery).await?;
 
+            services
+                .recovery_history
+                .record_recovery_history_entry(RecordRecoveryHistoryEntryInput {
+                    account_id: &account_id,
+                    action: RecoveryHistoryAction::DelayNotifyCreated,
+                    actor_account_id: &account_id,
+                    app_signed: key_proof.app_signed,
+                    hw_signed: key_proof.hw_signed,
+                })
+                .await;
+
             // If this recovery is for a lost App, turn off Mobile Pay
             if let Factor::App = lost_factor {
                 services
@@ -208,6 +239,7 @@ impl TransitioningRecoveryState for CurrentAccountRecoveryState {
                     initiation_time: now,
                     delay_end_time: now + delay_period,
                     lost_factor,
+                    cancellation_token: Some(cancellation_token.token),
                 }))
                 .recovery_completed_delay_period_payload(Some(
                     RecoveryCompletedDelayPeriodPayload {
@@ -342,68 +374,58 @@ impl TransitioningRecoveryState for CurrentAccountRecoveryState {
                     .await?;
             }
 
-            let status = if is_contesting_recovery {
-                RecoveryStatus::CanceledInContest
+            let action = if is_contesting_recovery {
+                RecoveryHistoryAction::DelayNotifyContested
             } else {
-                RecoveryStatus::Canceled
+                RecoveryHistoryAction::DelayNotifyCanceled
             };
+            cancel_delay_notify_recovery(
+                services,
+                &self.account,
+                recovery,
+                self.active_contest,
+                is_contesting_recovery,
+                RecordRecoveryHistoryEntryInput {
+                    account_id: &account_id,
+                    action,
+                    actor_account_id: &account_id,
+                    app_signed: key_proof.app_signed,
+                    hw_signed: key_proof.hw_signed,
+                },
+            )
+            .await?;
 
-            services
+            Ok(Transition::next(self, CanceledRecoveryState {}))
+        } else if let RecoveryEvent::CancelRecoveryWithToken {
+            recovery_created_at,
+        } = event
+        {
+            // The token is bound to the recovery it was minted for, so it can't cancel a later one
+            let recovery = self
                 .recovery
-                .complete((recovery.account_id.clone(), recovery.created_at), status)
-                .await?;
-
-            // If this recovery is being contested, turn off Mobile Pay
-            if is_contesting_recovery {
-                services
-                    .account
-                    .fetch_and_update_spend_limit(FetchAndUpdateSpendingLimitInput {
-                        account_id: &account_id,
-                        new_spending_limit: self.account.spending_limit.clone().map_or_else(
-                            || None,
-                            |old_limit| {
-                                Some(SpendingLimit {
-                                    active: false,
-                                    ..old_limit
-                                })
-                            },
-                        ),
-                    })
-                    .await?;
-            }
-
-            let payload = NotificationPayloadBuilder::default()
-                .recovery_canceled_delay_period_payload(Some(RecoveryCanceledDelayPeriodPayload {
-                    initiation_time: recovery.created_at,
-                    lost_factor: requirements.lost_factor,
-                }))
-                .build()
-                .map_err(|_| RecoveryError::GenerateNotificationPayloadError)?;
+                .as_ref()
+                .filter(|r| r.created_at == recovery_created_at)
+                .ok_or(RecoveryError::CancellationTokenMismatch)?;
 
-            services
-                .notification
-                .send_notification(SendNotificationInput {
+            // Holding a token means the customer received our pending recovery notification, so
+            //   no further comms verification is required. We treat it as contesting the recovery,
+            //   since it's how customers are told to cancel recoveries they didn't start.
+            let account_id = self.account.clone().id;
+            cancel_delay_notify_recovery(
+                services,
+                &self.account,
+                recovery,
+                self.active_contest,
+                true,
+                RecordRecoveryHistoryEntryInput {
                     account_id: &account_id,
-                    payload_type: NotificationPayloadType::RecoveryCanceledDelayPeriod,
-                    payload: &payload,
-                    only_touchpoints: None,
-                })
-                .await
-                .map_err(|_| RecoveryError::SendNotificationError)?;
-
-            let attributes = &[
-                KeyValue::new(
-                    metrics::LOST_FACTOR_KEY,
-                    requirements.lost_factor.to_string(),
-                ),
-                KeyValue::new(metrics::CREATED_DURING_CONTEST_KEY, self.active_contest),
-                KeyValue::new(metrics::CANCELED_IN_CONTEST_KEY, is_contesting_recovery),
-            ];
-            metrics::DELAY_NOTIFY_CANCELED.add(1, attributes);
-            metrics::DELAY_NOTIFY_TIME_TO_CANCEL.record(
-                (services.recovery.cur_time() - recovery.created_at).whole_minutes() as u64,
-                attributes,
-            );
+                    action: RecoveryHistoryAction::DelayNotifyCanceledWithToken,
+                    actor_account_id: &account_id,
+                    app_signed: false,
+                    hw_signed: false,
+                },
+            )
+            .await?;
 
             Ok(Transition::next(self, CanceledRecoveryState {}))
         } else if let RecoveryEvent::UpdateDelayForTestAccountRecovery {
@@ -436,6 +458,7 @@ impl TransitioningRecoveryState for CurrentAccountRecoveryState {
                     delay_end_time,
                     ..requirements
                 }),
+                social_recovery_requirements: None,
             };
 
             services
@@ -463,7 +486,93 @@ impl TransitioningRecoveryState for CurrentAccountRecoveryState {
     }
 }
 
-async fn validate_destination(
+async fn cancel_delay_notify_recovery(
+    services: &RecoveryServices<'_>,
+    account: &FullAccount,
+    recovery: &WalletRecovery,
+    active_contest: bool,
+    is_contesting_recovery: bool,
+    history_entry: RecordRecoveryHistoryEntryInput<'_>,
+) -> Result<(), RecoveryError> {
+    let requirements = recovery
+        .requirements
+        .delay_notify_requirements
+        .as_ref()
+        .ok_or(RecoveryError::MalformedRecoveryRequirements)?;
+    let account_id = &account.id;
+
+    let status = if is_contesting_recovery {
+        RecoveryStatus::CanceledInContest
+    } else {
+        RecoveryStatus::Canceled
+    };
+
+    services
+        .recovery
+        .complete((recovery.account_id.clone(), recovery.created_at), status)
+        .await?;
+
+    services
+        .recovery_history
+        .record_recovery_history_entry(history_entry)
+        .await;
+
+    // If this recovery is being contested, turn off Mobile Pay
+    if is_contesting_recovery {
+        services
+            .account
+            .fetch_and_update_spend_limit(FetchAndUpdateSpendingLimitInput {
+                account_id,
+                new_spending_limit: account.spending_limit.clone().map_or_else(
+                    || None,
+                    |old_limit| {
+                        Some(SpendingLimit {
+                            active: false,
+                            ..old_limit
+                        })
+                    },
+                ),
+            })
+            .await?;
+    }
+
+    let payload = NotificationPayloadBuilder::default()
+        .recovery_canceled_delay_period_payload(Some(RecoveryCanceledDelayPeriodPayload {
+            initiation_time: recovery.created_at,
+            lost_factor: requirements.lost_factor,
+        }))
+        .build()
+        .map_err(|_| RecoveryError::GenerateNotificationPayloadError)?;
+
+    services
+        .notification
+        .send_notification(SendNotificationInput {
+            account_id,
+            payload_type: NotificationPayloadType::RecoveryCanceledDelayPeriod,
+            payload: &payload,
+            only_touchpoints: None,
+        })
+        .await
+        .map_err(|_| RecoveryError::SendNotificationError)?;
+
+    let attributes = &[
+        KeyValue::new(
+            metrics::LOST_FACTOR_KEY,
+            requirements.lost_factor.to_string(),
+        ),
+        KeyValue::new(metrics::CREATED_DURING_CONTEST_KEY, active_contest),
+        KeyValue::new(metrics::CANCELED_IN_CONTEST_KEY, is_contesting_recovery),
+    ];
+    metrics::DELAY_NOTIFY_CANCELED.add(1, attributes);
+    metrics::DELAY_NOTIFY_TIME_TO_CANCEL.record(
+        (services.recovery.cur_time() - recovery.created_at).whole_minutes() as u64,
+        attributes,
+    );
+
+    Ok(())
+}
+
+pub(super) async fn validate_destination(
     services: &RecoveryServices<'_>,
     account_id: &AccountId,
     account_auth_keys: &FullAccountAuthKeys,
@@ -0,0 +1,460 @@
+use account::{
+    entities::{CommsVerificationScope, Factor, FullAccount, FullAccountAuthKeysPayload},
+    service::FetchAndUpdateSpendingLimitInput,
+    spend_limit::SpendingLimit,
+};
+use async_trait::async_trait;
+
+use ::metrics::KeyValue;
+
+use comms_verification::ConsumeVerificationForScopeInput;
+use notification::{
+    payloads::social_recovery::{
+        SocialRecoveryCanceledPayload, SocialRecoveryCompletedPayload,
+        SocialRecoveryInitiatedPayload,
+    },
+    service::SendNotificationInput,
+    NotificationPayloadBuilder, NotificationPayloadType,
+};
+use time::format_description::well_known::Rfc3339;
+use types::recovery::history::RecoveryHistoryAction;
+
+use crate::{
+    entities::{
+        RecoveryAction, RecoveryRequirements, RecoveryStatus, RecoveryType, SocialRecoveryAction,
+        SocialRecoveryRequirements, ToActor, ToActorStrategy, WalletRecovery,
+    },
+    metrics,
+    service::history::record_recovery_history_entry::RecordRecoveryHistoryEntryInput,
+    service::social::challenge::{
+        evaluate_social_challenge::EvaluateSocialChallengeInput,
+        fetch_social_challenge::FetchSocialChallengeAsCustomerInput,
+    },
+    state_machine::{PendingSocialRecovery, SocialRecoveryResponse},
+};
+
+use super::{
+    cancel_recovery::CanceledRecoveryState, current_account_recovery::validate_destination,
+    social_challenge_created::SocialChallengeCreatedState,
+    threshold_responded::ThresholdRespondedState, RecoveryError, RecoveryEvent, RecoveryServices,
+    RecoveryStateResponse, Transition, TransitionTo, TransitioningRecoveryState,
+};
+
+pub(crate) struct CurrentSocialRecoveryState {
+    pub(crate) account: FullAccount,
+    pub(crate) recovery: Option<WalletRecovery>,
+    // The weight of qualifying responses under the customer's recovery policy
+    pub(crate) responses: usize,
+    pub(crate) satisfied_by_latest_response: bool,
+    pub(crate) active_contest: bool,
+}
+
+pub(super) fn pending_social_recovery(
+    recovery: &WalletRecovery,
+    responses: usize,
+) -> Option<PendingSocialRecovery> {
+    let requirements = recovery
+        .requirements
+        .social_recovery_requirements
+        .as_ref()?;
+    let destination = recovery.get_destination()?;
+    Some(PendingSocialRecovery {
+        start_time: recovery.created_at,
+        social_challenge_id: requirements.social_challenge_id.clone(),
+        required_responses: requirements.required_responses,
+        responses,
+        auth_keys: FullAccountAuthKeysPayload {
+            app: destination.app_auth_pubkey,
+            hardware: destination.hardware_auth_pubkey,
+            recovery: destination.recovery_auth_pubkey,
+        },
+    })
+}
+
+#[async_trait]
+impl RecoveryStateResponse for CurrentSocialRecoveryState {
+    fn response(self: Box<Self>) -> serde_json::Value {
+        serde_json::json!(SocialRecoveryResponse {
+            pending_social_recovery: self
+                .recovery
+                .as_ref()
+                .and_then(|recovery| pending_social_recovery(recovery, self.responses)),
+            active_contest: self.active_contest,
+        })
+    }
+}
+
+#[async_trait]
+impl TransitioningRecoveryState for CurrentSocialRecoveryState {
+    async fn next_transition_or_err(
+        self: Box<Self>,
+        event: RecoveryEvent,
+        services: &RecoveryServices,
+    ) -> Result<Transition, RecoveryError> {
+        if let RecoveryEvent::CreateSocialRecovery {
+            account,
+            destination,
+            social_challenge_id,
+            key_proof,
+        } = event
+        {
+            // Social recovery only restores the App, so the Hardware has to be the actor
+            let actor = key_proof.to_actor(ToActorStrategy::ExclusiveOr)?;
+            if actor == Factor::App {
+                return Err(RecoveryError::UnexpectedKeyProof);
+            }
+
+            // If there's an existing recovery with the same keys and challenge, return it
+            if let Some(existing_recovery) = self.recovery.clone() {
+                let Some(requirements) = existing_recovery
+                    .requirements
+                    .social_recovery_requirements
+                    .as_ref()
+                else {
+                    return Err(RecoveryError::StartRecoveryForAccount);
+                };
+
+                if existing_recovery.get_destination() == Some(&destination)
+                    && requirements.social_challenge_id == social_challenge_id
+                {
+                    let (responses, active_contest) = (self.responses, self.active_contest);
+                    return Ok(Transition::next(
+                        self,
+                        SocialChallengeCreatedState {
+                            recovery: existing_recovery,
+                            responses,
+                            active_contest,
+                        },
+                    ));
+                } else {
+                    return Err(RecoveryError::StartRecoveryForAccount);
+                }
+            }
+
+            // Only one kind of recovery can be pending at a time
+            if services
+                .recovery
+                .has_pending_recovery(&account.id, RecoveryType::DelayAndNotify)
+                .await?
+            {
+                return Err(RecoveryError::StartRecoveryForAccount);
+            }
+
+            let social_challenge = services
+                .social_challenge
+                .fetch_social_challenge_as_customer(FetchSocialChallengeAsCustomerInput {
+                    customer_account_id: &account.id,
+                    social_challenge_id: &social_challenge_id,
+                })
+                .await?;
+            let evaluation = services
+                .social_challenge
+                .evaluate_social_challenge(EvaluateSocialChallengeInput {
+                    customer_account_id: &account.id,
+                    challenge: &social_challenge,
+                    now: services.recovery.cur_time(),
+                })
+                .await?;
+
+            // Source needs to match account's active auth keys
+            if account.common_fields.active_auth_keys_id != destination.source_auth_keys_id {
+                return Err(RecoveryError::InvalidRecoverySource);
+            }
+
+            // Validate destination
+            let account_auth_keys = account
+                .active_auth_keys()
+                .ok_or(RecoveryError::NoActiveAuthKeysError)?;
+            validate_destination(
+                services,
+                &account.id,
+                account_auth_keys,
+                Factor::App,
+                &destination,
+            )
+            .await?;
+
+            let account_id = self.account.clone().id;
+            let now = services.recovery.cur_time();
+
+            // If there's been a contested recovery within 30 days, require comms verification
+            if self.active_contest {
+                let scope = CommsVerificationScope::DelayNotifyActor(actor);
+                services
+                    .comms_verification
+                    .consume_verification_for_scope(ConsumeVerificationForScopeInput {
+                        account_id: account_id.clone(),
+                        scope: scope.clone(),
+                    })
+                    .await?;
+            }
+
+            let recovery_type = RecoveryType::SocialRecovery;
+            let requirements = RecoveryRequirements {
+                delay_notify_requirements: None,
+                social_recovery_requirements: Some(SocialRecoveryRequirements {
+                    social_challenge_id,
+                    required_responses: evaluation.required_weight as usize,
+                }),
+            };
+
+            let recovery_action = RecoveryAction {
+                delay_notify_action: None,
+                social_recovery_action: Some(SocialRecoveryAction {
+                    destination: destination.clone(),
+                }),
+            };
+
+            let new_recovery = WalletRecovery {
+                account_id: account_id.clone(),
+                created_at: now,
+                recovery_status: RecoveryStatus::Pending,
+                recovery_type,
+                recovery_type_time: format!("{}:{}", recovery_type, now.format(&Rfc3339).unwrap()),
+                requirements,
+                recovery_action,
+                destination_app_auth_pubkey: Some(destination.app_auth_pubkey),
+                destination_hardware_auth_pubkey: Some(destination.hardware_auth_pubkey),
+                destination_recovery_auth_pubkey: destination.recovery_auth_pubkey,
+                updated_at: now,
+            };
+            services.recovery.create(&new_recovery).await?;
+
+            services
+                .recovery_history
+                .record_recovery_history_entry(RecordRecoveryHistoryEntryInput {
+                    account_id: &account_id,
+                    action: RecoveryHistoryAction::SocialRecoveryCreated,
+                    actor_account_id: &account_id,
+                    app_signed: key_proof.app_signed,
+                    hw_signed: key_proof.hw_signed,
+                })
+                .await;
+
+            // The App is lost, so turn off Mobile Pay
+            services
+                .account
+                .fetch_and_update_spend_limit(FetchAndUpdateSpendingLimitInput {
+                    account_id: &account_id,
+                    new_spending_limit: account.spending_limit.map_or_else(
+                        || None,
+                        |old_limit| {
+                            Some(SpendingLimit {
+                                active: false,
+                                ..old_limit
+                            })
+                        },
+                    ),
+                })
+                .await?;
+
+            let payload = NotificationPayloadBuilder::default()
+                .social_recovery_initiated_payload(Some(SocialRecoveryInitiatedPayload {
+                    initiation_time: new_recovery.created_at,
+                }))
+                .build()
+                .map_err(|_| RecoveryError::GenerateNotificationPayloadError)?;
+
+            services
+                .notification
+                .send_notification(SendNotificationInput {
+                    account_id: &account_id,
+                    payload_type: NotificationPayloadType::SocialRecoveryInitiated,
+                    payload: &payload,
+                    only_touchpoints: None,
+                })
+                .await
+                .map_err(|_| RecoveryError::SendNotificationError)?;
+
+            metrics::SOCIAL_RECOVERY_CREATED.add(
+                1,
+                &[KeyValue::new(
+                    metrics::CREATED_DURING_CONTEST_KEY,
+                    self.active_contest,
+                )],
+            );
+
+            let active_contest = self.active_contest;
+            Ok(Transition::next(
+                self,
+                SocialChallengeCreatedState {
+                    recovery: new_recovery,
+                    responses: evaluation.satisfied_weight as usize,
+                    active_contest,
+                },
+            ))
+        } else if let RecoveryEvent::CheckSocialChallengeThreshold = event {
+            let recovery = self
+                .recovery
+                .clone()
+                .ok_or(RecoveryError::NoExistingRecovery)?;
+            let requirements = recovery
+                .requirements
+                .social_recovery_requirements
+                .as_ref()
+                .ok_or(RecoveryError::MalformedRecoveryRequirements)?;
+
+            let (responses, active_contest) = (self.responses, self.active_contest);
+            if responses < requirements.required_responses {
+                return Ok(Transition::next(
+                    self,
+                    SocialChallengeCreatedState {
+                        recovery,
+                        responses,
+                        active_contest,
+                    },
+                ));
+            }
+
+            let account = self.account.clone();
+            Ok(Transition::next(
+                self,
+                ThresholdRespondedState {
+                    account,
+                    recovery,
+                    responses,
+                    active_contest,
+                },
+            ))
+        } else if let RecoveryEvent::SocialChallengeResponded { first_response } = event {
+            // Social challenges can be used without a social recovery, in which case
+            //   there's nothing to do
+            let Some(recovery) = self.recovery.clone() else {
+                return Ok(Transition::Complete(Ok(self)));
+            };
+            let requirements = recovery
+                .requirements
+                .social_recovery_requirements
+                .as_ref()
+                .ok_or(RecoveryError::MalformedRecoveryRequirements)?;
+
+            let (responses, active_contest) = (self.responses, self.active_contest);
+            if responses < requirements.required_responses {
+                return Ok(Transition::next(
+                    self,
+                    SocialChallengeCreatedState {
+                        recovery,
+                        responses,
+                        active_contest,
+                    },
+                ));
+            }
+
+            // Weighted responses can overshoot the threshold, so only notify for the
+            //   response that crossed it. A repeated response replaces one that was already
+            //   counted, so it can't be the one that crossed it.
+            if first_response && self.satisfied_by_latest_response {
+                let payload = NotificationPayloadBuilder::default()
+                    .social_recovery_completed_payload(Some(SocialRecoveryCompletedPayload {
+                        initiation_time: recovery.created_at,
+                    }))
+                    .build()
+                    .map_err(|_| RecoveryError::GenerateNotificationPayloadError)?;
+
+                services
+                    .notification
+                    .send_notification(SendNotificationInput {
+                        account_id: &self.account.id,
+                        payload_type: NotificationPayloadType::SocialRecoveryCompleted,
+                        payload: &payload,
+                        only_touchpoints: None,
+                    })
+                    .await
+                    .map_err(|_| RecoveryError::SendNotificationError)?;
+            }
+
+            let account = self.account.clone();
+            Ok(Transition::next(
+                self,
+                ThresholdRespondedState {
+                    account,
+                    recovery,
+                    responses,
+                    active_contest,
+                },
+            ))
+        } else if let RecoveryEvent::CancelRecovery { key_proof } = event {
+            let recovery = self
+                .recovery
+                .as_ref()
+                .ok_or(RecoveryError::NoExistingRecovery)?;
+
+            // Cancelling with the App that's supposedly lost contests the recovery
+            let actor = key_proof.to_actor(ToActorStrategy::PreferNonLostFactor(Factor::App))?;
+            let is_contesting_recovery = actor == Factor::App;
+            let account_id = self.account.clone().id;
+
+            if is_contesting_recovery && self.active_contest {
+                let scope = CommsVerificationScope::DelayNotifyActor(actor);
+                services
+                    .comms_verification
+                    .consume_verification_for_scope(ConsumeVerificationForScopeInput {
+                        account_id: account_id.clone(),
+                        scope: scope.clone(),
+                    })
+                    .await?;
+            }
+
+            let status = if is_contesting_recovery {
+                RecoveryStatus::CanceledInContest
+            } else {
+                RecoveryStatus::Canceled
+            };
+
+            services
+                .recovery
+                .complete((recovery.account_id.clone(), recovery.created_at), status)
+                .await?;
+
+            let action = if is_contesting_recovery {
+                RecoveryHistoryAction::SocialRecoveryContested
+            } else {
+                RecoveryHistoryAction::SocialRecoveryCanceled
+            };
+            services
+                .recovery_history
+                .record_recovery_history_entry(RecordRecoveryHistoryEntryInput {
+                    account_id: &account_id,
+                    action,
+                    actor_account_id: &account_id,
+                    app_signed: key_proof.app_signed,
+                    hw_signed: key_proof.hw_signed,
+                })
+                .await;
+
+            let payload = NotificationPayloadBuilder::default()
+                .social_recovery_canceled_payload(Some(SocialRecoveryCanceledPayload {
+                    initiation_time: recovery.created_at,
+                }))
+                .build()
+                .map_err(|_| RecoveryError::GenerateNotificationPayloadError)?;
+
+            services
+                .notification
+                .send_notification(SendNotificationInput {
+                    account_id: &account_id,
+                    payload_type: NotificationPayloadType::SocialRecoveryCanceled,
+                    payload: &payload,
+                    only_touchpoints: None,
+                })
+                .await
+                .map_err(|_| RecoveryError::SendNotificationError)?;
+
+            metrics::SOCIAL_RECOVERY_CANCELED.add(
+                1,
+                &[
+                    KeyValue::new(metrics::CREATED_DURING_CONTEST_KEY, self.active_contest),
+                    KeyValue::new(metrics::CANCELED_IN_CONTEST_KEY, is_contesting_recovery),
+                ],
+            );
+
+            Ok(Transition::next(self, CanceledRecoveryState {}))
+        } else {
+            Err(RecoveryError::InvalidTransition)
+        }
+    }
+}
+
+impl TransitionTo<CanceledRecoveryState> for CurrentSocialRecoveryState {}
+impl TransitionTo<SocialChallengeCreatedState> for CurrentSocialRecoveryState {}
+impl TransitionTo<ThresholdRespondedState> for CurrentSocialRecoveryState {}
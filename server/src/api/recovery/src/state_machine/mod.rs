@@ -13,12 +13,16 @@ use time::serde::rfc3339;
 use time::OffsetDateTime;
 use tracing::{event, Level};
 use types::account::identifiers::AccountId;
+use types::recovery::social::challenge::SocialChallengeId;
 use utoipa::ToSchema;
 
 use crate::entities::{RecoveryStatus, RecoveryType};
 use crate::{
     entities::RecoveryDestination, error::RecoveryError,
     repository::Repository as RecoveryRepository,
+    service::cancellation_token::Service as CancellationTokenService,
+    service::history::Service as RecoveryHistoryService,
+    service::social::challenge::Service as SocialChallengeService,
 };
 
 use self::start_recovery::StartRecoveryState;
@@ -31,6 +35,11 @@ pub mod pending_recovery; // TODO: [W-774] Update visibility of struct after mig
 pub mod rotated_keyset; // TODO: [W-774] Update visibility of struct after migration
 pub(crate) mod start_recovery; // TODO: [W-774] Update visibility of struct after migration
 
+// Social Recovery
+pub(crate) mod current_social_recovery;
+pub(crate) mod social_challenge_created;
+pub(crate) mod threshold_responded;
+
 pub(crate) const CONTEST_LOOKBACK_DAYS: i64 = 30;
 
 pub struct RecoveryServices<'a> {
@@ -38,6 +47,9 @@ pub struct RecoveryServices<'a> {
     pub recovery: &'a RecoveryRepository,
     pub notification: &'a NotificationService,
     pub comms_verification: &'a CommsVerificationService,
+    pub social_challenge: &'a SocialChallengeService,
+    pub recovery_history: &'a RecoveryHistoryService,
+    pub cancellation_token: &'a CancellationTokenService,
 }
 
 pub type BoxedRecoveryState = Box<dyn RecoveryState>;
@@ -59,12 +71,26 @@ pub enum RecoveryEvent {
     CancelRecovery {
         key_proof: KeyClaims,
     },
+    CancelRecoveryWithToken {
+        recovery_created_at: OffsetDateTime,
+    },
     RotateKeyset {
         user_pool_service: UserPoolService,
     },
     UpdateDelayForTestAccountRecovery {
         delay_period_num_sec: Option<i64>,
     },
+    CheckSocialRecoveryState,
+    CreateSocialRecovery {
+        account: FullAccount,
+        destination: RecoveryDestination,
+        social_challenge_id: SocialChallengeId,
+        key_proof: KeyClaims,
+    },
+    CheckSocialChallengeThreshold,
+    SocialChallengeResponded {
+        first_response: bool,
+    },
 }
 
 /// Represents result of state execution and which state to transition to next.
@@ -123,6 +149,7 @@ pub trait TransitioningRecoveryState: RecoveryState {
     ) -> Result<Transition, RecoveryError>;
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn run_recovery_fsm(
     account_id: AccountId,
     events: Vec<RecoveryEvent>,
@@ -130,6 +157,9 @@ pub async fn run_recovery_fsm(
     recovery_service: &RecoveryRepository,
     notification_service: &NotificationService,
     comms_verification_service: &CommsVerificationService,
+    social_challenge_service: &SocialChallengeService,
+    recovery_history_service: &RecoveryHistoryService,
+    cancellation_token_service: &CancellationTokenService,
 ) -> Result<BoxedRecoveryState, ApiError> {
     let mut state: BoxedRecoveryState = Box::new(StartRecoveryState { account_id });
     let iter = events.iter();
@@ -138,6 +168,9 @@ pub async fn run_recovery_fsm(
         recovery: recovery_service,
         notification: notification_service,
         comms_verification: comms_verification_service,
+        social_challenge: social_challenge_service,
+        recovery_history: recovery_history_service,
+        cancellation_token: cancellation_token_service,
     };
 
     for ref mut iter in iter {
@@ -180,38 +213,64 @@ pub struct PendingDelayNotifyRecovery {
     pub auth_keys: FullAccountAuthKeysPayload,
 }
 
+#[derive(Serialize, Deserialize, Debug, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SocialRecoveryResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_social_recovery: Option<PendingSocialRecovery>,
+    pub active_contest: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PendingSocialRecovery {
+    #[serde(with = "rfc3339")]
+    pub start_time: OffsetDateTime,
+    pub social_challenge_id: SocialChallengeId,
+    pub required_responses: usize,
+    pub responses: usize,
+    pub auth_keys: FullAccountAuthKeysPayload,
+}
+
 async fn has_recent_contested_delay_notify(
     services: &RecoveryServices<'_>,
     account_id: &AccountId,
     since: OffsetDateTime,
 ) -> Result<bool, RecoveryError> {
-    let recent_contested = services
-        .recovery
-        .fetch_by_status_since(
-            account_id,
-            RecoveryType::DelayAndNotify,
-            RecoveryStatus::CanceledInContest,
-            since,
-        )
-        .await?;
-
-    let Some(recent_contested) = recent_contested else {
-        return Ok(false);
-    };
+    // A contest of either recovery type counts against both
+    for recovery_type in [RecoveryType::DelayAndNotify, RecoveryType::SocialRecovery] {
+        let recent_contested = services
+            .recovery
+            .fetch_by_status_since(
+                account_id,
+                recovery_type,
+                RecoveryStatus::CanceledInContest,
+                since,
+            )
+            .await?;
 
-    let Some(action) = recent_contested.recovery_action.delay_notify_action else {
-        return Err(RecoveryError::MalformedRecoveryAction);
-    };
+        let Some(recent_contested) = recent_contested else {
+            continue;
+        };
 
-    let account = services
-        .account
-        .fetch_account(FetchAccountInput { account_id })
-        .await?;
-    let Account::Full(full_account) = account else {
-        return Err(RecoveryError::SignPSBT);
-    };
+        let Some(destination) = recent_contested.get_destination() else {
+            return Err(RecoveryError::MalformedRecoveryAction);
+        };
+
+        let account = services
+            .account
+            .fetch_account(FetchAccountInput { account_id })
+            .await?;
+        let Account::Full(full_account) = account else {
+            return Err(RecoveryError::SignPSBT);
+        };
+
+        // We only consider contested recoveries if its source auth key id is the currently
+        //   active auth key id
+        if full_account.common_fields.active_auth_keys_id == destination.source_auth_keys_id {
+            return Ok(true);
+        }
+    }
 
-    // We only consider contested recoveries if its source auth key id is the currently
-    //   active auth key id
-    Ok(full_account.common_fields.active_auth_keys_id == action.destination.source_auth_keys_id)
+    Ok(false)
 }
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::{entities::WalletRecovery, state_machine::SocialRecoveryResponse};
+
+use super::{
+    current_social_recovery::pending_social_recovery, RecoveryError, RecoveryEvent,
+    RecoveryServices, RecoveryStateResponse, Transition, TransitioningRecoveryState,
+};
+
+/// A social recovery whose challenge is still waiting on Trusted Contact responses.
+pub(crate) struct SocialChallengeCreatedState {
+    pub(crate) recovery: WalletRecovery,
+    pub(crate) responses: usize,
+    pub(crate) active_contest: bool,
+}
+
+#[async_trait]
+impl RecoveryStateResponse for SocialChallengeCreatedState {
+    fn response(self: Box<Self>) -> serde_json::Value {
+        serde_json::json!(SocialRecoveryResponse {
+            pending_social_recovery: pending_social_recovery(&self.recovery, self.responses),
+            active_contest: self.active_contest,
+        })
+    }
+}
+
+#[async_trait]
+impl TransitioningRecoveryState for SocialChallengeCreatedState {
+    async fn next_transition_or_err(
+        self: Box<Self>,
+        event: RecoveryEvent,
+        _services: &RecoveryServices,
+    ) -> Result<Transition, RecoveryError> {
+        if let RecoveryEvent::CheckEligibleForCompletion { .. } = event {
+            Err(RecoveryError::SocialChallengeThresholdNotMet)
+        } else {
+            Ok(Transition::Complete(Ok(self)))
+        }
+    }
+}
@@ -3,10 +3,18 @@ use async_trait::async_trait;
 use time::Duration;
 use types::account::identifiers::AccountId;
 
-use crate::{entities::RecoveryType, error::RecoveryError};
+use crate::{
+    entities::RecoveryType,
+    error::RecoveryError,
+    service::social::challenge::{
+        evaluate_social_challenge::EvaluateSocialChallengeInput,
+        fetch_social_challenge::FetchSocialChallengeAsCustomerInput,
+    },
+};
 
 use super::{
-    current_account_recovery::CurrentAccountRecoveryState, has_recent_contested_delay_notify,
+    current_account_recovery::CurrentAccountRecoveryState,
+    current_social_recovery::CurrentSocialRecoveryState, has_recent_contested_delay_notify,
     pending_recovery::PendingRecoveryState, RecoveryEvent, RecoveryServices, RecoveryStateResponse,
     Transition, TransitionTo, TransitioningRecoveryState, CONTEST_LOOKBACK_DAYS,
 };
@@ -69,6 +77,60 @@ impl TransitioningRecoveryState for StartRecoveryState {
                     active_contest,
                 },
             ))
+        } else if let RecoveryEvent::CheckSocialRecoveryState = event {
+            let recovery = services
+                .recovery
+                .fetch_pending(&self.account_id, RecoveryType::SocialRecovery)
+                .await?;
+
+            // Same lookback as for delay and notify; see above.
+            let since = if let Some(recovery) = recovery.as_ref() {
+                recovery.created_at - Duration::days(CONTEST_LOOKBACK_DAYS)
+            } else {
+                services.recovery.cur_time() - Duration::days(CONTEST_LOOKBACK_DAYS)
+            };
+
+            let active_contest =
+                has_recent_contested_delay_notify(services, &self.account_id, since).await?;
+
+            let evaluation = if let Some(recovery) = recovery.as_ref() {
+                let requirements = recovery
+                    .requirements
+                    .social_recovery_requirements
+                    .as_ref()
+                    .ok_or(RecoveryError::MalformedRecoveryRequirements)?;
+                let challenge = services
+                    .social_challenge
+                    .fetch_social_challenge_as_customer(FetchSocialChallengeAsCustomerInput {
+                        customer_account_id: &self.account_id,
+                        social_challenge_id: &requirements.social_challenge_id,
+                    })
+                    .await?;
+                Some(
+                    services
+                        .social_challenge
+                        .evaluate_social_challenge(EvaluateSocialChallengeInput {
+                            customer_account_id: &self.account_id,
+                            challenge: &challenge,
+                            now: services.recovery.cur_time(),
+                        })
+                        .await?,
+                )
+            } else {
+                None
+            };
+
+            Ok(Transition::next(
+                self,
+                CurrentSocialRecoveryState {
+                    account: full_account,
+                    recovery,
+                    responses: evaluation.map_or(0, |e| e.satisfied_weight as usize),
+                    satisfied_by_latest_response: evaluation
+                        .is_some_and(|e| e.satisfied_by_latest_response),
+                    active_contest,
+                },
+            ))
         } else {
             Err(RecoveryError::InvalidTransition)
         }
@@ -77,3 +139,4 @@ impl TransitioningRecoveryState for StartRecoveryState {
 
 impl TransitionTo<PendingRecoveryState> for StartRecoveryState {}
 impl TransitionTo<CurrentAccountRecoveryState> for StartRecoveryState {}
+impl TransitionTo<CurrentSocialRecoveryState> for StartRecoveryState {}
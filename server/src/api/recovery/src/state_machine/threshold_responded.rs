@@ -0,0 +1,75 @@
+use account::entities::FullAccount;
+use async_trait::async_trait;
+
+use crate::{
+    entities::WalletRecovery, helpers::validate_signatures, state_machine::SocialRecoveryResponse,
+};
+
+use super::{
+    completable_recovery::CompletableRecoveryState,
+    current_social_recovery::pending_social_recovery, RecoveryError, RecoveryEvent,
+    RecoveryServices, RecoveryStateResponse, Transition, TransitionTo, TransitioningRecoveryState,
+};
+
+/// A social recovery whose challenge has been answered by enough Trusted Contacts.
+pub(crate) struct ThresholdRespondedState {
+    pub(crate) account: FullAccount,
+    pub(crate) recovery: WalletRecovery,
+    pub(crate) responses: usize,
+    pub(crate) active_contest: bool,
+}
+
+#[async_trait]
+impl RecoveryStateResponse for ThresholdRespondedState {
+    fn response(self: Box<Self>) -> serde_json::Value {
+        serde_json::json!(SocialRecoveryResponse {
+            pending_social_recovery: pending_social_recovery(&self.recovery, self.responses),
+            active_contest: self.active_contest,
+        })
+    }
+}
+
+#[async_trait]
+impl TransitioningRecoveryState for ThresholdRespondedState {
+    async fn next_transition_or_err(
+        self: Box<Self>,
+        event: RecoveryEvent,
+        _services: &RecoveryServices,
+    ) -> Result<Transition, RecoveryError> {
+        if let RecoveryEvent::CheckEligibleForCompletion {
+            challenge,
+            app_signature,
+            hardware_signature,
+        } = event
+        {
+            let destination = self
+                .recovery
+                .get_destination()
+                .ok_or(RecoveryError::InvalidRecoveryDestination)?;
+            validate_signatures(
+                destination.app_auth_pubkey,
+                destination.hardware_auth_pubkey,
+                destination.recovery_auth_pubkey,
+                &challenge,
+                &app_signature,
+                &hardware_signature,
+            )?;
+
+            let account = self.account.clone();
+            let recovery = self.recovery.clone();
+            let active_contest = self.active_contest;
+            Ok(Transition::next(
+                self,
+                CompletableRecoveryState {
+                    account,
+                    recovery,
+                    active_contest,
+                },
+            ))
+        } else {
+            Ok(Transition::Complete(Ok(self)))
+        }
+    }
+}
+
+impl TransitionTo<CompletableRecoveryState> for ThresholdRespondedState {}
@@ -0,0 +1,113 @@
+use database::{
+    aws_sdk_dynamodb::{error::ProvideErrorMetadata, types::AttributeValue},
+    ddb::{try_from_items, try_to_attribute_val, DDBService, DatabaseError},
+};
+use time::OffsetDateTime;
+use tracing::{event, instrument, Level};
+use types::{currencies::CurrencyCode, exchange_rate::history::ExchangeRateSnapshot};
+
+use super::{Repository, PARTITION_KEY, SORT_KEY};
+
+impl Repository {
+    /// Fetches the snapshots for a currency between `from` and `to` inclusive, oldest first.
+    #[instrument(skip(self))]
+    pub async fn fetch_range(
+        &self,
+        currency_code: &CurrencyCode,
+        from: OffsetDateTime,
+        to: OffsetDateTime,
+    ) -> Result<Vec<ExchangeRateSnapshot>, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let currency_code_attr: AttributeValue =
+            try_to_attribute_val(currency_code, database_object)?;
+
+        let mut exclusive_start_key = None;
+        let mut result = Vec::new();
+
+        loop {
+            let item_output = self
+                .connection
+                .client
+                .query()
+                .table_name(table_name.clone())
+                .key_condition_expression(format!(
+                    "{PARTITION_KEY} = :{PARTITION_KEY} AND {SORT_KEY} BETWEEN :from AND :to"
+                ))
+                .expression_attribute_values(format!(":{PARTITION_KEY}"), currency_code_attr.clone())
+                .expression_attribute_values(
+                    ":from",
+                    AttributeValue::N(from.unix_timestamp().to_string()),
+                )
+                .expression_attribute_values(":to", AttributeValue::N(to.unix_timestamp().to_string()))
+                .scan_index_forward(true)
+                .set_exclusive_start_key(exclusive_start_key.clone())
+                .send()
+                .await
+                .map_err(|err| {
+                    let service_err = err.into_service_error();
+                    event!(
+                        Level::ERROR,
+                        "Could not fetch exchange rate snapshots for {currency_code} with err: {service_err:?} and message: {:?}",
+                        service_err.message(),
+                    );
+                    DatabaseError::FetchError(database_object)
+                })?;
+
+            let mut snapshots: Vec<ExchangeRateSnapshot> =
+                try_from_items(item_output.items().to_owned(), database_object)?;
+            result.append(&mut snapshots);
+
+            if let Some(last_evaluated_key) = item_output.last_evaluated_key() {
+                exclusive_start_key = Some(last_evaluated_key.to_owned());
+            } else {
+                break;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Fetches the most recent snapshot for a currency taken at or before `at`, if there is one.
+    #[instrument(skip(self))]
+    pub async fn fetch_latest_at_or_before(
+        &self,
+        currency_code: &CurrencyCode,
+        at: OffsetDateTime,
+    ) -> Result<Option<ExchangeRateSnapshot>, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let currency_code_attr: AttributeValue =
+            try_to_attribute_val(currency_code, database_object)?;
+
+        let item_output = self
+            .connection
+            .client
+            .query()
+            .table_name(table_name)
+            .key_condition_expression(format!(
+                "{PARTITION_KEY} = :{PARTITION_KEY} AND {SORT_KEY} <= :at"
+            ))
+            .expression_attribute_values(format!(":{PARTITION_KEY}"), currency_code_attr)
+            .expression_attribute_values(":at", AttributeValue::N(at.unix_timestamp().to_string()))
+            .scan_index_forward(false)
+            .limit(1)
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not fetch exchange rate snapshot for {currency_code} at {at} with err: {service_err:?} and message: {:?}",
+                    service_err.message(),
+                );
+                DatabaseError::FetchError(database_object)
+            })?;
+
+        let snapshots: Vec<ExchangeRateSnapshot> =
+            try_from_items(item_output.items().to_owned(), database_object)?;
+        Ok(snapshots.into_iter().next())
+    }
+}
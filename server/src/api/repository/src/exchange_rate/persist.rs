@@ -0,0 +1,36 @@
+use database::{
+    aws_sdk_dynamodb::error::ProvideErrorMetadata,
+    ddb::{try_to_item, DDBService, DatabaseError},
+};
+use tracing::{event, instrument, Level};
+use types::exchange_rate::history::ExchangeRateSnapshot;
+
+use super::Repository;
+
+impl Repository {
+    /// Persists a snapshot, replacing any existing snapshot for the same currency and timestamp.
+    #[instrument(skip(self))]
+    pub async fn persist(&self, snapshot: &ExchangeRateSnapshot) -> Result<(), DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+        let item = try_to_item(snapshot, database_object)?;
+
+        self.connection
+            .client
+            .put_item()
+            .table_name(table_name)
+            .set_item(Some(item))
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not persist exchange rate snapshot: {service_err:?} with message: {:?}",
+                    service_err.message()
+                );
+                DatabaseError::PersistenceError(database_object)
+            })?;
+        Ok(())
+    }
+}
@@ -1,5 +1,14 @@
 #[cfg(feature = "consent")]
 pub mod consent;
 
+#[cfg(feature = "exchange_rate")]
+pub mod exchange_rate;
+
+#[cfg(feature = "onboarding_signal")]
+pub mod onboarding_signal;
+
 #[cfg(feature = "recovery")]
 pub mod recovery;
+
+#[cfg(feature = "transaction_history")]
+pub mod transaction_history;
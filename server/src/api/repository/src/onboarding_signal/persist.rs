@@ -0,0 +1,37 @@
+use database::{
+    aws_sdk_dynamodb::error::ProvideErrorMetadata,
+    ddb::{try_to_item, DDBService, DatabaseError},
+};
+use tracing::{event, instrument, Level};
+use types::account::risk::OnboardingSignal;
+
+use super::Repository;
+
+impl Repository {
+    /// Persists a signal, replacing any earlier one for the same key and account so that an
+    /// account is only counted once per identifier.
+    #[instrument(skip(self, signal))]
+    pub async fn persist(&self, signal: &OnboardingSignal) -> Result<(), DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+        let item = try_to_item(signal, database_object)?;
+
+        self.connection
+            .client
+            .put_item()
+            .table_name(table_name)
+            .set_item(Some(item))
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not persist onboarding signal: {service_err:?} with message: {:?}",
+                    service_err.message()
+                );
+                DatabaseError::PersistenceError(database_object)
+            })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use database::{
+    aws_sdk_dynamodb::{
+        error::ProvideErrorMetadata,
+        types::{AttributeValue, ReturnValue},
+    },
+    ddb::{DDBService, DatabaseError},
+};
+use time::OffsetDateTime;
+use tracing::{event, instrument, Level};
+
+use super::{Repository, EXPIRING_AT_ATTRIBUTE, FAILURES_ATTRIBUTE, PARTITION_KEY};
+
+impl Repository {
+    /// Atomically counts a failed attempt from `source` in the window that starts at
+    /// `window_start`, and returns how many failures that window has seen so far. The counter is
+    /// deleted by the table's TTL at `expiring_at`.
+    #[instrument(skip(self))]
+    pub async fn increment_failures(
+        &self,
+        source: &str,
+        window_start: OffsetDateTime,
+        expiring_at: OffsetDateTime,
+    ) -> Result<u64, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let output = self
+            .connection
+            .client
+            .update_item()
+            .table_name(table_name)
+            .key(
+                PARTITION_KEY,
+                AttributeValue::S(format!("{source}#{}", window_start.unix_timestamp())),
+            )
+            .update_expression(format!(
+                "ADD {FAILURES_ATTRIBUTE} :one SET {EXPIRING_AT_ATTRIBUTE} = :expiring_at"
+            ))
+            .expression_attribute_values(":one", AttributeValue::N("1".to_owned()))
+            .expression_attribute_values(
+                ":expiring_at",
+                AttributeValue::N(expiring_at.unix_timestamp().to_string()),
+            )
+            .return_values(ReturnValue::UpdatedNew)
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not count failed recovery cancellation attempt: {service_err:?} with message: {:?}",
+                    service_err.message()
+                );
+                DatabaseError::UpdateError(database_object)
+            })?;
+
+        output
+            .attributes()
+            .and_then(|attributes| attributes.get(FAILURES_ATTRIBUTE))
+            .and_then(|failures| failures.as_n().ok())
+            .and_then(|failures| failures.parse().ok())
+            .ok_or(DatabaseError::UpdateError(database_object))
+    }
+}
@@ -0,0 +1,60 @@
+use database::{
+    aws_sdk_dynamodb::{error::ProvideErrorMetadata, types::AttributeValue},
+    ddb::{try_from_items, try_to_attribute_val, DDBService, DatabaseError},
+};
+use tracing::{event, instrument, Level};
+use types::{account::identifiers::AccountId, recovery::history::RecoveryHistoryEntry};
+
+use super::{Repository, PARTITION_KEY};
+
+impl Repository {
+    /// Fetches the recovery history for an account, oldest entry first.
+    #[instrument(skip(self))]
+    pub async fn fetch_for_account_id(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Vec<RecoveryHistoryEntry>, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let account_id_attr: AttributeValue = try_to_attribute_val(account_id, database_object)?;
+
+        let mut exclusive_start_key = None;
+        let mut result = Vec::new();
+
+        loop {
+            let item_output = self
+                .connection
+                .client
+                .query()
+                .table_name(table_name.clone())
+                .key_condition_expression(format!("{PARTITION_KEY} = :{PARTITION_KEY}"))
+                .expression_attribute_values(format!(":{PARTITION_KEY}"), account_id_attr.clone())
+                .scan_index_forward(true)
+                .set_exclusive_start_key(exclusive_start_key.clone())
+                .send()
+                .await
+                .map_err(|err| {
+                    let service_err = err.into_service_error();
+                    event!(
+                        Level::ERROR,
+                        "Could not fetch recovery history for account id: {account_id} with err: {service_err:?} and message: {:?}",
+                        service_err.message(),
+                    );
+                    DatabaseError::FetchError(database_object)
+                })?;
+
+            let mut entries: Vec<RecoveryHistoryEntry> =
+                try_from_items(item_output.items().to_owned(), database_object)?;
+            result.append(&mut entries);
+
+            if let Some(last_evaluated_key) = item_output.last_evaluated_key() {
+                exclusive_start_key = Some(last_evaluated_key.to_owned());
+            } else {
+                break;
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,37 @@
+use database::{
+    aws_sdk_dynamodb::error::ProvideErrorMetadata,
+    ddb::{try_to_item, DDBService, DatabaseError},
+};
+use tracing::{event, instrument, Level};
+use types::recovery::history::RecoveryHistoryEntry;
+
+use super::{Repository, SORT_KEY};
+
+impl Repository {
+    /// Appends an entry to the recovery history. Entries are never overwritten.
+    #[instrument(skip(self, entry))]
+    pub async fn persist(&self, entry: &RecoveryHistoryEntry) -> Result<(), DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+        let item = try_to_item(entry, database_object)?;
+
+        self.connection
+            .client
+            .put_item()
+            .table_name(table_name)
+            .set_item(Some(item))
+            .condition_expression(format!("attribute_not_exists({SORT_KEY})"))
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not persist recovery history entry: {service_err:?} with message: {:?}",
+                    service_err.message()
+                );
+                DatabaseError::PersistenceError(database_object)
+            })?;
+        Ok(())
+    }
+}
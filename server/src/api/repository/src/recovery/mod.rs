@@ -1 +1,3 @@
+pub mod cancellation_attempts;
+pub mod history;
 pub mod social;
@@ -4,7 +4,10 @@ use database::{
 };
 use types::{
     account::identifiers::AccountId,
-    recovery::social::challenge::{SocialChallenge, SocialChallengeId},
+    recovery::social::{
+        challenge::{SocialChallenge, SocialChallengeId},
+        policy::RecoveryPolicy,
+    },
 };
 
 use serde::Serialize;
@@ -13,10 +16,15 @@ use types::recovery::social::relationship::{RecoveryRelationship, RecoveryRelati
 
 use super::{
     Repository, SocialRecoveryRow, CODE_IDX, CODE_IDX_PARTITION_KEY, CUSTOMER_IDX,
-    CUSTOMER_IDX_PARTITION_KEY, PARTITION_KEY, TRUSTED_CONTACT_IDX,
+    CUSTOMER_IDX_PARTITION_KEY, PARTITION_KEY, RELATIONSHIP_TYPE_ATTRIBUTE, TRUSTED_CONTACT_IDX,
     TRUSTED_CONTACT_IDX_PARTITION_KEY, TRUSTED_CONTACT_IDX_SORT_KEY,
 };
 
+pub struct RecoveryRelationshipsToSweep {
+    pub invitations: Vec<RecoveryRelationship>,
+    pub unendorsed_trusted_contacts: Vec<RecoveryRelationship>,
+}
+
 pub struct RecoveryRelationshipsForAccount {
     pub invitations: Vec<RecoveryRelationship>,
     pub endorsed_trusted_contacts: Vec<RecoveryRelationship>,
@@ -103,6 +111,30 @@ impl Repository {
         }
     }
 
+    #[instrument(skip(self))]
+    pub async fn fetch_optional_recovery_policy(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Option<RecoveryPolicy>, DatabaseError> {
+        let database_object = self.get_database_object();
+
+        let item_output = self.fetch(account_id).await?;
+
+        match item_output.item {
+            Some(item) => match try_from_item::<_, SocialRecoveryRow>(item, database_object)? {
+                SocialRecoveryRow::Policy(policy) => Ok(Some(policy)),
+                _ => {
+                    event!(
+                        Level::ERROR,
+                        "unexpected row type for recovery policy of {account_id}"
+                    );
+                    Err(DatabaseError::FetchError(database_object))
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
     #[instrument(skip(self))]
     pub async fn fetch_recovery_relationship_for_code(
         &self,
@@ -199,6 +231,7 @@ impl Repository {
             })?;
 
         let items = item_output.items();
+        // A stale relationship doesn't stop the same Trusted Contact from being invited again
         let relationships =
             try_from_items::<_, SocialRecoveryRow>(items.to_owned(), database_object)?
                 .into_iter()
@@ -206,15 +239,14 @@ impl Repository {
                     SocialRecoveryRow::Relationship(relationship) => Some(relationship),
                     _ => None,
                 })
+                .filter(|r| !r.is_inactive())
                 .collect::<Vec<RecoveryRelationship>>();
 
-        if relationships.len() == 1 {
-            return Ok(Some(relationships[0].clone()));
-        } else if items.len() > 1 {
-            return Err(DatabaseError::ObjectNotUnique(database_object));
+        match relationships.as_slice() {
+            [] => Ok(None),
+            [relationship] => Ok(Some(relationship.clone())),
+            _ => Err(DatabaseError::ObjectNotUnique(database_object)),
         }
-
-        Ok(None)
     }
 
     #[instrument(skip(self))]
@@ -274,6 +306,7 @@ impl Repository {
                 RecoveryRelationship::Invitation(_) => invitations.push(r),
                 RecoveryRelationship::Unendorsed(_) => unendorsed_trusted_contacts.push(r),
                 RecoveryRelationship::Endorsed(_) => endorsed_trusted_contacts.push(r),
+                RecoveryRelationship::ArchivedInvitation(_) | RecoveryRelationship::Stale(_) => {}
             });
 
             if let Some(last_evaluated_key) = item_output.last_evaluated_key() {
@@ -321,7 +354,7 @@ impl Repository {
             })
             .collect::<Vec<RecoveryRelationship>>();
 
-            customers.extend(relationships);
+            customers.extend(relationships.into_iter().filter(|r| !r.is_inactive()));
 
             if let Some(last_evaluated_key) = item_output.last_evaluated_key() {
                 exclusive_start_key = Some(last_evaluated_key.to_owned());
@@ -337,4 +370,73 @@ impl Repository {
             customers,
         })
     }
+
+    /// Scans the whole table for relationships that haven't been endorsed yet, which are the ones
+    /// whose lifecycle is managed by the invitation sweeper.
+    #[instrument(skip(self))]
+    pub async fn fetch_recovery_relationships_to_sweep(
+        &self,
+    ) -> Result<RecoveryRelationshipsToSweep, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let (mut invitations, mut unendorsed_trusted_contacts) = (Vec::new(), Vec::new());
+        let mut exclusive_start_key = None;
+
+        loop {
+            let item_output = self
+                .connection
+                .client
+                .scan()
+                .table_name(table_name.clone())
+                .filter_expression("#type IN (:invitation, :unendorsed)")
+                .expression_attribute_names("#type", RELATIONSHIP_TYPE_ATTRIBUTE)
+                .expression_attribute_values(
+                    ":invitation",
+                    try_to_attribute_val("Invitation", database_object)?,
+                )
+                .expression_attribute_values(
+                    ":unendorsed",
+                    try_to_attribute_val("Unendorsed", database_object)?,
+                )
+                .set_exclusive_start_key(exclusive_start_key.clone())
+                .send()
+                .await
+                .map_err(|err| {
+                    let service_err = err.into_service_error();
+                    event!(
+                        Level::ERROR,
+                        "Could not scan recovery relationships: {service_err:?} with message: {:?}",
+                        service_err.message()
+                    );
+                    DatabaseError::FetchError(database_object)
+                })?;
+
+            try_from_items::<_, SocialRecoveryRow>(
+                item_output.items().to_owned(),
+                database_object,
+            )?
+            .into_iter()
+            .for_each(|r| match r {
+                SocialRecoveryRow::Relationship(r @ RecoveryRelationship::Invitation(_)) => {
+                    invitations.push(r)
+                }
+                SocialRecoveryRow::Relationship(r @ RecoveryRelationship::Unendorsed(_)) => {
+                    unendorsed_trusted_contacts.push(r)
+                }
+                _ => {}
+            });
+
+            if let Some(last_evaluated_key) = item_output.last_evaluated_key() {
+                exclusive_start_key = Some(last_evaluated_key.to_owned());
+            } else {
+                break;
+            }
+        }
+
+        Ok(RecoveryRelationshipsToSweep {
+            invitations,
+            unendorsed_trusted_contacts,
+        })
+    }
 }
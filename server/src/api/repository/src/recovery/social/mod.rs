@@ -11,7 +11,9 @@ use database::{
 };
 use serde::{Deserialize, Serialize};
 use tracing::{event, Level};
-use types::recovery::social::{challenge::SocialChallenge, relationship::RecoveryRelationship};
+use types::recovery::social::{
+    challenge::SocialChallenge, policy::RecoveryPolicy, relationship::RecoveryRelationship,
+};
 
 pub mod delete;
 pub mod fetch;
@@ -27,6 +29,9 @@ pub(super) const TRUSTED_CONTACT_IDX: &str = "trusted_contact_account_id_to_cust
 pub(super) const TRUSTED_CONTACT_IDX_PARTITION_KEY: &str = "trusted_contact_account_id";
 const TRUSTED_CONTACT_IDX_SORT_KEY: &str = "customer_account_id";
 
+// Set by serde from the tag on `RecoveryRelationship`
+pub(super) const RELATIONSHIP_TYPE_ATTRIBUTE: &str = "_RecoveryRelationship_type";
+
 pub(super) const CODE_IDX: &str = "by_code";
 pub(super) const CODE_IDX_PARTITION_KEY: &str = "code";
 
@@ -35,6 +40,7 @@ pub(super) const CODE_IDX_PARTITION_KEY: &str = "code";
 enum SocialRecoveryRow {
     Relationship(RecoveryRelationship),
     Challenge(SocialChallenge),
+    Policy(RecoveryPolicy),
 }
 
 #[derive(Clone)]
@@ -6,7 +6,9 @@ use database::{
 };
 use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 use tracing::{event, instrument, Level};
-use types::recovery::social::{challenge::SocialChallenge, relationship::RecoveryRelationship};
+use types::recovery::social::{
+    challenge::SocialChallenge, policy::RecoveryPolicy, relationship::RecoveryRelationship,
+};
 
 use super::{Repository, SocialRecoveryRow};
 
@@ -90,4 +92,23 @@ impl Repository {
 
         Ok(updated_challenge)
     }
+
+    #[instrument(skip(self, policy))]
+    pub async fn persist_recovery_policy(
+        &self,
+        policy: &RecoveryPolicy,
+    ) -> Result<RecoveryPolicy, DatabaseError> {
+        let updated_policy = policy.with_updated_at(OffsetDateTime::now_utc());
+
+        let database_object = self.get_database_object();
+
+        let item = try_to_item(
+            SocialRecoveryRow::Policy(updated_policy.clone()),
+            database_object,
+        )?;
+
+        self.persist(item, policy.updated_at).await?;
+
+        Ok(updated_policy)
+    }
 }
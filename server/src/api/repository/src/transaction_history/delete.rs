@@ -0,0 +1,54 @@
+use database::{
+    aws_sdk_dynamodb::{error::ProvideErrorMetadata, types::AttributeValue},
+    ddb::{try_to_attribute_val, DDBService, DatabaseError},
+};
+use tracing::{event, instrument, Level};
+use types::account::identifiers::AccountId;
+
+use super::{Repository, PARTITION_KEY, SORT_KEY};
+
+impl Repository {
+    /// Deletes an account's record of a transaction, but only if it was recorded as confirmed in
+    /// the given block. A record that has since been rewritten for another block is left alone.
+    #[instrument(skip(self))]
+    pub async fn delete_if_in_block(
+        &self,
+        account_id: &AccountId,
+        txid: &str,
+        block_hash: &str,
+    ) -> Result<(), DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let result = self
+            .connection
+            .client
+            .delete_item()
+            .table_name(table_name)
+            .key(
+                PARTITION_KEY,
+                try_to_attribute_val(account_id, database_object)?,
+            )
+            .key(SORT_KEY, AttributeValue::S(txid.to_string()))
+            .condition_expression("block_hash = :block_hash")
+            .expression_attribute_values(":block_hash", AttributeValue::S(block_hash.to_string()))
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                let service_err = err.into_service_error();
+                if service_err.is_conditional_check_failed_exception() {
+                    return Ok(());
+                }
+                event!(
+                    Level::ERROR,
+                    "Could not delete transaction {txid} for account id: {account_id} with err: {service_err:?} and message: {:?}",
+                    service_err.message()
+                );
+                Err(DatabaseError::PersistenceError(database_object))
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use database::{
+    aws_sdk_dynamodb::{error::ProvideErrorMetadata, types::AttributeValue},
+    ddb::{try_from_item, try_from_items, try_to_attribute_val, DDBService, DatabaseError},
+};
+use tracing::{event, instrument, Level};
+use types::{
+    account::identifiers::AccountId,
+    transaction_history::{AccountTransaction, TransactionHistoryCursor},
+};
+
+use super::{Repository, BLOCK_HEIGHT_INDEX, BLOCK_HEIGHT_SORT_KEY, PARTITION_KEY, SORT_KEY};
+
+impl Repository {
+    /// Fetches an account's record of a single transaction, if there is one.
+    #[instrument(skip(self))]
+    pub async fn fetch(
+        &self,
+        account_id: &AccountId,
+        txid: &str,
+    ) -> Result<Option<AccountTransaction>, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        self.connection
+            .client
+            .get_item()
+            .table_name(table_name)
+            .key(
+                PARTITION_KEY,
+                try_to_attribute_val(account_id, database_object)?,
+            )
+            .key(SORT_KEY, AttributeValue::S(txid.to_string()))
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not fetch transaction {txid} for account id: {account_id} with err: {service_err:?} and message: {:?}",
+                    service_err.message(),
+                );
+                DatabaseError::FetchError(database_object)
+            })?
+            .item
+            .map(|item| try_from_item(item, database_object))
+            .transpose()
+    }
+
+    /// Fetches up to `limit` of an account's transactions, newest first, starting after `cursor`.
+    /// Also returns the cursor for the next page, if there may be one.
+    #[instrument(skip(self))]
+    pub async fn fetch_page(
+        &self,
+        account_id: &AccountId,
+        limit: i32,
+        cursor: Option<&TransactionHistoryCursor>,
+    ) -> Result<(Vec<AccountTransaction>, Option<TransactionHistoryCursor>), DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let account_id_attr: AttributeValue = try_to_attribute_val(account_id, database_object)?;
+        let exclusive_start_key = cursor.map(|cursor| {
+            HashMap::from([
+                (PARTITION_KEY.to_string(), account_id_attr.clone()),
+                (SORT_KEY.to_string(), AttributeValue::S(cursor.txid.clone())),
+                (
+                    BLOCK_HEIGHT_SORT_KEY.to_string(),
+                    AttributeValue::N(cursor.block_height.to_string()),
+                ),
+            ])
+        });
+
+        let item_output = self
+            .connection
+            .client
+            .query()
+            .table_name(table_name)
+            .index_name(BLOCK_HEIGHT_INDEX)
+            .key_condition_expression(format!("{PARTITION_KEY} = :{PARTITION_KEY}"))
+            .expression_attribute_values(format!(":{PARTITION_KEY}"), account_id_attr)
+            .scan_index_forward(false)
+            .limit(limit)
+            .set_exclusive_start_key(exclusive_start_key)
+            .send()
+            .await
+            .map_err(|err| {
+                let service_err = err.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Could not fetch transactions for account id: {account_id} with err: {service_err:?} and message: {:?}",
+                    service_err.message(),
+                );
+                DatabaseError::FetchError(database_object)
+            })?;
+
+        let transactions: Vec<AccountTransaction> =
+            try_from_items(item_output.items().to_owned(), database_object)?;
+        let next_cursor = item_output
+            .last_evaluated_key()
+            .and(transactions.last())
+            .map(AccountTransaction::cursor);
+
+        Ok((transactions, next_cursor))
+    }
+
+    /// Fetches every transaction recorded for an account, in no particular order.
+    #[instrument(skip(self))]
+    pub async fn fetch_all_for_account(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<Vec<AccountTransaction>, DatabaseError> {
+        let table_name = self.get_table_name().await?;
+        let database_object = self.get_database_object();
+
+        let account_id_attr: AttributeValue = try_to_attribute_val(account_id, database_object)?;
+
+        let mut exclusive_start_key = None;
+        let mut result = Vec::new();
+
+        loop {
+            let item_output = self
+                .connection
+                .client
+                .query()
+                .table_name(table_name.clone())
+                .key_condition_expression(format!("{PARTITION_KEY} = :{PARTITION_KEY}"))
+                .expression_attribute_values(format!(":{PARTITION_KEY}"), account_id_attr.clone())
+                .set_exclusive_start_key(exclusive_start_key.clone())
+                .send()
+                .await
+                .map_err(|err| {
+                    let service_err = err.into_service_error();
+                    event!(
+                        Level::ERROR,
+                        "Could not fetch transactions for account id: {account_id} with err: {service_err:?} and message: {:?}",
+                        service_err.message(),
+                    );
+                    DatabaseError::FetchError(database_object)
+                })?;
+
+            let mut transactions: Vec<AccountTransaction> =
+                try_from_items(item_output.items().to_owned(), database_object)?;
+            result.append(&mut transactions);
+
+            if let Some(last_evaluated_key) = item_output.last_evaluated_key() {
+                exclusive_start_key = Some(last_evaluated_key.to_owned());
+            } else {
+                break;
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -1,11 +1,13 @@
 use clap::{Parser, Subcommand};
 use tracing::instrument;
 
+use account::service::FetchAccountInput;
 use database::ddb;
 use database::ddb::DDBService;
 use http_server::config;
 use migration::repository::Repository as MigrationRepository;
 use migration::MigrationError;
+use types::account::identifiers::AccountId;
 use types::notification::NotificationChannel;
 
 #[derive(Parser)]
@@ -25,6 +27,30 @@ pub(crate) enum Commands {
         command: WorkerCommands,
     },
     Migrate,
+    /// Run an operator command against the configured environment
+    Admin {
+        #[clap(subcommand)]
+        command: AdminCommands,
+    },
+}
+
+#[derive(Subcommand)]
+pub(crate) enum AdminCommands {
+    /// Print the onboarding risk assessment stored on an account
+    AccountRisk {
+        /// The account to report on
+        account_id: AccountId,
+    },
+    /// Print on-chain wallet health across all accounts, including funds left on inactive keysets
+    WalletHealth,
+    /// Index account transactions for a range of blocks, e.g. from before the blockchain polling
+    /// worker started recording transaction history
+    BackfillTransactions {
+        /// The height of the first block to index
+        from_height: u64,
+        /// The height of the last block to index
+        to_height: u64,
+    },
 }
 
 #[allow(clippy::upper_case_acronyms)]
@@ -36,6 +62,10 @@ pub(crate) enum WorkerCommands {
     Email,
     /// Run the SMS worker
     Sms,
+    /// Run the Webhook worker
+    Webhook,
+    /// Run the Analytics worker
+    Analytics,
     /// Run the Scheduled Notification worker
     ScheduledNotification {
         /// Number of seconds to sleep per iteration
@@ -54,6 +84,18 @@ pub(crate) enum WorkerCommands {
         #[arg(long, default_value_t = 300, env = "SLEEP_DURATION_SECONDS")]
         sleep_duration_seconds: u64,
     },
+    /// Run the Recovery Relationship Sweeper worker
+    RecoveryRelationshipSweeper {
+        /// Number of seconds to sleep per iteration
+        #[arg(long, default_value_t = 3600, env = "SLEEP_DURATION_SECONDS")]
+        sleep_duration_seconds: u64,
+    },
+    /// Run the Exchange Rate Snapshot worker
+    ExchangeRateSnapshot {
+        /// Number of seconds to sleep per iteration
+        #[arg(long, default_value_t = 300, env = "SLEEP_DURATION_SECONDS")]
+        sleep_duration_seconds: u64,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -79,17 +121,7 @@ async fn main() -> Result<(), Error> {
         }
         Commands::Worker { command } => {
             let profile = None;
-            let bootstrap = server::create_bootstrap(profile).await?;
-            let state = workers::jobs::WorkerState {
-                config: http_server::config::extract(profile).unwrap(),
-                notification_service: bootstrap.services.notification_service,
-                account_service: bootstrap.services.account_service,
-                recovery_service: bootstrap.services.recovery_service,
-                chain_indexer_service: bootstrap.services.chain_indexer_service,
-                address_repo: bootstrap.services.address_repo,
-                sqs: bootstrap.services.sqs,
-                feature_flags_service: bootstrap.services.feature_flags_service,
-            };
+            let state = worker_state(profile).await?;
 
             match command {
                 WorkerCommands::Push => {
@@ -116,6 +148,20 @@ async fn main() -> Result<(), Error> {
                     workers::jobs::customer_notification::handler(&state, NotificationChannel::Sms)
                         .await?;
                 }
+                WorkerCommands::Webhook => {
+                    workers::jobs::customer_notification::handler(
+                        &state,
+                        NotificationChannel::Webhook,
+                    )
+                    .await?;
+                }
+                WorkerCommands::Analytics => {
+                    let tracker =
+                        http_server::config::extract::<analytics::routes::Config>(profile)
+                            .unwrap()
+                            .to_tracker();
+                    workers::jobs::analytics::handler(&state, tracker).await?;
+                }
                 WorkerCommands::BlockchainPolling {
                     sleep_duration_seconds,
                 } => {
@@ -127,6 +173,21 @@ async fn main() -> Result<(), Error> {
                 } => {
                     workers::jobs::metrics::handler(&state, sleep_duration_seconds).await?;
                 }
+                WorkerCommands::RecoveryRelationshipSweeper {
+                    sleep_duration_seconds,
+                } => {
+                    workers::jobs::recovery_relationship_sweeper::handler(
+                        state,
+                        sleep_duration_seconds,
+                    )
+                    .await?;
+                }
+                WorkerCommands::ExchangeRateSnapshot {
+                    sleep_duration_seconds,
+                } => {
+                    workers::jobs::exchange_rate_snapshot::handler(state, sleep_duration_seconds)
+                        .await?;
+                }
             }
         }
         Commands::Migrate => {
@@ -155,6 +216,9 @@ async fn main() -> Result<(), Error> {
                 migration_runner.run_migrations().await?;
             }
         }
+        Commands::Admin { command } => {
+            admin_handler(command).await?;
+        }
     }
 
     Ok(())
@@ -170,3 +234,51 @@ async fn server_handler() -> Result<(), Box<dyn std::error::Error>> {
     .await?;
     Ok(())
 }
+
+async fn worker_state(profile: Option<&str>) -> Result<workers::jobs::WorkerState, Error> {
+    let bootstrap = server::create_bootstrap(profile).await?;
+    Ok(workers::jobs::WorkerState {
+        config: http_server::config::extract(profile).unwrap(),
+        notification_service: bootstrap.services.notification_service,
+        account_service: bootstrap.services.account_service,
+        recovery_service: bootstrap.services.recovery_service,
+        chain_indexer_service: bootstrap.services.chain_indexer_service,
+        address_repo: bootstrap.services.address_repo,
+        sqs: bootstrap.services.sqs,
+        feature_flags_service: bootstrap.services.feature_flags_service,
+        recovery_relationship_service: bootstrap.services.recovery_relationship_service,
+        exchange_rate_service: bootstrap.services.exchange_rate_service,
+        rate_history_service: bootstrap.services.rate_history_service,
+        transaction_history_service: bootstrap.services.transaction_history_service,
+    })
+}
+
+async fn admin_handler(command: AdminCommands) -> Result<(), Box<dyn std::error::Error>> {
+    let state = worker_state(None).await?;
+    match command {
+        AdminCommands::AccountRisk { account_id } => {
+            let account = state
+                .account_service
+                .fetch_account(FetchAccountInput {
+                    account_id: &account_id,
+                })
+                .await?;
+            let report = serde_json::json!({
+                "account_id": account_id,
+                "risk_assessment": account.get_common_fields().risk_assessment,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        AdminCommands::WalletHealth => {
+            let report = workers::wallet_health::measure(&state).await?;
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        }
+        AdminCommands::BackfillTransactions {
+            from_height,
+            to_height,
+        } => {
+            workers::jobs::blockchain_polling::backfill(&state, from_height, to_height).await?;
+        }
+    }
+    Ok(())
+}
@@ -26,10 +26,13 @@ use authn_authz::userpool::UserPoolService;
 use bdk_utils::{TransactionBroadcaster, TransactionBroadcasterTrait};
 use chain_indexer::{
     repository::Repository as ChainIndexerRepository, service::Service as ChainIndexerService,
+    transaction_history::Service as TransactionHistoryService,
 };
 use comms_verification::Service as CommsVerificationService;
 use database::ddb::{self, DDBService};
+use exchange_rate::rate_history::Service as RateHistoryService;
 use exchange_rate::service::Service as ExchangeRateService;
+use fee_estimation::service::Service as FeeEstimationService;
 use http_server::config::Config;
 use http_server::middlewares::identifier_generator::IdentifierGenerator;
 use http_server::middlewares::wsm;
@@ -48,12 +51,19 @@ use notification::address_repo::AddressWatchlistTrait;
 use notification::repository::Repository as NotificationRepository;
 use notification::service::Service as NotificationService;
 use recovery::repository::Repository as RecoveryRepository;
+use recovery::service::cancellation_token::Service as CancellationTokenService;
+use recovery::service::history::Service as RecoveryHistoryService;
 use recovery::service::social::{
     challenge::Service as SocialChallengeService,
     relationship::Service as RecoveryRelationshipService,
 };
 use repository::consent::Repository as ConsentRepository;
+use repository::exchange_rate::Repository as ExchangeRateHistoryRepository;
+use repository::onboarding_signal::Repository as OnboardingSignalRepository;
+use repository::recovery::cancellation_attempts::Repository as RecoveryCancellationAttemptsRepository;
+use repository::recovery::history::Repository as RecoveryHistoryRepository;
 use repository::recovery::social::Repository as SocialRecoveryRepository;
+use repository::transaction_history::Repository as TransactionHistoryRepository;
 pub use routes::axum::axum;
 use wallet_telemetry::{set_global_telemetry, METRICS_REPORTING_PERIOD_SECS};
 
@@ -84,6 +94,9 @@ pub struct Services {
     pub sqs: SqsQueue,
     pub feature_flags_service: feature_flags::service::Service,
     pub exchange_rate_service: ExchangeRateService,
+    pub rate_history_service: RateHistoryService,
+    pub fee_estimation_service: FeeEstimationService,
+    pub transaction_history_service: TransactionHistoryService,
     pub iterable_client: IterableClient,
     pub consent_repository: ConsentRepository,
 }
@@ -108,6 +121,10 @@ pub enum BootstrapError {
     Telemetry(#[from] wallet_telemetry::Error),
     #[error(transparent)]
     Metrics(#[from] metrics::error::MetricsError),
+    #[error(transparent)]
+    NotificationTemplates(#[from] notification::templates::TemplateError),
+    #[error(transparent)]
+    CancellationToken(#[from] recovery::service::cancellation_token::error::ServiceError),
 }
 
 #[derive(Default)]
@@ -143,6 +160,7 @@ pub async fn create_bootstrap_with_overrides(
     let config = Config::new(profile)?;
     set_global_telemetry(&config.wallet_telemetry)?;
     init_tokio_metrics(Duration::from_secs(METRICS_REPORTING_PERIOD_SECS))?;
+    notification::templates::validate()?;
 
     let cognito_connection = config::extract::<authn_authz::userpool::Config>(profile)?
         .to_connection()
@@ -202,6 +220,20 @@ pub async fn create_bootstrap_with_overrides(
         recovery_relationship_service.clone(),
         notification_service.clone(),
     );
+    let recovery_history_repository = RecoveryHistoryRepository::new(ddb.clone());
+    recovery_history_repository
+        .create_table_if_necessary()
+        .await?;
+    let recovery_history_service = RecoveryHistoryService::new(recovery_history_repository);
+    let recovery_cancellation_attempts_repository =
+        RecoveryCancellationAttemptsRepository::new(ddb.clone());
+    recovery_cancellation_attempts_repository
+        .create_table_if_necessary()
+        .await?;
+    let cancellation_token_service = CancellationTokenService::new(
+        config::extract::<recovery::service::cancellation_token::Config>(profile)?,
+        recovery_cancellation_attempts_repository,
+    )?;
     let chain_indexer_repository = ChainIndexerRepository::new(ddb.clone());
     chain_indexer_repository.create_table_if_necessary().await?;
     let chain_indexer_service = ChainIndexerService::new(chain_indexer_repository);
@@ -230,7 +262,28 @@ pub async fn create_bootstrap_with_overrides(
     let comms_verification_service =
         CommsVerificationService::new(account_service.clone(), notification_service.clone()).await;
 
-    let exchange_rate_service = ExchangeRateService::new();
+    let exchange_rate_service = ExchangeRateService::new_with_config(config::extract(profile)?);
+    let exchange_rate_history_repository = ExchangeRateHistoryRepository::new(ddb.clone());
+    exchange_rate_history_repository
+        .create_table_if_necessary()
+        .await?;
+    let rate_history_service = RateHistoryService::new(exchange_rate_history_repository);
+
+    let fee_estimation_service = FeeEstimationService::new();
+
+    let transaction_history_repository = TransactionHistoryRepository::new(ddb.clone());
+    transaction_history_repository
+        .create_table_if_necessary()
+        .await?;
+    let transaction_history_service = TransactionHistoryService::new(
+        transaction_history_repository,
+        rate_history_service.clone(),
+    );
+
+    let onboarding_signal_repository = OnboardingSignalRepository::new(ddb.clone());
+    onboarding_signal_repository
+        .create_table_if_necessary()
+        .await?;
 
     let notification = notification::routes::RouteState(
         notification_service.clone(),
@@ -254,6 +307,10 @@ pub async fn create_bootstrap_with_overrides(
             .twilio
             .to_client(),
         feature_flags.clone(),
+        config::extract::<onboarding::routes::Config>(profile)?
+            .webhook
+            .to_client(),
+        onboarding_signal_repository,
     );
     let mobile_pay = mobile_pay::routes::RouteState(
         config::extract(profile)?,
@@ -265,6 +322,7 @@ pub async fn create_bootstrap_with_overrides(
         exchange_rate_service.clone(),
         signed_psbt_cache_service.clone(),
         feature_flags.clone(),
+        fee_estimation_service.clone(),
     );
     let recovery = recovery::routes::RouteState(
         account_service.clone(),
@@ -276,9 +334,18 @@ pub async fn create_bootstrap_with_overrides(
         recovery_relationship_service.clone(),
         social_challenge_service.clone(),
         feature_flags.clone(),
+        recovery_history_service.clone(),
+        cancellation_token_service,
+    );
+    let exchange_rate = exchange_rate::routes::RouteState(
+        exchange_rate_service.clone(),
+        feature_flags.clone(),
+        rate_history_service.clone(),
     );
-    let exchange_rate =
-        exchange_rate::routes::RouteState(exchange_rate_service.clone(), feature_flags.clone());
+    let fee_estimation =
+        fee_estimation::routes::RouteState(fee_estimation_service.clone(), feature_flags.clone());
+    let transaction_history =
+        chain_indexer::routes::RouteState(transaction_history_service.clone());
     let customer_feedback_config = config::extract::<customer_feedback::routes::Config>(profile)?;
     let customer_feedback = customer_feedback::routes::RouteState(
         account_service.clone(),
@@ -287,13 +354,14 @@ pub async fn create_bootstrap_with_overrides(
     );
     let authentication =
         authn_authz::routes::RouteState(userpool_service.clone(), account_service.clone());
-    let analytics = config::extract::<analytics::routes::Config>(profile)?.to_state();
+    let analytics = config::extract::<analytics::routes::Config>(profile)?.to_state(sqs.clone());
     #[allow(unused_mut)]
     let mut router = Router::new()
         .merge(notification.authed_router())
         .merge(Router::from(mobile_pay.clone()))
         .merge(recovery.authed_router())
         .merge(onboarding.authed_router())
+        .merge(Router::from(transaction_history.clone()))
         .route_layer(middleware::from_fn(authorize_token_for_path));
 
     let recovery_router = Router::new()
@@ -318,6 +386,7 @@ pub async fn create_bootstrap_with_overrides(
 
     router = router
         .merge(exchange_rate.basic_validation_router())
+        .merge(fee_estimation.basic_validation_router())
         .merge(customer_feedback.basic_validation_router())
         .layer(authorizer)
         .merge(authentication.unauthed_router())
@@ -334,6 +403,8 @@ pub async fn create_bootstrap_with_overrides(
             SwaggerEndpoint::from(notification),
             SwaggerEndpoint::from(recovery),
             SwaggerEndpoint::from(exchange_rate),
+            SwaggerEndpoint::from(transaction_history),
+            SwaggerEndpoint::from(fee_estimation),
             SwaggerEndpoint::from(customer_feedback),
             SwaggerEndpoint::from(authentication),
         ]))
@@ -356,6 +427,9 @@ pub async fn create_bootstrap_with_overrides(
             sqs,
             feature_flags_service: feature_flags,
             exchange_rate_service,
+            rate_history_service,
+            fee_estimation_service,
+            transaction_history_service,
             iterable_client,
             consent_repository,
         },
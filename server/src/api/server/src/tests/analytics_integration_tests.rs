@@ -1,6 +1,13 @@
-use analytics::routes::definitions::{Event, EventBundle};
+use std::fs;
+use std::time::Duration;
+
+use analytics::destination::tracker::Tracker;
+use analytics::destination::AnalyticsDestinationType;
+use analytics::pipeline::prepare_events;
+use analytics::routes::definitions::{Action, Event, EventBundle, HardwareInfo};
 use http::StatusCode;
 use prost::Message;
+use workers::jobs::analytics::deliver;
 
 use crate::tests::gen_services;
 
@@ -55,3 +62,74 @@ async fn event_tracking_request_succeeds_with_valid_request_non_empty_events() {
 
     assert_eq!(result.status_code, StatusCode::OK,);
 }
+
+#[tokio::test]
+async fn analytics_worker_delivers_queued_events_to_file() {
+    let path = std::env::temp_dir().join(format!(
+        "analytics-{}.ndjson",
+        types::account::identifiers::AccountId::gen().unwrap()
+    ));
+    let tracker = Tracker::new(
+        AnalyticsDestinationType::File,
+        String::new(),
+        String::new(),
+        path.to_string_lossy().to_string(),
+    );
+
+    let event = Event {
+        event_time: String::from("2024-03-14T12:00:00Z"),
+        action: Action::AppOpenInitialize as i32,
+        app_device_id: String::from("test-app-device-id"),
+        hw_info: Some(HardwareInfo {
+            serial_number: String::from("test-serial-number"),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let queued = prepare_events(EventBundle {
+        events: vec![event; 150],
+    });
+    let messages = vec![
+        serde_json::to_string(&queued).unwrap(),
+        String::from("not-an-event-bundle"),
+    ];
+
+    let delivered = deliver(&tracker, messages, Duration::ZERO).await.unwrap();
+    assert_eq!(delivered, 150);
+
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(contents.lines().count(), 150);
+    assert!(!contents.contains("test-serial-number"));
+}
+
+#[tokio::test]
+async fn analytics_worker_fails_when_events_cannot_be_delivered() {
+    // The destination can't be written to, since its directory doesn't exist.
+    let path = std::env::temp_dir()
+        .join(
+            types::account::identifiers::AccountId::gen()
+                .unwrap()
+                .to_string(),
+        )
+        .join("analytics.ndjson");
+    let tracker = Tracker::new(
+        AnalyticsDestinationType::File,
+        String::new(),
+        String::new(),
+        path.to_string_lossy().to_string(),
+    );
+
+    let queued = prepare_events(EventBundle {
+        events: vec![Event {
+            event_time: String::from("2024-03-14T12:00:00Z"),
+            action: Action::AppOpenInitialize as i32,
+            app_device_id: String::from("test-app-device-id"),
+            ..Default::default()
+        }],
+    });
+    assert_eq!(queued.events.len(), 1);
+    let messages = vec![serde_json::to_string(&queued).unwrap()];
+
+    assert!(deliver(&tracker, messages, Duration::ZERO).await.is_err());
+}
@@ -132,6 +132,10 @@ async fn setup_full_accounts_and_server() -> (
             .set_mock_server(mock_server.base_url()),
         sqs: bootstrap.services.sqs.clone(),
         feature_flags_service: bootstrap.services.feature_flags_service.clone(),
+        recovery_relationship_service: bootstrap.services.recovery_relationship_service.clone(),
+        exchange_rate_service: bootstrap.services.exchange_rate_service.clone(),
+        rate_history_service: bootstrap.services.rate_history_service.clone(),
+        transaction_history_service: bootstrap.services.transaction_history_service.clone(),
     };
     state
         .account_service
@@ -150,6 +154,7 @@ async fn setup_full_accounts_and_server() -> (
                 account_security: HashSet::default(),
                 money_movement: HashSet::from([NotificationChannel::Push]),
                 product_marketing: HashSet::new(),
+                quiet_hours: None,
             },
         )
         .await;
@@ -170,6 +175,7 @@ async fn setup_full_accounts_and_server() -> (
                 account_security: HashSet::default(),
                 money_movement: HashSet::from([NotificationChannel::Push]),
                 product_marketing: HashSet::new(),
+                quiet_hours: None,
             },
         )
         .await;
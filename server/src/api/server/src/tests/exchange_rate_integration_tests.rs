@@ -1,7 +1,11 @@
 use crate::tests::gen_services;
 use crate::tests::requests::axum::TestClient;
 use http::StatusCode;
-use types::currencies::Currency;
+use time::{Duration, OffsetDateTime};
+use types::account::identifiers::AccountId;
+use types::currencies::CurrencyCode::{EUR, JPY};
+use types::currencies::{Currency, RateSource};
+use types::exchange_rate::history::{ExchangeRateSnapshot, ExchangeRateSnapshotSource};
 
 #[tokio::test]
 async fn test_get_currency_definitions() {
@@ -18,7 +22,79 @@ async fn test_get_currency_definitions() {
         .iter()
         .map(|c| c.currency.text_code.clone())
         .collect::<Vec<String>>();
-    Currency::supported_fiat_currencies()
-        .iter()
-        .for_each(|c| assert!(currency_text_codes.contains(&c.currency.text_code)));
+    // Only the currencies the active spot rate provider, Bitstamp, can quote are advertised.
+    assert_eq!(
+        currency_text_codes,
+        Currency::supported_currency_codes_for(&RateSource::Bitstamp)
+            .iter()
+            .map(|c| c.to_string())
+            .collect::<Vec<String>>()
+    );
+    assert!(!currency_text_codes.contains(&JPY.to_string()));
+}
+
+#[tokio::test]
+async fn test_get_price_chart() {
+    let bootstrap = gen_services().await;
+    let repository = bootstrap.services.rate_history_service.repository.clone();
+    let client = TestClient::new(bootstrap.router).await;
+
+    let now = OffsetDateTime::now_utc();
+    let rates = [
+        (60, 40_000.0),
+        (40, 42_000.0),
+        (20, 39_000.0),
+        (1, 41_000.0),
+    ];
+    for (minutes_ago, rate) in rates {
+        repository
+            .persist(&ExchangeRateSnapshot {
+                currency_code: EUR,
+                timestamp: now - Duration::minutes(minutes_ago),
+                rate,
+                source: ExchangeRateSnapshotSource::Spot,
+            })
+            .await
+            .unwrap();
+    }
+    // Outside of the day range, so it should not be charted.
+    repository
+        .persist(&ExchangeRateSnapshot {
+            currency_code: EUR,
+            timestamp: now - Duration::days(2),
+            rate: 10_000.0,
+            source: ExchangeRateSnapshotSource::Backfill,
+        })
+        .await
+        .unwrap();
+
+    let response = client
+        .get_price_chart(&AccountId::gen().unwrap(), "EUR", "day")
+        .await;
+    assert_eq!(response.status_code, StatusCode::OK);
+
+    let buckets = response.body.unwrap().buckets;
+    assert!(!buckets.is_empty());
+    assert!(buckets.windows(2).all(|w| w[0].start < w[1].start));
+    assert_eq!(buckets.first().unwrap().open, 40_000.0);
+    assert_eq!(buckets.last().unwrap().close, 41_000.0);
+    assert_eq!(
+        buckets.iter().map(|b| b.high).fold(f64::MIN, f64::max),
+        42_000.0
+    );
+    assert_eq!(
+        buckets.iter().map(|b| b.low).fold(f64::MAX, f64::min),
+        39_000.0
+    );
+}
+
+#[tokio::test]
+async fn test_get_price_chart_unsupported_currency() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let response = client
+        .get_price_chart(&AccountId::gen().unwrap(), "XYZ", "day")
+        .await;
+    assert_eq!(response.status_code, StatusCode::BAD_REQUEST);
 }
@@ -26,6 +26,7 @@ use account::service::{
     ActivateTouchpointForAccountInput, AddPushTouchpointToAccountInput,
     CreateAccountAndKeysetsInput, CreateLiteAccountInput, FetchAccountInput,
     FetchOrCreateEmailTouchpointInput, FetchOrCreatePhoneTouchpointInput,
+    FetchOrCreateWebhookTouchpointInput,
 };
 use bdk_utils::bdk::bitcoin::bip32::{
     DerivationPath, ExtendedPrivKey, ExtendedPubKey, Fingerprint,
@@ -60,7 +61,7 @@ const RECEIVE_DERIVATION_PATH: &str = "m/0";
 const CHANGE_DERIVATION_PATH: &str = "m/1";
 
 const TEST_APP_AUTH_KEY: &[u8] = &[0xcd; 32];
-const TEST_HW_AUTH_KEY: &[u8] = &[0xab; 32];
+pub(crate) const TEST_HW_AUTH_KEY: &[u8] = &[0xab; 32];
 const TEST_RECOVERY_AUTH_KEY: &[u8] = &[0xef; 32];
 
 pub(crate) fn gen_external_wallet_address() -> AddressInfo {
@@ -310,6 +311,7 @@ pub(crate) async fn create_account(
                 },
             },
             is_test_account: network != Network::BitcoinMain,
+            risk_assessment: None,
         })
         .await
         .unwrap();
@@ -346,6 +348,7 @@ pub(crate) async fn create_lite_account(
             auth_key_id: AuthKeysId::new(Ulid::default()).unwrap(),
             auth: auth.clone(),
             is_test_account,
+            risk_assessment: None,
         })
         .await
         .unwrap();
@@ -494,6 +497,53 @@ pub(crate) async fn create_email_touchpoint(
     .unwrap()
 }
 
+pub(crate) async fn create_webhook_touchpoint(
+    services: &Services,
+    account_id: &AccountId,
+) -> TouchpointId {
+    let touchpoint = services
+        .account_service
+        .fetch_or_create_webhook_touchpoint(FetchOrCreateWebhookTouchpointInput {
+            account_id: account_id.to_owned(),
+            url: "https://monitoring.example.com/bitkey".to_string(),
+            secret: "secret".to_string(),
+        })
+        .await
+        .unwrap();
+
+    let Touchpoint::Webhook { id, .. } = touchpoint else {
+        panic!("Expected a webhook touchpoint");
+    };
+
+    services
+        .account_service
+        .activate_touchpoint_for_account(ActivateTouchpointForAccountInput {
+            account_id: account_id.to_owned(),
+            touchpoint_id: id.clone(),
+        })
+        .await
+        .unwrap();
+
+    let current_notification_preferences = services
+        .notification_service
+        .fetch_notifications_preferences(FetchNotificationsPreferencesInput { account_id })
+        .await
+        .unwrap();
+    services
+        .notification_service
+        .update_notifications_preferences(UpdateNotificationsPreferencesInput {
+            account_id,
+            notifications_preferences: &current_notification_preferences.with_enabled(
+                NotificationCategory::AccountSecurity,
+                NotificationChannel::Webhook,
+            ),
+        })
+        .await
+        .unwrap();
+
+    id
+}
+
 fn create_bdk_wallet(
     app_xprv: &str,
     app_xpub: &str,
@@ -623,14 +673,17 @@ pub(crate) fn generate_delay_and_notify_recovery(
             delay_notify_requirements: DelayNotifyRequirements {
                 lost_factor,
                 delay_end_time,
+                cancellation_token_hash: None,
             }
             .into(),
+            social_recovery_requirements: None,
         },
         recovery_action: RecoveryAction {
             delay_notify_action: DelayNotifyRecoveryAction {
                 destination: destination.clone(),
             }
             .into(),
+            social_recovery_action: None,
         },
         destination_app_auth_pubkey: Some(destination.app_auth_pubkey),
         destination_hardware_auth_pubkey: Some(destination.hardware_auth_pubkey),
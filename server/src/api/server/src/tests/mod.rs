@@ -19,7 +19,9 @@ mod requests;
 mod scheduled_notifications_integration_tests;
 mod send_customer_notifications_integration_tests;
 mod social_challenge_integration_tests;
+mod transaction_history_integration_tests;
 mod transaction_integration_tests;
+mod wallet_health_integration_tests;
 
 #[macro_export]
 macro_rules! tests {
@@ -1,18 +1,33 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::tests;
-use account::entities::{Network, TouchpointPlatform};
+use account::entities::{Factor, Network, TouchpointPlatform};
+use account::service::FetchAccountInput;
 use http::StatusCode;
 use notification::clients::iterable::IterableClient;
-use notification::routes::{SendTestPushData, SetNotificationsPreferencesRequest};
-use notification::service::FetchForAccountInput;
+use notification::entities::NotificationTouchpoint;
+use notification::payloads::recovery_pending_delay_period::RecoveryPendingDelayPeriodPayload;
+use notification::routes::{
+    NotificationsLocaleRequest, NotificationsLocaleResponse, SendTestPushData,
+    SetNotificationsPreferencesRequest,
+};
+use notification::service::{
+    FetchForAccountInput, FetchForCompositeKeyInput, SendNotificationInput,
+};
+use notification::{DeliveryStatus, NotificationPayloadBuilder, NotificationPayloadType};
 use onboarding::routes::AccountAddDeviceTokenRequest;
+use time::OffsetDateTime;
 use types::account::identifiers::AccountId;
 use types::consent::{Consent, NotificationConsentAction};
-use types::notification::{NotificationCategory, NotificationChannel, NotificationsPreferences};
+use types::notification::{
+    Locale, NotificationCategory, NotificationChannel, NotificationsPreferences,
+};
 
 use crate::tests::gen_services;
-use crate::tests::lib::{create_account, create_default_account_with_predefined_wallet};
+use crate::tests::lib::{
+    create_account, create_default_account_with_predefined_wallet, create_email_touchpoint,
+    create_phone_touchpoint,
+};
 use crate::tests::requests::axum::TestClient;
 
 struct SendTestNotificationTestVector {
@@ -82,7 +97,7 @@ async fn twilio_status_callback_test(vector: TwilioStatusCallbackTestVector) {
     let bootstrap = gen_services().await;
     let client = TestClient::new(bootstrap.router).await;
     let response = client
-        .twilio_status_callback(&vector.body, vector.signature)
+        .twilio_status_callback(None, &vector.body, vector.signature)
         .await;
     assert_eq!(response.status(), vector.expected_status);
 }
@@ -111,6 +126,162 @@ tests! {
     },
 }
 
+#[tokio::test]
+async fn test_status_callback_fails_over_undelivered_sms() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+    let notification_service = &bootstrap.services.notification_service;
+
+    let account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+    let phone_touchpoint_id = create_phone_touchpoint(&bootstrap.services, &account.id, true).await;
+
+    notification_service
+        .send_notification(SendNotificationInput {
+            account_id: &account.id,
+            payload_type: NotificationPayloadType::RecoveryPendingDelayPeriod,
+            payload: &NotificationPayloadBuilder::default()
+                .recovery_pending_delay_period_payload(Some(RecoveryPendingDelayPeriodPayload {
+                    initiation_time: OffsetDateTime::now_utc(),
+                    delay_end_time: OffsetDateTime::now_utc(),
+                    lost_factor: Factor::Hw,
+                    cancellation_token: None,
+                }))
+                .build()
+                .unwrap(),
+            only_touchpoints: None,
+        })
+        .await
+        .unwrap();
+    let enqueued_messages = bootstrap.services.sqs.fetch_messages("").await.unwrap();
+    assert_eq!(enqueued_messages.len(), 1);
+
+    // Added after the notification was sent, so it has no sibling on the email touchpoint
+    let email_touchpoint_id = create_email_touchpoint(&bootstrap.services, &account.id, true).await;
+
+    let phone_touchpoint = NotificationTouchpoint::Phone {
+        touchpoint_id: phone_touchpoint_id,
+    };
+    let sms_notification = notification_service
+        .fetch_customer_for_account(FetchForAccountInput {
+            account_id: account.id.clone(),
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|n| n.touchpoint == phone_touchpoint)
+        .expect("SMS notification");
+
+    let response = client
+        .twilio_status_callback(
+            Some(&sms_notification.composite_key()),
+            &HashMap::from([("MessageStatus".into(), "undelivered".into())]),
+            Some("VALID".to_string()),
+        )
+        .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // The same notification moves on to the email touchpoint and is enqueued again
+    let failed_over_notification = notification_service
+        .fetch_pending(FetchForCompositeKeyInput {
+            composite_key: sms_notification.composite_key(),
+        })
+        .await
+        .unwrap()
+        .expect("Failed over notification is pending");
+    assert_eq!(
+        failed_over_notification.touchpoint,
+        NotificationTouchpoint::Email {
+            touchpoint_id: email_touchpoint_id,
+        }
+    );
+    assert_eq!(failed_over_notification.attempts.len(), 1);
+    assert_eq!(
+        failed_over_notification.attempts[0].touchpoint,
+        phone_touchpoint
+    );
+    assert_eq!(
+        failed_over_notification.attempts[0].status,
+        DeliveryStatus::Error
+    );
+    let enqueued_messages = bootstrap.services.sqs.fetch_messages("").await.unwrap();
+    assert_eq!(enqueued_messages.len(), 2);
+}
+
+#[tokio::test]
+async fn test_status_callback_skips_failover_to_sibling_touchpoints() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+    let notification_service = &bootstrap.services.notification_service;
+
+    let account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+    let phone_touchpoint_id = create_phone_touchpoint(&bootstrap.services, &account.id, true).await;
+    let email_touchpoint_id = create_email_touchpoint(&bootstrap.services, &account.id, true).await;
+
+    notification_service
+        .send_notification(SendNotificationInput {
+            account_id: &account.id,
+            payload_type: NotificationPayloadType::RecoveryPendingDelayPeriod,
+            payload: &NotificationPayloadBuilder::default()
+                .recovery_pending_delay_period_payload(Some(RecoveryPendingDelayPeriodPayload {
+                    initiation_time: OffsetDateTime::now_utc(),
+                    delay_end_time: OffsetDateTime::now_utc(),
+                    lost_factor: Factor::Hw,
+                    cancellation_token: None,
+                }))
+                .build()
+                .unwrap(),
+            only_touchpoints: None,
+        })
+        .await
+        .unwrap();
+    let enqueued_messages = bootstrap.services.sqs.fetch_messages("").await.unwrap();
+    assert_eq!(enqueued_messages.len(), 2);
+
+    let phone_touchpoint = NotificationTouchpoint::Phone {
+        touchpoint_id: phone_touchpoint_id,
+    };
+    let sms_notification = notification_service
+        .fetch_customer_for_account(FetchForAccountInput {
+            account_id: account.id.clone(),
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|n| n.touchpoint == phone_touchpoint)
+        .expect("SMS notification");
+
+    let response = client
+        .twilio_status_callback(
+            Some(&sms_notification.composite_key()),
+            &HashMap::from([("MessageStatus".into(), "undelivered".into())]),
+            Some("VALID".to_string()),
+        )
+        .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    // The notification was already sent to the email touchpoint separately, so it isn't failed
+    // over there
+    let failed_notification = notification_service
+        .fetch_customer_for_account(FetchForAccountInput {
+            account_id: account.id.clone(),
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|n| n.unique_id == sms_notification.unique_id)
+        .expect("SMS notification");
+    assert_eq!(failed_notification.touchpoint, phone_touchpoint);
+    assert_eq!(failed_notification.delivery_status, DeliveryStatus::Error);
+    assert_eq!(
+        failed_notification.sibling_touchpoints,
+        vec![NotificationTouchpoint::Email {
+            touchpoint_id: email_touchpoint_id,
+        }]
+    );
+    let enqueued_messages = bootstrap.services.sqs.fetch_messages("").await.unwrap();
+    assert_eq!(enqueued_messages.len(), 2);
+}
+
 #[tokio::test]
 async fn test_notifications_preferences() {
     let bootstrap = gen_services().await;
@@ -152,6 +323,7 @@ async fn test_notifications_preferences() {
                     NotificationChannel::Sms,
                     NotificationChannel::Email,
                 ]),
+                quiet_hours: None,
             },
         )
         .await;
@@ -165,6 +337,7 @@ async fn test_notifications_preferences() {
                 NotificationChannel::Sms,
                 NotificationChannel::Email
             ]),
+            quiet_hours: None,
         }
     );
     assert_eq!(
@@ -203,6 +376,7 @@ async fn test_notifications_preferences() {
             account_security: HashSet::default(),
             money_movement: HashSet::from([NotificationChannel::Push, NotificationChannel::Email]),
             product_marketing: HashSet::from([NotificationChannel::Sms]),
+            quiet_hours: None,
         },
     );
     assert_eq!(
@@ -228,6 +402,7 @@ async fn test_notifications_preferences() {
                     NotificationChannel::Push,
                     NotificationChannel::Sms,
                 ]),
+                quiet_hours: None,
             },
         )
         .await;
@@ -238,6 +413,7 @@ async fn test_notifications_preferences() {
             account_security: HashSet::default(),
             money_movement: HashSet::from([NotificationChannel::Push, NotificationChannel::Sms]),
             product_marketing: HashSet::from([NotificationChannel::Push, NotificationChannel::Sms]),
+            quiet_hours: None,
         }
     );
     assert_eq!(
@@ -258,3 +434,57 @@ async fn test_notifications_preferences() {
     assert_eq!(consents.iter().filter(|c| matches!(c, Consent::Notification(n) if n.action == NotificationConsentAction::OptIn)).count(), 6);
     assert_eq!(consents.iter().filter(|c| matches!(c, Consent::Notification(n) if n.action == NotificationConsentAction::OptOut)).count(), 2);
 }
+
+#[tokio::test]
+async fn test_notifications_locale() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+
+    let get_response = client
+        .get_notifications_locale(&account.id.to_string())
+        .await;
+    assert_eq!(get_response.status_code, StatusCode::OK);
+    assert_eq!(
+        get_response.body.unwrap(),
+        NotificationsLocaleResponse {
+            locale: Locale::EnUs
+        }
+    );
+
+    let set_response = client
+        .set_notifications_locale(
+            &account.id.to_string(),
+            &NotificationsLocaleRequest {
+                locale: Locale::EsUs,
+            },
+        )
+        .await;
+    assert_eq!(set_response.status_code, StatusCode::OK);
+
+    let get_response = client
+        .get_notifications_locale(&account.id.to_string())
+        .await;
+    assert_eq!(
+        get_response.body.unwrap(),
+        NotificationsLocaleResponse {
+            locale: Locale::EsUs
+        }
+    );
+
+    // Changing the locale doesn't touch the rest of the account
+    let updated_account = bootstrap
+        .services
+        .account_service
+        .fetch_full_account(FetchAccountInput {
+            account_id: &account.id,
+        })
+        .await
+        .unwrap();
+    assert_eq!(updated_account.common_fields.locale, Locale::EsUs);
+    assert_eq!(
+        updated_account.common_fields.touchpoints,
+        account.common_fields.touchpoints
+    );
+}
@@ -18,7 +18,7 @@ use account::entities::{
     Factor, FullAccountAuthKeysPayload, LiteAccountAuthKeysPayload, Network as AccountNetwork,
     SpendingKeysetRequest, Touchpoint, TouchpointPlatform, UpgradeLiteAccountAuthKeysPayload,
 };
-use account::service::FetchAccountInput;
+use account::service::{DeactivatePushTouchpointInput, FetchAccountInput};
 use bdk_utils::bdk::bitcoin::Network;
 use bdk_utils::bdk::miniscript::DescriptorPublicKey;
 use comms_verification::TEST_CODE;
@@ -29,6 +29,7 @@ use onboarding::routes::{
     UpgradeAccountRequest,
 };
 use types::account::identifiers::TouchpointId;
+use types::account::risk::RiskDecision;
 
 use crate::tests;
 use crate::tests::gen_services;
@@ -158,6 +159,7 @@ async fn add_device_token_test(vector: AddDeviceTokenTestVector) {
                     platform: _,
                     arn: _,
                     device_token,
+                    active: _,
                 } = t
                 {
                     *device_token == vector.request.device_token
@@ -181,6 +183,60 @@ tests! {
     },
 }
 
+#[tokio::test]
+async fn test_deactivated_push_touchpoint_is_reactivated() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+    let account = create_account(
+        &bootstrap.services,
+        account::entities::Network::BitcoinSignet,
+        None,
+    )
+    .await;
+    let request = AccountAddDeviceTokenRequest {
+        device_token: "device-token".to_owned(),
+        platform: TouchpointPlatform::ApnsTeam,
+    };
+    let account_service = &bootstrap.services.account_service;
+    let account_id = &account.id;
+    let fetch_push_touchpoints = || async move {
+        account_service
+            .fetch_full_account(FetchAccountInput { account_id })
+            .await
+            .unwrap()
+            .common_fields
+            .touchpoints
+            .into_iter()
+            .filter(|t| matches!(t, Touchpoint::Push { .. }))
+            .collect::<Vec<_>>()
+    };
+
+    let response = client
+        .add_device_token(&account.id.to_string(), &request)
+        .await;
+    assert_eq!(response.status_code, StatusCode::OK);
+
+    account_service
+        .deactivate_push_touchpoint(DeactivatePushTouchpointInput {
+            account_id: &account.id,
+            device_token: &request.device_token,
+        })
+        .await
+        .unwrap();
+    let touchpoints = fetch_push_touchpoints().await;
+    assert_eq!(touchpoints.len(), 1);
+    assert!(!touchpoints[0].is_active());
+
+    // Registering the device again reactivates it rather than adding a second touchpoint
+    let response = client
+        .add_device_token(&account.id.to_string(), &request)
+        .await;
+    assert_eq!(response.status_code, StatusCode::OK);
+    let touchpoints = fetch_push_touchpoints().await;
+    assert_eq!(touchpoints.len(), 1);
+    assert!(touchpoints[0].is_active());
+}
+
 enum TouchpointLifecycleTestStep {
     AddPhoneTouchpoint {
         phone_number: String,
@@ -192,6 +248,11 @@ enum TouchpointLifecycleTestStep {
         expected_status: StatusCode,
         expected_num_touchpoints: usize,
     },
+    AddWebhookTouchpoint {
+        url: String,
+        expected_status: StatusCode,
+        expected_num_touchpoints: usize,
+    },
     VerifyTouchpoint {
         use_last_seen_touchpoint_id: bool,
         use_real_verification_code: bool,
@@ -210,6 +271,7 @@ enum TouchpointLifecycleTestStep {
 trait TouchpointTestGetters {
     fn get_email_address(&self) -> String;
     fn get_phone_number(&self) -> String;
+    fn get_webhook_url(&self) -> String;
     fn get_active(&self) -> bool;
 }
 
@@ -230,10 +292,19 @@ impl TouchpointTestGetters for Touchpoint {
         .unwrap()
     }
 
+    fn get_webhook_url(&self) -> String {
+        match self {
+            Touchpoint::Webhook { url, .. } => Some(url.to_owned()),
+            _ => None,
+        }
+        .unwrap()
+    }
+
     fn get_active(&self) -> bool {
         match self {
             Touchpoint::Email { active, .. } => Some(*active),
             Touchpoint::Phone { active, .. } => Some(*active),
+            Touchpoint::Webhook { active, .. } => Some(*active),
             _ => None,
         }
         .unwrap()
@@ -270,7 +341,8 @@ async fn touchpoint_lifecycle_test(vector: TouchpointLifecycleTestVector) {
     for step in vector.steps {
         match step {
             TouchpointLifecycleTestStep::AddPhoneTouchpoint { .. }
-            | TouchpointLifecycleTestStep::AddEmailTouchpoint { .. } => {
+            | TouchpointLifecycleTestStep::AddEmailTouchpoint { .. }
+            | TouchpointLifecycleTestStep::AddWebhookTouchpoint { .. } => {
                 let (req, expected_status, expected_num_touchpoints) = match step {
                     TouchpointLifecycleTestStep::AddPhoneTouchpoint {
                         phone_number,
@@ -294,6 +366,15 @@ async fn touchpoint_lifecycle_test(vector: TouchpointLifecycleTestVector) {
                         expected_status,
                         expected_num_touchpoints,
                     ),
+                    TouchpointLifecycleTestStep::AddWebhookTouchpoint {
+                        url,
+                        expected_status,
+                        expected_num_touchpoints,
+                    } => (
+                        AccountAddTouchpointRequest::Webhook { url: url.clone() },
+                        expected_status,
+                        expected_num_touchpoints,
+                    ),
                     _ => panic!("This is impossible"),
                 };
 
@@ -315,7 +396,12 @@ async fn touchpoint_lifecycle_test(vector: TouchpointLifecycleTestVector) {
                 );
 
                 if actual_response.status_code == StatusCode::OK {
-                    let touchpoint_id = actual_response.body.unwrap().touchpoint_id;
+                    let body = actual_response.body.unwrap();
+                    let touchpoint_id = body.touchpoint_id;
+                    assert_eq!(
+                        body.webhook_secret.is_some(),
+                        matches!(req, AccountAddTouchpointRequest::Webhook { .. })
+                    );
 
                     let account = bootstrap
                         .services
@@ -337,6 +423,9 @@ async fn touchpoint_lifecycle_test(vector: TouchpointLifecycleTestVector) {
                                 AccountAddTouchpointRequest::Phone { phone_number } => {
                                     touchpoint.unwrap().get_phone_number() == phone_number
                                 }
+                                AccountAddTouchpointRequest::Webhook { url } => {
+                                    touchpoint.unwrap().get_webhook_url() == url
+                                }
                             },
                     );
 
@@ -663,6 +752,50 @@ tests! {
             },
         ],
     },
+    test_webhook_touchpoint_lifecycle: TouchpointLifecycleTestVector {
+        onboarding_complete: true,
+        steps: vec![
+            TouchpointLifecycleTestStep::AddWebhookTouchpoint {
+                // Add a non-HTTPS webhook fails
+                url: "http://monitoring.example.com/bitkey".to_owned(),
+                expected_status: StatusCode::BAD_REQUEST,
+                expected_num_touchpoints: 0,
+            },
+            TouchpointLifecycleTestStep::AddWebhookTouchpoint {
+                url: "https://monitoring.example.com/bitkey".to_owned(),
+                expected_status: StatusCode::OK,
+                expected_num_touchpoints: 1,
+            },
+            TouchpointLifecycleTestStep::AddWebhookTouchpoint {
+                // Add a fresh webhook replaces the previous pending webhook
+                url: "https://monitoring.example.com/bitkey/v2".to_owned(),
+                expected_status: StatusCode::OK,
+                expected_num_touchpoints: 1,
+            },
+            TouchpointLifecycleTestStep::ActivateTouchpoint {
+                // Activate webhook without hw sig fails
+                use_last_seen_touchpoint_id: true,
+                expected_status: StatusCode::FORBIDDEN,
+                expected_num_touchpoints: 1,
+                app_signed: true,
+                hw_signed: false,
+            },
+            TouchpointLifecycleTestStep::ActivateTouchpoint {
+                // Activate webhook answers the challenge without a verification code
+                use_last_seen_touchpoint_id: true,
+                expected_status: StatusCode::OK,
+                expected_num_touchpoints: 1,
+                app_signed: true,
+                hw_signed: true,
+            },
+            TouchpointLifecycleTestStep::AddWebhookTouchpoint {
+                // Re-add an active webhook fails
+                url: "https://monitoring.example.com/bitkey/v2".to_owned(),
+                expected_status: StatusCode::CONFLICT,
+                expected_num_touchpoints: 1,
+            },
+        ],
+    },
 }
 
 #[tokio::test]
@@ -1505,3 +1638,46 @@ async fn test_notifications_preferences() {
         }
     );
 }
+
+#[tokio::test]
+async fn test_risk_assessment_is_stored_with_created_account() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let request = CreateAccountRequest::Full {
+        auth: FullAccountAuthKeysPayload {
+            app: create_pubkey(),
+            hardware: create_pubkey(),
+            recovery: Some(create_pubkey()),
+        },
+        spending: SpendingKeysetRequest {
+            network: Network::Signet,
+            app: create_descriptor_keys(Network::Signet).1,
+            hardware: create_descriptor_keys(Network::Signet).1,
+        },
+        is_test_account: true,
+    };
+    let response = client.create_account(&request).await;
+    assert_eq!(response.status_code, StatusCode::OK);
+    let account = bootstrap
+        .services
+        .account_service
+        .fetch_account(FetchAccountInput {
+            account_id: &response.body.unwrap().account_id,
+        })
+        .await
+        .unwrap();
+
+    // The hardware isn't attested by the server, which is recorded without being scored
+    let assessment = account.get_common_fields().risk_assessment.clone().unwrap();
+    assert_eq!(assessment.decision, RiskDecision::Allow);
+    assert_eq!(assessment.score, 0);
+    assert_eq!(
+        assessment
+            .factors
+            .iter()
+            .map(|factor| factor.key.as_str())
+            .collect::<Vec<_>>(),
+        vec!["HARDWARE_ATTESTATION"]
+    );
+}
@@ -1,4 +1,6 @@
-use account::entities::{Factor, FullAccountAuthKeys, FullAccountAuthKeysPayload, Network};
+use account::entities::{
+    Factor, FullAccount, FullAccountAuthKeys, FullAccountAuthKeysPayload, Network,
+};
 use account::service::FetchAccountInput;
 use http_body_util::BodyExt;
 use types::account::identifiers::AccountId;
@@ -12,12 +14,14 @@ use notification::NotificationPayloadType;
 use recovery::entities::{RecoveryDestination, RecoveryStatus, RecoveryType};
 use recovery::error::RecoveryError;
 use recovery::routes::{
-    CompleteDelayNotifyRequest, CreateAccountDelayNotifyRequest,
+    CancelDelayNotifyWithTokenRequest, CompleteDelayNotifyRequest, CreateAccountDelayNotifyRequest,
     SendAccountVerificationCodeRequest, UpdateDelayForTestRecoveryRequest,
-    VerifyAccountVerificationCodeRequest,
+    UpdateRecoveryDelayPeriodRequest, VerifyAccountVerificationCodeRequest,
 };
+use recovery::service::cancellation_token::TEST_TOKEN;
 
 use time::{Duration, OffsetDateTime};
+use types::recovery::history::RecoveryHistoryAction;
 
 use crate::tests;
 use crate::tests::gen_services;
@@ -28,6 +32,7 @@ use crate::tests::lib::{
 };
 use crate::tests::requests::axum::TestClient;
 use crate::tests::requests::Response;
+use crate::Services;
 
 #[derive(Debug)]
 struct CreateDelayNotifyTestVector {
@@ -1277,6 +1282,484 @@ tests! {
     },
 }
 
+#[derive(Debug)]
+struct UpdateRecoveryDelayPeriodTestVector {
+    initial_delay_period_num_sec: Option<i64>,
+    delay_period_num_sec: i64,
+    app_signed: bool,
+    hw_signed: bool,
+    expected_status: StatusCode,
+    expected_delay_period_num_sec: i64,
+    expected_pending: bool,
+}
+
+async fn update_recovery_delay_period_test(vector: UpdateRecoveryDelayPeriodTestVector) {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+    let account_id = account.id.to_string();
+
+    if let Some(initial_delay_period_num_sec) = vector.initial_delay_period_num_sec {
+        let response = client
+            .update_recovery_delay_period(
+                &account_id,
+                &UpdateRecoveryDelayPeriodRequest {
+                    delay_period_num_sec: initial_delay_period_num_sec,
+                },
+                true,
+                true,
+            )
+            .await;
+        assert_eq!(
+            response.status_code,
+            StatusCode::OK,
+            "{}",
+            response.body_string
+        );
+    }
+
+    let response = client
+        .update_recovery_delay_period(
+            &account_id,
+            &UpdateRecoveryDelayPeriodRequest {
+                delay_period_num_sec: vector.delay_period_num_sec,
+            },
+            vector.app_signed,
+            vector.hw_signed,
+        )
+        .await;
+    assert_eq!(
+        response.status_code, vector.expected_status,
+        "{}",
+        response.body_string
+    );
+
+    let response = client.get_recovery_delay_period(&account_id).await;
+    assert_eq!(response.status_code, StatusCode::OK);
+    let delay_period = response.body.unwrap();
+    assert_eq!(
+        delay_period.delay_period_num_sec,
+        vector.expected_delay_period_num_sec
+    );
+    assert_eq!(
+        delay_period.pending_delay_period_num_sec.is_some(),
+        vector.expected_pending
+    );
+
+    let scheduled_notifications = bootstrap
+        .services
+        .notification_service
+        .fetch_scheduled_for_account(FetchForAccountInput {
+            account_id: account.id.clone(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        scheduled_notifications
+            .iter()
+            .any(|n| n.payload_type == NotificationPayloadType::RecoveryDelayPeriodChangePending),
+        vector.expected_pending
+    );
+
+    // New recoveries wait out whichever delay period is currently in effect
+    let response = client
+        .create_delay_notify_recovery(
+            &account_id,
+            &CreateAccountDelayNotifyRequest {
+                lost_factor: Factor::Hw,
+                delay_period_num_sec: None,
+                auth: FullAccountAuthKeysPayload {
+                    app: create_pubkey(),
+                    hardware: create_pubkey(),
+                    recovery: Some(create_pubkey()),
+                },
+            },
+            true,
+            false,
+        )
+        .await;
+    assert_eq!(
+        response.status_code,
+        StatusCode::OK,
+        "{}",
+        response.body_string
+    );
+    let recovery = bootstrap
+        .services
+        .recovery_service
+        .fetch_pending(&account.id, RecoveryType::DelayAndNotify)
+        .await
+        .unwrap()
+        .expect("Existing Recovery for Account");
+    assert_eq!(
+        response.body.unwrap().pending_delay_notify.delay_end_time,
+        recovery.created_at + Duration::seconds(vector.expected_delay_period_num_sec)
+    );
+}
+
+tests! {
+    runner = update_recovery_delay_period_test,
+    test_lengthen_recovery_delay_period: UpdateRecoveryDelayPeriodTestVector {
+        initial_delay_period_num_sec: None,
+        delay_period_num_sec: 600,
+        app_signed: true,
+        hw_signed: true,
+        expected_status: StatusCode::OK,
+        expected_delay_period_num_sec: 600,
+        expected_pending: false,
+    },
+    test_shorten_recovery_delay_period_is_pending: UpdateRecoveryDelayPeriodTestVector {
+        initial_delay_period_num_sec: Some(600),
+        delay_period_num_sec: 30,
+        app_signed: true,
+        hw_signed: true,
+        expected_status: StatusCode::OK,
+        expected_delay_period_num_sec: 600,
+        expected_pending: true,
+    },
+    test_lengthen_recovery_delay_period_clears_pending: UpdateRecoveryDelayPeriodTestVector {
+        initial_delay_period_num_sec: Some(600),
+        delay_period_num_sec: 900,
+        app_signed: true,
+        hw_signed: true,
+        expected_status: StatusCode::OK,
+        expected_delay_period_num_sec: 900,
+        expected_pending: false,
+    },
+    test_recovery_delay_period_below_minimum: UpdateRecoveryDelayPeriodTestVector {
+        initial_delay_period_num_sec: None,
+        delay_period_num_sec: 10,
+        app_signed: true,
+        hw_signed: true,
+        expected_status: StatusCode::BAD_REQUEST,
+        expected_delay_period_num_sec: 20,
+        expected_pending: false,
+    },
+    test_recovery_delay_period_above_maximum: UpdateRecoveryDelayPeriodTestVector {
+        initial_delay_period_num_sec: None,
+        delay_period_num_sec: 7200,
+        app_signed: true,
+        hw_signed: true,
+        expected_status: StatusCode::BAD_REQUEST,
+        expected_delay_period_num_sec: 20,
+        expected_pending: false,
+    },
+    test_update_recovery_delay_period_without_hw_signature: UpdateRecoveryDelayPeriodTestVector {
+        initial_delay_period_num_sec: None,
+        delay_period_num_sec: 600,
+        app_signed: true,
+        hw_signed: false,
+        expected_status: StatusCode::BAD_REQUEST,
+        expected_delay_period_num_sec: 20,
+        expected_pending: false,
+    },
+    test_update_recovery_delay_period_without_app_signature: UpdateRecoveryDelayPeriodTestVector {
+        initial_delay_period_num_sec: None,
+        delay_period_num_sec: 600,
+        app_signed: false,
+        hw_signed: true,
+        expected_status: StatusCode::BAD_REQUEST,
+        expected_delay_period_num_sec: 20,
+        expected_pending: false,
+    },
+}
+
+#[derive(Debug)]
+struct RecoveryHistoryTestVector {
+    lost_factor: Factor,
+    cancel_app_signed: bool,
+    cancel_hw_signed: bool,
+    expected_cancel_action: RecoveryHistoryAction,
+}
+
+async fn recovery_history_test(vector: RecoveryHistoryTestVector) {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+    let account_id = account.id.to_string();
+
+    let response = client.get_recovery_history(&account_id).await;
+    assert_eq!(response.status_code, StatusCode::OK);
+    assert!(response.body.unwrap().entries.is_empty());
+
+    let (create_app_signed, create_hw_signed) = match vector.lost_factor {
+        Factor::App => (false, true),
+        Factor::Hw => (true, false),
+    };
+    let response = client
+        .create_delay_notify_recovery(
+            &account_id,
+            &CreateAccountDelayNotifyRequest {
+                lost_factor: vector.lost_factor,
+                delay_period_num_sec: None,
+                auth: FullAccountAuthKeysPayload {
+                    app: create_pubkey(),
+                    hardware: match vector.lost_factor {
+                        Factor::App => account.hardware_auth_pubkey,
+                        Factor::Hw => create_pubkey(),
+                    },
+                    recovery: Some(create_pubkey()),
+                },
+            },
+            create_app_signed,
+            create_hw_signed,
+        )
+        .await;
+    assert_eq!(
+        response.status_code,
+        StatusCode::OK,
+        "{}",
+        response.body_string
+    );
+
+    let response = client
+        .cancel_delay_notify_recovery(
+            &account_id,
+            vector.cancel_app_signed,
+            vector.cancel_hw_signed,
+        )
+        .await;
+    assert_eq!(
+        response.status_code,
+        StatusCode::OK,
+        "{}",
+        response.body_string
+    );
+
+    let response = client.get_recovery_history(&account_id).await;
+    assert_eq!(response.status_code, StatusCode::OK);
+    let entries = response.body.unwrap().entries;
+    assert_eq!(
+        entries
+            .iter()
+            .map(|e| (e.action, e.app_signed, e.hw_signed))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                RecoveryHistoryAction::DelayNotifyCreated,
+                create_app_signed,
+                create_hw_signed
+            ),
+            (
+                vector.expected_cancel_action,
+                vector.cancel_app_signed,
+                vector.cancel_hw_signed
+            ),
+        ]
+    );
+    assert!(entries.iter().all(|e| e.actor_account_id == account.id));
+}
+
+tests! {
+    runner = recovery_history_test,
+    test_recovery_history_for_canceled_lost_app_recovery: RecoveryHistoryTestVector {
+        lost_factor: Factor::App,
+        cancel_app_signed: false,
+        cancel_hw_signed: true,
+        expected_cancel_action: RecoveryHistoryAction::DelayNotifyCanceled,
+    },
+    test_recovery_history_for_contested_lost_app_recovery: RecoveryHistoryTestVector {
+        lost_factor: Factor::App,
+        cancel_app_signed: true,
+        cancel_hw_signed: false,
+        expected_cancel_action: RecoveryHistoryAction::DelayNotifyContested,
+    },
+    test_recovery_history_for_contested_lost_hw_recovery: RecoveryHistoryTestVector {
+        lost_factor: Factor::Hw,
+        cancel_app_signed: false,
+        cancel_hw_signed: true,
+        expected_cancel_action: RecoveryHistoryAction::DelayNotifyContested,
+    },
+}
+
+#[derive(Debug)]
+enum CancellationToken {
+    Valid,
+    Tampered,
+    Malformed,
+    ForAccountWithoutRecovery,
+}
+
+#[derive(Debug)]
+struct CancelDelayNotifyWithTokenTestVector {
+    token: CancellationToken,
+    expected_status: StatusCode,
+}
+
+async fn create_recovery_and_fetch_cancellation_token(
+    client: &TestClient,
+    services: &Services,
+    account: &FullAccount,
+) -> String {
+    let response = client
+        .create_delay_notify_recovery(
+            &account.id.to_string(),
+            &CreateAccountDelayNotifyRequest {
+                lost_factor: Factor::App,
+                delay_period_num_sec: None,
+                auth: FullAccountAuthKeysPayload {
+                    app: create_pubkey(),
+                    hardware: account.hardware_auth_pubkey,
+                    recovery: Some(create_pubkey()),
+                },
+            },
+            false,
+            true,
+        )
+        .await;
+    assert_eq!(
+        response.status_code,
+        StatusCode::OK,
+        "{}",
+        response.body_string
+    );
+
+    let scheduled_notifications = services
+        .notification_service
+        .fetch_scheduled_for_account(FetchForAccountInput {
+            account_id: account.id.clone(),
+        })
+        .await
+        .unwrap();
+    let reminder = scheduled_notifications
+        .into_iter()
+        .find(|n| n.payload_type == NotificationPayloadType::RecoveryPendingDelayPeriod)
+        .and_then(|n| n.payload.recovery_pending_delay_period_payload)
+        .expect("Pending recovery reminders should be scheduled");
+    // The token is only sent with the first notification, never persisted with the reminders
+    assert!(reminder.cancellation_token.is_none());
+
+    // Test accounts are always sent the test token
+    TEST_TOKEN.to_owned()
+}
+
+async fn cancel_delay_notify_with_token_test(vector: CancelDelayNotifyWithTokenTestVector) {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+    let account_id = account.id.to_string();
+
+    let mut token =
+        create_recovery_and_fetch_cancellation_token(&client, &bootstrap.services, &account).await;
+    let mut target_account_id = account_id.clone();
+    match vector.token {
+        CancellationToken::Valid => {}
+        CancellationToken::Tampered => token = token.replace('0', "1"),
+        CancellationToken::Malformed => token = "not-a-token".to_owned(),
+        CancellationToken::ForAccountWithoutRecovery => {
+            let other_account =
+                create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+            target_account_id = other_account.id.to_string();
+        }
+    }
+
+    let request = CancelDelayNotifyWithTokenRequest { token };
+    let response = client
+        .cancel_delay_notify_recovery_with_token(&target_account_id, &request)
+        .await;
+    assert_eq!(
+        response.status_code, vector.expected_status,
+        "{}",
+        response.body_string
+    );
+
+    let status = client.get_recovery_status(&account_id).await.body.unwrap();
+    if vector.expected_status != StatusCode::OK {
+        assert!(status.pending_delay_notify.is_some());
+        return;
+    }
+    assert!(status.pending_delay_notify.is_none());
+    assert!(status.active_contest);
+
+    let entries = client
+        .get_recovery_history(&account_id)
+        .await
+        .body
+        .unwrap()
+        .entries;
+    let last = entries.last().unwrap();
+    assert_eq!(
+        (last.action, last.app_signed, last.hw_signed),
+        (
+            RecoveryHistoryAction::DelayNotifyCanceledWithToken,
+            false,
+            false
+        )
+    );
+
+    // Tokens are single-use
+    let response = client
+        .cancel_delay_notify_recovery_with_token(&account_id, &request)
+        .await;
+    assert_eq!(response.status_code, StatusCode::FORBIDDEN);
+}
+
+tests! {
+    runner = cancel_delay_notify_with_token_test,
+    test_cancel_delay_notify_with_valid_token: CancelDelayNotifyWithTokenTestVector {
+        token: CancellationToken::Valid,
+        expected_status: StatusCode::OK,
+    },
+    test_cancel_delay_notify_with_tampered_token: CancelDelayNotifyWithTokenTestVector {
+        token: CancellationToken::Tampered,
+        expected_status: StatusCode::FORBIDDEN,
+    },
+    test_cancel_delay_notify_with_malformed_token: CancelDelayNotifyWithTokenTestVector {
+        token: CancellationToken::Malformed,
+        expected_status: StatusCode::BAD_REQUEST,
+    },
+    test_cancel_delay_notify_with_token_for_account_without_recovery: CancelDelayNotifyWithTokenTestVector {
+        token: CancellationToken::ForAccountWithoutRecovery,
+        expected_status: StatusCode::FORBIDDEN,
+    },
+}
+
+#[tokio::test]
+async fn cancel_delay_notify_with_token_rate_limit_test() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+    let account_id = account.id.to_string();
+    let token =
+        create_recovery_and_fetch_cancellation_token(&client, &bootstrap.services, &account).await;
+
+    let malformed = CancelDelayNotifyWithTokenRequest {
+        token: "not-a-token".to_owned(),
+    };
+    let mut statuses = Vec::new();
+    for _ in 0..20 {
+        let response = client
+            .cancel_delay_notify_recovery_with_token(&account_id, &malformed)
+            .await;
+        statuses.push(response.status_code);
+        if response.status_code == StatusCode::TOO_MANY_REQUESTS {
+            break;
+        }
+    }
+    assert_eq!(statuses.last(), Some(&StatusCode::TOO_MANY_REQUESTS));
+    assert!(statuses[..statuses.len() - 1]
+        .iter()
+        .all(|s| *s == StatusCode::BAD_REQUEST));
+
+    // Failures from the same source never lock out a customer holding a valid token
+    let response = client
+        .cancel_delay_notify_recovery_with_token(
+            &account_id,
+            &CancelDelayNotifyWithTokenRequest { token },
+        )
+        .await;
+    assert_eq!(
+        response.status_code,
+        StatusCode::OK,
+        "{}",
+        response.body_string
+    );
+}
+
 #[derive(Debug)]
 enum AuthKeyReuse {
     MyAccountApp,
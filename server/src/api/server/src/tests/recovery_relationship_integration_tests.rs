@@ -13,10 +13,11 @@ use recovery::routes::{
     CreateRecoveryRelationshipResponse, EndorseRecoveryRelationshipsRequest,
     EndorseRecoveryRelationshipsResponse,
 };
-use time::OffsetDateTime;
+use recovery::service::social::relationship::sweep_recovery_relationships::SweepRecoveryRelationshipsInput;
+use time::{Duration, OffsetDateTime};
 use types::account::identifiers::AccountId;
 use types::recovery::social::relationship::{
-    RecoveryRelationshipEndorsement, RecoveryRelationshipId,
+    RecoveryRelationship, RecoveryRelationshipEndorsement, RecoveryRelationshipId,
 };
 
 use super::requests::CognitoAuthentication;
@@ -1136,3 +1137,96 @@ async fn test_get_recovery_relationship_invitation_for_code() {
         get_response.body_string
     );
 }
+
+#[tokio::test]
+async fn test_sweep_recovery_relationships_archives_expired_invitations() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let customer_account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+    let tc_account = create_lite_account(&bootstrap.services, None, true).await;
+    let auth = CognitoAuthentication::Wallet {
+        is_app_signed: true,
+        is_hardware_signed: true,
+    };
+
+    let recently_expired_body = try_create_recovery_relationship(
+        &client,
+        &customer_account.id,
+        &auth,
+        StatusCode::OK,
+        1,
+        0,
+    )
+    .await
+    .unwrap();
+    let expired_body = try_create_recovery_relationship(
+        &client,
+        &customer_account.id,
+        &auth,
+        StatusCode::OK,
+        2,
+        0,
+    )
+    .await
+    .unwrap();
+
+    update_recovery_relationship_invitation_expiration(
+        &bootstrap.services,
+        &recently_expired_body.invitation.recovery_relationship_id,
+        OffsetDateTime::now_utc() - Duration::days(1),
+    )
+    .await;
+    update_recovery_relationship_invitation_expiration(
+        &bootstrap.services,
+        &expired_body.invitation.recovery_relationship_id,
+        OffsetDateTime::now_utc() - Duration::days(8),
+    )
+    .await;
+
+    bootstrap
+        .services
+        .recovery_relationship_service
+        .sweep_recovery_relationships(SweepRecoveryRelationshipsInput {
+            now: OffsetDateTime::now_utc(),
+        })
+        .await
+        .unwrap();
+
+    // Recently expired invitations are kept around so they can be reissued
+    let relationship = bootstrap
+        .services
+        .recovery_relationship_service
+        .repository
+        .fetch_recovery_relationship(&recently_expired_body.invitation.recovery_relationship_id)
+        .await
+        .unwrap();
+    assert!(matches!(relationship, RecoveryRelationship::Invitation(_)));
+
+    let relationship = bootstrap
+        .services
+        .recovery_relationship_service
+        .repository
+        .fetch_recovery_relationship(&expired_body.invitation.recovery_relationship_id)
+        .await
+        .unwrap();
+    assert!(matches!(
+        relationship,
+        RecoveryRelationship::ArchivedInvitation(_)
+    ));
+    assert_relationship_counts(&client, &customer_account.id, 1, 0, 0, 0).await;
+
+    // An archived invitation can no longer be looked up or accepted
+    let get_response = client
+        .get_recovery_relationship_invitation_for_code(
+            &tc_account.id.to_string(),
+            &expired_body.invitation.code,
+        )
+        .await;
+    assert_eq!(
+        get_response.status_code,
+        StatusCode::NOT_FOUND,
+        "{:?}",
+        get_response.body_string
+    );
+}
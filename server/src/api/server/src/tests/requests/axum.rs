@@ -19,14 +19,17 @@ use authn_authz::routes::{
     AuthenticateWithRecoveryAuthkeyRequest, AuthenticateWithRecoveryResponse,
     AuthenticationRequest, AuthenticationResponse, GetTokensRequest, GetTokensResponse,
 };
-use exchange_rate::routes::SupportedFiatCurrenciesResponse;
+use chain_indexer::routes::{BalanceResponse, ListTransactionsResponse};
+use exchange_rate::routes::{PriceChartResponse, SupportedFiatCurrenciesResponse};
 use mobile_pay::routes::{
-    MobilePayResponse, MobilePaySetupRequest, MobilePaySetupResponse, SignTransactionData,
-    SignTransactionResponse,
+    BumpFeeData, MobilePayResponse, MobilePaySetupRequest, MobilePaySetupResponse,
+    SignTransactionData, SignTransactionResponse,
 };
+use notification::entities::NotificationCompositeKey;
 use notification::routes::{
-    RegisterWatchAddressRequest, RegisterWatchAddressResponse, SendTestPushData,
-    SendTestPushResponse, SetNotificationsPreferencesRequest,
+    NotificationsLocaleRequest, NotificationsLocaleResponse, RegisterWatchAddressRequest,
+    RegisterWatchAddressResponse, SendTestPushData, SendTestPushResponse,
+    SetNotificationsPreferencesRequest,
 };
 use onboarding::routes::{
     AccountActivateTouchpointRequest, AccountActivateTouchpointResponse,
@@ -40,19 +43,22 @@ use onboarding::routes::{
 use types::account::identifiers::{AccountId, KeysetId};
 
 use recovery::routes::{
-    CompleteDelayNotifyRequest, CreateAccountDelayNotifyRequest, CreateRecoveryRelationshipRequest,
-    CreateRecoveryRelationshipResponse, EndorseRecoveryRelationshipsRequest,
-    EndorseRecoveryRelationshipsResponse, FetchSocialChallengeResponse,
+    CancelDelayNotifyWithTokenRequest, CompleteDelayNotifyRequest, CreateAccountDelayNotifyRequest,
+    CreateRecoveryRelationshipRequest, CreateRecoveryRelationshipResponse,
+    CreateSocialRecoveryRequest, EndorseRecoveryRelationshipsRequest,
+    EndorseRecoveryRelationshipsResponse, FetchSocialChallengeResponse, GetRecoveryHistoryResponse,
     GetRecoveryRelationshipInvitationForCodeResponse, GetRecoveryRelationshipsResponse,
-    RespondToSocialChallengeRequest, RespondToSocialChallengeResponse,
-    RotateAuthenticationKeysRequest, RotateAuthenticationKeysResponse,
-    SendAccountVerificationCodeRequest, SendAccountVerificationCodeResponse,
-    StartSocialChallengeRequest, StartSocialChallengeResponse, UpdateDelayForTestRecoveryRequest,
-    UpdateRecoveryRelationshipRequest, UpdateRecoveryRelationshipResponse,
-    VerifyAccountVerificationCodeRequest, VerifyAccountVerificationCodeResponse,
-    VerifySocialChallengeCodeRequest, VerifySocialChallengeCodeResponse,
+    RecoveryDelayPeriodResponse, RecoveryPolicyResponse, RespondToSocialChallengeRequest,
+    RespondToSocialChallengeResponse, RotateAuthenticationKeysRequest,
+    RotateAuthenticationKeysResponse, SendAccountVerificationCodeRequest,
+    SendAccountVerificationCodeResponse, StartSocialChallengeRequest, StartSocialChallengeResponse,
+    UpdateDelayForTestRecoveryRequest, UpdateRecoveryDelayPeriodRequest,
+    UpdateRecoveryPolicyRequest, UpdateRecoveryRelationshipRequest,
+    UpdateRecoveryRelationshipResponse, VerifyAccountVerificationCodeRequest,
+    VerifyAccountVerificationCodeResponse, VerifySocialChallengeCodeRequest,
+    VerifySocialChallengeCodeResponse,
 };
-use recovery::state_machine::RecoveryResponse;
+use recovery::state_machine::{RecoveryResponse, SocialRecoveryResponse};
 
 use crate::test_utils::AuthenticatedRequest;
 
@@ -374,6 +380,20 @@ impl TestClient {
             .await
     }
 
+    pub(crate) async fn cancel_delay_notify_recovery_with_token(
+        &self,
+        account_id: &str,
+        request: &CancelDelayNotifyWithTokenRequest,
+    ) -> Response<()> {
+        Request::builder()
+            .uri(format!(
+                "/api/accounts/{account_id}/delay-notify/cancel-with-token"
+            ))
+            .post(request)
+            .call(&self.router)
+            .await
+    }
+
     pub(crate) async fn complete_delay_notify_recovery(
         &self,
         account_id: &str,
@@ -522,6 +542,22 @@ impl TestClient {
             .await
     }
 
+    pub(crate) async fn bump_fee_with_keyset(
+        &self,
+        account_id: &AccountId,
+        keyset_id: &KeysetId,
+        request: &BumpFeeData,
+    ) -> Response<SignTransactionResponse> {
+        Request::builder()
+            .uri(format!(
+                "/api/accounts/{account_id}/keysets/{keyset_id}/bump-fee"
+            ))
+            .authenticated(account_id, false, false)
+            .post(&request)
+            .call(&self.router)
+            .await
+    }
+
     pub(crate) async fn send_test_push(
         &self,
         account_id: &str,
@@ -640,10 +676,17 @@ impl TestClient {
 
     pub(crate) async fn twilio_status_callback(
         &self,
+        notification: Option<&NotificationCompositeKey>,
         body: &HashMap<String, String>,
         signature: Option<String>,
     ) -> axum::response::Response {
-        let mut builder = Request::builder().uri("/api/twilio/status-callback");
+        let uri = match notification {
+            Some((account_id, notification_id)) => format!(
+                "/api/twilio/status-callback?account_id={account_id}&notification_id={notification_id}"
+            ),
+            None => "/api/twilio/status-callback".to_string(),
+        };
+        let mut builder = Request::builder().uri(uri);
 
         if let Some(signature) = signature {
             builder = builder.header("X-Twilio-Signature", signature);
@@ -757,6 +800,48 @@ impl TestClient {
             .await
     }
 
+    pub(crate) async fn get_price_chart(
+        &self,
+        account_id: &AccountId,
+        currency_code: &str,
+        range: &str,
+    ) -> Response<PriceChartResponse> {
+        Request::builder()
+            .uri(format!(
+                "/api/exchange-rates/chart?currency_code={currency_code}&range={range}"
+            ))
+            .authenticated(account_id, false, false)
+            .get()
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn list_transactions(
+        &self,
+        account_id: &AccountId,
+        query: &str,
+    ) -> Response<ListTransactionsResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/transactions?{query}"))
+            .authenticated(account_id, false, false)
+            .get()
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn get_balance(
+        &self,
+        account_id: &AccountId,
+        query: &str,
+    ) -> Response<BalanceResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/balance?{query}"))
+            .authenticated(account_id, false, false)
+            .get()
+            .call(&self.router)
+            .await
+    }
+
     pub(crate) async fn start_social_challenge(
         &self,
         account_id: &str,
@@ -818,6 +903,114 @@ impl TestClient {
             .await
     }
 
+    pub(crate) async fn create_social_recovery(
+        &self,
+        account_id: &str,
+        request: &CreateSocialRecoveryRequest,
+    ) -> Response<SocialRecoveryResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/social-recovery"))
+            .authenticated(&AccountId::from_str(account_id).unwrap(), false, true)
+            .post(request)
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn get_social_recovery_status(
+        &self,
+        account_id: &str,
+    ) -> Response<SocialRecoveryResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/social-recovery"))
+            .authenticated(&AccountId::from_str(account_id).unwrap(), false, false)
+            .get()
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn complete_social_recovery(
+        &self,
+        account_id: &str,
+        request: &CompleteDelayNotifyRequest,
+    ) -> Response<RotatedKeysetResponse> {
+        Request::builder()
+            .uri(format!(
+                "/api/accounts/{account_id}/social-recovery/complete"
+            ))
+            .post(&request)
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn get_recovery_policy(
+        &self,
+        account_id: &str,
+    ) -> Response<RecoveryPolicyResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/recovery/policy"))
+            .authenticated(&AccountId::from_str(account_id).unwrap(), false, false)
+            .get()
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn update_recovery_policy(
+        &self,
+        account_id: &str,
+        request: &UpdateRecoveryPolicyRequest,
+        auth: &CognitoAuthentication,
+    ) -> Response<RecoveryPolicyResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/recovery/policy"))
+            .with_authentication(auth, &AccountId::from_str(account_id).unwrap())
+            .put(&request)
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn get_recovery_delay_period(
+        &self,
+        account_id: &str,
+    ) -> Response<RecoveryDelayPeriodResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/recovery/delay-period"))
+            .authenticated(&AccountId::from_str(account_id).unwrap(), false, false)
+            .get()
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn update_recovery_delay_period(
+        &self,
+        account_id: &str,
+        request: &UpdateRecoveryDelayPeriodRequest,
+        app_signed: bool,
+        hw_signed: bool,
+    ) -> Response<RecoveryDelayPeriodResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/recovery/delay-period"))
+            .authenticated(
+                &AccountId::from_str(account_id).unwrap(),
+                app_signed,
+                hw_signed,
+            )
+            .put(request)
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn get_recovery_history(
+        &self,
+        account_id: &str,
+    ) -> Response<GetRecoveryHistoryResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/recovery/history"))
+            .authenticated(&AccountId::from_str(account_id).unwrap(), false, false)
+            .get()
+            .call(&self.router)
+            .await
+    }
+
     pub(crate) async fn delete_account(
         &self,
         account_id: &str,
@@ -859,4 +1052,29 @@ impl TestClient {
             .call(&self.router)
             .await
     }
+
+    pub(crate) async fn set_notifications_locale(
+        &self,
+        account_id: &str,
+        request: &NotificationsLocaleRequest,
+    ) -> Response<NotificationsLocaleResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/notifications-locale"))
+            .authenticated(&AccountId::from_str(account_id).unwrap(), false, false)
+            .put(request)
+            .call(&self.router)
+            .await
+    }
+
+    pub(crate) async fn get_notifications_locale(
+        &self,
+        account_id: &str,
+    ) -> Response<NotificationsLocaleResponse> {
+        Request::builder()
+            .uri(format!("/api/accounts/{account_id}/notifications-locale"))
+            .authenticated(&AccountId::from_str(account_id).unwrap(), false, false)
+            .get()
+            .call(&self.router)
+            .await
+    }
 }
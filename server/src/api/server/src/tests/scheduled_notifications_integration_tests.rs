@@ -2,6 +2,7 @@ use account::{
     entities::{Factor, TouchpointPlatform},
     service::AddPushTouchpointToAccountInput,
 };
+use http::StatusCode;
 use notification::{
     entities::{NotificationTouchpoint, ScheduledNotification},
     identifiers::NotificationId,
@@ -9,6 +10,7 @@ use notification::{
         recovery_pending_delay_period::RecoveryPendingDelayPeriodPayload,
         test_notification::TestNotificationPayload,
     },
+    routes::NotificationsLocaleRequest,
     schedule::ScheduleNotificationType,
     service::{
         FetchForAccountInput, FetchScheduledForWindowInput, PersistScheduledNotificationsInput,
@@ -18,7 +20,7 @@ use notification::{
 };
 use queue::sqs::SqsQueue;
 use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
-use types::account::identifiers::AccountId;
+use types::{account::identifiers::AccountId, notification::Locale};
 
 use crate::{
     create_bootstrap, tests,
@@ -47,6 +49,7 @@ async fn fetch_scheduled_notifications_test(input: FetchScheduledNotificationsTe
             initiation_time: OffsetDateTime::now_utc(),
             delay_end_time: OffsetDateTime::now_utc(),
             lost_factor: Factor::Hw,
+            cancellation_token: None,
         }),
         ..Default::default()
     };
@@ -159,6 +162,10 @@ async fn test_scheduled_handler() {
         address_repo: bootstrap.services.address_repo.clone(),
         sqs: bootstrap.services.sqs.clone(),
         feature_flags_service: bootstrap.services.feature_flags_service.clone(),
+        recovery_relationship_service: bootstrap.services.recovery_relationship_service.clone(),
+        exchange_rate_service: bootstrap.services.exchange_rate_service.clone(),
+        rate_history_service: bootstrap.services.rate_history_service.clone(),
+        transaction_history_service: bootstrap.services.transaction_history_service.clone(),
     };
     let worker = TestWorker::new(state.clone()).await;
 
@@ -258,3 +265,68 @@ async fn test_scheduled_handler() {
         panic!("Expected sqs queue to be in test mode");
     }
 }
+
+#[tokio::test]
+async fn test_scheduled_handler_uses_account_locale() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router.clone()).await;
+    let state = workers::jobs::WorkerState {
+        config: http_server::config::extract(None).unwrap(),
+        notification_service: bootstrap.services.notification_service.clone(),
+        account_service: bootstrap.services.account_service.clone(),
+        recovery_service: bootstrap.services.recovery_service.clone(),
+        chain_indexer_service: bootstrap.services.chain_indexer_service.clone(),
+        address_repo: bootstrap.services.address_repo.clone(),
+        sqs: bootstrap.services.sqs.clone(),
+        feature_flags_service: bootstrap.services.feature_flags_service.clone(),
+        recovery_relationship_service: bootstrap.services.recovery_relationship_service.clone(),
+        exchange_rate_service: bootstrap.services.exchange_rate_service.clone(),
+        rate_history_service: bootstrap.services.rate_history_service.clone(),
+        transaction_history_service: bootstrap.services.transaction_history_service.clone(),
+    };
+    let worker = TestWorker::new(state.clone()).await;
+
+    let (account, _) =
+        create_default_account_with_predefined_wallet(&client, &bootstrap.services).await;
+    let account_id = account.id;
+    create_push_touchpoint(&bootstrap.services, &account_id).await;
+    let response = client
+        .set_notifications_locale(
+            &account_id.to_string(),
+            &NotificationsLocaleRequest {
+                locale: Locale::EsUs,
+            },
+        )
+        .await;
+    assert_eq!(response.status_code, StatusCode::OK);
+
+    let payload = NotificationPayloadBuilder::default()
+        .test_notification_payload(Some(TestNotificationPayload::default()))
+        .build()
+        .unwrap();
+    bootstrap
+        .services
+        .notification_service
+        .schedule_notifications(ScheduleNotificationsInput {
+            account_id: account_id.clone(),
+            notification_type: ScheduleNotificationType::TestPushNotification,
+            payload,
+        })
+        .await
+        .unwrap();
+
+    let SqsQueue::Test(messages) = bootstrap.services.sqs else {
+        panic!("Expected sqs queue to be in test mode");
+    };
+    let sent_immediately = messages.lock().unwrap().len();
+
+    worker.scheduled_notification().await;
+
+    // The rescheduled notification is rendered in the account's locale, just like the first
+    let messages = messages.lock().unwrap();
+    assert!(messages.len() > sent_immediately);
+    assert!(messages.iter().all(|m| m
+        .body()
+        .unwrap()
+        .contains("Notificación de prueba recibida")));
+}
@@ -11,23 +11,28 @@ use notification::{
     identifiers::NotificationId,
     payloads::{
         comms_verification::{CommsVerificationPayload, TemplateType},
+        payment::PaymentPayload,
         recovery_pending_delay_period::RecoveryPendingDelayPeriodPayload,
+        test_notification::TestNotificationPayload,
     },
     service::{
         FetchForAccountInput, FetchForCompositeKeyInput, PersistCustomerNotificationsInput,
-        SendNotificationInput, UpdateDeliveryStatusInput,
+        SendNotificationInput, UpdateDeliveryStatusInput, UpdateNotificationsPreferencesInput,
     },
     DeliveryStatus, NotificationMessage, NotificationPayloadBuilder, NotificationPayloadType,
 };
 use time::OffsetDateTime;
-use types::{account::identifiers::TouchpointId, notification::NotificationChannel};
+use types::{
+    account::identifiers::TouchpointId,
+    notification::{Locale, NotificationChannel, NotificationsPreferences, QuietHours},
+};
 
 use crate::tests;
 use crate::tests::{
     gen_services,
     lib::{
         create_default_account_with_predefined_wallet, create_email_touchpoint,
-        create_phone_touchpoint, create_push_touchpoint,
+        create_phone_touchpoint, create_push_touchpoint, create_webhook_touchpoint,
     },
     requests::axum::TestClient,
     requests::worker::TestWorker,
@@ -51,6 +56,10 @@ async fn send_customer_notifications_test(input: SendCustomerNotificationsTestVe
         address_repo: bootstrap.services.address_repo.clone(),
         sqs: bootstrap.services.sqs.clone(),
         feature_flags_service: bootstrap.services.feature_flags_service.clone(),
+        recovery_relationship_service: bootstrap.services.recovery_relationship_service.clone(),
+        exchange_rate_service: bootstrap.services.exchange_rate_service.clone(),
+        rate_history_service: bootstrap.services.rate_history_service.clone(),
+        transaction_history_service: bootstrap.services.transaction_history_service.clone(),
     };
     let worker = TestWorker::new(state.clone()).await;
 
@@ -69,11 +78,16 @@ async fn send_customer_notifications_test(input: SendCustomerNotificationsTestVe
     let email_touchpoint = &NotificationTouchpoint::Email {
         touchpoint_id: email_touchpoint_id,
     };
+    let webhook_touchpoint_id = create_webhook_touchpoint(&bootstrap.services, &account_id).await;
+    let webhook_touchpoint = &NotificationTouchpoint::Webhook {
+        touchpoint_id: webhook_touchpoint_id,
+    };
 
     let payload = RecoveryPendingDelayPeriodPayload {
         initiation_time: OffsetDateTime::now_utc(),
         delay_end_time: OffsetDateTime::now_utc(),
         lost_factor: Factor::Hw,
+        cancellation_token: None,
     };
 
     let notifications = input
@@ -84,6 +98,7 @@ async fn send_customer_notifications_test(input: SendCustomerNotificationsTestVe
                 NotificationChannel::Email => email_touchpoint,
                 NotificationChannel::Sms => phone_touchpoint,
                 NotificationChannel::Push => push_touchpoint,
+                NotificationChannel::Webhook => webhook_touchpoint,
             };
             CustomerNotification {
                 account_id: account_id.clone(),
@@ -93,6 +108,9 @@ async fn send_customer_notifications_test(input: SendCustomerNotificationsTestVe
                 delivery_status: DeliveryStatus::Enqueued,
                 created_at: OffsetDateTime::now_utc(),
                 updated_at: OffsetDateTime::now_utc(),
+                payload: None,
+                attempts: vec![],
+                sibling_touchpoints: vec![],
             }
         })
         .collect::<Vec<CustomerNotification>>();
@@ -110,9 +128,12 @@ async fn send_customer_notifications_test(input: SendCustomerNotificationsTestVe
         .clone()
         .into_iter()
         .map(|m| {
-            let sns_message =
-                TryInto::<NotificationMessage>::try_into((m.composite_key(), payload.clone()))
-                    .unwrap();
+            let sns_message = TryInto::<NotificationMessage>::try_into((
+                m.composite_key(),
+                Locale::EnUs,
+                payload.clone(),
+            ))
+            .unwrap();
             Message::builder()
                 .body(serde_json::to_string(&sns_message).unwrap())
                 .build()
@@ -153,6 +174,9 @@ tests! {
     test_send_customer_notifications_both_push_and_email: SendCustomerNotificationsTestVector {
         entries: vec![NotificationChannel::Push, NotificationChannel::Email],
     },
+    test_send_customer_notifications_only_webhook: SendCustomerNotificationsTestVector {
+        entries: vec![NotificationChannel::Webhook],
+    },
 }
 
 #[tokio::test]
@@ -202,6 +226,9 @@ async fn customer_notification_lifecycle_test() {
                     delivery_status: DeliveryStatus::Enqueued,
                     created_at: now,
                     updated_at: now,
+                    payload: None,
+                    attempts: vec![],
+                    sibling_touchpoints: vec![],
                 },
             )
         })
@@ -387,3 +414,106 @@ async fn service_send_notifications_test() {
         .await
         .unwrap();
 }
+
+#[tokio::test]
+async fn service_send_notifications_quiet_hours_test() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router.clone()).await;
+    let notification_service = &bootstrap.services.notification_service;
+
+    let (account, _) =
+        create_default_account_with_predefined_wallet(&client, &bootstrap.services).await;
+    let account_id = account.id;
+    create_push_touchpoint(&bootstrap.services, &account_id).await;
+
+    // Quiet hours from an hour ago until an hour from now
+    let now = OffsetDateTime::now_utc();
+    let minute_of_day = u16::from(now.hour()) * 60 + u16::from(now.minute());
+    let quiet_hours = QuietHours {
+        start_minute: (minute_of_day + 23 * 60) % (24 * 60),
+        end_minute: (minute_of_day + 60) % (24 * 60),
+        time_zone: "UTC".to_string(),
+    };
+    notification_service
+        .update_notifications_preferences(UpdateNotificationsPreferencesInput {
+            account_id: &account_id,
+            notifications_preferences: &NotificationsPreferences {
+                account_security: HashSet::from([NotificationChannel::Push]),
+                money_movement: HashSet::from([NotificationChannel::Push]),
+                product_marketing: HashSet::default(),
+                quiet_hours: Some(quiet_hours.clone()),
+            },
+        })
+        .await
+        .unwrap();
+
+    let payment_payload = &NotificationPayloadBuilder::default()
+        .payment_payload(Some(PaymentPayload {
+            account_id: account_id.clone(),
+        }))
+        .build()
+        .unwrap();
+    // Sent concurrently, as payments in the same block are
+    let send_payment_notification = || {
+        notification_service.send_notification(SendNotificationInput {
+            account_id: &account_id,
+            payload_type: NotificationPayloadType::PaymentNotification,
+            payload: payment_payload,
+            only_touchpoints: None,
+        })
+    };
+    let (first, second) = tokio::join!(send_payment_notification(), send_payment_notification());
+    first.unwrap();
+    second.unwrap();
+
+    // Payments are collapsed into a single digest for when quiet hours end
+    let customer_notifications = notification_service
+        .fetch_customer_for_account(FetchForAccountInput {
+            account_id: account_id.clone(),
+        })
+        .await
+        .unwrap();
+    assert!(customer_notifications.is_empty());
+    let digests = notification_service
+        .fetch_scheduled_for_account(FetchForAccountInput {
+            account_id: account_id.clone(),
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|n| n.payload_type == NotificationPayloadType::MoneyMovementDigest)
+        .collect::<Vec<_>>();
+    assert_eq!(digests.len(), 1);
+    assert_eq!(
+        Some(digests[0].execution_date_time),
+        quiet_hours.quiet_until(now)
+    );
+    assert_eq!(
+        digests[0]
+            .payload
+            .money_movement_digest_payload
+            .as_ref()
+            .unwrap()
+            .notification_count,
+        2
+    );
+
+    // AccountSecurity notifications aren't held back
+    notification_service
+        .send_notification(SendNotificationInput {
+            account_id: &account_id,
+            payload_type: NotificationPayloadType::TestPushNotification,
+            payload: &NotificationPayloadBuilder::default()
+                .test_notification_payload(Some(TestNotificationPayload {}))
+                .build()
+                .unwrap(),
+            only_touchpoints: None,
+        })
+        .await
+        .unwrap();
+    let customer_notifications = notification_service
+        .fetch_customer_for_account(FetchForAccountInput { account_id })
+        .await
+        .unwrap();
+    assert_eq!(customer_notifications.len(), 1);
+}
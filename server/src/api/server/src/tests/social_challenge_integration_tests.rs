@@ -1,10 +1,16 @@
+use std::collections::HashMap;
+
 use crate::tests;
-use account::entities::{Account, Network};
+use account::entities::{Account, FullAccountAuthKeysPayload, Network};
+use account::service::FetchAccountInput;
+use bdk_utils::bdk::bitcoin::secp256k1::SecretKey;
 use http::StatusCode;
+use notification::{service::FetchForAccountInput, NotificationPayloadType};
 use recovery::routes::{
-    FetchSocialChallengeResponse, RespondToSocialChallengeRequest,
-    RespondToSocialChallengeResponse, StartChallengeTrustedContactRequest,
-    StartSocialChallengeRequest, StartSocialChallengeResponse, VerifySocialChallengeCodeRequest,
+    CompleteDelayNotifyRequest, CreateSocialRecoveryRequest, FetchSocialChallengeResponse,
+    RespondToSocialChallengeRequest, RespondToSocialChallengeResponse,
+    StartChallengeTrustedContactRequest, StartSocialChallengeRequest, StartSocialChallengeResponse,
+    UpdateRecoveryPolicyRequest, VerifySocialChallengeCodeRequest,
     VerifySocialChallengeCodeResponse,
 };
 use types::{
@@ -17,7 +23,10 @@ use types::{
 
 use super::{
     gen_services,
-    lib::{create_account, create_lite_account},
+    lib::{
+        create_account, create_lite_account, create_plain_keys, gen_signature,
+        get_static_test_authkeys, TEST_HW_AUTH_KEY,
+    },
     recovery_relationship_integration_tests::{
         try_accept_recovery_relationship_invitation, try_create_recovery_relationship,
         try_endorse_recovery_relationship, AccountType, CodeOverride,
@@ -524,3 +533,383 @@ tests! {
         expected_status_code: StatusCode::FORBIDDEN,
     },
 }
+
+#[derive(Debug)]
+struct SocialRecoveryTestVector {
+    min_relationship_age_secs: Option<i64>,
+    trusted_contact_responds: bool,
+    expected_responses: usize,
+    expected_complete_status_code: StatusCode,
+}
+
+async fn social_recovery_test(vector: SocialRecoveryTestVector) {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let customer_account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+    let tc_account = create_lite_account(&bootstrap.services, None, true).await;
+
+    let create_relationship_body = try_create_recovery_relationship(
+        &client,
+        &customer_account.id,
+        &CognitoAuthentication::Wallet {
+            is_app_signed: true,
+            is_hardware_signed: true,
+        },
+        StatusCode::OK,
+        1,
+        0,
+    )
+    .await
+    .unwrap();
+    let recovery_relationship_id = create_relationship_body
+        .invitation
+        .recovery_relationship_id
+        .clone();
+
+    try_accept_recovery_relationship_invitation(
+        &client,
+        &customer_account.id,
+        &tc_account.id,
+        &CognitoAuthentication::Recovery,
+        &create_relationship_body.invitation,
+        CodeOverride::None,
+        StatusCode::OK,
+    )
+    .await;
+
+    try_endorse_recovery_relationship(
+        &client,
+        &customer_account.id,
+        &recovery_relationship_id,
+        "ENDORSEMENT_CERT",
+        StatusCode::OK,
+    )
+    .await;
+
+    if let Some(min_relationship_age_secs) = vector.min_relationship_age_secs {
+        let policy_resp = client
+            .update_recovery_policy(
+                &customer_account.id.to_string(),
+                &UpdateRecoveryPolicyRequest {
+                    threshold: 1,
+                    weights: HashMap::new(),
+                    min_relationship_age_secs,
+                },
+                &CognitoAuthentication::Wallet {
+                    is_app_signed: false,
+                    is_hardware_signed: true,
+                },
+            )
+            .await;
+        assert_eq!(
+            policy_resp.status_code,
+            StatusCode::OK,
+            "{}",
+            policy_resp.body_string
+        );
+    }
+
+    let start_body = try_start_social_challenge(
+        &client,
+        &customer_account.id,
+        vec![StartChallengeTrustedContactRequest {
+            recovery_relationship_id: recovery_relationship_id.clone(),
+            challenge_request: TrustedContactChallengeRequest {
+                customer_recovery_pubkey: CUSTOMER_RECOVERY_PUBKEY.to_owned(),
+                enrollment_sealed_pkek: "".to_owned(),
+            },
+        }],
+        StatusCode::OK,
+    )
+    .await
+    .unwrap();
+    let social_challenge_id = start_body.social_challenge.social_challenge_id.clone();
+
+    // Social recovery replaces the App and keeps the Hardware
+    let (app_auth_seckey, app_auth_pubkey) = create_plain_keys();
+    let (_, recovery_auth_pubkey) = create_plain_keys();
+    let (_, hardware_auth_pubkey, _) = get_static_test_authkeys();
+    let create_resp = client
+        .create_social_recovery(
+            &customer_account.id.to_string(),
+            &CreateSocialRecoveryRequest {
+                social_challenge_id: social_challenge_id.clone(),
+                auth: FullAccountAuthKeysPayload {
+                    app: app_auth_pubkey,
+                    hardware: hardware_auth_pubkey,
+                    recovery: Some(recovery_auth_pubkey),
+                },
+            },
+        )
+        .await;
+    assert_eq!(
+        create_resp.status_code,
+        StatusCode::OK,
+        "{}",
+        create_resp.body_string
+    );
+    let pending = create_resp.body.unwrap().pending_social_recovery.unwrap();
+    assert_eq!(pending.social_challenge_id, social_challenge_id);
+    assert_eq!(pending.responses, 0);
+
+    if vector.trusted_contact_responds {
+        try_verify_social_challenge_code(
+            &client,
+            &tc_account.id,
+            &recovery_relationship_id,
+            &start_body.social_challenge.code,
+            StatusCode::OK,
+        )
+        .await;
+        try_respond_to_social_challenge(
+            &client,
+            &tc_account.id,
+            &social_challenge_id,
+            StatusCode::OK,
+        )
+        .await;
+    }
+
+    let status_resp = client
+        .get_social_recovery_status(&customer_account.id.to_string())
+        .await;
+    assert_eq!(
+        status_resp.status_code,
+        StatusCode::OK,
+        "{}",
+        status_resp.body_string
+    );
+    let pending = status_resp.body.unwrap().pending_social_recovery.unwrap();
+    assert_eq!(pending.responses, vector.expected_responses);
+
+    let customer_notification_types = bootstrap
+        .services
+        .notification_service
+        .fetch_customer_for_account(FetchForAccountInput {
+            account_id: customer_account.id.clone(),
+        })
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|n| n.payload_type)
+        .collect::<Vec<NotificationPayloadType>>();
+    assert!(customer_notification_types.contains(&NotificationPayloadType::SocialRecoveryInitiated));
+    assert_eq!(
+        customer_notification_types.contains(&NotificationPayloadType::SocialRecoveryCompleted),
+        vector.expected_responses > 0
+    );
+
+    let challenge = "CompleteDelayNotify".to_string()
+        + &hardware_auth_pubkey.to_string()
+        + &app_auth_pubkey.to_string()
+        + &recovery_auth_pubkey.to_string();
+    let hardware_auth_seckey = SecretKey::from_slice(TEST_HW_AUTH_KEY).unwrap();
+    let complete_resp = client
+        .complete_social_recovery(
+            &customer_account.id.to_string(),
+            &CompleteDelayNotifyRequest {
+                app_signature: gen_signature(&challenge, &app_auth_seckey),
+                hardware_signature: gen_signature(&challenge, &hardware_auth_seckey),
+                challenge,
+            },
+        )
+        .await;
+    assert_eq!(
+        complete_resp.status_code, vector.expected_complete_status_code,
+        "{}",
+        complete_resp.body_string
+    );
+
+    let account = bootstrap
+        .services
+        .account_service
+        .fetch_full_account(FetchAccountInput {
+            account_id: &customer_account.id,
+        })
+        .await
+        .unwrap();
+    if vector.expected_complete_status_code.is_success() {
+        assert_eq!(account.application_auth_pubkey, Some(app_auth_pubkey));
+        assert_ne!(
+            customer_account.common_fields.active_auth_keys_id,
+            account.common_fields.active_auth_keys_id
+        );
+    } else {
+        assert_eq!(
+            customer_account.common_fields.active_auth_keys_id,
+            account.common_fields.active_auth_keys_id
+        );
+    }
+}
+
+tests! {
+    runner = social_recovery_test,
+    test_complete_social_recovery_after_trusted_contact_responds: SocialRecoveryTestVector {
+        min_relationship_age_secs: None,
+        trusted_contact_responds: true,
+        expected_responses: 1,
+        expected_complete_status_code: StatusCode::OK,
+    },
+    test_complete_social_recovery_without_responses: SocialRecoveryTestVector {
+        min_relationship_age_secs: None,
+        trusted_contact_responds: false,
+        expected_responses: 0,
+        expected_complete_status_code: StatusCode::BAD_REQUEST,
+    },
+    test_complete_social_recovery_with_too_recent_trusted_contact: SocialRecoveryTestVector {
+        min_relationship_age_secs: Some(86400),
+        trusted_contact_responds: true,
+        expected_responses: 0,
+        expected_complete_status_code: StatusCode::BAD_REQUEST,
+    },
+}
+
+#[derive(Debug)]
+struct UpdateRecoveryPolicyTestVector {
+    is_hardware_signed: bool,
+    threshold: u32,
+    weight: Option<u32>,
+    weigh_unknown_relationship: bool,
+    expected_status_code: StatusCode,
+}
+
+async fn update_recovery_policy_test(vector: UpdateRecoveryPolicyTestVector) {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let customer_account = create_account(&bootstrap.services, Network::BitcoinSignet, None).await;
+    let tc_account = create_lite_account(&bootstrap.services, None, true).await;
+
+    let create_relationship_body = try_create_recovery_relationship(
+        &client,
+        &customer_account.id,
+        &CognitoAuthentication::Wallet {
+            is_app_signed: true,
+            is_hardware_signed: true,
+        },
+        StatusCode::OK,
+        1,
+        0,
+    )
+    .await
+    .unwrap();
+    let recovery_relationship_id = create_relationship_body
+        .invitation
+        .recovery_relationship_id
+        .clone();
+    try_accept_recovery_relationship_invitation(
+        &client,
+        &customer_account.id,
+        &tc_account.id,
+        &CognitoAuthentication::Recovery,
+        &create_relationship_body.invitation,
+        CodeOverride::None,
+        StatusCode::OK,
+    )
+    .await;
+    try_endorse_recovery_relationship(
+        &client,
+        &customer_account.id,
+        &recovery_relationship_id,
+        "ENDORSEMENT_CERT",
+        StatusCode::OK,
+    )
+    .await;
+
+    // Accounts without a policy get the default one
+    let default_policy = client
+        .get_recovery_policy(&customer_account.id.to_string())
+        .await
+        .body
+        .unwrap();
+    assert_eq!(default_policy.threshold, 1);
+    assert!(default_policy.weights.is_empty());
+
+    let weighted_relationship_id = if vector.weigh_unknown_relationship {
+        RecoveryRelationshipId::gen().unwrap()
+    } else {
+        recovery_relationship_id
+    };
+    let request = UpdateRecoveryPolicyRequest {
+        threshold: vector.threshold,
+        weights: vector
+            .weight
+            .map(|w| HashMap::from([(weighted_relationship_id, w)]))
+            .unwrap_or_default(),
+        min_relationship_age_secs: 0,
+    };
+    let update_resp = client
+        .update_recovery_policy(
+            &customer_account.id.to_string(),
+            &request,
+            &CognitoAuthentication::Wallet {
+                is_app_signed: false,
+                is_hardware_signed: vector.is_hardware_signed,
+            },
+        )
+        .await;
+    assert_eq!(
+        update_resp.status_code, vector.expected_status_code,
+        "{}",
+        update_resp.body_string
+    );
+
+    let policy = client
+        .get_recovery_policy(&customer_account.id.to_string())
+        .await
+        .body
+        .unwrap();
+    if vector.expected_status_code.is_success() {
+        assert_eq!(policy.threshold, request.threshold);
+        assert_eq!(policy.weights, request.weights);
+    } else {
+        assert_eq!(policy, default_policy);
+    }
+}
+
+tests! {
+    runner = update_recovery_policy_test,
+    test_update_recovery_policy: UpdateRecoveryPolicyTestVector {
+        is_hardware_signed: true,
+        threshold: 2,
+        weight: Some(2),
+        weigh_unknown_relationship: false,
+        expected_status_code: StatusCode::OK,
+    },
+    test_update_recovery_policy_without_hw_keyproof: UpdateRecoveryPolicyTestVector {
+        is_hardware_signed: false,
+        threshold: 1,
+        weight: None,
+        weigh_unknown_relationship: false,
+        expected_status_code: StatusCode::BAD_REQUEST,
+    },
+    test_update_recovery_policy_with_unattainable_threshold: UpdateRecoveryPolicyTestVector {
+        is_hardware_signed: true,
+        threshold: 2,
+        weight: None,
+        weigh_unknown_relationship: false,
+        expected_status_code: StatusCode::BAD_REQUEST,
+    },
+    test_update_recovery_policy_with_zero_threshold: UpdateRecoveryPolicyTestVector {
+        is_hardware_signed: true,
+        threshold: 0,
+        weight: None,
+        weigh_unknown_relationship: false,
+        expected_status_code: StatusCode::BAD_REQUEST,
+    },
+    test_update_recovery_policy_with_zero_weight: UpdateRecoveryPolicyTestVector {
+        is_hardware_signed: true,
+        threshold: 1,
+        weight: Some(0),
+        weigh_unknown_relationship: false,
+        expected_status_code: StatusCode::BAD_REQUEST,
+    },
+    test_update_recovery_policy_weighing_unknown_relationship: UpdateRecoveryPolicyTestVector {
+        is_hardware_signed: true,
+        threshold: 1,
+        weight: Some(1),
+        weigh_unknown_relationship: true,
+        expected_status_code: StatusCode::BAD_REQUEST,
+    },
+}
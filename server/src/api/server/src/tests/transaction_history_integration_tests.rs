@@ -0,0 +1,135 @@
+use crate::tests::gen_services;
+use crate::tests::requests::axum::TestClient;
+use http::StatusCode;
+use time::{Duration, OffsetDateTime};
+use types::account::identifiers::AccountId;
+use types::currencies::CurrencyCode::EUR;
+use types::exchange_rate::history::{ExchangeRateSnapshot, ExchangeRateSnapshotSource};
+use types::transaction_history::{AccountTransaction, ReceivedOutput, SpentInput};
+
+fn account_transaction(
+    account_id: &AccountId,
+    txid: &str,
+    block_height: u64,
+    confirmed_at: OffsetDateTime,
+) -> AccountTransaction {
+    AccountTransaction {
+        account_id: account_id.clone(),
+        txid: txid.to_string(),
+        block_height,
+        block_hash: format!("hash-{block_height}"),
+        confirmed_at,
+        received_outputs: vec![],
+        spent_inputs: vec![],
+        fee_sats: None,
+        created_at: OffsetDateTime::now_utc(),
+    }
+}
+
+#[tokio::test]
+async fn test_transaction_history_and_balance() {
+    let bootstrap = gen_services().await;
+    let transaction_repository = bootstrap
+        .services
+        .transaction_history_service
+        .repository
+        .clone();
+    let rate_repository = bootstrap.services.rate_history_service.repository.clone();
+    let client = TestClient::new(bootstrap.router).await;
+
+    let account_id = AccountId::gen().unwrap();
+    let confirmed_at = OffsetDateTime::from_unix_timestamp(1_615_723_200).unwrap();
+
+    let mut deposit = account_transaction(
+        &account_id,
+        "deposit",
+        100,
+        confirmed_at - Duration::days(2),
+    );
+    deposit.received_outputs = vec![ReceivedOutput {
+        vout: 0,
+        address: "address-0".to_string(),
+        amount_sats: 100_000,
+    }];
+    let mut spend =
+        account_transaction(&account_id, "spend", 101, confirmed_at - Duration::days(1));
+    spend.spent_inputs = vec![SpentInput {
+        previous_txid: "deposit".to_string(),
+        previous_vout: 0,
+        amount_sats: Some(100_000),
+    }];
+    spend.received_outputs = vec![ReceivedOutput {
+        vout: 1,
+        address: "change-0".to_string(),
+        amount_sats: 30_000,
+    }];
+    spend.fee_sats = Some(1_000);
+    let mut payment = account_transaction(&account_id, "payment", 102, confirmed_at);
+    payment.received_outputs = vec![ReceivedOutput {
+        vout: 3,
+        address: "address-1".to_string(),
+        amount_sats: 50_000,
+    }];
+
+    for transaction in [&deposit, &spend, &payment] {
+        transaction_repository.persist(transaction).await.unwrap();
+    }
+    rate_repository
+        .persist(&ExchangeRateSnapshot {
+            currency_code: EUR,
+            timestamp: confirmed_at - Duration::minutes(1),
+            rate: 40_000.0,
+            source: ExchangeRateSnapshotSource::Backfill,
+        })
+        .await
+        .unwrap();
+
+    let response = client
+        .list_transactions(&account_id, "limit=2&currency_code=EUR")
+        .await;
+    assert_eq!(response.status_code, StatusCode::OK);
+    let page = response.body.unwrap();
+    assert_eq!(
+        page.transactions
+            .iter()
+            .map(|t| t.txid.as_str())
+            .collect::<Vec<_>>(),
+        vec!["payment", "spend"]
+    );
+    let fiat_value = page.transactions[0].fiat_value.clone().unwrap();
+    assert_eq!(fiat_value.currency_code, EUR);
+    assert_eq!(fiat_value.amount, 2_000);
+    assert_eq!(page.transactions[1].net_sats, -70_000);
+    assert_eq!(page.transactions[1].fee_sats, Some(1_000));
+    assert!(page.transactions[1].complete);
+
+    let next_cursor = page.next_cursor.unwrap();
+    let response = client
+        .list_transactions(&account_id, &format!("limit=2&cursor={next_cursor}"))
+        .await;
+    assert_eq!(response.status_code, StatusCode::OK);
+    let page = response.body.unwrap();
+    assert_eq!(page.transactions.len(), 1);
+    assert_eq!(page.transactions[0].txid, "deposit");
+    assert_eq!(page.transactions[0].received_sats, 100_000);
+
+    let response = client.get_balance(&account_id, "currency_code=EUR").await;
+    assert_eq!(response.status_code, StatusCode::OK);
+    let balance = response.body.unwrap();
+    assert_eq!(balance.confirmed_sats, 80_000);
+    assert_eq!(balance.total_received_sats, 180_000);
+    assert_eq!(balance.total_sent_sats, 100_000);
+    assert_eq!(balance.transaction_count, 3);
+    assert!(balance.complete);
+}
+
+#[tokio::test]
+async fn test_list_transactions_invalid_cursor() {
+    let bootstrap = gen_services().await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let response = client
+        .list_transactions(&AccountId::gen().unwrap(), "cursor=invalid")
+        .await;
+    assert_eq!(response.status_code, StatusCode::BAD_REQUEST);
+}
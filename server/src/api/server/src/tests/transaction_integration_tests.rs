@@ -13,6 +13,7 @@ use bdk_utils::{DescriptorKeyset, ElectrumRpcUris, TransactionBroadcasterTrait};
 use external_identifier::ExternalIdentifier;
 use http::StatusCode;
 
+use mobile_pay::routes::BumpFeeData;
 use mobile_pay::routes::SignTransactionData;
 use mobile_pay::routes::SignTransactionResponse;
 use onboarding::routes::RotateSpendingKeysetRequest;
@@ -489,3 +490,134 @@ async fn test_mismatched_account_id_keyproof() {
         response.body_string
     );
 }
+
+fn rebuild_with_change_reduced_by(wallet: &Wallet<AnyDatabase>, psbt: &Psbt, amount: u64) -> Psbt {
+    let mut replacement = psbt.clone();
+    let change_index = replacement
+        .outputs
+        .iter()
+        .position(|output| !output.bip32_derivation.is_empty())
+        .unwrap();
+    replacement.unsigned_tx.output[change_index].value -= amount;
+    for input in replacement.inputs.iter_mut() {
+        input.partial_sigs.clear();
+    }
+    let _ = wallet.sign(
+        &mut replacement,
+        SignOptions {
+            remove_partial_sigs: false,
+            ..SignOptions::default()
+        },
+    );
+    replacement
+}
+
+#[tokio::test]
+async fn test_bump_fee_counts_only_fee_delta() {
+    let mut broadcaster_mock = MockTransactionBroadcaster::new();
+    broadcaster_mock
+        .expect_broadcast()
+        .times(2)
+        .returning(|_, _, _| Ok(()));
+
+    let overrides = GenServiceOverrides::new().broadcaster(Arc::new(broadcaster_mock));
+    let bootstrap = gen_services_with_overrides(overrides).await;
+    let client = TestClient::new(bootstrap.router).await;
+
+    let (account, bdk_wallet) =
+        create_default_account_with_predefined_wallet(&client, &bootstrap.services).await;
+    let limit = SpendingLimit {
+        active: true,
+        amount: Money {
+            amount: 5_000,
+            currency_code: USD,
+        },
+        ..Default::default()
+    };
+    let mobile_pay_response = client
+        .put_mobile_pay(&account.id, &build_mobile_pay_request(limit))
+        .await;
+    assert_eq!(
+        mobile_pay_response.status_code,
+        StatusCode::OK,
+        "{}",
+        mobile_pay_response.body_string
+    );
+
+    let original = build_transaction_with_amount(&bdk_wallet, gen_external_wallet_address(), 2_000);
+    let response = client
+        .sign_transaction_with_keyset(
+            &account.id,
+            &account.active_keyset_id,
+            &SignTransactionData {
+                psbt: original.to_string(),
+            },
+        )
+        .await;
+    assert_eq!(
+        response.status_code,
+        StatusCode::OK,
+        "{}",
+        response.body_string
+    );
+    let spent_before_bump = client
+        .get_mobile_pay(&account.id)
+        .await
+        .body
+        .unwrap()
+        .spent
+        .amount;
+
+    // A replacement that also changes what the recipient is paid is not a fee bump
+    let mut changed_recipient = original.clone();
+    let recipient_index = changed_recipient
+        .outputs
+        .iter()
+        .position(|output| output.bip32_derivation.is_empty())
+        .unwrap();
+    changed_recipient.unsigned_tx.output[recipient_index].value += 100;
+    let changed_recipient = rebuild_with_change_reduced_by(&bdk_wallet, &changed_recipient, 600);
+    let response = client
+        .bump_fee_with_keyset(
+            &account.id,
+            &account.active_keyset_id,
+            &BumpFeeData {
+                psbt: changed_recipient.to_string(),
+                original_txid: original.unsigned_tx.txid().to_string(),
+            },
+        )
+        .await;
+    assert_eq!(
+        response.status_code,
+        StatusCode::BAD_REQUEST,
+        "{}",
+        response.body_string
+    );
+
+    let replacement = rebuild_with_change_reduced_by(&bdk_wallet, &original, 500);
+    let response = client
+        .bump_fee_with_keyset(
+            &account.id,
+            &account.active_keyset_id,
+            &BumpFeeData {
+                psbt: replacement.to_string(),
+                original_txid: original.unsigned_tx.txid().to_string(),
+            },
+        )
+        .await;
+    assert_eq!(
+        response.status_code,
+        StatusCode::OK,
+        "{}",
+        response.body_string
+    );
+
+    let spent_after_bump = client
+        .get_mobile_pay(&account.id)
+        .await
+        .body
+        .unwrap()
+        .spent
+        .amount;
+    assert_eq!(spent_after_bump, spent_before_bump + 500);
+}
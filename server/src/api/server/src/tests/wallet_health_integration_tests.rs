@@ -0,0 +1,150 @@
+use account::entities::Network as AccountNetwork;
+use bdk_utils::bdk::bitcoin::address::NetworkUnchecked;
+use bdk_utils::bdk::bitcoin::{Address, Network, ScriptBuf};
+use notification::address_repo::AddressAndKeysetId;
+use time::OffsetDateTime;
+use types::account::identifiers::{AccountId, KeysetId};
+use types::transaction_history::{AccountTransaction, ReceivedOutput, SpentInput};
+use ulid::Ulid;
+
+use crate::tests::gen_services;
+use crate::tests::lib::create_account;
+
+fn random_signet_address() -> Address<NetworkUnchecked> {
+    // [W-5648]: Use `as_unchecked` once it's available in BDK.
+    let script = ScriptBuf::from(Ulid::new().to_bytes().to_vec());
+    Address::p2wsh(&script, Network::Signet)
+        .to_string()
+        .parse()
+        .unwrap()
+}
+
+fn account_transaction(
+    account_id: &AccountId,
+    txid: &str,
+    block_height: u64,
+    received_outputs: Vec<ReceivedOutput>,
+    spent_inputs: Vec<SpentInput>,
+) -> AccountTransaction {
+    AccountTransaction {
+        account_id: account_id.clone(),
+        txid: txid.to_string(),
+        block_height,
+        block_hash: format!("hash-{block_height}"),
+        confirmed_at: OffsetDateTime::now_utc(),
+        received_outputs,
+        spent_inputs,
+        fee_sats: None,
+        created_at: OffsetDateTime::now_utc(),
+    }
+}
+
+fn received_output(
+    vout: u32,
+    address: &Address<NetworkUnchecked>,
+    amount_sats: u64,
+) -> ReceivedOutput {
+    ReceivedOutput {
+        vout,
+        address: address.clone().assume_checked().to_string(),
+        amount_sats,
+    }
+}
+
+#[tokio::test]
+async fn test_wallet_health_reports_inactive_keyset_funds_and_dust() {
+    let bootstrap = gen_services().await;
+    let account = create_account(&bootstrap.services, AccountNetwork::BitcoinSignet, None).await;
+
+    // One address on the active keyset and one on a keyset the account has rotated away from
+    let active_address = random_signet_address();
+    let inactive_address = random_signet_address();
+    let inactive_keyset_id = KeysetId::gen().unwrap();
+    let mut address_repo = bootstrap.services.address_repo.clone();
+    address_repo
+        .insert(
+            &[
+                AddressAndKeysetId::new(active_address.clone(), account.active_keyset_id.clone()),
+                AddressAndKeysetId::new(inactive_address.clone(), inactive_keyset_id.clone()),
+            ],
+            &account.id,
+        )
+        .await
+        .unwrap();
+
+    let transactions = [
+        account_transaction(
+            &account.id,
+            "active-deposit",
+            100,
+            vec![
+                received_output(0, &active_address, 50_000),
+                received_output(1, &active_address, 500),
+            ],
+            vec![],
+        ),
+        account_transaction(
+            &account.id,
+            "inactive-deposit",
+            101,
+            vec![
+                received_output(0, &inactive_address, 20_000),
+                received_output(1, &inactive_address, 30_000),
+            ],
+            vec![],
+        ),
+        account_transaction(
+            &account.id,
+            "inactive-sweep",
+            102,
+            vec![received_output(0, &active_address, 29_000)],
+            vec![SpentInput {
+                previous_txid: "inactive-deposit".to_string(),
+                previous_vout: 1,
+                amount_sats: Some(30_000),
+            }],
+        ),
+    ];
+    for transaction in &transactions {
+        bootstrap
+            .services
+            .transaction_history_service
+            .repository
+            .persist(transaction)
+            .await
+            .unwrap();
+    }
+
+    let state = workers::jobs::WorkerState {
+        config: http_server::config::extract(None).unwrap(),
+        notification_service: bootstrap.services.notification_service.clone(),
+        account_service: bootstrap.services.account_service.clone(),
+        recovery_service: bootstrap.services.recovery_service.clone(),
+        address_repo,
+        chain_indexer_service: bootstrap.services.chain_indexer_service.clone(),
+        sqs: bootstrap.services.sqs.clone(),
+        feature_flags_service: bootstrap.services.feature_flags_service.clone(),
+        recovery_relationship_service: bootstrap.services.recovery_relationship_service.clone(),
+        exchange_rate_service: bootstrap.services.exchange_rate_service.clone(),
+        rate_history_service: bootstrap.services.rate_history_service.clone(),
+        transaction_history_service: bootstrap.services.transaction_history_service.clone(),
+    };
+    let health = workers::wallet_health::measure(&state)
+        .await
+        .unwrap()
+        .network(Network::Signet);
+
+    // Only the unspent output on the inactive keyset is reported
+    let inactive_keysets = health
+        .inactive_keysets
+        .iter()
+        .filter(|f| f.account_id == account.id)
+        .collect::<Vec<_>>();
+    assert_eq!(inactive_keysets.len(), 1);
+    assert_eq!(inactive_keysets[0].keyset_id, inactive_keyset_id);
+    assert_eq!(inactive_keysets[0].balance_sats, 20_000);
+    assert_eq!(inactive_keysets[0].utxo_count, 1);
+    assert!(health.inactive_keyset_accounts >= 1);
+    assert!(health.inactive_keyset_balance_sats >= 20_000);
+    assert!(health.dust_utxos >= 1);
+}
@@ -1 +1,2 @@
 pub mod identifiers;
+pub mod risk;
@@ -0,0 +1,97 @@
+use base32::Alphabet;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use strum_macros::Display;
+use time::{serde::rfc3339, OffsetDateTime};
+use utoipa::ToSchema;
+
+use crate::account::identifiers::AccountId;
+
+/// What onboarding decided to do with an account, from least to most severe.
+#[derive(
+    Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ToSchema,
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RiskDecision {
+    Allow,
+    Challenge,
+    Deny,
+}
+
+/// The aggregate result of the account validation rules, kept on the account for support.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct RiskAssessment {
+    pub decision: RiskDecision,
+    /// The sum of the factors' scores.
+    pub score: u32,
+    pub factors: Vec<RiskFactor>,
+    #[serde(with = "rfc3339")]
+    pub assessed_at: OffsetDateTime,
+}
+
+/// A rule's contribution to an assessment. A later assessment that produces a factor with the
+/// same key replaces the earlier one rather than adding to it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct RiskFactor {
+    pub key: String,
+    pub score: u32,
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Display, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum OnboardingSignalKind {
+    Touchpoint,
+}
+
+/// Records that an account was onboarded with an identifier, so that the number of accounts
+/// onboarded with the same identifier can be counted.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OnboardingSignal {
+    /// See [`OnboardingSignal::key`].
+    #[serde(rename = "partition_key")]
+    pub key: String,
+    #[serde(rename = "sort_key")]
+    pub account_id: AccountId,
+    #[serde(with = "rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl OnboardingSignal {
+    pub fn new(kind: OnboardingSignalKind, value: &str, account_id: AccountId) -> Self {
+        Self {
+            key: Self::key(kind, value),
+            account_id,
+            created_at: OffsetDateTime::now_utc(),
+        }
+    }
+
+    // The identifiers are hashed so the table doesn't hold contact details
+    pub fn key(kind: OnboardingSignalKind, value: &str) -> String {
+        let hash = Sha256::digest(value.trim().to_lowercase().as_bytes());
+        format!("{kind}#{}", base32::encode(Alphabet::Crockford, &hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OnboardingSignal, OnboardingSignalKind, RiskDecision};
+
+    #[test]
+    fn test_signal_key_normalizes_value() {
+        let key = OnboardingSignal::key(OnboardingSignalKind::Touchpoint, "Someone@Example.com ");
+        assert_eq!(
+            key,
+            OnboardingSignal::key(OnboardingSignalKind::Touchpoint, "someone@example.com")
+        );
+        assert!(key.starts_with("TOUCHPOINT#"));
+    }
+
+    #[test]
+    fn test_risk_decision_ordering() {
+        assert!(RiskDecision::Allow < RiskDecision::Challenge);
+        assert!(RiskDecision::Challenge < RiskDecision::Deny);
+    }
+}
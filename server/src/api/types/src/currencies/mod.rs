@@ -0,0 +1,233 @@
+use crate::currencies::Currency::{Bitcoin, Fiat};
+use crate::currencies::CurrencyCode::{BTC, XXX};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use strum_macros::{EnumIter, IntoStaticStr};
+use utoipa::ToSchema;
+
+pub use registry::RateSource;
+
+mod registry;
+
+static SUPPORTED_CURRENCIES: Lazy<Vec<CurrencyCode>> =
+    Lazy::new(|| registry::REGISTRY.supported_currency_codes());
+
+pub enum Currency {
+    Fiat(FiatCurrency),
+    Bitcoin(BitcoinCurrency),
+}
+
+impl Currency {
+    /// Fiat currencies that at least one rate source can quote.
+    pub fn supported_currency_codes() -> Vec<CurrencyCode> {
+        SUPPORTED_CURRENCIES.to_vec()
+    }
+
+    /// Fiat currencies that the given rate source can quote.
+    pub fn supported_currency_codes_for(source: &RateSource) -> Vec<CurrencyCode> {
+        registry::REGISTRY.supported_currency_codes_for(source)
+    }
+
+    pub fn supported_fiat_currencies() -> Vec<FiatCurrency> {
+        Self::fiat_currencies(Self::supported_currency_codes())
+    }
+
+    /// Display data for the given fiat currencies. Non-fiat codes are skipped.
+    pub fn fiat_currencies(codes: Vec<CurrencyCode>) -> Vec<FiatCurrency> {
+        codes
+            .into_iter()
+            .map(Currency::from)
+            .filter_map(|c| match c {
+                Fiat(f) => Some(f),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+pub struct BitcoinCurrency {
+    pub currency: CurrencyData,
+    fractional_unit_configuration: BitcoinFractionalUnitConfiguration,
+}
+
+struct BitcoinFractionalUnitConfiguration {
+    name: String,
+    name_plural: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct FiatCurrency {
+    pub currency: CurrencyData,
+    fiat_display_configuration: FiatDisplayConfiguration,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct FiatDisplayConfiguration {
+    name: String,
+    display_country_code: String,
+    symbol_placement: SymbolPlacement,
+}
+
+/// Which side of the amount a currency's symbol is written on, e.g. "$5" or "5 zł".
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum SymbolPlacement {
+    #[default]
+    Prefix,
+    Suffix,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct CurrencyData {
+    pub text_code: String,
+    pub unit_symbol: String,
+    pub fractional_digits: u8,
+}
+
+// ISO 4217 currency codes. Display data and rate source support for fiat currencies lives in
+// `registry.json`. Adding a currency still takes a variant here as well as a registry entry, since
+// codes are deserialized into this enum; the registry tests check that the two stay in sync.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, ToSchema, EnumIter, IntoStaticStr)]
+pub enum CurrencyCode {
+    AED = 784,
+    ALL = 8,
+    AMD = 51,
+    ARS = 32,
+    AUD = 36,
+    AZN = 944,
+    BAM = 977,
+    BDT = 50,
+    BHD = 48,
+    BMD = 60,
+    BOB = 68,
+    BRL = 986,
+    BYN = 933,
+    CAD = 124,
+    CHF = 756,
+    CLP = 152,
+    CNY = 156,
+    COP = 170,
+    CRC = 188,
+    CUP = 192,
+    CZK = 203,
+    DKK = 208,
+    DOP = 214,
+    DZD = 12,
+    EGP = 818,
+    EUR = 978,
+    GBP = 826,
+    GEL = 981,
+    GHS = 936,
+    GTQ = 320,
+    HKD = 344,
+    HNL = 340,
+    HUF = 348,
+    IDR = 360,
+    ILS = 376,
+    INR = 356,
+    IQD = 368,
+    IRR = 364,
+    ISK = 352,
+    JMD = 388,
+    JOD = 400,
+    JPY = 392,
+    KES = 404,
+    KGS = 417,
+    KHR = 116,
+    KRW = 410,
+    KWD = 414,
+    KZT = 398,
+    LBP = 422,
+    LKR = 144,
+    MAD = 504,
+    MDL = 498,
+    MKD = 807,
+    MMK = 104,
+    MNT = 496,
+    MUR = 480,
+    MXN = 484,
+    MYR = 458,
+    NAD = 516,
+    NGN = 566,
+    NIO = 558,
+    NOK = 578,
+    NPR = 524,
+    NZD = 554,
+    OMR = 512,
+    PAB = 590,
+    PEN = 604,
+    PHP = 608,
+    PKR = 586,
+    PLN = 985,
+    QAR = 634,
+    RON = 946,
+    RSD = 941,
+    RUB = 643,
+    SAR = 682,
+    SEK = 752,
+    SGD = 702,
+    SSP = 728,
+    THB = 764,
+    TND = 788,
+    TRY = 949,
+    TTD = 780,
+    TWD = 901,
+    UAH = 980,
+    UGX = 800,
+    USD = 840,
+    UYU = 858,
+    UZS = 860,
+    VES = 928,
+    VND = 704,
+    ZAR = 710,
+    XXX = 999,  // Defined as "no currency" by ISO-4217.
+    BTC = 1001, // Not an ISO code!
+}
+
+impl From<CurrencyCode> for Currency {
+    fn from(value: CurrencyCode) -> Self {
+        match value {
+            BTC => Bitcoin(BitcoinCurrency {
+                currency: CurrencyData {
+                    text_code: "BTC".to_string(),
+                    unit_symbol: "₿".to_string(),
+                    fractional_digits: 8,
+                },
+                fractional_unit_configuration: BitcoinFractionalUnitConfiguration {
+                    name: "sat".to_string(),
+                    name_plural: "sats".to_string(),
+                },
+            }),
+            XXX => Fiat(FiatCurrency {
+                currency: CurrencyData {
+                    text_code: "XXX".to_string(),
+                    unit_symbol: "XXX".to_string(),
+                    fractional_digits: 2,
+                },
+                fiat_display_configuration: FiatDisplayConfiguration {
+                    name: "No Currency".to_string(),
+                    display_country_code: "XX".to_string(),
+                    symbol_placement: SymbolPlacement::Prefix,
+                },
+            }),
+            fiat => Fiat(registry::REGISTRY.fiat_currency(&fiat)),
+        }
+    }
+}
+
+impl CurrencyCode {
+    /// The ISO-4217 minor unit exponent, e.g. 2 for currencies counted in cents.
+    pub fn fractional_digits(&self) -> u8 {
+        match Currency::from(self.clone()) {
+            Fiat(f) => f.currency.fractional_digits,
+            Bitcoin(b) => b.currency.fractional_digits,
+        }
+    }
+}
+
+impl Display for CurrencyCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let text_code: &'static str = self.into();
+        write!(f, "{}", text_code)
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use super::{CurrencyCode, CurrencyData, FiatCurrency, FiatDisplayConfiguration, SymbolPlacement};
+
+pub(super) static REGISTRY: Lazy<Registry> = Lazy::new(|| {
+    serde_json::from_str(include_str!("registry.json")).expect("Invalid currency registry")
+});
+
+/// An upstream source of exchange rates, as listed in the registry's support matrix.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Hash)]
+pub enum RateSource {
+    Bitstamp,
+    CashApp,
+    Coingecko,
+    Coinmarketcap,
+}
+
+#[derive(Deserialize)]
+struct FiatCurrencyEntry {
+    code: CurrencyCode,
+    numeric_code: u16,
+    name: String,
+    unit_symbol: String,
+    symbol_placement: SymbolPlacement,
+    fractional_digits: u8,
+    display_country_code: String,
+}
+
+#[derive(Deserialize)]
+pub(super) struct Registry {
+    currencies: Vec<FiatCurrencyEntry>,
+    /// The fiat currencies each rate source can quote.
+    providers: HashMap<RateSource, Vec<CurrencyCode>>,
+}
+
+impl Registry {
+    fn entry(&self, code: &CurrencyCode) -> Option<&FiatCurrencyEntry> {
+        let numeric_code = code.clone() as u16;
+        self.currencies
+            .iter()
+            .find(|e| e.numeric_code == numeric_code)
+    }
+
+    /// Currencies quoted by at least one rate source, in registry order.
+    pub(super) fn supported_currency_codes(&self) -> Vec<CurrencyCode> {
+        let quoted = self
+            .providers
+            .values()
+            .flatten()
+            .map(|c| c.clone() as u16)
+            .collect::<HashSet<_>>();
+
+        self.currencies
+            .iter()
+            .filter(|e| quoted.contains(&(e.code.clone() as u16)))
+            .map(|e| e.code.clone())
+            .collect()
+    }
+
+    /// Currencies quoted by the given rate source, in registry order.
+    pub(super) fn supported_currency_codes_for(&self, source: &RateSource) -> Vec<CurrencyCode> {
+        let Some(quoted) = self.providers.get(source) else {
+            return Vec::new();
+        };
+
+        self.currencies
+            .iter()
+            .filter(|e| quoted.contains(&e.code))
+            .map(|e| e.code.clone())
+            .collect()
+    }
+
+    // Falls back to the bare currency code for display if a currency is missing from the
+    // registry, rather than failing.
+    pub(super) fn fiat_currency(&self, code: &CurrencyCode) -> FiatCurrency {
+        match self.entry(code) {
+            Some(entry) => FiatCurrency {
+                currency: CurrencyData {
+                    text_code: code.to_string(),
+                    unit_symbol: entry.unit_symbol.clone(),
+                    fractional_digits: entry.fractional_digits,
+                },
+                fiat_display_configuration: FiatDisplayConfiguration {
+                    name: entry.name.clone(),
+                    display_country_code: entry.display_country_code.clone(),
+                    symbol_placement: entry.symbol_placement,
+                },
+            },
+            None => FiatCurrency {
+                currency: CurrencyData {
+                    text_code: code.to_string(),
+                    unit_symbol: code.to_string(),
+                    fractional_digits: 2,
+                },
+                fiat_display_configuration: FiatDisplayConfiguration {
+                    name: code.to_string(),
+                    display_country_code: "XX".to_string(),
+                    symbol_placement: SymbolPlacement::Prefix,
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use strum::IntoEnumIterator;
+
+    use super::{RateSource, REGISTRY};
+    use crate::currencies::CurrencyCode::{self, AUD, BTC, EUR, GBP, JPY, USD, XXX};
+    use crate::currencies::{Currency, SymbolPlacement};
+
+    #[test]
+    fn test_registry_matches_currency_codes() {
+        let fiat_codes = CurrencyCode::iter()
+            .filter(|c| *c != BTC && *c != XXX)
+            .collect::<Vec<_>>();
+        assert_eq!(REGISTRY.currencies.len(), fiat_codes.len());
+
+        for code in fiat_codes {
+            let entry = REGISTRY
+                .entry(&code)
+                .unwrap_or_else(|| panic!("{code} is missing from the registry"));
+            assert_eq!(entry.code, code);
+            assert_eq!(entry.display_country_code.len(), 2);
+            assert!(entry.fractional_digits <= 3);
+        }
+    }
+
+    #[test]
+    fn test_every_currency_has_a_rate_source() {
+        let supported = Currency::supported_currency_codes();
+        assert_eq!(supported.len(), REGISTRY.currencies.len());
+        assert_eq!(
+            supported
+                .iter()
+                .map(|c| c.clone() as u16)
+                .collect::<HashSet<_>>()
+                .len(),
+            supported.len()
+        );
+        assert!(!supported.contains(&BTC));
+        assert!(!supported.contains(&XXX));
+    }
+
+    #[test]
+    fn test_supported_currency_codes_for() {
+        assert_eq!(
+            Currency::supported_currency_codes_for(&RateSource::Bitstamp),
+            vec![EUR, GBP, USD]
+        );
+
+        let coingecko = Currency::supported_currency_codes_for(&RateSource::Coingecko);
+        assert!(coingecko.contains(&AUD));
+        assert!(coingecko.contains(&JPY));
+    }
+
+    #[test]
+    fn test_display_data() {
+        assert_eq!(JPY.fractional_digits(), 0);
+        assert_eq!(USD.fractional_digits(), 2);
+        assert_eq!(BTC.fractional_digits(), 8);
+        assert_eq!(USD.to_string(), "USD");
+
+        let Currency::Fiat(pln) = Currency::from(CurrencyCode::PLN) else {
+            panic!("PLN should be a fiat currency");
+        };
+        assert_eq!(pln.currency.unit_symbol, "zł");
+        assert_eq!(
+            pln.fiat_display_configuration.symbol_placement,
+            SymbolPlacement::Suffix
+        );
+    }
+}
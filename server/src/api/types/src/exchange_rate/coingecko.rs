@@ -2,6 +2,7 @@ use crate::currencies::CurrencyCode;
 use reqwest::{Client, RequestBuilder};
 use serde::de::{SeqAccess, Visitor};
 use serde::{de, Deserialize, Deserializer};
+use std::collections::HashMap;
 use std::ops::Sub;
 use std::{env, fmt};
 use time::{Duration, OffsetDateTime};
@@ -12,6 +13,12 @@ pub struct Response {
     pub prices: Vec<PriceAt>,
 }
 
+/// Deserializes Coingecko spot price responses, keyed by lowercase currency code.
+#[derive(Deserialize)]
+pub struct SpotResponse {
+    pub bitcoin: HashMap<String, f64>,
+}
+
 #[derive(Debug)]
 pub struct PriceAt {
     pub timestamp: OffsetDateTime,
@@ -76,6 +83,7 @@ impl Default for RateProvider {
 
 const ROOT_URL: &str = "https://pro-api.coingecko.com";
 const HISTORICAL_RATE_REQUEST_COIN: &str = "bitcoin";
+const SPOT_RATE_REQUEST_COIN: &str = "bitcoin";
 const HISTORICAL_RATE_REQUEST_GRANULARITY: Duration = Duration::minutes(5);
 
 impl RateProvider {
@@ -110,4 +118,14 @@ impl RateProvider {
                 ("x_cg_pro_api_key", self.api_key.clone()),
             ])
     }
+
+    pub fn spot_rate_request(&self, currency: &CurrencyCode) -> RequestBuilder {
+        Client::new()
+            .get(format!("{}/api/v3/simple/price", &self.root_url))
+            .query(&[
+                ("ids", SPOT_RATE_REQUEST_COIN.to_string()),
+                ("vs_currencies", currency.to_string().to_lowercase()),
+                ("x_cg_pro_api_key", self.api_key.clone()),
+            ])
+    }
 }
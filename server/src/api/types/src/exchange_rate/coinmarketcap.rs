@@ -17,6 +17,18 @@ pub struct ResponseData {
     pub btc: Vec<CryptoQuotes>,
 }
 
+/// Deserializes Coinmarketcap latest quote responses from CoinmarketcapRateProvider.
+#[derive(Deserialize)]
+pub struct LatestResponse {
+    pub data: LatestResponseData,
+}
+
+#[derive(Deserialize)]
+pub struct LatestResponseData {
+    #[serde(rename = "BTC")]
+    pub btc: Vec<Quotes>,
+}
+
 #[derive(Deserialize)]
 pub struct CryptoQuotes {
     pub quotes: Vec<Quotes>,
@@ -35,7 +47,7 @@ pub struct Value {
 #[derive(Clone)]
 pub struct RateProvider {
     pub root_url: String,
-    api_key: String,
+    pub api_key: String,
 }
 
 impl Default for RateProvider {
@@ -47,6 +59,7 @@ impl Default for RateProvider {
 const ROOT_URL: &str = "https://pro-api.coinmarketcap.com/v2/";
 const HISTORICAL_RATE_REQUEST_SYMBOL: &str = "BTC";
 const HISTORICAL_RATE_REQUEST_COUNT: &str = "1";
+const LATEST_RATE_REQUEST_SYMBOL: &str = "BTC";
 
 impl RateProvider {
     pub fn new() -> Self {
@@ -83,4 +96,17 @@ impl RateProvider {
             ])
             .header("X-CMC_PRO_API_KEY", &self.api_key)
     }
+
+    pub fn latest_rate_request(&self, currency: &CurrencyCode) -> RequestBuilder {
+        Client::new()
+            .get(format!(
+                "{}{}",
+                &self.root_url, "cryptocurrency/quotes/latest"
+            ))
+            .query(&[
+                ("symbol", LATEST_RATE_REQUEST_SYMBOL.to_string()),
+                ("convert", currency.to_string()),
+            ])
+            .header("X-CMC_PRO_API_KEY", &self.api_key)
+    }
 }
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::currencies::CurrencyCode;
+use crate::serde::{deserialize_ts, serialize_ts};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExchangeRateSnapshotSource {
+    /// Recorded from the spot rate by the snapshot worker.
+    Spot,
+    /// Filled in from a historical rate provider.
+    Backfill,
+}
+
+/// A point-in-time price of one bitcoin in a fiat currency, as kept in the rate history store.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExchangeRateSnapshot {
+    #[serde(rename = "partition_key")]
+    pub currency_code: CurrencyCode,
+    // Stored as a unix timestamp so snapshots for a currency sort chronologically
+    #[serde(
+        rename = "sort_key",
+        serialize_with = "serialize_ts",
+        deserialize_with = "deserialize_ts"
+    )]
+    pub timestamp: OffsetDateTime,
+    pub rate: f64,
+    pub source: ExchangeRateSnapshotSource,
+}
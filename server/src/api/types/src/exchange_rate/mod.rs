@@ -9,6 +9,7 @@ pub mod bitstamp;
 pub mod cash;
 pub mod coingecko;
 pub mod coinmarketcap;
+pub mod history;
 pub mod local_rate_provider;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
@@ -10,3 +10,5 @@ pub mod notification;
 #[cfg(feature = "recovery")]
 pub mod recovery;
 pub mod serde;
+#[cfg(feature = "transaction_history")]
+pub mod transaction_history;
@@ -2,7 +2,9 @@ use std::{collections::HashSet, hash::Hash};
 
 use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
-use strum_macros::{Display as StrumDisplay, EnumIter};
+use strum_macros::{Display as StrumDisplay, EnumIter, EnumString};
+use time::{Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use time_tz::{timezones, OffsetDateTimeExt, OffsetResult, PrimitiveDateTimeExt};
 use utoipa::ToSchema;
 
 #[derive(
@@ -25,6 +27,8 @@ pub enum NotificationChannel {
     Push,
     #[serde(alias = "s_m_s")]
     Sms,
+    #[serde(alias = "webhook")]
+    Webhook,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Default, ToSchema)]
@@ -32,6 +36,72 @@ pub struct NotificationsPreferences {
     pub account_security: HashSet<NotificationChannel>,
     pub money_movement: HashSet<NotificationChannel>,
     pub product_marketing: HashSet<NotificationChannel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_hours: Option<QuietHours>,
+}
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// A daily window, in the customer's local time, during which MoneyMovement notifications are
+/// held back and sent as a digest once it ends. The window may wrap past midnight.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, ToSchema)]
+pub struct QuietHours {
+    // Minutes after local midnight
+    pub start_minute: u16,
+    pub end_minute: u16,
+    // The customer's IANA time zone (e.g. "America/New_York"), so the window follows daylight
+    // saving time without the app having to update it
+    pub time_zone: String,
+}
+
+impl QuietHours {
+    pub fn is_valid(&self) -> bool {
+        i64::from(self.start_minute) < MINUTES_PER_DAY
+            && i64::from(self.end_minute) < MINUTES_PER_DAY
+            && self.start_minute != self.end_minute
+            && timezones::get_by_name(&self.time_zone).is_some()
+    }
+
+    /// Returns when the quiet hours that `at` falls in end, or `None` if `at` isn't during quiet
+    /// hours.
+    pub fn quiet_until(&self, at: OffsetDateTime) -> Option<OffsetDateTime> {
+        if !self.is_valid() {
+            return None;
+        }
+        let time_zone = timezones::get_by_name(&self.time_zone)?;
+        let local = at.to_timezone(time_zone);
+        let minute_of_day = i64::from(local.hour()) * 60 + i64::from(local.minute());
+        let (start, end) = (i64::from(self.start_minute), i64::from(self.end_minute));
+
+        let end_day = if start < end {
+            // e.g. 01:00-06:00
+            if !(start..end).contains(&minute_of_day) {
+                return None;
+            }
+            0
+        } else if minute_of_day >= start {
+            // e.g. 22:00-07:00, before midnight
+            1
+        } else if minute_of_day < end {
+            // e.g. 22:00-07:00, after midnight
+            0
+        } else {
+            return None;
+        };
+
+        let end = PrimitiveDateTime::new(
+            local.date() + Duration::days(end_day),
+            Time::from_hms((end / 60) as u8, (end % 60) as u8, 0).ok()?,
+        );
+        let quiet_until = match end.assume_timezone(time_zone) {
+            OffsetResult::Some(end) => end,
+            // The clocks went back, so the end happens twice; the first is when it's first reached
+            OffsetResult::Ambiguous(end, _) => end,
+            // The clocks went forward past the end, so keep the offset from before they did
+            OffsetResult::None => end.assume_offset(local.offset()),
+        };
+        Some(quiet_until.to_offset(UtcOffset::UTC))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -149,3 +219,112 @@ pub enum NotificationCategory {
     MoneyMovement,
     ProductMarketing,
 }
+
+/// The language and region an account's notifications are written in.
+#[derive(
+    Deserialize,
+    Serialize,
+    StrumDisplay,
+    EnumString,
+    Clone,
+    Debug,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    ToSchema,
+    EnumIter,
+)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en-US")]
+    #[strum(serialize = "en-US")]
+    EnUs,
+    #[serde(rename = "es-US")]
+    #[strum(serialize = "es-US")]
+    EsUs,
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::QuietHours;
+
+    #[test]
+    fn test_quiet_until() {
+        // 22:00-07:00 in New York, which is UTC-05:00 in winter
+        let overnight = QuietHours {
+            start_minute: 22 * 60,
+            end_minute: 7 * 60,
+            time_zone: "America/New_York".to_string(),
+        };
+        assert_eq!(
+            overnight.quiet_until(datetime!(2024-03-06 04:00 UTC)),
+            Some(datetime!(2024-03-06 12:00 UTC))
+        );
+        assert_eq!(
+            overnight.quiet_until(datetime!(2024-03-06 08:00 UTC)),
+            Some(datetime!(2024-03-06 12:00 UTC))
+        );
+        assert_eq!(overnight.quiet_until(datetime!(2024-03-06 12:00 UTC)), None);
+        assert_eq!(overnight.quiet_until(datetime!(2024-03-06 20:00 UTC)), None);
+
+        // 01:00-06:00 in UTC
+        let early = QuietHours {
+            start_minute: 60,
+            end_minute: 6 * 60,
+            time_zone: "UTC".to_string(),
+        };
+        assert_eq!(
+            early.quiet_until(datetime!(2024-03-06 01:00 UTC)),
+            Some(datetime!(2024-03-06 06:00 UTC))
+        );
+        assert_eq!(early.quiet_until(datetime!(2024-03-06 00:59 UTC)), None);
+
+        let invalid = QuietHours {
+            start_minute: 60,
+            end_minute: 60,
+            time_zone: "UTC".to_string(),
+        };
+        assert!(!invalid.is_valid());
+        assert_eq!(invalid.quiet_until(datetime!(2024-03-06 01:00 UTC)), None);
+
+        let unknown_time_zone = QuietHours {
+            time_zone: "Mars/Olympus_Mons".to_string(),
+            ..early
+        };
+        assert!(!unknown_time_zone.is_valid());
+    }
+
+    #[test]
+    fn test_quiet_until_across_daylight_saving_changes() {
+        let overnight = QuietHours {
+            start_minute: 22 * 60,
+            end_minute: 7 * 60,
+            time_zone: "America/New_York".to_string(),
+        };
+        // Clocks go forward on the night of 2024-03-09, so 07:00 is UTC-04:00
+        assert_eq!(
+            overnight.quiet_until(datetime!(2024-03-10 04:00 UTC)),
+            Some(datetime!(2024-03-10 11:00 UTC))
+        );
+        // And back on the night of 2024-11-02, so 07:00 is UTC-05:00
+        assert_eq!(
+            overnight.quiet_until(datetime!(2024-11-03 03:00 UTC)),
+            Some(datetime!(2024-11-03 12:00 UTC))
+        );
+
+        // 02:30 doesn't exist in New York on 2024-03-10
+        let into_gap = QuietHours {
+            start_minute: 60,
+            end_minute: 2 * 60 + 30,
+            ..overnight
+        };
+        assert_eq!(
+            into_gap.quiet_until(datetime!(2024-03-10 06:30 UTC)),
+            Some(datetime!(2024-03-10 07:30 UTC))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use external_identifier::ExternalIdentifier;
+use serde::{Deserialize, Serialize};
+use time::{serde::rfc3339, OffsetDateTime};
+use ulid::Ulid;
+use urn::Urn;
+use utoipa::ToSchema;
+
+use crate::account::identifiers::AccountId;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecoveryHistoryEntryId(urn::Urn);
+
+impl FromStr for RecoveryHistoryEntryId {
+    type Err = urn::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Urn::from_str(s)?.into())
+    }
+}
+
+impl From<urn::Urn> for RecoveryHistoryEntryId {
+    fn from(urn: urn::Urn) -> Self {
+        Self(urn)
+    }
+}
+
+impl ExternalIdentifier<Ulid> for RecoveryHistoryEntryId {
+    fn namespace() -> &'static str {
+        "recovery-history-entry"
+    }
+}
+
+impl RecoveryHistoryEntryId {
+    pub fn gen() -> Result<Self, external_identifier::Error> {
+        Self::new(Ulid::new())
+    }
+}
+
+impl Display for RecoveryHistoryEntryId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+pub enum RecoveryHistoryAction {
+    DelayNotifyCreated,
+    DelayNotifyCanceled,
+    DelayNotifyContested,
+    DelayNotifyCompleted,
+    DelayNotifyCanceledWithToken,
+    SocialRecoveryCreated,
+    SocialRecoveryCanceled,
+    SocialRecoveryContested,
+    SocialRecoveryCompleted,
+    CommsVerificationSent,
+    CommsVerificationVerified,
+    AuthKeysRotated,
+    SocialChallengeResponded,
+}
+
+/// An append-only record of something that happened to an account's recovery, along with who did
+/// it and which factors signed for it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecoveryHistoryEntry {
+    #[serde(rename = "partition_key")]
+    pub account_id: AccountId,
+    // Ulid-based, so entries for an account sort chronologically
+    #[serde(rename = "sort_key")]
+    pub id: RecoveryHistoryEntryId,
+    pub action: RecoveryHistoryAction,
+    // The account that took the action; a Trusted Contact's account for social challenge responses
+    pub actor_account_id: AccountId,
+    pub app_signed: bool,
+    pub hw_signed: bool,
+    #[serde(with = "rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl RecoveryHistoryEntry {
+    pub fn new(
+        account_id: &AccountId,
+        action: RecoveryHistoryAction,
+        actor_account_id: &AccountId,
+        app_signed: bool,
+        hw_signed: bool,
+    ) -> Result<Self, external_identifier::Error> {
+        Ok(Self {
+            account_id: account_id.to_owned(),
+            id: RecoveryHistoryEntryId::gen()?,
+            action,
+            actor_account_id: actor_account_id.to_owned(),
+            app_signed,
+            hw_signed,
+            created_at: OffsetDateTime::now_utc(),
+        })
+    }
+}
@@ -1,2 +1,3 @@
 pub mod challenge;
+pub mod policy;
 pub mod relationship;
@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use time::{serde::rfc3339, Duration, OffsetDateTime};
+
+use crate::account::identifiers::AccountId;
+
+use super::{
+    challenge::SocialChallengeResponse,
+    relationship::{RecoveryRelationship, RecoveryRelationshipId},
+};
+
+const DEFAULT_THRESHOLD: u32 = 1;
+const DEFAULT_WEIGHT: u32 = 1;
+
+/// A customer's policy for how many of their endorsed Trusted Contacts need to respond
+/// to a social challenge before it can be used for recovery.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryPolicy {
+    #[serde(rename = "partition_key")]
+    pub account_id: AccountId,
+    // The total weight of responses required to satisfy a challenge (the k in k-of-n)
+    pub threshold: u32,
+    // Trusted Contacts that aren't listed here count with a weight of 1
+    #[serde(default)]
+    pub weights: HashMap<RecoveryRelationshipId, u32>,
+    // How long a relationship needs to have existed before its responses count
+    pub min_relationship_age_secs: i64,
+    #[serde(with = "rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "rfc3339")]
+    pub updated_at: OffsetDateTime,
+}
+
+impl RecoveryPolicy {
+    #[must_use]
+    pub fn new(
+        account_id: &AccountId,
+        threshold: u32,
+        weights: HashMap<RecoveryRelationshipId, u32>,
+        min_relationship_age_secs: i64,
+    ) -> Self {
+        Self {
+            account_id: account_id.to_owned(),
+            threshold,
+            weights,
+            min_relationship_age_secs,
+            created_at: OffsetDateTime::now_utc(),
+            updated_at: OffsetDateTime::now_utc(),
+        }
+    }
+
+    /// The policy used for accounts that haven't set one: any single endorsed Trusted Contact
+    /// can satisfy a challenge.
+    #[must_use]
+    pub fn default_for_account(account_id: &AccountId) -> Self {
+        Self::new(account_id, DEFAULT_THRESHOLD, HashMap::new(), 0)
+    }
+
+    pub fn with_updated_at(&self, updated_at: OffsetDateTime) -> Self {
+        Self {
+            updated_at,
+            ..self.to_owned()
+        }
+    }
+
+    pub fn weight_for(&self, id: &RecoveryRelationshipId) -> u32 {
+        self.weights.get(id).copied().unwrap_or(DEFAULT_WEIGHT)
+    }
+
+    pub fn min_relationship_age(&self) -> Duration {
+        Duration::seconds(self.min_relationship_age_secs)
+    }
+
+    /// Whether a relationship's responses count toward this policy at `now`. Only endorsed
+    /// relationships that are old enough qualify.
+    pub fn is_qualifying_relationship(
+        &self,
+        relationship: &RecoveryRelationship,
+        now: OffsetDateTime,
+    ) -> bool {
+        match relationship {
+            RecoveryRelationship::Endorsed(r) => {
+                now - r.common_fields.created_at >= self.min_relationship_age()
+            }
+            _ => false,
+        }
+    }
+
+    /// Sums the weights of the responses that come from qualifying relationships. Each
+    /// relationship is counted at most once.
+    pub fn satisfied_weight(
+        &self,
+        responses: &[SocialChallengeResponse],
+        relationships: &[RecoveryRelationship],
+        now: OffsetDateTime,
+    ) -> u32 {
+        let qualifying = relationships
+            .iter()
+            .filter(|r| self.is_qualifying_relationship(r, now))
+            .map(|r| &r.common_fields().id)
+            .collect::<HashSet<_>>();
+
+        responses
+            .iter()
+            .map(|r| &r.recovery_relationship_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter(|id| qualifying.contains(id))
+            .fold(0u32, |total, id| total.saturating_add(self.weight_for(id)))
+    }
+
+    /// The highest weight the given relationships could contribute, ignoring relationship age.
+    pub fn attainable_weight(&self, relationships: &[RecoveryRelationship]) -> u32 {
+        relationships
+            .iter()
+            .filter(|r| matches!(r, RecoveryRelationship::Endorsed(_)))
+            .fold(0u32, |total, r| {
+                total.saturating_add(self.weight_for(&r.common_fields().id))
+            })
+    }
+
+    pub fn is_satisfied_by(&self, weight: u32) -> bool {
+        weight >= self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use external_identifier::ExternalIdentifier;
+    use time::{Duration, OffsetDateTime};
+    use ulid::Ulid;
+
+    use crate::{
+        account::identifiers::AccountId,
+        recovery::social::{
+            challenge::SocialChallengeResponse,
+            relationship::{
+                RecoveryRelationship, RecoveryRelationshipCommonFields,
+                RecoveryRelationshipConnectionFields, RecoveryRelationshipEndorsed,
+                RecoveryRelationshipId, RecoveryRelationshipInvitation,
+            },
+        },
+    };
+
+    use super::RecoveryPolicy;
+
+    fn customer() -> AccountId {
+        AccountId::new(Ulid::default()).unwrap()
+    }
+
+    fn common_fields(created_at: OffsetDateTime) -> RecoveryRelationshipCommonFields {
+        RecoveryRelationshipCommonFields {
+            id: RecoveryRelationshipId::gen().unwrap(),
+            customer_account_id: customer(),
+            trusted_contact_alias: "Trusted Contact".to_owned(),
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    fn endorsed(created_at: OffsetDateTime) -> RecoveryRelationship {
+        RecoveryRelationship::Endorsed(RecoveryRelationshipEndorsed {
+            common_fields: common_fields(created_at),
+            connection_fields: RecoveryRelationshipConnectionFields {
+                customer_alias: "Customer".to_owned(),
+                trusted_contact_account_id: AccountId::gen().unwrap(),
+                trusted_contact_identity_pubkey: String::new(),
+            },
+            endorsement_key_certificate: String::new(),
+        })
+    }
+
+    fn invitation(created_at: OffsetDateTime) -> RecoveryRelationship {
+        RecoveryRelationship::Invitation(RecoveryRelationshipInvitation {
+            common_fields: common_fields(created_at),
+            code: String::new(),
+            expires_at: created_at,
+            customer_enrollment_pubkey: String::new(),
+            reminder_sent_at: None,
+        })
+    }
+
+    #[allow(deprecated)]
+    fn response(relationship: &RecoveryRelationship) -> SocialChallengeResponse {
+        SocialChallengeResponse {
+            recovery_relationship_id: relationship.common_fields().id.to_owned(),
+            shared_secret_ciphertext: String::new(),
+            trusted_contact_recovery_pubkey: String::new(),
+            recovery_key_confirmation: String::new(),
+            recovery_sealed_pkek: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_default_policy_needs_one_endorsed_response() {
+        let now = OffsetDateTime::now_utc();
+        let policy = RecoveryPolicy::default_for_account(&customer());
+        let (endorsed, invitation) = (endorsed(now), invitation(now));
+        let relationships = vec![endorsed.clone(), invitation.clone()];
+
+        let weight = policy.satisfied_weight(&[response(&invitation)], &relationships, now);
+        assert_eq!(weight, 0);
+        assert!(!policy.is_satisfied_by(weight));
+
+        let weight = policy.satisfied_weight(&[response(&endorsed)], &relationships, now);
+        assert_eq!(weight, 1);
+        assert!(policy.is_satisfied_by(weight));
+    }
+
+    #[test]
+    fn test_weighted_threshold() {
+        let now = OffsetDateTime::now_utc();
+        let (a, b, c) = (endorsed(now), endorsed(now), endorsed(now));
+        let relationships = vec![a.clone(), b.clone(), c.clone()];
+        let policy = RecoveryPolicy::new(
+            &customer(),
+            3,
+            HashMap::from([(a.common_fields().id.to_owned(), 2)]),
+            0,
+        );
+
+        assert_eq!(policy.attainable_weight(&relationships), 4);
+        assert_eq!(
+            policy.satisfied_weight(&[response(&b), response(&c)], &relationships, now),
+            2
+        );
+        assert_eq!(
+            policy.satisfied_weight(&[response(&a), response(&b)], &relationships, now),
+            3
+        );
+        // Repeated responses from the same relationship only count once
+        assert_eq!(
+            policy.satisfied_weight(&[response(&b), response(&b)], &relationships, now),
+            1
+        );
+    }
+
+    #[test]
+    fn test_min_relationship_age() {
+        let now = OffsetDateTime::now_utc();
+        let (old, new) = (endorsed(now - Duration::days(10)), endorsed(now));
+        let relationships = vec![old.clone(), new.clone()];
+        let policy = RecoveryPolicy::new(
+            &customer(),
+            1,
+            HashMap::new(),
+            Duration::days(7).whole_seconds(),
+        );
+
+        assert_eq!(
+            policy.satisfied_weight(&[response(&new)], &relationships, now),
+            0
+        );
+        assert_eq!(
+            policy.satisfied_weight(&[response(&old)], &relationships, now),
+            1
+        );
+    }
+}
@@ -85,6 +85,9 @@ pub struct RecoveryRelationshipInvitation {
     // The enrollment fields are thrown away once the customer has
     // endorsed the Trusted Contact
     pub customer_enrollment_pubkey: String,
+    // Set once the customer has been reminded that the invitation is about to expire
+    #[serde(default, with = "rfc3339::option")]
+    pub reminder_sent_at: Option<OffsetDateTime>,
 }
 
 impl RecoveryRelationshipInvitation {
@@ -94,6 +97,14 @@ impl RecoveryRelationshipInvitation {
             customer_enrollment_pubkey: self.customer_enrollment_pubkey.to_owned(),
             code: code.to_owned(),
             expires_at: expires_at.to_owned(),
+            reminder_sent_at: None,
+        }
+    }
+
+    pub fn with_reminder_sent_at(&self, reminder_sent_at: &OffsetDateTime) -> Self {
+        Self {
+            reminder_sent_at: Some(reminder_sent_at.to_owned()),
+            ..self.to_owned()
         }
     }
 
@@ -102,6 +113,16 @@ impl RecoveryRelationshipInvitation {
     }
 }
 
+// An invitation that expired without being accepted. It no longer has a code, so it can't be
+// looked up or redeemed.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RecoveryRelationshipArchivedInvitation {
+    #[serde(flatten)]
+    pub common_fields: RecoveryRelationshipCommonFields,
+    #[serde(with = "rfc3339")]
+    pub expires_at: OffsetDateTime,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Builder)]
 pub struct RecoveryRelationshipUnendorsed {
     #[serde(flatten)]
@@ -125,12 +146,24 @@ pub struct RecoveryRelationshipEndorsed {
     pub endorsement_key_certificate: String,
 }
 
+// A Trusted Contact that accepted an invitation but was never endorsed by the customer. The
+// enrollment fields are thrown away, so the customer has to invite them again.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RecoveryRelationshipStale {
+    #[serde(flatten)]
+    pub common_fields: RecoveryRelationshipCommonFields,
+    #[serde(flatten)]
+    pub connection_fields: RecoveryRelationshipConnectionFields,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "_RecoveryRelationship_type")]
 pub enum RecoveryRelationship {
     Invitation(RecoveryRelationshipInvitation),
     Unendorsed(RecoveryRelationshipUnendorsed),
     Endorsed(RecoveryRelationshipEndorsed),
+    ArchivedInvitation(RecoveryRelationshipArchivedInvitation),
+    Stale(RecoveryRelationshipStale),
 }
 
 impl RecoveryRelationship {
@@ -153,6 +186,7 @@ impl RecoveryRelationship {
             code: code.to_owned(),
             expires_at: expires_at.to_owned(),
             customer_enrollment_pubkey: customer_enrollment_pubkey.to_owned(),
+            reminder_sent_at: None,
         })
     }
 
@@ -161,9 +195,17 @@ impl RecoveryRelationship {
             Self::Invitation(invitation) => &invitation.common_fields,
             Self::Unendorsed(connection) => &connection.common_fields,
             Self::Endorsed(connection) => &connection.common_fields,
+            Self::ArchivedInvitation(invitation) => &invitation.common_fields,
+            Self::Stale(connection) => &connection.common_fields,
         }
     }
 
+    /// Archived invitations and stale relationships are kept for reference, but otherwise act as
+    /// if they had been deleted.
+    pub fn is_inactive(&self) -> bool {
+        matches!(self, Self::ArchivedInvitation(_) | Self::Stale(_))
+    }
+
     pub fn with_common_fields(&self, common_fields: &RecoveryRelationshipCommonFields) -> Self {
         match self {
             Self::Invitation(invitation) => Self::Invitation(RecoveryRelationshipInvitation {
@@ -171,6 +213,7 @@ impl RecoveryRelationship {
                 code: invitation.code.to_owned(),
                 expires_at: invitation.expires_at.to_owned(),
                 customer_enrollment_pubkey: invitation.customer_enrollment_pubkey.to_owned(),
+                reminder_sent_at: invitation.reminder_sent_at.to_owned(),
             }),
             Self::Unendorsed(connection) => Self::Unendorsed(RecoveryRelationshipUnendorsed {
                 common_fields: common_fields.to_owned(),
@@ -189,6 +232,16 @@ impl RecoveryRelationship {
                 connection_fields: connection.connection_fields.to_owned(),
                 endorsement_key_certificate: connection.endorsement_key_certificate.to_owned(),
             }),
+            Self::ArchivedInvitation(invitation) => {
+                Self::ArchivedInvitation(RecoveryRelationshipArchivedInvitation {
+                    common_fields: common_fields.to_owned(),
+                    expires_at: invitation.expires_at.to_owned(),
+                })
+            }
+            Self::Stale(connection) => Self::Stale(RecoveryRelationshipStale {
+                common_fields: common_fields.to_owned(),
+                connection_fields: connection.connection_fields.to_owned(),
+            }),
         }
     }
 }
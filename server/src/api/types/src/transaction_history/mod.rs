@@ -0,0 +1,128 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+use time::{serde::rfc3339, OffsetDateTime};
+
+use crate::account::identifiers::AccountId;
+
+/// An output of a transaction paid to one of the account's addresses.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ReceivedOutput {
+    pub vout: u32,
+    pub address: String,
+    pub amount_sats: u64,
+}
+
+/// An input of a transaction that spent one of the account's outputs.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SpentInput {
+    pub previous_txid: String,
+    pub previous_vout: u32,
+    /// `None` if the output being spent was confirmed before the account's history was indexed.
+    pub amount_sats: Option<u64>,
+}
+
+/// A confirmed transaction that paid to or spent from any of an account's keysets, as recorded
+/// by the blockchain polling worker.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AccountTransaction {
+    #[serde(rename = "partition_key")]
+    pub account_id: AccountId,
+    #[serde(rename = "sort_key")]
+    pub txid: String,
+    pub block_height: u64,
+    pub block_hash: String,
+    #[serde(with = "rfc3339")]
+    pub confirmed_at: OffsetDateTime,
+    pub received_outputs: Vec<ReceivedOutput>,
+    pub spent_inputs: Vec<SpentInput>,
+    /// Only known when every input of the transaction was spent from the account.
+    pub fee_sats: Option<u64>,
+    #[serde(with = "rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+impl AccountTransaction {
+    pub fn received_sats(&self) -> u64 {
+        self.received_outputs.iter().map(|o| o.amount_sats).sum()
+    }
+
+    /// The value of the account's outputs spent by this transaction, counting only those whose
+    /// value is known.
+    pub fn sent_sats(&self) -> u64 {
+        self.spent_inputs.iter().filter_map(|i| i.amount_sats).sum()
+    }
+
+    /// Whether the value of every output the transaction spent from the account is known.
+    pub fn is_complete(&self) -> bool {
+        self.spent_inputs.iter().all(|i| i.amount_sats.is_some())
+    }
+
+    pub fn cursor(&self) -> TransactionHistoryCursor {
+        TransactionHistoryCursor {
+            block_height: self.block_height,
+            txid: self.txid.clone(),
+        }
+    }
+}
+
+/// Position in an account's transaction history, newest first. Handed to clients as an opaque
+/// string to fetch the next page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionHistoryCursor {
+    pub block_height: u64,
+    pub txid: String,
+}
+
+impl Display for TransactionHistoryCursor {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.block_height, self.txid)
+    }
+}
+
+impl FromStr for TransactionHistoryCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (block_height, txid) = s
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid transaction history cursor: {s}"))?;
+        let block_height = block_height
+            .parse()
+            .map_err(|_| format!("Invalid transaction history cursor: {s}"))?;
+        if txid.is_empty() {
+            return Err(format!("Invalid transaction history cursor: {s}"));
+        }
+
+        Ok(Self {
+            block_height,
+            txid: txid.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::TransactionHistoryCursor;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = TransactionHistoryCursor {
+            block_height: 840_000,
+            txid: "abc123".to_string(),
+        };
+        assert_eq!(
+            TransactionHistoryCursor::from_str(&cursor.to_string()),
+            Ok(cursor)
+        );
+
+        for invalid in ["", "840000", "abc:def", "840000:"] {
+            assert!(TransactionHistoryCursor::from_str(invalid).is_err());
+        }
+    }
+}
@@ -79,6 +79,15 @@ impl SendEmail {
                     })?;
                 Ok(())
             }
+            EmailPayload::Rendered { subject, html } => {
+                self.ses
+                    .send_rendered_email(
+                        email_address.to_owned(),
+                        subject.to_owned(),
+                        html.to_owned(),
+                    )
+                    .await
+            }
         }
     }
 }
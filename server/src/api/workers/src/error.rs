@@ -1,7 +1,9 @@
 use account::error::AccountError;
+use aws_sdk_sesv2::error::BuildError;
 use aws_sdk_sesv2::operation::send_email::SendEmailError;
 use aws_sdk_sns::operation::publish::PublishError;
 use bdk_utils::error::BdkUtilError;
+use chain_indexer::transaction_history::error::TransactionHistoryError;
 use chain_indexer::ChainIndexerError;
 use errors::ApiError;
 use notification::NotificationError;
@@ -20,8 +22,12 @@ pub enum WorkerError {
     NotificationPayloadBuilderError(#[from] notification::NotificationPayloadBuilderError),
     #[error("SNS Publish Error")]
     SNSPublishError(#[from] PublishError),
+    #[error("Push endpoint is disabled")]
+    PushEndpointDisabled,
     #[error("SES Publish Error")]
     SESPublishError(#[from] SendEmailError),
+    #[error("Failed to build SES email: {0}")]
+    SESBuildEmailError(#[from] BuildError),
     #[error("Failed to setup SIGTERM handler: {0}")]
     SetupSigtermHandler(#[from] ctrlc::Error),
     #[error("Failed to parse or serialize SNSNotificationMessage with error: {0}")]
@@ -32,8 +38,12 @@ pub enum WorkerError {
     SQSError(#[from] queue::sqs::QueueError),
     #[error("Unable to get balance")]
     GetBalanceError(#[from] BdkUtilError),
+    #[error("Failed to deliver analytics events: {0:?}")]
+    AnalyticsDelivery(analytics::errors::AnalyticsError),
     #[error("Couldn't retrieve blockchain data due to error: {0}")]
     ChainIndexerError(#[from] ChainIndexerError),
+    #[error("Couldn't index transaction history due to error: {0}")]
+    TransactionHistoryError(#[from] TransactionHistoryError),
     #[error("Database error due to error: {0}")]
     DatabaseError(#[from] database::ddb::DatabaseError),
     #[error("Unable to retrieve block height")]
@@ -58,6 +68,8 @@ pub enum WorkerError {
     IncorrectTouchpointType,
     #[error("Electrum client error: {0}")]
     ElectrumClientError(#[from] bdk_utils::bdk::electrum_client::Error),
+    #[error("Blocking task failed: {0}")]
+    BlockingTaskError(#[from] tokio::task::JoinError),
 }
 
 impl From<WorkerError> for ApiError {
@@ -69,12 +81,16 @@ impl From<WorkerError> for ApiError {
             | WorkerError::NotificationPayloadBuilderError(_)
             | WorkerError::SetupSigtermHandler(_)
             | WorkerError::SESPublishError(_)
+            | WorkerError::SESBuildEmailError(_)
             | WorkerError::SNSPublishError(_)
+            | WorkerError::PushEndpointDisabled
             | WorkerError::SerdeSerialization(_)
             | WorkerError::GetBalanceError(_)
+            | WorkerError::AnalyticsDelivery(_)
             | WorkerError::FetchNotifications
             | WorkerError::SQSError(_)
             | WorkerError::ChainIndexerError(_)
+            | WorkerError::TransactionHistoryError(_)
             | WorkerError::DatabaseError(_)
             | WorkerError::BlockHeightError
             | WorkerError::FormatError(_)
@@ -86,6 +102,7 @@ impl From<WorkerError> for ApiError {
             | WorkerError::NotificationClientsError(_)
             | WorkerError::MetricsRegisterCallback
             | WorkerError::ElectrumClientError(_)
+            | WorkerError::BlockingTaskError(_)
             | WorkerError::IncorrectTouchpointType => {
                 ApiError::GenericInternalApplicationError(err_msg)
             }
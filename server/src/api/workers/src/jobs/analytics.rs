@@ -0,0 +1,95 @@
+use std::env;
+use std::time::Duration;
+
+use analytics::destination::tracker::Tracker;
+use analytics::pipeline::{decode_queued_events, ANALYTICS_QUEUE_ENV_VAR};
+use analytics::routes::definitions::EventBundle;
+use tokio::time::{sleep, Instant};
+use tracing::{event, instrument, Level};
+
+use super::WorkerState;
+use crate::error::WorkerError;
+use crate::sqs::sqs_job_handler;
+
+/// The most events sent to the destination in a single call.
+const MAX_BATCH_SIZE: usize = 100;
+const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+/// How long delivering the messages from one receive may spend retrying. It's kept well under the
+/// queue's visibility timeout (30 seconds by default), so the messages aren't redelivered to
+/// another worker while this one is still retrying them.
+const MAX_RETRY_TIME: Duration = Duration::from_secs(15);
+
+#[instrument(skip(state, tracker))]
+pub async fn handler(state: &WorkerState, tracker: Tracker) -> Result<(), WorkerError> {
+    let queue_url = env::var(ANALYTICS_QUEUE_ENV_VAR).unwrap_or_default();
+    let tracker_ref = &tracker;
+
+    sqs_job_handler(state, queue_url, |serialized_messages| async move {
+        deliver(tracker_ref, serialized_messages, INITIAL_BACKOFF).await?;
+        Ok(())
+    })
+    .await
+}
+
+/// Batches the events from the queued messages and delivers each batch to the destination,
+/// retrying with exponential backoff for as long as [`MAX_RETRY_TIME`] allows. Returns the number
+/// of events delivered.
+///
+/// Fails if a batch still can't be delivered after the last attempt, or once there's no time left
+/// to retry, leaving the messages on the queue to be redelivered and, eventually, moved to the
+/// dead-letter queue. Batches delivered before the failure will be delivered again.
+pub async fn deliver(
+    tracker: &Tracker,
+    serialized_messages: Vec<String>,
+    initial_backoff: Duration,
+) -> Result<usize, WorkerError> {
+    let events = serialized_messages
+        .iter()
+        .filter_map(|message| match decode_queued_events(message) {
+            Ok(bundle) => Some(bundle.events),
+            Err(e) => {
+                event!(Level::ERROR, "Failed to decode analytics events: {e:?}");
+                None
+            }
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let retry_deadline = Instant::now() + MAX_RETRY_TIME;
+    let mut delivered = 0;
+    for batch in events.chunks(MAX_BATCH_SIZE) {
+        let mut backoff = initial_backoff;
+        for attempt in 1..=MAX_DELIVERY_ATTEMPTS {
+            let bundle = EventBundle {
+                events: batch.to_vec(),
+            };
+            match tracker.track(bundle).await {
+                Ok(()) => {
+                    delivered += batch.len();
+                    break;
+                }
+                Err(e)
+                    if attempt < MAX_DELIVERY_ATTEMPTS
+                        && Instant::now() + backoff < retry_deadline =>
+                {
+                    event!(
+                        Level::WARN,
+                        "Failed to deliver analytics events on attempt {attempt}: {e:?}"
+                    );
+                    sleep(backoff).await;
+                    backoff *= 2;
+                }
+                Err(e) => {
+                    event!(
+                        Level::ERROR,
+                        "Failed to deliver {} analytics events after {attempt} attempts: {e:?}",
+                        batch.len()
+                    );
+                    return Err(WorkerError::AnalyticsDelivery(e));
+                }
+            }
+        }
+    }
+    Ok(delivered)
+}
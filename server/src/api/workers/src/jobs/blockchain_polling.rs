@@ -1,5 +1,8 @@
 use account::service::FetchAccountInput;
-use bdk_utils::bdk::bitcoin::Address;
+use bdk_utils::bdk::bitcoin::{Address, Block};
+use chain_indexer::entities::IndexedTransaction;
+use chain_indexer::metrics as chain_indexer_metrics;
+use chain_indexer::transaction_history::index_block::{candidate_addresses, IndexBlockInput};
 use futures::stream::{FuturesUnordered, StreamExt};
 use itertools::Itertools;
 use notification::service::SendNotificationInput;
@@ -34,12 +37,54 @@ pub async fn handler(state: &WorkerState, sleep_duration_seconds: u64) -> Result
 pub async fn run_once(state: &WorkerState) -> Result<(), WorkerError> {
     event!(Level::INFO, "Starting blockchain polling job");
 
+    // Blocks that failed to index are retried first, so that their outputs are recorded before
+    // any newer block spends them.
+    for block in state.chain_indexer_service.get_unindexed_blocks().await? {
+        match state
+            .chain_indexer_service
+            .get_block(&block.block_hash)
+            .await
+        {
+            Ok(block) => index_block(&block, state).await,
+            Err(e) => event!(
+                Level::ERROR,
+                "Unable to retrieve unindexed block {}: {e}",
+                block.block_hash
+            ),
+        }
+    }
+
     let blocks = state.chain_indexer_service.get_new_blocks().await?;
     if blocks.is_empty() {
         event!(Level::INFO, "No new blocks detected");
         return Ok(());
     }
     event!(Level::INFO, "{} blocks found", blocks.len());
+
+    // Transactions from blocks that were reorganized out are removed before the new blocks are
+    // recorded, so that balances never count both branches.
+    for block in state
+        .chain_indexer_service
+        .get_orphaned_blocks(&blocks)
+        .await?
+    {
+        event!(
+            Level::WARN,
+            "Block {} at height {} was reorganized out of the chain",
+            block.block_hash,
+            block.height
+        );
+        chain_indexer_metrics::ORPHANED_BLOCKS.add(1, &[]);
+        state
+            .transaction_history_service
+            .remove_block(block.block_hash, &block.indexed_transactions)
+            .await?;
+        state
+            .chain_indexer_service
+            .remove_block(block.block_hash)
+            .await?;
+    }
+
     // We update state here to avoid sending duplicate notifications if the job crashes,
     // however, this could result in missed notifications in that case. We plan on updating
     // this job with a cursor so that it can resume where it left off.
@@ -48,6 +93,11 @@ pub async fn run_once(state: &WorkerState) -> Result<(), WorkerError> {
     }
     event!(Level::INFO, "{} blocks added", blocks.len());
 
+    // Blocks are indexed in order, so that outputs are recorded before they're spent.
+    for block in &blocks {
+        index_block(block, state).await;
+    }
+
     let addresses: Vec<Address<NetworkUnchecked>> = blocks
         .into_iter()
         .flat_map(|block| block.txdata)
@@ -111,6 +161,87 @@ pub async fn run_once(state: &WorkerState) -> Result<(), WorkerError> {
     Ok(())
 }
 
+/// Indexes the transactions of blocks in the given height range, inclusive, without sending
+/// notifications. Used to fill in account transaction histories from before the blockchain
+/// polling job started recording them, or for addresses added to the watchlist after their
+/// blocks were polled. Indexing a block again rewrites the same records, so ranges may overlap
+/// blocks that have already been indexed.
+#[instrument(skip(state))]
+pub async fn backfill(
+    state: &WorkerState,
+    from_height: u64,
+    to_height: u64,
+) -> Result<(), WorkerError> {
+    for height in from_height..=to_height {
+        let block = state
+            .chain_indexer_service
+            .get_block_at_height(height)
+            .await?;
+        let indexed = index_transactions(&block, state).await?;
+        event!(
+            Level::INFO,
+            "Backfilled {} account transactions for block {} at height {height}",
+            indexed.len(),
+            block.block_hash()
+        );
+    }
+
+    Ok(())
+}
+
+// Indexes a polled block and records that it was indexed. Failures are logged rather than
+// returned, leaving the block to be retried by a later run.
+async fn index_block(block: &Block, state: &WorkerState) {
+    let block_hash = block.block_hash();
+    let result = match index_transactions(block, state).await {
+        Ok(indexed) => state
+            .chain_indexer_service
+            .mark_transactions_indexed(block_hash, &indexed)
+            .await
+            .map_err(WorkerError::from),
+        Err(e) => Err(e),
+    };
+
+    if let Err(e) = result {
+        event!(
+            Level::ERROR,
+            "Unable to index transactions for block {block_hash}: {e}"
+        );
+        chain_indexer_metrics::BLOCK_INDEXING_FAILURE.add(1, &[]);
+    }
+}
+
+async fn index_transactions(
+    block: &Block,
+    state: &WorkerState,
+) -> Result<Vec<IndexedTransaction>, WorkerError> {
+    let network = state.chain_indexer_service.network();
+    let owners = state
+        .address_repo
+        .get(&candidate_addresses(block, network))
+        .await?;
+    if owners.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let indexed = state
+        .transaction_history_service
+        .index_block(IndexBlockInput {
+            block,
+            network,
+            owners: &owners,
+        })
+        .await?;
+    event!(
+        Level::INFO,
+        "{} account transactions indexed for block {}",
+        indexed.len(),
+        block.block_hash()
+    );
+
+    Ok(indexed)
+}
+
 async fn process_account_id(account_id: AccountId, state: &WorkerState) -> Result<(), WorkerError> {
     let account = state
         .account_service
@@ -1,16 +1,21 @@
 use std::env;
 
 use account::entities::Touchpoint;
-use account::service::FetchAccountInput;
 use account::service::Service as AccountService;
+use account::service::{DeactivatePushTouchpointInput, FetchAccountInput};
 use errors::ApiError;
 use notification::entities::NotificationTouchpoint;
-use notification::service::FetchForCompositeKeyInput;
+use notification::service::{
+    DeadLetterWebhookInput, FetchForCompositeKeyInput, RecordDeliveryAttemptInput,
+    RetryWebhookDeliveryInput,
+};
 use notification::{
     service::{Service as NotificationService, UpdateDeliveryStatusInput},
     DeliveryStatus, NotificationMessage,
 };
-use notification::{EMAIL_QUEUE_ENV_VAR, PUSH_QUEUE_ENV_VAR, SMS_QUEUE_ENV_VAR};
+use notification::{
+    EMAIL_QUEUE_ENV_VAR, PUSH_QUEUE_ENV_VAR, SMS_QUEUE_ENV_VAR, WEBHOOK_QUEUE_ENV_VAR,
+};
 
 use tracing::{event, instrument, Level};
 use types::notification::NotificationChannel;
@@ -18,6 +23,7 @@ use types::notification::NotificationChannel;
 use super::WorkerState;
 use crate::sms::SendSMS;
 use crate::sqs::sqs_job_handler;
+use crate::webhook::SendWebhook;
 use crate::{email::SendEmail, error::WorkerError, sns::SendPushNotification};
 
 #[instrument(skip(state))]
@@ -29,6 +35,7 @@ pub async fn handler(
         NotificationChannel::Push => env::var(PUSH_QUEUE_ENV_VAR).unwrap_or_default(),
         NotificationChannel::Email => env::var(EMAIL_QUEUE_ENV_VAR).unwrap_or_default(),
         NotificationChannel::Sms => env::var(SMS_QUEUE_ENV_VAR).unwrap_or_default(),
+        NotificationChannel::Webhook => env::var(WEBHOOK_QUEUE_ENV_VAR).unwrap_or_default(),
     };
     let email_client = SendEmail::new(
         state.config.ses.to_owned(),
@@ -37,9 +44,11 @@ pub async fn handler(
     .await;
     let push_client = SendPushNotification::new(state.config.sns.to_owned()).await;
     let sms_client = SendSMS::new(state.config.twilio.to_owned()).await;
+    let webhook_client = SendWebhook::new(state.config.webhook.to_owned()).await;
     let email_client_ref = &email_client;
     let push_client_ref = &push_client;
     let sms_client_ref = &sms_client;
+    let webhook_client_ref = &webhook_client;
 
     sqs_job_handler(state, queue_url, |serialized_messages| async move {
         let mut failed_messages: Vec<NotificationMessage> = Vec::new();
@@ -52,6 +61,7 @@ pub async fn handler(
                 push_client_ref,
                 email_client_ref,
                 sms_client_ref,
+                webhook_client_ref,
                 &notification,
             )
             .await
@@ -84,6 +94,7 @@ async fn notification_handler(
     push_client: &SendPushNotification,
     email_client: &SendEmail,
     sms_client: &SendSMS,
+    webhook_client: &SendWebhook,
     message: &NotificationMessage,
 ) -> Result<(), ApiError> {
     let Ok(account) = account_service
@@ -123,10 +134,12 @@ async fn notification_handler(
     let email_payload = message.email_payload.as_ref();
     let push_payload = message.push_payload.as_ref();
     let sms_payload = message.sms_payload.as_ref();
+    let webhook_payload = message.webhook_payload.as_ref();
 
     let touchpoint = match notification.touchpoint {
         NotificationTouchpoint::Email { touchpoint_id }
-        | NotificationTouchpoint::Phone { touchpoint_id } => {
+        | NotificationTouchpoint::Phone { touchpoint_id }
+        | NotificationTouchpoint::Webhook { touchpoint_id } => {
             account.get_touchpoint_by_id(touchpoint_id)
         }
         NotificationTouchpoint::Push {
@@ -139,47 +152,110 @@ async fn notification_handler(
         return Ok(());
     };
 
-    match t.to_owned() {
+    let mut webhook_body = None;
+    let result = match t.to_owned() {
         Touchpoint::Email { .. } => {
-            if let Some(payload) = email_payload {
-                email_client.send(account.get_id(), t, payload).await?;
-            }
+            let Some(payload) = email_payload else {
+                return Ok(());
+            };
+            email_client.send(account.get_id(), t, payload).await
         }
         Touchpoint::Push {
             platform: _,
             arn,
-            device_token: _,
+            device_token,
+            ..
         } => {
-            if let Some(payload) = push_payload {
-                push_client.send(&arn, payload).await?;
+            let Some(payload) = push_payload else {
+                return Ok(());
+            };
+            let result = push_client.send(&arn, payload).await;
+            if let Err(WorkerError::PushEndpointDisabled) = result {
+                account_service
+                    .deactivate_push_touchpoint(DeactivatePushTouchpointInput {
+                        account_id: &message.account_id,
+                        device_token: &device_token,
+                    })
+                    .await?;
             }
+            result
         }
         Touchpoint::Phone { country_code, .. } => {
-            if let Some(payload) = sms_payload {
-                if !sms_client.is_supported_country_code(country_code) {
+            let Some(payload) = sms_payload else {
+                return Ok(());
+            };
+            if !sms_client.is_supported_country_code(country_code) {
+                event!(
+                    Level::INFO,
+                    "Filtering SMS: client does not support the country_code {}",
+                    country_code,
+                );
+                return Ok(());
+            }
+
+            if let Some(unsupported_country_codes) = payload.unsupported_country_codes.as_ref() {
+                if unsupported_country_codes.contains(&country_code) {
                     event!(
                         Level::INFO,
-                        "Filtering SMS: client does not support the country_code {}",
+                        "Filtering SMS: payload does not support the country_code {}",
                         country_code,
                     );
                     return Ok(());
                 }
+            }
 
-                if let Some(unsupported_country_codes) = payload.unsupported_country_codes.as_ref()
-                {
-                    if unsupported_country_codes.contains(&country_code) {
-                        event!(
-                            Level::INFO,
-                            "Filtering SMS: payload does not support the country_code {}",
-                            country_code,
-                        );
-                        return Ok(());
-                    }
-                }
+            sms_client.send(t, payload, &composite_key).await
+        }
+        Touchpoint::Webhook { .. } => {
+            let Some(payload) = webhook_payload else {
+                return Ok(());
+            };
+            let body = serde_json::to_string(payload).map_err(WorkerError::from)?;
+            let result = webhook_client.send(t, body.clone()).await;
+            webhook_body = Some(body);
+            result
+        }
+    };
 
-                sms_client.send(t, payload).await?;
+    notification_service
+        .record_delivery_attempt(RecordDeliveryAttemptInput {
+            composite_key: composite_key.clone(),
+            channel: NotificationChannel::from(t),
+            error: result.as_ref().err().map(ToString::to_string),
+        })
+        .await?;
+
+    // Webhook deliveries that failed transiently are attempted again later from the queue. Those
+    // that failed for good or ran out of attempts are dead-lettered so they can be replayed.
+    if let (Touchpoint::Webhook { id, url, .. }, Some(body), Err(e)) = (t, webhook_body, &result) {
+        let retried = match e {
+            WorkerError::NotificationClientsError(e) if e.is_transient() => {
+                notification_service
+                    .retry_webhook_delivery(RetryWebhookDeliveryInput {
+                        composite_key: composite_key.clone(),
+                        message,
+                    })
+                    .await?
             }
+            _ => false,
+        };
+        if retried {
+            return Ok(());
         }
+        notification_service
+            .dead_letter_webhook(DeadLetterWebhookInput {
+                composite_key,
+                touchpoint_id: id.to_owned(),
+                url: url.to_owned(),
+                body,
+                error: e.to_string(),
+            })
+            .await?;
+    }
+
+    match result {
+        // Disabled push endpoints are expected and have been handled above
+        Err(WorkerError::PushEndpointDisabled) => Ok(()),
+        result => Ok(result?),
     }
-    Ok(())
 }
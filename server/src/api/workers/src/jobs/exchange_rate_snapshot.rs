@@ -0,0 +1,78 @@
+use errors::ApiError;
+use exchange_rate::flags::{
+    FLAG_USE_AGGREGATE_EXCHANGE_RATE_PROVIDER, FLAG_USE_CASH_EXCHANGE_RATE_PROVIDER,
+};
+use exchange_rate::ExchangeRateProvider;
+use time::{Duration, OffsetDateTime};
+use tracing::{event, instrument, Level};
+use types::exchange_rate::bitstamp::BitstampRateProvider;
+use types::exchange_rate::cash::CashAppRateProvider;
+use types::exchange_rate::coingecko::RateProvider as CoingeckoRateProvider;
+
+use super::WorkerState;
+use crate::error::WorkerError;
+
+/// How far back each run looks for gaps in the rate history to backfill.
+const BACKFILL_WINDOW: Duration = Duration::days(1);
+
+#[instrument(skip(state))]
+pub async fn handler(state: WorkerState, sleep_duration_seconds: u64) -> Result<(), WorkerError> {
+    let sleep_duration = std::time::Duration::from_secs(sleep_duration_seconds);
+
+    loop {
+        let result = run_once(&state).await;
+        if let Err(e) = result {
+            event!(Level::ERROR, "Failed to snapshot exchange rates: {e}")
+        }
+        tokio::time::sleep(sleep_duration).await;
+    }
+}
+
+pub async fn run_once(state: &WorkerState) -> Result<(), WorkerError> {
+    let use_aggregate_rate = FLAG_USE_AGGREGATE_EXCHANGE_RATE_PROVIDER
+        .resolver(&state.feature_flags_service)
+        .resolve();
+    let use_cash_app_rate = FLAG_USE_CASH_EXCHANGE_RATE_PROVIDER
+        .resolver(&state.feature_flags_service)
+        .resolve();
+
+    let exchange_rate_service = &state.exchange_rate_service;
+    let rate_history_service = &state.rate_history_service;
+    let snapshotted = if use_aggregate_rate {
+        rate_history_service
+            .snapshot_latest_rates(
+                exchange_rate_service,
+                exchange_rate_service.aggregate_rate_provider(),
+            )
+            .await
+    } else if use_cash_app_rate {
+        rate_history_service
+            .snapshot_latest_rates(exchange_rate_service, CashAppRateProvider::new())
+            .await
+    } else {
+        rate_history_service
+            .snapshot_latest_rates(exchange_rate_service, BitstampRateProvider::new())
+            .await
+    }
+    .map_err(ApiError::from)?;
+
+    let now = OffsetDateTime::now_utc();
+    let mut backfilled = 0;
+    for currency_code in CoingeckoRateProvider::supported_currency_codes() {
+        backfilled += rate_history_service
+            .backfill_gaps(
+                CoingeckoRateProvider::new(),
+                currency_code,
+                now - BACKFILL_WINDOW,
+                now,
+            )
+            .await
+            .map_err(ApiError::from)?;
+    }
+
+    event!(
+        Level::INFO,
+        "Snapshotted {snapshotted} exchange rates and backfilled {backfilled}"
+    );
+    Ok(())
+}
@@ -1,8 +1,12 @@
 use account::entities::Factor;
 use bdk_utils::{generate_electrum_rpc_uris, metrics as bdk_utils_metrics};
-use metrics::{factory::Histogram, factory::ObservableCallbackRegistry, KeyValue};
+use chain_indexer::metrics as chain_indexer_metrics;
+use metrics::{
+    factory::Histogram, factory::ObservableCallbackRegistry, factory::ObservableGauge, KeyValue,
+};
 use notification::{
     metrics as notification_metrics, EMAIL_QUEUE_ENV_VAR, PUSH_QUEUE_ENV_VAR, SMS_QUEUE_ENV_VAR,
+    WEBHOOK_QUEUE_ENV_VAR,
 };
 use recovery::metrics as recovery_metrics;
 
@@ -18,6 +22,9 @@ use types::notification::NotificationChannel;
 
 use super::WorkerState;
 use crate::error::WorkerError;
+use crate::wallet_health::{self, NetworkWalletHealth, WalletHealthReport};
+
+const WALLET_HEALTH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
 
 // This job is a global singleton intended to gather and emit metrics that are meaningful
 //   as absolute system-wide measurements. For example, the total number of currently-pending
@@ -31,6 +38,8 @@ pub struct MeasurementsCache {
     customer_notification_push_queue_num_messages: Arc<RwLock<u64>>,
     customer_notification_email_queue_num_messages: Arc<RwLock<u64>>,
     customer_notification_sms_queue_num_messages: Arc<RwLock<u64>>,
+    customer_notification_webhook_queue_num_messages: Arc<RwLock<u64>>,
+    wallet_health: Arc<RwLock<WalletHealthReport>>,
 }
 
 // This cache holds the "current" value of each measurement. The job performs the necessary work
@@ -46,6 +55,8 @@ impl MeasurementsCache {
             customer_notification_push_queue_num_messages: Arc::new(RwLock::new(0)),
             customer_notification_email_queue_num_messages: Arc::new(RwLock::new(0)),
             customer_notification_sms_queue_num_messages: Arc::new(RwLock::new(0)),
+            customer_notification_webhook_queue_num_messages: Arc::new(RwLock::new(0)),
+            wallet_health: Arc::new(RwLock::new(WalletHealthReport::default())),
         }
     }
 
@@ -180,6 +191,62 @@ impl MeasurementsCache {
                 )],
             )
             .map_err(|_| WorkerError::MetricsRegisterCallback)?;
+
+        let customer_notification_webhook_queue_num_messages = self
+            .customer_notification_webhook_queue_num_messages
+            .clone();
+        notification_metrics::FACTORY
+            .register_callback(
+                notification_metrics::NOTIFICATION_QUEUE_NUM_MESSAGES.to_owned(),
+                move || {
+                    customer_notification_webhook_queue_num_messages
+                        .read()
+                        .unwrap()
+                        .to_owned()
+                },
+                &[KeyValue::new(
+                    notification_metrics::CUSTOMER_NOTIFICATION_TYPE,
+                    NotificationChannel::Webhook.to_string(),
+                )],
+            )
+            .map_err(|_| WorkerError::MetricsRegisterCallback)?;
+
+        let wallet_health_gauges: [(&ObservableGauge<u64>, fn(&NetworkWalletHealth) -> u64); 5] = [
+            (
+                &chain_indexer_metrics::WALLET_HEALTH_INACTIVE_KEYSET_ACCOUNTS,
+                |h| h.inactive_keyset_accounts,
+            ),
+            (
+                &chain_indexer_metrics::WALLET_HEALTH_INACTIVE_KEYSET_BALANCE,
+                |h| h.inactive_keyset_balance_sats,
+            ),
+            (&chain_indexer_metrics::WALLET_HEALTH_DUST_UTXOS, |h| {
+                h.dust_utxos
+            }),
+            (
+                &chain_indexer_metrics::WALLET_HEALTH_PENDING_OUTFLOWS,
+                |h| h.pending_outflows,
+            ),
+            (
+                &chain_indexer_metrics::WALLET_HEALTH_PENDING_OUTFLOW_BALANCE,
+                |h| h.pending_outflow_balance_sats,
+            ),
+        ];
+        for network in [Network::Bitcoin, Network::Signet] {
+            for (gauge, measurement) in wallet_health_gauges {
+                let wallet_health = self.wallet_health.clone();
+                chain_indexer_metrics::FACTORY
+                    .register_callback(
+                        gauge.to_owned(),
+                        move || measurement(&wallet_health.read().unwrap().network(network)),
+                        &[KeyValue::new(
+                            chain_indexer_metrics::NETWORK_KEY,
+                            network.to_string(),
+                        )],
+                    )
+                    .map_err(|_| WorkerError::MetricsRegisterCallback)?;
+            }
+        }
         Ok(())
     }
 }
@@ -191,10 +258,21 @@ pub async fn handler(state: &WorkerState, sleep_duration_seconds: u64) -> Result
     let sleep_duration = std::time::Duration::from_secs(sleep_duration_seconds);
     let measurements_cache = MeasurementsCache::new();
 
+    tokio::select! {
+        result = poll_measurements(state, &measurements_cache, sleep_duration) => result,
+        _ = poll_wallet_health(state, &measurements_cache) => Ok(()),
+    }
+}
+
+async fn poll_measurements(
+    state: &WorkerState,
+    measurements_cache: &MeasurementsCache,
+    sleep_duration: std::time::Duration,
+) -> Result<(), WorkerError> {
     let mut callbacks_registered = false;
 
     loop {
-        let result = run_once(state, &measurements_cache).await;
+        let result = run_once(state, measurements_cache).await;
         if let Err(e) = result {
             event!(Level::ERROR, "Failed to run metrics job: {e}")
         } else if !callbacks_registered {
@@ -219,10 +297,12 @@ pub async fn run_once(
     let push_queue_url: String = env::var(PUSH_QUEUE_ENV_VAR).unwrap_or_default();
     let email_queue_url: String = env::var(EMAIL_QUEUE_ENV_VAR).unwrap_or_default();
     let sms_queue_url: String = env::var(SMS_QUEUE_ENV_VAR).unwrap_or_default();
+    let webhook_queue_url: String = env::var(WEBHOOK_QUEUE_ENV_VAR).unwrap_or_default();
     let queue_urls = vec![
         push_queue_url.clone(),
         email_queue_url.clone(),
         sms_queue_url.clone(),
+        webhook_queue_url.clone(),
     ];
     let messages_per_queue = state.sqs.fetch_number_of_messages(queue_urls).await?;
     {
@@ -288,6 +368,17 @@ pub async fn run_once(
             messages_per_queue.get(&sms_queue_url).unwrap().to_owned();
     }
 
+    {
+        let mut customer_notification_webhook_queue_num_messages = measurements_cache
+            .customer_notification_webhook_queue_num_messages
+            .write()
+            .unwrap();
+        *customer_notification_webhook_queue_num_messages = messages_per_queue
+            .get(&webhook_queue_url)
+            .unwrap()
+            .to_owned();
+    }
+
     measure_electrum_signet_ping_response_time(state).await;
     measure_electrum_mainnet_ping_response_time(state).await;
 
@@ -295,6 +386,21 @@ pub async fn run_once(
     Ok(())
 }
 
+// Wallet health reads every account's transaction history, so it's measured on its own, slower
+//   schedule. Failures are logged and the previous report keeps being emitted.
+async fn poll_wallet_health(state: &WorkerState, measurements_cache: &MeasurementsCache) {
+    loop {
+        match wallet_health::measure(state).await {
+            Ok(wallet_health) => {
+                let mut wallet_health_cache = measurements_cache.wallet_health.write().unwrap();
+                *wallet_health_cache = wallet_health;
+            }
+            Err(e) => event!(Level::ERROR, "Failed to measure wallet health: {e}"),
+        }
+        tokio::time::sleep(WALLET_HEALTH_INTERVAL).await;
+    }
+}
+
 async fn measure_electrum_ping_response_time(
     network: Network,
     state: &WorkerState,
@@ -1,19 +1,26 @@
 use account::service::Service as AccountService;
 use chain_indexer::service::Service as ChainIndexerService;
+use chain_indexer::transaction_history::Service as TransactionHistoryService;
+use exchange_rate::rate_history::Service as RateHistoryService;
+use exchange_rate::service::Service as ExchangeRateService;
 use feature_flags::service::Service as FeatureFlagsService;
 use notification::address_repo::AddressWatchlistTrait;
-use notification::clients::{iterable::IterableMode, twilio::TwilioMode};
+use notification::clients::{iterable::IterableMode, twilio::TwilioMode, webhook::WebhookMode};
 use notification::service::Service as NotificationService;
 use notification_validation::NotificationValidationState;
 use queue::sqs::SqsQueue;
 use recovery::repository::Repository as RecoveryRepository;
+use recovery::service::social::relationship::Service as RecoveryRelationshipService;
 use serde::Deserialize;
 
 use crate::{ses::SESMode, sns::SNSMode};
 
+pub mod analytics;
 pub mod blockchain_polling;
 pub mod customer_notification;
+pub mod exchange_rate_snapshot;
 pub mod metrics;
+pub mod recovery_relationship_sweeper;
 pub mod scheduled_notification;
 pub mod unified_keyset_migration;
 
@@ -23,6 +30,7 @@ pub struct Config {
     pub(crate) iterable: IterableMode,
     pub(crate) sns: SNSMode,
     pub(crate) twilio: TwilioMode,
+    pub(crate) webhook: WebhookMode,
 }
 
 #[derive(Clone, axum_macros::FromRef)]
@@ -35,10 +43,14 @@ pub struct WorkerState {
     pub address_repo: Box<dyn AddressWatchlistTrait>,
     pub sqs: SqsQueue,
     pub feature_flags_service: FeatureFlagsService,
+    pub recovery_relationship_service: RecoveryRelationshipService,
+    pub exchange_rate_service: ExchangeRateService,
+    pub rate_history_service: RateHistoryService,
+    pub transaction_history_service: TransactionHistoryService,
 }
 
 impl From<WorkerState> for NotificationValidationState {
     fn from(value: WorkerState) -> Self {
-        NotificationValidationState::new(value.recovery_service)
+        NotificationValidationState::new(value.recovery_service, value.account_service)
     }
 }
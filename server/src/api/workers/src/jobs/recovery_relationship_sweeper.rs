@@ -0,0 +1,39 @@
+use errors::ApiError;
+use recovery::service::social::relationship::sweep_recovery_relationships::SweepRecoveryRelationshipsInput;
+use time::OffsetDateTime;
+use tracing::{event, instrument, Level};
+
+use super::WorkerState;
+use crate::error::WorkerError;
+
+#[instrument(skip(state))]
+pub async fn handler(state: WorkerState, sleep_duration_seconds: u64) -> Result<(), WorkerError> {
+    let sleep_duration = std::time::Duration::from_secs(sleep_duration_seconds);
+
+    loop {
+        let result = run_once(state.clone()).await;
+        if let Err(e) = result {
+            event!(Level::ERROR, "Failed to sweep recovery relationships: {e}")
+        }
+        tokio::time::sleep(sleep_duration).await;
+    }
+}
+
+pub async fn run_once(state: WorkerState) -> Result<(), WorkerError> {
+    let output = state
+        .recovery_relationship_service
+        .sweep_recovery_relationships(SweepRecoveryRelationshipsInput {
+            now: OffsetDateTime::now_utc(),
+        })
+        .await
+        .map_err(ApiError::from)?;
+
+    event!(
+        Level::INFO,
+        "Swept recovery relationships: {} reminders sent, {} invitations archived, {} relationships marked stale",
+        output.reminders_sent,
+        output.invitations_archived,
+        output.relationships_marked_stale
+    );
+    Ok(())
+}
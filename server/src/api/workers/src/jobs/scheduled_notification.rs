@@ -77,6 +77,7 @@ async fn handle_scheduled_notification(
         return Ok(());
     }
 
+    // Sending resolves the account, so the notification is rendered in the account's locale
     state
         .notification_service
         .send_notification(SendNotificationInput {
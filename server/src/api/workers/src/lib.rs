@@ -5,3 +5,5 @@ mod ses;
 mod sms;
 mod sns;
 pub mod sqs;
+pub mod wallet_health;
+mod webhook;
@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 
 use aws_config::BehaviorVersion;
+use aws_sdk_sesv2::types::Body;
+use aws_sdk_sesv2::types::Content;
 use aws_sdk_sesv2::types::Destination;
 use aws_sdk_sesv2::types::EmailContent;
+use aws_sdk_sesv2::types::Message;
 use aws_sdk_sesv2::types::Template;
 use aws_sdk_sesv2::Client;
 use aws_sdk_sns::error::ProvideErrorMetadata;
@@ -47,8 +50,6 @@ impl SESClient {
     ) -> Result<(), WorkerError> {
         match self {
             Self::Real { client } => {
-                let dest = Destination::builder().to_addresses(to_address).build();
-
                 let template_data_content = tags
                     .iter()
                     .map(|(tag_name, tag_value)| format!("\"{}\": \"{}\"", tag_name, tag_value))
@@ -63,24 +64,61 @@ impl SESClient {
                     )
                     .build();
 
-                client
-                    .send_email()
-                    .from_email_address("noreply@dev.wallet.build")
-                    .destination(dest)
-                    .content(email_content)
-                    .send()
-                    .await
-                    .map_or_else(|e| {
-                        let service_err = e.into_service_error();
-                        event!(
-                            Level::ERROR,
-                            "Email Notification Lambda could not publish to email to SES due to error kind: {service_err:?} with message: {:?}",
-                            service_err.message()
-                        );
-                        Err(WorkerError::SESPublishError(service_err))
-                    },|_| Ok(()))
+                Self::send(client, to_address, email_content).await
             }
             Self::Test => Ok(()),
         }
     }
+
+    pub async fn send_rendered_email(
+        &self,
+        to_address: String,
+        subject: String,
+        html: String,
+    ) -> Result<(), WorkerError> {
+        match self {
+            Self::Real { client } => {
+                let email_content = EmailContent::builder()
+                    .simple(
+                        Message::builder()
+                            .subject(Content::builder().data(subject).build()?)
+                            .body(
+                                Body::builder()
+                                    .html(Content::builder().data(html).build()?)
+                                    .build(),
+                            )
+                            .build(),
+                    )
+                    .build();
+
+                Self::send(client, to_address, email_content).await
+            }
+            Self::Test => Ok(()),
+        }
+    }
+
+    async fn send(
+        client: &Client,
+        to_address: String,
+        email_content: EmailContent,
+    ) -> Result<(), WorkerError> {
+        let dest = Destination::builder().to_addresses(to_address).build();
+
+        client
+            .send_email()
+            .from_email_address("noreply@dev.wallet.build")
+            .destination(dest)
+            .content(email_content)
+            .send()
+            .await
+            .map_or_else(|e| {
+                let service_err = e.into_service_error();
+                event!(
+                    Level::ERROR,
+                    "Email Notification Lambda could not publish to email to SES due to error kind: {service_err:?} with message: {:?}",
+                    service_err.message()
+                );
+                Err(WorkerError::SESPublishError(service_err))
+            },|_| Ok(()))
+    }
 }
@@ -1,6 +1,7 @@
 use account::entities::Touchpoint;
 use isocountry::CountryCode;
 use notification::clients::twilio::{TwilioClient, TwilioMode};
+use notification::entities::NotificationCompositeKey;
 use notification::sms::SmsPayload;
 use tracing::instrument;
 
@@ -22,6 +23,7 @@ impl SendSMS {
         &self,
         touchpoint: &Touchpoint,
         payload: &SmsPayload,
+        notification: &NotificationCompositeKey,
     ) -> Result<(), WorkerError> {
         let Touchpoint::Phone {
             phone_number,
@@ -37,6 +39,7 @@ impl SendSMS {
                 country_code.to_owned(),
                 phone_number.to_owned(),
                 payload.message.clone(),
+                notification,
             )
             .await?;
 
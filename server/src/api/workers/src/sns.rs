@@ -58,11 +58,10 @@ impl SendPushNotification {
 
                         // These are expected when the user either disables push notifications
                         //   or uninstalls the application, so don't record it as an attempt or
-                        //   a failure.
-                        // TODO: (W-4532) figure out if we should be pruning disabled endpoints
+                        //   a failure. The caller prunes the touchpoint.
                         if service_err.is_endpoint_disabled_exception() {
                             event!(Level::INFO, msg);
-                            Ok(())
+                            Err(WorkerError::PushEndpointDisabled)
                         } else {
                             event!(Level::ERROR, msg);
                             notification_metrics::SNS_PUBLISH_ATTEMPT.add(1, &[]);
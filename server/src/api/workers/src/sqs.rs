@@ -4,6 +4,7 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use queue::sqs::SqsQueue;
+use tracing::{event, Level};
 
 use crate::error::WorkerError;
 use crate::jobs::WorkerState;
@@ -37,7 +38,18 @@ where
                 .collect()
         };
 
-        operation(serialized_messages).await?;
+        if let Err(e) = operation(serialized_messages).await {
+            if run_once {
+                return Err(e);
+            }
+            // Leave the messages on the queue, so they're redelivered once their visibility
+            // timeout expires and moved to the dead-letter queue after too many attempts.
+            event!(
+                Level::ERROR,
+                "Failed to process messages from {queue_url}: {e}"
+            );
+            continue;
+        }
 
         state
             .sqs
@@ -0,0 +1,229 @@
+//! System-wide on-chain health of account wallets, computed from the transaction index and the
+//! address watchlist. Gathered by the metrics job and printed by the admin CLI.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+
+use account::entities::Account;
+use bdk_utils::bdk::bitcoin::address::NetworkUnchecked;
+use bdk_utils::bdk::bitcoin::{Address, Network, OutPoint, Txid};
+use bdk_utils::bdk::electrum_client::ElectrumApi;
+use bdk_utils::{generate_electrum_rpc_uris, get_electrum_client, ElectrumRpcUris};
+use chain_indexer::transaction_history::get_unspent_outputs::UnspentOutput;
+use itertools::Itertools;
+use serde::Serialize;
+use tracing::{event, instrument, Level};
+use types::account::identifiers::{AccountId, KeysetId};
+
+use crate::error::WorkerError;
+use crate::jobs::WorkerState;
+
+/// Outputs worth less than this lose a large share of their value to fees when spent. A 2-of-3
+/// P2WSH input is about 105 vbytes, so this is roughly its fee at 10 sat/vB.
+pub const DUST_THRESHOLD_SATS: u64 = 1_000;
+
+// Electrum servers cap the size of a batched request.
+const ELECTRUM_BATCH_SIZE: usize = 100;
+
+/// Confirmed funds left on a keyset the account has rotated away from.
+#[derive(Debug, Clone, Serialize)]
+pub struct InactiveKeysetFunds {
+    pub account_id: AccountId,
+    pub keyset_id: KeysetId,
+    pub balance_sats: u64,
+    pub utxo_count: u64,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct NetworkWalletHealth {
+    /// Accounts with confirmed funds on any inactive keyset.
+    pub inactive_keyset_accounts: u64,
+    pub inactive_keyset_balance_sats: u64,
+    pub dust_utxos: u64,
+    /// Unconfirmed transactions spending any account's outputs.
+    pub pending_outflows: u64,
+    pub pending_outflow_balance_sats: u64,
+    pub inactive_keysets: Vec<InactiveKeysetFunds>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct WalletHealthReport {
+    pub networks: BTreeMap<Network, NetworkWalletHealth>,
+}
+
+impl WalletHealthReport {
+    pub fn network(&self, network: Network) -> NetworkWalletHealth {
+        self.networks.get(&network).cloned().unwrap_or_default()
+    }
+}
+
+/// Measures the wallets of every full account. This reads each account's whole transaction
+/// history, so it's only meant to be run periodically.
+///
+/// Unconfirmed spends are looked up from Electrum; if that fails for a network, its pending
+/// outflows are reported as zero rather than failing the whole measurement.
+#[instrument(skip(state))]
+pub async fn measure(state: &WorkerState) -> Result<WalletHealthReport, WorkerError> {
+    let mut report = WalletHealthReport::default();
+    let mut unspent_outputs_by_network: HashMap<Network, Vec<UnspentOutput>> = HashMap::new();
+
+    for account in state.account_service.fetch_accounts().await? {
+        let Account::Full(full_account) = account else {
+            continue;
+        };
+        let Some(active_keyset) = full_account.active_spending_keyset() else {
+            continue;
+        };
+        let network: Network = active_keyset.network.into();
+
+        let unspent_outputs = state
+            .transaction_history_service
+            .get_unspent_outputs(&full_account.id)
+            .await?;
+        if unspent_outputs.is_empty() {
+            continue;
+        }
+
+        let addresses = unspent_outputs
+            .iter()
+            .filter_map(|o| Address::<NetworkUnchecked>::from_str(&o.address).ok())
+            .unique()
+            .collect::<Vec<_>>();
+        let keyset_ids = state.address_repo.get_keyset_ids(&addresses).await?;
+
+        let health = report.networks.entry(network).or_default();
+        let mut inactive_keysets: HashMap<KeysetId, InactiveKeysetFunds> = HashMap::new();
+        for output in &unspent_outputs {
+            if output.amount_sats < DUST_THRESHOLD_SATS {
+                health.dust_utxos += 1;
+            }
+
+            // Outputs to addresses the app never registered can't be attributed to a keyset.
+            let Some(keyset_id) = Address::<NetworkUnchecked>::from_str(&output.address)
+                .ok()
+                .and_then(|address| keyset_ids.get(&address))
+            else {
+                continue;
+            };
+            if *keyset_id == full_account.active_keyset_id {
+                continue;
+            }
+            let funds = inactive_keysets
+                .entry(keyset_id.clone())
+                .or_insert_with(|| InactiveKeysetFunds {
+                    account_id: full_account.id.clone(),
+                    keyset_id: keyset_id.clone(),
+                    balance_sats: 0,
+                    utxo_count: 0,
+                });
+            funds.balance_sats += output.amount_sats;
+            funds.utxo_count += 1;
+        }
+
+        if !inactive_keysets.is_empty() {
+            health.inactive_keyset_accounts += 1;
+            health.inactive_keyset_balance_sats += inactive_keysets
+                .values()
+                .map(|f| f.balance_sats)
+                .sum::<u64>();
+            health
+                .inactive_keysets
+                .extend(inactive_keysets.into_values());
+        }
+
+        unspent_outputs_by_network
+            .entry(network)
+            .or_default()
+            .extend(unspent_outputs);
+    }
+
+    if unspent_outputs_by_network.is_empty() {
+        return Ok(report);
+    }
+    let rpc_uris = generate_electrum_rpc_uris(&state.feature_flags_service)?;
+    for (network, unspent_outputs) in unspent_outputs_by_network {
+        let rpc_uris = rpc_uris.clone();
+        // The Electrum client is blocking, so keep it off the runtime's worker threads
+        let pending_outflows = tokio::task::spawn_blocking(move || {
+            measure_pending_outflows(network, &unspent_outputs, &rpc_uris)
+        })
+        .await
+        .unwrap_or_else(|e| Err(e.into()));
+        match pending_outflows {
+            Ok((pending_outflows, pending_outflow_balance_sats)) => {
+                let health = report.networks.entry(network).or_default();
+                health.pending_outflows = pending_outflows;
+                health.pending_outflow_balance_sats = pending_outflow_balance_sats;
+            }
+            Err(e) => event!(
+                Level::ERROR,
+                "Error measuring {network} pending outflows: {e}"
+            ),
+        }
+    }
+
+    Ok(report)
+}
+
+/// Finds the unconfirmed transactions spending any of the outputs, and returns how many there are
+/// and the value of the outputs they spend.
+fn measure_pending_outflows(
+    network: Network,
+    unspent_outputs: &[UnspentOutput],
+    rpc_uris: &ElectrumRpcUris,
+) -> Result<(u64, u64), WorkerError> {
+    let electrum_client = get_electrum_client(network, rpc_uris)?;
+
+    let outpoints = unspent_outputs
+        .iter()
+        .filter_map(|o| {
+            let txid = Txid::from_str(&o.txid).ok()?;
+            Some((OutPoint::new(txid, o.vout), o.amount_sats))
+        })
+        .collect::<HashMap<_, _>>();
+    let scripts = unspent_outputs
+        .iter()
+        .map(|o| o.address.as_str())
+        .unique()
+        .filter_map(|address| {
+            Address::<NetworkUnchecked>::from_str(address)
+                .ok()?
+                .require_network(network)
+                .ok()
+        })
+        .map(|address| address.script_pubkey())
+        .collect::<Vec<_>>();
+
+    let mut mempool_txids = HashSet::new();
+    for chunk in scripts.chunks(ELECTRUM_BATCH_SIZE) {
+        let histories =
+            electrum_client.batch_script_get_history(chunk.iter().map(|s| s.as_script()))?;
+        // Electrum reports unconfirmed transactions at height 0, or -1 if a parent is unconfirmed.
+        mempool_txids.extend(
+            histories
+                .into_iter()
+                .flatten()
+                .filter(|h| h.height <= 0)
+                .map(|h| h.tx_hash),
+        );
+    }
+
+    let mut pending_outflows = 0;
+    let mut pending_outflow_balance_sats = 0;
+    let mempool_txids = mempool_txids.into_iter().collect::<Vec<_>>();
+    for chunk in mempool_txids.chunks(ELECTRUM_BATCH_SIZE) {
+        for tx in electrum_client.batch_transaction_get(chunk)? {
+            let spent_sats = tx
+                .input
+                .iter()
+                .filter_map(|i| outpoints.get(&i.previous_output))
+                .sum::<u64>();
+            if spent_sats > 0 {
+                pending_outflows += 1;
+                pending_outflow_balance_sats += spent_sats;
+            }
+        }
+    }
+
+    Ok((pending_outflows, pending_outflow_balance_sats))
+}
@@ -0,0 +1,28 @@
+use account::entities::Touchpoint;
+use notification::clients::webhook::{WebhookClient, WebhookMode};
+use tracing::instrument;
+
+use crate::error::WorkerError;
+
+pub struct SendWebhook {
+    pub webhook: WebhookClient,
+}
+
+impl SendWebhook {
+    pub async fn new(webhook_mode: WebhookMode) -> Self {
+        Self {
+            webhook: WebhookClient::new(webhook_mode),
+        }
+    }
+
+    #[instrument(skip(self, touchpoint, body))]
+    pub async fn send(&self, touchpoint: &Touchpoint, body: String) -> Result<(), WorkerError> {
+        let Touchpoint::Webhook { url, secret, .. } = touchpoint else {
+            return Err(WorkerError::IncorrectTouchpointType);
+        };
+
+        self.webhook.deliver(url, secret, body).await?;
+
+        Ok(())
+    }
+}
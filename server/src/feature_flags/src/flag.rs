@@ -23,12 +23,20 @@ where
     }
 
     pub fn resolver(&self, service: &Service) -> Resolver<T> {
-        self.with_context(service, "flag")
+        self.with_context(service, "user", "flag")
             .expect("context should always be valid")
     }
 
-    fn with_context(&self, service: &Service, context: &str) -> Result<Resolver<T>, Error> {
-        let context = ContextBuilder::new(context)
+    /// Resolves the flag for a single account, so that it can be targeted at specific accounts
+    /// or segments of them in LaunchDarkly.
+    pub fn resolver_for_account(&self, service: &Service, account_id: &str) -> Resolver<T> {
+        self.with_context(service, "account", account_id)
+            .expect("account context should always be valid")
+    }
+
+    fn with_context(&self, service: &Service, kind: &str, key: &str) -> Result<Resolver<T>, Error> {
+        let context = ContextBuilder::new(key)
+            .kind(kind)
             .build()
             .map_err(Error::Context)?;
         Ok(Resolver {
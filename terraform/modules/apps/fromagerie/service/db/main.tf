@@ -297,3 +297,113 @@ module "consent_table" {
 
   deletion_protection_enabled = var.enable_deletion_protection
 }
+
+module "recovery_history_table" {
+  source = "git::https://github.com/terraform-aws-modules/terraform-aws-dynamodb-table//?ref=9b66b76b2d178ca42425378deac9d9ebf95bf14e" // Tag v3.2.0
+
+  create_table = var.create_dynamodb_tables
+
+  name      = var.recovery_history_table_name
+  hash_key  = "partition_key"
+  range_key = "sort_key"
+
+  attributes = [
+    { name = "partition_key", type = "S" },
+    { name = "sort_key", type = "S" },
+  ]
+
+  point_in_time_recovery_enabled = true
+  server_side_encryption_enabled = true
+
+  deletion_protection_enabled = var.enable_deletion_protection
+}
+
+module "exchange_rate_history_table" {
+  source = "git::https://github.com/terraform-aws-modules/terraform-aws-dynamodb-table//?ref=9b66b76b2d178ca42425378deac9d9ebf95bf14e" // Tag v3.2.0
+
+  create_table = var.create_dynamodb_tables
+
+  name      = var.exchange_rate_history_table_name
+  hash_key  = "partition_key"
+  range_key = "sort_key"
+
+  attributes = [
+    { name = "partition_key", type = "S" },
+    { name = "sort_key", type = "N" },
+  ]
+
+  point_in_time_recovery_enabled = true
+  server_side_encryption_enabled = true
+
+  deletion_protection_enabled = var.enable_deletion_protection
+}
+
+module "account_transaction_table" {
+  source = "git::https://github.com/terraform-aws-modules/terraform-aws-dynamodb-table//?ref=9b66b76b2d178ca42425378deac9d9ebf95bf14e" // Tag v3.2.0
+
+  create_table = var.create_dynamodb_tables
+
+  name      = var.account_transaction_table_name
+  hash_key  = "partition_key"
+  range_key = "sort_key"
+
+  attributes = [
+    { name = "partition_key", type = "S" },
+    { name = "sort_key", type = "S" },
+    { name = "block_height", type = "N" },
+  ]
+
+  global_secondary_indexes = [
+    {
+      name            = "account_block_height_index"
+      hash_key        = "partition_key"
+      range_key       = "block_height"
+      projection_type = "ALL"
+    },
+  ]
+
+  point_in_time_recovery_enabled = true
+  server_side_encryption_enabled = true
+
+  deletion_protection_enabled = var.enable_deletion_protection
+}
+
+module "onboarding_signal_table" {
+  source = "git::https://github.com/terraform-aws-modules/terraform-aws-dynamodb-table//?ref=9b66b76b2d178ca42425378deac9d9ebf95bf14e" // Tag v3.2.0
+
+  create_table = var.create_dynamodb_tables
+
+  name      = var.onboarding_signal_table_name
+  hash_key  = "partition_key"
+  range_key = "sort_key"
+
+  attributes = [
+    { name = "partition_key", type = "S" },
+    { name = "sort_key", type = "S" },
+  ]
+
+  point_in_time_recovery_enabled = true
+  server_side_encryption_enabled = true
+
+  deletion_protection_enabled = var.enable_deletion_protection
+}
+
+module "recovery_cancellation_attempts_table" {
+  source = "git::https://github.com/terraform-aws-modules/terraform-aws-dynamodb-table//?ref=9b66b76b2d178ca42425378deac9d9ebf95bf14e" // Tag v3.2.0
+
+  create_table = var.create_dynamodb_tables
+
+  name     = var.recovery_cancellation_attempts_table_name
+  hash_key = "partition_key"
+
+  attributes = [
+    { name = "partition_key", type = "S" },
+  ]
+
+  server_side_encryption_enabled = true
+
+  ttl_enabled        = true
+  ttl_attribute_name = "expiring_at"
+
+  deletion_protection_enabled = var.enable_deletion_protection
+}
@@ -61,3 +61,28 @@ variable "consent_table_name" {
   type        = string
   description = "The name of the consent table"
 }
+
+variable "recovery_history_table_name" {
+  type        = string
+  description = "The name of the recovery history table"
+}
+
+variable "exchange_rate_history_table_name" {
+  type        = string
+  description = "The name of the exchange rate history table"
+}
+
+variable "account_transaction_table_name" {
+  type        = string
+  description = "The name of the account transaction table"
+}
+
+variable "onboarding_signal_table_name" {
+  type        = string
+  description = "The name of the onboarding signal table"
+}
+
+variable "recovery_cancellation_attempts_table_name" {
+  type        = string
+  description = "The name of the recovery cancellation attempts table"
+}
@@ -20,14 +20,19 @@ locals {
   # DynamoDB Tables
   ################################################
   tables = {
-    address_watchlist_table_name     = "${module.this.id_dot}.address_watchlist"
-    notification_table_name          = "${module.this.id_dot}.notification"
-    chain_indexer_table_name         = "${module.this.id_dot}.chain_indexer"
-    daily_spending_record_table_name = "${module.this.id_dot}.daily_spending_record"
-    signed_psbt_cache_table_name     = "${module.this.id_dot}.signed_psbt_cache"
-    migration_record_table_name      = "${module.this.id_dot}.migration_records"
-    social_recovery_table_name       = "${module.this.id_dot}.social_recovery"
-    consent_table_name               = "${module.this.id_dot}.consent"
+    address_watchlist_table_name              = "${module.this.id_dot}.address_watchlist"
+    notification_table_name                   = "${module.this.id_dot}.notification"
+    chain_indexer_table_name                  = "${module.this.id_dot}.chain_indexer"
+    daily_spending_record_table_name          = "${module.this.id_dot}.daily_spending_record"
+    signed_psbt_cache_table_name              = "${module.this.id_dot}.signed_psbt_cache"
+    migration_record_table_name               = "${module.this.id_dot}.migration_records"
+    social_recovery_table_name                = "${module.this.id_dot}.social_recovery"
+    consent_table_name                        = "${module.this.id_dot}.consent"
+    recovery_history_table_name               = "${module.this.id_dot}.recovery_history"
+    exchange_rate_history_table_name          = "${module.this.id_dot}.exchange_rate_history"
+    account_transaction_table_name            = "${module.this.id_dot}.account_transaction"
+    onboarding_signal_table_name              = "${module.this.id_dot}.onboarding_signal"
+    recovery_cancellation_attempts_table_name = "${module.this.id_dot}.recovery_cancellation_attempts"
 
     # Below are old tables that we retain a name override for the deprecated PrototypeOnboardingStack
     # New tables should be added above without coalesce()
@@ -73,20 +78,27 @@ locals {
     PUSH_QUEUE_URL                   = module.push_notification_queue.queue_url
     EMAIL_QUEUE_URL                  = module.email_notification_queue.queue_url
     SMS_QUEUE_URL                    = module.sms_notification_queue.queue_url
+    WEBHOOK_QUEUE_URL                = module.webhook_notification_queue.queue_url
+    ANALYTICS_QUEUE_URL              = module.analytics_queue.queue_url
     SERVER_WSM_ENDPOINT              = var.wsm_endpoint
     SERVER_FROMAGERIE_ENDPOINT       = "https://${module.ecs_api.alb_fqdn}"
     SERVER_ENABLE_FUND_SIGNET_WALLET = "true"
     ROCKET_PROFILE                   = var.config_profile
 
-    ACCOUNT_TABLE               = local.tables.account_table_name
-    ADDRESS_WATCHLIST_TABLE     = local.tables.address_watchlist_table_name
-    CHAIN_INDEXER_TABLE         = local.tables.chain_indexer_table_name
-    DAILY_SPENDING_RECORD_TABLE = local.tables.daily_spending_record_table_name
-    NOTIFICATION_TABLE          = local.tables.notification_table_name
-    RECOVERY_TABLE              = local.tables.recovery_table_name
-    SIGNED_PSBT_CACHE_TABLE     = local.tables.signed_psbt_cache_table_name
-    SOCIAL_RECOVERY_TABLE       = local.tables.social_recovery_table_name
-    CONSENT_TABLE               = local.tables.consent_table_name
+    ACCOUNT_TABLE                        = local.tables.account_table_name
+    ADDRESS_WATCHLIST_TABLE              = local.tables.address_watchlist_table_name
+    CHAIN_INDEXER_TABLE                  = local.tables.chain_indexer_table_name
+    DAILY_SPENDING_RECORD_TABLE          = local.tables.daily_spending_record_table_name
+    NOTIFICATION_TABLE                   = local.tables.notification_table_name
+    RECOVERY_TABLE                       = local.tables.recovery_table_name
+    SIGNED_PSBT_CACHE_TABLE              = local.tables.signed_psbt_cache_table_name
+    SOCIAL_RECOVERY_TABLE                = local.tables.social_recovery_table_name
+    CONSENT_TABLE                        = local.tables.consent_table_name
+    RECOVERY_HISTORY_TABLE               = local.tables.recovery_history_table_name
+    EXCHANGE_RATE_HISTORY_TABLE          = local.tables.exchange_rate_history_table_name
+    ACCOUNT_TRANSACTION_TABLE            = local.tables.account_transaction_table_name
+    ONBOARDING_SIGNAL_TABLE              = local.tables.onboarding_signal_table_name
+    RECOVERY_CANCELLATION_ATTEMPTS_TABLE = local.tables.recovery_cancellation_attempts_table_name
   }
 
   ###############################################
@@ -110,6 +122,10 @@ data "aws_secretsmanager_secret" "fromagerie_twilio_credentials" {
   name = "fromagerie/twilio/credentials"
 }
 
+data "aws_secretsmanager_secret" "fromagerie_recovery_cancellation_token_key" {
+  name = "fromagerie/recovery/cancellation_token_key"
+}
+
 data "aws_secretsmanager_secret" "fromagerie_launchdarkly_sdk_key" {
   name = "fromagerie/launchdarkly/sdk_key"
 }
@@ -136,16 +152,21 @@ module "dynamodb_tables" {
 
   enable_deletion_protection = var.enable_deletion_protection
 
-  account_table_name               = local.tables.account_table_name
-  address_watchlist_table_name     = local.tables.address_watchlist_table_name
-  chain_indexer_table_name         = local.tables.chain_indexer_table_name
-  daily_spending_record_table_name = local.tables.daily_spending_record_table_name
-  notification_table_name          = local.tables.notification_table_name
-  recovery_table_name              = local.tables.recovery_table_name
-  signed_psbt_cache_table_name     = local.tables.signed_psbt_cache_table_name
-  migration_record_table_name      = local.tables.migration_record_table_name
-  social_recovery_table_name       = local.tables.social_recovery_table_name
-  consent_table_name               = local.tables.consent_table_name
+  account_table_name                        = local.tables.account_table_name
+  address_watchlist_table_name              = local.tables.address_watchlist_table_name
+  chain_indexer_table_name                  = local.tables.chain_indexer_table_name
+  daily_spending_record_table_name          = local.tables.daily_spending_record_table_name
+  notification_table_name                   = local.tables.notification_table_name
+  recovery_table_name                       = local.tables.recovery_table_name
+  signed_psbt_cache_table_name              = local.tables.signed_psbt_cache_table_name
+  migration_record_table_name               = local.tables.migration_record_table_name
+  social_recovery_table_name                = local.tables.social_recovery_table_name
+  consent_table_name                        = local.tables.consent_table_name
+  recovery_history_table_name               = local.tables.recovery_history_table_name
+  exchange_rate_history_table_name          = local.tables.exchange_rate_history_table_name
+  account_transaction_table_name            = local.tables.account_transaction_table_name
+  onboarding_signal_table_name              = local.tables.onboarding_signal_table_name
+  recovery_cancellation_attempts_table_name = local.tables.recovery_cancellation_attempts_table_name
 }
 
 module "ecs_api" {
@@ -171,14 +192,15 @@ module "ecs_api" {
     ROCKET_PORT             = local.port
   })
   secrets = merge(local.common_secrets, {
-    ITERABLE_API_KEY      = data.aws_secretsmanager_secret.fromagerie_iterable_credentials.arn,
-    COINGECKO_API_KEY     = data.aws_secretsmanager_secret.fromagerie_coingecko_api_key.arn,
-    COINMARKETCAP_API_KEY = data.aws_secretsmanager_secret.fromagerie_coinmarketcap_api_key.arn,
-    TWILIO_ACCOUNT_SID    = "${data.aws_secretsmanager_secret.fromagerie_twilio_credentials.arn}:TWILIO_ACCOUNT_SID::",
-    TWILIO_AUTH_TOKEN     = "${data.aws_secretsmanager_secret.fromagerie_twilio_credentials.arn}:TWILIO_AUTH_TOKEN::",
-    TWILIO_KEY_SID        = "${data.aws_secretsmanager_secret.fromagerie_twilio_credentials.arn}:TWILIO_KEY_SID::",
-    TWILIO_KEY_SECRET     = "${data.aws_secretsmanager_secret.fromagerie_twilio_credentials.arn}:TWILIO_KEY_SECRET::",
-    ZENDESK_AUTHORIZATION = data.aws_secretsmanager_secret.fromagerie_zendesk_credentials.arn,
+    ITERABLE_API_KEY                = data.aws_secretsmanager_secret.fromagerie_iterable_credentials.arn,
+    COINGECKO_API_KEY               = data.aws_secretsmanager_secret.fromagerie_coingecko_api_key.arn,
+    COINMARKETCAP_API_KEY           = data.aws_secretsmanager_secret.fromagerie_coinmarketcap_api_key.arn,
+    TWILIO_ACCOUNT_SID              = "${data.aws_secretsmanager_secret.fromagerie_twilio_credentials.arn}:TWILIO_ACCOUNT_SID::",
+    TWILIO_AUTH_TOKEN               = "${data.aws_secretsmanager_secret.fromagerie_twilio_credentials.arn}:TWILIO_AUTH_TOKEN::",
+    TWILIO_KEY_SID                  = "${data.aws_secretsmanager_secret.fromagerie_twilio_credentials.arn}:TWILIO_KEY_SID::",
+    TWILIO_KEY_SECRET               = "${data.aws_secretsmanager_secret.fromagerie_twilio_credentials.arn}:TWILIO_KEY_SECRET::",
+    ZENDESK_AUTHORIZATION           = data.aws_secretsmanager_secret.fromagerie_zendesk_credentials.arn,
+    RECOVERY_CANCELLATION_TOKEN_KEY = data.aws_secretsmanager_secret.fromagerie_recovery_cancellation_token_key.arn,
   })
   image_name       = var.image_name
   image_tag        = var.image_tag
@@ -199,11 +221,12 @@ module "api_migration" {
   command    = ["migrate"]
 
   environment_variables = merge(local.common_env_vars, {
-    SERVER_WALLET_TELEMETRY = "{service_name=${var.name}-api-migration,mode=datadog}"
-    COGNITO_USER_POOL       = var.cognito_user_pool_id
-    COGNITO_CLIENT_ID       = var.cognito_user_pool_client_id
-    MIGRATION_TABLE         = local.tables.migration_record_table_name
-    SERVER_ZENDESK          = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-api-migration,mode=datadog}"
+    COGNITO_USER_POOL                  = var.cognito_user_pool_id
+    COGNITO_CLIENT_ID                  = var.cognito_user_pool_client_id
+    MIGRATION_TABLE                    = local.tables.migration_record_table_name
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
   })
   environment = var.environment
   secrets = merge(local.common_secrets, {
@@ -265,11 +288,12 @@ module "ecs_job_push" {
 
   environment = var.environment
   environment_variables = merge(local.common_env_vars, {
-    SERVER_WALLET_TELEMETRY = "{service_name=${var.name}-job-push,mode=datadog}"
-    SERVER_COGNITO          = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
-    SERVER_TWILIO           = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
-    SERVER_ITERABLE         = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
-    SERVER_ZENDESK          = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-push,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
   })
   secrets          = merge(local.common_secrets, {})
   image_name       = var.image_name
@@ -282,6 +306,36 @@ module "ecs_job_push" {
   wait_for_steady_state = var.wait_for_steady_state
 }
 
+module "ecs_job_analytics" {
+  source = "../../../models/ecs-service"
+
+  namespace = var.namespace
+  name      = "${var.name}-job-analytics"
+
+  create_load_balancer = false
+  vpc_name             = var.vpc_name
+  cluster_arn          = var.cluster_arn
+
+  environment = var.environment
+  environment_variables = merge(local.common_env_vars, {
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-analytics,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
+  })
+  secrets          = merge(local.common_secrets, {})
+  image_name       = var.image_name
+  image_tag        = var.image_tag
+  command          = ["worker", "analytics"]
+  port             = local.port
+  cpu_architecture = "ARM64"
+
+  desired_count         = var.job_analytics_desired_count
+  wait_for_steady_state = var.wait_for_steady_state
+}
+
 module "ecs_job_email" {
   source = "../../../models/ecs-service"
 
@@ -294,10 +348,11 @@ module "ecs_job_email" {
 
   environment = var.environment
   environment_variables = merge(local.common_env_vars, {
-    SERVER_WALLET_TELEMETRY = "{service_name=${var.name}-job-email,mode=datadog}"
-    SERVER_COGNITO          = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
-    SERVER_TWILIO           = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
-    SERVER_ZENDESK          = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-email,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
   })
   secrets = merge(local.common_secrets, {
     ITERABLE_API_KEY = data.aws_secretsmanager_secret.fromagerie_iterable_credentials.arn
@@ -324,10 +379,11 @@ module "ecs_job_sms" {
 
   environment = var.environment
   environment_variables = merge(local.common_env_vars, {
-    SERVER_WALLET_TELEMETRY = "{service_name=${var.name}-job-sms,mode=datadog}"
-    SERVER_COGNITO          = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
-    SERVER_ITERABLE         = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
-    SERVER_ZENDESK          = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-sms,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
   })
   secrets = merge(local.common_secrets, {
     TWILIO_ACCOUNT_SID = "${data.aws_secretsmanager_secret.fromagerie_twilio_credentials.arn}:TWILIO_ACCOUNT_SID::",
@@ -345,6 +401,36 @@ module "ecs_job_sms" {
   wait_for_steady_state = var.wait_for_steady_state
 }
 
+module "ecs_job_webhook" {
+  source = "../../../models/ecs-service"
+
+  namespace = var.namespace
+  name      = "${var.name}-job-webhook"
+
+  create_load_balancer = false
+  vpc_name             = var.vpc_name
+  cluster_arn          = var.cluster_arn
+
+  environment = var.environment
+  environment_variables = merge(local.common_env_vars, {
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-webhook,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
+  })
+  secrets          = local.common_secrets
+  image_name       = var.image_name
+  image_tag        = var.image_tag
+  command          = ["worker", "webhook"]
+  port             = local.port
+  cpu_architecture = "ARM64"
+
+  desired_count         = var.job_webhook_desired_count
+  wait_for_steady_state = var.wait_for_steady_state
+}
+
 module "ecs_job_scheduled_notification_task" {
   source = "../../../models/ecs-service"
 
@@ -360,11 +446,12 @@ module "ecs_job_scheduled_notification_task" {
   command     = ["worker", "scheduled-notification"]
   environment = var.environment
   environment_variables = merge(local.common_env_vars, {
-    SERVER_WALLET_TELEMETRY = "{service_name=${var.name}-job-scheduled-notification,mode=datadog}"
-    SERVER_COGNITO          = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
-    SERVER_TWILIO           = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
-    SERVER_ITERABLE         = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
-    SERVER_ZENDESK          = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-scheduled-notification,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
   })
   secrets          = merge(local.common_secrets, {})
   cpu_architecture = "ARM64"
@@ -388,13 +475,14 @@ module "ecs_job_blockchain_polling_task_signet" {
   command     = ["worker", "blockchain-polling"]
   environment = var.environment
   environment_variables = merge(local.common_env_vars, {
-    SERVER_WALLET_TELEMETRY = "{service_name=${var.name}-job-blockchain-polling,mode=datadog}"
-    SERVER_COGNITO          = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
-    SERVER_TWILIO           = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
-    SERVER_ITERABLE         = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
-    SERVER_ZENDESK          = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
-    CHAIN_INDEXER_BASE_URL  = "https://bitkey.mempool.space/signet/api"
-    CHAIN_INDEXER_NETWORK   = "signet"
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-blockchain-polling,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
+    CHAIN_INDEXER_BASE_URL             = "https://bitkey.mempool.space/signet/api"
+    CHAIN_INDEXER_NETWORK              = "signet"
   })
   secrets          = merge(local.common_secrets, {})
   cpu_architecture = "ARM64"
@@ -418,13 +506,14 @@ module "ecs_job_blockchain_polling_task_mainnet" {
   command     = ["worker", "blockchain-polling"]
   environment = var.environment
   environment_variables = merge(local.common_env_vars, {
-    SERVER_WALLET_TELEMETRY = "{service_name=${var.name}-job-blockchain-polling,mode=datadog}"
-    SERVER_COGNITO          = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
-    SERVER_TWILIO           = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
-    SERVER_ITERABLE         = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
-    SERVER_ZENDESK          = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
-    CHAIN_INDEXER_BASE_URL  = "https://bitkey.mempool.space/api"
-    CHAIN_INDEXER_NETWORK   = "bitcoin"
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-blockchain-polling,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
+    CHAIN_INDEXER_BASE_URL             = "https://bitkey.mempool.space/api"
+    CHAIN_INDEXER_NETWORK              = "bitcoin"
   })
   secrets          = merge(local.common_secrets, {})
   cpu_architecture = "ARM64"
@@ -448,11 +537,12 @@ module "ecs_job_metrics" {
   command     = ["worker", "metrics"]
   environment = var.environment
   environment_variables = merge(local.common_env_vars, {
-    SERVER_WALLET_TELEMETRY = "{service_name=${var.name}-job-metrics,mode=datadog}"
-    SERVER_COGNITO          = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
-    SERVER_TWILIO           = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
-    SERVER_ITERABLE         = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
-    SERVER_ZENDESK          = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-metrics,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
   })
   secrets          = merge(local.common_secrets, {})
   cpu_architecture = "ARM64"
@@ -461,6 +551,67 @@ module "ecs_job_metrics" {
   wait_for_steady_state = var.wait_for_steady_state
 }
 
+module "ecs_job_recovery_relationship_sweeper" {
+  source = "../../../models/ecs-service"
+
+  namespace = var.namespace
+  name      = "${var.name}-job-recovery-relationship-sweeper"
+
+  create_load_balancer = false
+  vpc_name             = var.vpc_name
+  cluster_arn          = var.cluster_arn
+
+  image_name  = var.image_name
+  image_tag   = var.image_tag
+  command     = ["worker", "recovery-relationship-sweeper"]
+  environment = var.environment
+  environment_variables = merge(local.common_env_vars, {
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-recovery-relationship-sweeper,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
+  })
+  secrets          = merge(local.common_secrets, {})
+  cpu_architecture = "ARM64"
+
+  desired_count         = var.job_recovery_relationship_sweeper_desired_count
+  wait_for_steady_state = var.wait_for_steady_state
+}
+
+module "ecs_job_exchange_rate_snapshot" {
+  source = "../../../models/ecs-service"
+
+  namespace = var.namespace
+  name      = "${var.name}-job-exchange-rate-snapshot"
+
+  create_load_balancer = false
+  vpc_name             = var.vpc_name
+  cluster_arn          = var.cluster_arn
+
+  image_name  = var.image_name
+  image_tag   = var.image_tag
+  command     = ["worker", "exchange-rate-snapshot"]
+  environment = var.environment
+  environment_variables = merge(local.common_env_vars, {
+    SERVER_WALLET_TELEMETRY            = "{service_name=${var.name}-job-exchange-rate-snapshot,mode=datadog}"
+    SERVER_COGNITO                     = "test"        //TODO: Pick apart bootstrap dependence on Cognito,
+    SERVER_TWILIO                      = "{mode=test}" //TODO: Pick apart bootstrap dependence on Twilio,
+    SERVER_ITERABLE                    = "{mode=test}" //TODO: Pick apart bootstrap dependence on Iterable,
+    SERVER_ZENDESK                     = "{mode=test}" //TODO: Pick apart bootstrap dependence on Zendesk,
+    SERVER_RECOVERY_CANCELLATION_TOKEN = "{mode=test}" //TODO: Pick apart bootstrap dependence on recovery cancellation tokens,
+  })
+  secrets = merge(local.common_secrets, {
+    COINGECKO_API_KEY     = data.aws_secretsmanager_secret.fromagerie_coingecko_api_key.arn,
+    COINMARKETCAP_API_KEY = data.aws_secretsmanager_secret.fromagerie_coinmarketcap_api_key.arn,
+  })
+  cpu_architecture = "ARM64"
+
+  desired_count         = var.job_exchange_rate_snapshot_desired_count
+  wait_for_steady_state = var.wait_for_steady_state
+}
+
 ################################################
 # S3 Buckets
 ################################################
@@ -514,7 +665,9 @@ data "aws_iam_policy_document" "api_iam_policy" {
     resources = [
       module.push_notification_queue.queue_arn,
       module.email_notification_queue.queue_arn,
-      module.sms_notification_queue.queue_arn
+      module.sms_notification_queue.queue_arn,
+      module.webhook_notification_queue.queue_arn,
+      module.analytics_queue.queue_arn
     ]
   }
 
@@ -529,7 +682,9 @@ data "aws_iam_policy_document" "api_iam_policy" {
     resources = [
       module.push_notification_queue.queue_arn,
       module.email_notification_queue.queue_arn,
-      module.sms_notification_queue.queue_arn
+      module.sms_notification_queue.queue_arn,
+      module.webhook_notification_queue.queue_arn,
+      module.analytics_queue.queue_arn
     ]
   }
 
@@ -542,7 +697,9 @@ data "aws_iam_policy_document" "api_iam_policy" {
     resources = [
       module.push_notification_queue.queue_arn,
       module.email_notification_queue.queue_arn,
-      module.sms_notification_queue.queue_arn
+      module.sms_notification_queue.queue_arn,
+      module.webhook_notification_queue.queue_arn,
+      module.analytics_queue.queue_arn
     ]
   }
 
@@ -593,6 +750,11 @@ resource "aws_iam_role_policy" "job_email_iam_policy" {
   policy = data.aws_iam_policy_document.api_iam_policy.json
 }
 
+resource "aws_iam_role_policy" "job_analytics_iam_policy" {
+  role   = module.ecs_job_analytics.task_role_name
+  policy = data.aws_iam_policy_document.api_iam_policy.json
+}
+
 resource "aws_iam_role_policy" "job_push_iam_policy" {
   role   = module.ecs_job_push.task_role_name
   policy = data.aws_iam_policy_document.api_iam_policy.json
@@ -603,6 +765,11 @@ resource "aws_iam_role_policy" "job_sms_iam_policy" {
   policy = data.aws_iam_policy_document.api_iam_policy.json
 }
 
+resource "aws_iam_role_policy" "job_webhook_iam_policy" {
+  role   = module.ecs_job_webhook.task_role_name
+  policy = data.aws_iam_policy_document.api_iam_policy.json
+}
+
 resource "aws_iam_role_policy" "job_scheduled_notification" {
   role   = module.ecs_job_scheduled_notification_task.task_role_name
   policy = data.aws_iam_policy_document.api_iam_policy.json
@@ -623,6 +790,16 @@ resource "aws_iam_role_policy" "job_metrics" {
   policy = data.aws_iam_policy_document.api_iam_policy.json
 }
 
+resource "aws_iam_role_policy" "job_recovery_relationship_sweeper" {
+  role   = module.ecs_job_recovery_relationship_sweeper.task_role_name
+  policy = data.aws_iam_policy_document.api_iam_policy.json
+}
+
+resource "aws_iam_role_policy" "job_exchange_rate_snapshot" {
+  role   = module.ecs_job_exchange_rate_snapshot.task_role_name
+  policy = data.aws_iam_policy_document.api_iam_policy.json
+}
+
 data "aws_iam_policy_document" "secrets_iam_policy" {
   statement {
     resources = [
@@ -644,6 +821,11 @@ resource "aws_iam_role_policy" "job_email_secrets_iam_policy" {
   policy = data.aws_iam_policy_document.secrets_iam_policy.json
 }
 
+resource "aws_iam_role_policy" "job_analytics_secrets_iam_policy" {
+  role   = module.ecs_job_analytics.exec_role_name
+  policy = data.aws_iam_policy_document.secrets_iam_policy.json
+}
+
 resource "aws_iam_role_policy" "job_push_secrets_iam_policy" {
   role   = module.ecs_job_push.exec_role_name
   policy = data.aws_iam_policy_document.secrets_iam_policy.json
@@ -654,6 +836,11 @@ resource "aws_iam_role_policy" "job_sms_secrets_iam_policy" {
   policy = data.aws_iam_policy_document.secrets_iam_policy.json
 }
 
+resource "aws_iam_role_policy" "job_webhook_secrets_iam_policy" {
+  role   = module.ecs_job_webhook.exec_role_name
+  policy = data.aws_iam_policy_document.secrets_iam_policy.json
+}
+
 resource "aws_iam_role_policy" "job_metrics_secrets_iam_policy" {
   role   = module.ecs_job_metrics.exec_role_name
   policy = data.aws_iam_policy_document.secrets_iam_policy.json
@@ -674,6 +861,16 @@ resource "aws_iam_role_policy" "job_scheduled_notification_secrets" {
   policy = data.aws_iam_policy_document.secrets_iam_policy.json
 }
 
+resource "aws_iam_role_policy" "job_recovery_relationship_sweeper_secrets" {
+  role   = module.ecs_job_recovery_relationship_sweeper.exec_role_name
+  policy = data.aws_iam_policy_document.secrets_iam_policy.json
+}
+
+resource "aws_iam_role_policy" "job_exchange_rate_snapshot_secrets" {
+  role   = module.ecs_job_exchange_rate_snapshot.exec_role_name
+  policy = data.aws_iam_policy_document.secrets_iam_policy.json
+}
+
 resource "aws_iam_role_policy" "task_api_migration_secrets" {
   role   = module.api_migration_iam.exec_role_name
   policy = data.aws_iam_policy_document.secrets_iam_policy.json
@@ -693,6 +890,13 @@ module "job_email_table_policy" {
   table_names = local.table_name_list
 }
 
+module "job_analytics_table_policy" {
+  source = "../../../pieces/dynamodb-iam-policy"
+
+  role        = module.ecs_job_analytics.task_role_name
+  table_names = local.table_name_list
+}
+
 module "job_push_table_policy" {
   source = "../../../pieces/dynamodb-iam-policy"
 
@@ -707,6 +911,13 @@ module "job_sms_table_policy" {
   table_names = local.table_name_list
 }
 
+module "job_webhook_table_policy" {
+  source = "../../../pieces/dynamodb-iam-policy"
+
+  role        = module.ecs_job_webhook.task_role_name
+  table_names = local.table_name_list
+}
+
 module "job_scheduled_notification" {
   source = "../../../pieces/dynamodb-iam-policy"
 
@@ -734,6 +945,21 @@ module "job_metrics_table_policy" {
   role        = module.ecs_job_metrics.task_role_name
   table_names = local.table_name_list
 }
+
+module "job_recovery_relationship_sweeper_table_policy" {
+  source = "../../../pieces/dynamodb-iam-policy"
+
+  role        = module.ecs_job_recovery_relationship_sweeper.task_role_name
+  table_names = local.table_name_list
+}
+
+module "job_exchange_rate_snapshot_table_policy" {
+  source = "../../../pieces/dynamodb-iam-policy"
+
+  role        = module.ecs_job_exchange_rate_snapshot.task_role_name
+  table_names = local.table_name_list
+}
+
 module "task_api_migration_table_policy" {
   source = "../../../pieces/dynamodb-iam-policy"
 
@@ -762,3 +988,15 @@ module "sms_notification_queue" {
 
   name = "${module.this.id}-sms-notification"
 }
+
+module "webhook_notification_queue" {
+  source = "git::https://github.com/terraform-aws-modules/terraform-aws-sqs//?ref=7ded3fe7c3b2423ad7da00ad90e651ec133e5774" // Tag v4.0.1
+
+  name = "${module.this.id}-webhook-notification"
+}
+
+module "analytics_queue" {
+  source = "git::https://github.com/terraform-aws-modules/terraform-aws-sqs//?ref=7ded3fe7c3b2423ad7da00ad90e651ec133e5774" // Tag v4.0.1
+
+  name = "${module.this.id}-analytics"
+}
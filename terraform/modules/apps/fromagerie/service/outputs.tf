@@ -10,6 +10,14 @@ output "sms_notification_queue_url" {
   value = module.sms_notification_queue.queue_url
 }
 
+output "webhook_notification_queue_url" {
+  value = module.webhook_notification_queue.queue_url
+}
+
+output "analytics_queue_url" {
+  value = module.analytics_queue.queue_url
+}
+
 output "scheduled_notification_task_role_arn" {
   value = module.ecs_job_scheduled_notification_task.task_role_arn
 }
@@ -112,6 +112,12 @@ variable "job_email_desired_count" {
   default     = 1
 }
 
+variable "job_analytics_desired_count" {
+  type        = number
+  description = "The number of instances of the task definition to place and keep running"
+  default     = 1
+}
+
 variable "job_push_desired_count" {
   type        = number
   description = "The number of instances of the task definition to place and keep running"
@@ -124,6 +130,12 @@ variable "job_sms_desired_count" {
   default     = 1
 }
 
+variable "job_webhook_desired_count" {
+  type        = number
+  description = "The number of instances of the task definition to place and keep running"
+  default     = 1
+}
+
 variable "job_blockchain_desired_count" {
   type        = number
   description = "The number of instances of the task definition to place and keep running"
@@ -136,6 +148,18 @@ variable "job_metrics_desired_count" {
   default     = 1
 }
 
+variable "job_recovery_relationship_sweeper_desired_count" {
+  type        = number
+  description = "The number of instances of the task definition to place and keep running"
+  default     = 1
+}
+
+variable "job_exchange_rate_snapshot_desired_count" {
+  type        = number
+  description = "The number of instances of the task definition to place and keep running"
+  default     = 1
+}
+
 variable "environment" {
   type        = string
   description = "Name of the deployment environment for tagging (beta, development, staging, production)"